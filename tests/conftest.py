@@ -2,6 +2,7 @@
 Pytest configuration and shared fixtures.
 """
 import asyncio
+import gzip
 from pathlib import Path
 from typing import AsyncGenerator, Generator
 from unittest.mock import Mock, AsyncMock
@@ -156,6 +157,98 @@ def sample_audit_result(sample_issues: list[Issue]) -> AuditResult:
     )
 
 
+@pytest.fixture
+def results_fixtures_dir() -> Path:
+    """Directory of audit results captured from real CLI runs."""
+    return Path(__file__).parent / "fixtures" / "results"
+
+
+@pytest.fixture
+def load_result_fixture(results_fixtures_dir: Path):
+    """Load a captured audit result by file name."""
+    def _load(name: str) -> AuditResult:
+        return AuditResult.load(results_fixtures_dir / name)
+
+    return _load
+
+
+@pytest.fixture
+def html_fixture():
+    """Read an HTML page with known defects from tests/fixtures/html."""
+    def _read(name: str) -> str:
+        return (Path(__file__).parent / "fixtures" / "html" / name).read_text(encoding="utf-8")
+
+    return _read
+
+
+@pytest.fixture
+def url_list_fixture():
+    """Read a crawled URL list from tests/fixtures/urls, skipping # comments."""
+    def _read(name: str) -> list[str]:
+        text = (Path(__file__).parent / "fixtures" / "urls" / name).read_text(encoding="utf-8")
+        return [line.strip() for line in text.splitlines() if line.strip() and not line.startswith("#")]
+
+    return _read
+
+
+class AssetSite:
+    """
+    A site serving static files: path -> (content type, body, encodings it
+    uses, extra headers, status).
+
+    Routes may also be keyed by full URL, for sites spanning hosts or schemes;
+    extra headers and status (200) are optional. Compresses only with the
+    listed encodings the request accepts (brotli is faked with gzip -9, so
+    compressed bodies can be measured but not decoded), answers HEAD with
+    Content-Length, and records each request.
+    """
+
+    def __init__(self, routes: dict):
+        self.routes = routes
+        self.requests: list[tuple[str, str, str]] = []
+
+    def __call__(self, request: httpx.Request) -> httpx.Response:
+        accepted = request.headers.get("accept-encoding", "")
+        self.requests.append((request.method, request.url.path, accepted))
+        route = self.routes.get(str(request.url)) or self.routes.get(request.url.path)
+        if route is None:
+            return httpx.Response(404, text="Not found")
+        content_type, body, encodings, *extra = route
+        headers = {"content-type": content_type, **(extra[0] if extra else {})}
+        status = extra[1] if len(extra) > 1 else 200
+        encoding = next((e for e in ("br", "gzip") if e in encodings and e in accepted), None)
+        if request.method != "HEAD" and encoding is not None:
+            body = gzip.compress(body, compresslevel=9 if encoding == "br" else 6)
+            headers["content-encoding"] = encoding
+        else:
+            headers["content-length"] = str(len(body))
+        if request.method == "HEAD":
+            return httpx.Response(status, headers=headers)
+        # Streamed as a network response is, so the bytes on the wire are counted
+        return httpx.Response(status, headers=headers, stream=httpx.ByteStream(body))
+
+    def client(self) -> httpx.AsyncClient:
+        return httpx.AsyncClient(transport=httpx.MockTransport(self))
+
+
+@pytest.fixture
+def asset_site():
+    """Build an AssetSite from routes; shared by the tests that need a small local site."""
+    return AssetSite
+
+
+@pytest.fixture
+def diff_base_result() -> AuditResult:
+    """Earlier audit of example.com for diff tests."""
+    return AuditResult.load(Path(__file__).parent / "fixtures" / "diff" / "base.json")
+
+
+@pytest.fixture
+def diff_head_result() -> AuditResult:
+    """Later audit of example.com: one renamed duplicate title, one new and one fixed issue."""
+    return AuditResult.load(Path(__file__).parent / "fixtures" / "diff" / "head.json")
+
+
 @pytest.fixture
 async def mock_httpx_client() -> AsyncGenerator[AsyncMock, None]:
     """Provide a mocked httpx AsyncClient."""
@@ -0,0 +1,97 @@
+"""
+Integration test for asset caching checks against a local site.
+"""
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.caching import (  # noqa: E402
+    CachingOptions,
+    Resource,
+    analyze,
+    collect,
+)
+
+PAGE = """<!DOCTYPE html>
+<html><head>
+  <link rel="stylesheet" href="/assets/index-BxT9kq2L.css">
+  <link rel="stylesheet" href="/css/legacy.css">
+  <script src="/assets/app.3f9a1c2b.js" defer></script>
+  <script src="/assets/vendor.9c8b7a6f.js" defer></script>
+  <script src="https://cdn.example.net/widget.js"></script>
+</head><body><img src="/img/hero.1a2b3c4d.webp"><img src="/img/missing.5e6f7a8b.png"></body></html>"""
+
+ROUTES = {
+    "/": ("text/html", PAGE.encode(), {"gzip"}, {"Cache-Control": "no-store"}),
+    "/assets/index-BxT9kq2L.css": (
+        "text/css",
+        b"body{}",
+        {"gzip"},
+        {"Cache-Control": "public, max-age=31536000, immutable"},
+    ),
+    "/css/legacy.css": ("text/css", b"p{}", set()),
+    "/assets/app.3f9a1c2b.js": ("text/javascript", b"app()", set(), {"cache-control": "Max-Age=300"}),
+    "/assets/vendor.9c8b7a6f.js": ("text/javascript", b"lib()", set()),
+    "/img/hero.1a2b3c4d.webp": (
+        "image/webp",
+        b"RIFF",
+        set(),
+        {"Cache-Control": "max-age=31536000", "Expires": "0"},
+    ),
+}
+
+
+@pytest.mark.integration
+@pytest.mark.asyncio
+async def test_site_caching(asset_site):
+    """Test HEAD requests to a page's assets and the findings for their headers."""
+    # Arrange
+    site = asset_site(ROUTES)
+    async with site.client() as client:
+        page = await client.get("https://example.com/")
+        page_resource = Resource.from_headers(str(page.url), page.headers, kind="document")
+
+        # Act
+        assets = await collect(PAGE, "https://example.com/", client)
+    issues = {i.type: i for i in analyze([page_resource, *assets])}
+
+    # Assert
+    assert [m for m, _, _ in site.requests[1:]] == ["HEAD"] * 6  # the CDN script is not requested
+    assert [a.url.rsplit("/", 1)[1] for a in assets] == [
+        "index-BxT9kq2L.css",
+        "app.3f9a1c2b.js",
+        "vendor.9c8b7a6f.js",
+        "hero.1a2b3c4d.webp",
+        "legacy.css",
+    ]  # fingerprinted first; the 404 image is dropped
+    assert sorted(issues) == [
+        "asset_cache_headers_missing",
+        "asset_cache_short",
+        "cache_headers_conflict",
+        "cache_immutable_missing",
+        "cache_no_store",
+    ]
+    assert issues["asset_cache_headers_missing"].detail.endswith("/assets/vendor.9c8b7a6f.js")
+    assert issues["asset_cache_short"].detail.endswith("/assets/app.3f9a1c2b.js (max-age=300)")
+    assert issues["cache_immutable_missing"].url == "https://example.com/img/hero.1a2b3c4d.webp"
+    assert "(Cache-Control allows 31536000s but Expires has already passed)" in (
+        issues["cache_headers_conflict"].detail
+    )
+    assert issues["cache_no_store"].url == "https://example.com/"
+
+
+@pytest.mark.integration
+@pytest.mark.asyncio
+async def test_asset_limit(asset_site):
+    """Test collect requests no more than max_assets files."""
+    # Arrange
+    site = asset_site(ROUTES)
+
+    # Act
+    async with site.client() as client:
+        assets = await collect(PAGE, "https://example.com/", client, CachingOptions(max_assets=2))
+
+    # Assert
+    assert len(site.requests) == 2
+    assert [a.url.rsplit("/", 1)[1] for a in assets] == ["index-BxT9kq2L.css", "app.3f9a1c2b.js"]
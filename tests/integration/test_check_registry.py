@@ -0,0 +1,70 @@
+"""
+Integration test for custom checks in the comprehensive audit.
+"""
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.builtin_checks import default_registry  # noqa: E402
+from tinyseoai.audit.engine_v2 import comprehensive_audit  # noqa: E402
+from tinyseoai.audit.registry import CheckContext, FunctionCheck, Requirements  # noqa: E402
+from tinyseoai.data.models import Issue  # noqa: E402
+
+PAGE = b"""<!DOCTYPE html>
+<html lang="en"><head><title>Mugs</title><meta name="description" content="Handmade mugs"></head>
+<body><h1>Mugs</h1><a href="/about">About</a></body></html>"""
+
+ABOUT = b"""<!DOCTYPE html>
+<html lang="en"><head><title>About</title></head><body><h1>About us</h1></body></html>"""
+
+
+def _analytics_missing(ctx: CheckContext) -> list[Issue]:
+    """A site-specific rule: every page should load our analytics snippet."""
+    return [
+        Issue(url=p.url, type="analytics_missing", severity="low", detail="No analytics snippet")
+        for p in ctx.pages
+        if p.html and "gtag(" not in p.html
+    ]
+
+
+@pytest.mark.integration
+@pytest.mark.asyncio
+async def test_custom_check_merges_into_result(asset_site):
+    """Test a registered check runs after the crawl and its findings and diagnostics reach the result."""
+    # Arrange
+    server = asset_site(
+        {"/": ("text/html", PAGE, set()), "/about": ("text/html", ABOUT, set())}
+    )
+    registry = default_registry()
+    registry.register(FunctionCheck("analytics", _analytics_missing, Requirements(crawl=True)))
+    registry.disable("duplicate_meta")
+
+    # Act
+    async with server.client() as client:
+        result = await comprehensive_audit(
+            "https://example.com/",
+            enable_all_checks=False,
+            show_progress=False,
+            registry=registry,
+            client=client,
+        )
+
+    # Assert
+    assert sorted(i.url for i in result.issues if i.type == "analytics_missing") == [
+        "https://example.com/",
+        "https://example.com/about",
+    ]
+    runs = {run["id"]: run for run in result.meta["diagnostics"]["checks"]}
+    assert runs["analytics"]["status"] == "ran"
+    assert runs["analytics"]["issues"] == 2
+    assert runs["duplicate_meta"]["status"] == "disabled"
+    assert runs["compression"] == {
+        "id": "compression",
+        "status": "skipped",
+        "reason": "comprehensive checks are off (--fast)",
+        "issues": 0,
+        "duration_ms": 0.0,
+        "requests": 0,
+        "bytes_fetched": 0,
+    }
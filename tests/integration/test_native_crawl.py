@@ -0,0 +1,393 @@
+"""
+Integration tests for the native crawler against a scripted multi-page site.
+"""
+import asyncio
+import time
+from datetime import datetime, timezone
+
+import httpx
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.crawler import (  # noqa: E402
+    Crawl,
+    Finished,
+    PageFailed,
+    PageFetched,
+    PageSkipped,
+    PolitenessOptions,
+    retry_after_seconds,
+)
+from tinyseoai.audit.scope import CrawlOptions  # noqa: E402
+from tinyseoai.progress import Phase, ProgressEmitter  # noqa: E402
+
+SEED = "https://example.com/"
+# No pacing and no robots.txt request, so tests of limits see only page requests
+FAST = PolitenessOptions(per_host_rps=1000, respect_robots=False)
+
+# path -> links on the page (depth in comments)
+SITE = {
+    "/": ["/a", "/b", "/c", "/report.pdf", "https://other.example/", "mailto:hi@example.com"],  # 0
+    "/a": ["/a1", "/a2", "/"],  # 1
+    "/b": ["/b1", "/a"],  # 1
+    "/c": ["/missing"],  # 1
+    "/a1": ["/deep"],  # 2
+    "/a2": [],  # 2
+    "/b1": [],  # 2
+    "/deep": [],  # 3
+}
+
+
+class _Site:
+    """Serves SITE, counting requests and the peak number in flight."""
+
+    def __init__(self, delay: float = 0.0, broken: frozenset[str] = frozenset()):
+        self.delay = delay
+        self.broken = broken
+        self.requested: list[str] = []
+        self.in_flight = 0
+        self.peak = 0
+
+    async def __call__(self, request: httpx.Request) -> httpx.Response:
+        path = request.url.path
+        self.requested.append(path)
+        self.in_flight += 1
+        self.peak = max(self.peak, self.in_flight)
+        try:
+            await asyncio.sleep(self.delay)
+        finally:
+            self.in_flight -= 1
+        if path in self.broken:
+            raise httpx.ConnectError("connection refused")
+        if path == "/report.pdf":
+            return httpx.Response(200, headers={"content-type": "application/pdf"}, content=b"%PDF-1.7")
+        if path not in SITE:
+            return httpx.Response(404, headers={"content-type": "text/html"}, text="<h1>Not found</h1>")
+        links = "".join(f'<a href="{href}">link</a>' for href in SITE[path])
+        return httpx.Response(200, headers={"content-type": "text/html"}, text=f"<html><body>{links}</body></html>")
+
+    def client(self) -> httpx.AsyncClient:
+        return httpx.AsyncClient(transport=httpx.MockTransport(self))
+
+
+async def _events(crawl: Crawl) -> list:
+    return [event async for event in crawl.stream()]
+
+
+@pytest.mark.integration
+class TestCrawl:
+    """Test traversal, limits, and events."""
+
+    @pytest.mark.asyncio
+    async def test_full_crawl(self):
+        """Test every same-site page is fetched once, breadth first, with its depth."""
+        # Arrange
+        site = _Site()
+        crawl = Crawl(SEED, site.client(), CrawlOptions(max_pages=100), concurrency=1, politeness=FAST)
+
+        # Act
+        events = await _events(crawl)
+
+        # Assert
+        assert sorted(site.requested) == sorted([*SITE, "/report.pdf", "/missing"])
+        by_depth: dict[int, list[str]] = {}
+        for page in crawl.pages:
+            by_depth.setdefault(page.depth, []).append(page.url.removeprefix("https://example.com"))
+        assert {depth: sorted(paths) for depth, paths in by_depth.items()} == {
+            0: ["/"],
+            1: ["/a", "/b", "/c", "/report.pdf"],
+            2: ["/a1", "/a2", "/b1", "/missing"],
+            3: ["/deep"],
+        }
+        assert [p.depth for p in crawl.pages] == sorted(p.depth for p in crawl.pages)
+        pdf = next(p for p in crawl.pages if p.url.endswith(".pdf"))
+        assert pdf.body == "" and pdf.content_type == "application/pdf"
+        assert sum(isinstance(e, PageFetched) for e in events) == 10
+        assert (events[-1].pages, events[-1].failed, events[-1].reason) == (10, 0, "complete")
+
+    @pytest.mark.asyncio
+    @pytest.mark.parametrize("max_pages", [1, 3, 7])
+    async def test_max_pages_exact(self, max_pages):
+        """Test exactly max_pages requests are made, even with several workers."""
+        # Arrange
+        site = _Site(delay=0.01)
+        crawl = Crawl(SEED, site.client(), CrawlOptions(max_pages=max_pages), concurrency=4, politeness=FAST)
+
+        # Act
+        events = await _events(crawl)
+
+        # Assert
+        assert len(site.requested) == len(crawl.pages) == max_pages
+        assert events[-1].reason == "max_pages"
+
+    @pytest.mark.asyncio
+    async def test_max_depth(self):
+        """Test pages deeper than max_depth are never requested."""
+        # Arrange
+        site = _Site()
+
+        # Act
+        await Crawl(SEED, site.client(), CrawlOptions(max_depth=1), politeness=FAST).run()
+
+        # Assert
+        assert sorted(site.requested) == ["/", "/a", "/b", "/c", "/report.pdf"]
+
+    @pytest.mark.asyncio
+    async def test_concurrency_bound(self):
+        """Test no more than `concurrency` requests are in flight."""
+        # Arrange
+        site = _Site(delay=0.02)
+
+        # Act
+        await Crawl(SEED, site.client(), concurrency=2, politeness=FAST).run()
+
+        # Assert
+        assert site.peak == 2
+
+    @pytest.mark.asyncio
+    async def test_extra_urls(self):
+        """Test extra start URLs are fetched at depth 1 when in scope, and only once."""
+        # Arrange
+        site = _Site()
+        extra = ["https://example.com/a2", "https://example.com/b", "https://other.example/x"]
+        crawl = Crawl(SEED, site.client(), CrawlOptions(max_depth=1), politeness=FAST, extra_urls=extra)
+
+        # Act
+        await crawl.run()
+
+        # Assert
+        depths = {page.url.removeprefix("https://example.com"): page.depth for page in crawl.pages}
+        assert depths["/a2"] == 1
+        assert sorted(site.requested) == ["/", "/a", "/a2", "/b", "/c", "/report.pdf"]
+
+    @pytest.mark.asyncio
+    async def test_max_duration(self):
+        """Test no request starts after max_duration."""
+        # Arrange
+        site = _Site(delay=0.05)
+        crawl = Crawl(SEED, site.client(), concurrency=1, max_duration=0.12, politeness=FAST)
+
+        # Act
+        events = await _events(crawl)
+
+        # Assert
+        assert 2 <= len(site.requested) <= 4
+        assert events[-1].reason == "max_duration"
+
+    @pytest.mark.asyncio
+    async def test_failures_reported(self):
+        """Test transport errors become PageFailed events and count toward max_pages."""
+        # Arrange
+        site = _Site(broken=frozenset({"/b"}))
+        crawl = Crawl(SEED, site.client(), concurrency=1, politeness=FAST)
+
+        # Act
+        events = await _events(crawl)
+
+        # Assert
+        failed = [e for e in events if isinstance(e, PageFailed)]
+        assert [(e.url, e.depth, e.error) for e in failed] == [("https://example.com/b", 1, "ConnectError")]
+        assert "/b1" not in site.requested
+        assert events[-1].failed == 1
+
+    @pytest.mark.asyncio
+    async def test_progress_events(self):
+        """Test every crawl event is mirrored as a crawling progress event counting fetches."""
+        # Arrange
+        site = _Site(broken=frozenset({"/b"}))
+        progress = []
+        crawl = Crawl(
+            SEED,
+            site.client(),
+            CrawlOptions(max_pages=4),
+            concurrency=1,
+            politeness=FAST,
+            progress=ProgressEmitter(progress.append, job_id="crawl-1"),
+        )
+
+        # Act
+        events = await _events(crawl)
+
+        # Assert
+        assert len(progress) == len(events)
+        assert {(e.phase, e.total, e.job_id) for e in progress} == {(Phase.CRAWLING, 4, "crawl-1")}
+        assert [e.done for e in progress] == [1, 2, 3, 4, 4]
+        assert progress[2].message == "https://example.com/b failed: ConnectError"
+        assert progress[-1].message.startswith("Crawl finished (max_pages)")
+
+    @pytest.mark.asyncio
+    async def test_cancel(self):
+        """Test cancelling stops new requests and still ends with Finished."""
+        # Arrange
+        site = _Site(delay=0.01)
+        crawl = Crawl(SEED, site.client(), concurrency=1, politeness=FAST)
+        events = []
+
+        # Act
+        async for event in crawl.stream():
+            events.append(event)
+            if len(events) == 2:
+                crawl.cancel()
+
+        # Assert
+        assert isinstance(events[-1], Finished) and events[-1].reason == "cancelled"
+        assert len(site.requested) <= 3
+
+
+ROBOTS = """User-agent: *
+Disallow: /
+
+User-agent: tinyseoai
+Disallow: /private
+Crawl-delay: 30
+"""
+
+# host -> path -> links
+HOSTS = {
+    "example.com": {
+        "/": ["/a", "/b", "/private/x", "https://blog.example.com/"],
+        "/a": [],
+        "/b": [],
+        "/private/x": [],
+    },
+    "blog.example.com": {"/": ["/post1", "/post2"], "/post1": [], "/post2": []},
+}
+
+
+class _PoliteSite:
+    """Two hosts; example.com has robots.txt. Records when each request arrived."""
+
+    def __init__(self, busy: dict[str, list[httpx.Response]] | None = None):
+        self.busy = busy or {}  # path -> responses to serve before the real page
+        self.requests: list[tuple[str, str, float]] = []
+
+    async def __call__(self, request: httpx.Request) -> httpx.Response:
+        host, path = request.url.host, request.url.path
+        self.requests.append((host, path, time.monotonic()))
+        if path == "/robots.txt":
+            if host == "example.com":
+                return httpx.Response(200, headers={"content-type": "text/plain"}, text=ROBOTS)
+            return httpx.Response(404)
+        if self.busy.get(path):
+            return self.busy[path].pop(0)
+        links = "".join(f'<a href="{href}">link</a>' for href in HOSTS[host].get(path, []))
+        return httpx.Response(200, headers={"content-type": "text/html"}, text=f"<html><body>{links}</body></html>")
+
+    def client(self) -> httpx.AsyncClient:
+        return httpx.AsyncClient(transport=httpx.MockTransport(self))
+
+    def paths(self, host: str = "example.com") -> list[str]:
+        return [p for h, p, _ in self.requests if h == host and p != "/robots.txt"]
+
+    def gaps(self, host: str) -> list[float]:
+        times = [t for h, p, t in self.requests if h == host and p != "/robots.txt"]
+        return [b - a for a, b in zip(times, times[1:], strict=False)]
+
+
+SCOPE = CrawlOptions(follow_subdomains=True)
+
+
+@pytest.mark.integration
+class TestPoliteness:
+    """Test robots.txt compliance, per-host pacing, and Retry-After handling."""
+
+    @pytest.mark.asyncio
+    async def test_disallowed_never_fetched(self):
+        """Test Disallow rules for our user agent apply and Crawl-delay is capped."""
+        # Arrange
+        site = _PoliteSite()
+        politeness = PolitenessOptions(per_host_rps=1000, max_crawl_delay=0.05)
+        crawl = Crawl(SEED, site.client(), SCOPE, concurrency=4, politeness=politeness)
+
+        # Act
+        events = await _events(crawl)
+
+        # Assert
+        assert "/private/x" not in site.paths()
+        assert [(e.url, e.reason) for e in events if isinstance(e, PageSkipped)] == [
+            ("https://example.com/private/x", "disallowed by robots.txt")
+        ]
+        assert sorted(site.paths("blog.example.com")) == ["/", "/post1", "/post2"]
+        assert all(gap >= 0.045 for gap in site.gaps("example.com"))
+        assert [p for h, p, _ in site.requests if p == "/robots.txt"] == ["/robots.txt"] * 2
+
+    @pytest.mark.asyncio
+    async def test_other_user_agent(self):
+        """Test the configured user agent picks the robots.txt group."""
+        # Arrange
+        site = _PoliteSite()
+        politeness = PolitenessOptions(per_host_rps=1000, user_agent="OtherBot")
+
+        # Act
+        pages = await Crawl(SEED, site.client(), SCOPE, politeness=politeness).run()
+
+        # Assert
+        assert pages == []
+        assert site.paths() == []
+
+    @pytest.mark.asyncio
+    async def test_robots_opt_out(self):
+        """Test respect_robots=False fetches disallowed paths and skips robots.txt."""
+        # Arrange
+        site = _PoliteSite()
+
+        # Act
+        await Crawl(SEED, site.client(), politeness=FAST).run()
+
+        # Assert
+        assert "/private/x" in site.paths()
+        assert all(p != "/robots.txt" for _, p, _ in site.requests)
+
+    @pytest.mark.asyncio
+    async def test_per_host_spacing(self):
+        """Test requests to one host are spaced while hosts are paced independently."""
+        # Arrange
+        site = _PoliteSite()
+        politeness = PolitenessOptions(delay=0.05, respect_robots=False)
+
+        # Act
+        await Crawl(SEED, site.client(), SCOPE, concurrency=4, politeness=politeness).run()
+
+        # Assert
+        for host in HOSTS:
+            assert all(gap >= 0.045 for gap in site.gaps(host)), host
+        first_blog = next(t for h, _, t in site.requests if h == "blog.example.com")
+        last_main = max(t for h, _, t in site.requests if h == "example.com")
+        assert first_blog < last_main  # the hosts overlap rather than taking turns
+
+    @pytest.mark.asyncio
+    async def test_retry_after(self):
+        """Test 429 waits for Retry-After before retrying, and persistent 503s give up."""
+        # Arrange
+        site = _PoliteSite(
+            busy={
+                "/a": [httpx.Response(429, headers={"retry-after": "0.1"})],
+                "/b": [httpx.Response(503) for _ in range(5)],
+            }
+        )
+        politeness = PolitenessOptions(per_host_rps=1000, respect_robots=False, max_retries=2, max_retry_after=0.05)
+
+        # Act
+        pages = {p.url: p for p in await Crawl(SEED, site.client(), politeness=politeness).run()}
+
+        # Assert
+        assert pages["https://example.com/a"].status == 200
+        assert pages["https://example.com/b"].status == 503
+        assert site.paths().count("/a") == 2
+        assert site.paths().count("/b") == 3
+        times = [t for _, p, t in site.requests if p == "/a"]
+        assert times[1] - times[0] >= 0.045  # Retry-After capped at max_retry_after
+
+    @pytest.mark.parametrize(
+        ("value", "seconds"),
+        [("120", 120.0), ("Wed, 21 Oct 2015 07:28:30 GMT", 30.0), ("soon", None), (None, None)],
+    )
+    def test_retry_after_seconds(self, value, seconds):
+        """Test delta-seconds and HTTP-date Retry-After values."""
+        # Arrange
+        now = datetime(2015, 10, 21, 7, 28, 0, tzinfo=timezone.utc)
+
+        # Act / Assert
+        assert retry_after_seconds(value, now) == seconds
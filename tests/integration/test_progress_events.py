@@ -0,0 +1,54 @@
+"""
+Integration test for progress events from the comprehensive audit.
+"""
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.engine_v2 import comprehensive_audit  # noqa: E402
+from tinyseoai.progress import Phase  # noqa: E402
+
+PAGE = b"""<!DOCTYPE html>
+<html lang="en"><head><title>Mugs</title><meta name="description" content="Handmade mugs"></head>
+<body><h1>Mugs</h1><a href="/about">About</a></body></html>"""
+
+ABOUT = b"""<!DOCTYPE html>
+<html lang="en"><head><title>About</title></head><body><h1>About us</h1></body></html>"""
+
+
+@pytest.mark.integration
+@pytest.mark.asyncio
+async def test_phases_in_order(asset_site):
+    """Test an audit reports every phase in order, each event stamped with the job id."""
+    # Arrange
+    server = asset_site(
+        {"/": ("text/html", PAGE, set()), "/about": ("text/html", ABOUT, set())}
+    )
+    events = []
+
+    # Act
+    async with server.client() as client:
+        result = await comprehensive_audit(
+            "https://example.com/",
+            max_pages=5,
+            enable_all_checks=False,
+            show_progress=False,
+            client=client,
+            on_progress=events.append,
+            job_id="job-42",
+        )
+
+    # Assert
+    phases = [e.phase for e in events]
+    order = list(Phase)
+    assert phases == sorted(phases, key=order.index)
+    assert set(phases) == set(Phase)
+    assert {e.job_id for e in events} == {"job-42"}
+    crawled = [e for e in events if e.phase == Phase.CRAWLING and e.message.startswith("https://")]
+    assert [e.message for e in crawled] == ["https://example.com/", "https://example.com/about"]
+    assert [e.done for e in crawled] == [1, 2]
+    checks = [e for e in events if e.check_id]
+    assert checks and [e.done for e in checks] == list(range(1, len(checks) + 1))
+    assert {e.total for e in checks} == {len(checks)}
+    assert (events[-1].phase, events[-1].done) == (Phase.DONE, result.pages_scanned)
@@ -0,0 +1,142 @@
+"""
+Integration test: the audit's user agent and credentials reach the site on every request.
+"""
+import httpx
+import pytest
+from pydantic import SecretStr
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit import engine  # noqa: E402
+from tinyseoai.audit.engine_v2 import comprehensive_audit  # noqa: E402
+from tinyseoai.utils.http import HttpOptions, client, default_user_agent  # noqa: E402
+
+PAGES = {
+    "/": '<html lang="en"><head><title>Home</title></head><body><a href="/about">About</a></body></html>',
+    "/about": '<html lang="en"><head><title>About</title></head><body><h1>About</h1></body></html>',
+}
+
+
+class _Site:
+    """Serves PAGES (404 elsewhere) and records each request's path and headers."""
+
+    def __init__(self, robots: str | None = None):
+        self.robots = robots
+        self.requests: list[tuple[str, httpx.Headers]] = []
+
+    def __call__(self, request: httpx.Request) -> httpx.Response:
+        self.requests.append((request.url.path, request.headers))
+        if request.url.path == "/robots.txt" and self.robots is not None:
+            return httpx.Response(200, headers={"content-type": "text/plain"}, text=self.robots)
+        if request.url.path not in PAGES:
+            return httpx.Response(404, text="Not found")
+        return httpx.Response(200, headers={"content-type": "text/html"}, text=PAGES[request.url.path])
+
+
+@pytest.mark.integration
+@pytest.mark.asyncio
+async def test_basic_audit_identifies_itself(monkeypatch):
+    """Test robots.txt, sitemap, and page requests of audit_site all carry the user agent."""
+    # Arrange
+    site = _Site()
+    monkeypatch.setattr(
+        engine,
+        "http_client",
+        lambda http, **kwargs: client(http, transport=httpx.MockTransport(site), **kwargs),
+    )
+
+    # Act
+    result = await engine.audit_site("https://example.com/", max_pages=2)
+
+    # Assert
+    paths = {path for path, _ in site.requests}
+    assert {"/robots.txt", "/", "/about"} <= paths
+    assert {headers["user-agent"] for _, headers in site.requests} == {default_user_agent()}
+    assert result.meta["user_agent"] == default_user_agent()
+    assert result.meta["tls_verification"] is True
+    assert not any(i.type == "tls_verification_disabled" for i in result.issues)
+
+
+@pytest.mark.integration
+@pytest.mark.asyncio
+async def test_comprehensive_audit_custom_identity():
+    """Test a configured identity is sent, and an insecure audit is marked in the result."""
+    # Arrange
+    site = _Site()
+    http = HttpOptions(user_agent="AcmeSEO/2.0", contact="seo@acme.example", verify_tls=False)
+
+    # Act
+    async with client(http, transport=httpx.MockTransport(site)) as mock_client:
+        result = await comprehensive_audit(
+            "https://example.com/",
+            max_pages=2,
+            enable_all_checks=False,
+            show_progress=False,
+            client=mock_client,
+            http=http,
+        )
+
+    # Assert
+    assert site.requests
+    assert all(h["user-agent"] == "AcmeSEO/2.0" for _, h in site.requests)
+    assert all(h["from"] == "seo@acme.example" for _, h in site.requests)
+    assert result.meta["tls_verification"] is False
+    assert [i.type for i in result.issues if i.type == "tls_verification_disabled"] == [
+        "tls_verification_disabled"
+    ]
+
+
+@pytest.mark.integration
+@pytest.mark.asyncio
+async def test_credentials_reach_site_not_report():
+    """Test a staging audit sends credentials on every site request and never reports them."""
+    # Arrange
+    site = _Site()
+    http = HttpOptions(
+        basic_auth=("preview", SecretStr("hunter2")), cookie=SecretStr("session=abc123")
+    ).for_site("https://staging.example.com/")
+
+    # Act
+    async with client(http, transport=httpx.MockTransport(site)) as mock_client:
+        result = await comprehensive_audit(
+            "https://staging.example.com/",
+            max_pages=2,
+            enable_all_checks=False,
+            show_progress=False,
+            client=mock_client,
+            http=http,
+        )
+
+    # Assert
+    assert {"/robots.txt", "/", "/about"} <= {path for path, _ in site.requests}
+    assert all(h["authorization"] == "Basic cHJldmlldzpodW50ZXIy" for _, h in site.requests)
+    assert all(h["cookie"] == "session=abc123" for _, h in site.requests)
+    assert result.meta["auth_host"] == "staging.example.com"
+    dumped = result.model_dump_json()
+    assert "hunter2" not in dumped
+    assert "abc123" not in dumped
+
+
+@pytest.mark.integration
+@pytest.mark.asyncio
+async def test_robots_rules_for_the_user_agent():
+    """Test the crawl obeys the robots.txt group naming the audit's own User-Agent."""
+    # Arrange
+    site = _Site(robots="User-agent: *\nAllow: /\n\nUser-agent: AcmeSEO\nDisallow: /about\n")
+    http = HttpOptions(user_agent="AcmeSEO/2.0")
+
+    # Act
+    async with client(http, transport=httpx.MockTransport(site)) as mock_client:
+        result = await comprehensive_audit(
+            "https://example.com/",
+            max_pages=5,
+            enable_all_checks=False,
+            show_progress=False,
+            client=mock_client,
+            http=http,
+        )
+
+    # Assert
+    assert "/about" not in {path for path, _ in site.requests}
+    assert result.pages_scanned == 1
@@ -0,0 +1,190 @@
+"""
+Unit tests for the AI providers of executive summaries.
+"""
+import json
+
+import httpx
+import pytest
+
+from tinyseoai.ai.providers import ProviderSettings, get_provider
+from tinyseoai.exceptions import AIError
+from tinyseoai.utils.http import RetryPolicy
+
+ANSWER = {"site": "https://example.com", "summary": "Fix the titles first."}
+
+OPENAI = {
+    "choices": [{"message": {"role": "assistant", "content": json.dumps(ANSWER)}}],
+    "usage": {"prompt_tokens": 120, "completion_tokens": 30, "total_tokens": 150},
+}
+ANTHROPIC = {
+    "type": "message",
+    "content": [{"type": "text", "text": "```json\n" + json.dumps(ANSWER) + "\n```"}],
+    "usage": {"input_tokens": 110, "output_tokens": 25},
+}
+OLLAMA = {
+    "message": {"role": "assistant", "content": json.dumps(ANSWER)},
+    "done": True,
+    "prompt_eval_count": 90,
+    "eval_count": 20,
+}
+RATE_LIMITED = {"error": {"type": "rate_limit_error", "message": "Too many requests"}}
+
+
+class _FakeServer:
+    """Answers provider requests with canned responses, in order."""
+
+    def __init__(self, *responses: tuple[int, dict], headers: dict | None = None):
+        self.responses = list(responses)
+        self.headers = headers or {}
+        self.requests: list[httpx.Request] = []
+
+    def __call__(self, request: httpx.Request) -> httpx.Response:
+        self.requests.append(request)
+        status, body = self.responses.pop(0)
+        return httpx.Response(status, json=body, headers=self.headers if status == 429 else None)
+
+    def ask(self, settings: ProviderSettings, api_key: str = "k3y"):
+        model = "gpt-4o-mini" if settings.provider == "openai" else None  # the plan's model
+        provider = get_provider(settings, model, api_key, transport=httpx.MockTransport(self))
+        return provider.complete_json("INPUT: {}", system="Be brief.", max_output_tokens=500)
+
+    def body(self, n: int = 0) -> dict:
+        return json.loads(self.requests[n].content)
+
+
+def _no_wait() -> RetryPolicy:
+    return RetryPolicy(attempts=3, backoff=0, jitter=0, statuses=[429, 503])
+
+
+@pytest.mark.unit
+class TestWireFormats:
+    """Test each provider speaks its endpoint's format and reports usage alike."""
+
+    def test_openai_compatible(self):
+        """Test chat completions with a bearer key, JSON mode, and the plan's model."""
+        # Arrange
+        server = _FakeServer((200, OPENAI))
+
+        # Act
+        answer, usage = server.ask(ProviderSettings(base_url="http://gateway.local/v1/"))
+
+        # Assert
+        request = server.requests[0]
+        assert request.url == "http://gateway.local/v1/chat/completions"
+        assert request.headers["authorization"] == "Bearer k3y"
+        body = server.body()
+        assert body["model"] == "gpt-4o-mini"
+        assert [m["role"] for m in body["messages"]] == ["system", "user"]
+        assert body["response_format"] == {"type": "json_object"}
+        assert answer == ANSWER
+        assert usage == {
+            "provider": "openai",
+            "model": "gpt-4o-mini",
+            "prompt_tokens": 120,
+            "completion_tokens": 30,
+            "total_tokens": 150,
+        }
+
+    def test_anthropic_messages(self):
+        """Test the Messages API: key header, top-level system, and a fenced JSON answer."""
+        # Arrange
+        server = _FakeServer((200, ANTHROPIC))
+
+        # Act
+        answer, usage = server.ask(ProviderSettings(provider="anthropic"))
+
+        # Assert
+        request = server.requests[0]
+        assert request.url == "https://api.anthropic.com/v1/messages"
+        assert request.headers["x-api-key"] == "k3y"
+        assert request.headers["anthropic-version"] == "2023-06-01"
+        body = server.body()
+        assert body["model"] == "claude-sonnet-4-5"
+        assert body["system"] == "Be brief."
+        assert body["max_tokens"] == 500
+        assert answer == ANSWER
+        assert (usage["provider"], usage["total_tokens"]) == ("anthropic", 135)
+
+    def test_ollama_local(self):
+        """Test the local chat endpoint needs no key and asks for JSON without streaming."""
+        # Arrange
+        server = _FakeServer((200, OLLAMA))
+
+        # Act
+        answer, usage = server.ask(ProviderSettings(provider="ollama", model="qwen2.5"), api_key="")
+
+        # Assert
+        request = server.requests[0]
+        assert request.url == "http://localhost:11434/api/chat"
+        assert "authorization" not in request.headers
+        body = server.body()
+        assert (body["model"], body["format"], body["stream"]) == ("qwen2.5", "json", False)
+        assert body["options"]["num_predict"] == 500
+        assert answer == ANSWER
+        assert (usage["prompt_tokens"], usage["completion_tokens"]) == (90, 20)
+
+
+@pytest.mark.unit
+class TestFailures:
+    """Test rate limits are retried and other failures raise AIError."""
+
+    def test_retries_rate_limit(self):
+        """Test a 429 is retried after its Retry-After, then the answer is used."""
+        # Arrange
+        server = _FakeServer((429, RATE_LIMITED), (200, OPENAI), headers={"Retry-After": "0"})
+
+        # Act
+        answer, _ = server.ask(ProviderSettings(retry=_no_wait()))
+
+        # Assert
+        assert answer == ANSWER
+        assert len(server.requests) == 2
+
+    def test_gives_up_after_attempts(self):
+        """Test a lasting rate limit raises AIError with the endpoint's message."""
+        # Arrange
+        server = _FakeServer(*[(429, RATE_LIMITED)] * 3)
+
+        # Act / Assert
+        with pytest.raises(AIError, match="HTTP 429: Too many requests"):
+            server.ask(ProviderSettings(provider="anthropic", retry=_no_wait()))
+        assert len(server.requests) == 3
+
+    def test_client_error_not_retried(self):
+        """Test a rejected key fails on the first try."""
+        # Arrange
+        server = _FakeServer((401, {"error": {"message": "Incorrect API key"}}), (200, OPENAI))
+
+        # Act / Assert
+        with pytest.raises(AIError, match="Incorrect API key"):
+            server.ask(ProviderSettings(retry=_no_wait()))
+        assert len(server.requests) == 1
+
+    def test_timeout(self):
+        """Test a timeout raises AIError naming the limit."""
+        # Arrange
+        def slow(request: httpx.Request) -> httpx.Response:
+            raise httpx.ReadTimeout("timed out")
+
+        provider = get_provider(
+            ProviderSettings(provider="ollama", timeout=5), transport=httpx.MockTransport(slow)
+        )
+
+        # Act / Assert
+        with pytest.raises(AIError, match="timed out after 5s"):
+            provider.complete_json("INPUT: {}", system="Be brief.")
+
+    def test_answer_not_an_object(self):
+        """Test an answer that is not a JSON object is rejected."""
+        # Arrange
+        server = _FakeServer((200, {**OLLAMA, "message": {"content": "Sure! Here it is."}}))
+
+        # Act / Assert
+        with pytest.raises(AIError, match="valid JSON"):
+            server.ask(ProviderSettings(provider="ollama"))
+
+    @pytest.mark.parametrize("provider", ["openai", "anthropic"])
+    def test_missing_key(self, provider):
+        """Test hosted providers need a key, and the error says where to put one."""
+        with pytest.raises(AIError, match="keyring set tinyseoai"):
+            get_provider(ProviderSettings(provider=provider), "gpt-4o-mini", api_key="")
@@ -0,0 +1,110 @@
+"""
+Unit tests for the AMP structural check.
+"""
+import asyncio
+from pathlib import Path
+
+import httpx
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.amp import amphtml_link, analyze, parse_amp, validate  # noqa: E402
+from tinyseoai.audit.crawler import FetchedPage  # noqa: E402
+
+FIXTURES = Path(__file__).parents[1] / "fixtures" / "amp"
+ARTICLE = "https://news.example/celadon"
+AMP = "https://news.example/celadon/amp"
+
+
+def _read(name: str) -> str:
+    return (FIXTURES / name).read_text(encoding="utf-8")
+
+
+def _run(amp_page: str | None, status: int = 200) -> list:
+    """Analyze the article with its AMP URL answering with a fixture (or failing)."""
+
+    def handler(request: httpx.Request) -> httpx.Response:
+        if amp_page is None:
+            raise httpx.ConnectError("refused", request=request)
+        return httpx.Response(status, headers={"content-type": "text/html"}, text=_read(amp_page))
+
+    client = httpx.AsyncClient(transport=httpx.MockTransport(handler))
+    pages = [FetchedPage(url=ARTICLE, body=_read("article.html"))]
+    return asyncio.run(analyze(pages, client))
+
+
+def _found(issues) -> list[tuple[str, str]]:
+    return sorted((i.url, i.type) for i in issues)
+
+
+@pytest.mark.unit
+class TestParse:
+    """Test reading amphtml links and AMP pages."""
+
+    def test_amphtml_link_resolved(self):
+        """Test the relative amphtml href resolves against the page."""
+        assert amphtml_link(ARTICLE, _read("article.html")) == AMP
+
+    def test_no_amphtml_link(self):
+        """Test pages without an AMP version have no link."""
+        assert amphtml_link(AMP, _read("valid.amp.html")) is None
+
+    def test_valid_page(self):
+        """Test the lightning attribute, runtime, extensions, and JSON-LD are accepted."""
+        # Act
+        page = parse_amp(AMP, _read("valid.amp.html"))
+
+        # Assert
+        assert page.amp_attribute
+        assert page.runtime
+        assert page.disallowed_scripts == []
+        assert validate(page, ARTICLE) == []
+
+
+@pytest.mark.unit
+class TestAnalyze:
+    """Test fetching AMP versions and reporting their defects."""
+
+    def test_valid_pair(self):
+        """Test a valid AMP page whose canonical points back (with tracking) passes."""
+        assert _run("valid.amp.html") == []
+
+    def test_missing_backlink(self):
+        """Test an AMP page without a canonical is reported on the original page."""
+        # Act
+        issues = _run("no_backlink.amp.html")
+
+        # Assert
+        assert _found(issues) == [(ARTICLE, "amp_canonical_missing")]
+        assert AMP in issues[0].detail
+
+    def test_rogue_script(self):
+        """Test custom scripts and a missing amp attribute are flagged as structural findings."""
+        # Act
+        issues = _run("rogue_script.amp.html")
+
+        # Assert
+        assert _found(issues) == [(AMP, "amp_attribute_missing"), (AMP, "amp_disallowed_script")]
+        script = next(i for i in issues if i.type == "amp_disallowed_script")
+        assert script.detail.startswith(
+            "AMP page has 2 scripts AMP does not allow: https://ads.example/tag.js, inline"
+        )
+        assert "not a full AMP validation" in script.detail
+
+    def test_canonical_mismatch(self):
+        """Test an AMP page naming another page as canonical is a mismatched pair."""
+        # Arrange
+        page = parse_amp(AMP, _read("valid.amp.html"))
+
+        # Act
+        issues = validate(page, "https://news.example/tenmoku")
+
+        # Assert
+        assert [i.type for i in issues] == ["amp_canonical_mismatch"]
+
+    @pytest.mark.parametrize("amp_page, status", [(None, 200), ("valid.amp.html", 404)])
+    def test_broken_amphtml(self, amp_page, status):
+        """Test an AMP link that fails or returns an error is reported on the original page."""
+        assert _found(_run(amp_page, status)) == [(ARTICLE, "amphtml_broken")]
@@ -0,0 +1,220 @@
+"""
+Unit tests for the anchor text check.
+"""
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.anchors import AnchorIndex, AnchorOptions, analyze  # noqa: E402
+from tinyseoai.audit.checks_config import ChecksConfig  # noqa: E402
+from tinyseoai.audit.crawler import FetchedPage  # noqa: E402
+
+HOME = "https://example.com/"
+
+
+def _page(path: str, body: str, status: int = 200) -> FetchedPage:
+    return FetchedPage(url=f"https://example.com{path}", status=status, body=body)
+
+
+def _links(*anchors: tuple[str, str]) -> str:
+    """A page body with one <a> per (href, inner HTML)."""
+    return "<html><body>" + "".join(f'<a href="{h}">{t}</a>' for h, t in anchors) + "</body></html>"
+
+
+def _found(issues, issue_type: str) -> dict[str, str]:
+    """Target path -> detail of the findings of one type."""
+    return {
+        i.url.removeprefix("https://example.com"): i.detail for i in issues if i.type == issue_type
+    }
+
+
+@pytest.mark.unit
+class TestReadMoreSite:
+    """Test a blog where every link says "read more"."""
+
+    def test_every_target_is_generic(self, html_fixture):
+        """Test each page is reported once, with the sources, however the phrase is written."""
+        # Arrange
+        post = html_fixture("anchors_read_more_post.html")
+        pages = [
+            _page("/", html_fixture("anchors_read_more.html")),
+            _page("/posts/a", post),
+            _page("/posts/b", post),
+            _page("/posts/c", post),
+        ]
+
+        # Act
+        issues = analyze(pages, HOME)
+
+        # Assert
+        generic = _found(issues, "generic_anchor_text")
+        assert sorted(generic) == ["/", "/posts/a", "/posts/b", "/posts/c"]
+        assert generic["/posts/a"].startswith('3 of 3 internal links to this page use generic')
+        assert '"read more"' in generic["/posts/a"]
+        assert "https://example.com/, https://example.com/posts/b" in generic["/posts/a"]
+        assert {i.severity for i in issues} == {"low"}
+        assert _found(issues, "inconsistent_anchor_text") == {}
+
+    def test_own_stop_phrases(self, html_fixture):
+        """Test generic_phrases replaces the built-in list."""
+        # Arrange
+        pages = [_page("/", html_fixture("anchors_read_more.html"))]
+
+        # Act
+        issues = analyze(pages, HOME, AnchorOptions(generic_phrases=["weiterlesen"]))
+
+        # Assert
+        assert _found(issues, "generic_anchor_text") == {}
+
+
+@pytest.mark.unit
+class TestFindings:
+    """Test each finding on small sites."""
+
+    def test_mostly_descriptive_is_fine(self):
+        """Test a few generic links among descriptive ones are not reported."""
+        # Arrange
+        pages = [
+            _page("/", _links(("/pricing", "Pricing"), ("/pricing", "click here"))),
+            _page("/a", _links(("/pricing", "Pricing plans"), ("/pricing", "Pricing"))),
+        ]
+
+        # Act
+        issues = analyze(pages, HOME)
+
+        # Assert
+        assert issues == []
+
+    def test_bare_url_is_generic(self):
+        """Test a link whose text is the URL itself counts as generic."""
+        # Act
+        issues = analyze([_page("/", _links(("/pricing", "https://example.com/pricing")))], HOME)
+
+        # Assert
+        assert list(_found(issues, "generic_anchor_text")) == ["/pricing"]
+
+    def test_inconsistent(self):
+        """Test a page linked with a different text every time."""
+        # Arrange
+        texts = ["Plans", "Our prices", "Costs", "Buy now", "Subscriptions", "Compare plans"]
+        pages = [_page(f"/p{n}", _links(("/pricing", text))) for n, text in enumerate(texts)]
+
+        # Act
+        issues = analyze(pages, HOME, AnchorOptions(max_examples=2))
+
+        # Assert
+        detail = _found(issues, "inconsistent_anchor_text")["/pricing"]
+        assert detail.startswith("6 internal links to this page use 6 different texts")
+        assert "https://example.com/p0, https://example.com/p1" in detail
+
+    def test_consistent(self):
+        """Test the same text on most links is not inconsistent."""
+        # Arrange
+        texts = ["Pricing", "pricing", "PRICING", "Our prices", "Costs", "Plans"]
+        pages = [_page(f"/p{n}", _links(("/pricing", text))) for n, text in enumerate(texts)]
+
+        # Act
+        issues = analyze(pages, HOME)
+
+        # Assert
+        assert _found(issues, "inconsistent_anchor_text") == {}
+
+    def test_empty_and_image_links(self):
+        """Test an image link counts with its alt; without one it is empty."""
+        # Arrange
+        body = _links(
+            ("/a", '<img src="logo.png" alt="About us">'),
+            ("/b", '<img src="logo.png">'),
+            ("/b", "   "),
+        )
+
+        # Act
+        issues = analyze([_page("/", body)], HOME)
+
+        # Assert
+        empty = _found(issues, "empty_anchor_text")
+        assert list(empty) == ["/b"]
+        assert empty["/b"].startswith("2 internal links to this page have no text")
+
+    def test_long(self):
+        """Test link text past max_anchor_length is reported, shortened."""
+        # Arrange
+        sentence = "Everything you need to know about choosing plans " * 3
+
+        # Act
+        issues = analyze([_page("/", _links(("/guide", sentence)))], HOME)
+
+        # Assert
+        detail = _found(issues, "long_anchor_text")["/guide"]
+        assert "longer than 100 characters" in detail
+        assert sentence.strip() not in detail
+
+    def test_only_internal_links_from_loaded_pages(self):
+        """Test external, mailto, fragment, and self links, and error pages, are left out."""
+        # Arrange
+        body = _links(
+            ("https://other.org/", "click here"),
+            ("mailto:me@example.com", "here"),
+            ("#top", "more"),
+            ("/", "read more"),
+        )
+        pages = [_page("/", body), _page("/gone", _links(("/x", "here")), status=404)]
+
+        # Act
+        issues = analyze(pages, HOME)
+
+        # Assert
+        assert issues == []
+
+
+@pytest.mark.unit
+class TestBounds:
+    """Test memory stays bounded on large crawls."""
+
+    def test_targets_and_texts_are_capped(self):
+        """Test links past max_targets are dropped and texts past max_texts not kept."""
+        # Arrange
+        index = AnchorIndex("example.com", AnchorOptions(max_targets=2, max_texts=3))
+
+        # Act
+        for n in range(5):
+            index.add(HOME, f"https://example.com/p{n}", "Page")
+        for n in range(10):
+            index.add(HOME, "https://example.com/p0", f"Text {n}")
+
+        # Assert
+        assert len(index.targets) == 2
+        assert index.dropped == 3
+        target = index.targets["https://example.com/p0"]
+        assert len(target.texts) == 3
+        assert target.links == 11
+
+    def test_normalized_text_and_target(self):
+        """Test texts are counted case- and whitespace-insensitively, per normalized URL."""
+        # Arrange
+        index = AnchorIndex("example.com")
+
+        # Act
+        index.add(HOME, "https://example.com/a#intro", "Pricing  plans")
+        index.add(HOME, "https://EXAMPLE.com/a", "pricing\nPLANS")
+
+        # Assert
+        (target,) = index.targets.values()
+        assert dict(target.texts) == {"pricing plans": 2}
+
+
+@pytest.mark.unit
+class TestSettings:
+    """Test the anchors table of checks.toml."""
+
+    def test_checks_config(self):
+        """Test the stop phrases and thresholds are read from the anchors table."""
+        # Act
+        config = ChecksConfig.from_toml(
+            '[anchors]\ngeneric_phrases = ["mehr"]\nmax_anchor_length = 60\n'
+        )
+
+        # Assert
+        assert config.anchors.generic_phrases == ["mehr"]
+        assert config.anchors.max_anchor_length == 60
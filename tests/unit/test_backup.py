@@ -0,0 +1,235 @@
+"""
+Unit tests for backups of the local data.
+"""
+import json
+import sqlite3
+import zipfile
+
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.backup import (  # noqa: E402
+    BACKUP_VERSION,
+    BackupPaths,
+    create_backup,
+    restore_backup,
+)
+from tinyseoai.config import AppConfig  # noqa: E402
+from tinyseoai.config_bundle import EXCLUDED_SECRETS  # noqa: E402
+from tinyseoai.data.baseline import Baseline, BaselineEntry  # noqa: E402
+from tinyseoai.data.comparison import compare_sites  # noqa: E402
+from tinyseoai.data.models import AuditResult  # noqa: E402
+from tinyseoai.exceptions import ConfigError  # noqa: E402
+from tinyseoai.store.history import SCHEMA_VERSION, HistoryStore  # noqa: E402
+
+THRESHOLDS = "[timing]\nttfb_warning_ms = 600\n"
+
+
+def _result(site: str, timestamp: str = "2025-01-01T00:00:00Z") -> AuditResult:
+    return AuditResult(
+        site=site,
+        pages_scanned=3,
+        issues=[{"url": site, "type": "title_missing", "severity": "high"}],
+        meta={"health_score": 80, "timestamp": timestamp},
+    )
+
+
+def _data_dir(root, plan: str = "premium") -> BackupPaths:
+    """A config folder, history, and baseline under root."""
+    paths = BackupPaths(
+        config_dir=root / "config",
+        history=root / "data" / "history.sqlite3",
+        baseline=root / "baseline.json",
+    )
+    paths.config_dir.mkdir(parents=True)
+    (paths.config_dir / "checks.toml").write_text(THRESHOLDS, encoding="utf-8")
+    config = AppConfig(plan=plan, checks_config=str(paths.config_dir / "checks.toml"))
+    paths.config.write_text(config.model_dump_json(indent=2), encoding="utf-8")
+    Baseline(entries=[BaselineEntry(fingerprint="abc123", reason="legacy blog")]).save(paths.baseline)
+    return paths
+
+
+@pytest.fixture
+def seeded(tmp_path):
+    """Provide a data dir with two audits, a delivery, and a comparison."""
+    paths = _data_dir(tmp_path / "source")
+    with HistoryStore(paths.history) as store:
+        first = store.insert(_result("https://example.com/"), label="before")
+        store.insert(_result("https://example.com/", "2025-02-01T00:00:00Z"))
+        store.record_delivery(first, "email", ["ana@example.com"])
+        store.insert_comparison(compare_sites(_result("https://example.com/"), _result("https://rival.example/")))
+    return paths
+
+
+@pytest.mark.unit
+class TestCreate:
+    """Test what goes into a backup."""
+
+    def test_contents_and_manifest(self, seeded, tmp_path):
+        """Test every file is included with a manifest, and secrets are listed, not stored."""
+        # Act
+        manifest = create_backup(tmp_path / "backup.zip", seeded)
+
+        # Assert
+        with zipfile.ZipFile(tmp_path / "backup.zip") as archive:
+            names = set(archive.namelist())
+            stored = json.loads(archive.read("manifest.json"))
+        assert names == {"manifest.json", "history.sqlite3", "config.json", "checks.toml", "baseline.json"}
+        assert stored["version"] == BACKUP_VERSION
+        assert stored["schema_version"] == SCHEMA_VERSION
+        assert stored["app_version"] and stored["created_at"]
+        assert stored["needs_reentry"] == EXCLUDED_SECRETS
+        assert manifest.files == sorted(names - {"manifest.json"})
+
+    def test_live_database_consistent(self, seeded, tmp_path):
+        """Test rows still in the WAL of an open store are in the backup."""
+        # Arrange
+        with HistoryStore(seeded.history) as store:
+            store._conn.execute("PRAGMA wal_autocheckpoint = 0")
+            store.insert(_result("https://example.com/", "2025-03-01T00:00:00Z"))
+
+            # Act
+            create_backup(tmp_path / "backup.zip", seeded)
+
+        # Assert
+        with zipfile.ZipFile(tmp_path / "backup.zip") as archive:
+            archive.extract("history.sqlite3", tmp_path / "out")
+        conn = sqlite3.connect(tmp_path / "out" / "history.sqlite3")
+        assert conn.execute("SELECT COUNT(*) FROM audits").fetchone()[0] == 3
+        conn.close()
+
+
+@pytest.mark.unit
+class TestRestore:
+    """Test restoring into empty and non-empty data dirs."""
+
+    def test_round_trip_into_empty(self, seeded, tmp_path):
+        """Test history, settings, thresholds, and baseline come back as backed up."""
+        # Arrange
+        create_backup(tmp_path / "backup.zip", seeded)
+        target = BackupPaths(
+            config_dir=tmp_path / "target" / "config",
+            history=tmp_path / "target" / "history.sqlite3",
+            baseline=tmp_path / "target" / "baseline.json",
+        )
+
+        # Act
+        result = restore_backup(tmp_path / "backup.zip", "replace", target)
+
+        # Assert
+        assert set(result.restored) == {"history.sqlite3", "config.json", "checks.toml", "baseline.json"}
+        with HistoryStore(target.history) as store:
+            rows = store.list()
+            assert [r.label for r in rows] == [None, "before"]
+            assert len(store.deliveries(rows[1].id)) == 1
+            assert len(store.comparisons()) == 1
+        config = AppConfig(**json.loads(target.config.read_text(encoding="utf-8")))
+        assert config.plan == "premium"
+        assert config.checks_config == str(target.config_dir / "checks.toml")  # not the source path
+        assert (target.config_dir / "checks.toml").read_text(encoding="utf-8") == THRESHOLDS
+        assert [e.fingerprint for e in Baseline.load(target.baseline).entries] == ["abc123"]
+
+    def test_replace_non_empty(self, seeded, tmp_path):
+        """Test replace drops the local history and overwrites the settings."""
+        # Arrange
+        create_backup(tmp_path / "backup.zip", seeded)
+        target = _data_dir(tmp_path / "target", plan="free")
+        with HistoryStore(target.history) as store:
+            store.insert(_result("https://local.example/"))
+
+        # Act
+        restore_backup(tmp_path / "backup.zip", "replace", target)
+
+        # Assert
+        with HistoryStore(target.history) as store:
+            assert {r.site for r in store.list()} == {"https://example.com/"}
+        assert json.loads(target.config.read_text(encoding="utf-8"))["plan"] == "premium"
+
+    def test_merge_non_empty(self, seeded, tmp_path):
+        """Test merge adds new audits, skips ones already there, and keeps local files."""
+        # Arrange
+        create_backup(tmp_path / "backup.zip", seeded)
+        target = _data_dir(tmp_path / "target", plan="free")
+        with HistoryStore(target.history) as store:
+            store.insert(_result("https://local.example/"))
+            store.insert(_result("https://example.com/"))  # same result as one in the backup
+
+        # Act
+        result = restore_backup(tmp_path / "backup.zip", "merge", target)
+
+        # Assert
+        assert (result.history.audits, result.history.duplicates) == (1, 1)
+        assert (result.history.comparisons, result.history.deliveries) == (1, 0)
+        assert set(result.kept) == {"config.json", "checks.toml", "baseline.json"}
+        with HistoryStore(target.history) as store:
+            assert len(store.list()) == 3
+        assert json.loads(target.config.read_text(encoding="utf-8"))["plan"] == "free"
+
+    def test_merge_twice_adds_nothing(self, seeded, tmp_path):
+        """Test merging the same backup again finds everything already there."""
+        # Arrange
+        create_backup(tmp_path / "backup.zip", seeded)
+        target = _data_dir(tmp_path / "target")
+        restore_backup(tmp_path / "backup.zip", "merge", target)
+
+        # Act
+        result = restore_backup(tmp_path / "backup.zip", "merge", target)
+
+        # Assert
+        assert (result.history.audits, result.history.duplicates, result.history.comparisons) == (0, 2, 0)
+
+
+@pytest.mark.unit
+class TestValidation:
+    """Test backups that cannot be restored are refused before anything changes."""
+
+    def _rewrite_manifest(self, path, **changes):
+        with zipfile.ZipFile(path) as archive:
+            members = {name: archive.read(name) for name in archive.namelist()}
+        manifest = json.loads(members["manifest.json"])
+        manifest.update(changes)
+        members["manifest.json"] = json.dumps(manifest).encode()
+        with zipfile.ZipFile(path, "w") as archive:
+            for name, data in members.items():
+                archive.writestr(name, data)
+
+    def test_newer_schema_refused(self, seeded, tmp_path):
+        """Test a backup from a newer history schema is refused with a clear message."""
+        # Arrange
+        create_backup(tmp_path / "backup.zip", seeded)
+        self._rewrite_manifest(tmp_path / "backup.zip", schema_version=SCHEMA_VERSION + 1, app_version="9.0.0")
+        target = _data_dir(tmp_path / "target")
+        with HistoryStore(target.history) as store:
+            store.insert(_result("https://local.example/"))
+
+        # Act / Assert
+        with pytest.raises(ConfigError, match="9.0.0.*newer than this tinyseoai supports.*Upgrade"):
+            restore_backup(tmp_path / "backup.zip", "replace", target)
+        with HistoryStore(target.history) as store:
+            assert [r.site for r in store.list()] == ["https://local.example/"]
+
+    def test_newer_backup_version_refused(self, seeded, tmp_path):
+        """Test a backup format from a newer version is refused."""
+        # Arrange
+        create_backup(tmp_path / "backup.zip", seeded)
+        self._rewrite_manifest(tmp_path / "backup.zip", version=BACKUP_VERSION + 1)
+
+        # Act / Assert
+        with pytest.raises(ConfigError, match="Upgrade tinyseoai"):
+            restore_backup(tmp_path / "backup.zip", "replace", _data_dir(tmp_path / "target"))
+
+    def test_not_a_backup(self, tmp_path):
+        """Test a zip without a manifest, and a file that is no zip, are refused."""
+        # Arrange
+        with zipfile.ZipFile(tmp_path / "other.zip", "w") as archive:
+            archive.writestr("readme.txt", "hi")
+        (tmp_path / "plain.zip").write_text("not a zip", encoding="utf-8")
+        target = BackupPaths(config_dir=tmp_path / "config", history=tmp_path / "history.sqlite3")
+
+        # Act / Assert
+        with pytest.raises(ConfigError, match="not a tinyseoai backup"):
+            restore_backup(tmp_path / "other.zip", "replace", target)
+        with pytest.raises(ConfigError, match="Cannot read backup"):
+            restore_backup(tmp_path / "plain.zip", "replace", target)
@@ -0,0 +1,174 @@
+"""
+Unit tests for baselines (issue suppression).
+"""
+from datetime import date
+
+import pytest
+
+from tinyseoai.data.baseline import (
+    SUPPRESSED_KEY,
+    Baseline,
+    BaselineEntry,
+    apply_baseline,
+    suppressed_issues,
+    update_baseline,
+)
+from tinyseoai.data.demo import build_demo_result
+from tinyseoai.data.fingerprint import fingerprint
+from tinyseoai.data.score_model import ScoreModel, recompute_score
+
+TODAY = date(2025, 6, 1)
+
+
+@pytest.fixture
+def result():
+    """Provide a fresh demo result."""
+    return build_demo_result()
+
+
+@pytest.mark.unit
+class TestApplyBaseline:
+    """Test applying a baseline to a result."""
+
+    def test_moves_matched_issues(self, result):
+        """Test matched issues leave result.issues and land in meta."""
+        # Arrange
+        target = result.issues[0]
+        baseline = update_baseline(Baseline(), [target], reason="Known")
+        total = len(result.issues)
+
+        # Act
+        report = apply_baseline(result, baseline, today=TODAY)
+
+        # Assert
+        assert report.suppressed == [target]
+        assert target not in result.issues
+        assert len(result.issues) == total - 1
+        assert suppressed_issues(result) == [target]
+        assert result.meta["baseline"]["suppressed"] == 1
+
+    def test_expired_entries_ignored(self, result):
+        """Test expired entries suppress nothing and are reported."""
+        # Arrange
+        baseline = update_baseline(Baseline(), result.issues[:1], expires=date(2025, 5, 31))
+
+        # Act
+        report = apply_baseline(result, baseline, today=TODAY)
+
+        # Assert
+        assert report.suppressed == []
+        assert [e.fingerprint for e in report.expired] == [fingerprint(result.issues[0])]
+        assert result.meta["baseline"]["expired_entries"] == 1
+
+    def test_entry_valid_on_expiry_day(self, result):
+        """Test an entry still applies on its expiry date."""
+        # Arrange
+        baseline = update_baseline(Baseline(), result.issues[:1], expires=TODAY)
+
+        # Act / Assert
+        assert len(apply_baseline(result, baseline, today=TODAY).suppressed) == 1
+
+    def test_unknown_fingerprints(self, result):
+        """Test entries that match no issue are reported as unmatched."""
+        # Arrange
+        baseline = Baseline(entries=[BaselineEntry(fingerprint="01" + "0" * 16)])
+        total = len(result.issues)
+
+        # Act
+        report = apply_baseline(result, baseline, today=TODAY)
+
+        # Assert
+        assert len(result.issues) == total
+        assert [e.fingerprint for e in report.unmatched] == ["01" + "0" * 16]
+
+    def test_applying_twice_accumulates(self, result):
+        """Test a second baseline adds to the suppressed list."""
+        # Arrange
+        first = update_baseline(Baseline(), result.issues[:1])
+        second = update_baseline(Baseline(), result.issues[1:2])
+
+        # Act
+        apply_baseline(result, first, today=TODAY)
+        apply_baseline(result, second, today=TODAY)
+
+        # Assert
+        assert len(result.meta[SUPPRESSED_KEY]) == 2
+
+
+@pytest.mark.unit
+class TestUpdateBaseline:
+    """Test building and saving baselines."""
+
+    def test_idempotent(self, result):
+        """Test adding the same issues twice keeps one entry each."""
+        # Arrange
+        baseline = update_baseline(Baseline(), result.issues)
+
+        # Act
+        again = update_baseline(baseline, result.issues)
+
+        # Assert
+        assert len(again.entries) == len(baseline.entries)
+
+    def test_does_not_mutate(self, result):
+        """Test the input baseline is left unchanged."""
+        # Arrange
+        baseline = Baseline()
+
+        # Act
+        update_baseline(baseline, result.issues[:2])
+
+        # Assert
+        assert baseline.entries == []
+
+    def test_round_trip(self, result, tmp_path):
+        """Test saved baselines load back identically."""
+        # Arrange
+        baseline = update_baseline(Baseline(), result.issues[:2], reason="Legacy", author="sam", expires=TODAY)
+
+        # Act
+        loaded = Baseline.load(baseline.save(tmp_path / "baseline.json"))
+
+        # Assert
+        assert loaded == baseline
+
+    def test_toml(self, tmp_path):
+        """Test TOML baselines load."""
+        # Arrange
+        path = tmp_path / "baseline.toml"
+        path.write_text('version = 1\n\n[[entries]]\nfingerprint = "01abc"\nexpires = 2025-12-31\n')
+
+        # Act
+        baseline = Baseline.load(path)
+
+        # Assert
+        assert baseline.entries[0].expires == date(2025, 12, 31)
+
+    def test_newer_version_rejected(self, tmp_path):
+        """Test baselines from a newer format version are refused."""
+        # Arrange
+        path = tmp_path / "baseline.json"
+        path.write_text('{"version": 99, "entries": []}')
+
+        # Act / Assert
+        with pytest.raises(ValueError):
+            Baseline.load(path)
+
+
+@pytest.mark.unit
+class TestBaselineScore:
+    """Test suppressed issues and score recomputation."""
+
+    def test_suppressed_count_by_default(self, result):
+        """Test suppression does not change the score unless the model says so."""
+        # Arrange
+        before = recompute_score(result)
+        apply_baseline(result, update_baseline(Baseline(), result.issues[:3]), today=TODAY)
+
+        # Act
+        kept = recompute_score(result)
+        excluded = recompute_score(result, ScoreModel(exclude_suppressed=True))
+
+        # Assert
+        assert kept == before
+        assert excluded > before
@@ -0,0 +1,133 @@
+"""
+Unit tests for report bundles.
+"""
+import csv
+import io
+import json
+import zipfile
+
+import pytest
+
+pytest.importorskip("jinja2")
+
+from tinyseoai.data.models import AuditResult  # noqa: E402
+from tinyseoai.exceptions import StoreError  # noqa: E402
+from tinyseoai.reporting.bundle import (  # noqa: E402
+    BUNDLE_VERSION,
+    MANIFEST,
+    BundleOptions,
+    export_bundle,
+    write_bundle,
+)
+from tinyseoai.reporting.tabular import CsvOptions  # noqa: E402
+from tinyseoai.store.history import HistoryStore  # noqa: E402
+
+MEMBERS = ["result.json", "report.html", "issues.csv", "summary.md"]
+
+
+def _summary(results_fixtures_dir) -> dict:
+    return json.loads((results_fixtures_dir / "comprehensive_summary.json").read_text())
+
+
+@pytest.mark.unit
+class TestWriteBundle:
+    """Test each member of a bundle parses as what it claims to be."""
+
+    def test_members_parse(self, results_fixtures_dir, tmp_path):
+        """Test the JSON loads as the same result, and the reports and CSV cover its issues."""
+        # Arrange
+        summary = _summary(results_fixtures_dir)
+        result = AuditResult(**summary)
+
+        # Act
+        write_bundle(summary, tmp_path / "report.zip")
+
+        # Assert
+        with zipfile.ZipFile(tmp_path / "report.zip") as archive:
+            assert sorted(archive.namelist()) == sorted([*MEMBERS, MANIFEST])
+            loaded = AuditResult.model_validate_json(archive.read("result.json"))
+            html = archive.read("report.html").decode("utf-8")
+            rows = list(csv.DictReader(io.StringIO(archive.read("issues.csv").decode("utf-8"))))
+            markdown = archive.read("summary.md").decode("utf-8")
+        assert loaded == result
+        assert html.lstrip().lower().startswith("<!doctype html") and result.site in html
+        assert {r["type"] for r in rows} == {i.type for i in result.issues}
+        assert markdown.startswith("#") and result.site in markdown
+
+    def test_manifest(self, results_fixtures_dir, tmp_path):
+        """Test the manifest names the audit, the app version, and each member with its size."""
+        # Arrange
+        summary = _summary(results_fixtures_dir)
+
+        # Act
+        written = write_bundle(summary, tmp_path / "report.zip")
+
+        # Assert
+        with zipfile.ZipFile(tmp_path / "report.zip") as archive:
+            manifest = json.loads(archive.read(MANIFEST))
+            sizes = {info.filename: info.file_size for info in archive.infolist()}
+        assert manifest["version"] == BUNDLE_VERSION
+        assert manifest["app_version"] == written.app_version
+        assert (manifest["site"], manifest["pages_scanned"]) == (summary["site"], summary["pages_scanned"])
+        assert manifest["issues"] == len(summary["issues"])
+        assert manifest["audit_id"] is None
+        assert [f["name"] for f in manifest["files"]] == MEMBERS
+        assert all(f["size"] == sizes[f["name"]] > 0 for f in manifest["files"])
+
+    def test_csv_options(self, results_fixtures_dir, tmp_path):
+        """Test the CSV member follows the CSV options, and the manifest says how."""
+        # Arrange
+        summary = _summary(results_fixtures_dir)
+        options = BundleOptions(csv=CsvOptions(explode_pages=True, delimiter="semicolon"))
+
+        # Act
+        manifest = write_bundle(summary, tmp_path / "report.zip", options)
+
+        # Assert
+        with zipfile.ZipFile(tmp_path / "report.zip") as archive:
+            header = archive.read("issues.csv").decode("utf-8").splitlines()[0]
+        assert header.startswith("severity;category;")
+        assert manifest.files[2].description == "Issues, one row per issue and page"
+
+    def test_replaces_existing(self, results_fixtures_dir, tmp_path):
+        """Test writing over an existing file leaves a complete bundle and no temporary files."""
+        # Arrange
+        dest = tmp_path / "report.zip"
+        dest.write_text("old")
+
+        # Act
+        write_bundle(_summary(results_fixtures_dir), dest)
+
+        # Assert
+        assert zipfile.is_zipfile(dest)
+        assert [p.name for p in tmp_path.iterdir()] == ["report.zip"]
+
+
+@pytest.mark.unit
+class TestExportBundle:
+    """Test bundles of audits saved in the history."""
+
+    def test_from_history(self, results_fixtures_dir, tmp_path):
+        """Test the stored audit is bundled with its history ID in the manifest."""
+        # Arrange
+        result = AuditResult(**_summary(results_fixtures_dir))
+        with HistoryStore(tmp_path / "history.sqlite3") as store:
+            audit_id = store.insert(result)
+
+            # Act
+            manifest = export_bundle(audit_id, tmp_path / "bundle.zip", store=store)
+
+        # Assert
+        assert manifest.audit_id == audit_id
+        with zipfile.ZipFile(tmp_path / "bundle.zip") as archive:
+            assert AuditResult.model_validate_json(archive.read("result.json")).site == result.site
+
+    def test_missing_audit(self, tmp_path):
+        """Test an unknown ID raises StoreError and writes nothing."""
+        # Arrange
+        with HistoryStore(tmp_path / "history.sqlite3") as store:
+
+            # Act / Assert
+            with pytest.raises(StoreError):
+                export_bundle(42, tmp_path / "bundle.zip", store=store)
+        assert not (tmp_path / "bundle.zip").exists()
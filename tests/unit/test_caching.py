@@ -0,0 +1,262 @@
+"""
+Unit tests for Cache-Control and Expires checks.
+"""
+import httpx
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.caching import (  # noqa: E402
+    CachingOptions,
+    Resource,
+    analyze,
+    asset_links,
+    expires_lifetime,
+    is_fingerprinted,
+    parse_cache_control,
+)
+
+DATE = "Wed, 14 Oct 2026 08:00:00 GMT"
+YEAR = "public, max-age=31536000, immutable"
+
+
+def _asset(url: str, **headers: str) -> Resource:
+    return Resource.from_headers(f"https://example.com{url}", [(k.replace("_", "-"), v) for k, v in headers.items()])
+
+
+def _page(url: str, **headers: str) -> Resource:
+    resource = _asset(url, **headers)
+    resource.kind = "document"
+    return resource
+
+
+def _types(issues) -> list[str]:
+    return sorted(i.type for i in issues)
+
+
+@pytest.mark.unit
+class TestParsing:
+    """Test header parsing."""
+
+    def test_directives(self):
+        """Test casing, spacing, quoted values, and valueless directives."""
+        assert parse_cache_control('Public,MAX-AGE = "600" ,  No-Transform') == {
+            "public": None,
+            "max-age": "600",
+            "no-transform": None,
+        }
+
+    def test_repeated_headers_and_directives(self):
+        """Test several Cache-Control headers combine and the first max-age wins."""
+        assert parse_cache_control(["max-age=60", "max-age=3600, must-revalidate"]) == {
+            "max-age": "60",
+            "must-revalidate": None,
+        }
+
+    def test_header_names_are_case_insensitive(self):
+        """Test headers given in any case are found."""
+        resource = _asset("/a.css", CACHE_CONTROL="max-age=10", Expires="0")
+        assert resource.header("cache-control") == "max-age=10"
+        assert resource.header("EXPIRES") == "0"
+
+    @pytest.mark.parametrize(
+        "expires,lifetime",
+        [
+            ("Wed, 14 Oct 2026 09:00:00 GMT", 3600),
+            ("Tue, 13 Oct 2026 08:00:00 GMT", 0),
+            ("0", 0),  # invalid dates mean already expired
+            ("-1", 0),
+        ],
+    )
+    def test_expires_lifetime(self, expires, lifetime):
+        """Test Expires is measured from the Date header."""
+        assert expires_lifetime(_asset("/a.css", Date=DATE, Expires=expires)) == lifetime
+
+    def test_no_expires(self):
+        """Test a response without Expires has no Expires lifetime."""
+        assert expires_lifetime(_asset("/a.css", Cache_Control="max-age=60")) is None
+
+
+@pytest.mark.unit
+class TestFingerprint:
+    """Test the content-hash filename heuristic."""
+
+    @pytest.mark.parametrize(
+        "url",
+        [
+            "/static/js/main.3f9a1c2b.js",  # webpack
+            "/assets/index-BxT9kq2L.css",  # Vite
+            "/_next/static/chunks/pages/_app-a1b2c3d4e5f60718.js",  # Next.js
+            "/dist/app.8e2f0c9d4b.js",  # Parcel
+            "/media/logo.5d41402abc4b2a76b9719d911017c592.png",
+        ],
+    )
+    def test_fingerprinted(self, url):
+        """Test hashed build output is recognized."""
+        assert is_fingerprinted(f"https://example.com{url}")
+
+    @pytest.mark.parametrize(
+        "url",
+        [
+            "/wp-includes/js/jquery/jquery.min.js?ver=3.7.1",
+            "/css/style-20240101.css",  # a date, not a hash
+            "/fonts/roboto-v30-latin-regular.woff2",
+            "/img/background.png",
+            "/js/analytics.js",
+            "/css/bootstrap.css",
+        ],
+    )
+    def test_not_fingerprinted(self, url):
+        """Test ordinary and version-suffixed filenames are not."""
+        assert not is_fingerprinted(f"https://example.com{url}")
+
+
+@pytest.mark.unit
+class TestAnalyze:
+    """Test findings for synthetic header sets."""
+
+    def test_well_cached(self):
+        """Test hashed assets cached for a year as immutable, and a cacheable page, pass."""
+        resources = [
+            _page("/", Cache_Control="no-cache"),
+            _asset("/assets/index-BxT9kq2L.css", Cache_Control=YEAR),
+            _asset("/assets/app.3f9a1c2b.js", Cache_Control=YEAR),
+            _asset("/img/hero.png"),  # not hashed: no lifetime can be expected
+        ]
+        assert analyze(resources) == []
+
+    def test_missing_headers(self):
+        """Test hashed assets without Cache-Control or Expires are listed together."""
+        # Act
+        issues = analyze(
+            [
+                _asset("/assets/index-BxT9kq2L.css"),
+                _asset("/assets/app.3f9a1c2b.js", ETag='"abc"'),
+                _asset("/assets/vendor.9c8b7a6f.js", Cache_Control=YEAR),
+            ]
+        )
+
+        # Assert
+        assert _types(issues) == ["asset_cache_headers_missing"]
+        assert issues[0].url == "https://example.com/assets/index-BxT9kq2L.css"
+        assert issues[0].severity == "medium"
+        assert issues[0].detail == (
+            "2 fingerprinted assets are served without Cache-Control or Expires, so browsers guess "
+            "how long to keep them: https://example.com/assets/index-BxT9kq2L.css, "
+            "https://example.com/assets/app.3f9a1c2b.js"
+        )
+
+    def test_short_max_age(self):
+        """Test short lifetimes, revalidation, and Expires-only lifetimes are reported with their values."""
+        # Act
+        issues = analyze(
+            [
+                _asset("/assets/a.3f9a1c2b.js", Cache_Control="public, max-age=600"),
+                _asset("/assets/b.3f9a1c2c.js", Cache_Control="no-cache"),
+                _asset("/assets/c.3f9a1c2d.js", Date=DATE, Expires="Wed, 14 Oct 2026 09:00:00 GMT"),
+            ]
+        )
+
+        # Assert
+        assert _types(issues) == ["asset_cache_short"]
+        assert issues[0].detail.endswith(
+            "https://example.com/assets/a.3f9a1c2b.js (max-age=600), "
+            "https://example.com/assets/b.3f9a1c2c.js (no-cache), "
+            "https://example.com/assets/c.3f9a1c2d.js (Expires in 3600s)"
+        )
+
+    def test_immutable_missing(self):
+        """Test long-cached hashed assets without immutable are info."""
+        # Act
+        issues = analyze([_asset("/assets/app.3f9a1c2b.js", Cache_Control="max-age=31536000")])
+
+        # Assert
+        assert _types(issues) == ["cache_immutable_missing"]
+        assert issues[0].severity == "info"
+
+    def test_no_store_on_public_pages(self):
+        """Test no-store is reported on pages without cookies and not on private pages."""
+        # Act
+        issues = analyze(
+            [
+                _page("/", Cache_Control="no-store"),
+                _page("/about", Cache_Control="No-Store, Max-Age=0"),
+                _page("/account", Cache_Control="no-store", Set_Cookie="session=1; HttpOnly"),
+                _page("/cart", Cache_Control="private, no-store"),
+            ]
+        )
+
+        # Assert
+        assert _types(issues) == ["cache_no_store"]
+        assert issues[0].detail.endswith(": https://example.com/, https://example.com/about")
+
+    @pytest.mark.parametrize(
+        "cache_control,expires,conflict",
+        [
+            ("max-age=31536000", "0", "Cache-Control allows 31536000s but Expires has already passed"),
+            ("no-store", "Thu, 15 Oct 2026 08:00:00 GMT", "Cache-Control forbids caching but Expires allows 86400s"),
+            ("max-age=0", "Thu, 15 Oct 2026 08:00:00 GMT", "Cache-Control forbids caching but Expires allows 86400s"),
+        ],
+    )
+    def test_conflicting_expires(self, cache_control, expires, conflict):
+        """Test Cache-Control and Expires disagreeing on whether to cache."""
+        # Act
+        issues = analyze([_page("/", Cache_Control=cache_control, Date=DATE, Expires=expires)])
+
+        # Assert
+        assert [i.type for i in issues if i.type == "cache_headers_conflict"] == ["cache_headers_conflict"]
+        assert next(i for i in issues if i.type == "cache_headers_conflict").detail == (
+            "1 responses have Cache-Control and Expires headers that disagree: "
+            f"https://example.com/ ({conflict})"
+        )
+
+    def test_agreeing_expires(self):
+        """Test an expired Expires next to no-cache is the usual belt and braces, not a conflict."""
+        assert analyze([_page("/", Cache_Control="no-cache, max-age=0", Expires="0")]) == []
+
+    def test_listing_limit(self):
+        """Test long lists end with a count."""
+        # Arrange
+        assets = [_asset(f"/assets/chunk.{n:08x}a.js") for n in range(5)]
+
+        # Act
+        issues = analyze(assets, CachingOptions(max_listed=2))
+
+        # Assert
+        assert issues[0].detail.endswith("chunk.00000001a.js and 3 more")
+
+    def test_httpx_headers(self):
+        """Test repeated headers from an httpx response are all read."""
+        # Arrange
+        headers = httpx.Headers([("Cache-Control", "public"), ("cache-control", "max-age=60")])
+
+        # Act
+        issues = analyze([Resource.from_headers("https://example.com/app.3f9a1c2b.js", headers)])
+
+        # Assert
+        assert issues[0].detail.endswith("app.3f9a1c2b.js (max-age=60)")
+
+
+@pytest.mark.unit
+class TestAssetLinks:
+    """Test asset discovery."""
+
+    def test_same_origin_assets(self):
+        """Test stylesheets, scripts, images, and preloads on the page's host are found."""
+        # Arrange
+        html = """<html><head>
+          <link rel="stylesheet" href="/assets/index-BxT9kq2L.css">
+          <link rel="preload" href="/fonts/inter.woff2" as="font">
+          <link rel="canonical" href="/">
+          <script src="https://www.googletagmanager.com/gtag/js"></script>
+          <script src="/assets/app.3f9a1c2b.js"></script>
+        </head><body><img src="/img/hero.png"><img src="data:image/gif;base64,R0lGOD"></body></html>"""
+
+        # Act & Assert
+        assert asset_links(html, "https://example.com/") == [
+            "https://example.com/assets/index-BxT9kq2L.css",
+            "https://example.com/fonts/inter.woff2",
+            "https://example.com/assets/app.3f9a1c2b.js",
+            "https://example.com/img/hero.png",
+        ]
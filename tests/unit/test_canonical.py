@@ -0,0 +1,138 @@
+"""
+Unit tests for canonical tag validation across pages.
+"""
+import httpx
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.canonical import analyze  # noqa: E402
+from tinyseoai.audit.crawler import FetchedPage  # noqa: E402
+
+
+def _page(url: str, *hrefs: str, status: int = 200) -> FetchedPage:
+    links = "".join(f'<link rel="canonical" href="{h}">' for h in hrefs)
+    return FetchedPage(url=url, body=f"<html><head>{links}</head><body></body></html>", status=status)
+
+
+def _found(issues) -> list[tuple[str, str]]:
+    return sorted((i.url, i.type) for i in issues)
+
+
+@pytest.mark.unit
+class TestPageChecks:
+    """Test findings that need only the page."""
+
+    @pytest.mark.asyncio
+    async def test_self_referencing_passes(self):
+        """Test a self-referencing canonical, absolute or with a fragment, is clean."""
+        # Arrange
+        pages = [_page("https://example.com", "https://example.com/"), _page("https://example.com/a", "https://example.com/a#top")]
+
+        # Act / Assert
+        assert await analyze(pages) == []
+
+    @pytest.mark.asyncio
+    async def test_page_defects(self):
+        """Test missing, empty, relative, conflicting, and cross-host canonicals."""
+        # Arrange
+        pages = [
+            _page("https://example.com/missing"),
+            _page("https://example.com/empty", ""),
+            _page("https://example.com/relative", "/relative"),
+            _page("https://example.com/conflict", "/a", "https://example.com/b"),
+            _page("https://example.com/same-twice", "/same-twice", "https://example.com/same-twice"),
+            _page("https://example.com/syndicated", "https://partner.example.org/story"),
+        ]
+
+        # Act
+        issues = await analyze(pages)
+
+        # Assert
+        assert _found(issues) == [
+            ("https://example.com/conflict", "conflicting_canonical_tags"),
+            ("https://example.com/empty", "empty_canonical"),
+            ("https://example.com/missing", "missing_canonical"),
+            ("https://example.com/relative", "canonical_relative"),
+            ("https://example.com/same-twice", "canonical_relative"),
+            ("https://example.com/syndicated", "canonical_cross_host"),
+        ]
+        cross_host = next(i for i in issues if i.type == "canonical_cross_host")
+        assert cross_host.detail == "Canonical points to another host: https://partner.example.org/story"
+
+
+@pytest.mark.unit
+class TestCrossPageChecks:
+    """Test loops, chains, and target status."""
+
+    @pytest.mark.asyncio
+    async def test_two_page_loop(self):
+        """Test A -> B -> A is reported on both pages."""
+        # Arrange
+        pages = [_page("https://example.com/a", "/b"), _page("https://example.com/b", "/a")]
+
+        # Act
+        issues = [i for i in await analyze(pages) if i.type == "canonical_loop"]
+
+        # Assert
+        assert [i.detail for i in issues] == [
+            "Canonical loop: https://example.com/a -> https://example.com/b -> https://example.com/a",
+            "Canonical loop: https://example.com/b -> https://example.com/a -> https://example.com/b",
+        ]
+
+    @pytest.mark.asyncio
+    async def test_chain_and_entry_into_loop(self):
+        """Test chains ending at a self-canonical page and pages leading into a loop."""
+        # Arrange
+        pages = [
+            _page("https://example.com/old", "https://example.com/older"),
+            _page("https://example.com/older", "https://example.com/new"),
+            _page("https://example.com/new", "https://example.com/new"),
+            _page("https://example.com/x", "https://example.com/y"),
+            _page("https://example.com/y", "https://example.com/z"),
+            _page("https://example.com/z", "https://example.com/y"),
+        ]
+
+        # Act
+        issues = await analyze(pages)
+
+        # Assert
+        assert _found(issues) == [
+            ("https://example.com/old", "canonical_chain"),
+            ("https://example.com/x", "canonical_loop"),
+            ("https://example.com/y", "canonical_loop"),
+            ("https://example.com/z", "canonical_loop"),
+        ]
+        chain = next(i for i in issues if i.type == "canonical_chain")
+        assert chain.detail.endswith("; point directly to https://example.com/new")
+
+    @pytest.mark.asyncio
+    async def test_targets(self):
+        """Test crawled 404 targets and uncrawled redirecting targets are reported."""
+        # Arrange
+        pages = [
+            _page("https://example.com/a", "https://example.com/gone"),
+            _page("https://example.com/gone", status=404),
+            _page("https://example.com/b", "https://example.com/moved"),
+        ]
+
+        def server(request):
+            if request.url.path == "/moved":
+                return httpx.Response(301, headers={"location": "https://example.com/new"})
+            return httpx.Response(200)
+
+        client = httpx.AsyncClient(transport=httpx.MockTransport(server))
+
+        # Act
+        issues = [i for i in await analyze(pages, client) if i.type.startswith("canonical_target")]
+
+        # Assert
+        assert [(i.url, i.type, i.detail) for i in issues] == [
+            ("https://example.com/a", "canonical_target_broken", "Canonical target https://example.com/gone returns 404"),
+            (
+                "https://example.com/b",
+                "canonical_target_redirects",
+                "Canonical target https://example.com/moved redirects to https://example.com/new",
+            ),
+        ]
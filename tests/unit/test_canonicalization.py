@@ -0,0 +1,179 @@
+"""
+Unit tests for www/apex, scheme, and trailing-slash canonicalization.
+"""
+import asyncio
+
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.canonicalization import (  # noqa: E402
+    CanonicalizationOptions,
+    analyze,
+)
+from tinyseoai.audit.crawler import FetchedPage  # noqa: E402
+from tinyseoai.i18n import text  # noqa: E402
+
+WWW = "https://www.example.com"
+APEX = "https://example.com"
+
+# A site that gets everything right: one host, https, trailing slashes
+CLEAN = {
+    f"{WWW}/": (200, None),
+    f"{APEX}/": (301, f"{WWW}/"),
+    "http://www.example.com/": (301, f"{WWW}/"),
+    "http://example.com/": (301, f"{WWW}/"),
+    f"{WWW}/mugs": (301, f"{WWW}/mugs/"),
+    f"{WWW}/mugs/": (200, None),
+    f"{WWW}/bowls": (301, "/bowls/"),
+    f"{WWW}/bowls/": (200, None),
+}
+
+
+def _site(asset_site, routes: dict, **changes):
+    """An AssetSite scripted by full URL: (status, Location); anything else is 404."""
+    return asset_site(
+        {
+            url: ("text/html", b"", set(), {"location": location}, status)
+            if location
+            else ("text/html", b"<p>Page</p>", set(), {}, status)
+            for url, (status, location) in {**routes, **changes}.items()
+        }
+    )
+
+
+def _analyze(site, pages: list[FetchedPage], **options):
+    return asyncio.run(analyze(f"{WWW}/", site.client(), pages, CanonicalizationOptions(**options)))
+
+
+def _page(url: str, canonical: str | None = None) -> FetchedPage:
+    link = f'<link rel="canonical" href="{canonical}">' if canonical else ""
+    return FetchedPage(url=url, body=f"<html><head>{link}</head><body><p>Page</p></body></html>")
+
+
+PAGES = [_page(f"{WWW}/", f"{WWW}/"), _page(f"{WWW}/mugs/", f"{WWW}/mugs/"), _page(f"{WWW}/bowls/")]
+
+
+def _found(report) -> list[tuple[str, str]]:
+    return sorted((i.url, i.type) for i in report.issues)
+
+
+@pytest.mark.unit
+class TestConsistentSite:
+    """Test a site with one redirect policy."""
+
+    def test_no_findings(self, asset_site):
+        """Test every variant redirecting one way passes and the rule is derived."""
+        # Act
+        report = _analyze(_site(asset_site, CLEAN), PAGES)
+
+        # Assert
+        assert report.issues == []
+        assert (report.host, report.other_host, report.slash) == (
+            "www.example.com",
+            "example.com",
+            "add",
+        )
+        assert [text(f["message"], f["args"]) for f in report.rule()] == [
+            "301 example.com→www.example.com",
+            "enforce trailing slash",
+        ]
+
+    def test_probe_budget(self, asset_site):
+        """Test the host variants come first and the rest of the budget goes to path pairs."""
+        # Arrange
+        site = _site(asset_site, CLEAN)
+
+        # Act
+        report = _analyze(site, PAGES, max_probes=6)
+
+        # Assert
+        assert len(site.requests) == 6
+        assert [p.page for p in report.paths] == [f"{WWW}/mugs/"]
+        assert report.meta()["paths"][0]["bare"] == {
+            "url": f"{WWW}/mugs",
+            "status": 301,
+            "location": f"{WWW}/mugs/",
+        }
+
+
+@pytest.mark.unit
+class TestInconsistencies:
+    """Test each way a site can split or contradict its URLs."""
+
+    def test_both_hosts_serve(self, asset_site):
+        """Test apex and www both answering 200 is reported with the rule to apply."""
+        # Act
+        report = _analyze(_site(asset_site, CLEAN, **{f"{APEX}/": (200, None)}), PAGES)
+
+        # Assert
+        assert _found(report) == [(f"{WWW}/", "host_variants_duplicated")]
+        assert report.issues[0].severity == "high"
+        assert report.issues[0].detail == (
+            "2 of the 4 host and scheme variants serve the home page without redirecting: "
+            f"{APEX}/, {WWW}/. Recommended rule: 301 example.com→www.example.com, "
+            "enforce trailing slash"
+        )
+
+    def test_host_redirects_disagree(self, asset_site):
+        """Test http redirecting to www while https www redirects to the apex."""
+        # Arrange
+        site = _site(
+            asset_site,
+            CLEAN,
+            **{
+                f"{WWW}/": (301, f"{APEX}/"),
+                f"{APEX}/": (200, None),
+                "http://example.com/": (301, f"{WWW}/"),
+            },
+        )
+
+        # Act
+        report = _analyze(site, [])
+
+        # Assert
+        assert _found(report) == [(f"{WWW}/", "redirect_policy_inconsistent")]
+        assert report.issues[0].detail.startswith("Redirects disagree on the host: ")
+
+    def test_slash_redirects_disagree(self, asset_site):
+        """Test one path adding the slash while another removes it."""
+        # Arrange
+        site = _site(
+            asset_site,
+            CLEAN,
+            **{f"{WWW}/bowls": (200, None), f"{WWW}/bowls/": (301, f"{WWW}/bowls")},
+        )
+
+        # Act
+        report = _analyze(site, PAGES)
+
+        # Assert
+        assert _found(report) == [(f"{WWW}/", "redirect_policy_inconsistent")]
+        assert report.issues[0].detail.startswith(
+            f"Redirects disagree on trailing slashes: {WWW}/mugs → {WWW}/mugs/, "
+            f"{WWW}/bowls/ → {WWW}/bowls."
+        )
+
+    def test_both_slash_forms_serve(self, asset_site):
+        """Test a path answering 200 with and without the slash is reported on the page."""
+        # Act
+        report = _analyze(_site(asset_site, CLEAN, **{f"{WWW}/mugs": (200, None)}), PAGES)
+
+        # Assert
+        assert _found(report) == [(f"{WWW}/mugs/", "trailing_slash_duplicated")]
+
+    def test_canonical_against_policy(self, asset_site):
+        """Test canonicals naming the apex or the bare path get the form the redirects lead to."""
+        # Arrange
+        pages = [_page(f"{WWW}/mugs/", f"{APEX}/mugs"), _page(f"{WWW}/bowls/", f"{WWW}/bowls/")]
+
+        # Act
+        report = _analyze(_site(asset_site, CLEAN), pages)
+
+        # Assert
+        assert _found(report) == [(f"{WWW}/mugs/", "canonical_conflicts_redirect_policy")]
+        assert report.issues[0].detail.startswith(
+            f"Canonical {APEX}/mugs is a URL form the site redirects away from; "
+            f"the redirects lead to {WWW}/mugs/."
+        )
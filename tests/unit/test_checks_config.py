@@ -0,0 +1,178 @@
+"""
+Unit tests for the per-check thresholds file.
+"""
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.content import ContentOptions  # noqa: E402
+from tinyseoai.audit.checks.meta import TITLE_MAX_LENGTH, TITLE_MIN_LENGTH  # noqa: E402
+from tinyseoai.audit.checks.performance import PerformanceChecker  # noqa: E402
+from tinyseoai.audit.checks.timing import TimingOptions  # noqa: E402
+from tinyseoai.audit.checks_config import ChecksConfig  # noqa: E402
+from tinyseoai.audit.engine_v2 import _check_title  # noqa: E402
+from tinyseoai.audit.registry import CheckContext, CheckRegistry, FunctionCheck  # noqa: E402
+from tinyseoai.exceptions import ConfigError  # noqa: E402
+
+
+@pytest.mark.unit
+class TestDefaults:
+    """Test the defaults match the checks' own."""
+
+    def test_hard_coded_values(self):
+        """Test the default config carries the thresholds the checks used before."""
+        config = ChecksConfig()
+
+        assert (config.meta.title_min, config.meta.title_max) == (TITLE_MIN_LENGTH, TITLE_MAX_LENGTH)
+        assert config.timing.ttfb_warning_ms == TimingOptions().ttfb_warning_ms == 800
+        assert config.performance.moderate_html_kb == 200
+        assert config.content.min_words == ContentOptions().min_words
+        assert (config.duplicate_content.near_threshold, config.duplicate_content.max_near_pages) == (0.8, 100)
+        assert config.disabled() == []
+
+    def test_settings_are_check_options(self):
+        """Test each table can be passed straight to its check."""
+        config = ChecksConfig()
+
+        assert isinstance(config.timing, TimingOptions)
+        assert isinstance(config.content, ContentOptions)
+
+
+@pytest.mark.unit
+class TestRoundTrip:
+    """Test dumping and loading."""
+
+    def test_default_round_trip(self):
+        """Test the dumped defaults load back to the defaults."""
+        assert ChecksConfig.from_toml(ChecksConfig().dumps()) == ChecksConfig()
+
+    def test_changed_values_round_trip(self):
+        """Test edited values survive a dump and load."""
+        # Arrange
+        config = ChecksConfig.from_toml(
+            '[meta]\ntitle_max = 65\n[indexability]\nuser_agent = "bingbot"\n[soft404]\nenabled = false\n'
+        )
+
+        # Act
+        loaded = ChecksConfig.from_toml(config.dumps())
+
+        # Assert
+        assert loaded == config
+        assert loaded.meta.title_max == 65
+        assert loaded.indexability.user_agent == "bingbot"
+
+    def test_dump_lists_every_table(self):
+        """Test the dump has a table per check, enabled first."""
+        dumped = ChecksConfig().dumps()
+
+        assert "[meta]\nenabled = true\ntitle_min = 30\n" in dumped
+        assert "[timing]\nenabled = true\nsamples = 3\nttfb_warning_ms = 800.0\n" in dumped
+        assert dumped.count("enabled = true") == len(ChecksConfig.model_fields)
+
+    def test_partial_file(self):
+        """Test keys left out keep their defaults."""
+        # Act
+        config = ChecksConfig.from_toml("[timing]\nttfb_warning_ms = 600\n")
+
+        # Assert
+        assert config.timing.ttfb_warning_ms == 600
+        assert config.timing.ttfb_critical_ms == 1800
+        assert config.meta == ChecksConfig().meta
+
+    def test_load_file(self, tmp_path):
+        """Test loading from a path."""
+        # Arrange
+        path = tmp_path / "checks.toml"
+        path.write_text("[links]\nenabled = false\n[content]\nmin_words = 150\n")
+
+        # Act
+        config = ChecksConfig.load(path)
+
+        # Assert
+        assert config.disabled() == ["links"]
+        assert config.content.min_words == 150
+
+
+@pytest.mark.unit
+class TestValidation:
+    """Test errors name the check id and key."""
+
+    @pytest.mark.parametrize(
+        "text,message",
+        [
+            ("[timing]\nttfb_warn_ms = 600\n", "[timing] unknown key 'ttfb_warn_ms'"),
+            ("[sitespeed]\nenabled = false\n", "unknown check id [sitespeed]"),
+            ('[meta]\ntitle_max = "long"\n', "[meta] title_max: Input should be a valid integer"),
+            ("[soft404]\nthreshold = 1.5\n", "[soft404] threshold: Input should be less than or equal to 1"),
+            ("timing = 5\n", "[timing] Input should be a valid dictionary"),
+        ],
+    )
+    def test_invalid_values(self, text, message):
+        """Test unknown ids and keys, wrong types, and out-of-range values."""
+        with pytest.raises(ConfigError) as exc:
+            ChecksConfig.from_toml(text)
+
+        assert message in str(exc.value)
+
+    def test_all_errors_reported(self):
+        """Test every problem in the file is named at once."""
+        with pytest.raises(ConfigError) as exc:
+            ChecksConfig.from_toml("[meta]\ntitle_mx = 1\n[caching]\nmax_age = 2\n")
+
+        assert str(exc.value) == "[meta] unknown key 'title_mx'; [caching] unknown key 'max_age'"
+
+    def test_invalid_toml(self):
+        """Test a syntax error is a ConfigError."""
+        with pytest.raises(ConfigError, match="Invalid TOML"):
+            ChecksConfig.from_toml("[meta\n")
+
+    def test_missing_file(self, tmp_path):
+        """Test a missing file is a ConfigError naming it."""
+        with pytest.raises(ConfigError, match="Cannot read .*checks.toml"):
+            ChecksConfig.load(tmp_path / "checks.toml")
+
+
+@pytest.mark.unit
+class TestApplied:
+    """Test the thresholds reach the checks."""
+
+    def test_title_length(self):
+        """Test the title limit comes from the meta table."""
+        title = "A" * 62
+
+        assert [i.type for i in _check_title("https://example.com/", title)] == ["title_too_long"]
+        assert _check_title("https://example.com/", title, ChecksConfig().meta.title_max + 5) == []
+
+    def test_html_size(self):
+        """Test a lower page size threshold reports a smaller page."""
+        # Arrange
+        html = f"<html><body><p>{'x' * 60 * 1024}</p></body></html>"
+        config = ChecksConfig.from_toml("[performance]\nmoderate_html_kb = 50\n")
+
+        # Act
+        default = PerformanceChecker(html, "https://example.com/").check_page_size()
+        tuned = PerformanceChecker(html, "https://example.com/", options=config.performance).check_page_size()
+
+        # Assert
+        assert default == []
+        assert [i.type for i in tuned] == ["moderate_html_size"]
+
+    @pytest.mark.asyncio
+    async def test_disabled_in_registry(self):
+        """Test a check turned off in the config is recorded as disabled and not run."""
+        # Arrange
+        registry = CheckRegistry([FunctionCheck("soft404", lambda ctx: 1 / 0)])
+        context = CheckContext(
+            seed_url="https://example.com/",
+            pages=[],
+            checks=ChecksConfig.from_toml("[soft404]\nenabled = false\n"),
+        )
+
+        # Act
+        results = await registry.run(context)
+
+        # Assert
+        assert [(r.status, r.reason) for r in results.runs] == [
+            ("disabled", "enabled = false in checks config")
+        ]
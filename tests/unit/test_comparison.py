@@ -0,0 +1,185 @@
+"""
+Unit tests for comparing the audits of two sites.
+"""
+from pathlib import Path
+
+import pytest
+
+from tinyseoai.data.comparison import SiteComparison, compare_sites
+from tinyseoai.data.models import AuditResult, Issue
+
+FIXTURES = Path(__file__).parents[1] / "fixtures" / "comparison"
+
+
+@pytest.fixture
+def prospect() -> AuditResult:
+    return AuditResult.load(FIXTURES / "prospect.json")
+
+
+@pytest.fixture
+def competitor() -> AuditResult:
+    return AuditResult.load(FIXTURES / "competitor.json")
+
+
+@pytest.mark.unit
+class TestCompareSites:
+    """Test the comparison built from two saved results."""
+
+    def test_side_by_side_metrics(self, prospect, competitor):
+        """Test each side carries its own headline numbers."""
+        # Act
+        comparison = compare_sites(prospect, competitor, mode="fast")
+
+        # Assert
+        assert comparison.mode == "fast"
+        assert (comparison.a.site, comparison.b.site) == (prospect.site, competitor.site)
+        assert (comparison.a.health_score, comparison.b.health_score) == (62.0, 81.0)
+        assert (comparison.a.issues_per_page, comparison.b.issues_per_page) == (1.5, 0.6)
+        assert comparison.a.severities["high"] == 2
+        assert comparison.b.severities["high"] == 0
+
+    def test_category_winners(self, prospect, competitor):
+        """Test each category goes to the site with the better per-page score."""
+        # Act
+        comparison = compare_sites(prospect, competitor)
+
+        # Assert
+        verdicts = {v.category: v for v in comparison.categories}
+        assert verdicts["content"].winner == "b"
+        assert verdicts["links"].winner == "b"
+        assert (verdicts["links"].a_issues, verdicts["links"].b_score) == (1, 100)
+        assert verdicts["performance"].winner == "a"
+        assert "technical" not in verdicts  # neither site has an issue there
+        assert comparison.wins("a") == ["performance"]
+
+    def test_tie(self, prospect):
+        """Test the same audit on both sides wins nothing."""
+        # Act
+        comparison = compare_sites(prospect, prospect)
+
+        # Assert
+        assert {v.winner for v in comparison.categories} == {None}
+        assert comparison.only_a == comparison.only_b == []
+
+    def test_issues_on_one_side(self, prospect, competitor):
+        """Test issue types only one site has, most severe first, with example pages."""
+        # Act
+        comparison = compare_sites(prospect, competitor, examples=1)
+
+        # Assert
+        assert [(g.type, g.severity, g.pages) for g in comparison.only_a] == [
+            ("title_missing", "high", 2),
+            ("broken_link", "medium", 1),
+        ]
+        assert comparison.only_a[0].examples == ["https://prospect.example/services"]
+        assert comparison.only_a[0].title == "Title missing"
+        assert [g.type for g in comparison.only_b] == ["no_compression", "missing_csp"]
+
+    def test_matched_by_type_not_page(self, prospect, competitor):
+        """Test an issue type both sites have is shared, whatever the pages and details."""
+        # Arrange
+        competitor.issues.append(
+            Issue(url="https://competitor.example/jobs", type="broken_link", severity="low", detail="/x")
+        )
+
+        # Act
+        comparison = compare_sites(prospect, competitor)
+
+        # Assert
+        assert [g.type for g in comparison.only_a] == ["title_missing"]
+
+    def test_json_round_trip(self, prospect, competitor):
+        """Test the comparison serializes to JSON and back unchanged."""
+        # Arrange
+        comparison = compare_sites(prospect, competitor)
+
+        # Act
+        loaded = SiteComparison.model_validate_json(comparison.model_dump_json())
+
+        # Assert
+        assert loaded == comparison
+
+
+@pytest.mark.unit
+class TestComparisonHtml:
+    """Test the two-column HTML report."""
+
+    @pytest.fixture(autouse=True)
+    def _config(self, monkeypatch):
+        pytest.importorskip("jinja2")
+        from tinyseoai.config import AppConfig
+        from tinyseoai.reporting import html_report
+
+        monkeypatch.setattr(html_report, "get_config", lambda: AppConfig())
+
+    def test_two_columns(self, prospect, competitor):
+        """Test both sites, the category table, and each side's gaps are rendered."""
+        from tinyseoai.reporting.html_report import render_comparison_html
+
+        # Act
+        html = render_comparison_html(compare_sites(prospect, competitor))
+
+        # Assert
+        assert "https://prospect.example/ vs https://competitor.example/" in html
+        assert "Only on https://prospect.example/" in html
+        assert "Only on https://competitor.example/" in html
+        assert "Title missing" in html and "No compression" in html
+        assert "Both sites were crawled" in html
+        assert "<link " not in html
+
+    def test_escaped(self, prospect, competitor):
+        """Test crawled URLs cannot inject markup."""
+        from tinyseoai.reporting.html_report import render_comparison_html
+
+        # Arrange
+        prospect.issues[0].url = 'https://prospect.example/"><script>alert(1)</script>'
+
+        # Act
+        html = render_comparison_html(compare_sites(prospect, competitor))
+
+        # Assert
+        assert "<script>alert(1)</script>" not in html
+
+
+@pytest.mark.unit
+class TestRunComparison:
+    """Test both sites are audited, one after the other."""
+
+    def test_sequential_audits(self, prospect, competitor, monkeypatch):
+        """Test each URL is audited in order with its own scope and the mode's checks."""
+        pytest.importorskip("bs4")
+        pytest.importorskip("lxml")
+        import asyncio
+
+        from tinyseoai.audit import comparison as module
+        from tinyseoai.audit.scope import CrawlOptions
+
+        # Arrange
+        calls = []
+
+        async def fake_audit(url, **kwargs):
+            calls.append((url, kwargs["enable_all_checks"], kwargs["options"].max_pages))
+            return prospect if "prospect" in url else competitor
+
+        monkeypatch.setattr(module, "comprehensive_audit", fake_audit)
+        started = []
+
+        # Act
+        run = asyncio.run(
+            module.run_comparison(
+                "https://prospect.example/",
+                "https://competitor.example/",
+                "fast",
+                options_a=CrawlOptions(max_pages=5),
+                options_b=CrawlOptions(max_pages=7),
+                on_site=started.append,
+            )
+        )
+
+        # Assert
+        assert calls == [
+            ("https://prospect.example/", False, 5),
+            ("https://competitor.example/", False, 7),
+        ]
+        assert started == ["https://prospect.example/", "https://competitor.example/"]
+        assert run.a is prospect and run.comparison.mode == "fast"
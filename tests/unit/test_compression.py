@@ -0,0 +1,213 @@
+"""
+Unit tests for compression and transfer size checks.
+"""
+import gzip
+import random
+import string
+
+import httpx
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.compression import (  # noqa: E402
+    CompressionOptions,
+    analyze,
+    measure,
+    resource_links,
+)
+
+URL = "https://example.com/"
+
+PAGE = """<!DOCTYPE html>
+<html><head>
+  <link rel="stylesheet" href="/static/site.css">
+  <link rel="stylesheet" href="https://cdn.example.net/fonts.css">
+  <script src="/static/app.js" defer></script>
+</head><body>{body}</body></html>"""
+
+CSS = ("body { color: #333; font-family: Georgia, serif; }\n" * 200).encode()
+JS = ("document.querySelectorAll('.mug').forEach(m => m.classList.add('ready'));\n" * 200).encode()
+
+
+def _noise(size: int) -> str:
+    """Text that compresses poorly, for pages that stay large after gzip."""
+    rng = random.Random(0)
+    return "".join(rng.choice(string.ascii_letters + " ") for _ in range(size))
+
+
+def _routes(encodings: set[str], body: str = "<h1>Mugs</h1>" * 200) -> dict:
+    return {
+        "/": ("text/html", PAGE.format(body=body).encode(), encodings),
+        "/static/site.css": ("text/css", CSS, encodings),
+        "/static/app.js": ("text/javascript", JS, encodings),
+    }
+
+
+def _types(issues) -> list[tuple[str, str]]:
+    return sorted((i.type, i.severity) for i in issues)
+
+
+@pytest.mark.unit
+class TestResourceLinks:
+    """Test stylesheet and script discovery."""
+
+    def test_same_origin_only(self):
+        """Test cross-origin, non-stylesheet, and inline resources are skipped."""
+        # Arrange
+        html = PAGE.format(body='<script>inline()</script><link rel="preload" href="/a.woff2">')
+
+        # Act
+        links = resource_links(html, URL)
+
+        # Assert
+        assert links == [
+            ("https://example.com/static/site.css", "stylesheet"),
+            ("https://example.com/static/app.js", "script"),
+        ]
+
+
+@pytest.mark.unit
+class TestMeasure:
+    """Test one resource fetched with and without compression."""
+
+    @pytest.mark.asyncio
+    async def test_sizes(self, asset_site):
+        """Test transfer size, full size, and the gzip estimate are recorded."""
+        # Arrange
+        server = asset_site(_routes({"gzip"}))
+
+        # Act
+        async with server.client() as client:
+            transfer, body = await measure(f"{URL}static/site.css", "stylesheet", client)
+
+        # Assert
+        assert transfer.content_encoding == "gzip"
+        assert transfer.identity_bytes == len(CSS) == len(body)
+        assert transfer.transfer_bytes == len(gzip.compress(CSS))
+        assert transfer.savings() > 0.9
+        assert [r[2] for r in server.requests] == ["gzip, br", "identity"]
+
+
+@pytest.mark.unit
+class TestAnalyze:
+    """Test findings against a server that compresses selectively."""
+
+    @pytest.mark.asyncio
+    async def test_uncompressed(self, asset_site):
+        """Test every resource served without compression is reported with its savings."""
+        # Arrange
+        async with asset_site(_routes(set())).client() as client:
+            # Act
+            issues = await analyze(URL, client)
+
+        # Assert
+        assert _types(issues) == [
+            ("no_compression", "high"),
+            ("no_compression", "medium"),
+            ("no_compression", "medium"),
+        ]
+        details = sorted(i.detail for i in issues)
+        assert details[0].startswith(
+            "Script https://example.com/static/app.js is served uncompressed to a client "
+            "accepting gzip and br: 14.5 KB; enable gzip, ~"
+        )
+        assert all("% smaller (" in d for d in details)
+        assert all(i.url == URL for i in issues)
+
+    @pytest.mark.asyncio
+    async def test_gzip_without_brotli(self, asset_site):
+        """Test gzip where br was accepted is info."""
+        # Arrange
+        async with asset_site(_routes({"gzip"})).client() as client:
+            # Act
+            issues = await analyze(URL, client)
+
+        # Assert
+        assert _types(issues) == [("compression_not_optimal", "info")] * 3
+        assert any(i.detail.startswith("The page is served with gzip although") for i in issues)
+
+    @pytest.mark.asyncio
+    async def test_brotli(self, asset_site):
+        """Test a site serving brotli passes."""
+        async with asset_site(_routes({"gzip", "br"})).client() as client:
+            assert await analyze(URL, client) == []
+
+    @pytest.mark.asyncio
+    async def test_only_html_uncompressed(self, asset_site):
+        """Test a server compressing static files but not the HTML."""
+        # Arrange
+        server = asset_site(_routes({"br"}))
+        content_type, body, _ = server.routes["/"]
+        server.routes["/"] = (content_type, body, set())
+
+        # Act
+        async with server.client() as client:
+            issues = await analyze(URL, client)
+
+        # Assert
+        assert _types(issues) == [("no_compression", "high")]
+        assert issues[0].detail.startswith("The page is served uncompressed")
+
+    @pytest.mark.asyncio
+    async def test_large_transfer(self, asset_site):
+        """Test a page still over the threshold after compression."""
+        # Arrange
+        server = asset_site(_routes({"br"}, body=f"<p>{_noise(8000)}</p>"))
+        options = CompressionOptions(max_document_transfer=4 * 1024)
+
+        # Act
+        async with server.client() as client:
+            issues = await analyze(URL, client, options)
+
+        # Assert
+        assert _types(issues) == [("large_transfer_size", "medium")]
+        assert issues[0].detail.startswith("The page is ")
+        assert "on the wire even with br (" in issues[0].detail
+        assert issues[0].detail.endswith("uncompressed; threshold 4.0 KB)")
+
+    @pytest.mark.asyncio
+    async def test_small_files_pass(self, asset_site):
+        """Test files under min_bytes are not reported."""
+        async with asset_site(_routes(set(), body="")).client() as client:
+            issues = await analyze(URL, client, CompressionOptions(min_bytes=64 * 1024))
+
+        assert issues == []
+
+    @pytest.mark.asyncio
+    async def test_request_budget(self, asset_site):
+        """Test HEAD ranking picks the largest file it could size and stays within max_requests."""
+        # Arrange
+        scripts = "".join(f'<script src="/js/{n}.js"></script>' for n in range(12))
+        routes = {"/": ("text/html", f"<html><head>{scripts}</head></html>".encode(), set())}
+        for n in range(12):
+            routes[f"/js/{n}.js"] = ("text/javascript", JS * (3 if n == 2 else 1), set())
+        server = asset_site(routes)
+        options = CompressionOptions(max_requests=8, max_resources=1)
+
+        # Act
+        async with server.client() as client:
+            issues = await analyze(URL, client, options)
+
+        # Assert
+        assert len(server.requests) == 8
+        assert [r[:2] for r in server.requests if r[0] == "GET"] == [
+            ("GET", "/"),
+            ("GET", "/"),
+            ("GET", "/js/2.js"),
+            ("GET", "/js/2.js"),
+        ]
+        assert any("/js/2.js" in i.detail for i in issues)
+
+    @pytest.mark.asyncio
+    async def test_unreachable_page(self):
+        """Test a transport error produces no findings."""
+
+        # Arrange
+        def handler(request: httpx.Request) -> httpx.Response:
+            raise httpx.ConnectError("refused", request=request)
+
+        async with httpx.AsyncClient(transport=httpx.MockTransport(handler)) as client:
+            # Act & Assert
+            assert await analyze(URL, client) == []
@@ -0,0 +1,214 @@
+"""
+Unit tests for team configuration bundles.
+"""
+import json
+
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai import config as config_module  # noqa: E402
+from tinyseoai.audit.checks_config import ChecksConfig  # noqa: E402
+from tinyseoai.config import AppConfig, SmtpConfig  # noqa: E402
+from tinyseoai.config_bundle import (  # noqa: E402
+    BUNDLE_VERSION,
+    EXCLUDED_SECRETS,
+    export_config,
+    import_config,
+    load_bundle,
+)
+from tinyseoai.data.baseline import Baseline, BaselineEntry  # noqa: E402
+from tinyseoai.exceptions import ConfigError  # noqa: E402
+from tinyseoai.utils.http import HttpOptions  # noqa: E402
+
+THRESHOLDS = "[timing]\nttfb_warning_ms = 600\n\n[soft404]\nenabled = false\n"
+
+
+def _team_config() -> AppConfig:
+    return AppConfig(
+        plan="premium",
+        disabled_checks=["amp"],
+        http=HttpOptions(user_agent="AcmeAudit/1.0", contact="seo@acme.example"),
+        lighthouse_path="/opt/lighthouse/bin/lighthouse",
+        checks_config="/home/ana/checks.toml",
+    )
+
+
+def _baseline(*entries: tuple[str, str]) -> Baseline:
+    return Baseline(entries=[BaselineEntry(fingerprint=f, reason=r) for f, r in entries])
+
+
+@pytest.mark.unit
+class TestRoundTrip:
+    """Test exporting and importing into an empty setup."""
+
+    def test_replace(self, tmp_path):
+        """Test settings, thresholds, and baseline come back as exported."""
+        # Arrange
+        path = export_config(
+            tmp_path / "team.json",
+            _team_config(),
+            ChecksConfig.from_toml(THRESHOLDS),
+            _baseline(("abc123", "legacy blog")),
+        )
+
+        # Act
+        result = import_config(path, AppConfig(checks_config="/home/ben/checks.toml"))
+
+        # Assert
+        assert result.config.plan == "premium"
+        assert result.config.http.contact == "seo@acme.example"
+        assert result.config.lighthouse_path == "/opt/lighthouse/bin/lighthouse"
+        assert result.config.checks_config == "/home/ben/checks.toml"  # local path kept
+        assert result.checks.timing.ttfb_warning_ms == 600
+        assert not result.checks.soft404.enabled
+        assert [e.fingerprint for e in result.baseline.entries] == ["abc123"]
+        assert result.conflicts == []
+
+    def test_only_changes_exported(self, tmp_path):
+        """Test defaults and local paths stay out of the file."""
+        # Act
+        path = export_config(tmp_path / "team.json", _team_config())
+
+        # Assert
+        data = json.loads(path.read_text())
+        assert data["version"] == BUNDLE_VERSION
+        assert set(data["settings"]) == {"plan", "disabled_checks", "http", "lighthouse_path"}
+        assert "checks" not in data and "baseline" not in data
+        assert data["secrets_excluded"] == EXCLUDED_SECRETS
+
+    def test_replace_without_thresholds_resets_them(self, tmp_path):
+        """Test replacing with a bundle that has no checks config restores the defaults."""
+        # Arrange
+        path = export_config(tmp_path / "team.json", AppConfig())
+
+        # Act
+        result = import_config(path, AppConfig(), ChecksConfig.from_toml(THRESHOLDS))
+
+        # Assert
+        assert result.checks == ChecksConfig()
+
+
+@pytest.mark.unit
+class TestMerge:
+    """Test merging a bundle into a local setup."""
+
+    def test_conflicts_reported(self, tmp_path):
+        """Test differing values are kept locally and reported; new ones are added."""
+        # Arrange
+        path = export_config(
+            tmp_path / "team.json",
+            _team_config(),
+            ChecksConfig.from_toml(THRESHOLDS),
+            _baseline(("abc123", "legacy blog"), ("def456", "vendor widget")),
+        )
+        local = AppConfig(plan="free", http=HttpOptions(user_agent="BenBot"))
+
+        # Act
+        result = import_config(
+            path,
+            local,
+            ChecksConfig.from_toml("[timing]\nttfb_warning_ms = 900\n"),
+            _baseline(("abc123", "accepted in March")),
+            merge=True,
+        )
+
+        # Assert
+        assert [(c.key, c.current, c.incoming) for c in result.conflicts] == [
+            ("settings.http.user_agent", "BenBot", "AcmeAudit/1.0"),
+            ("checks.timing.ttfb_warning_ms", 900.0, 600.0),
+            (
+                "baseline.abc123",
+                {"fingerprint": "abc123", "reason": "accepted in March"},
+                {"fingerprint": "abc123", "reason": "legacy blog"},
+            ),
+        ]
+        assert result.config.http.user_agent == "BenBot"
+        assert result.config.http.contact == "seo@acme.example"
+        assert result.config.plan == "premium"  # free is the default, so not a local choice
+        assert result.checks.timing.ttfb_warning_ms == 900
+        assert not result.checks.soft404.enabled
+        assert [e.fingerprint for e in result.baseline.entries] == ["abc123", "def456"]
+
+    def test_same_values_no_conflict(self, tmp_path):
+        """Test importing a bundle into the setup it came from changes nothing."""
+        # Arrange
+        config, checks = _team_config(), ChecksConfig.from_toml(THRESHOLDS)
+        path = export_config(tmp_path / "team.json", config, checks)
+
+        # Act
+        result = import_config(path, config, checks, merge=True)
+
+        # Assert
+        assert result.conflicts == []
+        assert result.config == config
+        assert result.checks == checks
+
+
+@pytest.mark.unit
+class TestSecrets:
+    """Test secrets never reach the file."""
+
+    def test_no_secret_exported(self, tmp_path, monkeypatch):
+        """Test keyring and environment secrets and HTTP credentials are left out."""
+        # Arrange
+        secrets = {
+            "OPENAI_API_KEY": "sk-openai-SECRET",
+            "ANTHROPIC_API_KEY": "sk-ant-SECRET",
+            "TINYSEOAI_GITHUB_TOKEN": "ghp_SECRET",
+            "TINYSEOAI_SMTP_PASSWORD": "smtp-SECRET",
+        }
+        for name, value in secrets.items():
+            monkeypatch.setenv(name, value)
+        monkeypatch.delenv("TINYSEOAI_PAGESPEED_API_KEY", raising=False)
+        monkeypatch.delenv("PAGESPEED_API_KEY", raising=False)
+        monkeypatch.setattr(config_module, "_keyring_secret", lambda name: f"keyring-{name}-SECRET")
+        config = _team_config()
+        config.http = HttpOptions(
+            basic_auth=("staging", "hunter2-SECRET"),
+            cookie="session=cookie-SECRET",
+            extra_headers={"X-Token": "header-SECRET"},
+        )
+        config.smtp = SmtpConfig(host="smtp.acme.example", username="seo", from_address="seo@acme.example")
+        assert config.pagespeed_api_key == "keyring-pagespeed-SECRET"
+
+        # Act
+        text = export_config(tmp_path / "team.json", config).read_text()
+
+        # Assert
+        assert "SECRET" not in text
+        assert "hunter2" not in text
+        assert "smtp.acme.example" in text
+
+
+@pytest.mark.unit
+class TestInvalid:
+    """Test bundles that cannot be imported."""
+
+    @pytest.mark.parametrize(
+        "content, error",
+        [
+            ('{"version": 2, "settings": {}}', r"bundle version 2 is newer than supported \(1\)"),
+            ("{not json", "invalid JSON"),
+            ('{"version": 1, "settings": {}, "extra": 1}', "Extra inputs are not permitted"),
+        ],
+    )
+    def test_rejected(self, tmp_path, content, error):
+        # Arrange
+        path = tmp_path / "team.json"
+        path.write_text(content)
+
+        # Act / Assert
+        with pytest.raises(ConfigError, match=error):
+            load_bundle(path)
+
+    def test_bad_threshold(self, tmp_path):
+        """Test thresholds are validated like a checks.toml."""
+        # Arrange
+        path = tmp_path / "team.json"
+        path.write_text('{"version": 1, "checks": {"timing": {"nope": 1}}}')
+
+        # Act / Assert
+        with pytest.raises(ConfigError, match="Invalid checks config in bundle"):
+            import_config(path, AppConfig())
@@ -0,0 +1,152 @@
+"""
+Unit tests for thin-content and boilerplate checks.
+"""
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.content import (  # noqa: E402
+    ContentAnalyzer,
+    ContentOptions,
+    analyze,
+    unique_ratio,
+    visible_text,
+    word_count,
+)
+
+URL = "https://example.com/blog/glazing"
+
+
+def _types(issues) -> list[str]:
+    return sorted(i.type for i in issues)
+
+
+@pytest.mark.unit
+class TestVisibleText:
+    """Test text extraction and word counting."""
+
+    def test_strips_page_chrome(self, html_fixture):
+        """Test head, navigation, sidebar, footer, and scripts are left out."""
+        # Act
+        text = visible_text(html_fixture("content_near_empty.html"))
+
+        # Assert
+        assert text == "Coming soon Our new collection is on its way."
+
+    def test_hidden_and_role_elements(self):
+        """Test hidden elements and landmark roles are left out."""
+        # Arrange
+        html = (
+            '<body><div role="navigation">Home Shop</div><p hidden>Draft</p>'
+            '<p aria-hidden="true">Icon</p><div id="cookie-banner">We use cookies</div>'
+            "<p>Kept</p></body>"
+        )
+
+        # Act & Assert
+        assert visible_text(html) == "Kept"
+
+    @pytest.mark.parametrize(
+        "text,expected",
+        [
+            ("Handmade mugs, glazed by hand.", 5),
+            ("Mugs — and cups | bowls", 4),
+            ("我们的杯子都是手工制作的。", 12),
+            ("手工 mugs とカップ", 7),
+            ("", 0),
+        ],
+    )
+    def test_word_count(self, text, expected):
+        """Test punctuation is not a word and each CJK character is one."""
+        assert word_count(text) == expected
+
+
+@pytest.mark.unit
+class TestAnalyze:
+    """Test thin and boilerplate findings on the fixtures."""
+
+    def test_article_passes(self, html_fixture):
+        """Test a normal article has no content findings."""
+        # Arrange
+        others = [visible_text(html_fixture("content_tag_listing.html"))]
+
+        # Act
+        issues = analyze(html_fixture("content_article.html"), URL, others=others)
+
+        # Assert
+        assert issues == []
+
+    def test_near_empty_page(self, html_fixture):
+        """Test a near-empty page is very thin and the detail gives the count."""
+        # Act
+        issues = analyze(html_fixture("content_near_empty.html"), URL)
+
+        # Assert
+        assert _types(issues) == ["very_thin_content"]
+        assert issues[0].severity == "high"
+        assert "only 9 words" in issues[0].detail
+
+    def test_thin_threshold_is_configurable(self, html_fixture):
+        """Test a lower minimum clears the thin finding."""
+        # Act
+        options = ContentOptions(min_words=5, very_thin_words=5)
+        issues = analyze(html_fixture("content_near_empty.html"), URL, options)
+
+        # Assert
+        assert issues == []
+
+    def test_cjk_detail(self):
+        """Test CJK pages say how words were counted."""
+        # Act
+        issues = analyze("<p>我们的杯子都是手工制作的。</p>", URL)
+
+        # Assert
+        assert "12 words of visible text (CJK characters counted as words)" in issues[0].detail
+
+    def test_tag_listing_is_boilerplate(self, html_fixture):
+        """Test a tag page whose text also appears on the article and another tag page."""
+        # Arrange
+        html = html_fixture("content_tag_listing.html")
+        others = [
+            visible_text(html_fixture("content_article.html")),
+            visible_text(html.replace("Tagged: glazes", "Tagged: kilns")),
+        ]
+
+        # Act
+        issues = analyze(html, "https://example.com/tag/glazes", others=others)
+
+        # Assert
+        assert _types(issues) == ["boilerplate_content", "thin_content"]
+        detail = next(i.detail for i in issues if i.type == "boilerplate_content")
+        assert detail.startswith("Only 1% of the page's 208 words are unique")
+        assert "2 other pages sampled" in detail
+
+    def test_no_others_no_boilerplate(self, html_fixture):
+        """Test boilerplate needs other pages to compare with."""
+        # Act
+        issues = analyze(html_fixture("content_tag_listing.html"), URL)
+
+        # Assert
+        assert _types(issues) == ["thin_content"]
+
+    def test_unique_ratio(self):
+        """Test the share of shingles found on no other page."""
+        # Arrange
+        text = "one two three four five six"
+
+        # Act & Assert
+        assert unique_ratio(text, ["one two three four five"], size=5) == 0.5
+        assert unique_ratio(text, [], size=5) == 1.0
+
+
+@pytest.mark.unit
+class TestContentAnalyzer:
+    """Test the per-page analyzer uses the same thresholds."""
+
+    def test_very_thin_reported(self):
+        """Test pages under 100 words are very thin, not just thin."""
+        # Act
+        issues = ContentAnalyzer("<p>Just a few words here.</p>", URL).check_content_length()
+
+        # Assert
+        assert _types(issues) == ["very_thin_content"]
@@ -0,0 +1,98 @@
+"""
+Unit tests for consolidating issues into findings.
+"""
+import pytest
+
+from tinyseoai.data.dedupe import Consolidation, consolidate, finding_counts
+from tinyseoai.data.models import Issue
+
+TYPES = [
+    ("meta_description_missing", "medium"),
+    ("title_missing", "high"),
+    ("img_alt_missing", "low"),
+    ("h1_missing", "medium"),
+    ("missing_hsts", "medium"),
+]
+
+
+@pytest.fixture
+def repeated() -> list[Issue]:
+    """200 issues: the same five problems on each of 40 pages."""
+    return [
+        Issue(url=f"https://example.com/p{n}", type=issue_type, severity=severity)
+        for n in range(40)
+        for issue_type, severity in TYPES
+    ]
+
+
+@pytest.mark.unit
+class TestConsolidate:
+    """Test grouping issues by problem."""
+
+    def test_repeated_issues_collapse(self, repeated):
+        """Test 200 per-page issues become one finding per problem."""
+        # Act
+        findings = consolidate(repeated)
+
+        # Assert
+        assert len(repeated) == 200
+        assert len(findings) == 5
+        assert all(len(f.pages) == 40 and f.issues == 40 for f in findings)
+        assert findings[0].title == "Title missing"
+        assert findings[0].recommendation
+
+    def test_aggressive_merges_similar_titles(self):
+        """Test titles whose words contain another's merge only when aggressive."""
+        # Arrange
+        issues = [
+            Issue(url="https://example.com/a", type="noindex", severity="medium"),
+            Issue(url="https://example.com/b", type="noindex_directive", severity="high"),
+        ]
+
+        # Act
+        exact = consolidate(issues)
+        aggressive = consolidate(issues, Consolidation.AGGRESSIVE)
+
+        # Assert
+        assert len(exact) == 2
+        assert len(aggressive) == 1
+        assert aggressive[0].types == ["noindex", "noindex_directive"]
+        assert aggressive[0].pages == ["https://example.com/a", "https://example.com/b"]
+
+    def test_keeps_most_severe_and_detailed(self):
+        """Test a finding takes the highest severity and the longest detail."""
+        # Arrange
+        issues = [
+            Issue(url="https://example.com/a", type="thin_content", severity="low", detail="Short"),
+            Issue(
+                url="https://example.com/b",
+                type="thin_content",
+                severity="medium",
+                detail="Only 40 words of body text",
+            ),
+            Issue(url="https://example.com/b", type="thin_content", severity="low"),
+        ]
+
+        # Act
+        (finding,) = consolidate(issues)
+
+        # Assert
+        assert finding.severity == "medium"
+        assert finding.detail == "Only 40 words of body text"
+        assert finding.pages == ["https://example.com/a", "https://example.com/b"]
+        assert finding.issues == 3
+
+    def test_off(self, repeated):
+        """Test the off policy keeps one finding per issue."""
+        # Act & Assert
+        assert len(consolidate(repeated, Consolidation.OFF)) == 200
+
+    def test_finding_counts(self, repeated):
+        """Test findings are counted per severity."""
+        # Act
+        counts = finding_counts(consolidate(repeated))
+
+        # Assert
+        assert counts["high"] == 1
+        assert counts["medium"] == 3
+        assert counts["low"] == 1
@@ -0,0 +1,67 @@
+"""
+Unit tests for per-check diagnostics in audit results.
+"""
+import pytest
+
+from tinyseoai.data.diagnostics import CheckRun, Diagnostics, format_bytes
+from tinyseoai.data.models import AuditResult
+
+RUNS = [
+    CheckRun(id="links", status="ran", issues=3, duration_ms=820.5, requests=40, bytes_fetched=51200),
+    CheckRun(id="timing", status="ran", duration_ms=1530.0, requests=3, bytes_fetched=90000),
+    CheckRun(id="soft404", status="failed", reason="boom", duration_ms=12.0, requests=1),
+    CheckRun(id="icons", status="skipped", reason="no HTML page was fetched"),
+]
+
+
+@pytest.mark.unit
+class TestDiagnostics:
+    """Test the diagnostics section of a result's meta."""
+
+    def test_round_trip_through_result(self):
+        """Test diagnostics survive serializing and loading a result."""
+        # Arrange
+        result = AuditResult(
+            site="https://example.com",
+            pages_scanned=1,
+            issues=[],
+            meta={"diagnostics": Diagnostics(checks=RUNS).model_dump()},
+        )
+
+        # Act
+        loaded = AuditResult.model_validate_json(result.model_dump_json())
+
+        # Assert
+        assert Diagnostics.from_meta(loaded.meta) == Diagnostics(checks=RUNS)
+
+    @pytest.mark.parametrize(
+        "meta",
+        [{}, None, {"diagnostics": None}, {"diagnostics": {"checks": [{"id": "x"}]}}],
+    )
+    def test_missing_or_malformed(self, meta):
+        """Test older results without the section, and malformed ones, give None."""
+        assert Diagnostics.from_meta(meta) is None
+
+    def test_older_runs_without_counters(self):
+        """Test runs recorded before requests and bytes were metered load with zeros."""
+        meta = {"diagnostics": {"checks": [{"id": "links", "status": "ran", "duration_ms": 5.0}]}}
+
+        [run] = Diagnostics.from_meta(meta).checks
+
+        assert (run.requests, run.bytes_fetched, run.issues) == (0, 0, 0)
+
+    def test_totals_and_slowest(self):
+        """Test totals add up and the slowest checks come first, skipped ones left out."""
+        diagnostics = Diagnostics(checks=RUNS)
+
+        assert diagnostics.requests == 44
+        assert diagnostics.bytes_fetched == 141200
+        assert diagnostics.duration_ms == 2362.5
+        assert [c.id for c in diagnostics.slowest(3)] == ["timing", "links", "soft404"]
+
+    @pytest.mark.parametrize(
+        ("size", "text"), [(0, "0 B"), (512, "512 B"), (14540, "14.2 KB"), (3250586, "3.1 MB")]
+    )
+    def test_format_bytes(self, size, text):
+        """Test byte counts are shown in B, KB, or MB."""
+        assert format_bytes(size) == text
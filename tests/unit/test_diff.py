@@ -0,0 +1,120 @@
+"""
+Unit tests for comparing two audit results.
+"""
+import json
+import random
+import pytest
+
+from tinyseoai.data.diff import AuditDiff, diff
+from tinyseoai.data.models import AuditResult, Issue
+
+
+@pytest.mark.unit
+class TestDiff:
+    """Test new/resolved/persisting classification."""
+
+    def test_new_resolved_persisting(self, diff_base_result, diff_head_result):
+        """Test issues are classified by fingerprint."""
+        # Act
+        result = diff(diff_base_result, diff_head_result)
+
+        # Assert
+        assert [(i.type, i.url) for i in result.new] == [
+            ("title_missing", "https://example.com/blog"),
+            ("duplicate_title", "https://example.com/about"),
+            ("duplicate_title", "https://example.com/team"),
+        ]
+        assert sorted(i.type for i in result.resolved) == [
+            "broken_link",
+            "duplicate_title",
+            "duplicate_title",
+        ]
+        # Trailing slash difference does not make the canonical issue new
+        assert [i.type for i in result.persisting] == ["missing_canonical"]
+
+    def test_renamed_title_is_remove_plus_add(self, diff_base_result, diff_head_result):
+        """Test a changed duplicate title is reported as resolved + new."""
+        # Act
+        result = diff(diff_base_result, diff_head_result)
+
+        # Assert
+        assert {i.detail for i in result.resolved if i.type == "duplicate_title"} == {
+            "example company"
+        }
+        assert {i.detail for i in result.new if i.type == "duplicate_title"} == {
+            "example company - team"
+        }
+
+    def test_metrics_and_summary(self, diff_base_result, diff_head_result):
+        """Test deltas and per-severity counts."""
+        # Act
+        result = diff(diff_base_result, diff_head_result)
+
+        # Assert
+        assert result.metrics.pages_scanned == 1
+        assert result.metrics.issues == 0
+        assert result.metrics.health_score == -5.5
+        assert result.summary.new == {"high": 1, "medium": 0, "low": 2, "info": 0}
+        assert result.summary.resolved == {"high": 0, "medium": 1, "low": 2, "info": 0}
+
+    def test_page_changes(self, diff_base_result, diff_head_result):
+        """Test per-type affected page additions and removals."""
+        # Act
+        changes = {c.type: c for c in diff(diff_base_result, diff_head_result).page_changes}
+
+        # Assert
+        assert changes["title_missing"].added_pages == ["https://example.com/blog"]
+        assert changes["broken_link"].removed_pages == ["https://example.com/about"]
+        assert "duplicate_title" not in changes  # same pages, different detail
+        # Only the trailing slash differs, which still counts as a page change
+        assert changes["missing_canonical"].added_pages == ["https://example.com"]
+
+    def test_order_independent(self, diff_base_result, diff_head_result):
+        """Test shuffling issue lists does not change the diff."""
+        # Arrange
+        expected = diff(diff_base_result, diff_head_result).model_dump()
+        rng = random.Random(7)
+        shuffled_base = diff_base_result.model_copy(deep=True)
+        shuffled_head = diff_head_result.model_copy(deep=True)
+        rng.shuffle(shuffled_base.issues)
+        rng.shuffle(shuffled_head.issues)
+
+        # Act
+        actual = diff(shuffled_base, shuffled_head).model_dump()
+
+        # Assert
+        assert actual == expected
+
+    def test_duplicate_issues_matched_as_multiset(self):
+        """Test one of two identical issues resolving is detected."""
+        # Arrange
+        issue = Issue(url="https://example.com/", type="broken_link", detail="https://x.test/")
+        base = AuditResult(site="https://example.com/", pages_scanned=1, issues=[issue, issue], meta={})
+        head = AuditResult(site="https://example.com/", pages_scanned=1, issues=[issue], meta={})
+
+        # Act
+        result = diff(base, head)
+
+        # Assert
+        assert len(result.persisting) == 1
+        assert len(result.resolved) == 1
+
+    def test_identical_results(self, diff_base_result):
+        """Test comparing a result with itself yields no changes."""
+        # Act
+        result = diff(diff_base_result, diff_base_result)
+
+        # Assert
+        assert result.new == [] and result.resolved == [] and result.page_changes == []
+        assert result.metrics.health_score == 0
+
+    def test_serialization_round_trip(self, diff_base_result, diff_head_result):
+        """Test the diff survives JSON serialization unchanged."""
+        # Arrange
+        original = diff(diff_base_result, diff_head_result)
+
+        # Act
+        restored = AuditDiff.model_validate(json.loads(original.model_dump_json()))
+
+        # Assert
+        assert restored == original
@@ -0,0 +1,162 @@
+"""
+Unit tests for duplicate title and meta description detection.
+"""
+import time
+
+import pytest
+
+from tinyseoai.audit.checks.duplicates import (
+    DuplicateOptions,
+    PageMeta,
+    analyze,
+    is_paginated,
+    jaccard,
+    near_pairs,
+    trigrams,
+)
+
+
+def _page(path: str, title: str | None = None, description: str | None = None) -> PageMeta:
+    return PageMeta(url=f"https://example.com{path}", title=title, description=description)
+
+
+@pytest.mark.unit
+class TestExactDuplicates:
+    """Test clustering by normalized text."""
+
+    def test_clusters_after_normalizing(self):
+        """Test whitespace and case differences still cluster, one Issue per cluster."""
+        # Arrange
+        pages = [
+            _page("/a", "Handmade Mugs", "Wheel-thrown mugs."),
+            _page("/b", "  handmade   MUGS ", "Small-batch glazes."),
+            _page("/c", "Handmade mugs\n", "Wheel-thrown  mugs."),
+            _page("/d", "About the studio"),
+        ]
+
+        # Act
+        issues = analyze(pages, DuplicateOptions(near_threshold=None))
+
+        # Assert
+        assert [(i.type, i.url, i.detail) for i in issues] == [
+            (
+                "duplicate_title",
+                "https://example.com/a",
+                '3 pages share the title "Handmade Mugs": https://example.com/a, https://example.com/b, '
+                "https://example.com/c",
+            ),
+            (
+                "duplicate_meta_description",
+                "https://example.com/a",
+                '2 pages share the meta description "Wheel-thrown mugs.": https://example.com/a, '
+                "https://example.com/c",
+            ),
+        ]
+
+    def test_listing_capped(self):
+        """Test long clusters name max_listed pages and count the rest."""
+        # Act
+        issues = analyze([_page(f"/{n}", "Shop") for n in range(5)], DuplicateOptions(max_listed=2))
+
+        # Assert
+        assert issues[0].detail.endswith("https://example.com/0, https://example.com/1 and 3 more")
+
+    def test_exact_path_scales(self):
+        """Test 50k pages cluster in well under quadratic time."""
+        # Arrange
+        pages = [_page(f"/p/{n}", f"Category {n % 1000}", None) for n in range(50_000)]
+
+        # Act
+        started = time.monotonic()
+        issues = analyze(pages, DuplicateOptions(near_threshold=None))
+        elapsed = time.monotonic() - started
+
+        # Assert
+        assert len(issues) == 1000
+        assert all(i.detail.startswith("50 pages share") for i in issues)
+        assert elapsed < 10
+
+
+@pytest.mark.unit
+class TestPagination:
+    """Test paginated pages are left out, and the heuristic can be replaced."""
+
+    def test_paginated_titles_skipped(self):
+        """Test "page 2" titles and ?page= URLs are not reported."""
+        # Arrange
+        pages = [
+            _page("/blog", "Blog"),
+            _page("/blog?page=2", "Blog"),
+            _page("/blog/page/3", "Blog – Page 3"),
+            _page("/news", "News – page 2"),
+            _page("/news-2", "News – page 2"),
+        ]
+
+        # Act / Assert
+        assert analyze(pages) == []
+
+    def test_heuristic_overridable(self):
+        """Test a custom is_paginated replaces the default."""
+        # Arrange
+        pages = [_page("/blog?page=2", "Blog"), _page("/blog?page=3", "Blog")]
+
+        # Act
+        issues = analyze(pages, DuplicateOptions(is_paginated=lambda page: False))
+
+        # Assert
+        assert [i.type for i in issues] == ["duplicate_title"]
+
+    @pytest.mark.parametrize(
+        ("title", "url", "expected"),
+        [
+            ("Blog – page 2", "/blog", True),
+            ("Results 2 of 9", "/results", True),
+            ("Blog", "/blog/page/2/", True),
+            ("Top 10 mugs", "/top-mugs", False),
+            ("Pages we love", "/love", False),
+        ],
+    )
+    def test_is_paginated(self, title, url, expected):
+        """Test the default pagination heuristic."""
+        assert is_paginated(_page(url, title)) is expected
+
+
+@pytest.mark.unit
+class TestNearDuplicates:
+    """Test similarity clustering at lower severity."""
+
+    def test_near_duplicate_cluster(self):
+        """Test titles differing by a word cluster separately from exact matches."""
+        # Arrange
+        pages = [
+            _page("/blue", "Handmade ceramic coffee mugs in blue | Example Pottery"),
+            _page("/blue-2", "Handmade ceramic coffee mugs in blue | Example Pottery"),
+            _page("/green", "Handmade ceramic coffee mugs in green | Example Pottery"),
+            _page("/about", "About the studio | Example Pottery"),
+        ]
+
+        # Act
+        issues = analyze(pages)
+
+        # Assert
+        assert [(i.type, i.severity) for i in issues] == [("duplicate_title", "low"), ("near_duplicate_title", "info")]
+        assert issues[1].detail.startswith("3 pages have nearly identical titles")
+        assert issues[1].detail.endswith("https://example.com/blue, https://example.com/blue-2, https://example.com/green")
+
+    def test_near_pairs_matches_brute_force(self):
+        """Test prefix filtering finds exactly the pairs an all-pairs comparison would."""
+        # Arrange
+        texts = [f"{adj} ceramic mug {n}" for adj in ("blue", "green", "large blue") for n in range(12)]
+
+        # Act
+        found = sorted(near_pairs(texts, 0.7))
+
+        # Assert
+        expected = [
+            (i, j)
+            for j in range(len(texts))
+            for i in range(j)
+            if jaccard(trigrams(texts[i]), trigrams(texts[j])) >= 0.7
+        ]
+        assert found == sorted(expected)
+        assert found
@@ -0,0 +1,144 @@
+"""
+Unit tests for fix-effort estimates and their overrides.
+"""
+import json
+
+import pytest
+
+from tinyseoai.audit.checks_config import ChecksConfig
+from tinyseoai.data.demo import build_demo_result
+from tinyseoai.data.effort import BULK_PAGES, Effort, EffortOptions, annotate, efforts, estimate
+from tinyseoai.data.models import AuditResult, Issue
+from tinyseoai.exceptions import ConfigError
+
+
+def _pages(issue_type: str, count: int, severity: str = "medium") -> list[Issue]:
+    return [
+        Issue(url=f"https://example.com/p{n}", type=issue_type, severity=severity)
+        for n in range(count)
+    ]
+
+
+@pytest.mark.unit
+class TestRules:
+    """Test the rules table and the effort-score fallback."""
+
+    def test_demo(self):
+        """Test every demo issue type gets the expected effort."""
+        # Act
+        by_type = efforts(build_demo_result().issues)
+
+        # Assert
+        assert by_type == {
+            "missing_canonical": Effort.TRIVIAL,
+            "title_missing": Effort.TRIVIAL,
+            "meta_description_missing": Effort.TRIVIAL,
+            "img_alt_missing": Effort.TRIVIAL,
+            "broken_link": Effort.MODERATE,
+            "missing_hsts": Effort.TRIVIAL,
+            "noindex": Effort.MODERATE,
+        }
+
+    @pytest.mark.parametrize(
+        ("pages", "expected"),
+        [
+            (1, Effort.TRIVIAL),
+            (9, Effort.TRIVIAL),
+            (10, Effort.MODERATE),
+            (99, Effort.MODERATE),
+            (100, Effort.INVOLVED),
+        ],
+    )
+    def test_meta_tags_by_page_count(self, pages, expected):
+        """Test meta tag edits get harder with the number of pages."""
+        assert estimate(Issue(url="https://example.com/", type="title_too_long"), pages) is expected
+
+    def test_template_fix_any_page_count(self):
+        """Test a type fixed once in a template stays trivial on a million pages."""
+        issue = Issue(url="https://example.com/", type="meta_description_missing")
+        assert estimate(issue, 1_000_000) is Effort.TRIVIAL
+
+    def test_site_wide_performance(self):
+        """Test performance work across many pages is involved, on a few pages it is not."""
+        # Arrange
+        issue = Issue(url="https://example.com/", type="large_html_size")
+
+        # Act / Assert
+        assert estimate(issue, 10) is Effort.INVOLVED
+        assert estimate(issue, 2) is Effort.MODERATE
+
+    def test_bulk_fallback(self):
+        """Test types graded by effort score get one level harder on huge page counts."""
+        # Arrange
+        issue = Issue(url="https://example.com/", type="empty_anchor_text")
+
+        # Act / Assert
+        assert estimate(issue, BULK_PAGES - 1) is Effort.TRIVIAL
+        assert estimate(issue, BULK_PAGES) is Effort.MODERATE
+        assert estimate(issue.model_copy(update={"type": "thin_content"}), 10**7) is Effort.INVOLVED
+
+    def test_page_count_is_distinct_urls(self):
+        """Test several issues on one page count as one page."""
+        # Arrange
+        issues = [Issue(url="https://example.com/", type="title_too_long") for _ in range(50)]
+
+        # Act / Assert
+        assert efforts(issues)["title_too_long"] is Effort.TRIVIAL
+
+
+@pytest.mark.unit
+class TestOverrides:
+    """Test checks config overrides and efforts set by hand."""
+
+    def test_config_override(self):
+        """Test an exact type wins over a pattern, and both over the rules."""
+        # Arrange
+        options = EffortOptions(types={"title_*": "involved", "title_missing": "moderate"})
+
+        # Act
+        by_type = efforts(build_demo_result().issues + _pages("title_too_long", 1), options)
+
+        # Assert
+        assert by_type["title_missing"] is Effort.MODERATE
+        assert by_type["title_too_long"] is Effort.INVOLVED
+        assert by_type["img_alt_missing"] is Effort.TRIVIAL
+
+    def test_set_effort_takes_precedence(self):
+        """Test an effort set on an issue beats the config and the rules."""
+        # Arrange
+        issues = _pages("title_too_long", 3)
+        issues[1].effort = "involved"
+
+        # Act
+        annotate(issues, EffortOptions(types={"title_too_long": "moderate"}))
+
+        # Assert
+        assert [i.effort for i in issues] == ["involved", "involved", "involved"]
+
+    def test_annotate_round_trip(self):
+        """Test efforts are saved with the result and left out when unset."""
+        # Arrange
+        result = build_demo_result()
+        plain = json.loads(result.to_json())
+
+        # Act
+        annotate(result.issues)
+        saved = json.loads(result.to_json())
+
+        # Assert
+        assert "effort" not in plain["issues"][0]
+        assert saved["issues"][1]["effort"] == "trivial"
+        assert AuditResult(**saved).issues[1].effort == "trivial"
+
+    def test_checks_config_table(self):
+        """Test the [effort] table of checks.toml is parsed and validated."""
+        # Act
+        config = ChecksConfig.from_toml(
+            '[effort.types]\nbroken_link = "trivial"\n"missing_og_*" = "involved"\n'
+        )
+
+        # Assert
+        assert config.effort.override("broken_link") is Effort.TRIVIAL
+        assert config.effort.override("missing_og_tag") is Effort.INVOLVED
+        with pytest.raises(ConfigError, match="effort"):
+            ChecksConfig.from_toml('[effort.types]\nbroken_link = "easy"\n')
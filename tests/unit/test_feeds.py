@@ -0,0 +1,177 @@
+"""
+Unit tests for RSS and Atom feed checks.
+"""
+import asyncio
+from pathlib import Path
+
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.feeds import (  # noqa: E402
+    analyze,
+    feed_links,
+    looks_like_blog,
+    parse_feed,
+)
+
+FIXTURES = Path(__file__).parents[1] / "fixtures" / "feeds"
+URL = "https://pottery.example/"
+
+PAGE = """<!DOCTYPE html>
+<html><head>
+  <link rel="alternate" type="application/atom+xml" href="/blog/atom.xml" title="Journal">
+  <link rel="alternate" type="application/rss+xml; charset=utf-8" href="/blog/rss.xml">
+  <link rel="alternate" hreflang="de" href="/de/">
+</head><body><h1>Mugs</h1></body></html>"""
+
+
+def _read(name: str) -> bytes:
+    return (FIXTURES / name).read_bytes()
+
+
+ATOM = ("application/atom+xml", _read("atom.xml"))
+
+
+def _analyze(site, html: str = PAGE) -> list:
+    """Run the feed checks on html against an AssetSite (other paths are 404)."""
+    return asyncio.run(analyze(html, URL, site.client()))
+
+
+def _found(issues) -> list[tuple[str, str]]:
+    return sorted((i.url, i.type) for i in issues)
+
+
+@pytest.mark.unit
+class TestDiscovery:
+    """Test finding feeds and blog-like pages."""
+
+    def test_feed_links(self):
+        """Test RSS and Atom alternates are found; other alternates are not."""
+        assert feed_links(PAGE, URL) == [f"{URL}blog/atom.xml", f"{URL}blog/rss.xml"]
+
+    @pytest.mark.parametrize(
+        "html, url, expected",
+        [
+            ("<main><h1>Mugs</h1></main>", URL, False),
+            ("<article><h1>Celadon</h1></article>", URL, True),
+            ('<meta property="og:type" content="article">', URL, True),
+            ('<a href="/blog/celadon">Celadon</a>', URL, True),
+            ("<h1>Journal</h1>", f"{URL}blog", True),
+        ],
+    )
+    def test_looks_like_blog(self, html, url, expected):
+        assert looks_like_blog(html, url) is expected
+
+
+@pytest.mark.unit
+class TestParse:
+    """Test recognizing feeds and their entries."""
+
+    def test_atom(self):
+        """Test Atom entries are counted with updated or published dates."""
+        # Act
+        doc = parse_feed(_read("atom.xml"))
+
+        # Assert
+        assert (doc.kind, doc.entries, doc.bad_dates) == ("atom", 2, [])
+
+    def test_bad_dates(self):
+        """Test unparsable, epoch, and far-future dates are listed."""
+        # Arrange
+        items = "".join(
+            f"<item><pubDate>{d}</pubDate></item>"
+            for d in (
+                "Thu, 02 May 2024 09:30:00 GMT",
+                "yesterday",
+                "Thu, 01 Jan 1970 00:00:00 GMT",
+                "Fri, 01 Jan 2100 00:00:00 GMT",
+            )
+        )
+        body = f'<rss version="2.0"><channel>{items}</channel></rss>'.encode()
+
+        # Act
+        doc = parse_feed(body)
+
+        # Assert
+        assert doc.entries == 4
+        assert doc.bad_dates == [
+            "yesterday",
+            "Thu, 01 Jan 1970 00:00:00 GMT",
+            "Fri, 01 Jan 2100 00:00:00 GMT",
+        ]
+
+    @pytest.mark.parametrize(
+        "body, error",
+        [
+            (b'<rss version="0.91"><channel/></rss>', "RSS version 0.91, not 2.0"),
+            (b"<html><body>Feed</body></html>", "<html> is neither RSS 2.0 nor Atom"),
+        ],
+    )
+    def test_not_a_feed(self, body, error):
+        assert parse_feed(body).error == error
+
+
+@pytest.mark.unit
+class TestAnalyze:
+    """Test declared feeds against a local site."""
+
+    def test_valid_feed(self, asset_site):
+        """Test a valid Atom feed passes and a declared feed that 404s is broken."""
+        # Act
+        issues = _analyze(asset_site({"/blog/atom.xml": (*ATOM, set())}))
+
+        # Assert
+        assert _found(issues) == [(f"{URL}blog/rss.xml", "feed_broken")]
+        assert issues[0].detail == "Declared feed returns HTTP 404"
+
+    def test_truncated_and_empty(self, asset_site):
+        """Test cut-off XML is invalid and a feed without items is empty."""
+        # Arrange
+        site = asset_site(
+            {
+                "/blog/atom.xml": ("application/xml", _read("truncated.xml"), set()),
+                "/blog/rss.xml": ("application/rss+xml", _read("rss_empty.xml"), set()),
+            }
+        )
+
+        # Act
+        issues = _analyze(site)
+
+        # Assert
+        assert _found(issues) == [
+            (f"{URL}blog/atom.xml", "feed_invalid"),
+            (f"{URL}blog/rss.xml", "feed_empty"),
+        ]
+        assert "invalid XML" in issues[0].detail
+        assert issues[0].severity == "high"
+
+    def test_served_as_html(self, asset_site):
+        """Test a feed with an HTML content type is reported even when its XML is valid."""
+        # Arrange
+        site = asset_site(
+            {
+                "/blog/atom.xml": ("text/html; charset=utf-8", ATOM[1], set()),
+                "/blog/rss.xml": (*ATOM, set()),
+            }
+        )
+
+        # Act
+        issues = _analyze(site)
+
+        # Assert
+        assert _found(issues) == [(f"{URL}blog/atom.xml", "feed_served_as_html")]
+
+    def test_blog_without_feed(self, asset_site):
+        """Test a blog-like page declaring no feed is reported on the page."""
+        # Act
+        issues = _analyze(asset_site({}), "<article><h1>How we fire celadon</h1></article>")
+
+        # Assert
+        assert _found(issues) == [(URL, "feed_missing")]
+
+    def test_shop_without_feed(self, asset_site):
+        """Test pages that do not look like a blog need no feed."""
+        assert _analyze(asset_site({}), "<main><h1>Mugs</h1></main>") == []
+
@@ -0,0 +1,246 @@
+"""
+Unit tests for issue fingerprints.
+"""
+import pytest
+
+from tinyseoai.data.fingerprint import (
+    FINGERPRINT_VERSION,
+    LEGACY_VERSION,
+    FingerprintSet,
+    fingerprint,
+    fingerprint_version,
+    fingerprints,
+    normalize_text,
+    normalize_url,
+)
+from tinyseoai.data.models import Issue
+from tinyseoai.i18n import Locale, localize, msg
+
+# Pinned outputs: if these change, existing baselines stop matching.
+# Bump FINGERPRINT_VERSION instead of editing these values.
+PINNED = [
+    (Issue(url="https://example.com/", type="title_missing", severity="high"), "02ed761598a105e68c"),
+    (
+        Issue(
+            url="https://example.com/blog/post-1",
+            type="title_too_long",
+            severity="low",
+            detail="Title is 72 characters",
+        ),
+        "02c3c216475bb6f9e7",
+    ),
+    (
+        Issue(url="https://example.com/shop?b=2&a=1", type="missing_canonical", severity="medium"),
+        "02caf45ce49e853f9c",
+    ),
+    (
+        Issue(url="https://example.com/", type="thin_content", severity="low", detail="Only 120 words"),
+        "029b4a93215dd8065d",
+    ),
+]
+
+# The same issues under version 1, which hashed the detail
+PINNED_V1 = ["01ed761598a105e68c", "01c3c216475bb6f9e7", "01caf45ce49e853f9c", "019b4a93215dd8065d"]
+
+# Version 1 of URLs its normalizer kept as written: port, dot segments, space, non-ASCII
+PINNED_V1_URLS = [
+    ("https://Example.com:443/Blog", "0110220d088385b0f2"),
+    ("https://example.com/./a/../b", "01a3acad690b4e62e5"),
+    ("https://example.com/a b", "018f48fe3416ddc127"),
+    ("https://example.com/café", "01dcca0d3c94acb76c"),
+]
+
+# The same issues under the pre-versioning algorithm
+PINNED_LEGACY = ["7b0ca3db4af9930c", "7e4819de5ee1aa40", "58cea3c3efc44ea2", "37dc7b12cf68860b"]
+
+
+@pytest.mark.unit
+class TestPinnedFingerprints:
+    """Test fingerprints of fixture issues never change by accident."""
+
+    @pytest.mark.parametrize("issue,expected", PINNED)
+    def test_pinned(self, issue, expected):
+        """Test the current algorithm produces the pinned value."""
+        assert fingerprint(issue) == expected
+
+    @pytest.mark.parametrize("url,expected", PINNED_V1_URLS)
+    def test_pinned_v1_urls(self, url, expected):
+        """Test version 1 keeps its own URL normalization, not the current one."""
+        # Act
+        issue = Issue(url=url, type="title_missing", severity="high")
+
+        # Assert
+        assert fingerprints(issue)[1] == expected
+
+    def test_version_prefix(self):
+        """Test fingerprints start with the algorithm version byte."""
+        # Act
+        value = fingerprint(PINNED[0][0])
+
+        # Assert
+        assert value.startswith(f"{FINGERPRINT_VERSION:02x}")
+        assert fingerprint_version(value) == FINGERPRINT_VERSION
+        assert len(value) == 18
+
+
+def _title_issue(length: int = 72) -> Issue:
+    return Issue(
+        url="https://example.com/blog/post-1",
+        type="title_too_long",
+        severity="low",
+        **msg("meta.title_too_long", length, 60),
+    )
+
+
+@pytest.mark.unit
+class TestMessageFingerprints:
+    """Test issues with a catalog message are fingerprinted by message and arguments."""
+
+    def test_pinned(self):
+        """Test the message form produces the pinned value."""
+        assert fingerprint(_title_issue()) == "02590eadb05e7b2560"
+
+    def test_language_ignored(self):
+        """Test rendering the detail in another language keeps the fingerprint."""
+        # Arrange
+        issue = _title_issue()
+        spanish = issue.model_copy(deep=True)
+
+        # Act
+        localize([spanish], Locale.ES)
+
+        # Assert
+        assert spanish.detail != issue.detail
+        assert fingerprint(spanish) == fingerprint(issue)
+
+    def test_numbers_in_arguments_ignored(self):
+        """Test changing counts in the arguments keeps the fingerprint."""
+        assert fingerprint(_title_issue(75)) == fingerprint(_title_issue())
+
+    def test_older_versions_use_english_detail(self):
+        """Test version 1 and 0 fingerprints of a Spanish issue match those of the English one."""
+        # Arrange
+        english = _title_issue()
+        spanish = english.model_copy(deep=True)
+        localize([spanish], Locale.ES)
+        written_v1 = FingerprintSet.of([fingerprints(english)[1]])
+        written_v0 = FingerprintSet.of([fingerprints(english)[2]])
+
+        # Act / Assert
+        assert written_v1.match(spanish) == fingerprints(english)[1]
+        assert written_v0.match(spanish) == fingerprints(english)[2]
+
+
+@pytest.mark.unit
+class TestNormalization:
+    """Test volatile parts of an issue do not change its fingerprint."""
+
+    @pytest.mark.parametrize(
+        "variant",
+        [
+            "http://example.com/shop?a=1&b=2",
+            "https://EXAMPLE.com/shop/?b=2&a=1",
+            "https://www.example.com/shop?b=2&a=1#reviews",
+            "https://example.com/shop?b=2&utm_source=news&a=1&gclid=abc",
+        ],
+    )
+    def test_url_variants(self, variant):
+        """Test scheme, host case, www, slash, order, fragment, and tracking params are ignored."""
+        # Arrange
+        issue, expected = PINNED[2]
+
+        # Act / Assert
+        assert fingerprint(issue.model_copy(update={"url": variant})) == expected
+
+    def test_path_case_matters(self):
+        """Test paths stay case-sensitive."""
+        assert normalize_url("https://example.com/Shop") != normalize_url("https://example.com/shop")
+
+    def test_numbers_in_detail_ignored(self):
+        """Test changing counts in the detail keeps the fingerprint."""
+        # Arrange
+        issue, expected = PINNED[1]
+
+        # Act
+        value = fingerprint(issue.model_copy(update={"detail": "Title  is 75 characters"}))
+
+        # Assert
+        assert value == expected
+
+    def test_severity_ignored(self):
+        """Test re-rating an issue keeps its fingerprint."""
+        # Arrange
+        issue, expected = PINNED[0]
+
+        # Act / Assert
+        assert fingerprint(issue.model_copy(update={"severity": "low"})) == expected
+
+    def test_type_matters(self):
+        """Test different issue types never collide."""
+        # Arrange
+        issue, expected = PINNED[0]
+
+        # Act / Assert
+        assert fingerprint(issue.model_copy(update={"type": "title_too_long"})) != expected
+
+    def test_normalize_text(self):
+        """Test whitespace collapses and numbers become #."""
+        assert normalize_text("  5 pages,\n 1,200 words ") == "# pages, # words"
+
+
+@pytest.mark.unit
+class TestFingerprintSet:
+    """Test FingerprintSet membership and serialization."""
+
+    def test_membership(self):
+        """Test issues and strings are both accepted."""
+        # Arrange
+        issues = [issue for issue, _ in PINNED]
+
+        # Act
+        fps = FingerprintSet.of(issues[:2])
+
+        # Assert
+        assert issues[0] in fps
+        assert PINNED[1][1] in fps
+        assert issues[2] not in fps
+        assert len(fps) == 2
+
+    def test_legacy_fingerprints_match(self):
+        """Test sets of version 0 fingerprints still match issues."""
+        # Arrange
+        fps = FingerprintSet.of(PINNED_LEGACY)
+
+        # Act / Assert
+        for (issue, _), legacy in zip(PINNED, PINNED_LEGACY, strict=True):
+            assert fps.match(issue) == legacy
+        assert fingerprint_version(PINNED_LEGACY[0]) == LEGACY_VERSION
+        assert fps.legacy() == sorted(PINNED_LEGACY)
+
+    def test_version_1_fingerprints_match(self):
+        """Test sets written before version 2 still match issues."""
+        # Arrange
+        fps = FingerprintSet.of(PINNED_V1)
+
+        # Act / Assert
+        for (issue, _), written in zip(PINNED, PINNED_V1, strict=True):
+            assert fps.match(issue) == written
+        assert fps.legacy() == sorted(PINNED_V1)
+
+    def test_serialized_sorted(self):
+        """Test serialization is a sorted JSON list that round-trips."""
+        # Arrange
+        fps = FingerprintSet.of([v for _, v in PINNED])
+
+        # Act
+        dumped = fps.model_dump_json()
+        loaded = FingerprintSet.model_validate_json(dumped)
+
+        # Assert
+        assert dumped == '["029b4a93215dd8065d","02c3c216475bb6f9e7","02caf45ce49e853f9c","02ed761598a105e68c"]'
+        assert set(loaded) == set(fps)
+
+    def test_invalid_fingerprint_version(self):
+        """Test non-fingerprints are rejected by fingerprint_version."""
+        with pytest.raises(ValueError):
+            fingerprint_version("not-a-fingerprint")
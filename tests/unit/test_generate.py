@@ -0,0 +1,160 @@
+"""
+Unit tests for the sitemap suggested from the crawl.
+"""
+import xml.etree.ElementTree as ET
+from pathlib import Path
+
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.crawler import FetchedPage  # noqa: E402
+from tinyseoai.audit.generate import (  # noqa: E402
+    SitemapGenOptions,
+    indexable_entries,
+    sitemap,
+    write_sitemap,
+)
+
+SCHEMAS = Path(__file__).parents[1] / "fixtures" / "schemas"
+NS = {"sm": "http://www.sitemaps.org/schemas/sitemap/0.9"}
+HTML = "<html><head><title>Page</title></head><body><p>Text</p></body></html>"
+
+
+def _page(path: str, status: int = 200, headers: dict | None = None, **fields) -> FetchedPage:
+    return FetchedPage(
+        url=f"https://example.com{path}",
+        status=status,
+        headers=headers or {},
+        body=fields.pop("body", HTML),
+        content_type=fields.pop("content_type", "text/html"),
+        **fields,
+    )
+
+
+def _locs(xml: str) -> list[str]:
+    return [loc.text for loc in ET.fromstring(xml).findall(".//sm:loc", NS)]
+
+
+def _assert_valid(xml: str, xsd: str) -> None:
+    etree = pytest.importorskip("lxml.etree")
+    schema = etree.XMLSchema(etree.parse(str(SCHEMAS / xsd)))
+    schema.assertValid(etree.fromstring(xml.encode("utf-8")))
+
+
+@pytest.mark.unit
+class TestIndexablePages:
+    """Test which crawled pages go into the sitemap."""
+
+    def test_excludes_unindexable(self):
+        """Test noindex, redirected, error, and non-HTML pages are left out."""
+        # Arrange
+        pages = [
+            _page("/"),
+            _page("/private", body='<html><head><meta name="robots" content="noindex"></head>'),
+            _page("/header-noindex", headers={"X-Robots-Tag": "noindex"}),
+            _page("/old", final_url="https://example.com/new"),
+            _page("/missing", status=404),
+            _page("/report.pdf", body="", content_type="application/pdf"),
+            _page("/about"),
+        ]
+
+        # Act
+        entries = indexable_entries(pages)
+
+        # Assert
+        assert [e.loc for e in entries] == ["https://example.com/", "https://example.com/about"]
+
+    def test_distinct_urls(self):
+        """Test the same page reached twice is listed once, under its first URL."""
+        # Act
+        entries = indexable_entries([_page("/about"), _page("/about#team"), _page("/about")])
+
+        # Assert
+        assert [e.loc for e in entries] == ["https://example.com/about"]
+
+    def test_lastmod_from_header(self):
+        """Test Last-Modified becomes a UTC W3C datetime; unreadable values are dropped."""
+        # Arrange
+        pages = [
+            _page("/", headers={"last-modified": "Wed, 21 Oct 2026 07:28:00 GMT"}),
+            _page("/a", headers={"Last-Modified": "yesterday"}),
+        ]
+
+        # Act
+        entries = indexable_entries(pages)
+
+        # Assert
+        assert entries[0].lastmod == "2026-10-21T07:28:00+00:00"
+        assert entries[1].lastmod is None
+
+    def test_nothing_indexable(self):
+        """Test no file is suggested when no page belongs in a sitemap."""
+        assert sitemap([_page("/missing", status=404)]) == {}
+
+
+@pytest.mark.unit
+class TestSitemapFiles:
+    """Test the files are valid sitemaps and split at the protocol limits."""
+
+    def test_single_file_validates(self):
+        """Test a small crawl gives one urlset valid against the sitemaps.org schema."""
+        # Arrange
+        pages = [
+            _page("/", headers={"Last-Modified": "Wed, 21 Oct 2026 07:28:00 GMT"}),
+            _page("/search?q=a&page=2"),
+        ]
+
+        # Act
+        files = sitemap(pages)
+
+        # Assert
+        assert list(files) == ["sitemap.xml"]
+        _assert_valid(files["sitemap.xml"], "sitemap-0.9.xsd")
+        assert "&amp;page=2" in files["sitemap.xml"]
+        assert _locs(files["sitemap.xml"])[1] == "https://example.com/search?q=a&page=2"
+
+    def test_split_by_url_count(self):
+        """Test entries past max_urls go to further files listed in a valid index."""
+        # Arrange
+        pages = [_page(f"/p{n}") for n in range(5)]
+        pages[3].headers["Last-Modified"] = "Mon, 05 Oct 2026 10:00:00 GMT"
+
+        # Act
+        files = sitemap(pages, SitemapGenOptions(max_urls=2))
+
+        # Assert
+        assert list(files) == ["sitemap.xml", "sitemap-1.xml", "sitemap-2.xml", "sitemap-3.xml"]
+        _assert_valid(files["sitemap.xml"], "siteindex-0.9.xsd")
+        for name in ("sitemap-1.xml", "sitemap-2.xml", "sitemap-3.xml"):
+            _assert_valid(files[name], "sitemap-0.9.xsd")
+        assert _locs(files["sitemap.xml"])[0] == "https://example.com/sitemap-1.xml"
+        listed = [loc for name in list(files)[1:] for loc in _locs(files[name])]
+        assert listed == [p.url for p in pages]
+        assert files["sitemap.xml"].count("<lastmod>2026-10-05T10:00:00+00:00</lastmod>") == 1
+
+    def test_split_by_size(self):
+        """Test no file grows past max_bytes."""
+        # Arrange
+        pages = [_page(f"/{'x' * 200}/{n}") for n in range(30)]
+        options = SitemapGenOptions(max_bytes=2048, base_url="https://cdn.example.com/maps")
+
+        # Act
+        files = sitemap(pages, options)
+
+        # Assert
+        assert len(files) > 2
+        parts = [xml for name, xml in files.items() if name != "sitemap.xml"]
+        assert all(len(xml.encode("utf-8")) <= 2048 for xml in parts)
+        assert sum(len(_locs(xml)) for xml in parts) == 30
+        assert _locs(files["sitemap.xml"])[0] == "https://cdn.example.com/maps/sitemap-1.xml"
+
+    def test_write(self, tmp_path):
+        """Test the files are written to a new folder under their names."""
+        # Act
+        paths = write_sitemap(sitemap([_page("/")]), tmp_path / "suggested")
+
+        # Assert
+        assert paths == [tmp_path / "suggested" / "sitemap.xml"]
+        assert _locs(paths[0].read_text(encoding="utf-8")) == ["https://example.com/"]
@@ -0,0 +1,173 @@
+"""
+Unit tests for filing findings as GitHub issues.
+"""
+import json
+
+import httpx
+import pytest
+
+from tinyseoai.data.fingerprint import fingerprint, fingerprints
+from tinyseoai.data.models import Issue
+from tinyseoai.exceptions import IntegrationError
+from tinyseoai.integrations.github_issues import (
+    GitHubConfig,
+    GitHubIssues,
+    find_marker,
+    issue_labels,
+    marker,
+    issue_payload,
+    select_issues,
+)
+
+
+def _issue(page: str = "a", severity: str = "high", issue_type: str = "title_missing") -> Issue:
+    return Issue(url=f"https://example.com/{page}", type=issue_type, severity=severity)
+
+
+class _FakeGitHub:
+    """In-memory stand-in for the GitHub issues API."""
+
+    def __init__(self, existing: list[dict] | None = None, fail_titles: tuple[str, ...] = (), limit: int | None = None):
+        self.issues = {item["number"]: item for item in existing or []}
+        self.fail_titles = fail_titles
+        self.limit = limit  # writes allowed before the rate limit hits
+        self.calls: list[tuple[str, str]] = []
+
+    def __call__(self, request: httpx.Request) -> httpx.Response:
+        self.calls.append((request.method, request.url.path))
+        if request.method == "GET":
+            return httpx.Response(200, json=list(self.issues.values()))
+        if self.limit is not None:
+            if self.limit == 0:
+                return httpx.Response(403, headers={"x-ratelimit-remaining": "0"}, json={"message": "rate limit"})
+            self.limit -= 1
+        payload = json.loads(request.content)
+        if any(t in payload["title"] for t in self.fail_titles):
+            return httpx.Response(422, json={"message": "Validation Failed"})
+        if request.method == "POST":
+            number = len(self.issues) + 1
+        else:
+            number = int(request.url.path.rsplit("/", 1)[1])
+        item = {**self.issues.get(number, {}), **payload, "number": number, "html_url": f"https://gh/{number}"}
+        self.issues[number] = item
+        return httpx.Response(201 if request.method == "POST" else 200, json=item)
+
+    def filer(self) -> GitHubIssues:
+        client = httpx.AsyncClient(transport=httpx.MockTransport(self))
+        return GitHubIssues(GitHubConfig(repo="acme/site", token="t0ken"), client=client)
+
+
+@pytest.mark.unit
+class TestPayload:
+    """Test issue title, body, and labels."""
+
+    def test_payload(self):
+        """Test the body carries the rendered finding and a fingerprint marker."""
+        # Arrange
+        issue = _issue()
+
+        # Act
+        payload = issue_payload(issue, extra_labels=["seo"])
+
+        # Assert
+        assert payload["title"] == "SEO: Title missing — https://example.com/a"
+        assert "### Title missing" in payload["body"]
+        assert find_marker(payload["body"]) == fingerprint(issue)
+        assert payload["labels"] == ["tinyseoai", "severity:high", "seo:content", "seo"]
+
+    def test_unknown_severity_label(self):
+        """Test unknown severities get their own label."""
+        assert issue_labels(_issue(severity="weird"))[1] == "severity:unknown"
+
+    def test_select_issues(self):
+        """Test selection filters by severity and drops duplicate fingerprints."""
+        # Arrange
+        issues = [_issue("a"), _issue("a"), _issue("b", severity="low"), _issue("c", severity="medium")]
+
+        # Act / Assert
+        assert [i.url for i in select_issues(issues)] == ["https://example.com/a"]
+        assert len(select_issues(issues, "medium")) == 2
+
+
+@pytest.mark.unit
+class TestFiling:
+    """Test creating, updating, and per-finding failures."""
+
+    @pytest.mark.asyncio
+    async def test_creates_issues(self):
+        """Test new findings open new issues."""
+        # Arrange
+        github = _FakeGitHub()
+
+        # Act
+        report = await github.filer().file([_issue("a"), _issue("b")])
+
+        # Assert
+        assert [r.action for r in report.results] == ["created", "created"]
+        assert [r.number for r in report.results] == [1, 2]
+        assert report.ok
+
+    @pytest.mark.asyncio
+    async def test_rerun_updates(self):
+        """Test filing the same finding again updates and reopens the existing issue."""
+        # Arrange
+        issue = _issue("a")
+        existing = {"number": 7, "state": "closed", **issue_payload(issue)}
+        github = _FakeGitHub(existing=[existing])
+
+        # Act
+        report = await github.filer().file([issue])
+
+        # Assert
+        assert report.results[0].action == "updated"
+        assert report.results[0].number == 7
+        assert ("PATCH", "/repos/acme/site/issues/7") in github.calls
+        assert github.issues[7]["state"] == "open"
+
+    @pytest.mark.asyncio
+    async def test_rerun_matches_older_marker(self):
+        """Test an issue filed under an older fingerprint version is updated, not filed again."""
+        # Arrange
+        issue = _issue("a")
+        payload = issue_payload(issue)
+        payload["body"] = payload["body"].replace(marker(fingerprint(issue)), marker(fingerprints(issue)[1]))
+        github = _FakeGitHub(existing=[{"number": 7, "state": "open", **payload}])
+
+        # Act
+        report = await github.filer().file([issue])
+
+        # Assert
+        assert report.results[0].action == "updated"
+        assert find_marker(github.issues[7]["body"]) == fingerprint(issue)
+
+    @pytest.mark.asyncio
+    async def test_partial_failure(self):
+        """Test one rejected finding does not stop the others."""
+        # Arrange
+        github = _FakeGitHub(fail_titles=("/b",))
+
+        # Act
+        report = await github.filer().file([_issue("a"), _issue("b"), _issue("c")])
+
+        # Assert
+        assert [r.action for r in report.results] == ["created", "failed", "created"]
+        assert "Validation Failed" in report.results[1].error
+        assert not report.ok
+
+    @pytest.mark.asyncio
+    async def test_rate_limit(self):
+        """Test findings after the rate limit are reported, not sent."""
+        # Arrange
+        github = _FakeGitHub(limit=1)
+
+        # Act
+        report = await github.filer().file([_issue("a"), _issue("b"), _issue("c")])
+
+        # Assert
+        assert [r.action for r in report.results] == ["created", "rate_limited", "rate_limited"]
+        assert len([c for c in github.calls if c[0] == "POST"]) == 2
+
+    def test_bad_repo(self):
+        """Test repositories must be owner/name."""
+        with pytest.raises(IntegrationError):
+            GitHubIssues(GitHubConfig(repo="site", token="t"))
@@ -0,0 +1,139 @@
+"""
+Unit tests for security response header checks.
+"""
+import httpx
+import pytest
+
+from tinyseoai.audit.checks.headers import analyze, grade, parse_csp
+
+URL = "https://example.com/"
+
+GOOD = {
+    "Strict-Transport-Security": "max-age=63072000; includeSubDomains; preload",
+    "X-Content-Type-Options": "nosniff",
+    "Content-Security-Policy": "default-src 'self'; script-src 'self' 'nonce-abc123'; frame-ancestors 'none'",
+    "Referrer-Policy": "strict-origin-when-cross-origin",
+    "Permissions-Policy": "camera=(), microphone=()",
+}
+
+PARTIAL = {
+    "strict-transport-security": "max-age=3600",
+    "x-content-type-options": "nosniff",
+    "X-FRAME-OPTIONS": "SAMEORIGIN",
+    "content-security-policy": "default-src 'self'; script-src 'self' 'unsafe-inline'",
+    "referrer-policy": "strict-origin-when-cross-origin",
+}
+
+TERRIBLE = {
+    "Server": "Apache/2.2.3",
+    "X-Frame-Options": "ALLOW-FROM https://partner.example.org",
+    "Referrer-Policy": "unsafe-url",
+    "Feature-Policy": "camera 'none'",
+}
+
+
+def _types(issues) -> list[str]:
+    return sorted(i.type for i in issues)
+
+
+def _grade(issues) -> str:
+    return next(i.detail for i in issues if i.type == "security_headers_grade")
+
+
+@pytest.mark.unit
+class TestAnalyze:
+    """Test good, partial, and terrible header sets."""
+
+    def test_good_headers(self):
+        """Test a complete, strict header set has no findings."""
+        assert analyze(GOOD, URL) == []
+
+    def test_partial_headers(self):
+        """Test a short HSTS max-age, inline scripts, and a missing Permissions-Policy."""
+        # Act
+        issues = analyze(PARTIAL, URL)
+
+        # Assert
+        assert _types(issues) == [
+            "csp_unsafe_inline",
+            "hsts_short_max_age",
+            "missing_permissions_policy",
+            "security_headers_grade",
+        ]
+        assert _grade(issues) == (
+            "Security headers grade D (3/6 checks pass); "
+            "needs attention: Strict-Transport-Security, Content-Security-Policy, Permissions-Policy"
+        )
+
+    def test_terrible_headers(self):
+        """Test every gap is reported with a header line to paste."""
+        # Act
+        issues = analyze(TERRIBLE, URL)
+
+        # Assert
+        assert _types(issues) == [
+            "invalid_x_frame_options",
+            "missing_csp",
+            "missing_hsts",
+            "missing_permissions_policy",
+            "missing_x_content_type_options",
+            "referrer_policy_unsafe",
+            "security_headers_grade",
+        ]
+        details = {i.type: i.detail for i in issues}
+        assert details["missing_hsts"].endswith(
+            "Recommended: Strict-Transport-Security: max-age=31536000; includeSubDomains"
+        )
+        assert details["missing_permissions_policy"].startswith("Only the deprecated Feature-Policy header is set")
+        assert _grade(issues).startswith("Security headers grade F (0/6 checks pass)")
+
+    def test_http_skips_hsts(self):
+        """Test HSTS is only expected on HTTPS pages."""
+        # Act
+        issues = analyze({k: v for k, v in GOOD.items() if k != "Strict-Transport-Security"}, "http://example.com/")
+
+        # Assert
+        assert issues == []
+
+
+@pytest.mark.unit
+class TestParsing:
+    """Test case-insensitive and repeated headers."""
+
+    def test_duplicate_headers(self):
+        """Test repeated CSP headers all apply and the first HSTS header wins."""
+        # Arrange
+        headers = httpx.Headers(
+            [
+                ("strict-transport-security", "max-age=63072000; includeSubDomains"),
+                ("Strict-Transport-Security", "max-age=0"),
+                ("X-Content-Type-Options", "nosniff, nosniff"),
+                ("Content-Security-Policy", "script-src 'self' 'unsafe-inline'"),
+                ("Content-Security-Policy", "default-src 'self'; frame-ancestors 'self'"),
+                ("Referrer-Policy", "unsafe-url, strict-origin-when-cross-origin"),
+                ("Permissions-Policy", "geolocation=()"),
+            ]
+        )
+
+        # Act / Assert
+        assert analyze(headers, URL) == []
+
+    def test_conflicting_frame_options(self):
+        """Test conflicting X-Frame-Options values are reported as ignored."""
+        # Act
+        issues = analyze([("X-Frame-Options", "DENY"), ("x-frame-options", "SAMEORIGIN")], URL)
+
+        # Assert
+        assert "invalid_x_frame_options" in _types(issues)
+
+    def test_parse_csp(self):
+        """Test directives are lowercased and the first occurrence wins."""
+        assert parse_csp("Script-Src 'self'; script-src *; ;img-src data:") == {
+            "script-src": ["'self'"],
+            "img-src": ["data:"],
+        }
+
+    @pytest.mark.parametrize(("passed", "letter"), [(6, "A"), (5, "B"), (4, "C"), (3, "D"), (1, "F")])
+    def test_grade(self, passed, letter):
+        """Test grade boundaries."""
+        assert grade(passed, 6) == letter
@@ -0,0 +1,81 @@
+"""
+Unit tests for heading hierarchy checks.
+"""
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.headings import HeadingOptions, analyze  # noqa: E402
+
+URL = "https://example.com/mugs"
+
+
+def _by_type(issues) -> dict[str, str]:
+    return {i.type: i.detail for i in issues}
+
+
+@pytest.mark.unit
+class TestAnalyze:
+    """Test one fixture per defect and a clean page."""
+
+    def test_correct_page(self, html_fixture):
+        """Test nested headings, image alt text, and template headings pass."""
+        assert analyze(html_fixture("headings_good.html"), URL) == []
+
+    def test_missing_h1(self, html_fixture):
+        """Test an H1 inside <noscript> does not count."""
+        # Act
+        issues = _by_type(analyze(html_fixture("headings_missing_h1.html"), URL))
+
+        # Assert
+        assert issues == {"h1_missing": 'No <h1> heading found. Outline: H2 "Glazes"'}
+
+    def test_multiple_h1(self, html_fixture):
+        """Test repeated H1s are reported with the outline."""
+        # Act
+        issues = _by_type(analyze(html_fixture("headings_multiple_h1.html"), URL))
+
+        # Assert
+        assert issues == {
+            "multiple_h1": '2 <h1> headings found; use one per page. Outline: H1 "Example Pottery Studio" > '
+            'H1 "Handmade ceramic mugs" > H2 "Glazes"'
+        }
+
+    def test_skipped_level(self, html_fixture):
+        """Test an H2 followed by an H4 is a skip, but going back up is not."""
+        # Act
+        issues = _by_type(analyze(html_fixture("headings_skipped.html"), URL))
+
+        # Assert
+        assert list(issues) == ["heading_level_skipped"]
+        assert issues["heading_level_skipped"].startswith('1 headings skip a level: H2 "Glazes" -> H4 "Care". ')
+
+    def test_empty_and_image_headings(self, html_fixture):
+        """Test empty headings and image headings without alt text are reported separately."""
+        # Act
+        issues = _by_type(analyze(html_fixture("headings_empty.html"), URL))
+
+        # Assert
+        assert issues == {
+            "heading_empty": "2 headings have no text: H2, H3",
+            "heading_image_without_alt": "2 headings are images without alt text, so they have no text: H1, H2",
+        }
+
+    def test_long_h1_and_aria_heading(self, html_fixture):
+        """Test overlong H1s and visible role="heading" elements."""
+        # Act
+        issues = analyze(html_fixture("headings_long_h1.html"), URL)
+
+        # Assert
+        assert [(i.type, i.severity) for i in issues] == [("h1_too_long", "low"), ("aria_heading", "info")]
+        assert issues[0].detail.startswith("<h1> is 97 characters (max 70)")
+        assert issues[1].detail.endswith('outline: "Shipping"')
+
+    def test_h1_length_option(self, html_fixture):
+        """Test the H1 length limit is configurable."""
+        # Act
+        issues = analyze(html_fixture("headings_long_h1.html"), URL, HeadingOptions(h1_max_length=120))
+
+        # Assert
+        assert "h1_too_long" not in _by_type(issues)
@@ -0,0 +1,509 @@
+"""
+Unit tests for the SQLite history store.
+"""
+import asyncio
+import os
+import shutil
+import sqlite3
+import threading
+import zlib
+
+import pytest
+
+from tinyseoai.data.comparison import compare_sites
+from tinyseoai.data.demo import build_demo_result
+from tinyseoai.data.diff import diff
+from tinyseoai.data.models import FORMAT_VERSION, AuditResult
+from tinyseoai.exceptions import StoreError
+from tinyseoai.store.history import (
+    _MIGRATIONS,
+    SCHEMA_VERSION,
+    AsyncHistoryStore,
+    HistoryFilter,
+    HistoryStore,
+    PortfolioFilter,
+)
+
+
+def _result(site: str = "https://example.com/", score: float = 80, timestamp: str = "2025-01-01T00:00:00Z"):
+    return AuditResult(
+        site=site,
+        pages_scanned=3,
+        issues=[{"url": site, "type": "title_missing", "severity": "high"}],
+        meta={"health_score": score, "timestamp": timestamp},
+    )
+
+
+@pytest.fixture
+def store(tmp_path):
+    """Provide a history store in a temporary file."""
+    with HistoryStore(tmp_path / "history.sqlite3") as s:
+        yield s
+
+
+@pytest.mark.unit
+class TestMigrations:
+    """Test opening databases at each schema version."""
+
+    def test_empty_database(self, tmp_path):
+        """Test a new file is created at the latest version."""
+        # Act
+        with HistoryStore(tmp_path / "new.sqlite3") as store:
+            # Assert
+            assert store.schema_version == SCHEMA_VERSION
+            assert store.list() == []
+
+    def test_v1_database(self, tmp_path):
+        """Test a v1 database is migrated and its summary columns backfilled."""
+        # Arrange
+        path = tmp_path / "v1.sqlite3"
+        conn = sqlite3.connect(path)
+        for statement in filter(str.strip, _MIGRATIONS[1].split(";")):
+            conn.execute(statement)
+        conn.execute("PRAGMA user_version = 1")
+        result = _result(score=64)
+        conn.execute(
+            "INSERT INTO audits (site, host, created_at, pages_scanned, issues, blob) VALUES (?, ?, ?, ?, ?, ?)",
+            (result.site, "example.com", "2025-01-01", 3, 1, zlib.compress(result.model_dump_json().encode())),
+        )
+        conn.commit()
+        conn.close()
+
+        # Act
+        with HistoryStore(path) as store:
+            # Assert
+            assert store.schema_version == SCHEMA_VERSION
+            [summary] = store.list()
+            assert (summary.health_score, summary.high) == (64, 1)
+            assert store.get(summary.id) == result
+
+    def test_v2_database(self, tmp_path):
+        """Test a v2 database gets fingerprints backfilled for the portfolio."""
+        # Arrange
+        path = tmp_path / "v2.sqlite3"
+        conn = sqlite3.connect(path)
+        for version in (1, 2):
+            for statement in filter(str.strip, _MIGRATIONS[version].split(";")):
+                conn.execute(statement)
+        conn.execute("PRAGMA user_version = 2")
+        for timestamp, issues in (("2025-01-01", []), ("2025-02-01", _result().issues)):
+            result = AuditResult(site="https://example.com/", pages_scanned=3, issues=issues, meta={})
+            conn.execute(
+                "INSERT INTO audits (site, host, created_at, pages_scanned, issues, blob, high) "
+                "VALUES (?, ?, ?, ?, ?, ?, ?)",
+                (result.site, "example.com", timestamp, 3, len(issues), zlib.compress(result.model_dump_json().encode()), len(issues)),
+            )
+        conn.commit()
+        conn.close()
+
+        # Act
+        with HistoryStore(path) as store:
+            # Assert
+            assert store.schema_version == SCHEMA_VERSION
+            assert store._conn.execute("SELECT COUNT(*) FROM audits WHERE fingerprints IS NULL").fetchone()[0] == 0
+            assert store._conn.execute("SELECT COUNT(*) FROM audits WHERE content_hash IS NULL").fetchone()[0] == 0
+            [entry] = store.portfolio()
+            assert entry.new_high == 1
+
+    def test_newer_database_rejected(self, tmp_path):
+        """Test databases from a newer version are refused."""
+        # Arrange
+        path = tmp_path / "future.sqlite3"
+        conn = sqlite3.connect(path)
+        conn.execute(f"PRAGMA user_version = {SCHEMA_VERSION + 1}")
+        conn.close()
+
+        # Act / Assert
+        with pytest.raises(StoreError):
+            HistoryStore(path)
+
+
+@pytest.mark.unit
+class TestHistoryStore:
+    """Test inserting, listing, loading, and deleting audits."""
+
+    def test_round_trip(self, store):
+        """Test a stored result loads back unchanged."""
+        # Arrange
+        result = build_demo_result()
+
+        # Act
+        audit_id = store.insert(result, label="demo")
+
+        # Assert
+        assert store.get(audit_id) == result
+        assert store.list()[0].label == "demo"
+
+    def test_list_filter(self, store):
+        """Test listing by host, date, and limit, newest first."""
+        # Arrange
+        store.insert(_result(timestamp="2025-01-01T00:00:00Z"))
+        store.insert(_result(timestamp="2025-02-01T00:00:00Z"))
+        store.insert(_result(site="https://other.com/", timestamp="2025-03-01T00:00:00Z"))
+
+        # Act
+        by_host = store.list(HistoryFilter(host="https://example.com/"))
+        recent = store.list(HistoryFilter(since="2025-01-15"))
+        latest = store.list(HistoryFilter(limit=1))
+
+        # Assert
+        assert [s.created_at for s in by_host] == ["2025-02-01T00:00:00Z", "2025-01-01T00:00:00Z"]
+        assert len(recent) == 2
+        assert latest[0].host == "other.com"
+
+    def test_delete(self, store):
+        """Test deleting removes the entry and reports missing ones."""
+        # Arrange
+        audit_id = store.insert(_result())
+
+        # Act / Assert
+        assert store.delete(audit_id) is True
+        assert store.delete(audit_id) is False
+        with pytest.raises(StoreError):
+            store.get(audit_id)
+
+    def test_trend(self, store):
+        """Test the trend lists scores oldest first for one host."""
+        # Arrange
+        store.insert(_result(score=70, timestamp="2025-02-01T00:00:00Z"))
+        store.insert(_result(score=60, timestamp="2025-01-01T00:00:00Z"))
+        store.insert(_result(site="https://other.com/", score=10))
+
+        # Act
+        points = store.trend("example.com")
+
+        # Assert
+        assert [p.health_score for p in points] == [60, 70]
+
+    def test_corrupted_blob_skipped(self, store):
+        """Test corrupted entries are skipped when loading many, and raise when loaded alone."""
+        # Arrange
+        good = store.insert(_result())
+        bad = store.insert(_result())
+        store._conn.execute("UPDATE audits SET blob = ? WHERE id = ?", (b"not zlib", bad))
+        store._conn.commit()
+
+        # Act
+        loaded = store.results()
+
+        # Assert
+        assert [audit_id for audit_id, _ in loaded] == [good]
+        assert len(store.list()) == 2
+        with pytest.raises(StoreError):
+            store.get(bad)
+
+    def test_old_format_rows_migrated(self, store, results_fixtures_dir):
+        """Test rows saved before format_version existed load in the current format."""
+        # Arrange
+        audit_id = store.insert(_result())
+        old = zlib.compress((results_fixtures_dir / "registry_checks_v0.json").read_bytes())
+        store._conn.execute("UPDATE audits SET blob = ? WHERE id = ?", (old, audit_id))
+        store._conn.commit()
+
+        # Act
+        result = store.get(audit_id)
+
+        # Assert
+        assert result.format_version == FORMAT_VERSION
+        assert "checks" not in result.meta
+        assert len(result.meta["diagnostics"]["checks"]) == 12
+
+    def test_newer_format_rows_skipped(self, store):
+        """Test rows written by a newer version are skipped rather than misread."""
+        # Arrange
+        good = store.insert(_result())
+        newer = store.insert(_result())
+        blob = zlib.compress(b'{"format_version": 99, "site": "https://example.com/"}')
+        store._conn.execute("UPDATE audits SET blob = ? WHERE id = ?", (blob, newer))
+        store._conn.commit()
+
+        # Act & Assert
+        assert [audit_id for audit_id, _ in store.results()] == [good]
+        with pytest.raises(StoreError):
+            store.get(newer)
+
+
+def _issue(path: str, severity: str = "high", issue_type: str = "title_missing") -> dict:
+    return {"url": f"https://example.com{path}", "type": issue_type, "severity": severity}
+
+
+@pytest.fixture
+def portfolio(store):
+    """Seed the store with several hosts, some audited more than once."""
+    for site, score, timestamp, issues in [
+        ("https://example.com/", 80, "2025-01-01T00:00:00Z", [_issue("/a")]),
+        ("https://example.com/", 65, "2025-02-01T00:00:00Z", [_issue("/a"), _issue("/b"), _issue("/c")]),
+        ("https://shop.example/", 50, "2025-01-10T00:00:00Z", [_issue("/x", "medium")]),
+        ("https://shop.example/", 55, "2025-02-10T00:00:00Z", [_issue("/x", "high")]),
+        ("https://blog.example/", 90, "2025-02-05T00:00:00Z", []),
+        ("https://new.example/", None, "2025-02-20T00:00:00Z", [_issue("/")]),
+    ]:
+        store.insert(AuditResult(site=site, pages_scanned=3, issues=issues, meta={"health_score": score, "timestamp": timestamp}))
+    return store
+
+
+@pytest.mark.unit
+class TestPortfolio:
+    """Test the latest-audit-per-host overview."""
+
+    def test_worst_first(self, portfolio):
+        """Test one entry per host, lowest score first and unscored hosts last."""
+        # Act
+        entries = portfolio.portfolio()
+
+        # Assert
+        assert [e.host for e in entries] == ["shop.example", "example.com", "blog.example", "new.example"]
+        assert [e.audits for e in entries] == [2, 2, 1, 1]
+        assert entries[1].last_audited == "2025-02-01T00:00:00Z"
+
+    def test_deltas_and_new_high(self, portfolio):
+        """Test changes are against the previous audit of the same host."""
+        # Act
+        entries = {e.host: e for e in portfolio.portfolio()}
+
+        # Assert
+        assert (entries["example.com"].score_delta, entries["example.com"].new_high) == (-15, 2)
+        assert entries["example.com"].high == 3
+        assert entries["shop.example"].score_delta == 5
+        assert entries["shop.example"].new_high == 0  # re-rated from medium, not new
+        assert (entries["blog.example"].score_delta, entries["blog.example"].new_high) == (None, None)
+
+    def test_matches_diff(self, portfolio):
+        """Test new high issues agree with the diff of the full results."""
+        # Arrange
+        [latest, previous] = portfolio.list(HistoryFilter(host="example.com"))
+        changes = diff(portfolio.get(previous.id), portfolio.get(latest.id))
+
+        # Act
+        [entry] = portfolio.portfolio(PortfolioFilter(host="https://example.com/"))
+
+        # Assert
+        assert entry.new_high == changes.summary.new["high"]
+
+    def test_pagination(self, portfolio):
+        """Test limit and offset page through the ordered hosts."""
+        # Act
+        first = portfolio.portfolio(PortfolioFilter(limit=2))
+        second = portfolio.portfolio(PortfolioFilter(limit=2, offset=2))
+
+        # Assert
+        assert [e.host for e in first + second] == [e.host for e in portfolio.portfolio()]
+        assert len(first) == len(second) == 2
+
+    def test_no_blob_decoding(self, portfolio, monkeypatch):
+        """Test the overview is computed from columns alone."""
+        # Arrange
+        monkeypatch.setattr(HistoryStore, "_decode", lambda *a: pytest.fail("decoded a blob"))
+
+        # Act / Assert
+        assert len(portfolio.portfolio()) == 4
+
+    def test_falls_back_to_diff(self, portfolio):
+        """Test rows without stored fingerprints are compared from their results."""
+        # Arrange
+        portfolio._conn.execute("UPDATE audits SET fingerprints = NULL")
+        portfolio._conn.commit()
+
+        # Act
+        [entry] = portfolio.portfolio(PortfolioFilter(host="example.com"))
+
+        # Assert
+        assert entry.new_high == 2
+
+    def test_empty(self, store):
+        """Test an empty history has an empty portfolio."""
+        assert store.portfolio() == []
+
+
+@pytest.fixture
+def import_dir(tmp_path, results_fixtures_dir):
+    """A folder of saved results: current, legacy, corrupt, and one copied twice."""
+    folder = tmp_path / "results"
+    (folder / "2025").mkdir(parents=True)
+    (folder / "a_current.json").write_text(_result(site="https://shop.example/").to_json())
+    shutil.copy(results_fixtures_dir / "registry_checks_v0.json", folder / "b_legacy.json")
+    (folder / "c_truncated.json").write_text('{"site": "https://example.com/", "issues": [')
+    (folder / "d_not_a_result.json").write_text('{"site": "https://example.com/"}')
+    (folder / "e_newer.json").write_text('{"format_version": 99, "site": "https://example.com/"}')
+    shutil.copy(folder / "a_current.json", folder / "2025" / "copy.json")
+    (folder / "notes.txt").write_text("not JSON, not imported")
+    return folder
+
+
+@pytest.mark.unit
+class TestImport:
+    """Test importing result files saved by earlier runs."""
+
+    def test_mixed_folder(self, store, import_dir):
+        """Test every file gets an outcome and only valid, new results are stored."""
+        # Act
+        outcomes = store.import_files([import_dir])
+
+        # Assert
+        by_name = {os.path.basename(o.path): o for o in outcomes}
+        assert list(by_name) == [
+            "copy.json", "a_current.json", "b_legacy.json", "c_truncated.json", "d_not_a_result.json", "e_newer.json",
+        ]
+        assert [by_name[n].status for n in by_name] == ["imported", "duplicate", "imported", "failed", "failed", "failed"]
+        assert by_name["a_current.json"].audit_id == by_name["copy.json"].audit_id
+        assert "Expecting value" in by_name["c_truncated.json"].reason
+        assert by_name["d_not_a_result.json"].reason.startswith("Not an audit result: pages_scanned")
+        assert "newer than supported" in by_name["e_newer.json"].reason
+        assert {s.origin for s in store.list()} == {"imported"}
+        assert len(store.list()) == 2
+
+    def test_legacy_upgraded(self, store, import_dir):
+        """Test a file from before format_version is stored in the current format."""
+        # Act
+        [outcome] = store.import_files([import_dir / "b_legacy.json"])
+
+        # Assert
+        result = store.get(outcome.audit_id)
+        assert result.format_version == FORMAT_VERSION
+        assert len(result.meta["diagnostics"]["checks"]) == 12
+        assert store.list()[0].created_at == "2026-09-02T14:03:51.208113Z"
+
+    def test_reimport_is_duplicate(self, store, import_dir):
+        """Test importing the same folder again stores nothing new."""
+        # Arrange
+        store.import_files([import_dir])
+
+        # Act
+        outcomes = store.import_files([import_dir])
+
+        # Assert
+        assert {o.status for o in outcomes} == {"duplicate", "failed"}
+        assert len(store.list()) == 2
+
+    def test_recorded_audit_is_duplicate(self, store, tmp_path):
+        """Test a file whose result was already recorded by an audit is not stored twice."""
+        # Arrange
+        audit_id = store.insert(_result())
+        path = tmp_path / "summary.json"
+        path.write_text(_result().to_json())
+
+        # Act
+        [outcome] = store.import_files([path])
+
+        # Assert
+        assert (outcome.status, outcome.audit_id) == ("duplicate", audit_id)
+
+    def test_timestamp_from_mtime(self, store, tmp_path):
+        """Test a result without meta.timestamp is dated by the file's modification time."""
+        # Arrange
+        path = tmp_path / "summary.json"
+        path.write_text(AuditResult(site="https://example.com/", pages_scanned=1, issues=[], meta={}).to_json())
+        os.utime(path, (1_700_000_000, 1_700_000_000))
+
+        # Act
+        store.import_files([path], label="old laptop")
+
+        # Assert
+        [summary] = store.list()
+        assert summary.created_at.startswith("2023-11-14T22:13:20")
+        assert (summary.label, summary.origin) == ("old laptop", "imported")
+
+    def test_missing_file(self, store, tmp_path):
+        """Test a path that does not exist fails without stopping the batch."""
+        # Arrange
+        good = tmp_path / "good.json"
+        good.write_text(_result().to_json())
+
+        # Act
+        missing, imported = store.import_files([tmp_path / "missing.json", good])
+
+        # Assert
+        assert (missing.status, missing.reason) == ("failed", "No such file or directory")
+        assert imported.status == "imported"
+
+    def test_progress(self, store, import_dir):
+        """Test progress is reported after every file."""
+        # Arrange
+        calls = []
+
+        # Act
+        store.import_files([import_dir], on_progress=lambda done, total: calls.append((done, total)))
+
+        # Assert
+        assert calls == [(n, 6) for n in range(1, 7)]
+
+
+@pytest.mark.unit
+class TestComparisons:
+    """Test site comparisons as records of their own."""
+
+    def test_round_trip(self, store):
+        """Test a comparison is stored, listed, and loaded unchanged, apart from the audits."""
+        # Arrange
+        comparison = compare_sites(_result(), _result(site="https://rival.example/"), mode="fast")
+
+        # Act
+        comparison_id = store.insert_comparison(comparison, label="pitch")
+
+        # Assert
+        [row] = store.comparisons()
+        assert (row.id, row.site_a, row.site_b) == (comparison_id, "https://example.com/", "https://rival.example/")
+        assert (row.mode, row.label) == ("fast", "pitch")
+        assert store.get_comparison(comparison_id) == comparison
+        assert store.list() == []
+
+    def test_missing_and_delete(self, store):
+        """Test unknown ids raise and deleting removes the record."""
+        # Arrange
+        comparison_id = store.insert_comparison(compare_sites(_result(), _result()))
+
+        # Act
+        deleted = store.delete_comparison(comparison_id)
+
+        # Assert
+        assert deleted is True
+        assert store.delete_comparison(comparison_id) is False
+        with pytest.raises(StoreError):
+            store.get_comparison(comparison_id)
+
+
+@pytest.mark.unit
+class TestConcurrency:
+    """Test use from several threads and from async code."""
+
+    def test_concurrent_writers(self, store):
+        """Test inserts from many threads all land."""
+        # Arrange
+        threads = [threading.Thread(target=lambda: [store.insert(_result()) for _ in range(10)]) for _ in range(5)]
+
+        # Act
+        for t in threads:
+            t.start()
+        for t in threads:
+            t.join()
+
+        # Assert
+        assert len(store.list()) == 50
+
+    def test_two_connections(self, tmp_path):
+        """Test two stores on the same file see each other's writes."""
+        # Arrange
+        path = tmp_path / "shared.sqlite3"
+        with HistoryStore(path) as first, HistoryStore(path) as second:
+            # Act
+            first.insert(_result())
+            second.insert(_result())
+
+            # Assert
+            assert len(first.list()) == 2
+
+    def test_async_wrapper(self, store):
+        """Test the async wrapper inserts and reads."""
+        # Arrange
+        async_store = AsyncHistoryStore(store)
+
+        async def run():
+            audit_id = await async_store.insert(_result())
+            return await async_store.get(audit_id), await async_store.list()
+
+        # Act
+        result, summaries = asyncio.run(run())
+
+        # Assert
+        assert result.site == "https://example.com/"
+        assert len(summaries) == 1
@@ -0,0 +1,96 @@
+"""
+Unit tests for the standalone HTML report.
+"""
+import json
+
+import pytest
+
+pytest.importorskip("jinja2")
+
+from tinyseoai.config import AppConfig  # noqa: E402
+from tinyseoai.data.demo import build_demo_result  # noqa: E402
+from tinyseoai.reporting import html_report  # noqa: E402
+from tinyseoai.reporting.html_report import HtmlOptions, render_html  # noqa: E402
+
+
+@pytest.fixture
+def demo_summary(monkeypatch) -> dict:
+    monkeypatch.setattr(html_report, "get_config", lambda: AppConfig())
+    return json.loads(build_demo_result().to_json())
+
+
+@pytest.mark.unit
+class TestRenderHtml:
+    """Test sections, white-labeling, and escaping."""
+
+    def test_key_sections_present(self, demo_summary):
+        """Test the demo result renders the dial, tabs, and issue groups."""
+        # Act
+        html = render_html(demo_summary)
+
+        # Assert
+        assert 'aria-label="Health score 72 of 100"' in html
+        for label in ("High (1)", "Medium (3)", "Low (2)", "Info (1)"):
+            assert label in html
+        assert "Missing canonical" in html
+        assert "<style>" in html and "@media print" in html
+        assert "<link " not in html  # no external assets
+
+    def test_crawled_content_is_escaped(self, demo_summary):
+        """Test script tags from crawled pages cannot reach the report."""
+        # Arrange
+        demo_summary["issues"][0]["detail"] = '<script>alert("x")</script>'
+        demo_summary["site"] = 'https://evil.example/"><img src=x onerror=alert(1)>'
+
+        # Act
+        html = render_html(demo_summary)
+
+        # Assert
+        assert '<script>alert("x")</script>' not in html
+        assert "&lt;script&gt;" in html
+        assert "<img src=x" not in html
+
+    def test_white_label(self, demo_summary):
+        """Test logo, byline, and accent options."""
+        # Act
+        html = render_html(
+            demo_summary,
+            HtmlOptions(
+                title="Acme SEO Review",
+                byline="Prepared by Acme Agency",
+                logo_src="data:image/png;base64,AAAA",
+                accent_hex="#123456",
+            ),
+        )
+
+        # Assert
+        assert "Acme SEO Review" in html
+        assert "Prepared by Acme Agency" in html
+        assert 'src="data:image/png;base64,AAAA"' in html
+        assert "--accent: #123456" in html
+
+    def test_unsafe_logo_and_accent_ignored(self, demo_summary):
+        """Test javascript: logos and CSS-breaking accents are dropped."""
+        # Act
+        html = render_html(
+            demo_summary,
+            HtmlOptions(logo_src="javascript:alert(1)", accent_hex="red;}</style><script>"),
+        )
+
+        # Assert
+        assert "javascript:alert" not in html
+        assert "</style><script>" not in html
+
+    def test_page_list_capped(self, demo_summary):
+        """Test long page lists are truncated with a count."""
+        # Arrange
+        demo_summary["issues"] = [
+            {"url": f"https://demo.tinyseoai.com/p{n}", "type": "broken_link", "severity": "medium"}
+            for n in range(5)
+        ]
+
+        # Act
+        html = render_html(demo_summary, HtmlOptions(max_pages_per_issue=2))
+
+        # Assert
+        assert "…and 3 more" in html
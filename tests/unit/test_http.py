@@ -0,0 +1,468 @@
+"""
+Unit tests for audit HTTP clients and request identity.
+"""
+import ssl
+import time
+from datetime import datetime, timezone
+
+import httpx
+import pytest
+
+from pydantic import SecretStr
+
+from tinyseoai.config import AppConfig
+from tinyseoai.utils.http import (
+    HttpOptions,
+    RetryPolicy,
+    attempts_for,
+    client,
+    default_user_agent,
+    flaky_urls,
+    host_matches,
+    metered,
+    retry_after_seconds,
+    tls_issues,
+)
+from tinyseoai.version import package_version
+
+
+class _Recorder:
+    """Answers 200 and keeps the headers of every request."""
+
+    def __init__(self):
+        self.headers: list[httpx.Headers] = []
+
+    def __call__(self, request: httpx.Request) -> httpx.Response:
+        self.headers.append(request.headers)
+        return httpx.Response(200, text="ok")
+
+
+async def _received(options: HttpOptions | None = None, **kwargs) -> httpx.Headers:
+    server = _Recorder()
+    async with client(options, transport=httpx.MockTransport(server), **kwargs) as http:
+        await http.get("https://example.com/")
+    return server.headers[0]
+
+
+@pytest.mark.unit
+class TestIdentity:
+    """Test the headers every audit request carries."""
+
+    def test_default_user_agent(self):
+        """Test the default names the tool, its version, and where to learn about it."""
+        assert default_user_agent() == f"tinyseoai-audit/{package_version()} (+https://tinyseoai.com)"
+        assert HttpOptions().agent == default_user_agent()
+
+    @pytest.mark.asyncio
+    async def test_defaults_sent(self):
+        """Test the default user agent is sent, and no From or Accept-Language."""
+        # Act
+        headers = await _received()
+
+        # Assert
+        assert headers["user-agent"] == default_user_agent()
+        assert "from" not in headers
+        assert "accept-language" not in headers
+
+    @pytest.mark.asyncio
+    async def test_configured_identity(self):
+        """Test a custom user agent, contact, and language reach the server."""
+        # Arrange
+        options = HttpOptions(
+            user_agent="AcmeSEO/2.0", contact="seo@acme.example", accept_language="de-DE,de;q=0.9"
+        )
+
+        # Act
+        headers = await _received(options)
+
+        # Assert
+        assert headers["user-agent"] == "AcmeSEO/2.0"
+        assert headers["from"] == "seo@acme.example"
+        assert headers["accept-language"] == "de-DE,de;q=0.9"
+
+    @pytest.mark.asyncio
+    async def test_extra_headers(self):
+        """Test headers passed to client() are sent along with the identity."""
+        headers = await _received(headers={"Accept": "text/html"})
+
+        assert headers["accept"] == "text/html"
+        assert headers["user-agent"] == default_user_agent()
+
+
+@pytest.mark.unit
+class TestTls:
+    """Test certificate verification settings."""
+
+    def test_verification_on_by_default(self):
+        """Test invalid certificates are rejected unless asked otherwise."""
+        assert HttpOptions().verify_tls is True
+        assert HttpOptions.model_validate({}).verify_tls is True
+        assert tls_issues("https://example.com", HttpOptions()) == []
+        assert tls_issues("https://example.com", None) == []
+
+    @pytest.mark.asyncio
+    async def test_client_verifies(self):
+        """Test the default client requires a valid certificate and the insecure one does not."""
+        async with client() as strict, client(HttpOptions(verify_tls=False)) as lax:
+            assert strict._transport._pool._ssl_context.verify_mode == ssl.CERT_REQUIRED
+            assert lax._transport._pool._ssl_context.verify_mode == ssl.CERT_NONE
+
+    def test_insecure_is_flagged(self):
+        """Test results gathered without verification carry a finding saying so."""
+        # Act
+        issues = tls_issues("https://staging.example.com", HttpOptions(verify_tls=False))
+
+        # Assert
+        assert [(i.type, i.severity, i.url) for i in issues] == [
+            ("tls_verification_disabled", "info", "https://staging.example.com")
+        ]
+        assert "--insecure" in issues[0].detail
+
+
+class _TwoHosts:
+    """The staging site and a CDN; /go on staging redirects to the CDN, /old to /."""
+
+    def __init__(self):
+        self.received: dict[str, list[httpx.Headers]] = {}
+
+    def __call__(self, request: httpx.Request) -> httpx.Response:
+        self.received.setdefault(request.url.host, []).append(request.headers)
+        if request.url.path == "/go":
+            return httpx.Response(302, headers={"Location": "https://cdn.example.net/og.png"})
+        if request.url.path == "/old":
+            return httpx.Response(301, headers={"Location": "/"})
+        return httpx.Response(200, text="ok")
+
+
+def _credentials(**kwargs) -> HttpOptions:
+    return HttpOptions(
+        basic_auth=("preview", SecretStr("hunter2")),
+        cookie=SecretStr("session=abc123"),
+        extra_headers={"X-Preview-Token": SecretStr("t0ken")},
+        **kwargs,
+    )
+
+
+async def _fetch(options: HttpOptions, *urls: str) -> _TwoHosts:
+    server = _TwoHosts()
+    async with client(options, transport=httpx.MockTransport(server), follow_redirects=True) as http:
+        for url in urls:
+            await http.get(url)
+    return server
+
+
+@pytest.mark.unit
+class TestCredentials:
+    """Test credentials only reach the configured host."""
+
+    @pytest.mark.parametrize(
+        "host,suffix,expected",
+        [
+            ("staging.example.com", "staging.example.com", True),
+            ("assets.staging.example.com", "staging.example.com", True),
+            ("STAGING.example.com", ".staging.example.com", True),
+            ("cdn.example.net", "staging.example.com", False),
+            ("badstaging.example.com", "staging.example.com", False),
+        ],
+    )
+    def test_host_matches(self, host, suffix, expected):
+        """Test the host itself and its subdomains match, lookalikes do not."""
+        assert host_matches(host, suffix) is expected
+
+    @pytest.mark.asyncio
+    async def test_sent_to_matching_host_only(self):
+        """Test the site and its subdomains get credentials and the CDN gets none."""
+        # Arrange
+        options = _credentials().for_site("https://staging.example.com/")
+
+        # Act
+        server = await _fetch(
+            options,
+            "https://staging.example.com/",
+            "https://img.staging.example.com/logo.png",
+            "https://cdn.example.net/og.png",
+        )
+
+        # Assert
+        for host in ("staging.example.com", "img.staging.example.com"):
+            [headers] = server.received[host]
+            assert headers["authorization"] == "Basic cHJldmlldzpodW50ZXIy"
+            assert headers["cookie"] == "session=abc123"
+            assert headers["x-preview-token"] == "t0ken"
+        [cdn] = server.received["cdn.example.net"]
+        assert not {"authorization", "cookie", "x-preview-token"} & set(cdn.keys())
+        assert cdn["user-agent"] == default_user_agent()
+
+    @pytest.mark.asyncio
+    async def test_redirects(self):
+        """Test a redirect on the site keeps credentials and one off the site drops them."""
+        # Act
+        server = await _fetch(
+            _credentials(auth_host="staging.example.com"),
+            "https://staging.example.com/old",
+            "https://staging.example.com/go",
+        )
+
+        # Assert
+        site = server.received["staging.example.com"]
+        assert len(site) == 3
+        assert all(h["cookie"] == "session=abc123" and "authorization" in h for h in site)
+        [cdn] = server.received["cdn.example.net"]
+        assert not {"authorization", "cookie", "x-preview-token"} & set(cdn.keys())
+
+    @pytest.mark.asyncio
+    async def test_not_sent_without_host(self):
+        """Test credentials are held back until a host is known."""
+        server = await _fetch(_credentials(), "https://staging.example.com/")
+
+        assert "authorization" not in server.received["staging.example.com"][0]
+
+    def test_explicit_host_kept(self):
+        """Test a configured auth_host is not replaced by the audited host."""
+        options = _credentials(auth_host="example.com").for_site("https://staging.example.com/")
+
+        assert options.auth_host == "example.com"
+        assert HttpOptions().for_site("https://example.com/").auth_host is None
+
+    def test_secrets_hidden(self):
+        """Test secrets are left out of reprs, dumps, and the saved config."""
+        # Arrange
+        options = _credentials(auth_host="staging.example.com")
+        cfg = AppConfig(http=options)
+
+        # Act
+        shown = [
+            repr(options),
+            str(options),
+            repr(options.credentials()),
+            options.model_dump_json(),
+            cfg.model_dump_json(),
+        ]
+
+        # Assert
+        for text in shown:
+            assert "hunter2" not in text
+            assert "abc123" not in text
+            assert "t0ken" not in text
+        assert options.model_dump()["auth_host"] == "staging.example.com"
+        assert "basic_auth" not in options.model_dump()
+
+
+class _Flaky:
+    """Answers each path from a script of failures, then 200; records when requests came."""
+
+    def __init__(self, **scripts: list):
+        self.scripts = {f"/{path}": list(script) for path, script in scripts.items()}
+        self.times: dict[str, list[float]] = {}
+
+    def __call__(self, request: httpx.Request) -> httpx.Response:
+        self.times.setdefault(request.url.path, []).append(time.monotonic())
+        script = self.scripts.get(request.url.path, [])
+        outcome = script.pop(0) if script else 200
+        if isinstance(outcome, type):
+            raise outcome("scripted failure", request=request)
+        if isinstance(outcome, httpx.Response):
+            return outcome
+        return httpx.Response(outcome)
+
+    def attempts(self, path: str) -> int:
+        return len(self.times.get(f"/{path}", []))
+
+
+def _retrying(server: _Flaky, **policy) -> httpx.AsyncClient:
+    retry = RetryPolicy(**{"backoff": 0.05, "jitter": 0, **policy})
+    return client(HttpOptions(retry=retry), transport=httpx.MockTransport(server))
+
+
+@pytest.mark.unit
+class TestRetry:
+    """Test retries of transient failures and the attempts recorded per URL."""
+
+    @pytest.mark.asyncio
+    async def test_fails_twice_then_succeeds(self):
+        """Test two 503s are retried with growing waits and the URL is recorded as recovered."""
+        # Arrange
+        server = _Flaky(page=[503, 503])
+
+        # Act
+        started = time.monotonic()
+        async with _retrying(server) as http:
+            response = await http.get("https://example.com/page")
+        elapsed = time.monotonic() - started
+
+        # Assert
+        assert response.status_code == 200
+        assert server.attempts("page") == 3
+        first, second, third = server.times["/page"]
+        assert second - first >= 0.045
+        assert third - second >= 0.095  # the backoff doubles
+        assert elapsed < 1.0
+        record = attempts_for(http, "https://example.com/page")
+        assert (record.attempts, record.outcome, record.last) == (3, "recovered", "200")
+        assert flaky_urls(http) == [record.model_dump()]
+
+    @pytest.mark.asyncio
+    async def test_connect_errors_and_timeouts(self):
+        """Test connection errors and timeouts are retried too."""
+        # Arrange
+        server = _Flaky(page=[httpx.ConnectError, httpx.ReadTimeout])
+
+        # Act
+        async with _retrying(server) as http:
+            response = await http.get("https://example.com/page")
+
+        # Assert
+        assert response.status_code == 200
+        assert attempts_for(http, "https://example.com/page").outcome == "recovered"
+
+    @pytest.mark.asyncio
+    async def test_gives_up_as_transient(self):
+        """Test a URL failing on every attempt is classified transient, not broken."""
+        # Arrange
+        server = _Flaky(busy=[504] * 5, down=[httpx.ConnectError] * 5)
+
+        # Act
+        async with _retrying(server, backoff=0) as http:
+            busy = await http.get("https://example.com/busy")
+            with pytest.raises(httpx.ConnectError):
+                await http.get("https://example.com/down")
+
+        # Assert
+        assert busy.status_code == 504
+        assert server.attempts("busy") == server.attempts("down") == 3
+        assert attempts_for(http, "https://example.com/busy").outcome == "transient"
+        assert attempts_for(http, "https://example.com/down").last == "ConnectError"
+
+    @pytest.mark.asyncio
+    @pytest.mark.parametrize("status", [404, 429, 500])
+    async def test_no_retry(self, status):
+        """Test 4xx and other errors are not retried and are confirmed broken."""
+        # Arrange
+        server = _Flaky(page=[status])
+
+        # Act
+        async with _retrying(server) as http:
+            response = await http.get("https://example.com/page")
+
+        # Assert
+        assert response.status_code == status
+        assert server.attempts("page") == 1
+        record = attempts_for(http, "https://example.com/page")
+        assert (record.attempts, record.outcome) == (1, "broken")
+        assert flaky_urls(http) == []
+
+    @pytest.mark.asyncio
+    async def test_post_not_retried(self):
+        """Test requests that are not idempotent are sent once."""
+        # Arrange
+        server = _Flaky(form=[503])
+
+        # Act
+        async with _retrying(server) as http:
+            response = await http.post("https://example.com/form", data={"q": "1"})
+
+        # Assert
+        assert response.status_code == 503
+        assert server.attempts("form") == 1
+        assert attempts_for(http, "https://example.com/form") is None
+
+    @pytest.mark.asyncio
+    async def test_retry_after(self):
+        """Test Retry-After is waited out instead of the backoff, up to max_retry_after."""
+        # Arrange
+        server = _Flaky(
+            soon=[httpx.Response(503, headers={"retry-after": "0.2"})],
+            later=[httpx.Response(503, headers={"retry-after": "3600"})],
+        )
+
+        # Act
+        async with _retrying(server, backoff=0, max_retry_after=0.1) as http:
+            await http.get("https://example.com/soon")
+            await http.get("https://example.com/later")
+
+        # Assert
+        soon, later = server.times["/soon"], server.times["/later"]
+        assert 0.095 <= soon[1] - soon[0] < 0.5
+        assert 0.095 <= later[1] - later[0] < 0.5
+
+    def test_backoff_and_jitter(self):
+        """Test waits double per retry, are capped, and jitter only adds."""
+        policy = RetryPolicy(backoff=1.0, max_backoff=3.0, jitter=0.5)
+
+        assert 1.0 <= policy.delay(1) <= 1.5
+        assert 2.0 <= policy.delay(2) <= 3.0
+        assert 3.0 <= policy.delay(5) <= 4.5
+        assert policy.delay(1, retry_after=2.0) == 2.0
+
+    @pytest.mark.asyncio
+    async def test_disabled(self):
+        """Test attempts = 1 turns retries off."""
+        # Arrange
+        server = _Flaky(page=[503])
+
+        # Act
+        async with _retrying(server, attempts=1) as http:
+            response = await http.get("https://example.com/page")
+
+        # Assert
+        assert response.status_code == 503
+        assert server.attempts("page") == 1
+        assert attempts_for(http, "https://example.com/page").outcome == "transient"
+
+    @pytest.mark.parametrize(
+        ("value", "seconds"),
+        [("7", 7.0), ("Wed, 21 Oct 2015 07:28:30 GMT", 30.0), ("soon", None), (None, None)],
+    )
+    def test_retry_after_seconds(self, value, seconds):
+        """Test delta-seconds and HTTP-date Retry-After values."""
+        now = datetime(2015, 10, 21, 7, 28, 0, tzinfo=timezone.utc)
+
+        assert retry_after_seconds(value, now) == seconds
+
+
+class _Chunks(httpx.AsyncByteStream):
+    """A body arriving in chunks, as from the network."""
+
+    async def __aiter__(self):
+        for _ in range(4):
+            yield b"x" * 256
+
+
+@pytest.mark.unit
+class TestMeter:
+    """Test counting requests and downloaded bytes."""
+
+    @pytest.mark.asyncio
+    async def test_streamed_bodies_counted(self):
+        """Test bytes are counted as a body is read, whether or not the caller streams."""
+        # Arrange
+        transport = httpx.MockTransport(lambda request: httpx.Response(200, stream=_Chunks()))
+
+        # Act
+        with metered() as meter:
+            async with client(transport=transport) as http:
+                await http.get("https://example.com/page")
+                async with http.stream("GET", "https://example.com/big") as response:
+                    async for _ in response.aiter_bytes():
+                        pass
+
+        # Assert
+        assert (meter.requests, meter.bytes_fetched) == (2, 2048)
+
+    @pytest.mark.asyncio
+    async def test_only_inside_metered(self):
+        """Test requests outside metered() are not counted, and meters do not nest into each other."""
+        # Arrange
+        transport = httpx.MockTransport(lambda request: httpx.Response(200, text="ok"))
+
+        # Act
+        async with client(transport=transport) as http:
+            await http.get("https://example.com/before")
+            with metered() as outer:
+                await http.get("https://example.com/outer")
+                with metered() as inner:
+                    await http.get("https://example.com/inner")
+            await http.get("https://example.com/after")
+
+        # Assert
+        assert (outer.requests, inner.requests) == (1, 1)
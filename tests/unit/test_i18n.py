@@ -0,0 +1,250 @@
+"""
+Unit tests for message catalogs and localized issue text.
+"""
+import asyncio
+import re
+import string
+from pathlib import Path
+
+import pytest
+
+from tinyseoai.audit.checks.duplicates import PageMeta
+from tinyseoai.audit.checks.duplicates import analyze as analyze_duplicates
+from tinyseoai.audit.registry import CheckContext, CheckRegistry, FunctionCheck
+from tinyseoai.data.diff import diff
+from tinyseoai.data.fingerprint import fingerprint
+from tinyseoai.data.models import Issue
+from tinyseoai.data.recommendations import issue_title, recommendation_for
+from tinyseoai.i18n import Locale, catalog, fragment, listing, localize, msg, result_locale, text
+from tinyseoai.reporting.markdown import RenderOptions, render_markdown
+
+PACKAGE = Path(__file__).parents[2] / "tinyseoai"
+_KEY = re.compile(r"""\b(?:msg|fragment)\(\s*"([a-z0-9_]+\.[a-z0-9_]+)\"""")
+
+
+def _pages() -> list[PageMeta]:
+    return [
+        PageMeta(url=f"https://example.com/{n}", title="Welcome", description="Same text")
+        for n in range(3)
+    ]
+
+
+def _fields(template: str) -> set[str]:
+    return {name for _, name, _, _ in string.Formatter().parse(template) if name is not None}
+
+
+@pytest.mark.unit
+class TestLocale:
+    """Test parsing locale names."""
+
+    def test_parse(self):
+        """Test case and region are ignored."""
+        # Act & Assert
+        assert Locale.parse("ES") is Locale.ES
+        assert Locale.parse("es-MX") is Locale.ES
+        assert Locale.parse("en_GB") is Locale.EN
+        assert Locale.parse(None) is Locale.EN
+
+    def test_unknown(self):
+        """Test a language without a catalog is refused."""
+        # Act & Assert
+        with pytest.raises(ValueError, match="Unknown locale 'fr'"):
+            Locale.parse("fr")
+
+    def test_result_locale(self):
+        """Test the locale a result was written in, English when unknown."""
+        # Act & Assert
+        assert result_locale({"locale": "es"}) is Locale.ES
+        assert result_locale({"locale": "xx"}) is Locale.EN
+        assert result_locale(None) is Locale.EN
+
+
+@pytest.mark.unit
+class TestText:
+    """Test rendering catalog messages."""
+
+    def test_positional_arguments(self):
+        """Test arguments fill the template in either language."""
+        # Act & Assert
+        assert text("meta.title_too_long", [72, 60]) == "Title is 72 characters (max 60)"
+        assert text("meta.title_too_long", [72, 60], Locale.ES) == (
+            "El título tiene 72 caracteres (máx. 60)"
+        )
+
+    def test_falls_back_to_english(self):
+        """Test a message missing from the Spanish catalog is rendered in English."""
+        # Arrange
+        assert "external_link_missing_noopener" not in catalog(Locale.ES).get("links", {})
+
+        # Act
+        output = text("links.external_link_missing_noopener", ["https://shop.example/"], Locale.ES)
+
+        # Assert
+        assert output == (
+            "External link with target='_blank' should have rel='noopener': https://shop.example/"
+        )
+
+    def test_fragments_and_lists(self):
+        """Test fragment arguments render in the same language and lists comma-separated."""
+        # Arrange
+        args = [5, listing(["/a", "/b", "/c"], 2)]
+
+        # Act
+        english = text("images.img_alt_missing", args)
+        spanish = text("images.img_alt_missing", args, Locale.ES)
+
+        # Assert
+        assert english == "5 images have no alt attribute: /a, /b and 1 more"
+        assert spanish == "5 imágenes no tienen atributo alt: /a, /b y 1 más"
+
+    def test_unknown_message(self):
+        """Test a message no catalog has raises KeyError."""
+        # Act & Assert
+        with pytest.raises(KeyError, match="meta.nope"):
+            text("meta.nope")
+
+    def test_msg_fields(self):
+        """Test msg() keeps the message and arguments next to the English detail."""
+        # Act
+        fields = msg("canonical.canonical_loop", [fragment("canonical.another_url")])
+
+        # Assert
+        assert fields["message"] == "canonical.canonical_loop"
+        assert fields["detail"] == "Canonical loop: another URL"
+
+
+@pytest.mark.unit
+class TestOtherCatalogVersions:
+    """Test results naming messages this catalog cannot render keep their stored detail."""
+
+    def test_unknown_message_and_arguments(self, load_result_fixture):
+        """Test an unknown message id and missing arguments reach diff, fingerprints and reports."""
+        # Arrange
+        result = load_result_fixture("other_catalog.json")
+        stored = [issue.detail for issue in result.issues]
+
+        # Act
+        changes = diff(result, result)
+        keys = [fingerprint(issue) for issue in result.issues]
+        localize(result.issues, Locale.ES)
+        report = render_markdown(result.model_dump(mode="json"), RenderOptions(locale=Locale.ES))
+
+        # Assert
+        assert len(changes.persisting) == 2
+        assert len(set(keys)) == 2
+        assert [issue.detail for issue in result.issues] == stored
+        assert all(detail in report for detail in stored)
+
+
+@pytest.mark.unit
+class TestCatalogs:
+    """Test the shipped catalogs stay consistent with the code."""
+
+    def test_every_message_in_english(self):
+        """Test each literal message key used by the checks exists in en.toml."""
+        # Arrange
+        keys = {
+            key
+            for path in PACKAGE.rglob("*.py")
+            for key in _KEY.findall(path.read_text(encoding="utf-8"))
+        }
+        english = catalog(Locale.EN)
+
+        # Act
+        missing = [k for k in keys if k.split(".")[1] not in english.get(k.split(".")[0], {})]
+
+        # Assert
+        assert keys
+        assert missing == []
+
+    def test_spanish_fields_match_english(self):
+        """Test Spanish templates only use fields their English counterparts take."""
+        # Arrange
+        english = catalog(Locale.EN)
+
+        # Act
+        extra = [
+            f"{table}.{key}"
+            for table, templates in catalog(Locale.ES).items()
+            if table not in ("titles", "recommendations")
+            for key, template in templates.items()
+            if key not in english[table] or not _fields(template) <= _fields(english[table][key])
+        ]
+
+        # Assert
+        assert extra == []
+
+    def test_titles_and_recommendations(self):
+        """Test titles and advice come from the locale, else English."""
+        # Act & Assert
+        assert issue_title("title_too_long") == "Title too long"
+        assert issue_title("title_too_long", Locale.ES) == "Título demasiado largo"
+        assert issue_title("custom_finding", Locale.ES) == "Custom finding"
+        assert recommendation_for("no_https", Locale.ES).startswith("Sirve el sitio")
+        assert recommendation_for("table_layout", Locale.ES) == recommendation_for("table_layout")
+
+
+@pytest.mark.unit
+class TestSpanishRun:
+    """Test a run in Spanish translates the text but keeps the fingerprints."""
+
+    def _run(self, locale: Locale) -> list[Issue]:
+        registry = CheckRegistry(
+            [FunctionCheck("duplicates", lambda ctx: analyze_duplicates(_pages()))]
+        )
+        ctx = CheckContext(seed_url="https://example.com/", pages=[], locale=locale)
+        return asyncio.run(registry.run(ctx)).issues
+
+    def test_translated_and_same_fingerprints(self):
+        """Test details are Spanish and fingerprints equal the English run's."""
+        # Act
+        english = self._run(Locale.EN)
+        spanish = self._run(Locale.ES)
+
+        # Assert
+        assert english[0].detail.startswith("3 pages share the title")
+        assert spanish[0].detail.startswith("3 páginas comparten el título")
+        assert [fingerprint(i) for i in spanish] == [fingerprint(i) for i in english]
+
+    def test_localize_back(self):
+        """Test a Spanish issue renders in English again."""
+        # Arrange
+        issues = self._run(Locale.ES)
+
+        # Act
+        localize(issues, Locale.EN)
+
+        # Assert
+        assert issues == self._run(Locale.EN)
+
+    def test_plain_details_untouched(self):
+        """Test issues without a catalog message keep their detail."""
+        # Arrange
+        issue = Issue(url="https://example.com/", type="custom", detail="Plain text")
+
+        # Act
+        localize([issue], Locale.ES)
+
+        # Assert
+        assert issue.detail == "Plain text"
+
+    def test_markdown_titles(self):
+        """Test a Spanish result renders Spanish titles unless another locale is asked for."""
+        # Arrange
+        issues = self._run(Locale.ES)
+        summary = {
+            "site": "https://example.com/",
+            "pages_scanned": 3,
+            "issues": [i.model_dump() for i in issues],
+            "meta": {"locale": "es"},
+        }
+
+        # Act
+        spanish = render_markdown(summary)
+        english = render_markdown(summary, RenderOptions(locale=Locale.EN))
+
+        # Assert
+        assert "### Título duplicado (1 page)" in spanish
+        assert "3 páginas comparten el título" in spanish
+        assert "Duplicate title" in english
+        assert "3 pages share the title" in english
@@ -0,0 +1,257 @@
+"""
+Unit tests for favicon, manifest, and theme-color checks.
+"""
+import json
+import struct
+
+import httpx
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.icons import IconOptions, analyze, icon_links, probe  # noqa: E402
+
+URL = "https://example.com/"
+
+PAGE = """<!DOCTYPE html>
+<html><head>
+  <link rel="icon" href="/favicon-32.png" sizes="32x32">
+  <link rel="apple-touch-icon" href="/apple-touch-icon.png">
+  <link rel="manifest" href="/static/site.webmanifest">
+  <meta name="theme-color" content="#2f4f4f">
+</head><body><h1>Mugs</h1></body></html>"""
+
+
+def _png(width: int, height: int) -> bytes:
+    return b"\x89PNG\r\n\x1a\n" + struct.pack(">I4sII", 13, b"IHDR", width, height) + b"\x00" * 64
+
+
+def _manifest(*icons: tuple[str, str]) -> bytes:
+    return json.dumps({"name": "Mugs", "icons": [{"src": src, "sizes": sizes} for src, sizes in icons]}).encode()
+
+
+class _Server:
+    """A small site: path -> (status, content type, body); records each request."""
+
+    def __init__(self, routes: dict):
+        self.routes = routes
+        self.requests: list[tuple[str, str]] = []
+
+    def __call__(self, request: httpx.Request) -> httpx.Response:
+        self.requests.append((request.method, request.url.path))
+        status, content_type, body = self.routes.get(request.url.path, (404, "text/html", b"Not found"))
+        return httpx.Response(status, headers={"content-type": content_type}, content=body)
+
+    def client(self) -> httpx.AsyncClient:
+        return httpx.AsyncClient(transport=httpx.MockTransport(self))
+
+
+def _valid_site() -> dict:
+    return {
+        "/favicon-32.png": (200, "image/png", _png(32, 32)),
+        "/apple-touch-icon.png": (200, "image/png", _png(180, 180)),
+        "/static/site.webmanifest": (
+            200,
+            "application/manifest+json",
+            _manifest(("icons/192.png", "192x192"), ("/static/icons/512.png", "512x512")),
+        ),
+        "/static/icons/192.png": (200, "image/png", _png(192, 192)),
+        "/static/icons/512.png": (200, "image/png", _png(512, 512)),
+    }
+
+
+def _types(issues) -> list[str]:
+    return sorted(i.type for i in issues)
+
+
+def _detail(issues, issue_type: str) -> str:
+    return next(i.detail for i in issues if i.type == issue_type)
+
+
+@pytest.mark.unit
+class TestIconLinks:
+    """Test reading icon tags."""
+
+    def test_links_resolved(self):
+        """Test hrefs are made absolute and rel values are matched as tokens."""
+        # Act
+        links = icon_links(PAGE.replace('rel="icon"', 'rel="shortcut icon"'), "https://example.com/shop/")
+
+        # Assert
+        assert links.favicons == ["https://example.com/favicon-32.png"]
+        assert links.apple_touch_icons == ["https://example.com/apple-touch-icon.png"]
+        assert links.manifest == "https://example.com/static/site.webmanifest"
+        assert links.theme_color == "#2f4f4f"
+
+
+@pytest.mark.unit
+class TestAnalyze:
+    """Test the checks against a scripted site."""
+
+    @pytest.mark.asyncio
+    async def test_valid_setup(self):
+        """Test a complete setup passes; icons resolve against the manifest URL."""
+        # Arrange
+        server = _Server(_valid_site())
+
+        # Act
+        issues = await analyze(PAGE, URL, server.client())
+
+        # Assert
+        assert issues == []
+        assert ("HEAD", "/favicon-32.png") in server.requests
+        assert ("GET", "/static/icons/192.png") in server.requests
+
+    @pytest.mark.asyncio
+    async def test_missing_favicon(self):
+        """Test no icon tag and no /favicon.ico is reported, along with the other gaps."""
+        # Arrange
+        server = _Server({})
+
+        # Act
+        issues = await analyze("<html><head></head><body></body></html>", URL, server.client())
+
+        # Assert
+        assert _types(issues) == [
+            "manifest_missing",
+            "missing_apple_touch_icon",
+            "missing_favicon",
+            "theme_color_missing",
+        ]
+        assert _detail(issues, "missing_favicon") == (
+            'No <link rel="icon"> and https://example.com/favicon.ico returns HTTP 404'
+        )
+
+    @pytest.mark.asyncio
+    async def test_favicon_ico_fallback(self):
+        """Test /favicon.ico counts when no icon is declared, but not an HTML soft 404."""
+        # Arrange
+        html = PAGE.replace('<link rel="icon" href="/favicon-32.png" sizes="32x32">', "")
+        good = _Server({**_valid_site(), "/favicon.ico": (200, "image/x-icon", b"\x00\x00\x01\x00")})
+        soft = _Server({**_valid_site(), "/favicon.ico": (200, "text/html", b"<html>Home</html>")})
+
+        # Act
+        passed = await analyze(html, URL, good.client())
+        failed = await analyze(html, URL, soft.client())
+
+        # Assert
+        assert passed == []
+        assert _detail(failed, "missing_favicon").endswith("favicon.ico is served as text/html")
+
+    @pytest.mark.asyncio
+    async def test_broken_declared_favicon(self):
+        """Test a declared favicon that 404s names the file."""
+        # Arrange
+        routes = _valid_site()
+        del routes["/favicon-32.png"]
+
+        # Act
+        issues = await analyze(PAGE, URL, _Server(routes).client())
+
+        # Assert
+        assert _types(issues) == ["favicon_broken"]
+        assert _detail(issues, "favicon_broken") == "Favicon https://example.com/favicon-32.png returns HTTP 404"
+
+    @pytest.mark.asyncio
+    async def test_manifest_with_broken_icons(self):
+        """Test broken icon paths, non-images, and wrong sizes are each named."""
+        # Arrange
+        routes = _valid_site()
+        routes["/static/site.webmanifest"] = (
+            200,
+            "application/manifest+json",
+            _manifest(("/icons/192.png", "192x192"), ("icons/page.png", "256x256"), ("icons/512.png", "512x512")),
+        )
+        routes["/static/icons/page.png"] = (200, "text/html", b"<html></html>")
+        routes["/static/icons/512.png"] = (200, "image/png", _png(256, 256))
+
+        # Act
+        issues = await analyze(PAGE, URL, _Server(routes).client())
+
+        # Assert
+        assert [(i.type, i.detail) for i in issues] == [
+            ("manifest_icon_broken", "Manifest icon https://example.com/icons/192.png (192x192) returns HTTP 404"),
+            (
+                "manifest_icon_broken",
+                "Manifest icon https://example.com/static/icons/page.png (256x256) is served as text/html",
+            ),
+            (
+                "manifest_icon_size_mismatch",
+                "Manifest icon https://example.com/static/icons/512.png declares 512x512 but is 256x256",
+            ),
+        ]
+
+    @pytest.mark.asyncio
+    @pytest.mark.parametrize(
+        ("body", "expected"),
+        [
+            (b"{not json", "is not valid JSON"),
+            (b"[]", "is not a JSON object"),
+            (b'{"name": "Mugs"}', "has no icons"),
+            (b'{"icons": []}' + b" " * 5000, "is larger than 1024 bytes"),
+        ],
+    )
+    async def test_manifest_problems(self, body, expected):
+        """Test unreadable or empty manifests are reported with the reason."""
+        # Arrange
+        routes = {**_valid_site(), "/static/site.webmanifest": (200, "application/json", body)}
+
+        # Act
+        issues = await analyze(PAGE, URL, _Server(routes).client(), IconOptions(max_bytes=1024))
+
+        # Assert
+        assert len(issues) == 1
+        assert issues[0].type in ("manifest_broken", "manifest_icons_missing")
+        assert expected in issues[0].detail
+
+
+@pytest.mark.unit
+class TestProbe:
+    """Test request handling."""
+
+    @pytest.mark.asyncio
+    async def test_get_fallback_when_head_refused(self):
+        """Test a GET is sent when HEAD returns 405, without reading the body."""
+        # Arrange
+        requests = []
+
+        def server(request):
+            requests.append(request.method)
+            if request.method == "HEAD":
+                return httpx.Response(405)
+            return httpx.Response(200, headers={"content-type": "image/png"}, content=_png(32, 32))
+
+        client = httpx.AsyncClient(transport=httpx.MockTransport(server))
+
+        # Act
+        result = await probe("https://example.com/favicon.png", client)
+
+        # Assert
+        assert requests == ["HEAD", "GET"]
+        assert result.ok and result.is_image
+        assert result.body == b""
+
+    @pytest.mark.asyncio
+    async def test_body_is_capped(self):
+        """Test no more than max_bytes of a body is kept."""
+        # Arrange
+        client = httpx.AsyncClient(
+            transport=httpx.MockTransport(lambda r: httpx.Response(200, content=b"x" * 100_000))
+        )
+
+        # Act
+        result = await probe("https://example.com/big", client, IconOptions(max_bytes=2048), body=True)
+
+        # Assert
+        assert len(result.body) == 2048
+        assert result.truncated
+
+    @pytest.mark.asyncio
+    async def test_data_uri(self):
+        """Test inline data: favicons are not requested."""
+        # Act
+        result = await probe("data:image/svg+xml,<svg></svg>", client=None)
+
+        # Assert
+        assert result.ok and result.content_type == "image/svg+xml"
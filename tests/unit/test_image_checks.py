@@ -0,0 +1,127 @@
+"""
+Unit tests for the image audit.
+"""
+import httpx
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.images import ImageOptions, analyze, page_images  # noqa: E402
+
+PAGE = "https://example.com/mugs"
+
+# path -> (content type served to clients accepting AVIF/WebP, size in bytes)
+IMAGES = {
+    "/img/logo.png": ("image/webp", 3_000),
+    "/img/hero.jpg": ("image/jpeg", 450_000),
+    "/img/divider.png": ("image/png", 1_000),
+    "/img/glaze.jpg": ("image/jpeg", 90_000),
+    "/img/kiln.jpg": ("image/jpeg", 50_000),
+    "/img/studio.webp": ("image/webp", 40_000),
+    "/img/placeholder.gif": ("image/webp", 100),
+}
+
+
+class _ImageServer:
+    """Serves HEAD responses for images of known type and size."""
+
+    def __init__(self):
+        self.requests: list[httpx.Request] = []
+
+    def __call__(self, request: httpx.Request) -> httpx.Response:
+        self.requests.append(request)
+        if request.url.path not in IMAGES:
+            return httpx.Response(404)
+        content_type, size = IMAGES[request.url.path]
+        return httpx.Response(200, headers={"content-type": content_type, "content-length": str(size)})
+
+    def client(self) -> httpx.AsyncClient:
+        return httpx.AsyncClient(transport=httpx.MockTransport(self))
+
+
+def _issues_by_type(issues) -> dict[str, str]:
+    return {i.type: i.detail for i in issues}
+
+
+@pytest.mark.unit
+class TestMarkup:
+    """Test checks that need only the HTML."""
+
+    def test_page_images(self, html_fixture):
+        """Test decorative images, <picture> sources, and script lazy loading are recognized."""
+        # Act
+        images = page_images(html_fixture("images.html"), PAGE)
+
+        # Assert
+        assert len(images) == 9
+        assert images[0].decorative and images[0].in_empty_link
+        assert images[2].decorative and not images[2].in_empty_link
+        assert images[3].modern_source
+        assert images[6].lazy_by_script
+
+    @pytest.mark.asyncio
+    async def test_markup_findings(self, html_fixture):
+        """Test alt, dimension, and lazy-loading findings without network access."""
+        # Act
+        issues = _issues_by_type(await analyze(html_fixture("images.html"), PAGE))
+
+        # Assert
+        assert issues == {
+            "img_alt_missing": "1 images have no alt attribute: https://example.com/img/kiln.jpg",
+            "img_alt_empty_in_link": "1 linked images have empty alt text and the link has no other text: "
+            "https://example.com/img/logo.png",
+            "images_without_dimensions": "1 images have no width/height (layout shift risk): "
+            "https://example.com/img/kiln.jpg",
+            "images_not_lazy_loaded": "2 below-the-fold images are not lazy loaded: "
+            "https://example.com/img/glaze.jpg, https://example.com/img/kiln.jpg",
+        }
+
+
+@pytest.mark.unit
+class TestProbing:
+    """Test findings from HEAD requests."""
+
+    @pytest.mark.asyncio
+    async def test_size_format_and_broken(self, html_fixture):
+        """Test oversized, legacy-format, and missing images are reported."""
+        # Arrange
+        server = _ImageServer()
+
+        # Act
+        issues = _issues_by_type(await analyze(html_fixture("images.html"), PAGE, server.client()))
+
+        # Assert
+        assert issues["image_too_large"] == "1 images are larger than 200 KB: https://example.com/img/hero.jpg (439 KB)"
+        assert issues["image_legacy_format"] == (
+            "3 images are served only as JPEG/PNG/GIF, with no AVIF or WebP alternative: "
+            "https://example.com/img/hero.jpg (jpeg), https://example.com/img/divider.png (png), "
+            "https://example.com/img/kiln.jpg (jpeg)"
+        )
+        assert issues["image_broken"] == "1 images do not load: https://example.com/img/missing.png (HTTP 404)"
+
+    @pytest.mark.asyncio
+    async def test_probes_deduplicated_and_capped(self, html_fixture):
+        """Test each image URL is requested once, up to max_probes."""
+        # Arrange
+        server = _ImageServer()
+
+        # Act
+        await analyze(html_fixture("images.html"), PAGE, server.client())
+        all_probes = [r.url.path for r in server.requests]
+        server.requests.clear()
+        await analyze(html_fixture("images.html"), PAGE, server.client(), ImageOptions(max_probes=2))
+
+        # Assert
+        assert len(all_probes) == len(set(all_probes)) == 8
+        assert all(r.method == "HEAD" and "image/avif" in r.headers["accept"] for r in server.requests)
+        assert len(server.requests) == 2
+
+    @pytest.mark.asyncio
+    async def test_size_threshold(self, html_fixture):
+        """Test the size threshold is configurable."""
+        # Act
+        issues = await analyze(html_fixture("images.html"), PAGE, _ImageServer().client(), ImageOptions(max_bytes=500_000))
+
+        # Assert
+        assert "image_too_large" not in _issues_by_type(issues)
@@ -0,0 +1,178 @@
+"""
+Unit tests for robots meta and X-Robots-Tag evaluation.
+"""
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.indexability import (  # noqa: E402
+    IndexabilityOptions,
+    analyze,
+    page_directives,
+    parse_directives,
+)
+from tinyseoai.audit.crawler import FetchedPage  # noqa: E402
+from tinyseoai.audit.robots import parse_robots  # noqa: E402
+
+
+def _html(*tags: tuple[str, str], head: str = "") -> str:
+    metas = "".join(f'<meta name="{name}" content="{content}">' for name, content in tags)
+    return f"<html><head>{metas}{head}</head><body></body></html>"
+
+
+def _page(
+    url: str, *tags: tuple[str, str], header: str | None = None, depth: int = 2, **kwargs
+) -> FetchedPage:
+    headers = {"X-Robots-Tag": header} if header is not None else {}
+    return FetchedPage(url=url, body=_html(*tags, **kwargs), headers=headers, depth=depth)
+
+
+def _found(issues) -> list[tuple[str, str]]:
+    return sorted((i.url, i.type) for i in issues)
+
+
+@pytest.mark.unit
+class TestDirectives:
+    """Test how meta tags and headers combine."""
+
+    @pytest.mark.parametrize(
+        "meta,header,noindex,nofollow,conflicts",
+        [
+            # Nothing, or only permissive directives: indexable
+            ([], None, False, False, []),
+            ([("robots", "index, follow")], None, False, False, []),
+            ([("robots", "all")], None, False, False, []),
+            # Either source alone restricts
+            ([("robots", "noindex")], None, True, False, []),
+            ([], "noindex", True, False, []),
+            ([("robots", "none")], None, True, True, []),
+            ([], "none", True, True, []),
+            ([("robots", "nofollow")], None, False, True, []),
+            # Meta and header combine; the most restrictive wins
+            ([("robots", "noindex")], "nofollow", True, True, []),
+            ([("robots", "index")], "noindex", True, False, [("index", "noindex")]),
+            ([("robots", "noindex")], "index", True, False, [("index", "noindex")]),
+            ([("robots", "all")], "noindex", True, False, [("index", "noindex")]),
+            ([("robots", "follow")], "nofollow", False, True, [("follow", "nofollow")]),
+            ([("robots", "index, noindex")], None, True, False, [("index", "noindex")]),
+            # Crawler-specific directives apply to that crawler only
+            ([("googlebot", "noindex")], None, True, False, []),
+            ([("bingbot", "noindex")], None, False, False, []),
+            ([("robots", "index"), ("googlebot", "noindex")], None, True, False, [("index", "noindex")]),
+            ([], "googlebot: noindex", True, False, []),
+            ([], "bingbot: noindex", False, False, []),
+            ([], "bingbot: noindex, googlebot: nofollow", False, True, []),
+            # Directives with values are not crawler names
+            ([], "unavailable_after: 25 Jun 2030 15:00:00 PST", False, False, []),
+            ([], "max-snippet: 20, noindex", True, False, []),
+        ],
+    )
+    def test_truth_table(self, meta, header, noindex, nofollow, conflicts):
+        """Test each combination of robots meta tags and X-Robots-Tag values."""
+        # Arrange
+        headers = {"x-robots-tag": header} if header is not None else {}
+
+        # Act
+        directives = page_directives(_html(*meta), headers, "googlebot")
+
+        # Assert
+        assert directives.noindex is noindex
+        assert directives.nofollow is nofollow
+        assert directives.conflicts() == conflicts
+
+    def test_parse_directives(self):
+        """Test agent prefixes apply until the next one and shorthands expand."""
+        assert parse_directives("NoIndex, googlebot: none, otherbot: follow") == {
+            "*": {"noindex"},
+            "googlebot": {"noindex", "nofollow"},
+            "otherbot": {"follow"},
+        }
+
+    def test_sources(self):
+        """Test a directive names every source it came from."""
+        # Act
+        directives = page_directives(_html(("robots", "noindex")), {"X-Robots-Tag": "noindex"})
+
+        # Assert
+        assert directives.sources("noindex") == "robots meta and X-Robots-Tag"
+
+
+@pytest.mark.unit
+class TestAnalyze:
+    """Test findings across crawled pages."""
+
+    def test_noindexed_seed_is_critical(self):
+        """Test noindex on the seed or a page one click away is high severity."""
+        # Arrange
+        pages = [
+            _page("https://example.com/", header="noindex", depth=0),
+            _page("https://example.com/shop", ("robots", "noindex"), depth=1),
+            _page("https://example.com/tag/blue", ("robots", "noindex"), depth=2),
+        ]
+
+        # Act
+        issues = analyze(pages)
+
+        # Assert
+        assert _found(issues) == [
+            ("https://example.com/", "important_page_noindex"),
+            ("https://example.com/shop", "important_page_noindex"),
+            ("https://example.com/tag/blue", "noindex_directive"),
+        ]
+        assert [i.severity for i in issues] == ["high", "high", "info"]
+        assert issues[0].detail == (
+            "The start page is noindexed by X-Robots-Tag and will drop out of search results"
+        )
+
+    def test_important_depth_is_configurable(self):
+        """Test only the seed is critical with important_depth=0."""
+        # Arrange
+        pages = [_page("https://example.com/shop", ("robots", "noindex"), depth=1)]
+
+        # Act
+        issues = analyze(pages, options=IndexabilityOptions(important_depth=0))
+
+        # Assert
+        assert _found(issues) == [("https://example.com/shop", "noindex_directive")]
+
+    def test_conflict_between_meta_and_header(self):
+        """Test a header/meta conflict names both sources and the winning directive."""
+        # Act
+        issues = analyze([_page("https://example.com/a", ("robots", "index, follow"), header="noindex")])
+
+        # Assert
+        conflict = next(i for i in issues if i.type == "conflicting_robots_directives")
+        assert conflict.detail == (
+            "'index' (robots meta) conflicts with 'noindex' (X-Robots-Tag); search engines apply 'noindex'"
+        )
+        assert "noindex_directive" in [i.type for i in issues]
+
+    def test_blocked_pages_with_signals(self):
+        """Test pages blocked by robots.txt report canonical, hreflang, and noindex as unseen."""
+        # Arrange
+        robots = parse_robots("User-agent: *\nDisallow: /private/\n")
+        head = (
+            '<link rel="canonical" href="https://example.com/private/a">'
+            '<link rel="alternate" hreflang="de" href="https://example.com/de/private/a">'
+        )
+        pages = [
+            _page("https://example.com/private/a", ("robots", "noindex"), head=head),
+            _page("https://example.com/private/b"),
+            _page("https://example.com/public", head=head),
+        ]
+
+        # Act
+        issues = analyze(pages, robots)
+
+        # Assert
+        assert _found(issues) == [("https://example.com/private/a", "robots_blocked_signals")]
+        assert "never see its canonical, hreflang, noindex" in issues[0].detail
+
+    def test_error_pages_are_skipped(self):
+        """Test noindex on error responses is not reported."""
+        # Arrange
+        page = FetchedPage(url="https://example.com/", status=404, headers={"X-Robots-Tag": "noindex"})
+
+        # Act & Assert
+        assert analyze([page]) == []
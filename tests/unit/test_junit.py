@@ -0,0 +1,137 @@
+"""
+Unit tests for the JUnit XML exporter.
+"""
+import xml.etree.ElementTree as ET
+from pathlib import Path
+
+import pytest
+
+from tinyseoai.data.demo import build_demo_result
+from tinyseoai.data.models import AuditResult
+from tinyseoai.data.severity import Category
+from tinyseoai.reporting.junit import JunitOptions, render_junit, write_junit
+
+JUNIT_XSD = Path(__file__).parents[1] / "fixtures" / "schemas" / "junit-10.xsd"
+
+
+def _result(issues: list[dict], **meta) -> AuditResult:
+    return AuditResult(site="https://example.com/", pages_scanned=1, issues=issues, meta=meta)
+
+
+def _suite(root: ET.Element, name: str) -> ET.Element:
+    return next(s for s in root.iter("testsuite") if s.get("name") == name)
+
+
+@pytest.mark.unit
+class TestJunitOutcomes:
+    """Test severities map to failures, skips, and passes."""
+
+    def test_high_is_failure(self):
+        """Test high issues become failures carrying the issue type."""
+        # Arrange
+        result = _result([{"url": "https://example.com/", "type": "title_missing", "severity": "high"}])
+
+        # Act
+        root = ET.fromstring(render_junit(result))
+
+        # Assert
+        failure = root.find(".//failure")
+        assert failure is not None
+        assert failure.get("type") == "title_missing"
+        assert "Recommendation:" in failure.text
+        assert root.get("failures") == "1"
+
+    @pytest.mark.parametrize("mode,tag", [("failure", "failure"), ("skipped", "skipped")])
+    def test_warnings_mode(self, mode, tag):
+        """Test medium issues follow warnings_as."""
+        # Arrange
+        result = _result([{"url": "https://example.com/", "type": "missing_hsts", "severity": "medium"}])
+
+        # Act
+        root = ET.fromstring(render_junit(result, JunitOptions(warnings_as=mode)))
+
+        # Assert
+        assert root.find(f".//testcase/{tag}") is not None
+
+    def test_low_passes(self):
+        """Test low issues pass with their detail in system-out."""
+        # Arrange
+        result = _result(
+            [{"url": "https://example.com/", "type": "thin_content", "severity": "low", "detail": "120 words"}]
+        )
+
+        # Act
+        root = ET.fromstring(render_junit(result))
+
+        # Assert
+        case = root.find(".//testcase[@name='https://example.com/']")
+        assert case.find("failure") is None and case.find("skipped") is None
+        assert case.find("system-out").text.startswith("120 words")
+
+    def test_clean_categories_get_placeholder(self):
+        """Test every category has a suite, with a passing case when clean."""
+        # Act
+        root = ET.fromstring(render_junit(_result([])))
+
+        # Assert
+        names = [s.get("name") for s in root.iter("testsuite")]
+        assert names == [f"seo.{c.value}" for c in Category if c is not Category.OTHER]
+        assert all(s.get("tests") == "1" and s.get("failures") == "0" for s in root.iter("testsuite"))
+
+
+@pytest.mark.unit
+class TestJunitMetadata:
+    """Test timestamps, durations, and escaping."""
+
+    def test_timestamp_and_duration(self):
+        """Test suite timestamps drop the timezone and durations add up."""
+        # Arrange
+        result = _result([], timestamp="2025-01-01T10:20:30.123456Z", duration_seconds=7)
+
+        # Act
+        root = ET.fromstring(render_junit(result))
+
+        # Assert
+        suites = list(root.iter("testsuite"))
+        assert suites[0].get("timestamp") == "2025-01-01T10:20:30"
+        assert root.get("time") == "7.000"
+        assert sum(float(s.get("time")) for s in suites) == pytest.approx(7, abs=0.01)
+
+    def test_hostile_text_round_trips(self):
+        """Test markup, quotes, and control characters cannot break the XML."""
+        # Arrange
+        detail = '</failure><script>"x" & \'y\'\x00\x07 ]]>'
+        result = _result(
+            [{"url": "https://example.com/?a=1&b=<2>", "type": "title_missing", "severity": "high", "detail": detail}]
+        )
+
+        # Act
+        root = ET.fromstring(render_junit(result))
+
+        # Assert
+        failure = root.find(".//failure")
+        assert failure.text.startswith("</failure><script>\"x\" & 'y' ]]>")
+        assert root.find(".//testcase").get("name") == "https://example.com/?a=1&b=<2>"
+
+
+@pytest.mark.unit
+class TestJunitSchema:
+    """Test output validates against the JUnit XSD."""
+
+    @pytest.mark.parametrize("fixture", ["demo", "pathological.json", "unreachable_site.json"])
+    def test_validates(self, results_fixtures_dir, tmp_path, fixture):
+        """Test rendered reports validate against the bundled junit-10.xsd."""
+        # Arrange
+        etree = pytest.importorskip("lxml.etree")
+        if fixture == "demo":
+            result = build_demo_result()
+            result.meta["duration_seconds"] = 3.5
+        else:
+            result = AuditResult.load(results_fixtures_dir / fixture)
+
+        # Act
+        path = write_junit(result, tmp_path / "junit.xml", JunitOptions(warnings_as="skipped"))
+
+        # Assert
+        schema = etree.XMLSchema(etree.parse(str(JUNIT_XSD)))
+        schema.assertValid(etree.parse(str(path)))
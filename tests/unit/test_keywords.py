@@ -0,0 +1,201 @@
+"""
+Unit tests for target keyword coverage.
+"""
+from pathlib import Path
+
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.keywords import (  # noqa: E402
+    KeywordOptions,
+    KeywordTargets,
+    analyze,
+    analyze_page,
+    tokens,
+)
+from tinyseoai.audit.crawler import FetchedPage  # noqa: E402
+from tinyseoai.exceptions import ConfigError  # noqa: E402
+
+FIXTURES = Path(__file__).parents[1] / "fixtures" / "keywords"
+SITE = "https://pottery.example/"
+
+
+def _types(issues) -> list[str]:
+    return sorted(i.type for i in issues)
+
+
+@pytest.mark.unit
+class TestTargets:
+    """Test loading targets and matching them to URLs."""
+
+    def test_toml_and_csv_agree(self):
+        """Test both file formats load the same targets."""
+        # Act
+        toml = KeywordTargets.load(FIXTURES / "targets.toml")
+        csv = KeywordTargets.load(FIXTURES / "targets.csv")
+
+        # Assert
+        assert toml == csv
+        assert toml.for_url(SITE) == ["pottery studio"]
+
+    def test_patterns_combine(self):
+        """Test a page takes the keywords of every pattern it matches, once each."""
+        # Arrange
+        targets = KeywordTargets.load(FIXTURES / "targets.toml")
+
+        # Act
+        keywords = targets.for_url(f"{SITE}mugs/celadon-large")
+
+        # Assert
+        assert keywords == ["stoneware mug", "stoneware mugs", "celadon mug"]
+        assert targets.for_url(f"{SITE}about") == []
+
+    @pytest.mark.parametrize(
+        "text, error",
+        [
+            ('"re:(" = ["mug"]', "Invalid regex pattern"),
+            ('"/mugs/*" = ["--"]', "has no words"),
+            ('"/mugs/*" = "mug"', "valid list"),
+            ("/mugs/* = [", "Invalid TOML"),
+        ],
+    )
+    def test_invalid_toml(self, text, error):
+        """Test bad patterns, keywords, and TOML raise ConfigError."""
+        with pytest.raises(ConfigError, match=error):
+            KeywordTargets.from_toml(text)
+
+    def test_short_csv_row(self):
+        """Test a CSV row without a keyword names its line."""
+        with pytest.raises(ConfigError, match="line 2"):
+            KeywordTargets.from_csv("/mugs/*,stoneware mug\n/bowls/*\n")
+
+    def test_missing_file(self, tmp_path):
+        """Test an unreadable file is a ConfigError naming it."""
+        with pytest.raises(ConfigError, match="nope.toml"):
+            KeywordTargets.load(tmp_path / "nope.toml")
+
+
+@pytest.mark.unit
+class TestTokens:
+    """Test Unicode-aware, case-folded tokenization."""
+
+    @pytest.mark.parametrize(
+        "text, expected",
+        [
+            ("Stoneware MUGS, hand-thrown!", ["stoneware", "mugs", "hand", "thrown"]),
+            ("STRASSE Straße", ["strasse", "strasse"]),
+            ("Tasse à café", ["tasse", "à", "café"]),
+            ("陶器マグ", ["陶", "器", "マ", "グ"]),
+            ("ｍｕｇ", ["mug"]),
+        ],
+    )
+    def test_tokens(self, text, expected):
+        assert tokens(text) == expected
+
+
+@pytest.mark.unit
+class TestAnalyze:
+    """Test placements and stuffing on fixture pages."""
+
+    def test_keywords_placed(self, html_fixture):
+        """Test a page with the keyword in every placement has no issues."""
+        # Act
+        coverage, issues = analyze_page(
+            f"{SITE}mugs/", html_fixture("keywords_good.html"), ["stoneware mugs"]
+        )
+
+        # Assert
+        assert issues == []
+        assert set(coverage.placements.values()) == {True}
+
+    def test_no_stemming(self, html_fixture):
+        """Test the singular keyword does not match the plural on the page."""
+        # Act
+        _, issues = analyze_page(
+            f"{SITE}mugs/", html_fixture("keywords_good.html"), ["stoneware mug"]
+        )
+
+        # Assert
+        assert len(issues) == 4
+
+    def test_keywords_missing(self, html_fixture):
+        """Test each placement without a keyword is reported; navigation is not the intro."""
+        # Act
+        coverage, issues = analyze_page(
+            f"{SITE}mugs/", html_fixture("keywords_missing.html"), ["stoneware mug"]
+        )
+
+        # Assert
+        assert _types(issues) == [
+            "keyword_missing_first_paragraph",
+            "keyword_missing_h1",
+            "keyword_missing_meta_description",
+            "keyword_missing_title",
+        ]
+        title = next(i for i in issues if i.type == "keyword_missing_title")
+        assert title.severity == "medium"
+        assert title.detail == "Title contains none of the target keywords: stoneware mug"
+        assert coverage.density["stoneware mug"] > 0
+
+    def test_absent_elements_not_reported(self):
+        """Test a page without a description or H1 is left to the meta and headings checks."""
+        # Arrange
+        html = "<html><head><title>Stoneware mug</title></head><body><p>A stoneware mug.</p>"
+
+        # Act
+        coverage, issues = analyze_page(f"{SITE}mugs/", html, ["stoneware mug"])
+
+        # Assert
+        assert issues == []
+        assert coverage.placements["h1"] is None
+        assert coverage.placements["meta_description"] is None
+
+    def test_stuffing(self):
+        """Test a keyword above the density threshold is reported."""
+        # Arrange
+        text = "stoneware mug " * 10 + "glazed by hand in small batches " * 20
+        html = f"<title>Stoneware mug</title><h1>Stoneware mug</h1><p>{text}</p>"
+
+        # Act
+        _, issues = analyze_page(f"{SITE}mugs/", html, ["stoneware mug"], KeywordOptions())
+
+        # Assert
+        (issue,) = issues
+        assert issue.type == "keyword_stuffing"
+        assert issue.detail.startswith("Target keyword 'stoneware mug' appears 12 times, 16.7%")
+
+    def test_short_pages_not_checked_for_stuffing(self):
+        """Test density is not judged on pages below min_words."""
+        # Arrange
+        html = "<title>Stoneware mug</title><p>Stoneware mug, stoneware mug.</p>"
+
+        # Act
+        _, issues = analyze_page(f"{SITE}mugs/", html, ["stoneware mug"])
+
+        # Assert
+        assert issues == []
+
+    def test_site_coverage(self, html_fixture):
+        """Test only targeted pages are analyzed and the table counts them."""
+        # Arrange
+        targets = KeywordTargets.load(FIXTURES / "targets.toml")
+        pages = [
+            FetchedPage(url=f"{SITE}mugs/", body=html_fixture("keywords_good.html")),
+            FetchedPage(url=f"{SITE}mugs/celadon", body=html_fixture("keywords_missing.html")),
+            FetchedPage(url=f"{SITE}about", body=html_fixture("keywords_missing.html")),
+        ]
+
+        # Act
+        analysis = analyze(pages, targets)
+
+        # Assert
+        assert [p.url for p in analysis.pages] == [f"{SITE}mugs/", f"{SITE}mugs/celadon"]
+        assert analysis.coverage()["title"] == [1, 2]
+        assert len(analysis.issues) == 4
+        assert analysis.meta()["pages"][1]["keywords"] == [
+            "stoneware mug",
+            "stoneware mugs",
+            "celadon mug",
+        ]
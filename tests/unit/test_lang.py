@@ -0,0 +1,165 @@
+"""
+Unit tests for <html lang> and language consistency checks.
+"""
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.lang import (  # noqa: E402
+    LangOptions,
+    analyze,
+    is_valid_language_tag,
+)
+from tinyseoai.audit.crawler import FetchedPage  # noqa: E402
+
+NO_DETECTION = LangOptions(detector=None)
+
+
+def _page(url: str, lang: str | None, head: str = "", text: str = "Mugs") -> FetchedPage:
+    attribute = f' lang="{lang}"' if lang is not None else ""
+    return FetchedPage(url=url, body=f"<html{attribute}><head>{head}</head><body><p>{text}</p></body></html>")
+
+
+def _found(issues) -> list[tuple[str, str]]:
+    return sorted((i.url, i.type) for i in issues)
+
+
+@pytest.mark.unit
+class TestLanguageTag:
+    """Test BCP 47 syntax validation."""
+
+    @pytest.mark.parametrize("tag", ["en", "en-US", "de-CH", "zh-Hant-TW", "sr-Latn", "es-419", "tlh"])
+    def test_valid(self, tag):
+        """Test common well-formed tags pass."""
+        assert is_valid_language_tag(tag)
+
+    @pytest.mark.parametrize("tag", ["en_US", "english", "e", "en-", "en US", "123"])
+    def test_invalid(self, tag):
+        """Test underscores, names, and stray characters fail."""
+        assert not is_valid_language_tag(tag)
+
+
+@pytest.mark.unit
+class TestAttribute:
+    """Test missing, empty, and invalid lang attributes."""
+
+    @pytest.mark.parametrize(
+        "lang,issue_type,detail",
+        [
+            (None, "missing_html_lang", "The lang attribute on <html> is missing."),
+            ("", "missing_html_lang", "The lang attribute on <html> is empty."),
+            ("  ", "missing_html_lang", "The lang attribute on <html> is empty."),
+            ("en_US", "html_lang_invalid", '<html lang="en_US"> is not a valid BCP 47 language tag; did you mean "en-US"?'),
+            ("english", "html_lang_invalid", '<html lang="english"> is not a valid BCP 47 language tag'),
+        ],
+    )
+    def test_attribute(self, lang, issue_type, detail):
+        """Test each bad attribute is a medium finding."""
+        # Act
+        issues = analyze([_page("https://example.com/", lang)], NO_DETECTION)
+
+        # Assert
+        assert [i.type for i in issues] == [issue_type]
+        assert issues[0].severity == "medium"
+        assert issues[0].detail.startswith(detail)
+
+    def test_valid_lang_passes(self):
+        """Test a valid tag produces no findings."""
+        assert analyze([_page("https://example.com/", "en-GB")], NO_DETECTION) == []
+
+
+@pytest.mark.unit
+class TestDetection:
+    """Test declared-versus-detected language."""
+
+    def test_german_page_declared_en(self, html_fixture):
+        """Test a confident detection of another language is reported as info."""
+        # Arrange
+        page = FetchedPage(url="https://example.com/tassen", body=html_fixture("lang_german_declared_en.html"))
+        options = LangOptions(detector=lambda text: ("de", 0.99))
+
+        # Act
+        issues = analyze([page], options)
+
+        # Assert
+        assert [i.type for i in issues] == ["html_lang_mismatch"]
+        assert issues[0].severity == "info"
+        assert issues[0].detail == 'Declared lang="en" but the text reads as "de" (confidence 0.99)'
+
+    @pytest.mark.parametrize(
+        "detected,words",
+        [
+            (("de", 0.6), 80),  # not confident enough
+            (("en", 0.99), 80),  # agrees with lang="en-US"
+            (("de", 0.99), 10),  # too short to detect
+            (None, 80),  # detector unavailable
+        ],
+    )
+    def test_no_mismatch(self, detected, words):
+        """Test uncertain, matching, short, and undetected pages are not reported."""
+        # Arrange
+        page = _page("https://example.com/", "en-US", text="wort " * words)
+
+        # Act & Assert
+        assert analyze([page], LangOptions(detector=lambda text: detected)) == []
+
+    def test_langdetect(self, html_fixture):
+        """Test the default detector recognizes German when langdetect is installed."""
+        # Arrange
+        pytest.importorskip("langdetect")
+        page = FetchedPage(url="https://example.com/tassen", body=html_fixture("lang_german_declared_en.html"))
+
+        # Act
+        issues = analyze([page])
+
+        # Assert
+        assert [i.type for i in issues] == ["html_lang_mismatch"]
+        assert 'reads as "de"' in issues[0].detail
+
+
+@pytest.mark.unit
+class TestSite:
+    """Test multilingual sites without hreflang."""
+
+    def test_mixed_languages_without_hreflang(self):
+        """Test pages in several languages without hreflang are listed once for the site."""
+        # Arrange
+        pages = [
+            _page("https://example.com/", "en"),
+            _page("https://example.com/de/", "de-DE"),
+            _page("https://example.com/fr/", "fr"),
+        ]
+
+        # Act
+        issues = analyze(pages, NO_DETECTION)
+
+        # Assert
+        assert _found(issues) == [("https://example.com/", "mixed_languages_without_hreflang")]
+        assert issues[0].detail == (
+            "Pages declare 3 languages (en (1), de (1), fr (1)) but 3 have no hreflang links to "
+            "their translations: https://example.com/, https://example.com/de/, https://example.com/fr/"
+        )
+
+    def test_regional_variants_are_one_language(self):
+        """Test en and en-GB pages without hreflang are not a multilingual site."""
+        # Arrange
+        pages = [_page("https://example.com/", "en"), _page("https://example.com/uk/", "en-GB")]
+
+        # Act & Assert
+        assert analyze(pages, NO_DETECTION) == []
+
+    def test_hreflang_annotated_site_passes(self):
+        """Test translations linked with hreflang pass."""
+        # Arrange
+        hreflang = (
+            '<link rel="alternate" hreflang="en" href="https://example.com/">'
+            '<link rel="alternate" hreflang="de" href="https://example.com/de/">'
+        )
+        pages = [
+            _page("https://example.com/", "en", head=hreflang),
+            _page("https://example.com/de/", "de", head=hreflang),
+        ]
+
+        # Act & Assert
+        assert analyze(pages, NO_DETECTION) == []
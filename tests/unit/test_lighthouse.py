@@ -0,0 +1,171 @@
+"""
+Unit tests for the Lighthouse CLI wrapper.
+"""
+import json
+import sys
+from pathlib import Path
+
+import pytest
+
+from tinyseoai.data.models import AuditResult, Issue
+from tinyseoai.data.query import issue_category
+from tinyseoai.integrations.lighthouse import (
+    LighthouseOptions,
+    lighthouse_audit_id,
+    lighthouse_issues,
+    merge_run,
+    run,
+)
+
+REPORT = Path(__file__).parents[1] / "fixtures" / "lighthouse" / "report.json"
+URL = "https://example.com/"
+
+pytestmark = pytest.mark.skipif(sys.platform == "win32", reason="stub executable needs a shebang")
+
+
+@pytest.fixture
+def stub(tmp_path):
+    """Write a fake lighthouse executable; returns a factory taking its script body."""
+
+    def make(body: str) -> str:
+        path = tmp_path / "lighthouse"
+        path.write_text(f"#!{sys.executable}\nimport sys, time\n{body}\n")
+        path.chmod(0o755)
+        return str(path)
+
+    return make
+
+
+@pytest.fixture
+def lighthouse(stub, tmp_path) -> str:
+    """A stub that records its arguments and prints the captured report."""
+    args = tmp_path / "args.json"
+    return stub(
+        f"import json; json.dump(sys.argv[1:], open({str(args)!r}, 'w'))\n"
+        f"sys.stdout.write(open({str(REPORT)!r}).read())"
+    )
+
+
+@pytest.mark.unit
+class TestRun:
+    """Test running the binary and parsing its report."""
+
+    def test_report(self, lighthouse):
+        """Test scores, opportunities, and diagnostics come from the report."""
+        # Act
+        result = run(URL, LighthouseOptions(binary=lighthouse, preset="desktop"))
+
+        # Assert
+        assert result.status == "ok"
+        assert result.version == "12.2.1"
+        assert result.scores == {
+            "performance": 62,
+            "accessibility": 88,
+            "best-practices": 96,
+            "seo": 91,
+        }
+        assert [a.id for a in result.opportunities] == [
+            "render-blocking-resources",
+            "uses-optimized-images",
+            "unused-javascript",
+        ]
+        assert [a.id for a in result.diagnostics] == ["mainthread-work-breakdown", "font-display"]
+        args = json.loads((Path(lighthouse).parent / "args.json").read_text())
+        assert args[:2] == [URL, "--output=json"]
+        assert "--chrome-flags=--headless" in args
+        assert "--preset=desktop" in args
+
+    def test_issues(self, lighthouse):
+        """Test failed audits become performance issues that keep the audit id."""
+        # Act
+        issues = lighthouse_issues(run(URL, LighthouseOptions(binary=lighthouse)))
+
+        # Assert
+        assert len(issues) == 5
+        first = issues[0]
+        assert first.type == "lighthouse_render_blocking_resources"
+        assert lighthouse_audit_id(first.type) == "render-blocking-resources"
+        assert first.severity == "medium"
+        assert first.detail == (
+            "Lighthouse: Eliminate render-blocking resources (Potential savings of 1,210 ms)"
+        )
+        assert issue_category(first).value == "performance"
+        assert issues[-1].detail == "Lighthouse: Ensure text remains visible during webfont load"
+
+    def test_missing_binary(self, tmp_path):
+        """Test a missing binary degrades to an informational issue."""
+        # Act
+        result = run(URL, LighthouseOptions(binary=str(tmp_path / "nope")))
+
+        # Assert
+        assert result.status == "not_installed"
+        (issue,) = lighthouse_issues(result)
+        assert issue.type == "lighthouse_unavailable"
+        assert issue.severity == "info"
+        assert lighthouse_audit_id(issue.type) is None
+
+    def test_timeout(self, stub):
+        """Test a run that takes too long is stopped."""
+        # Arrange
+        binary = stub("time.sleep(5)")
+
+        # Act
+        result = run(URL, LighthouseOptions(binary=binary, timeout=0.5))
+
+        # Assert
+        assert result.status == "timeout"
+        assert lighthouse_issues(result)[0].type == "lighthouse_failed"
+
+    def test_crash(self, stub):
+        """Test a non-zero exit is reported with the last line of stderr."""
+        # Arrange
+        binary = stub("sys.stderr.write('Runtime error: CHROME_NOT_FOUND\\n'); sys.exit(1)")
+
+        # Act
+        result = run(URL, LighthouseOptions(binary=binary))
+
+        # Assert
+        assert result.status == "failed"
+        assert result.error == "exit code 1: Runtime error: CHROME_NOT_FOUND"
+
+
+@pytest.mark.unit
+class TestMergeRun:
+    """Test adding a run to an audit result."""
+
+    def test_merge_twice(self, lighthouse):
+        """Test a second run replaces the first's issues and keeps the audit's own."""
+        # Arrange
+        audit = AuditResult(
+            site=URL,
+            pages_scanned=3,
+            issues=[Issue(url=URL, type="title_missing", severity="high")],
+            meta={"health_score": 80},
+        )
+        options = LighthouseOptions(binary=lighthouse)
+
+        # Act
+        merge_run(audit, run(URL, options))
+        merge_run(audit, run(URL, options))
+
+        # Assert
+        assert len(audit.issues) == 6
+        assert audit.pages_scanned == 3
+        assert audit.meta["health_score"] == 80
+        assert audit.meta["lighthouse"][URL]["scores"]["performance"] == 62
+
+    def test_runs_kept_per_url(self, lighthouse):
+        """Test a run on another page is added next to the first, not in its place."""
+        # Arrange
+        audit = AuditResult(site=URL, pages_scanned=3, issues=[], meta={})
+        options = LighthouseOptions(binary=lighthouse)
+        pricing = URL + "pricing"
+
+        # Act
+        merge_run(audit, run(URL, options))
+        merge_run(audit, run(pricing, options))
+
+        # Assert
+        assert set(audit.meta["lighthouse"]) == {URL, pricing}
+        assert audit.meta["lighthouse"][URL]["url"] == URL
+        assert {issue.url for issue in audit.issues} == {URL, pricing}
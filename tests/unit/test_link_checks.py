@@ -0,0 +1,256 @@
+"""
+Unit tests for the per-page broken link checker.
+"""
+import time
+
+import httpx
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.links import (  # noqa: E402
+    LinkCheckOptions,
+    check_links,
+    link_issues,
+    page_links,
+)
+from tinyseoai.utils.http import HttpOptions, RetryPolicy, client  # noqa: E402
+
+PAGE = "https://example.com/blog/post"
+
+HTML = """
+<html><body>
+  <a href="/about">About</a>
+  <a href="about-team">Team</a>
+  <a href="/about">About again</a>
+  <a href="/old">Old page</a>
+  <a href="/missing">Missing</a>
+  <a href="/docs#install">Install</a>
+  <a href="/docs#nowhere">Nowhere</a>
+  <a href="#top">Top</a>
+  <a href="https://partner.example.org/" rel="nofollow sponsored">Partner</a>
+  <a href="https://gone.example.net/">Gone</a>
+  <a href="mailto:hi@example.com">Mail</a>
+  <a href="tel:+15555550100">Call</a>
+  <a href="javascript:void(0)">JS</a>
+</body></html>
+"""
+
+DOCS = '<html><body><h2 id="install">Install</h2></body></html>'
+
+
+class _Server:
+    """Scripted site: a redirect chain, 404s, and a page with anchors."""
+
+    def __init__(self):
+        self.requests: list[httpx.Request] = []
+
+    def __call__(self, request: httpx.Request) -> httpx.Response:
+        self.requests.append(request)
+        url = str(request.url)
+        if url == "https://example.com/old":
+            return httpx.Response(301, headers={"location": "/older"})
+        if url == "https://example.com/older":
+            return httpx.Response(302, headers={"location": "/about"})
+        if url in ("https://example.com/about", "https://example.com/blog/about-team"):
+            return httpx.Response(200)
+        if url == "https://example.com/docs":
+            return httpx.Response(200, text=DOCS)
+        if url == "https://partner.example.org/":
+            return httpx.Response(200)
+        return httpx.Response(404)
+
+    def client(self) -> httpx.AsyncClient:
+        return httpx.AsyncClient(transport=httpx.MockTransport(self))
+
+
+def _by_url(reports):
+    return {r.url: r for r in reports}
+
+
+@pytest.mark.unit
+class TestPageLinks:
+    """Test link extraction."""
+
+    def test_extract(self):
+        """Test links are resolved, de-duplicated, and classified."""
+        # Act
+        links = _by_url(page_links(PAGE, HTML))
+
+        # Assert
+        assert "https://example.com/blog/about-team" in links
+        assert len([u for u in links if u == "https://example.com/about"]) == 1
+        assert all("#top" not in u for u in links)
+        assert links["https://example.com/about"].internal
+        assert not links["https://gone.example.net/"].internal
+        assert links["https://partner.example.org/"].nofollow
+
+    def test_skipped_schemes(self):
+        """Test mailto, tel, and javascript links are skipped."""
+        # Act
+        skipped = [r.skipped for r in page_links(PAGE, HTML) if r.skipped]
+
+        # Assert
+        assert skipped == ["mailto link", "tel link", "javascript link"]
+
+
+@pytest.mark.unit
+class TestCheckLinks:
+    """Test probing link targets."""
+
+    @pytest.mark.asyncio
+    async def test_statuses_and_redirects(self):
+        """Test status codes and redirect targets are recorded per link."""
+        # Arrange
+        server = _Server()
+
+        # Act
+        reports = _by_url(await check_links(PAGE, HTML, LinkCheckOptions(per_host_rps=1000), client=server.client()))
+
+        # Assert
+        assert reports["https://example.com/old"].status_code == 200
+        assert reports["https://example.com/old"].redirected_to == "https://example.com/about"
+        assert reports["https://example.com/missing"].broken
+        assert not reports["https://example.com/about"].broken
+
+    @pytest.mark.asyncio
+    async def test_targets_probed_once(self):
+        """Test links differing only by fragment share one request."""
+        # Arrange
+        server = _Server()
+
+        # Act
+        await check_links(PAGE, HTML, LinkCheckOptions(per_host_rps=1000), client=server.client())
+
+        # Assert
+        docs = [r for r in server.requests if r.url.path == "/docs"]
+        assert len(docs) == 1
+
+    @pytest.mark.asyncio
+    async def test_fragments(self):
+        """Test fragment checks find existing ids and flag missing ones."""
+        # Arrange
+        options = LinkCheckOptions(per_host_rps=1000, check_fragments=True)
+
+        # Act
+        reports = _by_url(await check_links(PAGE, HTML, options, client=_Server().client()))
+
+        # Assert
+        assert reports["https://example.com/docs#install"].fragment_found is True
+        assert reports["https://example.com/docs#nowhere"].fragment_found is False
+        assert reports["https://example.com/about"].fragment_found is None
+
+    @pytest.mark.asyncio
+    async def test_slow_endpoint_times_out(self):
+        """Test slow targets are reported as timeouts."""
+        # Arrange
+        options = LinkCheckOptions(per_host_rps=1000, timeout=0.05)
+
+        def slow(request):
+            if request.url.host == "partner.example.org":
+                raise httpx.ReadTimeout("slow", request=request)
+            return httpx.Response(200)
+
+        client = httpx.AsyncClient(transport=httpx.MockTransport(slow))
+
+        # Act
+        reports = _by_url(await check_links(PAGE, HTML, options, client=client))
+
+        # Assert
+        assert reports["https://partner.example.org/"].error == "timeout"
+
+    @pytest.mark.asyncio
+    async def test_per_host_rate_limit(self):
+        """Test requests to one host are spaced out."""
+        # Arrange
+        html = "".join(f'<a href="/p{n}">p</a>' for n in range(3))
+        server = _Server()
+        started = time.monotonic()
+
+        # Act
+        await check_links(PAGE, html, LinkCheckOptions(per_host_rps=20), client=server.client())
+
+        # Assert
+        assert time.monotonic() - started >= 0.09  # three requests at 20/s
+
+    @pytest.mark.asyncio
+    async def test_external_excluded(self):
+        """Test external links can be left unprobed."""
+        # Arrange
+        server = _Server()
+
+        # Act
+        reports = _by_url(
+            await check_links(PAGE, HTML, LinkCheckOptions(per_host_rps=1000, include_external=False), client=server.client())
+        )
+
+        # Assert
+        assert reports["https://gone.example.net/"].skipped == "external link"
+        assert all(r.url.host == "example.com" for r in server.requests)
+
+
+@pytest.mark.unit
+class TestLinkIssues:
+    """Test folding reports into issues."""
+
+    @pytest.mark.asyncio
+    async def test_issues(self):
+        """Test broken internal and external links become separate issue types."""
+        # Arrange
+        options = LinkCheckOptions(per_host_rps=1000, check_fragments=True)
+        reports = await check_links(PAGE, HTML, options, client=_Server().client())
+
+        # Act
+        issues = link_issues(PAGE, reports, options)
+
+        # Assert
+        assert sorted((i.type, i.detail) for i in issues) == [
+            ("broken_external_link", "https://gone.example.net/"),
+            ("broken_link", "https://example.com/missing"),
+            ("missing_link_fragment", "https://example.com/docs#nowhere"),
+        ]
+        assert all(i.url == PAGE for i in issues)
+
+    @pytest.mark.asyncio
+    async def test_threshold(self):
+        """Test fewer broken links than the threshold raise no issue."""
+        # Arrange
+        options = LinkCheckOptions(per_host_rps=1000, internal_error_threshold=2, external_error_threshold=2)
+        reports = await check_links(PAGE, HTML, options, client=_Server().client())
+
+        # Act / Assert
+        assert link_issues(PAGE, reports, options) == []
+
+    @pytest.mark.asyncio
+    async def test_flaky_links_downgraded(self):
+        """Test links failing only with 503s are reported a severity lower than 404s."""
+        # Arrange
+        html = (
+            '<a href="/busy">Busy</a><a href="/missing">Missing</a>'
+            '<a href="https://cdn.example.net/">CDN</a>'
+        )
+
+        def server(request):
+            if request.url.path == "/missing":
+                return httpx.Response(404)
+            return httpx.Response(503)
+
+        http = HttpOptions(retry=RetryPolicy(attempts=2, backoff=0))
+        options = LinkCheckOptions(per_host_rps=1000)
+
+        # Act
+        async with client(http, transport=httpx.MockTransport(server)) as retrying:
+            reports = _by_url(await check_links(PAGE, html, options, client=retrying))
+        issues = link_issues(PAGE, reports.values(), options)
+
+        # Assert
+        assert reports["https://example.com/busy"].attempts == 2
+        assert reports["https://example.com/busy"].transient
+        assert reports["https://example.com/missing"].attempts == 1
+        assert not reports["https://example.com/missing"].transient
+        assert sorted((i.detail, i.severity) for i in issues) == [
+            ("https://cdn.example.net/", "info"),
+            ("https://example.com/busy", "low"),
+            ("https://example.com/missing", "medium"),
+        ]
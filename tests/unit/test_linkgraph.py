@@ -0,0 +1,182 @@
+"""
+Unit tests for the internal link graph check.
+"""
+from dataclasses import dataclass, field
+
+import pytest
+
+from tinyseoai.audit.checks.linkgraph import InternalLinkGraph, LinkGraphOptions, analyze
+from tinyseoai.audit.checks_config import ChecksConfig
+
+HOME = "https://example.com/"
+
+
+@dataclass
+class _Page:
+    """A crawled page with the links the crawl found on it."""
+
+    url: str
+    links: set[str] = field(default_factory=set)
+    status: int = 200
+    html: str = "<html></html>"
+    final_url: str | None = None
+
+
+def _site(edges: dict[str, list[str]]) -> list[_Page]:
+    """Pages from "path -> linked paths", in crawl order."""
+    return [
+        _Page(url=f"https://example.com{path}", links={f"https://example.com{t}" for t in targets})
+        for path, targets in edges.items()
+    ]
+
+
+def _urls(issues, issue_type: str) -> list[str]:
+    return [i.url.removeprefix("https://example.com") for i in issues if i.type == issue_type]
+
+
+@pytest.mark.unit
+class TestFindings:
+    """Test orphans, dead ends, depth, and hubs on small graphs."""
+
+    def test_orphans_and_dead_ends(self):
+        """Test a page only the sitemap leads to is an orphan, a page linking nowhere a dead end."""
+        # Arrange
+        pages = _site(
+            {
+                "/": ["/a", "/b"],
+                "/a": ["/", "/b"],
+                "/b": [],
+                "/from-sitemap": ["/"],
+            }
+        )
+
+        # Act
+        issues = analyze(pages, HOME)
+
+        # Assert
+        assert _urls(issues, "orphan_page") == ["/from-sitemap"]
+        assert _urls(issues, "dead_end_page") == ["/b"]
+
+    def test_self_and_external_links_do_not_count(self):
+        """Test links to the page itself or to other hosts leave it a dead end."""
+        # Arrange
+        pages = [
+            _Page(HOME, links={"https://example.com/a"}),
+            _Page("https://example.com/a", links={"https://example.com/a#top", "https://x.org/"}),
+        ]
+
+        # Act
+        issues = analyze(pages, HOME)
+
+        # Assert
+        assert _urls(issues, "dead_end_page") == ["/a"]
+        assert _urls(issues, "orphan_page") == []
+
+    @pytest.mark.parametrize(("threshold", "deep"), [(1, ["/b", "/c"]), (2, ["/c"]), (3, [])])
+    def test_click_depth(self, threshold, deep):
+        """Test pages past the configured number of clicks from home are reported."""
+        # Arrange
+        pages = _site({"/": ["/a"], "/a": ["/b"], "/b": ["/c"], "/c": ["/"]})
+
+        # Act
+        issues = analyze(pages, HOME, LinkGraphOptions(max_click_depth=threshold))
+
+        # Assert
+        assert _urls(issues, "page_too_deep") == deep
+
+    def test_shortest_path_counts(self):
+        """Test depth is the fewest clicks, whatever order links are found in."""
+        # Arrange
+        pages = _site({"/": ["/a", "/d"], "/a": ["/b"], "/b": ["/c"], "/c": ["/d"], "/d": ["/"]})
+
+        # Act
+        depths = InternalLinkGraph.build(pages, HOME).depths("https://example.com/")
+
+        # Assert
+        assert depths["https://example.com/d"] == 1
+        assert depths["https://example.com/c"] == 3
+
+    def test_hubs(self):
+        """Test the most linked pages are named, most first, as one info note on home."""
+        # Arrange
+        pages = _site({"/": ["/a", "/b"], "/a": ["/", "/b"], "/b": ["/"], "/c": ["/", "/b"]})
+
+        # Act
+        issues = analyze(pages, HOME, LinkGraphOptions(top_hubs=2))
+
+        # Assert
+        (note,) = [i for i in issues if i.type == "internal_link_hubs"]
+        assert note.url == HOME and note.severity == "info"
+        assert note.detail.endswith("https://example.com/ (3), https://example.com/b (3)")
+
+    def test_hubs_off(self):
+        """Test top_hubs = 0 leaves the note out."""
+        # Act
+        issues = analyze(_site({"/": ["/a"], "/a": ["/"]}), HOME, LinkGraphOptions(top_hubs=0))
+
+        # Assert
+        assert _urls(issues, "internal_link_hubs") == []
+
+    def test_redirects_are_one_node(self):
+        """Test links to a redirected URL count for the page it ends on."""
+        # Arrange
+        pages = [
+            _Page(HOME, links={"https://example.com/old"}),
+            _Page("https://example.com/old", final_url="https://example.com/new", links={HOME}),
+            _Page("https://example.com/new", links={HOME}),
+        ]
+
+        # Act
+        issues = analyze(pages, HOME)
+
+        # Assert
+        assert _urls(issues, "orphan_page") == []
+
+    def test_error_pages_are_not_orphans(self):
+        """Test pages that failed to load are left to the HTTP error findings."""
+        # Arrange
+        pages = _site({"/": [], "/gone": []})
+        pages[1].status = 404
+
+        # Act
+        issues = analyze(pages, HOME)
+
+        # Assert
+        assert _urls(issues, "orphan_page") == []
+        assert _urls(issues, "dead_end_page") == ["/"]
+
+
+@pytest.mark.unit
+class TestScale:
+    """Test the graph stays linear in pages plus links."""
+
+    def test_large_site(self):
+        """Test a 30,000-page site with a link to home on every page."""
+        # Arrange
+        count = 30_000
+        pages = []
+        for n in range(count):
+            children = {f"https://example.com/p{c}" for c in (2 * n + 1, 2 * n + 2) if c < count}
+            pages.append(_Page(f"https://example.com/p{n}", links={HOME, *children}))
+        pages[0].url = HOME
+
+        # Act
+        issues = analyze(pages, HOME, LinkGraphOptions(max_click_depth=13))
+
+        # Assert
+        assert _urls(issues, "orphan_page") == []
+        assert len(_urls(issues, "page_too_deep")) == count - (2**14 - 1)
+
+
+@pytest.mark.unit
+class TestSettings:
+    """Test the links table of checks.toml."""
+
+    def test_checks_config(self):
+        """Test the click depth and hub count are read from the links table."""
+        # Act
+        config = ChecksConfig.from_toml("[links]\nmax_click_depth = 5\ntop_hubs = 0\n")
+
+        # Assert
+        assert config.links.max_click_depth == 5
+        assert config.links.top_hubs == 0
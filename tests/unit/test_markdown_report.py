@@ -0,0 +1,176 @@
+"""
+Unit tests for the Markdown report renderer.
+"""
+import json
+from pathlib import Path
+
+import pytest
+
+from tinyseoai.data.demo import build_demo_result
+from tinyseoai.data.diff import diff
+from tinyseoai.data.models import Issue
+from tinyseoai.reporting.markdown import RenderOptions, escape_md, render_markdown
+
+GOLDEN = Path(__file__).parents[1] / "fixtures" / "golden"
+
+
+@pytest.mark.unit
+class TestMarkdownGolden:
+    """Golden-file tests; regenerate the .md files deliberately when output changes."""
+
+    def test_demo_result(self):
+        """Test the demo result renders exactly as the golden file."""
+        # Arrange
+        summary = json.loads(build_demo_result().to_json())
+
+        # Act
+        output = render_markdown(summary)
+
+        # Assert
+        assert output == (GOLDEN / "demo.md").read_text()
+
+    def test_pathological_content(self, results_fixtures_dir):
+        """Test markdown-looking issue text renders literally."""
+        # Arrange
+        summary = json.loads((results_fixtures_dir / "pathological.json").read_text())
+
+        # Act
+        output = render_markdown(summary)
+
+        # Assert
+        assert output == (GOLDEN / "pathological.md").read_text()
+        assert "<script>" not in output
+
+
+@pytest.mark.unit
+class TestMarkdownSections:
+    """Test optional sections and limits."""
+
+    def test_escape(self):
+        """Test pipes, backticks, and angle brackets are neutralized."""
+        assert escape_md("a|b `c` <d>") == "a\\|b \\`c\\` &lt;d&gt;"
+
+    def test_pages_capped(self):
+        """Test long page lists end with an '…and N more' line."""
+        # Arrange
+        issues = [
+            {"url": f"https://example.com/p{n}", "type": "title_missing", "severity": "medium"}
+            for n in range(5)
+        ]
+        summary = {"site": "https://example.com/", "pages_scanned": 5, "issues": issues, "meta": {}}
+
+        # Act
+        output = render_markdown(summary, RenderOptions(max_pages_per_issue=2))
+
+        # Assert
+        assert "- https://example.com/p1\n- …and 3 more" in output
+        assert "https://example.com/p2" not in output
+
+    def test_ai_summary_verbatim(self, sample_audit_result, sample_ai_summary):
+        """Test the AI summary is included as written, in place of the one from the data."""
+        # Arrange
+        summary = json.loads(sample_audit_result.model_dump_json())
+        summary["ai_summary"] = sample_ai_summary
+
+        # Act
+        output = render_markdown(summary)
+
+        # Assert
+        assert "## AI analysis" in output
+        assert sample_ai_summary["summary"] in output
+        assert "## Summary" not in output
+        without_ai = render_markdown(summary, RenderOptions(include_ai=False))
+        assert "AI analysis" not in without_ai
+        assert "## Summary" in without_ai
+
+    def test_diff_and_baseline_sections(self, diff_base_result, diff_head_result):
+        """Test the diff and baselined sections appear only when provided."""
+        # Arrange
+        summary = json.loads(diff_head_result.model_dump_json())
+        options = RenderOptions(
+            diff=diff(diff_base_result, diff_head_result),
+            suppressed=[Issue(url="https://example.com/x", type="missing_hsts", severity="medium")],
+        )
+
+        # Act
+        plain = render_markdown(summary)
+        full = render_markdown(summary, options)
+
+        # Assert
+        assert "Changes since previous audit" not in plain
+        assert "| Health score | -5.5 |" in full
+        assert "## Baselined issues (1)" in full
+
+    def test_diagnostics_section(self):
+        """Test per-check diagnostics are added only when asked for, and older results still render."""
+        # Arrange
+        checks = [
+            {
+                "id": "links",
+                "status": "ran",
+                "issues": 2,
+                "duration_ms": 840.2,
+                "requests": 31,
+                "bytes_fetched": 20480,
+            },
+            {"id": "icons", "status": "skipped", "reason": "comprehensive checks are off (--fast)"},
+        ]
+        summary = {
+            "site": "https://example.com/",
+            "pages_scanned": 1,
+            "issues": [],
+            "meta": {"diagnostics": {"checks": checks}},
+        }
+        with_diagnostics = RenderOptions(include_diagnostics=True)
+
+        # Act
+        plain = render_markdown(summary)
+        full = render_markdown(summary, with_diagnostics)
+        older = render_markdown({**summary, "meta": {}}, with_diagnostics)
+
+        # Assert
+        assert "## Diagnostics" not in plain
+        assert "| links | ran | 840 ms | 31 | 20.0 KB | 2 |  |" in full
+        assert "| icons | skipped | - | - | - | - | comprehensive checks are off (--fast) |" in full
+        assert "## Diagnostics" not in older
+
+    def test_score_breakdown_section(self):
+        """Test the score breakdown lists the costliest issue types only when asked for."""
+        # Arrange
+        summary = json.loads(build_demo_result().to_json())
+
+        # Act
+        plain = render_markdown(summary)
+        full = render_markdown(summary, RenderOptions(score_breakdown=True))
+
+        # Assert
+        assert "## Score breakdown" not in plain
+        assert "| Title missing | 1 | 4.84 x 1.00 | -4.84 |" in full
+        assert "Fixing the top 3 issue types gains ~" in full
+
+    def test_keyword_section(self):
+        """Test the keyword coverage table and the pages missing a placement."""
+        # Arrange
+        summary = json.loads(build_demo_result().to_json())
+        page = {
+            "url": "https://demo.example/mugs/",
+            "keywords": ["stoneware mug"],
+            "words": 240,
+            "placements": {
+                "title": False,
+                "h1": True,
+                "first_paragraph": True,
+                "meta_description": None,
+            },
+            "density": {"stoneware mug": 0.008},
+        }
+        coverage = {"title": [0, 1], "h1": [1, 1], "first_paragraph": [1, 1]}
+        coverage["meta_description"] = [0, 0]
+        summary["meta"]["keywords"] = {"coverage": coverage, "pages": [page]}
+
+        # Act
+        output = render_markdown(summary)
+
+        # Assert
+        assert "| Title | 0/1 |" in output
+        assert "| https://demo.example/mugs/ | stoneware mug | title |" in output
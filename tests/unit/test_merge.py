@@ -0,0 +1,104 @@
+"""
+Unit tests for merging audit results.
+"""
+import pytest
+
+from tinyseoai.data.merge import absorb, merge
+from tinyseoai.data.models import AuditResult, Issue
+from tinyseoai.data.score_model import compute
+from tinyseoai.exceptions import AuditError
+
+
+def _result(site: str, pages: int, issues: list[Issue], score: float | None = None) -> AuditResult:
+    meta = {"timestamp": "2025-01-01T00:00:00Z"}
+    if score is not None:
+        meta["health_score"] = score
+    return AuditResult(site=site, pages_scanned=pages, issues=issues, meta=meta)
+
+
+@pytest.mark.unit
+class TestMerge:
+    """Test aggregate results."""
+
+    def test_empty_input_is_an_error(self):
+        """Test merging nothing raises."""
+        with pytest.raises(AuditError):
+            merge([])
+
+    def test_single_input_is_identity(self, sample_audit_result):
+        """Test a single result comes back unchanged."""
+        assert merge([sample_audit_result]) is sample_audit_result
+
+    def test_pages_summed_and_score_weighted(self):
+        """Test metrics roll up with a page-weighted score."""
+        # Arrange
+        a = _result("https://a.example/", 30, [], score=90)
+        b = _result("https://b.example/", 10, [], score=50)
+
+        # Act
+        merged = merge([a, b])
+
+        # Assert
+        assert merged.pages_scanned == 40
+        assert merged.meta["health_score"] == 80.0
+        assert merged.meta["health_grade"] == "B"
+        assert merged.meta["merged_from"] == ["https://a.example/", "https://b.example/"]
+        assert [s["health_score"] for s in merged.meta["sites"]] == [90, 50]
+        assert merged.site == "2 sites"
+
+    def test_shared_issues_kept_once(self, sample_issues):
+        """Test issues with the same fingerprint are deduplicated."""
+        # Arrange
+        a = _result("https://example.com/", 5, sample_issues[:3])
+        b = _result("https://example.com/", 5, sample_issues[1:])
+
+        # Act
+        merged = merge([a, b])
+
+        # Assert
+        assert len(merged.issues) == len(sample_issues)
+        assert merged.site == "https://example.com/"
+        assert merged.meta["health_score"] == compute(merged.issues, 10).score
+        assert "merge_conflicts" not in merged.meta
+
+    def test_conflicting_duplicates_keep_most_severe_and_are_noted(self):
+        """Test disagreements are resolved deterministically and recorded."""
+        # Arrange
+        low = Issue(url="https://example.com/", type="missing_canonical", severity="low", detail="x")
+        high = Issue(url="https://example.com/", type="missing_canonical", severity="high", detail="X ")
+
+        # Act
+        merged = merge(
+            [_result("https://example.com/", 1, [low]), _result("https://example.com/", 1, [high])]
+        )
+
+        # Assert
+        assert len(merged.issues) == 1
+        assert merged.issues[0].severity == "high"
+        assert merged.issues[0].detail == "X "
+        assert merged.meta["merge_conflicts"][0]["severities"] == ["high", "low"]
+
+
+@pytest.mark.unit
+class TestAbsorb:
+    """Test adding a partial result to an audit in place."""
+
+    def test_score_and_summary_survive(self):
+        """Test the partial's score and timestamp do not replace the audit's."""
+        # Arrange
+        own = Issue(url="https://example.com/", type="title_missing", severity="high")
+        extra = Issue(url="https://example.com/", type="lighthouse_unused_javascript", severity="low")
+        audit = _result("https://example.com/", 4, [own], score=72)
+        audit.meta["health_grade"] = "C"
+        partial = _result("https://example.com/", 0, [extra], score=100)
+        partial.meta.update({"timestamp": "2026-01-01T00:00:00Z", "health_grade": "A", "tool": "x"})
+
+        # Act
+        absorb(audit, partial)
+
+        # Assert
+        assert len(audit.issues) == 2
+        assert audit.meta["health_score"] == 72
+        assert audit.meta["health_grade"] == "C"
+        assert audit.meta["timestamp"] == "2025-01-01T00:00:00Z"
+        assert audit.meta["tool"] == "x"
@@ -0,0 +1,102 @@
+"""
+Unit tests for page-level meta tag checks.
+"""
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.meta import MetaThresholds, analyze  # noqa: E402
+
+URL = "https://example.com/mugs"
+
+
+def _types(issues) -> list[str]:
+    return sorted(i.type for i in issues)
+
+
+def _detail(issues, issue_type: str) -> str:
+    return next(i.detail for i in issues if i.type == issue_type)
+
+
+@pytest.mark.unit
+class TestAnalyze:
+    """Test defects found in fixture pages."""
+
+    def test_clean_page(self, html_fixture):
+        """Test a well-formed page has no findings."""
+        assert analyze(html_fixture("clean.html"), URL) == []
+
+    def test_lengths(self, html_fixture):
+        """Test short titles and long descriptions report their actual lengths."""
+        # Act
+        issues = analyze(html_fixture("lengths.html"), URL)
+
+        # Assert
+        assert _types(issues) == ["meta_description_too_long", "title_too_short"]
+        assert _detail(issues, "title_too_short") == "Title is 4 characters (min 30)"
+        assert _detail(issues, "meta_description_too_long") == "Meta description is 179 characters (max 160)"
+
+    def test_duplicates_and_missing(self, html_fixture):
+        """Test duplicate tags, several H1s, robots directives, and missing head tags."""
+        # Act
+        issues = analyze(html_fixture("duplicates.html"), URL)
+
+        # Assert
+        assert _types(issues) == [
+            "missing_canonical",
+            "missing_charset",
+            "missing_viewport",
+            "multiple_h1",
+            "multiple_meta_description_tags",
+            "multiple_title_tags",
+            "nofollow_directive",
+            "noindex_directive",
+        ]
+        assert _detail(issues, "multiple_h1") == "3 <h1> headings found; use one per page"
+
+    def test_malformed_html(self, html_fixture):
+        """Test unclosed and misnested markup still parses."""
+        # Act
+        issues = analyze(html_fixture("malformed.html"), URL)
+
+        # Assert
+        assert _types(issues) == [
+            "missing_canonical",
+            "missing_charset",
+            "missing_viewport",
+            "multiple_h1",
+        ]
+
+    def test_missing_title_and_h1(self):
+        """Test empty titles count as missing."""
+        # Act
+        issues = analyze("<html><head><title> </title></head><body></body></html>", URL)
+
+        # Assert
+        assert {"title_missing", "meta_description_missing", "h1_missing"} <= set(_types(issues))
+
+
+@pytest.mark.unit
+class TestThresholds:
+    """Test overriding the length limits."""
+
+    def test_custom_thresholds(self, html_fixture):
+        """Test custom limits change what counts as too short or too long."""
+        # Arrange
+        thresholds = MetaThresholds(title_min=3, description_max=200)
+
+        # Act
+        issues = analyze(html_fixture("lengths.html"), URL, thresholds)
+
+        # Assert
+        assert issues == []
+
+    def test_defaults(self):
+        """Test the default limits match the documented ranges."""
+        assert MetaThresholds().model_dump() == {
+            "title_min": 30,
+            "title_max": 60,
+            "description_min": 70,
+            "description_max": 160,
+        }
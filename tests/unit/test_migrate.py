@@ -0,0 +1,148 @@
+"""
+Unit tests for upgrading saved audit results to the current format.
+"""
+import copy
+import json
+
+import pytest
+
+from tinyseoai.data.diagnostics import Diagnostics
+from tinyseoai.data.migrate import STEPS, migrate, upgrade, v0_to_v1, version_of
+from tinyseoai.data.models import FORMAT_VERSION, AuditResult
+
+CAPTURED_V0 = [
+    "basic_summary.json",
+    "comprehensive_summary.json",
+    "unreachable_site.json",
+    "pathological.json",
+    "registry_checks_v0.json",
+]
+
+
+def _read(results_fixtures_dir, name: str) -> dict:
+    return json.loads((results_fixtures_dir / name).read_text())
+
+
+@pytest.mark.unit
+class TestSteps:
+    """Test each migration step on its own."""
+
+    def test_one_step_per_version(self):
+        """Test there is a step from every older version to the next."""
+        assert len(STEPS) == FORMAT_VERSION
+
+    def test_v0_checks_move_to_diagnostics(self, results_fixtures_dir):
+        """Test meta.checks of a registry-era file becomes meta.diagnostics.checks."""
+        # Arrange
+        old = _read(results_fixtures_dir, "registry_checks_v0.json")
+
+        # Act
+        new = v0_to_v1(old)
+
+        # Assert
+        assert "checks" not in new["meta"]
+        assert new["meta"]["diagnostics"] == {"checks": old["meta"]["checks"]}
+        assert {k: v for k, v in new["meta"].items() if k != "diagnostics"} == {
+            k: v for k, v in old["meta"].items() if k != "checks"
+        }
+
+    def test_v0_without_checks_unchanged(self, results_fixtures_dir):
+        """Test files from before the check registry pass through as they are."""
+        # Arrange
+        old = _read(results_fixtures_dir, "comprehensive_summary.json")
+
+        # Act & Assert
+        assert v0_to_v1(old) == old
+
+    @pytest.mark.parametrize("name", CAPTURED_V0)
+    def test_steps_are_pure(self, results_fixtures_dir, name):
+        """Test a step leaves its input untouched."""
+        # Arrange
+        old = _read(results_fixtures_dir, name)
+        before = copy.deepcopy(old)
+
+        # Act
+        for step in STEPS:
+            step(old)
+
+        # Assert
+        assert old == before
+
+
+@pytest.mark.unit
+class TestMigrate:
+    """Test loading results of any version."""
+
+    @pytest.mark.parametrize("name", CAPTURED_V0)
+    def test_old_files_load(self, results_fixtures_dir, name):
+        """Test every captured old file loads as the current version."""
+        # Act
+        result = migrate(_read(results_fixtures_dir, name))
+
+        # Assert
+        assert result.format_version == FORMAT_VERSION
+        assert "checks" not in result.meta
+
+    def test_diagnostics_readable_after_migration(self, results_fixtures_dir):
+        """Test migrated check runs read as diagnostics, with the newer fields defaulted."""
+        # Act
+        result = AuditResult.load(results_fixtures_dir / "registry_checks_v0.json")
+        diagnostics = Diagnostics.from_meta(result.meta)
+
+        # Assert
+        assert diagnostics is not None
+        assert diagnostics.slowest(1)[0].id == "caching"
+        assert diagnostics.requests == 0
+
+    def test_current_version_unchanged(self, results_fixtures_dir):
+        """Test a current file is read as written, and saving it gives the same file."""
+        # Arrange
+        current = AuditResult.load(results_fixtures_dir / "registry_checks_v0.json").to_json()
+
+        # Act
+        result = migrate(json.loads(current))
+
+        # Assert
+        assert upgrade(json.loads(current)) == json.loads(current)
+        assert result.to_json() == current
+
+    def test_unknown_keys_kept(self, results_fixtures_dir):
+        """Test upgrade keeps keys outside the model, such as a merged AI summary."""
+        # Arrange
+        data = {**_read(results_fixtures_dir, "basic_summary.json"), "ai_summary": {"text": "ok"}}
+
+        # Act
+        upgraded = upgrade(data)
+
+        # Assert
+        assert upgraded["ai_summary"] == {"text": "ok"}
+        assert upgraded["format_version"] == FORMAT_VERSION
+
+    def test_saving_writes_newest_version(self):
+        """Test a result is written with the current version whatever it was built with."""
+        # Arrange
+        result = AuditResult(
+            format_version=0, site="https://a.test/", pages_scanned=0, issues=[], meta={}
+        )
+
+        # Act & Assert
+        assert json.loads(result.to_json())["format_version"] == FORMAT_VERSION
+
+    @pytest.mark.parametrize(
+        "value, message",
+        [
+            ({"format_version": FORMAT_VERSION + 1}, "newer than supported"),
+            ({"format_version": "1"}, "whole number"),
+            ({"format_version": -1}, "whole number"),
+            ({"format_version": True}, "whole number"),
+            ([], "Expected an audit result object, got list"),
+        ],
+    )
+    def test_unreadable_versions(self, value, message):
+        """Test newer, malformed, and non-object values are refused."""
+        with pytest.raises(ValueError, match=message):
+            migrate(value)
+
+    def test_version_of_legacy(self):
+        """Test a result without the field is version 0."""
+        assert version_of({"site": "https://a.test/"}) == 0
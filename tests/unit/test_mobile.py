@@ -0,0 +1,144 @@
+"""
+Unit tests for viewport and mobile-friendliness checks.
+"""
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.mobile import MobileOptions, analyze  # noqa: E402
+from tinyseoai.data.scoring import IssueScorer  # noqa: E402
+from tinyseoai.data.severity import Category  # noqa: E402
+
+URL = "https://example.com/"
+
+
+def _types(issues) -> list[str]:
+    return sorted(i.type for i in issues)
+
+
+def _page(viewport: str) -> str:
+    return f'<html><head><meta name="viewport" content="{viewport}"></head><body></body></html>'
+
+
+@pytest.mark.unit
+class TestViewport:
+    """Test the viewport meta tag checks."""
+
+    def test_mobile_friendly_page_passes(self, html_fixture):
+        """Test a responsive page with a data table and srcset images has no findings."""
+        assert analyze(html_fixture("mobile_good.html"), URL) == []
+
+    def test_missing_viewport(self):
+        """Test a page without a viewport is reported as high."""
+        # Act
+        issues = analyze("<html><head><title>Mugs</title></head></html>", URL)
+
+        # Assert
+        assert _types(issues) == ["missing_viewport"]
+        assert issues[0].severity == "high"
+
+    def test_zoom_disabled_and_limited(self, html_fixture):
+        """Test user-scalable=no and maximum-scale=1 are reported separately."""
+        # Act
+        issues = analyze(html_fixture("mobile_zoom_disabled.html"), URL)
+
+        # Assert
+        assert _types(issues) == ["viewport_zoom_disabled", "viewport_zoom_limited"]
+        assert all(i.severity == "medium" for i in issues)
+        assert "user-scalable=no" in issues[0].detail
+
+    @pytest.mark.parametrize(
+        "viewport,expected",
+        [
+            ("width=device-width, initial-scale=1, maximum-scale=5", []),
+            ("width=device-width, initial-scale=1, user-scalable=yes", []),
+            ("width=device-width, initial-scale=1, user-scalable=0", ["viewport_zoom_disabled"]),
+            ("width=device-width; initial-scale=1; maximum-scale=1.5", ["viewport_zoom_limited"]),
+            ("initial-scale=1", ["viewport_missing_device_width"]),
+            ("width=device-width", ["viewport_missing_initial_scale"]),
+        ],
+    )
+    def test_viewport_settings(self, viewport, expected):
+        """Test viewport content parsing, including ";" separators and zoom values."""
+        assert _types(analyze(_page(viewport), URL)) == expected
+
+
+@pytest.mark.unit
+class TestLayout:
+    """Test the fixed-width, table, and image heuristics."""
+
+    def test_fixed_width(self, html_fixture):
+        """Test a fixed viewport width and wide inline widths; max-width is not flagged."""
+        # Act
+        issues = analyze(html_fixture("mobile_fixed_width.html"), URL)
+
+        # Assert
+        assert _types(issues) == [
+            "fixed_width_layout",
+            "viewport_fixed_width",
+            "viewport_missing_initial_scale",
+        ]
+        fixed = next(i for i in issues if i.type == "fixed_width_layout")
+        assert fixed.detail.startswith("2 elements have fixed widths over 600px")
+        assert '<div style="width:720px">' in fixed.detail
+        assert "max-width" not in fixed.detail
+        assert "border-width" not in fixed.detail
+
+    def test_fixed_width_threshold(self, html_fixture):
+        """Test the width threshold is configurable."""
+        # Act
+        issues = analyze(
+            html_fixture("mobile_fixed_width.html"), URL, MobileOptions(max_fixed_width=1000)
+        )
+
+        # Assert
+        assert "fixed_width_layout" not in _types(issues)
+
+    def test_table_layout(self, html_fixture):
+        """Test layout tables are counted once per outer table; data tables are not."""
+        # Act
+        issues = analyze(html_fixture("mobile_table_layout.html"), URL)
+
+        # Assert
+        assert _types(issues) == ["table_layout"]
+        assert issues[0].detail.startswith("2 tables appear to lay out the page")
+
+    def test_large_images_without_srcset(self, html_fixture):
+        """Test wide images without srcset or <picture> get an info note."""
+        # Act
+        issues = analyze(html_fixture("mobile_large_images.html"), URL)
+
+        # Assert
+        assert _types(issues) == ["images_not_responsive"]
+        assert issues[0].severity == "info"
+        assert issues[0].detail == (
+            "2 large images have no srcset or <picture>, so phones download the full size: "
+            "/img/kiln.jpg (2400px wide), /img/wheel.jpg (1000px wide)"
+        )
+
+    def test_listing_is_capped(self):
+        """Test long element lists end with "and N more"."""
+        # Arrange
+        html = _page("width=device-width, initial-scale=1").replace(
+            "<body></body>", "<body>" + '<div style="width: 900px"></div>' * 7 + "</body>"
+        )
+
+        # Act
+        issues = analyze(html, URL, MobileOptions(max_listed=2))
+
+        # Assert
+        assert issues[0].detail.endswith("and 5 more")
+
+
+@pytest.mark.unit
+class TestCategory:
+    """Test the mobile issue types are scored under the mobile category."""
+
+    @pytest.mark.parametrize(
+        "issue_type",
+        ["missing_viewport", "viewport_zoom_disabled", "table_layout", "images_not_responsive"],
+    )
+    def test_category(self, issue_type):
+        """Test mobile findings are categorized as mobile."""
+        assert IssueScorer()._categorize_issue(issue_type) == Category.MOBILE.value
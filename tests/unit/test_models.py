@@ -0,0 +1,74 @@
+"""
+Unit tests for the audit result model and its JSON compatibility.
+"""
+import json
+
+import pytest
+
+from tinyseoai.data.demo import build_demo_result
+from tinyseoai.data.models import AuditResult
+
+CAPTURED = ["basic_summary.json", "comprehensive_summary.json", "unreachable_site.json"]
+
+
+@pytest.mark.unit
+class TestAuditResultRoundTrip:
+    """Test that captured CLI output survives load/dump unchanged."""
+
+    @pytest.mark.parametrize("name", CAPTURED)
+    def test_round_trip_is_byte_identical(self, results_fixtures_dir, name):
+        """Test load + to_json reproduces the file exactly, adding only the format version."""
+        # Arrange
+        raw = (results_fixtures_dir / name).read_text()
+
+        # Act
+        result = AuditResult.load(results_fixtures_dir / name)
+
+        # Assert
+        assert result.to_json() == raw.replace("{\n", '{\n  "format_version": 1,\n', 1)
+        assert AuditResult.model_validate_json(result.to_json()).to_json() == result.to_json()
+
+    def test_null_detail_preserved(self, load_result_fixture):
+        """Test that missing details stay explicit nulls."""
+        # Act
+        result = load_result_fixture("basic_summary.json")
+
+        # Assert
+        assert result.issues[0].detail is None
+        assert '"detail": null' in result.to_json()
+
+    def test_meta_is_kept_verbatim(self, load_result_fixture):
+        """Test that unknown meta keys are not dropped."""
+        # Act
+        result = load_result_fixture("unreachable_site.json")
+
+        # Assert
+        assert result.meta["health_grade"] == "F"
+        assert result.meta["crawl_delay"] is None
+
+
+@pytest.mark.unit
+class TestDemoResult:
+    """Test the demo result builder."""
+
+    def test_demo_is_deterministic(self):
+        """Test two builds serialize identically."""
+        assert build_demo_result().to_json() == build_demo_result().to_json()
+
+    def test_demo_covers_all_severities(self):
+        """Test the demo exercises every severity bucket."""
+        # Act
+        result = build_demo_result()
+
+        # Assert
+        assert {i.severity for i in result.issues} == {"high", "medium", "low", "info"}
+        assert result.meta["demo"] is True
+
+    def test_demo_custom_site(self):
+        """Test issue URLs follow the requested site."""
+        # Act
+        result = build_demo_result("https://shop.example.com/")
+
+        # Assert
+        assert all(i.url.startswith("https://shop.example.com/") for i in result.issues)
+        assert json.loads(result.to_json())["site"] == "https://shop.example.com/"
@@ -0,0 +1,141 @@
+"""
+Unit tests for the executive summary written from audit data.
+"""
+import pytest
+
+from tinyseoai.data.demo import build_demo_result
+from tinyseoai.data.diff import diff
+from tinyseoai.data.models import AuditResult, Issue
+from tinyseoai.data.narrative import generate
+from tinyseoai.i18n import Locale
+
+DEMO = {
+    Locale.EN: (
+        "At 72/100 (grade C) across 5 pages, demo.tinyseoai.com has clear room for improvement. "
+        "The biggest share of the points lost, 12 of 22 points, comes from content and technical issues. "
+        'The most impactful issue is "Title missing" on 1 page, costing 4.8 points.'
+    ),
+    Locale.ES: (
+        "Con 72/100 (nota C) en 5 páginas, demo.tinyseoai.com tiene un claro margen de mejora. "
+        "La mayor parte de los puntos perdidos, 12 de 22 puntos, viene de problemas de contenido y SEO técnico. "
+        'El problema de mayor impacto es "Falta el título" en 1 página, que cuesta 4.8 puntos.'
+    ),
+}
+
+TREND = {
+    Locale.EN: "The score improved by 6 points since the last audit, with 1 issue resolved and 1 new.",
+    Locale.ES: "La puntuación subió 6 puntos desde la última auditoría, con 1 problema resuelto y 1 nuevo.",
+}
+
+
+def _previous(result: AuditResult) -> AuditResult:
+    """An earlier audit: 6 points lower, with an h1_missing issue instead of the noindex one."""
+    issues = result.issues[:-1] + [Issue(url=result.site, type="h1_missing", severity="medium")]
+    return result.model_copy(deep=True, update={"meta": {**result.meta, "health_score": 66}, "issues": issues})
+
+
+def _scored(site: str, score: float, issues: list[Issue] | None = None) -> AuditResult:
+    return AuditResult(site=site, pages_scanned=4, issues=issues or [], meta={"health_score": score, "health_grade": "B"})
+
+
+@pytest.mark.unit
+class TestSnapshots:
+    """Test the demo result reads exactly as recorded, per locale."""
+
+    @pytest.mark.parametrize("locale", list(Locale))
+    def test_demo(self, locale):
+        """Test score, categories, and the top issue, in that order."""
+        assert generate(build_demo_result(), locale) == DEMO[locale]
+
+    @pytest.mark.parametrize("locale", list(Locale))
+    def test_demo_with_trend(self, locale):
+        """Test a diff adds the trend sentence last."""
+        # Arrange
+        result = build_demo_result()
+
+        # Act
+        output = generate(result, locale, diff(_previous(result), result))
+
+        # Assert
+        assert output == f"{DEMO[locale]} {TREND[locale]}"
+
+
+@pytest.mark.unit
+class TestSentences:
+    """Test which sentences are written for which data."""
+
+    def test_clean_site(self):
+        """Test a site without issues gets two sentences and no category or top issue."""
+        # Act
+        output = generate(_scored("https://clean.example/", 100))
+
+        # Assert
+        assert "clean.example" in output and "100/100" in output
+        assert output.count(". ") == 1
+        assert "points" not in output
+
+    def test_no_pages(self):
+        """Test an unreachable site says nothing was scanned."""
+        # Arrange
+        result = AuditResult(site="https://down.example/", pages_scanned=0, issues=[], meta={})
+
+        # Act
+        output = generate(result)
+
+        # Assert
+        assert output.startswith("down.example could not be audited: no pages were scanned.")
+
+    def test_unscored(self, results_fixtures_dir):
+        """Test a result without a health score counts pages and issues instead."""
+        # Arrange
+        result = AuditResult.load(results_fixtures_dir / "pathological.json")
+        result.meta.pop("health_score", None)
+
+        # Act
+        output = generate(result)
+
+        # Assert
+        assert output.startswith(f"example.com was audited across {result.pages_scanned} pages, with ")
+
+    def test_score_bands(self):
+        """Test the score sentence differs between bands."""
+        # Act
+        sentences = {generate(_scored("https://a.example/", score)).split(". ")[0] for score in (95, 80, 60, 20)}
+
+        # Assert
+        assert len(sentences) == 4
+
+    def test_score_dropped(self):
+        """Test a lower score than last time reads as a drop."""
+        # Arrange
+        result = build_demo_result()
+        better = result.model_copy(deep=True, update={"meta": {**result.meta, "health_score": 80}})
+
+        # Act
+        output = generate(result, changes=diff(better, result))
+
+        # Assert
+        assert "8 points" in output
+        assert "dropped" in output or "down" in output
+
+
+@pytest.mark.unit
+class TestPhrasing:
+    """Test phrasing variants are picked deterministically."""
+
+    def test_deterministic(self):
+        """Test the same result always gives the same text."""
+        assert generate(build_demo_result()) == generate(build_demo_result())
+
+    def test_variants_spread(self):
+        """Test different sites do not all get the same phrasing."""
+        # Arrange
+        issues = build_demo_result().issues
+
+        # Act
+        openings = {
+            generate(_scored(f"https://site{n}.example/", 72, issues)).startswith("At ") for n in range(20)
+        }
+
+        # Assert
+        assert openings == {True, False}
@@ -0,0 +1,206 @@
+"""
+Unit tests for the PageSpeed Insights client.
+"""
+import asyncio
+
+import httpx
+import pytest
+
+from tinyseoai.data.models import AuditResult, Issue
+from tinyseoai.integrations.pagespeed import (
+    enrich,
+    fetch,
+    field_summary,
+    pagespeed_issues,
+    stored_results,
+)
+from tinyseoai.reporting.markdown import render_markdown
+
+URL = "https://example.com/"
+
+FULL = {
+    "loadingExperience": {
+        "metrics": {
+            "LARGEST_CONTENTFUL_PAINT_MS": {"percentile": 2100, "category": "FAST"},
+            "CUMULATIVE_LAYOUT_SHIFT_SCORE": {"percentile": 5, "category": "FAST"},
+            "INTERACTION_TO_NEXT_PAINT": {"percentile": 240, "category": "AVERAGE"},
+            "EXPERIMENTAL_TIME_TO_FIRST_BYTE": {"percentile": 900, "category": "AVERAGE"},
+        },
+        "overall_category": "AVERAGE",
+    },
+    "lighthouseResult": {
+        "categories": {
+            "performance": {"score": 0.87},
+            "accessibility": {"score": 0.95},
+            "best-practices": {"score": 1},
+            "seo": {"score": 0.92},
+        }
+    },
+}
+NO_FIELD = {
+    "loadingExperience": {"id": URL},
+    "originLoadingExperience": {"id": "https://example.com"},
+    "lighthouseResult": {"categories": {"performance": {"score": 0.5}}},
+}
+QUOTA = {
+    "error": {
+        "code": 429,
+        "message": "Quota exceeded for quota metric 'Queries'",
+        "errors": [{"reason": "rateLimitExceeded"}],
+    }
+}
+
+
+class _FakePsi:
+    """Answers runPagespeed requests with canned responses, in order."""
+
+    def __init__(self, *responses: tuple[int, dict]):
+        self.responses = list(responses)
+        self.requests: list[httpx.Request] = []
+
+    def __call__(self, request: httpx.Request) -> httpx.Response:
+        self.requests.append(request)
+        status, body = self.responses.pop(0)
+        return httpx.Response(status, json=body)
+
+    def fetch(self, strategy: str = "mobile"):
+        client = httpx.AsyncClient(transport=httpx.MockTransport(self))
+        return asyncio.run(fetch(URL, "k3y", strategy, client=client))
+
+
+@pytest.mark.unit
+class TestFetch:
+    """Test parsing responses and classifying API errors."""
+
+    def test_full_response(self):
+        """Test field percentiles and lab scores are parsed, CLS unscaled."""
+        # Arrange
+        psi = _FakePsi((200, FULL))
+
+        # Act
+        result = psi.fetch("desktop")
+
+        # Assert
+        assert result.status == "ok"
+        assert result.field["lcp_ms"].percentile == 2100
+        assert result.field["cls"].percentile == 0.05
+        assert result.field["inp_ms"].category == "AVERAGE"
+        assert result.lab_scores == {
+            "performance": 87,
+            "accessibility": 95,
+            "best-practices": 100,
+            "seo": 92,
+        }
+        assert psi.requests[0].url.params["strategy"] == "desktop"
+        assert psi.requests[0].url.params.get_list("category")[-1] == "seo"
+        assert pagespeed_issues(result) == []
+
+    def test_origin_fallback(self):
+        """Test the origin's field data is used when the page has none."""
+        # Arrange
+        data = {"loadingExperience": {}, "originLoadingExperience": FULL["loadingExperience"]}
+
+        # Act
+        result = _FakePsi((200, data)).fetch()
+
+        # Assert
+        assert result.origin_fallback
+        assert field_summary(result).endswith("(origin)")
+
+    def test_no_field_data(self):
+        """Test a page without CrUX data keeps its lab scores and gets an info issue."""
+        # Act
+        result = _FakePsi((200, NO_FIELD)).fetch()
+
+        # Assert
+        assert result.status == "no_field_data"
+        assert result.field == {}
+        assert result.lab_scores == {"performance": 50}
+        (issue,) = pagespeed_issues(result)
+        assert issue.type == "field_data_unavailable"
+        assert issue.severity == "info"
+        assert "no mobile field data" in issue.detail
+
+    def test_quota_exceeded(self):
+        """Test a 429 is reported as quota exceeded, without a retry."""
+        # Arrange
+        psi = _FakePsi((429, QUOTA), (200, FULL))
+
+        # Act
+        result = psi.fetch()
+
+        # Assert
+        assert result.status == "quota_exceeded"
+        assert len(psi.requests) == 1
+        assert pagespeed_issues(result)[0].type == "pagespeed_quota_exceeded"
+
+    def test_invalid_key(self):
+        """Test a rejected key is reported as such."""
+        # Arrange
+        body = {
+            "error": {
+                "code": 400,
+                "message": "API key not valid. Please pass a valid API key.",
+                "details": [{"reason": "API_KEY_INVALID"}],
+            }
+        }
+
+        # Act
+        result = _FakePsi((400, body)).fetch()
+
+        # Assert
+        assert result.status == "invalid_key"
+        assert "API key not valid" in pagespeed_issues(result)[0].detail
+
+    def test_server_error_retried_once(self):
+        """Test a server error is retried once before giving up."""
+        # Arrange
+        psi = _FakePsi((503, {}), (503, {}), (200, FULL))
+
+        # Act
+        result = psi.fetch()
+
+        # Assert
+        assert result.status == "failed"
+        assert result.error == "HTTP 503"
+        assert len(psi.requests) == 2
+
+
+@pytest.mark.unit
+class TestEnrich:
+    """Test adding PageSpeed data to audit results."""
+
+    def _audit(self) -> AuditResult:
+        return AuditResult(
+            site=URL,
+            pages_scanned=1,
+            issues=[Issue(url=URL, type="title_missing", severity="high")],
+            meta={},
+        )
+
+    def test_enrich_replaces_earlier_issues(self):
+        """Test re-enriching replaces the data and the informational issues."""
+        # Arrange
+        audit = self._audit()
+
+        # Act
+        enrich(audit, _FakePsi((429, QUOTA)).fetch())
+        enrich(audit, _FakePsi((200, FULL)).fetch())
+
+        # Assert
+        assert [i.type for i in audit.issues] == ["title_missing"]
+        (stored,) = stored_results(audit.meta)
+        assert stored.status == "ok"
+
+    def test_markdown_rows(self):
+        """Test the Markdown report shows field metrics and lab scores."""
+        # Arrange
+        audit = self._audit()
+        enrich(audit, _FakePsi((200, FULL)).fetch())
+
+        # Act
+        output = render_markdown(audit.model_dump(mode="json"))
+
+        # Assert
+        assert "| Core Web Vitals (mobile) | LCP 2.1 s (FAST) · CLS 0.05 (FAST)" in output
+        assert "| Lighthouse (mobile) | performance 87, accessibility 95" in output
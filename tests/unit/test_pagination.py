@@ -0,0 +1,176 @@
+"""
+Unit tests for paginated series detection and checks.
+"""
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.pagination import analyze, group_series, page_number  # noqa: E402
+from tinyseoai.audit.crawler import FetchedPage  # noqa: E402
+
+BLOG = "https://pottery.example/blog/"
+
+
+def _series(urls: list[str], links=None) -> dict[str, list[int]]:
+    return {s.key: list(s.pages) for s in group_series(urls, links)}
+
+
+def _page(
+    url: str,
+    title: str = "Blog",
+    canonical: str | None = None,
+    next: str | None = None,
+    prev: str | None = None,
+    robots: str | None = None,
+) -> FetchedPage:
+    head = f"<title>{title}</title>"
+    for rel, href in (("canonical", canonical), ("next", next), ("prev", prev)):
+        if href:
+            head += f'<link rel="{rel}" href="{href}">'
+    if robots:
+        head += f'<meta name="robots" content="{robots}">'
+    return FetchedPage(url=url, body=f"<html><head>{head}</head><body></body></html>")
+
+
+def _blog(**overrides) -> list[FetchedPage]:
+    """A three-page blog series with correct links, titles, and canonicals."""
+    pages = {
+        1: dict(title="Blog", canonical=BLOG, next=f"{BLOG}page/2/"),
+        2: dict(title="Blog – Page 2", canonical=f"{BLOG}page/2/", next=f"{BLOG}page/3/", prev=BLOG),
+        3: dict(title="Blog – Page 3", canonical=f"{BLOG}page/3/", prev=f"{BLOG}page/2/"),
+    }
+    for number, changes in overrides.items():
+        pages[int(number[1:])].update(changes)
+    return [_page(BLOG if n == 1 else f"{BLOG}page/{n}/", **kw) for n, kw in pages.items()]
+
+
+def _types(issues) -> list[str]:
+    return sorted(i.type for i in issues)
+
+
+@pytest.mark.unit
+class TestGrouping:
+    """Test series detection against URL lists from real site layouts."""
+
+    @pytest.mark.parametrize(
+        "url,key,number",
+        [
+            ("https://pottery.example/blog/page/3/", "https://pottery.example/blog/", 3),
+            ("https://shop.example/c/mugs?page=2&sort=asc", "https://shop.example/c/mugs?sort=asc", 2),
+            ("https://shop.example/c/mugs?sort=asc&PAGED=4", "https://shop.example/c/mugs?sort=asc", 4),
+        ],
+    )
+    def test_page_number(self, url, key, number):
+        """Test page numbers in paths and query parameters."""
+        # Act
+        position = page_number(url)
+
+        # Assert
+        assert (position.key, position.number) == (key, number)
+
+    def test_no_page_number(self):
+        """Test URLs without exactly one page number are not paginated."""
+        assert page_number("https://shop.example/collections/mugs") is None
+        assert page_number("https://shop.example/c?page=2&p=3") is None
+        assert page_number("https://pottery.example/pages/page/") is None
+
+    def test_wordpress(self, url_list_fixture):
+        """Test /page/N/ archives group per listing, including sparse later pages."""
+        assert _series(url_list_fixture("wordpress_blog.txt")) == {
+            "https://pottery.example/blog/": [1, 2, 3, 12],
+            "https://pottery.example/category/news/": [1, 2],
+        }
+
+    def test_shopify(self, url_list_fixture):
+        """Test sorted listings are separate series and a lone ?page=1 is not a series."""
+        assert _series(url_list_fixture("shopify_collection.txt")) == {
+            "https://shop.example/collections/mugs": [1, 2, 3],
+            "https://shop.example/collections/mugs?sort_by=price-ascending": [1, 2],
+        }
+
+    def test_drupal_zero_based(self, url_list_fixture):
+        """Test ?page=0 or a rel=next from the first page to ?page=1 marks a zero-based series."""
+        # Arrange
+        urls = url_list_fixture("drupal_news.txt")
+        links = {("https://council.example/news", "https://council.example/news?page=1")}
+
+        # Act
+        series = {s.key: s for s in group_series(urls, links)}
+
+        # Assert
+        assert series["https://council.example/news"].pages == {
+            1: "https://council.example/news",
+            2: "https://council.example/news?page=1",
+            3: "https://council.example/news?page=2",
+        }
+        assert list(series["https://council.example/events"].pages) == [1, 2]
+
+    def test_post_ids_need_links(self, url_list_fixture):
+        """Test ?p= and ?pg= only form a series when rel=next/prev links join the pages."""
+        # Arrange
+        urls = url_list_fixture("post_ids.txt")
+        links = {("https://blog.example/", "https://blog.example/?p=2")}
+
+        # Act & Assert
+        assert _series(urls) == {}
+        assert _series(urls, links) == {"https://blog.example/": [1, 2, 57, 103]}
+
+
+@pytest.mark.unit
+class TestAnalyze:
+    """Test findings per series."""
+
+    def test_healthy_series(self):
+        """Test a series with page titles, self canonicals, and a correct chain is clean."""
+        assert analyze(_blog()) == []
+
+    def test_canonical_to_first_page(self):
+        """Test later pages canonicalized to page 1 are reported together."""
+        # Act
+        issues = analyze(_blog(p2=dict(canonical=BLOG), p3=dict(canonical="/blog/")))
+
+        # Assert
+        assert _types(issues) == ["pagination_canonical_to_first"]
+        assert issues[0].url == BLOG
+        assert issues[0].detail == (
+            "2 paginated pages set their canonical to the first page, so search engines may never "
+            "index the items they list: https://pottery.example/blog/page/2/, https://pottery.example/blog/page/3/"
+        )
+
+    def test_broken_chain(self):
+        """Test rel=next to a 404 and rel=next skipping a page."""
+        # Arrange
+        pages = _blog(p1=dict(next=f"{BLOG}page/3/"), p3=dict(next=f"{BLOG}page/4/"))
+        pages.append(FetchedPage(url=f"{BLOG}page/4/", status=404))
+
+        # Act
+        issues = analyze(pages)
+
+        # Assert
+        assert _types(issues) == ["pagination_broken_chain"]
+        assert issues[0].detail == (
+            "2 rel=next/prev links break the series: "
+            "https://pottery.example/blog/ (rel=next on page 1 points to page 3), "
+            "https://pottery.example/blog/page/3/ (rel=next https://pottery.example/blog/page/4/ returns 404)"
+        )
+
+    def test_duplicate_titles(self):
+        """Test pages repeating a title without a page number; "Page 2" titles pass."""
+        # Act
+        issues = analyze(_blog(p3=dict(title="blog")))
+
+        # Assert
+        assert _types(issues) == ["pagination_duplicate_titles"]
+        assert issues[0].detail.startswith('2 pages of the series share the title "Blog" with no page number')
+
+    def test_first_page_noindex(self):
+        """Test a noindexed first page is reported; noindex on later pages is not."""
+        # Act
+        first = analyze(_blog(p1=dict(robots="noindex, follow")))
+        later = analyze(_blog(p2=dict(robots="noindex, follow")))
+
+        # Assert
+        assert _types(first) == ["pagination_first_page_noindex"]
+        assert first[0].detail.startswith("The first page of a 3-page series is noindexed")
+        assert later == []
@@ -0,0 +1,159 @@
+"""
+Unit tests for duplicate content generated by URL parameters.
+"""
+import json
+from pathlib import Path
+
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.params import (  # noqa: E402
+    ParamOptions,
+    analyze,
+    candidates,
+    find_duplicates,
+    fingerprint,
+)
+from tinyseoai.audit.crawler import FetchedPage  # noqa: E402
+
+FIXTURES = Path(__file__).parents[1] / "fixtures" / "params"
+
+
+def _page(url: str, text: str, canonical: str | None = None) -> FetchedPage:
+    link = f'<link rel="canonical" href="{canonical}">' if canonical else ""
+    body = f"<html><head><title>Shop</title>{link}</head><body><main><p>{text}</p></main></body></html>"
+    return FetchedPage(url=url, body=body)
+
+
+def _shop() -> tuple[list[str], dict, dict[str, int]]:
+    """Crawled URLs, their fingerprints, and the duplicates each parameter generates."""
+    data = json.loads((FIXTURES / "shop.json").read_text(encoding="utf-8"))
+    pages = [_page(p["url"], data["texts"][p["text"]], p.get("canonical")) for p in data["pages"]]
+    return [p.url for p in pages], {p.url: fingerprint(p) for p in pages}, data["expected"]
+
+
+@pytest.mark.unit
+class TestDuplicateFamilies:
+    """Test parameters are blamed for the duplicate families of a shop crawl."""
+
+    def test_counts_per_parameter(self):
+        """Test each parameter's duplicates, ignoring ones canonicalized elsewhere."""
+        # Arrange
+        urls, fingerprints, expected = _shop()
+
+        # Act
+        report = find_duplicates(urls, fingerprints)
+
+        # Assert
+        assert {p.param: p.duplicates for p in report.params} == expected
+        assert [p.param for p in report.params] == ["sort", "view", "ref"]
+        assert {p.param: p.paths for p in report.params} == {"sort": 2, "view": 1, "ref": 1}
+        assert not report.capped
+
+    def test_examples_pair_duplicate_with_url_without_parameter(self):
+        """Test example pairs name the duplicate, then the URL it duplicates."""
+        # Arrange
+        urls, fingerprints, _ = _shop()
+
+        # Act
+        sort = find_duplicates(urls, fingerprints).params[0]
+
+        # Assert
+        assert sort.examples == [
+            ("https://shop.example/hats?sort=price", "https://shop.example/hats"),
+            ("https://shop.example/hats?page=2&sort=price", "https://shop.example/hats?page=2"),
+            ("https://shop.example/shoes?sort=name", "https://shop.example/shoes"),
+        ]
+
+    def test_findings(self):
+        """Test one finding per parameter at min_duplicates or more, most duplicates first."""
+        # Arrange
+        urls, fingerprints, _ = _shop()
+
+        # Act
+        issues = analyze(urls, fingerprints)
+
+        # Assert
+        assert [(i.type, i.severity, i.args[0]) for i in issues] == [
+            ("parameter_duplicates", "medium", "sort"),
+            ("parameter_duplicates", "medium", "view"),
+        ]
+        assert issues[0].url == "https://shop.example/hats"
+        assert issues[0].detail.startswith("?sort= generates 5 duplicate URLs on 2 paths")
+        assert "https://shop.example/hats?sort=price duplicates https://shop.example/hats" in issues[0].detail
+
+    def test_candidates_only_parameterized_paths(self):
+        """Test only paths crawled with a query string need fingerprints."""
+        # Arrange
+        urls, _, _ = _shop()
+
+        # Act
+        wanted = candidates(urls)
+
+        # Assert
+        assert "https://shop.example/shoes" in wanted
+        assert "https://shop.example/about" not in wanted
+
+    def test_different_content_not_duplicate(self):
+        """Test a parameter that changes the content, like a filter, is not reported."""
+        # Arrange
+        pages = [
+            _page("https://shop.example/shoes", "walking shoes and leather boots for every season " * 4),
+            _page("https://shop.example/shoes?color=red", "crimson sneakers and scarlet heels picked this year " * 4),
+        ]
+
+        # Act
+        report = find_duplicates([p.url for p in pages], {p.url: fingerprint(p) for p in pages})
+
+        # Assert
+        assert report.params == []
+        assert report.comparisons == 1
+
+
+@pytest.mark.unit
+class TestComparisonLimits:
+    """Test the work done is bounded on large crawls."""
+
+    def test_length_bucketing_skips_comparison(self):
+        """Test pages whose word counts differ beyond length_tolerance are never compared."""
+        # Arrange
+        text = "walking shoes and leather boots for every season and street "
+        pages = [
+            _page("https://shop.example/shoes", text * 2),
+            _page("https://shop.example/shoes?sort=price", text * 4),
+        ]
+
+        # Act
+        report = find_duplicates([p.url for p in pages], {p.url: fingerprint(p) for p in pages})
+
+        # Assert
+        assert report.comparisons == 0
+        assert report.params == []
+
+    def test_one_comparison_per_url_and_parameter(self):
+        """Test a family of N sort orders costs N - 1 comparisons, not every pair."""
+        # Arrange
+        text = "walking shoes and leather boots for every season and street " * 3
+        urls = ["https://shop.example/shoes"] + [f"https://shop.example/shoes?sort=o{n}" for n in range(40)]
+        fingerprints = {url: fingerprint(_page(url, text)) for url in urls}
+
+        # Act
+        report = find_duplicates(urls, fingerprints)
+
+        # Assert
+        assert report.comparisons == 40
+        assert report.params[0].duplicates == 40
+
+    def test_comparison_cap(self):
+        """Test max_comparisons stops the analysis and marks the report capped."""
+        # Arrange
+        urls, fingerprints, _ = _shop()
+
+        # Act
+        report = find_duplicates(urls, fingerprints, ParamOptions(max_comparisons=3))
+
+        # Assert
+        assert report.comparisons == 3
+        assert report.capped
@@ -0,0 +1,199 @@
+"""
+Unit tests for the PDF report and its print layout.
+"""
+import json
+import os
+import re
+from pathlib import Path
+
+import pytest
+
+pytest.importorskip("jinja2")
+
+from tinyseoai.config import AppConfig  # noqa: E402
+from tinyseoai.data.demo import build_demo_result  # noqa: E402
+from tinyseoai.exceptions import StoreError  # noqa: E402
+from tinyseoai.i18n import Locale  # noqa: E402
+from tinyseoai.reporting import pdf  # noqa: E402
+from tinyseoai.reporting.pdf import PdfOptions, export_pdf, render_print_html, write_pdf  # noqa: E402
+from tinyseoai.store.history import HistoryStore  # noqa: E402
+
+GOLDEN = Path(__file__).parents[1] / "fixtures" / "golden"
+# Set in CI, which installs WeasyPrint's system libraries: a missing renderer fails instead of skipping
+REQUIRE_RENDERER = bool(os.environ.get("TINYSEOAI_REQUIRE_PDF"))
+
+
+@pytest.fixture
+def demo_summary(monkeypatch) -> dict:
+    monkeypatch.setattr(pdf, "get_config", lambda: AppConfig())
+    return json.loads(build_demo_result().to_json())
+
+
+def _many_pages(count: int) -> list[dict]:
+    return [
+        {"url": f"https://demo.tinyseoai.com/blog/post-{n}", "type": "title_missing", "severity": "high"}
+        for n in range(count)
+    ]
+
+
+def _page_count(data: bytes) -> int:
+    return len(re.findall(rb"/Type\s*/Page(?![a-zA-Z])", data))
+
+
+def _render_pdf(summary: dict, out: Path, options: PdfOptions | None = None) -> bytes:
+    try:
+        return write_pdf(summary, out, options).read_bytes()
+    except RuntimeError as e:
+        if REQUIRE_RENDERER:
+            raise
+        pytest.skip(f"No PDF renderer installed: {e}")
+
+
+@pytest.mark.unit
+class TestPrintLayout:
+    """Test the HTML the PDF is printed from."""
+
+    def test_golden(self, demo_summary, monkeypatch):
+        """Test the demo result renders exactly as the golden file, font aside."""
+        # Arrange
+        monkeypatch.setattr(pdf, "_font_data", lambda: None)
+
+        # Act
+        html = render_print_html(demo_summary)
+
+        # Assert
+        assert html == (GOLDEN / "demo.print.html").read_text()
+
+    def test_cover_and_sections(self, demo_summary):
+        """Test the cover comes first and severities follow from high to info."""
+        # Act
+        html = render_print_html(demo_summary)
+
+        # Assert
+        cover = html[html.index('<div class="cover">') : html.index("<section>")]
+        assert "https://demo.tinyseoai.com/" in cover
+        assert "2025-01-01" in cover
+        assert "<strong>72</strong>" in cover and "Grade C" in cover
+        positions = [html.index(f"<h2>{label} ") for label in ("High", "Medium", "Low", "Info")]
+        assert positions == sorted(positions)
+        assert html.count('class="severity"') == 4
+
+    def test_font_embedded(self, demo_summary):
+        """Test Work Sans is inlined, with no external assets."""
+        # Act
+        html = render_print_html(demo_summary)
+
+        # Assert
+        assert 'src: url("data:font/woff2;base64,' in html
+        assert "<link " not in html and "<script" not in html
+
+    def test_every_page_listed_by_default(self, demo_summary):
+        """Test long page lists are kept whole, so they continue on the next page."""
+        # Arrange
+        demo_summary["issues"] = _many_pages(250)
+
+        # Act
+        html = render_print_html(demo_summary)
+
+        # Assert
+        assert html.count("<li>https://demo.tinyseoai.com/blog/post-") == 250
+        assert "…and" not in html
+
+    def test_page_list_capped(self, demo_summary):
+        """Test a page limit cuts the list with a count."""
+        # Arrange
+        demo_summary["issues"] = _many_pages(12)
+
+        # Act
+        html = render_print_html(demo_summary, PdfOptions(max_pages_per_issue=5))
+
+        # Assert
+        assert html.count("<li>https://demo.tinyseoai.com/blog/post-") == 5
+        assert "…and 7 more" in html
+
+    def test_crawled_content_is_escaped(self, demo_summary):
+        """Test script tags from crawled pages cannot reach the document."""
+        # Arrange
+        demo_summary["issues"][0]["detail"] = '<script>alert("x")</script>'
+
+        # Act
+        html = render_print_html(demo_summary)
+
+        # Assert
+        assert "<script>" not in html
+        assert "&lt;script&gt;" in html
+
+    def test_locale_and_ai(self, demo_summary):
+        """Test the locale sets the language and the AI summary can be left out."""
+        # Arrange
+        demo_summary["ai_summary"] = {"summary": "Fix the pricing title first."}
+
+        # Act
+        spanish = render_print_html(demo_summary, PdfOptions(locale=Locale.ES))
+        without_ai = render_print_html(demo_summary, PdfOptions(include_ai=False))
+
+        # Assert
+        assert '<html lang="es">' in spanish
+        assert "Fix the pricing title first." in spanish
+        assert "Fix the pricing title first." not in without_ai
+
+
+@pytest.mark.unit
+class TestWritePdf:
+    """Test rendered PDFs; skipped without Playwright's Chromium or WeasyPrint, except in CI."""
+
+    def test_demo(self, demo_summary, tmp_path):
+        """Test the demo PDF has a cover, a summary, and a page per severity."""
+        # Act
+        data = _render_pdf(demo_summary, tmp_path / "demo.pdf")
+
+        # Assert
+        assert data.startswith(b"%PDF-")
+        assert _page_count(data) == 6
+        assert b"/FontFile" in data  # fonts embedded, not referenced
+        assert 10_000 < len(data) < 400_000
+
+    def test_long_lists_paginate(self, demo_summary, tmp_path):
+        """Test hundreds of pages flow onto extra pages instead of being clipped."""
+        # Arrange
+        demo_summary["issues"] = _many_pages(400)
+
+        # Act
+        data = _render_pdf(demo_summary, tmp_path / "long.pdf")
+
+        # Assert
+        assert _page_count(data) >= 8
+        assert len(data) < 1_000_000
+
+
+@pytest.mark.unit
+class TestExportPdf:
+    """Test exporting from the history."""
+
+    def test_from_history(self, tmp_path, monkeypatch):
+        """Test the stored result is rendered with the given options."""
+        # Arrange
+        rendered = {}
+
+        def fake_write(summary, out_path, options=None):
+            rendered.update(summary=summary, options=options)
+            return out_path
+
+        monkeypatch.setattr(pdf, "write_pdf", fake_write)
+        with HistoryStore(tmp_path / "history.sqlite3") as store:
+            audit_id = store.insert(build_demo_result())
+
+            # Act
+            path = export_pdf(audit_id, tmp_path / "a.pdf", PdfOptions(max_pages_per_issue=3), store)
+
+        # Assert
+        assert path == tmp_path / "a.pdf"
+        assert rendered["summary"]["site"] == "https://demo.tinyseoai.com/"
+        assert len(rendered["summary"]["issues"]) == 7
+        assert rendered["options"].max_pages_per_issue == 3
+
+    def test_unknown_id(self, tmp_path):
+        """Test a missing audit raises StoreError."""
+        with HistoryStore(tmp_path / "history.sqlite3") as store:
+            with pytest.raises(StoreError):
+                export_pdf(99, tmp_path / "a.pdf", store=store)
@@ -0,0 +1,161 @@
+"""
+Unit tests for pass/fail policies.
+"""
+import pytest
+
+from tinyseoai.data.diff import diff
+from tinyseoai.data.models import AuditResult
+from tinyseoai.data.policy import Policy
+
+
+def _result(high: int = 0, medium: int = 0, score: float | None = 95, issue_type: str = "title_missing"):
+    issues = [
+        {"url": f"https://example.com/h{n}", "type": issue_type, "severity": "high"} for n in range(high)
+    ] + [
+        {"url": f"https://example.com/m{n}", "type": "missing_hsts", "severity": "medium"} for n in range(medium)
+    ]
+    meta = {} if score is None else {"health_score": score}
+    return AuditResult(site="https://example.com/", pages_scanned=10, issues=issues, meta=meta)
+
+
+def _rules(verdict) -> list[str]:
+    return sorted(v.rule for v in verdict.violations)
+
+
+@pytest.mark.unit
+class TestSingleRules:
+    """Test each rule fires on its own."""
+
+    def test_empty_policy_passes(self):
+        """Test a policy without limits passes anything."""
+        assert Policy().evaluate(_result(high=50, medium=50, score=0)).passed
+
+    def test_max_critical(self):
+        """Test too many high issues violate max_critical."""
+        # Act
+        verdict = Policy(max_critical=1).evaluate(_result(high=2))
+
+        # Assert
+        assert _rules(verdict) == ["max_critical"]
+        assert (verdict.violations[0].actual, verdict.violations[0].allowed) == (2, 1)
+
+    def test_max_critical_at_limit(self):
+        """Test counts equal to the limit pass."""
+        assert Policy(max_critical=2).evaluate(_result(high=2)).passed
+
+    def test_max_warnings(self):
+        """Test too many medium issues violate max_warnings."""
+        assert _rules(Policy(max_warnings=0).evaluate(_result(medium=1))) == ["max_warnings"]
+
+    def test_min_score(self):
+        """Test a low stored health score violates min_score."""
+        # Act
+        verdict = Policy(min_score=80).evaluate(_result(score=72.5))
+
+        # Assert
+        assert _rules(verdict) == ["min_score"]
+        assert verdict.violations[0].actual == 72.5
+
+    def test_min_score_computed_when_missing(self):
+        """Test the score is computed when the result has none."""
+        assert _rules(Policy(min_score=99).evaluate(_result(high=5, score=None))) == ["min_score"]
+
+    def test_category_override(self):
+        """Test per-category limits only count that category."""
+        # Arrange
+        policy = Policy.from_toml("[categories.security]\nmax_warnings = 0\n")
+
+        # Act
+        verdict = policy.evaluate(_result(high=3, medium=1))
+
+        # Assert
+        assert _rules(verdict) == ["categories.security.max_warnings"]
+        assert "in security" in verdict.violations[0].message
+
+    def test_fail_on_new_issues(self, diff_base_result, diff_head_result):
+        """Test new issues in the diff violate fail_on_new_issues."""
+        # Arrange
+        changes = diff(diff_base_result, diff_head_result)
+
+        # Act
+        verdict = Policy(fail_on_new_issues=True).evaluate(diff_head_result, changes)
+
+        # Assert
+        assert _rules(verdict) == ["fail_on_new_issues"]
+        assert verdict.violations[0].actual == len(changes.new)
+
+    def test_fail_on_new_issues_without_diff(self):
+        """Test the rule is skipped, not failed, without a previous audit."""
+        # Act
+        verdict = Policy(fail_on_new_issues=True).evaluate(_result())
+
+        # Assert
+        assert verdict.passed
+        assert verdict.skipped
+
+
+@pytest.mark.unit
+class TestCombinedRules:
+    """Test several rules reported together."""
+
+    def test_all_violations_listed(self, diff_base_result, diff_head_result):
+        """Test every broken rule is listed, not just the first."""
+        # Arrange
+        policy = Policy.from_toml(
+            """
+            [policy]
+            max_critical = 0
+            max_warnings = 0
+            min_score = 90
+            fail_on_new_issues = true
+
+            [policy.categories.content]
+            max_critical = 0
+            """
+        )
+        result = _result(high=1, medium=1, score=50)
+        changes = diff(diff_base_result, diff_head_result)
+
+        # Act
+        verdict = policy.evaluate(result, changes)
+
+        # Assert
+        assert _rules(verdict) == [
+            "categories.content.max_critical",
+            "fail_on_new_issues",
+            "max_critical",
+            "max_warnings",
+            "min_score",
+        ]
+
+    def test_partial_violation(self):
+        """Test passing rules are not listed."""
+        # Arrange
+        policy = Policy(max_critical=5, max_warnings=0, min_score=50)
+
+        # Act / Assert
+        assert _rules(policy.evaluate(_result(high=1, medium=2, score=60))) == ["max_warnings"]
+
+
+@pytest.mark.unit
+class TestPolicyParsing:
+    """Test TOML parsing errors."""
+
+    def test_unknown_category(self):
+        """Test misspelled categories are rejected."""
+        with pytest.raises(ValueError):
+            Policy.from_toml("[categories.secruity]\nmax_warnings = 0\n")
+
+    def test_bad_value(self):
+        """Test wrongly typed limits are rejected."""
+        with pytest.raises(ValueError):
+            Policy.from_toml('max_critical = "lots"\n')
+
+    def test_load(self, tmp_path):
+        """Test policies load from files."""
+        # Arrange
+        path = tmp_path / "policy.toml"
+        path.write_text("min_score = 70\n")
+
+        # Act / Assert
+        assert Policy.load(path).min_score == 70
@@ -0,0 +1,120 @@
+"""
+Unit tests for progress events and parsing captured CLI output.
+"""
+import json
+from pathlib import Path
+
+import pytest
+
+from tinyseoai.progress import Phase, ProgressEmitter, ProgressEvent, parse_stream
+
+FIXTURES = Path(__file__).parent.parent / "fixtures" / "progress"
+
+
+@pytest.mark.unit
+class TestParseStream:
+    """Test converting captured CLI output into events."""
+
+    def test_captured_stream(self):
+        """Test a captured audit-full stderr gives the expected events, in order."""
+        # Arrange
+        lines = (FIXTURES / "audit_full.stderr.txt").read_text(encoding="utf-8").splitlines()
+        expected = json.loads((FIXTURES / "audit_full.events.json").read_text(encoding="utf-8"))
+
+        # Act
+        events = parse_stream(lines)
+
+        # Assert
+        assert [e.model_dump() for e in events] == [
+            ProgressEvent.model_validate(e).model_dump() for e in expected
+        ]
+        assert events[0].phase == Phase.FETCHING
+        assert events[-1].phase == Phase.DONE
+
+    def test_job_id_for_log_lines(self):
+        """Test log lines take the given job id; JSON lines keep their own."""
+        # Arrange
+        lines = [
+            "Phase 4: Calculating health scores...",
+            '{"phase": "done", "job_id": "other"}',
+        ]
+
+        # Act
+        events = parse_stream(lines, job_id="run-1")
+
+        # Assert
+        assert [e.job_id for e in events] == ["run-1", "other"]
+
+    def test_unrelated_lines_ignored(self):
+        """Test noise, bad JSON, and unknown phases give no events."""
+        # Arrange
+        lines = [
+            "",
+            "Crawling pages... ━━━━━━━━ 40%",
+            "2026-10-16 09:12:04.512 | WARNING  | x:y:1 - Error crawling https://a.test/: 404",
+            '{"phase": "sleeping"}',
+            "{not json",
+        ]
+
+        # Act & Assert
+        assert parse_stream(lines) == []
+
+
+@pytest.mark.unit
+class TestProgressEvent:
+    """Test the event model."""
+
+    def test_line_round_trip(self):
+        """Test to_line output parses back to the same event."""
+        # Arrange
+        event = ProgressEvent(
+            phase=Phase.CHECKING, check_id="links", done=2, total=9, message="x", job_id="j"
+        )
+
+        # Act
+        parsed = ProgressEvent.from_line(event.to_line())
+
+        # Assert
+        assert parsed == event
+        assert "\n" not in event.to_line()
+
+    def test_unset_fields_omitted(self):
+        """Test a JSON line leaves out fields that are not set."""
+        # Act
+        data = json.loads(ProgressEvent(phase=Phase.DONE).to_line())
+
+        # Assert
+        assert set(data) == {"phase", "message", "timestamp"}
+
+
+@pytest.mark.unit
+class TestProgressEmitter:
+    """Test emitting events to a hook."""
+
+    def test_stamps_job_id(self):
+        """Test emitted events carry the emitter's job id."""
+        # Arrange
+        events = []
+        emit = ProgressEmitter(events.append, job_id="nightly")
+
+        # Act
+        emit(Phase.CRAWLING, "https://example.com/", done=1, total=5)
+
+        # Assert
+        assert len(events) == 1
+        assert events[0].job_id == "nightly"
+        assert (events[0].done, events[0].total) == (1, 5)
+
+    def test_without_hook(self):
+        """Test an emitter without a hook does nothing."""
+        # Act & Assert
+        ProgressEmitter()(Phase.DONE)
+
+    def test_hook_failure_swallowed(self):
+        """Test a hook that raises does not stop the caller."""
+        # Arrange
+        def broken(event):
+            raise RuntimeError("listener gone")
+
+        # Act & Assert
+        ProgressEmitter(broken)(Phase.FETCHING)
@@ -0,0 +1,258 @@
+"""
+Unit tests for the HTTP/2 and HTTP/3 protocol check.
+"""
+import asyncio
+import socket
+import ssl
+import threading
+from datetime import datetime, timedelta, timezone
+
+import pytest
+from cryptography import x509
+from cryptography.hazmat.primitives import hashes, serialization
+from cryptography.hazmat.primitives.asymmetric import ec
+from cryptography.x509.oid import NameOID
+
+from tinyseoai.audit.checks.protocol import (
+    ProtocolOptions,
+    ProtocolSupport,
+    describe,
+    detect,
+    parse_alt_svc,
+    protocol_issues,
+    quic_reachable,
+)
+
+URL = "https://pottery.example/"
+FAST = ProtocolOptions(timeout=1.0)
+
+
+def _certificate(tmp_path):
+    """Write a self-signed certificate for localhost and its key."""
+    key = ec.generate_private_key(ec.SECP256R1())
+    name = x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "localhost")])
+    now = datetime.now(timezone.utc)
+    cert = (
+        x509.CertificateBuilder()
+        .subject_name(name)
+        .issuer_name(name)
+        .public_key(key.public_key())
+        .serial_number(x509.random_serial_number())
+        .not_valid_before(now - timedelta(days=1))
+        .not_valid_after(now + timedelta(days=30))
+        .sign(key, hashes.SHA256())
+    )
+    cert_file, key_file = tmp_path / "localhost.pem", tmp_path / "localhost.key"
+    cert_file.write_bytes(cert.public_bytes(serialization.Encoding.PEM))
+    key_file.write_bytes(
+        key.private_bytes(
+            serialization.Encoding.PEM,
+            serialization.PrivateFormat.PKCS8,
+            serialization.NoEncryption(),
+        )
+    )
+    return cert_file, key_file
+
+
+@pytest.fixture
+def tls_server(tmp_path):
+    """Start a local TLS server offering the given ALPN protocols."""
+    sockets = []
+
+    def start(alpn: list[str] | None) -> int:
+        context = ssl.SSLContext(ssl.PROTOCOL_TLS_SERVER)
+        context.load_cert_chain(*_certificate(tmp_path))
+        if alpn:
+            context.set_alpn_protocols(alpn)
+        listener = socket.create_server(("127.0.0.1", 0))
+        sockets.append(listener)
+
+        def serve():
+            while True:
+                try:
+                    conn, _ = listener.accept()
+                except OSError:
+                    return  # closed by the fixture
+                try:
+                    with context.wrap_socket(conn, server_side=True):
+                        pass
+                except (OSError, ssl.SSLError):
+                    pass
+
+        threading.Thread(target=serve, daemon=True).start()
+        return listener.getsockname()[1]
+
+    yield start
+    for listener in sockets:
+        listener.close()
+
+
+@pytest.fixture
+def quic_server():
+    """Start a UDP server answering QUIC packets of unknown versions with version negotiation."""
+    sock = socket.socket(socket.AF_INET, socket.SOCK_DGRAM)
+    sock.bind(("127.0.0.1", 0))
+
+    def serve():
+        while True:
+            try:
+                data, address = sock.recvfrom(2048)
+            except OSError:
+                return  # closed by the fixture
+            dcid = data[6 : 6 + data[5]]
+            scid_at = 6 + data[5]
+            scid = data[scid_at + 1 : scid_at + 1 + data[scid_at]]
+            # Echo the IDs swapped, then the one version supported: QUIC v1
+            reply = bytes([0x80]) + b"\0\0\0\0" + bytes([len(scid)]) + scid + bytes([len(dcid)]) + dcid
+            sock.sendto(reply + bytes.fromhex("00000001"), address)
+
+    threading.Thread(target=serve, daemon=True).start()
+    yield sock.getsockname()[1]
+    sock.close()
+
+
+def _closed_udp_port() -> int:
+    with socket.socket(socket.AF_INET, socket.SOCK_DGRAM) as sock:
+        sock.bind(("127.0.0.1", 0))
+        return sock.getsockname()[1]
+
+
+@pytest.mark.unit
+class TestNegotiation:
+    """Test HTTP/2 is read from ALPN against local servers."""
+
+    def test_h2_server(self, tls_server):
+        """Test a server accepting h2 is recorded as HTTP/2, with no findings."""
+        # Arrange
+        port = tls_server(["h2", "http/1.1"])
+        url = f"https://127.0.0.1:{port}/"
+
+        # Act
+        support = asyncio.run(detect(url, {}, FAST))
+
+        # Assert
+        assert (support.alpn, support.http2) == ("h2", True)
+        assert protocol_issues(support, url) == []
+        assert support.metrics()["negotiated"] == "h2"
+
+    @pytest.mark.parametrize("alpn", [["http/1.1"], None])
+    def test_http1_only_server(self, tls_server, alpn):
+        """Test a server choosing http/1.1, or not speaking ALPN, is HTTP/1.1-only."""
+        # Arrange
+        port = tls_server(alpn)
+        url = f"https://127.0.0.1:{port}/"
+
+        # Act
+        support = asyncio.run(detect(url, {}, FAST))
+        issues = protocol_issues(support, url)
+
+        # Assert
+        assert (support.alpn, support.http2) == ("http/1.1", False)
+        assert [(i.type, i.severity) for i in issues] == [("http2_unsupported", "medium")]
+        assert issues[0].detail.startswith("127.0.0.1 only speaks HTTP/1.1")
+
+    def test_failed_handshake_no_findings(self):
+        """Test a host that refuses the connection has no protocol findings."""
+        # Arrange
+        with socket.socket() as sock:
+            sock.bind(("127.0.0.1", 0))
+            port = sock.getsockname()[1]
+
+        # Act
+        support = asyncio.run(detect(f"https://127.0.0.1:{port}/", {}, FAST))
+
+        # Assert
+        assert support.alpn is None and support.error
+        assert protocol_issues(support, URL) == []
+
+    def test_plain_http_skipped(self):
+        """Test http:// URLs are not checked."""
+        assert asyncio.run(detect("http://pottery.example/", {}, FAST)) is None
+
+
+@pytest.mark.unit
+class TestHttp3:
+    """Test HTTP/3 advertised in Alt-Svc and probed over UDP."""
+
+    def test_parse_alt_svc(self):
+        """Test protocols, authorities, and max age are parsed, and clear gives nothing."""
+        # Act
+        services = parse_alt_svc('h3=":443"; ma=86400, h3-29="alt.pottery.example:8443", h2=":443"')
+
+        # Assert
+        assert [(s.protocol, s.host, s.port, s.max_age) for s in services] == [
+            ("h3", None, 443, 86400),
+            ("h3-29", "alt.pottery.example", 8443, None),
+            ("h2", None, 443, None),
+        ]
+        assert [s.http3 for s in services] == [True, True, False]
+        assert parse_alt_svc("clear") == []
+        assert parse_alt_svc(None) == []
+
+    def test_advertised_and_reachable(self, tls_server, quic_server):
+        """Test an h3 endpoint answering version negotiation is reachable, with no findings."""
+        # Arrange
+        url = f"https://127.0.0.1:{tls_server(['h2'])}/"
+        headers = {"alt-svc": f'h3=":{quic_server}"; ma=86400'}
+
+        # Act
+        support = asyncio.run(detect(url, headers, FAST))
+
+        # Assert
+        assert support.h3_reachable is True
+        assert support.h3_endpoint == f"127.0.0.1:{quic_server}"
+        assert protocol_issues(support, url) == []
+        assert describe(support.metrics()) == "HTTP/2, HTTP/3 advertised"
+
+    def test_advertised_but_unreachable(self, tls_server):
+        """Test an h3 endpoint that does not answer is an info finding."""
+        # Arrange
+        url = f"https://127.0.0.1:{tls_server(['h2'])}/"
+        headers = {"alt-svc": f'h3=":{_closed_udp_port()}"'}
+
+        # Act
+        support = asyncio.run(detect(url, headers, FAST))
+        issues = protocol_issues(support, url)
+
+        # Assert
+        assert support.h3_reachable is False
+        assert [(i.type, i.severity) for i in issues] == [("http3_unreachable", "info")]
+        assert describe(support.metrics()) == "HTTP/2, HTTP/3 unreachable"
+
+    def test_probe_disabled(self, tls_server):
+        """Test probe_h3 = false records the advertisement without sending a packet."""
+        # Arrange
+        url = f"https://127.0.0.1:{tls_server(['h2'])}/"
+        options = ProtocolOptions(timeout=1.0, probe_h3=False)
+
+        # Act
+        support = asyncio.run(detect(url, {"alt-svc": 'h3=":443"'}, options))
+
+        # Assert
+        assert support.http3_advertised and support.h3_reachable is None
+
+    def test_stray_reply_is_not_quic(self):
+        """Test a UDP service replying with something other than version negotiation is unreachable."""
+        # Arrange
+        sock = socket.socket(socket.AF_INET, socket.SOCK_DGRAM)
+        sock.bind(("127.0.0.1", 0))
+
+        def echo():
+            try:
+                data, address = sock.recvfrom(2048)
+                sock.sendto(data[:32], address)
+            except OSError:
+                pass
+
+        threading.Thread(target=echo, daemon=True).start()
+
+        # Act
+        reachable = quic_reachable("127.0.0.1", sock.getsockname()[1], timeout=0.5)
+        sock.close()
+
+        # Assert
+        assert reachable is False
+
+    def test_unknown_describe(self):
+        """Test a failed handshake is described as unknown."""
+        assert describe(ProtocolSupport(host="pottery.example", error="refused").metrics()) == "unknown"
@@ -0,0 +1,138 @@
+"""
+Unit tests for issue filtering and sorting.
+"""
+import random
+
+import pytest
+
+from tinyseoai.data.models import AuditResult, Issue
+from tinyseoai.data.query import IssueQuery, SortKey, issue_category
+from tinyseoai.data.severity import Severity, severity_rank
+
+TYPES = ["title_missing", "broken_link", "missing_hsts", "img_alt_missing", "unknown_thing"]
+SEVERITIES = ["high", "medium", "low", "info", "Critical"]
+PATHS = ["/", "/blog/a", "/blog/b", "/shop/x", "/about"]
+
+
+def _random_issues(seed: int, n: int = 200) -> list[Issue]:
+    rng = random.Random(seed)
+    return [
+        Issue(
+            url="https://example.com" + rng.choice(PATHS),
+            type=rng.choice(TYPES),
+            severity=rng.choice(SEVERITIES),
+        )
+        for _ in range(n)
+    ]
+
+
+@pytest.mark.unit
+class TestIssueQuery:
+    """Test filters, sorting, and counts."""
+
+    def test_chained_filters(self, sample_audit_result):
+        """Test severity, category, and page filters combine."""
+        # Act
+        issues = (
+            sample_audit_result.query()
+            .severity_at_least("medium")
+            .category("links")
+            .page_contains("page4")
+            .collect()
+        )
+
+        # Assert
+        assert [i.type for i in issues] == ["broken_link"]
+
+    def test_returns_original_objects(self, sample_audit_result):
+        """Test results are references, not copies."""
+        # Act
+        issues = sample_audit_result.query().collect()
+
+        # Assert
+        assert all(a is b for a, b in zip(issues, sample_audit_result.issues, strict=True))
+
+    def test_queries_are_independent(self, sample_audit_result):
+        """Test adding a filter does not change the parent query."""
+        # Arrange
+        base = sample_audit_result.query()
+
+        # Act
+        narrowed = base.severity("medium")
+
+        # Assert
+        assert base.count() == 5
+        assert narrowed.count() == 2
+
+    def test_unknown_severity_rejected(self, sample_audit_result):
+        """Test a typo in the filter is an error rather than an empty result."""
+        with pytest.raises(ValueError):
+            sample_audit_result.query().severity_at_least("severe-ish")
+
+    def test_sort_by_page_count(self):
+        """Test issue types affecting more pages sort first, ties in input order."""
+        # Arrange
+        issues = [
+            Issue(url="https://example.com/1", type="a"),
+            Issue(url="https://example.com/2", type="b"),
+            Issue(url="https://example.com/3", type="b"),
+            Issue(url="https://example.com/4", type="c"),
+        ]
+
+        # Act
+        order = IssueQuery(issues).sort_by(SortKey.PAGE_COUNT).indices()
+
+        # Assert
+        assert order == [1, 2, 0, 3]
+
+    def test_sort_by_severity(self, sample_audit_result):
+        """Test most severe first."""
+        # Act
+        severities = [i.severity for i in sample_audit_result.query().sort_by("severity")]
+
+        # Assert
+        assert severities == ["medium", "medium", "low", "low", "low"]
+
+    def test_counts_by_category(self, sample_audit_result):
+        """Test category counts."""
+        assert sample_audit_result.query().counts_by_category() == {"content": 4, "links": 1}
+
+
+@pytest.mark.unit
+class TestIssueQueryProperties:
+    """Property-style checks over seeded random results."""
+
+    @pytest.mark.parametrize("seed", range(5))
+    def test_count_equals_filtered_len(self, seed):
+        """Test filtering then counting equals counting the filtered set."""
+        # Arrange
+        result = AuditResult(site="https://example.com/", pages_scanned=5, issues=_random_issues(seed), meta={})
+        query = result.query().severity_at_least(Severity.LOW).page_contains("/blog")
+
+        # Act
+        expected = [
+            i for i in result.issues
+            if severity_rank(i.severity) >= Severity.LOW.rank and "/blog" in i.url
+        ]
+
+        # Assert
+        assert query.count() == len(query.collect()) == len(expected)
+        assert sum(query.counts_by_severity().values()) == len(expected)
+        assert sum(query.counts_by_category().values()) == len(expected)
+
+    @pytest.mark.parametrize("seed", range(5))
+    def test_sorting_is_deterministic_permutation(self, seed):
+        """Test sorting returns each matching index once and repeats identically."""
+        # Arrange
+        issues = _random_issues(seed)
+        query = IssueQuery(issues).category("content")
+
+        for key in SortKey:
+            # Act
+            first = query.sort_by(key).indices()
+            second = query.sort_by(key).indices()
+
+            # Assert
+            assert first == second
+            assert sorted(first) == query.indices()
+            assert all(issue_category(issues[i]).value == "content" for i in first)
@@ -42,3 +42,31 @@ async def test_rate_limiter_updates_delay_dynamically():
     elapsed = time.monotonic() - start
 
     assert elapsed >= 0.29
+
+
+@pytest.mark.asyncio
+async def test_rate_limiter_burst():
+    limiter = RateLimiter(requests_per_second=10, burst=3)  # 0.1s refill
+
+    start = time.monotonic()
+    for _ in range(3):
+        await limiter.wait()
+    burst_elapsed = time.monotonic() - start
+    await limiter.wait()
+    elapsed = time.monotonic() - start
+
+    assert burst_elapsed < 0.05
+    assert elapsed >= 0.09
+
+
+@pytest.mark.asyncio
+async def test_rate_limiter_defer():
+    limiter = RateLimiter(requests_per_second=100)
+
+    await limiter.wait()
+    limiter.defer(0.2)
+    start = time.monotonic()
+    await limiter.wait()
+    elapsed = time.monotonic() - start
+
+    assert elapsed >= 0.19
@@ -0,0 +1,234 @@
+"""
+Unit tests for reachability probes, their history, and state transitions.
+"""
+from datetime import datetime, timedelta, timezone
+
+import httpx
+import pytest
+
+from tinyseoai.audit.checks.tls import Certificate, Handshake
+from tinyseoai.audit.reachability import ReachabilityOptions, run_reachability_check
+from tinyseoai.data.reachability import Probe, transitions, uptime_share
+from tinyseoai.integrations.webhook import Webhook, WebhookConfig
+from tinyseoai.store.history import HistoryStore, ProbeFilter
+
+SITE = "https://example.com/"
+FAST = ReachabilityOptions(retry_delay=0, tls=False)
+
+
+class _Server:
+    """A site that is healthy or failing, switched by the test between probes."""
+
+    def __init__(self, failure: int | Exception = 503):
+        self.healthy = True
+        self.failure = failure
+        self.failures_left = 0  # fail this many requests, then recover
+        self.requests: list[httpx.Request] = []
+
+    def __call__(self, request: httpx.Request) -> httpx.Response:
+        self.requests.append(request)
+        if request.url.path == "/old":
+            return httpx.Response(301, headers={"location": SITE})
+        if self.failures_left:
+            self.failures_left -= 1
+        elif self.healthy:
+            return httpx.Response(200)
+        if isinstance(self.failure, Exception):
+            raise self.failure
+        return httpx.Response(self.failure)
+
+    def client(self) -> httpx.AsyncClient:
+        return httpx.AsyncClient(transport=httpx.MockTransport(self))
+
+
+def _certificate(days: int):
+    def read(host, options):
+        not_after = datetime.now(timezone.utc) + timedelta(days=days, hours=1)
+        return Handshake(host=host, certificate=Certificate(subject=host, issuer="Test CA", not_after=not_after))
+
+    return read
+
+
+def _probe(up: bool, url: str = SITE, at: str = "2025-01-01T00:00:00+00:00") -> Probe:
+    return Probe(url=url, checked_at=at, up=up, status_code=200 if up else 503)
+
+
+@pytest.mark.unit
+class TestProbes:
+    """Test what one probe records."""
+
+    @pytest.mark.asyncio
+    async def test_healthy_with_redirect_and_certificate(self):
+        """Test status, latency, redirect target, and certificate days are recorded."""
+        # Arrange
+        server = _Server()
+        options = ReachabilityOptions(retry_delay=0)
+
+        # Act
+        up, moved = await run_reachability_check(
+            [SITE, "https://example.com/old"], options, server.client(), read_certificate=_certificate(30)
+        )
+
+        # Assert
+        assert (up.up, up.status_code, up.final_url, up.tls_days_left) == (True, 200, None, 30)
+        assert up.latency_ms is not None
+        assert (moved.up, moved.final_url) == (True, SITE)
+        assert [r.method for r in server.requests] == ["HEAD", "HEAD", "HEAD"]
+
+    @pytest.mark.asyncio
+    async def test_failure_retried_once(self):
+        """Test a failing site is tried twice, then recorded as down."""
+        # Arrange
+        server = _Server()
+        server.healthy = False
+
+        # Act
+        (probe,) = await run_reachability_check([SITE], FAST, server.client())
+
+        # Assert
+        assert (probe.up, probe.status_code, probe.outcome) == (False, 503, "503")
+        assert len(server.requests) == 2
+
+    @pytest.mark.asyncio
+    async def test_retry_recovers(self):
+        """Test one failed request followed by a success counts as up."""
+        # Arrange
+        server = _Server()
+        server.failures_left = 1
+
+        # Act
+        (probe,) = await run_reachability_check([SITE], FAST, server.client())
+
+        # Assert
+        assert probe.up and probe.status_code == 200
+
+    @pytest.mark.asyncio
+    async def test_connection_error(self):
+        """Test a site that does not answer is down with the error and no status."""
+        # Arrange
+        server = _Server(failure=httpx.ConnectError("refused"))
+        server.healthy = False
+
+        # Act
+        (probe,) = await run_reachability_check([SITE], FAST, server.client())
+
+        # Assert
+        assert (probe.up, probe.status_code) == (False, None)
+        assert probe.error and probe.outcome == probe.error
+
+
+@pytest.mark.unit
+class TestTransitions:
+    """Test sites going down and recovering are detected against the history."""
+
+    @pytest.mark.asyncio
+    async def test_toggling_site(self, tmp_path):
+        """Test up → down → down → up gives one down and one recovered transition."""
+        # Arrange
+        server = _Server()
+        client = server.client()
+        found = []
+
+        # Act
+        with HistoryStore(tmp_path / "history.sqlite3") as store:
+            for healthy in (True, False, False, True):
+                server.healthy = healthy
+                probes = await run_reachability_check([SITE], FAST, client)
+                found.append(transitions(store.latest_probes([SITE]), probes))
+                store.insert_probes(probes)
+            history = store.probes(ProbeFilter(url=SITE))
+
+        # Assert
+        assert [[t.kind for t in changes] for changes in found] == [[], ["down"], [], ["recovered"]]
+        assert found[1][0].since == history[0].checked_at
+        assert [p.up for p in history] == [True, False, False, True]
+
+    def test_first_sample_not_a_transition(self):
+        """Test a URL with no previous sample is not reported, even when down."""
+        assert transitions({}, [_probe(False)]) == []
+
+    def test_uptime_share(self):
+        """Test the share of up samples, and None without samples."""
+        assert uptime_share([_probe(True), _probe(True), _probe(False)]) == 66.7
+        assert uptime_share([]) is None
+
+
+@pytest.mark.unit
+class TestProbeHistory:
+    """Test probes are stored apart from audits."""
+
+    def test_filters_and_latest(self, tmp_path):
+        """Test probes filter by URL, host, and date, and the latest is per URL."""
+        # Arrange
+        other = "https://shop.example/"
+        with HistoryStore(tmp_path / "history.sqlite3") as store:
+            store.insert_probes(
+                [
+                    _probe(True, at="2025-01-01T00:00:00+00:00"),
+                    _probe(False, at="2025-01-01T00:05:00+00:00"),
+                    _probe(True, other, at="2025-01-01T00:00:00+00:00"),
+                ]
+            )
+
+            # Act
+            by_host = store.probes(ProbeFilter(host="example.com"))
+            since = store.probes(ProbeFilter(since="2025-01-01T00:01:00"))
+            newest = store.probes(ProbeFilter(limit=1))
+            latest = store.latest_probes()
+
+            # Assert
+            assert [p.checked_at[11:16] for p in by_host] == ["00:00", "00:05"]
+            assert [p.url for p in since] == [SITE]
+            assert newest[0].checked_at.startswith("2025-01-01T00:05")
+            assert (latest[SITE].up, latest[other].up) == (False, True)
+            assert store.list() == []
+
+    def test_merge_copies_probes_once(self, tmp_path):
+        """Test merging another history adds its probes, skipping ones already here."""
+        # Arrange
+        with HistoryStore(tmp_path / "other.sqlite3") as other:
+            other.insert_probes([_probe(True), _probe(False, at="2025-01-02T00:00:00+00:00")])
+        with HistoryStore(tmp_path / "history.sqlite3") as store:
+            store.insert_probes([_probe(True)])
+
+            # Act
+            merged = store.merge_from(tmp_path / "other.sqlite3")
+
+            # Assert
+            assert merged.probes == 1
+            assert len(store.probes()) == 2
+
+
+@pytest.mark.unit
+class TestNotification:
+    """Test transitions are posted to webhooks."""
+
+    @pytest.mark.asyncio
+    async def test_generic_payload(self):
+        """Test the generic payload lists each change with its new sample."""
+        # Arrange
+        changes = transitions({SITE: _probe(True)}, [_probe(False, at="2025-01-01T00:05:00+00:00")])
+        webhook = Webhook(WebhookConfig(url="https://ci.example.com/hooks/seo"))
+
+        # Act
+        delivery = await webhook.send_transitions(changes, dry_run=True)
+
+        # Assert
+        payload = delivery.payload
+        assert payload["event"] == "reachability.changed"
+        assert payload["text"] == "Reachability: 1 site(s) down, 0 recovered"
+        assert payload["changes"][0]["state"] == "down"
+        assert payload["changes"][0]["probe"]["status_code"] == 503
+
+    @pytest.mark.asyncio
+    async def test_slack_payload(self):
+        """Test Slack messages name the site and its outcome."""
+        # Arrange
+        changes = transitions({SITE: _probe(False)}, [_probe(True, at="2025-01-01T00:05:00+00:00")])
+        webhook = Webhook(WebhookConfig(url="https://hooks.slack.com/services/T000/B000/secret"))
+
+        # Act
+        delivery = await webhook.send_transitions(changes, dry_run=True)
+
+        # Assert
+        assert f"{SITE} is back up (200)" in delivery.payload["blocks"][1]["text"]["text"]
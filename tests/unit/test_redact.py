@@ -0,0 +1,186 @@
+"""
+Unit tests for report redaction.
+"""
+import json
+import re
+
+import pytest
+
+from tinyseoai.data.models import AuditResult, Issue
+from tinyseoai.data.redact import (
+    RedactionOptions,
+    hosts_of,
+    redact,
+    redact_summaries,
+    redact_summary,
+)
+from tinyseoai.i18n import listing, msg
+from tinyseoai.reporting.markdown import render_markdown
+
+HOSTS = ["shop.acme.com", "acme.com", "cdn.acme-assets.net"]
+
+
+def _summary() -> dict:
+    issues = [
+        Issue(url="https://shop.acme.com/", type="missing_hsts", severity="medium"),
+        Issue(
+            url="https://shop.acme.com/cart?session=abc123",
+            type="broken_link",
+            severity="medium",
+            detail="https://cdn.acme-assets.net/app.js?token=s3cr3t",
+        ),
+        Issue(
+            url="https://shop.acme.com/old",
+            type="redirect_chain",
+            severity="medium",
+            **msg(
+                "redirects.redirect_chain",
+                2,
+                listing(["https://shop.acme.com/old", "https://acme.com/new?utm=x"], 5),
+            ),
+        ),
+    ]
+    result = AuditResult(
+        site="https://shop.acme.com/",
+        pages_scanned=3,
+        issues=issues,
+        meta={"suppressed_issues": [{"url": "https://acme.com/legal", "type": "noindex"}]},
+    )
+    summary = json.loads(result.to_json())
+    summary["ai_summary"] = {
+        "summary": "Shop.Acme.com (Project Falcon) loads slowly; see acme.com for the old site.",
+        "recommended_actions": [
+            {"action": "Move cdn.acme-assets.net assets behind the main host", "impact": "high"}
+        ],
+    }
+    return summary
+
+
+def _leaks(output: str) -> list[str]:
+    return [host for host in HOSTS if re.search(re.escape(host), output, re.IGNORECASE)]
+
+
+@pytest.mark.unit
+class TestRedactSummary:
+    """Test masking, query stripping, and custom patterns."""
+
+    def test_hosts_of(self):
+        """Test hosts are collected in order of first appearance."""
+        # Act & Assert
+        assert hosts_of(_summary()) == ["shop.acme.com", "cdn.acme-assets.net", "acme.com"]
+
+    def test_consistent_masks(self):
+        """Test every mention of a host gets the same mask, the site's being host1."""
+        # Act
+        redacted = redact_summary(_summary(), RedactionOptions(mask_hostnames=True))
+
+        # Assert
+        assert redacted["site"] == "https://host1.example/"
+        assert redacted["issues"][1]["detail"].startswith("https://host2.example/app.js")
+        assert "host3.example/new" in redacted["issues"][2]["detail"]
+        assert redacted["ai_summary"]["summary"].startswith("host1.example (Project Falcon)")
+        assert redacted["meta"]["suppressed_issues"][0]["url"] == "https://host3.example/legal"
+        assert _leaks(json.dumps(redacted)) == []
+
+    def test_strip_query_strings(self):
+        """Test query strings are removed from URLs in fields and text."""
+        # Act
+        redacted = redact_summary(_summary(), RedactionOptions(strip_query_strings=True))
+
+        # Assert
+        assert redacted["issues"][1]["url"] == "https://shop.acme.com/cart"
+        assert redacted["issues"][1]["detail"] == "https://cdn.acme-assets.net/app.js"
+        assert "s3cr3t" not in json.dumps(redacted)
+        assert "utm=" not in json.dumps(redacted)
+
+    def test_custom_patterns(self):
+        """Test custom patterns are replaced everywhere, including the AI summary."""
+        # Act
+        redacted = redact_summary(_summary(), RedactionOptions(custom_patterns=[r"Project \w+"]))
+
+        # Assert
+        assert "([redacted])" in redacted["ai_summary"]["summary"]
+
+    def test_invalid_pattern(self):
+        """Test a pattern that does not compile is refused."""
+        # Act & Assert
+        with pytest.raises(ValueError, match="invalid pattern"):
+            RedactionOptions(custom_patterns=["(unclosed"])
+
+    def test_catalog_messages_stay_consistent(self):
+        """Test redacted arguments still render the redacted detail, or are dropped."""
+        # Act
+        masked = redact_summary(_summary(), RedactionOptions(mask_hostnames=True))
+        patterned = redact_summary(_summary(), RedactionOptions(custom_patterns=[r"old, https"]))
+
+        # Assert
+        assert masked["issues"][2]["message"] == "redirects.redirect_chain"
+        assert "message" not in patterned["issues"][2]
+        assert "[redacted]" in patterned["issues"][2]["detail"]
+
+    def test_shared_masks_across_results(self):
+        """Test results redacted together map the same host to the same mask."""
+        # Arrange
+        other = _summary()
+        other["site"] = "https://acme.com/"
+        options = RedactionOptions(mask_hostnames=True)
+
+        # Act
+        first, second = redact_summaries([_summary(), other], options)
+
+        # Assert
+        assert first["site"] == "https://host1.example/"
+        assert second["site"] == "https://host2.example/"
+        assert second["issues"][0]["url"] == "https://host1.example/"
+
+    def test_inactive_options(self):
+        """Test options that redact nothing leave the result alone."""
+        # Arrange
+        summary = _summary()
+
+        # Act & Assert
+        assert redact_summary(summary, RedactionOptions()) is summary
+
+    def test_redact_in_place(self):
+        """Test an AuditResult is redacted in place."""
+        # Arrange
+        result = AuditResult(**_summary())
+
+        # Act
+        redact(result, RedactionOptions(mask_hostnames=True, strip_query_strings=True))
+
+        # Assert
+        assert result.site == "https://host1.example/"
+        assert result.issues[1].url == "https://host1.example/cart"
+
+
+@pytest.mark.unit
+class TestRenderedReports:
+    """Test no unmasked hostname survives in rendered reports."""
+
+    OPTIONS = RedactionOptions(mask_hostnames=True, strip_query_strings=True)
+
+    def test_markdown(self):
+        """Test the Markdown report mentions masks only."""
+        # Act
+        output = render_markdown(redact_summary(_summary(), self.OPTIONS))
+
+        # Assert
+        assert _leaks(output) == []
+        assert "host1.example" in output
+
+    def test_html(self, monkeypatch):
+        """Test the HTML report mentions masks only."""
+        # Arrange
+        pytest.importorskip("jinja2")
+        from tinyseoai.config import AppConfig
+        from tinyseoai.reporting import html_report
+
+        monkeypatch.setattr(html_report, "get_config", lambda: AppConfig())
+
+        # Act
+        output = html_report.render_html(redact_summary(_summary(), self.OPTIONS))
+
+        # Assert
+        assert _leaks(output) == []
+        assert "host1.example" in output
@@ -0,0 +1,234 @@
+"""
+Unit tests for redirect tracing.
+"""
+import httpx
+import pytest
+
+from tinyseoai.audit.checks.redirects import (
+    analyze,
+    meta_refresh_target,
+    redirect_type_issues,
+    trace,
+    trace_issues,
+)
+
+# path -> (status, Location) or (200, body)
+ROUTES = {
+    "/one": (301, "/two"),
+    "/two": (302, "/three"),
+    "/three": (307, "https://example.com/four"),
+    "/four": (200, "<html><body>Done</body></html>"),
+    "/short": (301, "/four"),
+    "/loop-a": (301, "/loop-b"),
+    "/loop-b": (308, "/loop-a"),
+    "/secure": (301, "http://example.com/insecure"),
+    "/insecure": (200, "<html></html>"),
+    "/refresh": (200, '<html><head><meta content="0; URL=\'/four\'" http-equiv="Refresh"></head></html>'),
+    "/dead": (301, "/gone"),
+    "/sale": (302, "/sale-2024"),
+    "/sale-2024": (307, "/four"),
+    "/mixed": (301, "/mixed-b"),
+    "/mixed-b": (302, "/four"),
+    "/temp-dead": (302, "/gone"),
+    "/api/subscribe": (308, "/api/v2/subscribe"),
+    "/api/v2/subscribe": (200, ""),
+}
+
+
+class _Server:
+    """Scripted redirects; records every request."""
+
+    def __init__(self):
+        self.requests: list[httpx.Request] = []
+
+    def __call__(self, request: httpx.Request) -> httpx.Response:
+        self.requests.append(request)
+        path = request.url.path
+        if path == "/endless":
+            return httpx.Response(302, headers={"location": f"/endless?n={len(self.requests)}"})
+        status, value = ROUTES.get(path, (404, ""))
+        if status in (301, 302, 307, 308):
+            return httpx.Response(status, headers={"location": value})
+        return httpx.Response(status, headers={"content-type": "text/html"}, text=value)
+
+    def client(self) -> httpx.AsyncClient:
+        return httpx.AsyncClient(transport=httpx.MockTransport(self))
+
+
+def _types(result) -> list[str]:
+    return sorted(i.type for i in trace_issues(result))
+
+
+@pytest.mark.unit
+class TestTrace:
+    """Test following redirects hop by hop."""
+
+    @pytest.mark.asyncio
+    async def test_chain(self):
+        """Test each hop's status and target are recorded and long chains reported."""
+        # Act
+        result = await trace("https://example.com/one", _Server().client())
+
+        # Assert
+        assert [(h.status_code, h.location) for h in result.hops] == [
+            (301, "https://example.com/two"),
+            (302, "https://example.com/three"),
+            (307, "https://example.com/four"),
+            (200, None),
+        ]
+        assert all(h.latency_ms >= 0 for h in result.hops)
+        assert result.final_url == "https://example.com/four"
+        issues = trace_issues(result)
+        assert [i.type for i in issues] == ["redirect_chain"]
+        assert issues[0].detail == (
+            "3-hop redirect chain wastes crawl budget: https://example.com/one -301-> https://example.com/two "
+            "-302-> https://example.com/three -307-> https://example.com/four (200)"
+        )
+
+    @pytest.mark.asyncio
+    async def test_short_chain_passes(self):
+        """Test a single redirect raises nothing."""
+        assert _types(await trace("https://example.com/short", _Server().client())) == []
+
+    @pytest.mark.asyncio
+    async def test_loop(self):
+        """Test loops stop without revisiting URLs."""
+        # Arrange
+        server = _Server()
+
+        # Act
+        result = await trace("https://example.com/loop-a", server.client())
+
+        # Assert
+        assert result.loop
+        assert len(server.requests) == 2
+        assert trace_issues(result)[0].detail == (
+            "Redirect loop: https://example.com/loop-a -301-> https://example.com/loop-b -308-> "
+            "https://example.com/loop-a"
+        )
+
+    @pytest.mark.asyncio
+    async def test_downgrade_meta_refresh_and_dead_end(self):
+        """Test HTTPS to HTTP hops, meta refreshes, and chains ending in errors."""
+        # Arrange
+        client = _Server().client()
+
+        # Act
+        downgrade = await trace("https://example.com/secure", client)
+        refresh = await trace("https://example.com/refresh", client)
+        dead = await trace("https://example.com/dead", client)
+
+        # Assert
+        assert _types(downgrade) == ["redirect_https_downgrade"]
+        assert refresh.hops[0].meta_refresh and refresh.final_url == "https://example.com/four"
+        assert _types(refresh) == ["meta_refresh_redirect"]
+        assert _types(dead) == ["redirect_to_error"]
+        assert trace_issues(dead)[0].detail.startswith("Redirect chain ends in HTTP 404")
+
+    @pytest.mark.asyncio
+    async def test_max_hops(self):
+        """Test tracing stops after max_hops redirects."""
+        # Arrange
+        server = _Server()
+
+        # Act
+        result = await trace("https://example.com/endless", server.client(), max_hops=4)
+
+        # Assert
+        assert result.too_many_hops
+        assert len(server.requests) == 4
+        assert _types(result) == ["redirect_too_many_hops"]
+
+    @pytest.mark.asyncio
+    async def test_head_ignores_body(self):
+        """Test HEAD traces cannot see meta refreshes."""
+        # Act
+        result = await trace("https://example.com/refresh", _Server().client(), method="HEAD")
+
+        # Assert
+        assert result.redirects == []
+        assert result.final_status == 200
+
+    @pytest.mark.parametrize(
+        ("html", "target"),
+        [
+            ('<meta http-equiv="refresh" content="5;url=/next">', "https://example.com/next"),
+            ("<META HTTP-EQUIV=refresh CONTENT='0, https://other.example/'>", "https://other.example/"),
+            ('<meta http-equiv="refresh" content="30">', None),
+            ('<meta name="refresh" content="0; url=/x">', None),
+        ],
+    )
+    def test_meta_refresh_target(self, html, target):
+        """Test refresh targets are parsed regardless of case, quoting, and separator."""
+        assert meta_refresh_target(html, "https://example.com/page") == target
+
+
+@pytest.mark.unit
+class TestRedirectTypes:
+    """Test classifying the redirects of linked URLs."""
+
+    @pytest.mark.asyncio
+    async def test_temporary_to_stable(self):
+        """Test temporary-only chains to a live page name the source, chain, and target."""
+        # Arrange
+        sources = ["https://example.com/", "https://example.com/blog"]
+
+        # Act
+        issues = await analyze({"https://example.com/sale": sources}, _Server().client())
+
+        # Assert
+        (issue,) = issues
+        assert (issue.url, issue.type, issue.severity) == (
+            "https://example.com/",
+            "temporary_redirect_stable",
+            "medium",
+        )
+        assert issue.detail == (
+            "The link to https://example.com/sale from https://example.com/ and 1 other page(s) "
+            "only goes through temporary redirects to a page that loads, so it is likely a "
+            "permanent move: https://example.com/sale -302-> https://example.com/sale-2024 "
+            "-307-> https://example.com/four (200). Use a 301 to https://example.com/four"
+        )
+
+    @pytest.mark.asyncio
+    async def test_mixed_chains(self):
+        """Test chains mixing 301/308 with 302/307 are reported once each."""
+        # Arrange
+        client = _Server().client()
+
+        # Act
+        mixed = await trace("https://example.com/mixed", client)
+        three_types = await trace("https://example.com/one", client)
+
+        # Assert
+        for result in (mixed, three_types):
+            issues = redirect_type_issues(result, ["https://example.com/"])
+            assert [i.type for i in issues] == ["redirect_chain_mixed_types"]
+        assert issues[0].detail.startswith(
+            "The link from https://example.com/ to https://example.com/one goes through a chain"
+        )
+
+    @pytest.mark.asyncio
+    async def test_method_preserving_on_form_endpoint(self):
+        """Test a 308 from an API-looking path is noted as info."""
+        # Act
+        result = await trace("https://example.com/api/subscribe", _Server().client())
+
+        # Assert
+        (issue,) = redirect_type_issues(result, [])
+        assert (issue.url, issue.type, issue.severity) == (
+            "https://example.com/api/subscribe",
+            "method_preserving_redirect",
+            "info",
+        )
+        assert issue.detail.startswith("The start URL https://example.com/api/subscribe passes")
+
+    @pytest.mark.asyncio
+    @pytest.mark.parametrize("path", ["/short", "/temp-dead", "/loop-a", "/four"])
+    async def test_nothing_to_classify(self, path):
+        """Test single 301s, dead ends, loops, and pages that do not redirect pass."""
+        # Act
+        result = await trace(f"https://example.com{path}", _Server().client())
+
+        # Assert
+        assert redirect_type_issues(result, ["https://example.com/"]) == []
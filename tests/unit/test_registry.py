@@ -0,0 +1,311 @@
+"""
+Unit tests for the site-level check registry.
+"""
+import asyncio
+
+import httpx
+import pytest
+
+from tinyseoai.audit.registry import (
+    Check,
+    CheckContext,
+    CheckRegistry,
+    FunctionCheck,
+    Requirements,
+)
+from tinyseoai.data.models import Issue
+from tinyseoai.progress import Phase, ProgressEmitter
+from tinyseoai.utils.http import HttpOptions, RetryPolicy, client
+
+
+class _Page:
+    def __init__(self, url: str, html: str = "<html></html>"):
+        self.url = url
+        self.html = html
+
+
+def _issue(url: str, issue_type: str = "custom_finding") -> Issue:
+    return Issue(url=url, type=issue_type, severity="low", detail="Found by a custom check")
+
+
+def _context(**kwargs) -> CheckContext:
+    kwargs.setdefault("pages", [_Page("https://example.com/")])
+    return CheckContext(seed_url="https://example.com/", **kwargs)
+
+
+def _statuses(results) -> dict[str, str]:
+    return {run.id: run.status for run in results.runs}
+
+
+class _PageCount(Check):
+    id = "page_count"
+    description = "Counts pages"
+    requires = Requirements(crawl=True)
+
+    async def run(self, ctx: CheckContext) -> list[Issue]:
+        return [_issue(ctx.seed_url, f"pages_{len(ctx.pages)}")]
+
+
+@pytest.mark.unit
+class TestRegistration:
+    """Test adding, listing, enabling, and disabling checks."""
+
+    def test_registration_order(self):
+        """Test checks keep the order they were registered in."""
+        # Arrange
+        registry = CheckRegistry([FunctionCheck("b", lambda ctx: []), _PageCount()])
+
+        # Act
+        registry.register(FunctionCheck("a", lambda ctx: []))
+
+        # Assert
+        assert registry.ids() == ["b", "page_count", "a"]
+        assert "page_count" in registry
+        assert [c.id for c in registry] == registry.ids()
+
+    def test_duplicate_id(self):
+        """Test a taken id is refused unless replacing."""
+        # Arrange
+        registry = CheckRegistry([FunctionCheck("a", lambda ctx: [])])
+        replacement = FunctionCheck("a", lambda ctx: [], description="New")
+
+        # Act & Assert
+        with pytest.raises(ValueError, match="already registered"):
+            registry.register(FunctionCheck("a", lambda ctx: []))
+        registry.register(replacement, replace=True)
+        assert list(registry) == [replacement]
+
+    def test_enable_and_disable(self):
+        """Test disabling and re-enabling by id."""
+        # Arrange
+        registry = CheckRegistry([FunctionCheck("a", lambda ctx: []), _PageCount()])
+
+        # Act
+        registry.disable("a", "page_count")
+        registry.enable("page_count")
+
+        # Assert
+        assert not registry.is_enabled("a")
+        assert registry.is_enabled("page_count")
+        assert not registry.is_enabled("missing")
+
+    def test_unknown_id(self):
+        """Test unknown ids are refused and the available ids are named."""
+        registry = CheckRegistry([FunctionCheck("a", lambda ctx: [])])
+
+        with pytest.raises(ValueError, match="Unknown check id\\(s\\): nope. Available: a"):
+            registry.disable("a", "nope")
+        assert registry.is_enabled("a")
+
+
+@pytest.mark.unit
+class TestRun:
+    """Test running checks against a context."""
+
+    @pytest.mark.asyncio
+    async def test_sync_async_and_class_checks(self):
+        """Test plain functions, coroutines, and Check subclasses all contribute issues."""
+
+        # Arrange
+        async def fetches(ctx: CheckContext) -> list[Issue]:
+            return [_issue(ctx.home.url, "async_finding")]
+
+        registry = CheckRegistry(
+            [
+                FunctionCheck("plain", lambda ctx: [_issue(ctx.seed_url)]),
+                FunctionCheck("fetches", fetches),
+                _PageCount(),
+            ]
+        )
+
+        # Act
+        results = await registry.run(_context())
+
+        # Assert
+        assert [i.type for i in results.issues] == ["custom_finding", "async_finding", "pages_1"]
+        assert _statuses(results) == {"plain": "ran", "fetches": "ran", "page_count": "ran"}
+        assert [run.issues for run in results.runs] == [1, 1, 1]
+
+    @pytest.mark.asyncio
+    async def test_unmet_requirements(self):
+        """Test checks the context cannot serve are skipped with the reason."""
+        # Arrange
+        registry = CheckRegistry(
+            [
+                FunctionCheck("deep", lambda ctx: [], Requirements(comprehensive=True)),
+                FunctionCheck("online", lambda ctx: [], Requirements(network=True)),
+                FunctionCheck("whole_site", lambda ctx: [], Requirements(crawl=True)),
+                FunctionCheck("needs_html", lambda ctx: [], Requirements(page=True)),
+                FunctionCheck("anywhere", lambda ctx: []),
+            ]
+        )
+        context = _context(pages=[_Page("https://example.com/", html="")])
+        context.comprehensive = False
+        context.full_crawl = False
+
+        # Act
+        results = await registry.run(context)
+
+        # Assert
+        assert {run.id: run.reason for run in results.runs} == {
+            "deep": "comprehensive checks are off (--fast)",
+            "online": "no network access",
+            "whole_site": "needs the full crawl",
+            "needs_html": "no HTML page was fetched",
+            "anywhere": None,
+        }
+        assert _statuses(results)["anywhere"] == "ran"
+
+    @pytest.mark.asyncio
+    async def test_disabled_checks_do_not_run(self):
+        """Test a disabled check is recorded but not called."""
+        # Arrange
+        calls = []
+        registry = CheckRegistry([FunctionCheck("a", lambda ctx: calls.append(ctx) or [])])
+        registry.disable("a")
+
+        # Act
+        results = await registry.run(_context())
+
+        # Assert
+        assert calls == []
+        assert _statuses(results) == {"a": "disabled"}
+
+    @pytest.mark.asyncio
+    async def test_progress_counts_runnable_checks(self):
+        """Test a checking event is emitted as each check starts, counting only runnable checks."""
+        # Arrange
+        events = []
+        registry = CheckRegistry(
+            [
+                FunctionCheck("a", lambda ctx: []),
+                FunctionCheck("offline", lambda ctx: [], Requirements(network=True)),
+                FunctionCheck("b", lambda ctx: []),
+            ]
+        )
+
+        # Act
+        await registry.run(_context(progress=ProgressEmitter(events.append, "j1")))
+
+        # Assert
+        assert [(e.phase, e.check_id, e.done, e.total, e.job_id) for e in events] == [
+            (Phase.CHECKING, "a", 1, 2, "j1"),
+            (Phase.CHECKING, "b", 2, 2, "j1"),
+        ]
+
+    @pytest.mark.asyncio
+    async def test_failure_is_isolated(self):
+        """Test a check that raises is recorded as failed and the next one still runs."""
+
+        # Arrange
+        def broken(ctx: CheckContext) -> list[Issue]:
+            raise RuntimeError("parser exploded")
+
+        registry = CheckRegistry(
+            [FunctionCheck("broken", broken), FunctionCheck("ok", lambda ctx: [_issue(ctx.seed_url)])]
+        )
+
+        # Act
+        results = await registry.run(_context())
+
+        # Assert
+        assert _statuses(results) == {"broken": "failed", "ok": "ran"}
+        assert results.runs[0].reason == "parser exploded"
+        assert len(results.issues) == 1
+
+    @pytest.mark.asyncio
+    async def test_checks_can_add_meta(self):
+        """Test values a check puts in ctx.meta stay on the context."""
+        # Arrange
+        def measures(ctx: CheckContext) -> list[Issue]:
+            ctx.meta["performance"] = {"ttfb_ms": 120}
+            return []
+
+        context = _context()
+
+        # Act
+        await CheckRegistry([FunctionCheck("measures", measures)]).run(context)
+
+        # Assert
+        assert context.meta == {"performance": {"ttfb_ms": 120}}
+
+    @pytest.mark.asyncio
+    async def test_requests_metered_per_check(self):
+        """Test each check is charged with the requests and bytes of its own clients."""
+        # Arrange
+        def server(request: httpx.Request) -> httpx.Response:
+            if request.url.path == "/flaky" and not server.retried:
+                server.retried = True
+                return httpx.Response(503)
+            return httpx.Response(200, content=b"x" * 1000)
+
+        server.retried = False
+        transport = httpx.MockTransport(server)
+        http = HttpOptions(retry=RetryPolicy(backoff=0))
+
+        async def fetches_two(ctx: CheckContext) -> list[Issue]:
+            async with client(http, transport=transport) as own:
+                await asyncio.gather(*(own.get(f"https://example.com/{p}") for p in "ab"))
+            return []
+
+        async def retries_one(ctx: CheckContext) -> list[Issue]:
+            async with client(http, transport=transport) as own:
+                async with own.stream("GET", "https://example.com/flaky") as response:
+                    await response.aread()
+            ctx.meter.requests += 1  # a request made with a client of its own
+            return []
+
+        registry = CheckRegistry(
+            [
+                FunctionCheck("fetches_two", fetches_two),
+                FunctionCheck("retries_one", retries_one),
+                FunctionCheck("offline", lambda ctx: []),
+            ]
+        )
+
+        # Act
+        results = await registry.run(_context())
+
+        # Assert
+        runs = {run.id: (run.requests, run.bytes_fetched) for run in results.runs}
+        assert runs == {"fetches_two": (2, 2000), "retries_one": (3, 1000), "offline": (0, 0)}
+        assert all(run.duration_ms >= 0 for run in results.runs)
+
+
+@pytest.mark.unit
+class TestBuiltins:
+    """Test the built-in registry."""
+
+    def test_default_ids(self):
+        """Test the built-in checks and their order."""
+        pytest.importorskip("bs4")
+        pytest.importorskip("lxml")
+        from tinyseoai.audit.builtin_checks import default_registry
+
+        registry = default_registry()
+
+        assert registry.ids() == [
+            "indexability",
+            "duplicate_meta",
+            "duplicate_content",
+            "boilerplate",
+            "pagination",
+            "lang",
+            "links",
+            "timing",
+            "icons",
+            "compression",
+            "caching",
+            "soft404",
+        ]
+        assert all(check.description for check in registry)
+
+    def test_fast_mode_keeps_crawl_checks(self):
+        """Test only the checks that worked with --fast before skip comprehensive mode."""
+        pytest.importorskip("bs4")
+        pytest.importorskip("lxml")
+        from tinyseoai.audit.builtin_checks import default_registry
+
+        fast = [c.id for c in default_registry() if not c.requires.comprehensive]
+
+        assert fast == ["indexability", "duplicate_meta"]
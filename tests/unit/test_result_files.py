@@ -0,0 +1,127 @@
+"""
+Unit tests for reading and writing plain and compressed result files.
+"""
+import gzip
+import json
+import shutil
+from pathlib import Path
+
+import pytest
+
+from tinyseoai.data.models import AuditResult
+from tinyseoai.store.history import HistoryStore
+from tinyseoai.utils.io import _zstd, compressed_path, read_json, rewrite_json, sniff, write_json
+
+
+def _has_zstd() -> bool:
+    try:
+        _zstd()
+    except ValueError:
+        return False
+    return True
+
+
+@pytest.mark.unit
+class TestRoundTrip:
+    """Test results written compressed load back unchanged."""
+
+    @pytest.mark.parametrize("codec,extension", [("gzip", ".gz"), ("zstd", ".zst")])
+    def test_codec(self, codec, extension, results_fixtures_dir, tmp_path):
+        """Test the extension is added, the codec is sniffed, and the result is the same."""
+        # Arrange
+        if codec == "zstd" and not _has_zstd():
+            pytest.skip("no zstd module (Python 3.14+ or the zstandard package)")
+        result = AuditResult.load(results_fixtures_dir / "basic_summary.json")
+
+        # Act
+        path = write_json(tmp_path / "summary.json", json.loads(result.model_dump_json()), codec)
+
+        # Assert
+        assert path.name == f"summary.json{extension}"
+        assert sniff(path) == codec
+        assert path.stat().st_size < len(result.to_json())
+        assert AuditResult.load(path) == result
+
+    def test_plain_unchanged(self, tmp_path):
+        """Test without a codec the file is the plain indented JSON as before."""
+        # Act
+        path = write_json(tmp_path / "summary.json", {"site": "https://example.com/"})
+
+        # Assert
+        assert path == tmp_path / "summary.json"
+        assert sniff(path) is None
+        assert path.read_text() == json.dumps({"site": "https://example.com/"}, indent=2)
+
+    def test_extension_not_doubled(self, tmp_path):
+        """Test a name already ending with the codec's extension is kept."""
+        # Act / Assert
+        assert compressed_path(tmp_path / "a.json.gz", "gzip") == tmp_path / "a.json.gz"
+        assert compressed_path(tmp_path / "a.json", "zstd") == tmp_path / "a.json.zst"
+        assert compressed_path(tmp_path / "a.json", None) == tmp_path / "a.json"
+
+
+@pytest.mark.unit
+class TestSniffing:
+    """Test compression is told from the content, not the name."""
+
+    def test_mislabeled_gzip_loads(self, results_fixtures_dir, tmp_path):
+        """Test gzip content saved as .json still loads."""
+        # Arrange
+        source = results_fixtures_dir / "basic_summary.json"
+        path = tmp_path / "summary.json"
+        path.write_bytes(gzip.compress(source.read_bytes()))
+
+        # Act
+        result = AuditResult.load(path)
+
+        # Assert
+        assert result == AuditResult.load(source)
+
+    def test_rewrite_keeps_codec(self, tmp_path):
+        """Test rewriting a compressed file keeps its compression and its name."""
+        # Arrange
+        path = tmp_path / "summary.json"
+        path.write_bytes(gzip.compress(b'{"site": "a"}'))
+
+        # Act
+        written = rewrite_json(path, {"site": "b"})
+
+        # Assert
+        assert written == path
+        assert sniff(path) == "gzip"
+        assert read_json(path) == {"site": "b"}
+
+    def test_truncated_file(self, results_fixtures_dir, tmp_path):
+        """Test a cut-off gzip file raises instead of loading part of a result."""
+        # Arrange
+        data = gzip.compress((results_fixtures_dir / "basic_summary.json").read_bytes())
+        path = tmp_path / "summary.json.gz"
+        path.write_bytes(data[: len(data) // 2])
+
+        # Act / Assert
+        with pytest.raises((OSError, ValueError)):
+            read_json(path)
+
+
+@pytest.mark.unit
+class TestHistoryImport:
+    """Test the history imports compressed files."""
+
+    def test_folder_with_compressed_files(self, results_fixtures_dir, tmp_path):
+        """Test .json.gz files in a folder are found, and a mislabeled one imports too."""
+        # Arrange
+        folder = tmp_path / "results"
+        folder.mkdir()
+        shutil.copy(results_fixtures_dir / "basic_summary.json", folder / "a.json")
+        comprehensive = (results_fixtures_dir / "comprehensive_summary.json").read_bytes()
+        (folder / "b.json.gz").write_bytes(gzip.compress(comprehensive))
+        pathological = (results_fixtures_dir / "pathological.json").read_bytes()
+        (folder / "c.json").write_bytes(gzip.compress(pathological))
+
+        # Act
+        with HistoryStore(tmp_path / "history.sqlite3") as store:
+            outcomes = store.import_files([folder])
+
+        # Assert
+        assert [Path(o.path).name for o in outcomes] == ["a.json", "b.json.gz", "c.json"]
+        assert {o.status for o in outcomes} == {"imported"}
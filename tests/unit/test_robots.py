@@ -1,10 +1,17 @@
 """
 Unit tests for robots.txt parsing and sitemap discovery.
 """
+import httpx
 import pytest
 from unittest.mock import AsyncMock, Mock
 
-from tinyseoai.audit.robots import RobotsAnalyzer, SitemapParser
+from tinyseoai.audit.robots import (
+    MAX_ROBOTS_BYTES,
+    RobotsAnalyzer,
+    SitemapParser,
+    analyze_robots,
+    parse_robots,
+)
 
 
 @pytest.mark.unit
@@ -229,3 +236,142 @@ class TestSitemapParser:
 
         # Assert
         assert success is False
+
+
+@pytest.mark.unit
+class TestParseRobots:
+    """Test the robots.txt parser and matcher."""
+
+    def test_groups(self):
+        """Test consecutive user-agent lines share a group and specific agents win."""
+        # Arrange
+        rules = parse_robots(
+            """
+            User-agent: Googlebot
+            User-agent: Bingbot
+            Disallow: /search
+            Crawl-delay: 2
+
+            User-agent: *
+            Disallow: /
+            Sitemap: https://example.com/sitemap.xml
+            """
+        )
+
+        # Act / Assert
+        assert rules.group_for("Googlebot/2.1").user_agents == ["Bingbot", "Googlebot"]
+        assert rules.group_for("Googlebot").crawl_delay == 2
+        assert rules.can_fetch("https://example.com/page", "Googlebot")
+        assert not rules.can_fetch("https://example.com/search?q=x", "bingbot")
+        assert not rules.can_fetch("https://example.com/page", "OtherBot")
+        assert rules.sitemaps == ["https://example.com/sitemap.xml"]
+
+    @pytest.mark.parametrize(
+        "robots, path, allowed",
+        [
+            ("Disallow: /*.pdf$", "/files/report.pdf", False),
+            ("Disallow: /*.pdf$", "/files/report.pdf?download=1", True),
+            ("Disallow: /private*/data", "/private-area/data/x", False),
+            ("Disallow: /fish", "/fish.html", False),
+            ("Disallow: /fish$", "/fish.html", True),
+            ("Disallow: /page\nAllow: /page", "/page", True),  # tie: allow wins
+            ("Disallow: /\nAllow: /public/", "/public/a", True),  # longer allow wins
+            ("Allow: /p\nDisallow: /p*s", "/posts", False),  # longer pattern wins
+            ("Allow: /*.css$\nDisallow: /assets/", "/assets/site.css", False),
+            ("Disallow:", "/anything", True),  # empty disallow allows all
+            ("Disallow: /", "/robots.txt", True),
+        ],
+    )
+    def test_precedence(self, robots, path, allowed):
+        """Test wildcards, end anchors, and longest-match precedence."""
+        # Arrange
+        rules = parse_robots("User-agent: *\n" + robots + "\n")
+
+        # Act / Assert
+        assert rules.can_fetch("https://example.com" + path) is allowed
+
+    def test_truncation(self):
+        """Test rules after the size limit are ignored."""
+        # Arrange
+        filler = "# " + "x" * 98 + "\n"
+        text = "User-agent: *\nDisallow: /early\n" + filler * (MAX_ROBOTS_BYTES // len(filler)) + "Disallow: /late\n"
+
+        # Act
+        rules = parse_robots(text)
+
+        # Assert
+        assert rules.truncated
+        assert not rules.can_fetch("https://example.com/early")
+        assert rules.can_fetch("https://example.com/late")
+
+    def test_analyzer_uses_wildcards(self):
+        """Test RobotsAnalyzer.can_fetch understands * and $."""
+        # Arrange
+        analyzer = RobotsAnalyzer("https://example.com")
+        analyzer.content = "User-agent: *\nDisallow: /*?sort=\n"
+
+        # Act / Assert
+        assert not analyzer.can_fetch("https://example.com/shop?sort=price")
+        assert analyzer.can_fetch("https://example.com/shop")
+
+
+def _robots_client(status: int = 200, body: str = "", content_type: str = "text/plain") -> httpx.AsyncClient:
+    def handler(request):
+        return httpx.Response(status, text=body, headers={"content-type": content_type})
+
+    return httpx.AsyncClient(transport=httpx.MockTransport(handler))
+
+
+@pytest.mark.unit
+class TestAnalyzeRobots:
+    """Test robots.txt findings."""
+
+    @pytest.mark.asyncio
+    async def test_missing(self):
+        """Test a 404 robots.txt is reported as missing."""
+        # Act
+        report = await analyze_robots("https://example.com/page", _robots_client(404))
+
+        # Assert
+        assert [i.type for i in report.issues] == ["robots_missing"]
+        assert report.can_fetch("https://example.com/anything")
+
+    @pytest.mark.asyncio
+    async def test_blanket_disallow_and_resources(self):
+        """Test a site-wide block, blocked assets, and a missing sitemap line."""
+        # Arrange
+        body = "User-agent: *\nDisallow: /\nDisallow: /assets/js/\n"
+
+        # Act
+        report = await analyze_robots("https://example.com", _robots_client(body=body))
+
+        # Assert
+        assert sorted(i.type for i in report.issues) == [
+            "robots_blocks_resources",
+            "robots_disallow_all",
+            "robots_sitemap_missing",
+        ]
+        assert not report.can_fetch("https://example.com/")
+
+    @pytest.mark.asyncio
+    async def test_served_as_html(self):
+        """Test an HTML page at /robots.txt is flagged and ignored."""
+        # Arrange
+        client = _robots_client(body="<!DOCTYPE html><html><body>Not found</body></html>", content_type="text/html")
+
+        # Act
+        report = await analyze_robots("https://example.com", client)
+
+        # Assert
+        assert [i.type for i in report.issues] == ["robots_served_as_html"]
+        assert not report.found
+
+    @pytest.mark.asyncio
+    async def test_clean(self, sample_robots_txt):
+        """Test a sensible robots.txt has no findings."""
+        # Act
+        report = await analyze_robots("https://example.com", _robots_client(body=sample_robots_txt))
+
+        # Assert
+        assert report.issues == []
+        assert report.rules.group_for("*").crawl_delay == 1
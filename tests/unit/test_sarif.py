@@ -0,0 +1,109 @@
+"""
+Unit tests for the SARIF exporter.
+"""
+import json
+from pathlib import Path
+
+import pytest
+
+from tinyseoai.data.demo import build_demo_result
+from tinyseoai.data.fingerprint import fingerprint
+from tinyseoai.data.models import AuditResult
+from tinyseoai.reporting.sarif import SARIF_VERSION, render_sarif, write_sarif
+
+SARIF_SCHEMA = Path(__file__).parents[1] / "fixtures" / "schemas" / "sarif-2.1.0.json"
+
+
+@pytest.mark.unit
+class TestSarifMapping:
+    """Test issues map onto SARIF results and rules."""
+
+    def test_one_result_per_issue(self):
+        """Test every issue becomes a result pointing at its page."""
+        # Arrange
+        result = build_demo_result()
+
+        # Act
+        sarif = render_sarif(result)
+
+        # Assert
+        run = sarif["runs"][0]
+        assert sarif["version"] == SARIF_VERSION
+        assert len(run["results"]) == len(result.issues)
+        first = run["results"][0]
+        assert first["ruleId"] == result.issues[0].type
+        assert first["locations"][0]["physicalLocation"]["artifactLocation"]["uri"] == result.issues[0].url
+        assert first["partialFingerprints"] == {"tinyseoai/v2": fingerprint(result.issues[0])}
+
+    def test_rules_are_distinct_types(self):
+        """Test the rules array lists each issue type once and ruleIndex points into it."""
+        # Arrange
+        result = build_demo_result()
+
+        # Act
+        run = render_sarif(result)["runs"][0]
+
+        # Assert
+        rules = run["tool"]["driver"]["rules"]
+        assert [r["id"] for r in rules] == list(dict.fromkeys(i.type for i in result.issues))
+        for res in run["results"]:
+            assert rules[res["ruleIndex"]]["id"] == res["ruleId"]
+
+    @pytest.mark.parametrize(
+        "severity,level",
+        [("high", "error"), ("medium", "warning"), ("low", "note"), ("info", "none"), ("Blocker", "warning")],
+    )
+    def test_levels(self, severity, level):
+        """Test severities map to SARIF levels, unknown ones to warning."""
+        # Arrange
+        result = AuditResult(
+            site="https://example.com/",
+            pages_scanned=1,
+            issues=[{"url": "https://example.com/", "type": "title_missing", "severity": severity}],
+            meta={},
+        )
+
+        # Act
+        res = render_sarif(result)["runs"][0]["results"][0]
+
+        # Assert
+        assert res["level"] == level
+
+    def test_message_includes_recommendation(self):
+        """Test the message combines the detail and the fix recommendation."""
+        # Arrange
+        result = AuditResult(
+            site="https://example.com/",
+            pages_scanned=1,
+            issues=[{"url": "https://example.com/", "type": "missing_hsts", "severity": "medium", "detail": "No HSTS"}],
+            meta={},
+        )
+
+        # Act
+        text = render_sarif(result)["runs"][0]["results"][0]["message"]["text"]
+
+        # Assert
+        assert text.startswith("No HSTS — ")
+        assert "Strict-Transport-Security" in text
+
+
+@pytest.mark.unit
+class TestSarifSchema:
+    """Test output validates against the SARIF 2.1.0 schema."""
+
+    @pytest.mark.parametrize("fixture", ["demo", "pathological.json", "unreachable_site.json"])
+    def test_validates(self, results_fixtures_dir, tmp_path, fixture):
+        """Test rendered logs validate against the bundled schema."""
+        # Arrange
+        jsonschema = pytest.importorskip("jsonschema")
+        if fixture == "demo":
+            result = build_demo_result()
+        else:
+            result = AuditResult.load(results_fixtures_dir / fixture)
+
+        # Act
+        path = write_sarif(result, tmp_path / "out.sarif")
+
+        # Assert
+        schema = json.loads(SARIF_SCHEMA.read_text())
+        jsonschema.validate(json.loads(path.read_text()), schema)
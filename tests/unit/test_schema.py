@@ -0,0 +1,75 @@
+"""
+Unit tests for the audit result JSON Schema and payload validation.
+"""
+import json
+from pathlib import Path
+
+import pytest
+
+from tinyseoai.data.schema import schema, validate
+
+SCHEMA_FILE = Path(__file__).parents[2] / "docs" / "schema" / "audit_result.schema.json"
+
+
+@pytest.mark.unit
+class TestSchema:
+    """Test the generated schema document."""
+
+    def test_schema_has_required_fields(self):
+        """Test the top-level contract."""
+        # Act
+        doc = schema()
+
+        # Assert
+        assert doc["$schema"].startswith("https://json-schema.org/")
+        assert set(doc["required"]) == {"site", "pages_scanned", "issues", "meta"}
+        assert "Issue" in doc["$defs"]
+
+    def test_shipped_schema_is_up_to_date(self):
+        """Test docs/schema matches the model (regenerate with `tinyseoai schema -o`)."""
+        assert json.loads(SCHEMA_FILE.read_text()) == schema()
+
+
+@pytest.mark.unit
+class TestValidate:
+    """Test lenient, path-level validation."""
+
+    @pytest.mark.parametrize(
+        "name", ["basic_summary.json", "comprehensive_summary.json", "unreachable_site.json"]
+    )
+    def test_captured_output_is_valid(self, results_fixtures_dir, name):
+        """Test real CLI output passes validation."""
+        # Arrange
+        payload = json.loads((results_fixtures_dir / name).read_text())
+
+        # Act & Assert
+        assert validate(payload) == []
+
+    def test_reports_paths_of_mismatches(self):
+        """Test every mismatch is reported with a readable path."""
+        # Arrange
+        payload = {
+            "site": "https://example.com/",
+            "pages_scanned": "many",
+            "issues": [
+                {"url": "https://example.com/", "type": "title_missing", "severity": "high"},
+                {"url": "https://example.com/a", "type": "noindex", "severity": None},
+            ],
+        }
+
+        # Act
+        messages = [str(issue) for issue in validate(payload)]
+
+        # Assert
+        assert "pages_scanned: expected integer, got string" in messages
+        assert "issues[1].severity: expected string, got null" in messages
+        assert "meta: required field is missing" in messages
+
+    def test_non_object_payload(self):
+        """Test a non-object payload does not raise."""
+        # Act
+        issues = validate([1, 2, 3])
+
+        # Assert
+        assert len(issues) == 1
+        assert issues[0].path == "$"
@@ -0,0 +1,112 @@
+"""
+Unit tests for crawl scope options.
+"""
+import pytest
+from pydantic import ValidationError
+
+from tinyseoai.audit.scope import CrawlOptions, compile_pattern
+from tinyseoai.config import AppConfig
+
+
+@pytest.mark.unit
+class TestCrawlOptionsValidation:
+    """Test that invalid scopes are rejected up front."""
+
+    def test_defaults(self):
+        """Test default scope matches the engine defaults."""
+        # Act
+        options = CrawlOptions()
+
+        # Assert
+        assert options.max_pages == 50
+        assert options.max_depth is None
+        assert options.follow_subdomains is False
+
+    def test_invalid_regex_names_pattern(self):
+        """Test that a broken regex is reported with the offending pattern."""
+        # Act & Assert
+        with pytest.raises(ValidationError) as exc_info:
+            CrawlOptions(exclude=["/ok/*", "re:([a-z"])
+
+        assert "re:([a-z" in str(exc_info.value)
+
+    def test_empty_pattern_rejected(self):
+        """Test that blank patterns are rejected."""
+        with pytest.raises(ValueError):
+            compile_pattern("  ")
+
+    @pytest.mark.parametrize("field,value", [("max_pages", 0), ("max_pages", 10_001), ("max_depth", -1)])
+    def test_limits_bounded(self, field, value):
+        """Test that page and depth limits are bounded."""
+        with pytest.raises(ValidationError):
+            CrawlOptions(**{field: value})
+
+
+@pytest.mark.unit
+class TestCrawlOptionsAllows:
+    """Test URL scope decisions."""
+
+    def test_other_host_rejected(self):
+        """Test that external hosts are never crawled."""
+        options = CrawlOptions()
+
+        assert options.allows("https://example.com/a", "example.com")
+        assert not options.allows("https://other.com/a", "example.com")
+
+    def test_subdomains_only_when_enabled(self):
+        """Test subdomain following is opt-in."""
+        # Arrange
+        strict = CrawlOptions()
+        loose = CrawlOptions(follow_subdomains=True)
+        url = "https://blog.example.com/post"
+
+        # Assert
+        assert not strict.allows(url, "www.example.com")
+        assert loose.allows(url, "www.example.com")
+        assert not loose.allows("https://notexample.com/", "example.com")
+
+    def test_max_depth(self):
+        """Test that links deeper than max_depth are skipped."""
+        options = CrawlOptions(max_depth=1)
+
+        assert options.allows("https://example.com/a", "example.com", depth=1)
+        assert not options.allows("https://example.com/a/b", "example.com", depth=2)
+
+    def test_glob_include_and_exclude(self):
+        """Test glob patterns match against the URL path."""
+        # Arrange
+        options = CrawlOptions(include=["/blog/*"], exclude=["*/drafts/*"])
+
+        # Assert
+        assert options.allows("https://example.com/blog/post-1", "example.com")
+        assert not options.allows("https://example.com/shop/item", "example.com")
+        assert not options.allows("https://example.com/blog/drafts/x", "example.com")
+
+    def test_regex_pattern_matches_full_url(self):
+        """Test re: patterns search the full URL."""
+        options = CrawlOptions(exclude=[r"re:\?page=\d+"])
+
+        assert options.allows("https://example.com/list", "example.com")
+        assert not options.allows("https://example.com/list?page=2", "example.com")
+
+
+@pytest.mark.unit
+class TestCrawlOptionsPersistence:
+    """Test scope metadata and per-site defaults."""
+
+    def test_describe(self):
+        """Test the report-facing description."""
+        options = CrawlOptions(max_pages=10, max_depth=2, exclude=["/tag/*"])
+
+        assert options.describe() == "up to 10 pages; depth ≤ 2; exclude /tag/*"
+
+    def test_site_defaults_round_trip(self):
+        """Test per-site defaults survive config serialization."""
+        # Arrange
+        cfg = AppConfig(site_defaults={"example.com": CrawlOptions(max_depth=3)})
+
+        # Act
+        loaded = AppConfig.model_validate_json(cfg.model_dump_json())
+
+        # Assert
+        assert loaded.site_defaults["example.com"].max_depth == 3
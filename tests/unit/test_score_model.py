@@ -0,0 +1,285 @@
+"""
+Unit tests for the configurable score model.
+"""
+import pytest
+
+from tinyseoai.data.demo import build_demo_result
+from tinyseoai.data.models import AuditResult
+from tinyseoai.data.score_model import ScoreModel, compute, explain, recompute_score
+from tinyseoai.data.scoring import HealthScoreCalculator
+
+
+def _result(issues: list[dict], pages: int = 10) -> AuditResult:
+    return AuditResult(site="https://example.com/", pages_scanned=pages, issues=issues, meta={})
+
+
+def _issues(count: int, issue_type: str = "title_missing", severity: str = "medium") -> list[dict]:
+    return [
+        {"url": f"https://example.com/p{n}", "type": issue_type, "severity": severity}
+        for n in range(count)
+    ]
+
+
+@pytest.mark.unit
+class TestDefaultModel:
+    """Test the default model matches the CLI score."""
+
+    @pytest.mark.parametrize(
+        "fixture", ["demo", "diff/base.json", "diff/head.json", "results/basic_summary.json"]
+    )
+    def test_reproduces_cli_score(self, results_fixtures_dir, fixture):
+        """Test the default model is within one point of HealthScoreCalculator."""
+        # Arrange
+        if fixture == "demo":
+            result = build_demo_result()
+        else:
+            result = AuditResult.load(results_fixtures_dir.parent / fixture)
+        expected = HealthScoreCalculator().calculate_health_score(result.issues, result.pages_scanned)
+
+        # Act
+        score = recompute_score(result)
+
+        # Assert
+        assert abs(score - expected["overall_score"]) <= 1
+
+    def test_does_not_modify_result(self):
+        """Test recomputing leaves the stored score alone."""
+        # Arrange
+        result = build_demo_result()
+        before = result.to_json()
+
+        # Act
+        recompute_score(result, ScoreModel(page_dampening="log"))
+
+        # Assert
+        assert result.to_json() == before
+
+
+@pytest.mark.unit
+class TestWeights:
+    """Test weight changes move the score the expected way."""
+
+    def test_heavier_severity_lowers_score(self):
+        """Test raising a severity weight lowers the score."""
+        # Arrange
+        result = _result(_issues(3))
+        heavy = ScoreModel(severity_weights={"medium": 3.0})
+
+        # Act / Assert
+        assert recompute_score(result, heavy) < recompute_score(result)
+
+    def test_category_multiplier(self):
+        """Test a category multiplier below 1 raises the score."""
+        # Arrange
+        result = _result(_issues(3))
+        lenient = ScoreModel(category_multipliers={"content": 0.2})
+
+        # Act / Assert
+        assert recompute_score(result, lenient) > recompute_score(result)
+
+    def test_info_cheaper_than_high(self):
+        """Test info issues cost less than the same number of high issues."""
+        # Arrange
+        info = _result(_issues(5, severity="info"))
+        high = _result(_issues(5, severity="high"))
+
+        # Act / Assert
+        assert recompute_score(info) > recompute_score(high)
+
+    @pytest.mark.parametrize("curve", ["sqrt", "log"])
+    def test_dampening_softens_sitewide_issues(self, curve):
+        """Test dampening curves make one issue on many pages cost less than linear."""
+        # Arrange
+        result = _result(_issues(40, issue_type="missing_canonical"), pages=40)
+
+        # Act
+        damped = recompute_score(result, ScoreModel(page_dampening=curve))
+
+        # Assert
+        assert damped > recompute_score(result)
+
+
+@pytest.mark.unit
+class TestClamping:
+    """Test the score stays within 0–100."""
+
+    def test_floor(self):
+        """Test huge penalties clamp to 0."""
+        assert recompute_score(_result(_issues(500), pages=1)) == 0
+
+    def test_ceiling(self):
+        """Test negative weights cannot push the score above 100."""
+        # Arrange
+        model = ScoreModel(severity_weights={"medium": -10.0}, density_penalty=0)
+
+        # Act / Assert
+        assert recompute_score(_result(_issues(3)), model) == 100
+
+    def test_no_pages(self):
+        """Test an unreachable site scores 0."""
+        assert recompute_score(_result([], pages=0)) == 0
+
+
+@pytest.mark.unit
+class TestLoading:
+    """Test score models load from config files."""
+
+    def test_toml(self, tmp_path):
+        """Test a TOML model overrides only the given keys."""
+        # Arrange
+        path = tmp_path / "model.toml"
+        path.write_text('page_dampening = "sqrt"\n\n[severity_weights]\ninfo = 0.0\n')
+
+        # Act
+        model = ScoreModel.load(path)
+
+        # Assert
+        assert model.page_dampening == "sqrt"
+        assert model.severity_weights["info"] == 0.0
+        assert model.severity_weights["high"] == 1.2
+        assert model.density_penalty == 5.0
+
+    def test_json(self, tmp_path):
+        """Test a JSON model loads."""
+        # Arrange
+        path = tmp_path / "model.json"
+        path.write_text('{"category_multipliers": {"security": 0.5}}')
+
+        # Act / Assert
+        assert ScoreModel.load(path).category_multipliers == {"security": 0.5}
+
+
+@pytest.mark.unit
+class TestCompute:
+    """Test compute against hand-computed scores."""
+
+    def test_no_issues_is_perfect(self):
+        """Test a scanned site without issues scores 100."""
+        # Act
+        metrics = compute([], 10)
+
+        # Assert
+        assert (metrics.score, metrics.grade, metrics.total) == (100, "A", 0)
+        assert metrics.deductions == {}
+
+    def test_no_pages_scores_zero(self):
+        """Test nothing scanned scores 0 but issues are still counted."""
+        # Act
+        metrics = compute(_result(_issues(2)).issues, 0)
+
+        # Assert
+        assert metrics.score == 0
+        assert metrics.warning == 2
+
+    def test_single_high_issue(self):
+        """Test (impact 8 x 1.2 x 2 + 5) / 10 pages = 2.42 points."""
+        # Act
+        metrics = compute(_result(_issues(1, severity="high")).issues, 10)
+
+        # Assert
+        assert metrics.deductions == {"title_missing": 2.42}
+        assert metrics.score == 98
+        assert metrics.critical == 1
+
+    @pytest.mark.parametrize("dampening,expected", [("linear", 92), ("sqrt", 96)])
+    def test_dampening(self, dampening, expected):
+        """Test four issues of one type: 4 x 21 = 84, so 8.4 points linear and 4.2 with sqrt."""
+        # Act
+        metrics = compute(_result(_issues(4)).issues, 10, ScoreModel(page_dampening=dampening))
+
+        # Assert
+        assert metrics.score == expected
+
+    def test_counts_by_severity(self):
+        """Test high is critical, medium/low/unknown are warnings, and info is info."""
+        # Arrange
+        issues = _result(
+            [
+                *_issues(1, severity="high"),
+                *_issues(1, severity="medium"),
+                *_issues(1, severity="low"),
+                *_issues(1, severity="odd"),
+                *_issues(1, severity="info"),
+            ]
+        ).issues
+
+        # Act
+        metrics = compute(issues, 5)
+
+        # Assert
+        assert (metrics.total, metrics.critical, metrics.warning, metrics.info) == (5, 1, 3, 1)
+
+    def test_deterministic(self):
+        """Test the order of issues does not change the result."""
+        # Arrange
+        issues = build_demo_result().issues
+
+        # Act & Assert
+        assert compute(issues, 12) == compute(list(reversed(issues)), 12)
+
+    def test_matches_recompute_score(self):
+        """Test recompute_score is compute over the result's issues."""
+        # Arrange
+        result = build_demo_result()
+
+        # Act & Assert
+        assert recompute_score(result) == compute(result.issues, result.pages_scanned).score
+
+
+@pytest.mark.unit
+class TestExplain:
+    """Test attributing lost points to issue types."""
+
+    @pytest.mark.parametrize(
+        "model", [ScoreModel(), ScoreModel(page_dampening="sqrt"), ScoreModel(impact_factor=4)]
+    )
+    @pytest.mark.parametrize("fixture", ["demo", "diff/base.json", "results/basic_summary.json"])
+    def test_sums_to_gap(self, results_fixtures_dir, fixture, model):
+        """Test contributions add up to 100 minus the computed score, within rounding."""
+        # Arrange
+        if fixture == "demo":
+            result = build_demo_result()
+        else:
+            result = AuditResult.load(results_fixtures_dir.parent / fixture)
+
+        # Act
+        contributions = explain(result, model)
+
+        # Assert
+        gap = 100 - recompute_score(result, model)
+        tolerance = 0.5 + 0.01 * len(contributions)  # the score and each point are rounded
+        assert sum(c.points for c in contributions) == pytest.approx(gap, abs=tolerance)
+
+    def test_sorted_with_math(self):
+        """Test the largest contribution comes first and is weight x page factor."""
+        # Arrange
+        result = _result([*_issues(1, severity="high"), *_issues(4, "meta_description_missing")])
+
+        # Act
+        contributions = explain(result, ScoreModel(page_dampening="sqrt"))
+
+        # Assert
+        first, second = contributions
+        assert (first.issue_type, first.issues) == ("meta_description_missing", 4)
+        assert first.page_factor == 0.5
+        assert first.points == pytest.approx(first.weight * first.page_factor, abs=0.01)
+        assert first.formula == f"{first.weight:.2f} x 0.50"
+        assert second.points == 2.42
+
+    def test_clamped_score(self):
+        """Test points are scaled down to 100 when the issues cost more."""
+        # Arrange
+        result = _result(_issues(50, severity="high") + _issues(50, "h1_missing", "high"), pages=1)
+
+        # Act
+        contributions = explain(result)
+
+        # Assert
+        assert recompute_score(result) == 0
+        assert sum(c.points for c in contributions) == pytest.approx(100, abs=0.02)
+
+    def test_nothing_to_explain(self):
+        """Test a clean result and a result without pages explain nothing."""
+        # Act & Assert
+        assert explain(_result([])) == []
+        assert explain(_result(_issues(2), pages=0)) == []
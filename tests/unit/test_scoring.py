@@ -3,11 +3,13 @@ Unit tests for scoring algorithms.
 """
 import pytest
 
+from tinyseoai.data.demo import build_demo_result
 from tinyseoai.data.models import Issue
 from tinyseoai.data.scoring import (
     IssueScorer,
     HealthScoreCalculator,
     prioritize_issues,
+    quick_wins,
     ISSUE_IMPACT_SCORES,
     ISSUE_EFFORT_SCORES,
 )
@@ -282,3 +284,49 @@ def test_quick_wins_identified():
     # title_missing should be higher priority than no_https
     first_issue_type = prioritized[0][0].type
     assert first_issue_type == "title_missing"
+
+
+@pytest.mark.unit
+class TestQuickWins:
+    """Test picking the easiest fixes."""
+
+    def test_demo_result(self):
+        """Test the choices for the demo result stay pinned."""
+        # Act
+        wins = quick_wins(build_demo_result().issues)
+
+        # Assert
+        assert [(w["issue_type"], w["severity"], w["pages"]) for w in wins] == [
+            ("title_missing", "high", 1),
+            ("missing_hsts", "medium", 1),
+            ("missing_canonical", "medium", 1),
+        ]
+
+    def test_page_count_and_templates(self):
+        """Test a fix repeated on many pages loses to one fixed once in a template."""
+        # Arrange
+        issues = [
+            Issue(url=f"https://example.com/p{n}", type=issue_type, severity="low")
+            for n in range(9)
+            for issue_type in ("img_alt_missing", "meta_description_missing")
+        ]
+
+        # Act
+        wins = quick_wins(issues)
+
+        # Assert
+        assert [w["issue_type"] for w in wins] == ["meta_description_missing", "img_alt_missing"]
+        assert wins[0]["score"] == 10.0
+        assert wins[1]["score"] == pytest.approx(8.0 / 3, abs=0.01)
+
+    def test_fewer_than_limit(self):
+        """Test only what qualifies is returned: info issues are left out."""
+        # Arrange
+        issues = [
+            Issue(url="https://example.com/", type="noindex", severity="info"),
+            Issue(url="https://example.com/", type="broken_link", severity="medium"),
+        ]
+
+        # Act / Assert
+        assert [w["issue_type"] for w in quick_wins(issues)] == ["broken_link"]
+        assert quick_wins([]) == []
@@ -0,0 +1,87 @@
+"""
+Unit tests for severity and category parsing.
+"""
+import pytest
+
+from tinyseoai.data.models import Issue
+from tinyseoai.data.severity import Category, Severity, count_by_severity, severity_rank
+
+
+@pytest.mark.unit
+class TestSeverity:
+    """Test tolerant severity parsing and ordering."""
+
+    @pytest.mark.parametrize(
+        "raw,expected",
+        [
+            ("high", Severity.HIGH),
+            ("HIGH", Severity.HIGH),
+            (" Critical ", Severity.HIGH),
+            ("Warning", Severity.MEDIUM),
+            ("low", Severity.LOW),
+            ("Info", Severity.INFO),
+        ],
+    )
+    def test_parse_known(self, raw, expected):
+        """Test case-insensitive parsing and aliases."""
+        assert Severity.parse(raw) is expected
+
+    @pytest.mark.parametrize("raw", [None, "", "blocker-ish"])
+    def test_parse_unknown(self, raw):
+        """Test unknown values are not guessed."""
+        assert Severity.parse(raw) is None
+
+    def test_ordering(self):
+        """Test severities sort by rank, not alphabetically."""
+        # Act
+        ordered = sorted([Severity.LOW, Severity.HIGH, Severity.INFO, Severity.MEDIUM])
+
+        # Assert
+        assert ordered == [Severity.INFO, Severity.LOW, Severity.MEDIUM, Severity.HIGH]
+        assert Severity.HIGH > Severity.LOW and Severity.HIGH >= Severity.MEDIUM
+        assert Severity.MEDIUM <= Severity.MEDIUM and not Severity.INFO >= Severity.LOW
+        assert max([Severity.LOW, Severity.HIGH, Severity.MEDIUM]) == Severity.HIGH
+        assert min([Severity.MEDIUM, Severity.INFO, Severity.HIGH]) == Severity.INFO
+        assert severity_rank("Critical") > severity_rank("medium") > severity_rank("whatever")
+
+    def test_count_by_severity(self):
+        """Test counting tolerates capitalisation and unknown values."""
+        # Act
+        counts = count_by_severity(["high", "Critical", "info", "bogus", None])
+
+        # Assert
+        assert counts == {"high": 2, "medium": 0, "low": 0, "info": 1, "other": 2}
+
+    def test_count_without_unknowns_has_no_other_bucket(self):
+        """Test the other bucket only appears when needed."""
+        assert "other" not in count_by_severity(["low"])
+
+    def test_issue_keeps_raw_string(self):
+        """Test the raw value round-trips while level is parsed."""
+        # Arrange
+        issue = Issue(url="https://example.com/", type="x", severity="Critical")
+
+        # Assert
+        assert issue.level is Severity.HIGH
+        assert issue.model_dump()["severity"] == "Critical"
+
+
+@pytest.mark.unit
+class TestCategory:
+    """Test category parsing."""
+
+    @pytest.mark.parametrize(
+        "raw,expected",
+        [
+            ("links", Category.LINKS),
+            ("Meta Tags", Category.CONTENT),
+            ("technical-seo", Category.TECHNICAL),
+            ("Accessibility", Category.ACCESSIBILITY),
+            ("Mobile Friendliness", Category.MOBILE),
+            ("something new", Category.OTHER),
+            (None, Category.OTHER),
+        ],
+    )
+    def test_parse(self, raw, expected):
+        """Test case-insensitive category parsing with fallback."""
+        assert Category.parse(raw) is expected
@@ -0,0 +1,208 @@
+"""
+Unit tests for sitemap discovery, parsing, and coverage checks.
+"""
+import gzip
+
+import httpx
+import pytest
+
+from tinyseoai.audit.checks.sitemap import (
+    SitemapEntry,
+    SitemapOptions,
+    SitemapStream,
+    analyze,
+    entry_problems,
+    parse_sitemap,
+)
+
+NS = 'xmlns="http://www.sitemaps.org/schemas/sitemap/0.9"'
+
+
+def _urlset(*entries: str) -> str:
+    return f'<?xml version="1.0" encoding="UTF-8"?>\n<urlset {NS}>{"".join(entries)}</urlset>'
+
+
+def _url(loc: str, extra: str = "") -> str:
+    return f"<url><loc>{loc}</loc>{extra}</url>"
+
+
+def _index(*locs: str) -> str:
+    return f'<sitemapindex {NS}>{"".join(f"<sitemap><loc>{loc}</loc></sitemap>" for loc in locs)}</sitemapindex>'
+
+
+class _Site:
+    """Serves a fixed map of path -> body, status code, or redirect target."""
+
+    def __init__(self, routes: dict):
+        self.routes = routes
+        self.requests: list[httpx.Request] = []
+
+    def __call__(self, request: httpx.Request) -> httpx.Response:
+        self.requests.append(request)
+        route = self.routes.get(request.url.path, 404)
+        if isinstance(route, int):
+            return httpx.Response(route)
+        if isinstance(route, bytes):
+            return httpx.Response(200, content=route)
+        if route.startswith("->"):
+            return httpx.Response(301, headers={"location": route[2:]})
+        return httpx.Response(200, text=route)
+
+    def client(self) -> httpx.AsyncClient:
+        return httpx.AsyncClient(transport=httpx.MockTransport(self))
+
+
+def _types(report) -> list[str]:
+    return sorted(i.type for i in report.issues)
+
+
+@pytest.mark.unit
+class TestParseSitemap:
+    """Test parsing sitemap bodies."""
+
+    def test_urlset(self):
+        """Test entries and optional fields are read."""
+        # Act
+        stream = parse_sitemap(_urlset(_url("https://example.com/a", "<lastmod>2024-05-01</lastmod>")).encode())
+
+        # Assert
+        assert stream.kind == "urlset"
+        assert stream.entries == [SitemapEntry(loc="https://example.com/a", lastmod="2024-05-01")]
+
+    def test_gzip_and_text(self):
+        """Test gzipped XML and plain-text sitemaps are both understood."""
+        # Act
+        gz = parse_sitemap(gzip.compress(_index("https://example.com/s1.xml").encode()))
+        text = parse_sitemap(b"https://example.com/a\r\n\nhttps://example.com/b")
+
+        # Assert
+        assert gz.kind == "index"
+        assert gz.nested == ["https://example.com/s1.xml"]
+        assert text.kind == "text"
+        assert [e.loc for e in text.entries] == ["https://example.com/a", "https://example.com/b"]
+
+    def test_malformed_keeps_partial_results(self):
+        """Test entries before a parse error are kept."""
+        # Arrange
+        body = _urlset(_url("https://example.com/a"), _url("https://example.com/b"))
+        body = body.replace("</urlset>", "<url><loc>https://example.com/c</lo></url>")
+
+        # Act
+        stream = parse_sitemap(body.encode())
+
+        # Assert
+        assert stream.error.startswith("XML:")
+        assert [e.loc for e in stream.entries] == ["https://example.com/a", "https://example.com/b"]
+
+    def test_large_sitemap_streamed(self):
+        """Test a 50,000-URL sitemap parses in chunks without keeping the tree."""
+        # Arrange
+        body = _urlset(*(_url(f"https://example.com/p{n}") for n in range(50_000))).encode()
+        stream = SitemapStream()
+
+        # Act
+        for start in range(0, len(body), 8192):
+            stream.feed(body[start : start + 8192])
+        stream.close()
+
+        # Assert
+        assert len(stream.entries) == 50_000
+        assert stream.entries[-1].loc == "https://example.com/p49999"
+        assert len(stream._root) == 0  # finished <url> elements were dropped
+
+    @pytest.mark.parametrize(
+        ("entry", "problems"),
+        [
+            (SitemapEntry(loc="x", lastmod="2024-05-01T10:00:00+02:00", changefreq="Weekly", priority="0.5"), []),
+            (SitemapEntry(loc="x", lastmod="01/05/2024"), ["sitemap_invalid_lastmod"]),
+            (SitemapEntry(loc="x", changefreq="fortnightly"), ["sitemap_invalid_changefreq"]),
+            (SitemapEntry(loc="x", priority="1.5"), ["sitemap_invalid_priority"]),
+            (SitemapEntry(loc="x", priority="high"), ["sitemap_invalid_priority"]),
+        ],
+    )
+    def test_entry_problems(self, entry, problems):
+        """Test lastmod, changefreq, and priority validation."""
+        assert list(entry_problems(entry)) == problems
+
+
+@pytest.mark.unit
+class TestAnalyze:
+    """Test discovery and coverage against a mock site."""
+
+    @pytest.mark.asyncio
+    async def test_nested_index_from_robots(self):
+        """Test robots.txt sitemaps and nested indexes are followed."""
+        # Arrange
+        site = _Site(
+            {
+                "/robots.txt": "User-agent: *\nAllow: /\nSitemap: https://example.com/index.xml\n",
+                "/index.xml": _index("https://example.com/nested.xml", "https://example.com/pages.xml.gz"),
+                "/nested.xml": _index("https://example.com/posts.txt"),
+                "/pages.xml.gz": gzip.compress(_urlset(_url("https://example.com/")).encode()),
+                "/posts.txt": "https://example.com/post\n",
+                "/post": 200,
+            }
+        )
+
+        # Act
+        report = await analyze("https://example.com", site.client(), ["https://example.com/"])
+
+        # Assert
+        assert report.urls == ["https://example.com/", "https://example.com/post"]
+        assert [s.kind for s in report.sitemaps] == ["index", "index", "urlset", "text"]
+        assert report.issues == []
+
+    @pytest.mark.asyncio
+    async def test_coverage(self):
+        """Test listed URLs that 404 or redirect and crawled pages not listed."""
+        # Arrange
+        site = _Site(
+            {
+                "/sitemap.xml": _urlset(*(_url(f"https://example.com/{p}") for p in ("", "gone", "old", "ok"))),
+                "/old": "->/new",
+                "/new": 200,
+                "/ok": 200,
+            }
+        )
+        crawled = ["https://example.com", "https://example.com/about"]
+
+        # Act
+        report = await analyze("https://example.com/", site.client(), crawled)
+
+        # Assert
+        assert _types(report) == ["page_missing_from_sitemap", "sitemap_orphaned_url", "sitemap_redirected_url"]
+        details = {i.type: i.detail for i in report.issues}
+        assert details["sitemap_orphaned_url"] == "1 sitemap URLs do not load: https://example.com/gone (404)"
+        assert details["sitemap_redirected_url"] == "1 sitemap URLs redirect: https://example.com/old -> https://example.com/new"
+        assert details["page_missing_from_sitemap"].endswith("https://example.com/about")
+        assert all(r.url.path != "/" for r in site.requests if r.method == "HEAD")  # crawled pages are not re-fetched
+
+    @pytest.mark.asyncio
+    async def test_findings_capped(self):
+        """Test long URL lists end with "and N more"."""
+        # Arrange
+        body = _urlset(*(_url(f"https://example.com/p{n}", "<priority>2</priority>") for n in range(8)))
+        site = _Site({"/sitemap.xml": body})
+
+        # Act
+        report = await analyze("https://example.com/", site.client(), [], SitemapOptions(max_probes=0, max_listed=3))
+
+        # Assert
+        detail = next(i.detail for i in report.issues if i.type == "sitemap_invalid_priority")
+        assert detail.startswith("8 entries where priority is not between 0.0 and 1.0: https://example.com/p0 (2)")
+        assert detail.endswith("and 5 more")
+
+    @pytest.mark.asyncio
+    async def test_malformed_and_missing(self):
+        """Test a broken file still yields entries, and no sitemap at all is reported."""
+        # Arrange
+        broken = _Site({"/sitemap.xml": _urlset(_url("https://example.com/a")).replace("</urlset>", "<url>")})
+
+        # Act
+        partial = await analyze("https://example.com/", broken.client(), ["https://example.com/a"])
+        missing = await analyze("https://example.com/", _Site({}).client(), [])
+
+        # Assert
+        assert partial.urls == ["https://example.com/a"]
+        assert _types(partial) == ["sitemap_malformed"]
+        assert _types(missing) == ["sitemap_missing"]
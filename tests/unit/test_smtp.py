@@ -0,0 +1,305 @@
+"""
+Unit tests for emailing reports, against a local SMTP test server.
+"""
+import base64
+import json
+import socketserver
+import threading
+from email import message_from_bytes, policy
+
+import pytest
+
+pytest.importorskip("jinja2")
+
+from tinyseoai.config import AppConfig, SmtpConfig  # noqa: E402
+from tinyseoai.data.demo import build_demo_result  # noqa: E402
+from tinyseoai.exceptions import IntegrationError, StoreError  # noqa: E402
+from tinyseoai.integrations import smtp  # noqa: E402
+from tinyseoai.integrations.smtp import EmailOptions, build_email, send_report_email  # noqa: E402
+from tinyseoai.reporting import html_report  # noqa: E402
+from tinyseoai.store.history import HistoryStore  # noqa: E402
+
+USER, PASSWORD = "seo@acme.example", "s3cret"
+
+
+class _Handler(socketserver.StreamRequestHandler):
+    """Just enough SMTP for smtplib: EHLO, AUTH PLAIN, MAIL, RCPT, DATA, NOOP, QUIT."""
+
+    def reply(self, line: str) -> None:
+        self.wfile.write(f"{line}\r\n".encode())
+
+    def handle(self) -> None:
+        server = self.server
+        self.reply("220 test ESMTP")
+        while line := self.rfile.readline():
+            command, _, arg = line.decode().strip().partition(" ")
+            command = command.upper()
+            if command == "EHLO":
+                extensions = ["STARTTLS"] if server.require_tls else ["AUTH PLAIN"]
+                self.reply("250-test")
+                for extension in extensions:
+                    self.reply(f"250-{extension}")
+                self.reply("250 SIZE 10000000")
+            elif command == "AUTH":
+                _, _, initial = arg.partition(" ")
+                _, user, password = base64.b64decode(initial).decode().split("\0")
+                if (user, password) == (USER, PASSWORD):
+                    self.reply("235 2.7.0 Authentication successful")
+                else:
+                    self.reply("535 5.7.8 Authentication credentials invalid")
+            elif command == "MAIL":
+                if server.require_tls:
+                    self.reply("530 5.7.0 Must issue a STARTTLS command first")
+                else:
+                    self.reply("250 OK")
+            elif command == "RCPT":
+                address = arg.split(":", 1)[1].strip("<> ")
+                self.reply("550 5.1.1 No such user" if address.startswith("nobody@") else "250 OK")
+            elif command == "DATA":
+                self.reply("354 End data with <CR><LF>.<CR><LF>")
+                lines = []
+                while (data := self.rfile.readline()) not in (b".\r\n", b""):
+                    lines.append(data[1:] if data.startswith(b"..") else data)
+                server.messages.append(message_from_bytes(b"".join(lines), policy=policy.default))
+                self.reply("250 OK queued")
+            elif command in ("NOOP", "RSET"):
+                self.reply("250 OK")
+            elif command == "QUIT":
+                self.reply("221 Bye")
+                return
+            else:
+                self.reply("502 Command not implemented")
+
+
+class _Server(socketserver.ThreadingTCPServer):
+    daemon_threads = True
+    allow_reuse_address = True
+
+    def __init__(self, require_tls: bool = False):
+        super().__init__(("127.0.0.1", 0), _Handler)
+        self.require_tls = require_tls
+        self.messages = []
+
+
+@pytest.fixture
+def smtp_server():
+    """Start local SMTP test servers; each call returns a new one."""
+    servers = []
+
+    def start(require_tls: bool = False) -> _Server:
+        server = _Server(require_tls)
+        threading.Thread(target=server.serve_forever, daemon=True).start()
+        servers.append(server)
+        return server
+
+    yield start
+    for server in servers:
+        server.shutdown()
+        server.server_close()
+
+
+def _config(server: _Server, **overrides) -> SmtpConfig:
+    settings = {
+        "host": "127.0.0.1",
+        "port": server.server_address[1],
+        "tls": "none",
+        "username": USER,
+        "from_address": "seo@acme.example",
+        "timeout": 5,
+    }
+    return SmtpConfig(**{**settings, **overrides})
+
+
+@pytest.fixture
+def store(tmp_path, monkeypatch):
+    """Provide a history holding the demo audit."""
+    monkeypatch.setattr(html_report, "get_config", lambda: AppConfig())
+    with HistoryStore(tmp_path / "history.sqlite3") as s:
+        yield s
+
+
+@pytest.mark.unit
+class TestBuildEmail:
+    """Test the message content."""
+
+    def test_html_with_text_alternative(self, monkeypatch):
+        """Test the HTML report is the body, with the text report as the alternative."""
+        # Arrange
+        monkeypatch.setattr(html_report, "get_config", lambda: AppConfig())
+        summary = json.loads(build_demo_result().to_json())
+
+        # Act
+        message = build_email(summary, "seo@acme.example", EmailOptions(recipients=["a@client.example"], message="Hi Ana,\nhere is March."))
+
+        # Assert
+        assert message["Subject"] == "SEO audit for demo.tinyseoai.com: 72/100"
+        assert message.get_content_type() == "multipart/alternative"
+        text = message.get_body(("plain",)).get_content()
+        html = message.get_body(("html",)).get_content()
+        assert text.startswith("Hi Ana,\nhere is March.\n\nSEO Audit — https://demo.tinyseoai.com/")
+        assert '<p class="intro">Hi Ana,\nhere is March.</p>' in html
+        assert "Missing canonical" in html
+
+    def test_pdf_attached(self, tmp_path, monkeypatch):
+        """Test the pdf format attaches the report to a short note."""
+        # Arrange
+        rendered = tmp_path / "r.pdf"
+        monkeypatch.setattr(smtp, "write_pdf", lambda summary, out: rendered.write_bytes(b"%PDF-1.7 demo") and rendered)
+        summary = json.loads(build_demo_result().to_json())
+
+        # Act
+        message = build_email(summary, "seo@acme.example", EmailOptions(recipients=["a@client.example"], format="pdf"))
+
+        # Assert
+        [attachment] = list(message.iter_attachments())
+        assert attachment.get_filename() == "demo.tinyseoai.com-report.pdf"
+        assert attachment.get_content() == b"%PDF-1.7 demo"
+        assert "The full report is attached." in message.get_body(("plain",)).get_content()
+
+    def test_existing_pdf_attached(self, tmp_path, monkeypatch):
+        """Test an existing PDF is attached as-is instead of rendering one."""
+        # Arrange
+        existing = tmp_path / "march.pdf"
+        existing.write_bytes(b"%PDF-1.7 existing")
+        monkeypatch.setattr(smtp, "write_pdf", lambda *a: pytest.fail("rendered a PDF"))
+        summary = json.loads(build_demo_result().to_json())
+        options = EmailOptions(recipients=["a@client.example"], format="pdf", pdf_path=existing)
+
+        # Act
+        message = build_email(summary, "seo@acme.example", options)
+
+        # Assert
+        assert next(message.iter_attachments()).get_content() == b"%PDF-1.7 existing"
+
+
+@pytest.mark.unit
+class TestSmtpSettings:
+    """Test checking the settings without sending."""
+
+    def test_ok(self, smtp_server):
+        """Test valid settings log in and send nothing."""
+        # Arrange
+        server = smtp_server()
+
+        # Act
+        smtp.test_smtp_settings(_config(server), PASSWORD)
+
+        # Assert
+        assert server.messages == []
+
+    def test_wrong_password(self, smtp_server):
+        """Test a refused login names the credentials."""
+        with pytest.raises(IntegrationError, match="Authentication failed for seo@acme.example"):
+            smtp.test_smtp_settings(_config(smtp_server()), "wrong")
+
+    def test_missing_password(self, smtp_server):
+        """Test a username without a password points to the keyring."""
+        with pytest.raises(IntegrationError, match="keyring set tinyseoai smtp"):
+            smtp.test_smtp_settings(_config(smtp_server()), "")
+
+    def test_starttls_required(self, smtp_server):
+        """Test a server that only logs in over TLS asks for starttls."""
+        with pytest.raises(IntegrationError, match="STARTTLS required"):
+            smtp.test_smtp_settings(_config(smtp_server(require_tls=True)), PASSWORD)
+
+    def test_starttls_not_offered(self, smtp_server):
+        """Test starttls against a server without it suggests the other modes."""
+        with pytest.raises(IntegrationError, match="does not offer STARTTLS"):
+            smtp.test_smtp_settings(_config(smtp_server(), tls="starttls"), PASSWORD)
+
+    def test_connection_refused(self, smtp_server):
+        """Test an unreachable server is reported with its address."""
+        # Arrange
+        server = smtp_server()
+        config = _config(server)
+        server.shutdown()
+        server.server_close()
+
+        # Act / Assert
+        with pytest.raises(IntegrationError, match=f"Cannot connect to 127.0.0.1:{config.port}"):
+            smtp.test_smtp_settings(config, PASSWORD)
+
+
+@pytest.mark.unit
+class TestSendReport:
+    """Test sending a stored audit and recording the delivery."""
+
+    def test_delivered_and_recorded(self, smtp_server, store):
+        """Test the message reaches every recipient and the delivery is logged."""
+        # Arrange
+        server = smtp_server()
+        audit_id = store.insert(build_demo_result())
+        options = EmailOptions(recipients=["a@client.example", "b@client.example"])
+
+        # Act
+        delivery = send_report_email(audit_id, options, _config(server), PASSWORD, store)
+
+        # Assert
+        [received] = server.messages
+        assert received["To"] == "a@client.example, b@client.example"
+        assert received["From"] == "seo@acme.example"
+        assert delivery.ok
+        assert store.deliveries(audit_id) == [delivery]
+
+    def test_failure_recorded(self, smtp_server, store):
+        """Test a refused recipient fails the send and is logged with the error."""
+        # Arrange
+        server = smtp_server()
+        audit_id = store.insert(build_demo_result())
+        options = EmailOptions(recipients=["nobody@client.example"])
+
+        # Act
+        with pytest.raises(IntegrationError, match="refused the recipients: nobody@client.example"):
+            send_report_email(audit_id, options, _config(server), PASSWORD, store)
+
+        # Assert
+        [delivery] = store.deliveries(audit_id)
+        assert not delivery.ok
+        assert "nobody@client.example" in delivery.error
+        assert server.messages == []
+
+    def test_starttls_required_on_send(self, smtp_server, store):
+        """Test a server refusing mail before STARTTLS without a login is explained."""
+        # Arrange
+        server = smtp_server(require_tls=True)
+        audit_id = store.insert(build_demo_result())
+
+        # Act / Assert
+        with pytest.raises(IntegrationError, match="STARTTLS required"):
+            send_report_email(
+                audit_id, EmailOptions(recipients=["a@client.example"]), _config(server, username=None), "", store
+            )
+
+    def test_pdf_renderer_missing(self, smtp_server, store, monkeypatch):
+        """Test a PDF that cannot be rendered is recorded as a failed delivery."""
+        # Arrange
+        def no_renderer(summary, out):
+            raise RuntimeError("PDF generation failed")
+
+        monkeypatch.setattr(smtp, "write_pdf", no_renderer)
+        audit_id = store.insert(build_demo_result())
+        options = EmailOptions(recipients=["a@client.example"], format="pdf")
+
+        # Act
+        with pytest.raises(IntegrationError, match="Cannot build the report"):
+            send_report_email(audit_id, options, _config(smtp_server()), PASSWORD, store)
+
+        # Assert
+        assert not store.deliveries(audit_id)[0].ok
+
+    def test_unknown_audit(self, smtp_server, store):
+        """Test a missing audit raises StoreError and records nothing."""
+        with pytest.raises(StoreError):
+            send_report_email(99, EmailOptions(recipients=["a@client.example"]), _config(smtp_server()), PASSWORD, store)
+
+    def test_deleted_with_audit(self, store):
+        """Test deleting an audit removes its deliveries."""
+        # Arrange
+        audit_id = store.insert(build_demo_result())
+        store.record_delivery(audit_id, "email", ["a@client.example"])
+
+        # Act
+        store.delete(audit_id)
+
+        # Assert
+        assert store.deliveries(audit_id) == []
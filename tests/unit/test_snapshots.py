@@ -0,0 +1,166 @@
+"""
+Unit tests for the page snapshot archive.
+"""
+import json
+from types import SimpleNamespace
+
+import pytest
+
+from tinyseoai.data.models import AuditResult, Issue
+from tinyseoai.store.snapshots import (
+    SnapshotArchive,
+    SnapshotOptions,
+    content_hash,
+    missing_snapshots,
+)
+
+SITE = "https://example.com/"
+
+
+def _page(path: str, html: str) -> SimpleNamespace:
+    return SimpleNamespace(
+        url=f"{SITE}{path}", status=200, headers={"content-type": "text/html"}, html=html
+    )
+
+
+def _audit(*paths: str, site: str = SITE) -> AuditResult:
+    issues = [
+        Issue(url=f"{site}{path}", type=issue_type, severity="medium")
+        for path in paths
+        for issue_type in ("meta_description_missing", "h1_missing")
+    ]
+    return AuditResult(site=site, pages_scanned=len(paths), issues=issues, meta={})
+
+
+@pytest.mark.unit
+class TestSave:
+    """Test storing pages and linking issues to them."""
+
+    def test_hash_addressing(self, tmp_path):
+        """Test bodies are stored under their SHA-256, once however many issues point at them."""
+        # Arrange
+        html = "<html><head><title>Home</title></head></html>"
+        archive = SnapshotArchive(SnapshotOptions(directory=tmp_path))
+        audit = _audit("a", "b")
+
+        # Act
+        manifest = archive.save(audit, [_page("a", html), _page("b", html)])
+
+        # Assert
+        digest = content_hash(html)
+        assert archive.object_path(digest) == tmp_path / "objects" / digest[:2] / f"{digest}.html"
+        assert archive.object_path(digest).read_text() == html
+        headers = json.loads(archive.headers_path(digest).read_text())
+        assert headers["headers"]["content-type"] == "text/html"
+        assert list(manifest.objects) == [digest]
+        assert {i.snapshot for i in audit.issues} == {digest}
+        assert audit.meta["snapshots"]["pages"] == 2
+
+    def test_pages_without_html_not_linked(self, tmp_path):
+        """Test issues on pages that were not fetched as HTML get no snapshot."""
+        # Arrange
+        audit = _audit("a", "b")
+
+        # Act
+        SnapshotArchive(SnapshotOptions(directory=tmp_path)).save(audit, [_page("a", "<p>a</p>")])
+
+        # Assert
+        assert [bool(i.snapshot) for i in audit.issues] == [True, True, False, False]
+        assert "snapshot" not in audit.issues[2].model_dump()
+
+    def test_size_cap_within_audit(self, tmp_path):
+        """Test pages that would take one audit past the cap are skipped."""
+        # Arrange
+        archive = SnapshotArchive(SnapshotOptions(directory=tmp_path, max_bytes=1500))
+        audit = _audit("a", "b")
+
+        # Act
+        archive.save(audit, [_page("a", "a" * 1000), _page("b", "b" * 1000)])
+
+        # Assert
+        assert audit.issues[0].snapshot is not None
+        assert audit.issues[2].snapshot is None
+        assert audit.meta["snapshots"]["skipped_pages"] == 1
+
+
+@pytest.mark.unit
+class TestPrune:
+    """Test retention and size-cap eviction."""
+
+    def test_size_cap_evicts_oldest_audits(self, tmp_path):
+        """Test saving past the cap evicts the oldest audits and their objects."""
+        # Arrange
+        archive = SnapshotArchive(SnapshotOptions(directory=tmp_path, max_bytes=5000))
+        audits = [_audit("a") for _ in range(3)]
+
+        # Act
+        for n, audit in enumerate(audits):
+            archive.save(audit, [_page("a", str(n) * 2000)])
+
+        # Assert
+        kept = [m.audit_id for m in archive.manifests()]
+        assert kept == [a.meta["snapshots"]["audit_id"] for a in audits[1:]]
+        assert archive.path_of(audits[0].issues[0].snapshot) is None
+        assert archive.path_of(audits[2].issues[0].snapshot) is not None
+
+    def test_retention_per_site(self, tmp_path):
+        """Test only the newest audits of each site are kept."""
+        # Arrange
+        archive = SnapshotArchive(SnapshotOptions(directory=tmp_path, keep_audits=2))
+        other = "https://other.example/"
+
+        # Act
+        for n in range(3):
+            archive.save(_audit("a"), [_page("a", f"<p>{n}</p>")])
+        page = _page("a", "<p>x</p>")
+        page.url = f"{other}a"
+        archive.save(_audit("a", site=other), [page])
+
+        # Assert
+        hosts = [m.host for m in archive.manifests()]
+        assert hosts.count("example.com") == 2
+        assert hosts.count("other.example") == 1
+        assert archive.path_of(content_hash("<p>0</p>")) is None
+
+    def test_shared_object_survives(self, tmp_path):
+        """Test an object still listed by a kept audit is not deleted with an evicted one."""
+        # Arrange
+        archive = SnapshotArchive(SnapshotOptions(directory=tmp_path, keep_audits=1))
+
+        # Act
+        archive.save(_audit("a"), [_page("a", "<p>same</p>")])
+        archive.save(_audit("a"), [_page("a", "<p>same</p>")])
+
+        # Assert
+        assert len(archive.manifests()) == 1
+        assert archive.path_of(content_hash("<p>same</p>")) is not None
+
+
+@pytest.mark.unit
+class TestMissing:
+    """Test loading results whose snapshots are gone."""
+
+    def test_missing_files_detected(self, tmp_path):
+        """Test issues whose snapshot file was deleted are reported, not raised."""
+        # Arrange
+        archive = SnapshotArchive(SnapshotOptions(directory=tmp_path))
+        audit = _audit("a", "b")
+        archive.save(audit, [_page("a", "<p>a</p>"), _page("b", "<p>b</p>")])
+        archive.object_path(content_hash("<p>b</p>")).unlink()
+        loaded = AuditResult.model_validate_json(audit.model_dump_json())
+
+        # Act
+        missing = missing_snapshots(loaded)
+
+        # Assert
+        assert [i.url for i in missing] == [f"{SITE}b", f"{SITE}b"]
+
+    def test_archive_gone(self, tmp_path):
+        """Test a result whose archive folder no longer exists reports every snapshot missing."""
+        # Arrange
+        audit = _audit("a")
+        audit.issues[0].snapshot = "ab" * 32
+        audit.meta["snapshots"] = {"directory": str(tmp_path / "gone")}
+
+        # Act & Assert
+        assert missing_snapshots(audit) == [audit.issues[0]]
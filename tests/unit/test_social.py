@@ -0,0 +1,162 @@
+"""
+Unit tests for Open Graph and Twitter Card checks.
+"""
+import struct
+
+import httpx
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.social import (  # noqa: E402
+    ImageProbe,
+    SocialOptions,
+    analyze,
+    analyze_with_images,
+    image_size,
+)
+from tinyseoai.data.models import Issue  # noqa: E402
+from tinyseoai.data.query import issue_category  # noqa: E402
+from tinyseoai.data.severity import Category  # noqa: E402
+
+URL = "https://example.com/mugs"
+
+
+def _png(width: int, height: int) -> bytes:
+    return b"\x89PNG\r\n\x1a\n" + struct.pack(">I4sII", 13, b"IHDR", width, height) + b"\x00" * 64
+
+
+def _types(issues) -> list[str]:
+    return sorted(i.type for i in issues)
+
+
+def _detail(issues, issue_type: str) -> str:
+    return next(i.detail for i in issues if i.type == issue_type)
+
+
+@pytest.mark.unit
+class TestAnalyze:
+    """Test tag checks on fixture pages."""
+
+    def test_complete_page(self, html_fixture):
+        """Test a page with every tag set has no findings."""
+        assert analyze(html_fixture("social_complete.html"), URL) == []
+
+    def test_missing_tags_suggest_fixes(self, html_fixture):
+        """Test missing tags are reported with tags derived from the page."""
+        # Act
+        issues = analyze(html_fixture("social_missing.html"), URL)
+
+        # Assert
+        assert _types(issues) == [
+            "og_description_missing",
+            "og_image_missing",
+            "og_title_missing",
+            "og_url_missing",
+            "twitter_card_missing",
+        ]
+        assert _detail(issues, "og_title_missing") == (
+            "No og:title tag. Suggested tag: "
+            '<meta property="og:title" content="Handmade Ceramic Mugs | Example Pottery Studio">'
+        )
+        assert _detail(issues, "og_image_missing") == "No og:image tag"
+        assert _detail(issues, "twitter_card_missing").endswith('<meta name="twitter:card" content="summary">')
+
+    def test_duplicated_tags(self, html_fixture):
+        """Test duplicated tags, a mismatched og:url, and an unknown card type."""
+        # Act
+        issues = analyze(html_fixture("social_duplicated.html"), URL)
+
+        # Assert
+        assert _types(issues) == [
+            "og_tag_duplicated",
+            "og_url_mismatch",
+            "twitter_card_invalid",
+            "twitter_tag_duplicated",
+        ]
+        assert _detail(issues, "og_tag_duplicated") == (
+            'og:title appears 2 times ("Handmade Ceramic Mugs", "Mugs & Cups"); keep one'
+        )
+        assert _detail(issues, "og_url_mismatch").endswith('<meta property="og:url" content="https://example.com/mugs">')
+
+    def test_relative_urls(self, html_fixture):
+        """Test relative og:url and image URLs get absolute replacements."""
+        # Act
+        issues = analyze(html_fixture("social_relative.html"), URL)
+
+        # Assert
+        assert _types(issues) == ["og_image_not_absolute", "og_url_not_absolute", "twitter_image_not_absolute"]
+        assert _detail(issues, "og_image_not_absolute") == (
+            'og:image is relative (/img/mugs.png). Suggested tag: '
+            '<meta property="og:image" content="https://example.com/img/mugs.png">'
+        )
+        assert _detail(issues, "twitter_image_not_absolute").endswith('content="https://example.com/img/mugs-card.png">')
+
+    def test_social_category(self):
+        """Test social findings are scored under the Social category."""
+        # Arrange
+        issue = Issue(url=URL, type="og_url_mismatch", severity="medium")
+
+        # Act / Assert
+        assert issue_category(issue) is Category.SOCIAL
+
+
+@pytest.mark.unit
+class TestImages:
+    """Test preview image probing."""
+
+    @pytest.mark.parametrize(
+        ("probe", "card", "expected"),
+        [
+            (ImageProbe(url="i", status_code=200, width=1200, height=630), "summary_large_image", []),
+            (ImageProbe(url="i", status_code=200, width=1200, height=1200), "summary_large_image", ["twitter_image_aspect_ratio"]),
+            (ImageProbe(url="i", status_code=200, width=120, height=120), "summary", ["og_image_too_small"]),
+            (ImageProbe(url="i", status_code=404), "summary", ["og_image_unreachable"]),
+            (ImageProbe(url="i", status_code=200, content_type="text/html"), "summary", ["og_image_not_image"]),
+        ],
+    )
+    def test_image_findings(self, probe, card, expected, html_fixture):
+        """Test dimension, aspect ratio, and reachability findings per card type."""
+        # Arrange
+        html = html_fixture("social_complete.html").replace("summary_large_image", card)
+
+        # Act
+        issues = analyze(html, URL, [probe])
+
+        # Assert
+        assert _types(issues) == expected
+
+    def test_image_size(self):
+        """Test dimensions are read from PNG, GIF, and JPEG headers."""
+        # Arrange
+        gif = b"GIF89a" + struct.pack("<HH", 640, 480)
+        jpeg = b"\xff\xd8" + b"\xff\xe0\x00\x04\x00\x00" + b"\xff\xc0\x00\x11\x08" + struct.pack(">HH", 630, 1200)
+
+        # Act / Assert
+        assert image_size(_png(1200, 630)) == (1200, 630)
+        assert image_size(gif) == (640, 480)
+        assert image_size(jpeg) == (1200, 630)
+        assert image_size(b"<html>") is None
+
+    @pytest.mark.asyncio
+    async def test_probing_is_bounded(self, html_fixture):
+        """Test only the first bytes of at most max_images images are fetched."""
+        # Arrange
+        requested = []
+
+        def server(request):
+            requested.append(request.url.path)
+            return httpx.Response(200, headers={"content-type": "image/png"}, content=_png(1200, 630) + b"\x00" * 500_000)
+
+        client = httpx.AsyncClient(transport=httpx.MockTransport(server))
+        html = html_fixture("social_complete.html").replace(
+            "</head>", '<meta name="twitter:image" content="https://example.com/img/card.png"></head>'
+        )
+
+        # Act
+        issues = await analyze_with_images(html, URL, client, SocialOptions(max_images=1))
+
+        # Assert
+        assert issues == []
+        assert requested == ["/img/mugs-1200x630.png"]
@@ -0,0 +1,172 @@
+"""
+Unit tests for soft 404 detection.
+"""
+import httpx
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.soft404 import (  # noqa: E402
+    Soft404Options,
+    analyze,
+    fingerprint,
+    not_found_phrase,
+)
+from tinyseoai.audit.crawler import FetchedPage  # noqa: E402
+
+ERROR_PAGE = """<html><head><title>Example Pottery Studio</title></head><body>
+<nav><a href="/">Home</a> <a href="/shop">Shop</a></nav>
+<main><h1>Oops!</h1><p>We looked everywhere on the shelves but could not find what you were after.
+Try the search box above or head back to the shop to browse our handmade mugs and bowls.</p></main>
+</body></html>"""
+
+ARTICLE = """<html><head><title>Glazing basics | Example Pottery Studio</title></head><body>
+<main><h1>Glazing basics</h1><p>Dip the bisqueware in the bucket for three seconds, let it dry,
+and wipe the foot clean before it goes back in the kiln. Celadon pools in the carved lines and
+turns a deeper green where it is thick, while tenmoku breaks brown over the rims.</p></main>
+</body></html>"""
+
+GONE = """<html><head><title>Seite nicht gefunden – Example Pottery Studio</title></head><body>
+<main><h1>Leider nichts hier</h1><p>Dieses Produkt führen wir nicht mehr.</p></main></body></html>"""
+
+
+def _site(unknown: tuple[int, dict]) -> httpx.AsyncClient:
+    """A site that answers unknown paths with the given status and headers."""
+    status, headers = unknown
+
+    def handler(request: httpx.Request) -> httpx.Response:
+        if request.url.path == "/":
+            return httpx.Response(200, headers={"content-type": "text/html"}, text=ARTICLE)
+        return httpx.Response(status, headers={"content-type": "text/html", **headers}, text=ERROR_PAGE)
+
+    return httpx.AsyncClient(transport=httpx.MockTransport(handler))
+
+
+def _pages() -> list[FetchedPage]:
+    return [
+        FetchedPage(url="https://example.com/", body=ARTICLE),
+        FetchedPage(url="https://example.com/glazing", body=ARTICLE.replace("Celadon", "Shino")),
+        FetchedPage(url="https://example.com/old-mug", body=ERROR_PAGE),
+        FetchedPage(url="https://example.com/blue-bowl", body=GONE),
+        FetchedPage(url="https://example.com/missing", status=404, body=ERROR_PAGE),
+    ]
+
+
+def _found(issues) -> list[tuple[str, str]]:
+    return sorted((i.url, i.type) for i in issues)
+
+
+@pytest.mark.unit
+class TestNotFoundPhrase:
+    """Test the not-found phrase table."""
+
+    @pytest.mark.parametrize(
+        "html,expected",
+        [
+            ("<title>Page Not Found | Shop</title>", "page not found"),
+            ("<h1>Sorry, this page doesn’t exist</h1>", "page doesn't exist"),
+            ("<title>Página no encontrada</title>", "página no encontrada"),
+            ("<h1>ページが見つかりません</h1>", "ページが見つかりません"),
+            ("<title>Mugs</title><h1>Handmade mugs</h1>", None),
+        ],
+    )
+    def test_phrases(self, html, expected):
+        """Test phrases are found in titles and headings across languages."""
+        assert not_found_phrase(html) == expected
+
+    def test_long_articles_are_not_searched(self):
+        """Test a long article mentioning "page not found" in its body is not matched."""
+        # Arrange
+        html = "<title>Fixing errors</title><p>" + "word " * 200 + "page not found</p>"
+
+        # Act & Assert
+        assert not_found_phrase(html) is None
+        assert not_found_phrase(html, max_words=500) == "page not found"
+
+
+@pytest.mark.unit
+class TestAnalyze:
+    """Test soft 404 detection against simulated sites."""
+
+    @pytest.mark.asyncio
+    async def test_error_page_returning_200(self):
+        """Test a site whose error page returns 200: the host and matching pages are flagged."""
+        # Arrange
+        async with _site((200, {})) as client:
+            # Act
+            issues = await analyze(_pages(), client)
+
+        # Assert
+        assert _found(issues) == [
+            ("https://example.com/", "soft_404_catch_all"),
+            ("https://example.com/blue-bowl", "soft_404"),
+            ("https://example.com/old-mug", "soft_404"),
+        ]
+        details = {i.url: i.detail for i in issues}
+        assert "-does-not-exist does not exist but returns 200;" in details["https://example.com/"]
+        assert details["https://example.com/old-mug"] == (
+            "Returns 200 but matches the site's error page (similarity 1.00); "
+            "return 404 or 410 if the page is gone"
+        )
+        assert 'says "seite nicht gefunden" (similarity' in details["https://example.com/blue-bowl"]
+        assert all(i.severity == "medium" for i in issues)
+
+    @pytest.mark.asyncio
+    async def test_proper_404_site(self):
+        """Test a host returning 404 is not reported, but 200 pages with its error text are."""
+        # Arrange
+        async with _site((404, {})) as client:
+            # Act
+            issues = await analyze(_pages(), client)
+
+        # Assert
+        assert _found(issues) == [
+            ("https://example.com/blue-bowl", "soft_404"),
+            ("https://example.com/old-mug", "soft_404"),
+        ]
+
+    @pytest.mark.asyncio
+    async def test_redirect_landing_page_is_not_compared(self):
+        """Test pages are not compared with the homepage that unknown URLs redirect to."""
+        # Arrange
+        async with _site((302, {"location": "https://example.com/"})) as client:
+            probe = await fingerprint("https://example.com", client)
+            issues = await analyze(_pages()[:2], client)
+
+        # Assert
+        assert probe.status_code == 200
+        assert probe.final_url == "https://example.com/"
+        assert _found(issues) == [("https://example.com/", "soft_404_catch_all")]
+        assert "(after redirecting to https://example.com/)" in issues[0].detail
+
+    @pytest.mark.asyncio
+    async def test_threshold(self):
+        """Test pages below the similarity threshold are not flagged by similarity."""
+        # Arrange
+        page = FetchedPage(url="https://example.com/old-mug", body=ERROR_PAGE)
+
+        # Act
+        async with _site((404, {})) as client:
+            strict = await analyze([page], client, Soft404Options(threshold=1.0))
+            page.body = ERROR_PAGE.replace("handmade mugs and bowls", "our spring catalogue")
+            changed = await analyze([page], client, Soft404Options(threshold=0.95))
+
+        # Assert
+        assert _found(strict) == [("https://example.com/old-mug", "soft_404")]
+        assert changed == []
+
+    @pytest.mark.asyncio
+    async def test_probe_failure(self):
+        """Test an unreachable probe only leaves the phrase check."""
+
+        # Arrange
+        def handler(request: httpx.Request) -> httpx.Response:
+            raise httpx.ConnectError("refused", request=request)
+
+        async with httpx.AsyncClient(transport=httpx.MockTransport(handler)) as client:
+            # Act
+            issues = await analyze(_pages(), client)
+
+        # Assert
+        assert _found(issues) == [("https://example.com/blue-bowl", "soft_404")]
@@ -0,0 +1,101 @@
+"""
+Unit tests for JSON-LD structured data validation.
+"""
+import pytest
+
+pytest.importorskip("bs4")
+pytest.importorskip("lxml")
+
+from tinyseoai.audit.checks.structured_data import (  # noqa: E402
+    STRUCTURED_DATA_RULES,
+    analyze,
+    extract_json_ld,
+    parse_block,
+    schema_types,
+)
+
+URL = "https://example.com/blog/glazing"
+
+
+def _details(issues) -> list[str]:
+    return sorted(i.detail for i in issues)
+
+
+@pytest.mark.unit
+class TestExtract:
+    """Test finding and parsing JSON-LD blocks."""
+
+    def test_graph_types(self, html_fixture):
+        """Test @graph members and nested nodes are found."""
+        # Act
+        types = schema_types(extract_json_ld(html_fixture("structured_graph.html")))
+
+        # Assert
+        assert types == ["Organization", "BlogPosting", "Person", "BreadcrumbList", "ListItem"]
+
+    @pytest.mark.parametrize(
+        ("text", "error"),
+        [
+            ('{\n  "a": 1,\n}', "trailing comma at line 2, column 9"),
+            ('{"a": [1, 2,]}', "trailing comma at line 1, column 12"),
+            ('{\n  // note\n  "a": 1\n}', "comment at line 2, column 3"),
+            ('{"a": 1 /* note */}', "comment at line 1, column 9"),
+            ('{"a": "http://x.com/,"}', None),
+            ('{"a" 1}', "Expecting ':' delimiter at line 1, column 6"),
+        ],
+    )
+    def test_parse_errors(self, text, error):
+        """Test trailing commas and comments are rejected with their position."""
+        assert parse_block(text)[1] == error
+
+
+@pytest.mark.unit
+class TestAnalyze:
+    """Test rule violations on fixture pages."""
+
+    def test_valid_graph(self, html_fixture):
+        """Test a complete @graph produces no findings."""
+        assert analyze(html_fixture("structured_graph.html"), URL) == []
+
+    def test_missing_properties(self, html_fixture):
+        """Test each missing or empty property becomes its own finding."""
+        # Act
+        issues = analyze(html_fixture("structured_missing.html"), URL)
+
+        # Assert
+        assert _details(issues) == [
+            "Answer (JSON-LD block 2) has an empty 'text'",
+            "Article (JSON-LD block 1) is missing required property 'datePublished'",
+            "Article (JSON-LD block 1) is missing required property 'image'",
+            "BreadcrumbList (JSON-LD block 2) has an empty 'itemListElement'",
+            "JSON-LD block 2 has a node without @type",
+            "Product (JSON-LD block 2) needs one of 'offers', 'review', 'aggregateRating'",
+        ]
+        assert {i.type for i in issues} == {
+            "structured_data_missing_property",
+            "structured_data_empty_property",
+            "structured_data_missing_type",
+        }
+
+    def test_invalid_blocks_do_not_hide_others(self, html_fixture):
+        """Test broken blocks are reported and the remaining blocks still checked."""
+        # Act
+        issues = analyze(html_fixture("structured_invalid.html"), URL)
+
+        # Assert
+        assert [(i.type, i.detail) for i in issues] == [
+            ("structured_data_invalid_json", "JSON-LD block 1: trailing comma at line 6, column 34"),
+            ("structured_data_invalid_json", "JSON-LD block 2: comment at line 3, column 5"),
+        ]
+
+    def test_rules_table_drives_checks(self, monkeypatch):
+        """Test a new type is validated from a table entry alone."""
+        # Arrange
+        monkeypatch.setitem(STRUCTURED_DATA_RULES, "Event", {"required": ["startDate"]})
+        html = '<script type="application/ld+json">{"@type": "https://schema.org/Event", "name": "Kiln open day"}</script>'
+
+        # Act
+        issues = analyze(html, URL)
+
+        # Assert
+        assert _details(issues) == ["Event (JSON-LD block 1) is missing required property 'startDate'"]
@@ -0,0 +1,154 @@
+"""
+Unit tests for AI summary prompt building and regeneration history.
+"""
+import json
+
+import pytest
+
+from tinyseoai.ai import summarizer
+from tinyseoai.ai.providers import estimate_tokens
+from tinyseoai.ai.summarizer import archive_previous_summary, build_prompt, summarize_with_ai
+from tinyseoai.data.models import AuditResult, Issue
+
+
+def _payload(prompt: str) -> dict:
+    return json.loads(prompt.split("INPUT:\n", 1)[1])
+
+
+def _large_result() -> AuditResult:
+    issues = [
+        Issue(url=f"https://shop.example/p/{n}?session=abc{n}", type=f"issue_{n % 40}", severity="low")
+        for n in range(400)
+    ]
+    return AuditResult(site="https://shop.example", pages_scanned=400, issues=issues, meta={"health_score": 71})
+
+
+@pytest.mark.unit
+class TestBuildPrompt:
+    """Test prompt construction."""
+
+    def test_default_prompt_has_no_language_hint(self, sample_audit_result):
+        """Test that the default prompt keeps the original goal."""
+        # Act
+        prompt = build_prompt(sample_audit_result)
+
+        # Assert
+        payload = json.loads(prompt.split("INPUT:\n", 1)[1])
+        assert payload["site"] == "https://example.com"
+        assert "Write every string value" not in payload["goal"]
+
+    def test_language_and_tone_in_goal(self, sample_audit_result):
+        """Test that language and tone are passed to the model."""
+        # Act
+        prompt = build_prompt(sample_audit_result, language="Spanish", tone="friendly")
+
+        # Assert
+        payload = json.loads(prompt.split("INPUT:\n", 1)[1])
+        assert "in Spanish" in payload["goal"]
+        assert "friendly tone" in payload["goal"]
+
+    def test_metrics(self, sample_audit_result):
+        """Test severity counts go with the issues, and the health score when known."""
+        # Act
+        payload = _payload(build_prompt(sample_audit_result))
+        scored = _payload(build_prompt(_large_result()))
+
+        # Assert
+        assert sum(payload["metrics_hint"]["by_severity"].values()) == len(sample_audit_result.issues)
+        assert "health_score" not in payload["metrics_hint"]
+        assert scored["metrics_hint"]["health_score"] == 71
+
+    def test_token_budget(self):
+        """Test samples, then the rarest issue types, are dropped until the prompt fits."""
+        # Arrange
+        result = _large_result()
+
+        # Act
+        full = build_prompt(result)
+        fewer_samples = build_prompt(result, token_budget=1200)
+        fewer_types = build_prompt(result, token_budget=300)
+
+        # Assert
+        assert estimate_tokens(full) > 1200 >= estimate_tokens(fewer_samples)
+        compact = _payload(fewer_samples)["issues_compact"]
+        assert len(compact["by_type_counts"]) == 40 and "types_omitted" not in compact
+        assert max(len(urls) for urls in compact["by_type_samples"].values()) < 5
+        assert estimate_tokens(fewer_types) <= 300
+        compact = _payload(fewer_types)["issues_compact"]
+        assert compact["total"] == 400
+        assert compact["types_omitted"] == 40 - len(compact["by_type_counts"]) > 0
+        assert not compact["by_type_samples"]
+
+    def test_redact_urls(self):
+        """Test no hostname or query string of the site reaches the prompt."""
+        # Act
+        prompt = build_prompt(_large_result(), redact_urls=True)
+
+        # Assert
+        assert "shop.example" not in prompt and "session=" not in prompt
+        payload = _payload(prompt)
+        assert payload["site"] == "https://host1.example"
+        assert payload["issues_compact"]["by_type_samples"]["issue_0"][0] == "https://host1.example/p/0"
+
+
+@pytest.mark.unit
+class TestArchivePreviousSummary:
+    """Test that regenerated summaries keep earlier versions."""
+
+    def test_no_previous_summary(self):
+        """Test first generation leaves no history."""
+        # Act
+        current = archive_previous_summary(None, {"summary": "new"})
+
+        # Assert
+        assert "history" not in current
+
+    def test_previous_summary_moved_to_history(self):
+        """Test the previous text is preserved oldest-first."""
+        # Arrange
+        previous = {
+            "summary": "second",
+            "language": "English",
+            "plan_used": "free",
+            "history": [{"summary": "first"}],
+        }
+
+        # Act
+        current = archive_previous_summary(previous, {"summary": "third"})
+
+        # Assert
+        assert current["summary"] == "third"
+        assert [h["summary"] for h in current["history"]] == ["first", "second"]
+        assert current["history"][1]["language"] == "English"
+        assert "history" not in current["history"][1]
+
+
+@pytest.mark.unit
+def test_summarize_records_usage_and_language(monkeypatch, sample_audit_result, mock_config):
+    """Test that token usage and options are attached to the summary."""
+    # Arrange
+    usage = {"model": "gpt-4o-mini", "prompt_tokens": 10, "completion_tokens": 5, "total_tokens": 15}
+    captured = {}
+
+    class FakeProvider:
+        def complete_json(self, prompt, **kwargs):
+            captured["prompt"] = prompt
+            return {"site": "https://example.com", "summary": "Resumen"}, usage
+
+    def fake_provider(settings, model=None, api_key=""):
+        captured["model"] = model
+        return FakeProvider()
+
+    monkeypatch.setattr(summarizer, "get_config", lambda: mock_config)
+    monkeypatch.setattr(summarizer, "get_provider", fake_provider)
+
+    # Act
+    data = summarize_with_ai(sample_audit_result, language="Spanish", tone="technical")
+
+    # Assert
+    assert data["usage"] == usage
+    assert data["language"] == "Spanish"
+    assert data["tone"] == "technical"
+    assert data["plan_used"] == "free"
+    assert "in Spanish" in captured["prompt"]
+    assert captured["model"] == "gpt-4o-mini"
@@ -0,0 +1,111 @@
+"""
+Unit tests for the CSV/TSV renderers.
+"""
+import csv
+import io
+
+import pytest
+
+from tinyseoai.data.demo import build_demo_result
+from tinyseoai.data.fingerprint import fingerprint
+from tinyseoai.data.models import AuditResult
+from tinyseoai.reporting.tabular import COLUMNS, CsvOptions, render_csv, write_csv_file
+
+
+def _parse(text: str, delimiter: str = ",") -> list[dict]:
+    return list(csv.DictReader(io.StringIO(text, newline=""), delimiter=delimiter))
+
+
+@pytest.mark.unit
+class TestCsvRows:
+    """Test row layout in grouped and exploded mode."""
+
+    def test_header_is_stable(self):
+        """Test the header row lists the columns in order."""
+        # Act
+        output = render_csv(build_demo_result())
+
+        # Assert
+        assert output.splitlines()[0] == ",".join(COLUMNS)
+
+    def test_exploded_one_row_per_issue_page(self):
+        """Test explode_pages emits one row per issue with its fingerprint."""
+        # Arrange
+        result = build_demo_result()
+
+        # Act
+        rows = _parse(render_csv(result, CsvOptions(explode_pages=True)))
+
+        # Assert
+        assert len(rows) == len(result.issues)
+        assert {r["fingerprint"] for r in rows} == {fingerprint(i) for i in result.issues}
+        assert rows[0]["severity"] == "high"
+
+    def test_grouped_joins_pages(self):
+        """Test grouped mode joins pages of the same issue type into one cell."""
+        # Arrange
+        result = AuditResult(
+            site="https://example.com/",
+            pages_scanned=2,
+            issues=[
+                {"url": "https://example.com/b", "type": "title_missing", "severity": "medium"},
+                {"url": "https://example.com/a", "type": "title_missing", "severity": "medium"},
+            ],
+            meta={},
+        )
+
+        # Act
+        rows = _parse(render_csv(result))
+
+        # Assert
+        assert len(rows) == 1
+        assert rows[0]["page_count"] == "2"
+        assert rows[0]["url"] == "https://example.com/a\nhttps://example.com/b"
+        assert len(rows[0]["fingerprint"].split("\n")) == 2
+
+
+@pytest.mark.unit
+class TestCsvQuoting:
+    """Test awkward cell content survives a round trip through a CSV parser."""
+
+    @pytest.mark.parametrize("delimiter", [",", "\t", ";"])
+    def test_round_trip(self, results_fixtures_dir, delimiter):
+        """Test delimiters, quotes, and newlines inside cells are quoted correctly."""
+        # Arrange
+        result = AuditResult.load(results_fixtures_dir / "pathological.json")
+        result.issues[0].detail = 'has "quotes", commas; tabs\tand\nnewlines'
+
+        # Act
+        rows = _parse(render_csv(result, CsvOptions(explode_pages=True, delimiter=delimiter)), delimiter)
+
+        # Assert
+        assert sorted(r["detail"] for r in rows) == sorted(i.detail for i in result.issues)
+        assert sorted(r["url"] for r in rows) == sorted(i.url for i in result.issues)
+
+
+@pytest.mark.unit
+class TestCsvOptions:
+    """Test option validation and file output."""
+
+    def test_delimiter_names(self):
+        """Test delimiter names map to characters."""
+        assert CsvOptions(delimiter="tab").delimiter == "\t"
+        assert CsvOptions(delimiter="Semicolon").delimiter == ";"
+
+    def test_unknown_delimiter_rejected(self):
+        """Test unsupported delimiters raise."""
+        with pytest.raises(ValueError):
+            CsvOptions(delimiter="|")
+
+    def test_bom(self, tmp_path):
+        """Test the BOM is written only when requested."""
+        # Arrange
+        result = build_demo_result()
+
+        # Act
+        with_bom = write_csv_file(result, tmp_path / "a.csv", CsvOptions(bom=True)).read_bytes()
+        without = write_csv_file(result, tmp_path / "b.csv").read_bytes()
+
+        # Assert
+        assert with_bom.startswith(b"\xef\xbb\xbf")
+        assert not without.startswith(b"\xef\xbb\xbf")
@@ -0,0 +1,107 @@
+"""
+Unit tests for the fixed-width text report.
+"""
+from pathlib import Path
+
+import pytest
+from rich.cells import cell_len
+
+from tinyseoai.data.demo import build_demo_result
+from tinyseoai.data.models import AuditResult
+from tinyseoai.reporting.text import TextOptions, render_ansi, strip_ansi, write_text
+
+GOLDEN = Path(__file__).parents[1] / "fixtures" / "golden"
+
+AI = {
+    "summary": "The site is in fair shape; the missing title on the pricing page costs the most.",
+    "top_issues": [{"type": "title_missing", "why_it_matters": "Pricing cannot rank without one"}],
+    "recommended_actions": [
+        {"action": "Add a title to /pricing", "impact": "high", "effort": "low"}
+    ],
+    "quick_wins": ["Send an HSTS header"],
+}
+
+
+@pytest.mark.unit
+class TestTextGolden:
+    """Golden-file tests; regenerate demo.txt and demo.ansi deliberately when output changes."""
+
+    def test_plain(self):
+        """Test the demo result renders without escapes exactly as the golden file."""
+        # Act
+        output = render_ansi(build_demo_result(), width=72, color=False)
+
+        # Assert
+        assert output == (GOLDEN / "demo.txt").read_text()
+        assert "\x1b" not in output
+
+    def test_color(self):
+        """Test the coloured variant matches its golden file and the plain layout."""
+        # Act
+        output = render_ansi(build_demo_result(), width=72, color=True)
+
+        # Assert
+        assert output == (GOLDEN / "demo.ansi").read_text()
+        assert "\x1b[1;31m[H]\x1b[0m" in output
+        assert strip_ansi(output) == (GOLDEN / "demo.txt").read_text()
+
+
+@pytest.mark.unit
+class TestTextLayout:
+    """Test widths, wrapping, and optional sections."""
+
+    @pytest.mark.parametrize("width", [40, 60, 100])
+    def test_lines_fit(self, results_fixtures_dir, width):
+        """Test no line exceeds the width, with long URLs, details, and titles."""
+        # Arrange
+        result = AuditResult.load(results_fixtures_dir / "pathological.json")
+        result.issues[0].url = "https://example.com/" + "very-long-segment/" * 10
+
+        # Act
+        output = render_ansi(result, width=width, color=True, options=TextOptions(ai=AI))
+
+        # Assert
+        assert max(cell_len(line) for line in strip_ansi(output).splitlines()) <= width
+
+    def test_counts_right_aligned(self):
+        """Test the page counts of every issue type end in the last column."""
+        # Act
+        lines = render_ansi(build_demo_result(), width=80, color=False).splitlines()
+
+        # Assert
+        titles = [line for line in lines if line.startswith("[")]
+        assert len(titles) == 7
+        assert all(len(line) == 80 and line.endswith("1 page") for line in titles)
+
+    def test_width_floor(self):
+        """Test widths below the minimum are raised to 40."""
+        # Act
+        output = render_ansi(build_demo_result(), width=10, color=False)
+
+        # Assert
+        assert output.splitlines()[1] == "=" * 40
+
+    def test_pages_capped_and_analysis(self):
+        """Test long page lists are cut and the AI analysis comes last."""
+        # Arrange
+        result = build_demo_result()
+        extra = result.issues[0].model_copy(update={"url": "https://demo.tinyseoai.com/faq"})
+        result.issues.append(extra)
+
+        # Act
+        output = render_ansi(
+            result, color=False, options=TextOptions(max_pages_per_issue=1, ai=AI)
+        )
+
+        # Assert
+        assert "    …and 1 more" in output
+        assert output.index("ANALYSIS ---") > output.index("INFO (1)")
+        assert "  - Add a title to /pricing (impact: high, effort: low)" in output
+
+    def test_write_text(self, tmp_path):
+        """Test files are written without colour by default."""
+        # Act
+        path = write_text(build_demo_result(), tmp_path / "out" / "report.txt", width=72)
+
+        # Assert
+        assert path.read_text() == (GOLDEN / "demo.txt").read_text()
@@ -0,0 +1,101 @@
+"""
+Unit tests for response timing.
+"""
+import asyncio
+import gzip
+
+import httpx
+import pytest
+
+from tinyseoai.audit.checks.timing import TimingOptions, measure, timing_issues
+
+URL = "https://example.com/"
+BODY = b"<html><body>" + b"x" * 2000 + b"</body></html>"
+
+
+class _SlowServer:
+    """Answers after a scripted delay per request."""
+
+    def __init__(self, delays: list[float], compress: bool = False):
+        self.delays = delays
+        self.compress = compress
+        self.requests = 0
+
+    async def __call__(self, request: httpx.Request) -> httpx.Response:
+        delay = self.delays[min(self.requests, len(self.delays) - 1)]
+        self.requests += 1
+        await asyncio.sleep(delay)
+        if self.compress:
+            return httpx.Response(200, headers={"content-encoding": "gzip"}, content=gzip.compress(BODY))
+        return httpx.Response(200, content=BODY)
+
+    def client(self) -> httpx.AsyncClient:
+        return httpx.AsyncClient(transport=httpx.MockTransport(self))
+
+
+@pytest.mark.unit
+class TestMeasure:
+    """Test sampling, medians, and size reporting."""
+
+    @pytest.mark.asyncio
+    async def test_median_ignores_one_slow_sample(self):
+        """Test the median of three samples is reported, not the slow outlier."""
+        # Arrange
+        server = _SlowServer([0.3, 0.01, 0.01])
+
+        # Act
+        report = await measure(URL, server.client())
+
+        # Assert
+        assert server.requests == 3
+        assert report.samples[0].ttfb_ms >= 300
+        assert report.ttfb_ms < 300
+        assert timing_issues(report, TimingOptions(ttfb_warning_ms=300)) == []
+
+    @pytest.mark.asyncio
+    async def test_sizes_and_compression(self):
+        """Test decoded and transferred sizes and the content encoding are recorded."""
+        # Act
+        report = await measure(URL, _SlowServer([0], compress=True).client(), TimingOptions(samples=1))
+
+        # Assert
+        metrics = report.metrics()
+        assert metrics["body_bytes"] == len(BODY)
+        assert metrics["transfer_bytes"] < len(BODY)
+        assert metrics["compressed"] and metrics["content_encoding"] == "gzip"
+        assert metrics["samples"] == 1
+
+    @pytest.mark.asyncio
+    async def test_transport_error(self):
+        """Test a failing request stops sampling and is reported."""
+        # Arrange
+        def fail(request):
+            raise httpx.ConnectError("refused")
+
+        # Act
+        report = await measure(URL, httpx.AsyncClient(transport=httpx.MockTransport(fail)))
+
+        # Assert
+        assert report.error == "ConnectError"
+        assert report.samples == []
+        assert timing_issues(report) == []
+
+
+@pytest.mark.unit
+class TestTimingIssues:
+    """Test TTFB thresholds."""
+
+    @pytest.mark.asyncio
+    @pytest.mark.parametrize(("warning", "critical", "severity"), [(50, 1000, "medium"), (20, 50, "high")])
+    async def test_thresholds(self, warning, critical, severity):
+        """Test the median TTFB is compared with configurable warning and critical limits."""
+        # Arrange
+        options = TimingOptions(samples=2, ttfb_warning_ms=warning, ttfb_critical_ms=critical)
+
+        # Act
+        report = await measure(URL, _SlowServer([0.1]).client(), options)
+        issues = timing_issues(report, options)
+
+        # Assert
+        assert [(i.type, i.severity) for i in issues] == [("slow_ttfb", severity)]
+        assert "over 2 requests" in issues[0].detail
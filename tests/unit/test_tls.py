@@ -0,0 +1,284 @@
+"""
+Unit tests for the TLS certificate and DNS check.
+"""
+import socket
+import ssl
+import threading
+from datetime import datetime, timedelta, timezone
+
+import pytest
+from cryptography import x509
+from cryptography.hazmat.primitives import hashes, serialization
+from cryptography.hazmat.primitives.asymmetric import ec
+from cryptography.x509.oid import NameOID
+
+from tinyseoai.audit.checks.tls import (
+    Certificate,
+    Handshake,
+    TlsOptions,
+    days_left,
+    dns_issues,
+    handshake,
+    hostname_matches,
+    tls_issues,
+)
+
+NOW = datetime(2024, 5, 2, 12, 0, tzinfo=timezone.utc)
+URL = "https://pottery.example/"
+
+
+def _types(issues) -> list[str]:
+    return sorted(i.type for i in issues)
+
+
+def _self_signed(tmp_path, name: str, days: int):
+    """Write a self-signed certificate for name, valid for days, and its key."""
+    key = ec.generate_private_key(ec.SECP256R1())
+    subject = x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, name)])
+    now = datetime.now(timezone.utc)
+    cert = (
+        x509.CertificateBuilder()
+        .subject_name(subject)
+        .issuer_name(subject)
+        .public_key(key.public_key())
+        .serial_number(x509.random_serial_number())
+        .not_valid_before(now - timedelta(days=1))
+        .not_valid_after(now + timedelta(days=days))
+        .add_extension(x509.SubjectAlternativeName([x509.DNSName(name)]), critical=False)
+        .add_extension(x509.BasicConstraints(ca=True, path_length=None), critical=True)
+        .sign(key, hashes.SHA256())
+    )
+    cert_file, key_file = tmp_path / f"{name}.pem", tmp_path / f"{name}.key"
+    cert_file.write_bytes(cert.public_bytes(serialization.Encoding.PEM))
+    key_file.write_bytes(
+        key.private_bytes(
+            serialization.Encoding.PEM,
+            serialization.PrivateFormat.PKCS8,
+            serialization.NoEncryption(),
+        )
+    )
+    return cert_file, key_file
+
+
+@pytest.fixture
+def tls_server(tmp_path):
+    """Start a local TLS server with a 5-day self-signed certificate for a name."""
+    sockets = []
+
+    def start(name: str = "localhost") -> tuple[int, str]:
+        cert_file, key_file = _self_signed(tmp_path, name, days=5)
+        context = ssl.SSLContext(ssl.PROTOCOL_TLS_SERVER)
+        context.load_cert_chain(cert_file, key_file)
+        listener = socket.create_server(("127.0.0.1", 0))
+        sockets.append(listener)
+
+        def serve():
+            while True:
+                try:
+                    conn, _ = listener.accept()
+                except OSError:
+                    return  # closed by the fixture
+                try:
+                    with context.wrap_socket(conn, server_side=True):
+                        pass
+                except (OSError, ssl.SSLError):
+                    pass  # the verifying client gives up on the self-signed certificate
+
+        threading.Thread(target=serve, daemon=True).start()
+        return listener.getsockname()[1], str(cert_file)
+
+    yield start
+    for listener in sockets:
+        listener.close()
+
+
+@pytest.mark.unit
+class TestExpiry:
+    """Test expiry arithmetic and findings."""
+
+    @pytest.mark.parametrize(
+        "not_after, expected",
+        [
+            (NOW + timedelta(days=30), 30),
+            (NOW + timedelta(days=20, hours=23), 20),
+            (NOW + timedelta(hours=1), 0),
+            (NOW - timedelta(hours=1), -1),
+            (NOW - timedelta(days=3), -3),
+        ],
+    )
+    def test_days_left(self, not_after, expected):
+        assert days_left(not_after, NOW) == expected
+
+    @pytest.mark.parametrize(
+        "days, expected",
+        [
+            (21, []),
+            (20, ["tls_certificate_expiring"]),
+            (-2, ["tls_certificate_expired"]),
+        ],
+    )
+    def test_expiry_findings(self, days, expected):
+        """Test certificates under 21 days warn and expired ones are critical."""
+        # Arrange
+        cert = Certificate(
+            subject="pottery.example",
+            issuer="Example CA",
+            not_after=NOW + timedelta(days=days),
+            names=["pottery.example"],
+        )
+        result = Handshake(host="pottery.example", protocol="TLSv1.3", certificate=cert)
+
+        # Act
+        issues = tls_issues(result, URL, TlsOptions(), now=NOW)
+
+        # Assert
+        assert _types(issues) == expected
+        if days < 0:
+            assert issues[0].severity == "high"
+            assert issues[0].detail == "TLS certificate expired 2 days ago (2024-04-30)"
+
+    def test_legacy_protocol_and_failure(self):
+        """Test TLS 1.1 is flagged and a failed handshake is the only finding."""
+        # Act
+        legacy = tls_issues(Handshake(host="pottery.example", protocol="TLSv1.1"), URL)
+        failed = tls_issues(Handshake(host="pottery.example", error="Connection refused"), URL)
+
+        # Assert
+        assert legacy[0].detail == "Server negotiates TLS 1.1, which browsers no longer accept"
+        assert _types(failed) == ["tls_handshake_failed"]
+
+
+@pytest.mark.unit
+class TestHostname:
+    """Test RFC 6125 host name matching."""
+
+    @pytest.mark.parametrize(
+        "host, names, expected",
+        [
+            ("pottery.example", ["pottery.example"], True),
+            ("Pottery.Example.", ["pottery.example"], True),
+            ("www.pottery.example", ["*.pottery.example"], True),
+            ("pottery.example", ["*.pottery.example"], False),
+            ("a.b.pottery.example", ["*.pottery.example"], False),
+            ("www.pottery.example", ["*.example"], False),
+            ("shop.example", ["pottery.example", "www.pottery.example"], False),
+            ("192.0.2.10", ["192.0.2.10"], True),
+            ("192.0.2.10", ["*.0.2.10"], False),
+            ("pottery.example", [], False),
+        ],
+    )
+    def test_hostname_matches(self, host, names, expected):
+        assert hostname_matches(host, names) is expected
+
+
+class _Resolver:
+    """DNS answers from a table: name -> (IPv4, IPv6, CNAME target)."""
+
+    def __init__(self, records: dict):
+        self.records = records
+
+    def addresses(self, host, family):
+        ipv4, ipv6, _ = self.records.get(host, ([], [], None))
+        return ipv4 if family == socket.AF_INET else ipv6
+
+    def canonical(self, host):
+        return self.records.get(host, ([], [], None))[2]
+
+
+@pytest.mark.unit
+class TestDns:
+    """Test apex and www lookups."""
+
+    def test_healthy(self):
+        """Test an apex with A and AAAA records and a resolving www passes."""
+        # Arrange
+        resolver = _Resolver(
+            {
+                "pottery.example": (["192.0.2.10"], ["2001:db8::10"], None),
+                "www.pottery.example": (["192.0.2.10"], [], "pottery.example"),
+            }
+        )
+
+        # Act / Assert
+        assert dns_issues("www.pottery.example", URL, resolver) == []
+
+    def test_apex_problems(self):
+        """Test a CNAME apex, a missing AAAA, and an unresolved www are reported."""
+        # Arrange
+        resolver = _Resolver({"pottery.example": (["192.0.2.10"], [], "shops.host.example")})
+
+        # Act
+        issues = dns_issues("pottery.example", URL, resolver)
+
+        # Assert
+        assert _types(issues) == ["dns_cname_at_apex", "dns_no_ipv6", "dns_unresolved"]
+        assert next(i for i in issues if i.type == "dns_no_ipv6").severity == "info"
+        assert issues[0].detail == (
+            "www.pottery.example does not resolve, though pottery.example does"
+        )
+
+    @pytest.mark.parametrize("host", ["192.0.2.10", "localhost"])
+    def test_not_looked_up(self, host):
+        """Test IP addresses and single-label hosts have no DNS findings."""
+        assert dns_issues(host, URL, _Resolver({})) == []
+
+
+@pytest.mark.unit
+class TestHandshake:
+    """Test handshakes against a local TLS server."""
+
+    def test_self_signed_short_lived(self, tls_server):
+        """Test the certificate is read despite failing verification."""
+        # Arrange
+        port, _ = tls_server()
+        options = TlsOptions(port=port, timeout=5)
+
+        # Act
+        result = handshake("localhost", options)
+
+        # Assert
+        assert result.protocol == "TLSv1.3"
+        assert result.certificate.names == ["localhost"]
+        assert result.chain_error is not None
+        assert _types(tls_issues(result, URL, options)) == [
+            "tls_certificate_expiring",
+            "tls_chain_invalid",
+        ]
+
+    def test_trusted_chain(self, tls_server):
+        """Test a certificate from the configured trust store verifies."""
+        # Arrange
+        port, cert_file = tls_server()
+
+        # Act
+        result = handshake("localhost", TlsOptions(port=port, timeout=5, ca_file=cert_file))
+
+        # Assert
+        assert result.chain_error is None
+
+    def test_hostname_mismatch(self, tls_server):
+        """Test a certificate for another name is a mismatch."""
+        # Arrange
+        port, cert_file = tls_server("other.example")
+        options = TlsOptions(port=port, timeout=5, ca_file=cert_file)
+
+        # Act
+        issues = tls_issues(handshake("localhost", options), URL, options)
+
+        # Assert
+        assert _types(issues) == ["tls_certificate_expiring", "tls_hostname_mismatch"]
+        mismatch = next(i for i in issues if i.type == "tls_hostname_mismatch")
+        assert mismatch.detail == "TLS certificate does not cover localhost; it names other.example"
+
+    def test_refused(self):
+        """Test a closed port fails the handshake."""
+        # Arrange
+        with socket.create_server(("127.0.0.1", 0)) as listener:
+            port = listener.getsockname()[1]
+
+        # Act
+        result = handshake("127.0.0.1", TlsOptions(port=port, timeout=2))
+
+        # Assert
+        assert result.error is not None
+        assert result.certificate is None
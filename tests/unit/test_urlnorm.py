@@ -0,0 +1,104 @@
+"""
+Unit tests for URL normalization.
+"""
+import pytest
+
+from tinyseoai.utils.urlnorm import DedupSet, NormalizeOptions, equivalent, normalize
+
+
+@pytest.mark.unit
+class TestNormalize:
+    """Test each normalization rule."""
+
+    @pytest.mark.parametrize(
+        "url,expected",
+        [
+            ("HTTPS://Example.COM/Path", "https://example.com/Path"),
+            ("https://example.com:443/a", "https://example.com/a"),
+            ("http://example.com:80/a", "http://example.com/a"),
+            ("https://example.com:8443/a", "https://example.com:8443/a"),
+            ("https://example.com", "https://example.com/"),
+            ("https://example.com/a/./b/../c", "https://example.com/a/c"),
+            ("https://example.com/a/b/..", "https://example.com/a/"),
+            ("https://example.com/../a", "https://example.com/a"),
+            ("https://example.com/%7euser/%2f", "https://example.com/~user/%2F"),
+            ("https://example.com/a b", "https://example.com/a%20b"),
+            ("https://example.com/a#section", "https://example.com/a"),
+            ("https://example.com/?b=2&a=1", "https://example.com/?a=1&b=2"),
+            ("https://bücher.example/", "https://xn--bcher-kva.example/"),
+            ("https://example.com./a", "https://example.com/a"),
+            ("  https://example.com/a  ", "https://example.com/a"),
+        ],
+    )
+    def test_rules(self, url, expected):
+        """Test case, ports, dot segments, escapes, fragments, sorting, and punycode."""
+        assert normalize(url) == expected
+
+    def test_strip_list(self):
+        """Test tracking and session parameters are removed, including utm_* globs."""
+        # Act
+        url = normalize("https://example.com/a?utm_source=x&UTM_Medium=y&fbclid=1&gclid=2&sessionid=3&id=7")
+
+        # Assert
+        assert url == "https://example.com/a?id=7"
+        assert url.query == "id=7"
+
+    def test_custom_strip_list_and_order(self):
+        """Test callers can keep all parameters in their original order."""
+        # Arrange
+        options = NormalizeOptions(strip_params=("ref",), sort_query=False)
+
+        # Act & Assert
+        assert normalize("https://example.com/?z=1&ref=x&a=2", options) == "https://example.com/?z=1&a=2"
+
+    @pytest.mark.parametrize(
+        "policy,url,expected",
+        [
+            ("keep", "https://example.com/a/", "https://example.com/a/"),
+            ("strip", "https://example.com/a/", "https://example.com/a"),
+            ("strip", "https://example.com/", "https://example.com/"),
+            ("add", "https://example.com/a", "https://example.com/a/"),
+            ("add", "https://example.com/a.html", "https://example.com/a.html"),
+        ],
+    )
+    def test_trailing_slash_policy(self, policy, url, expected):
+        """Test the trailing slash policies; the root path keeps its slash."""
+        assert normalize(url, NormalizeOptions(trailing_slash=policy)) == expected
+
+    def test_drop_www(self):
+        """Test "www." is only dropped when asked."""
+        assert normalize("https://www.example.com/") == "https://www.example.com/"
+        assert normalize("https://www.example.com/", NormalizeOptions(drop_www=True)) == "https://example.com/"
+
+    def test_non_hierarchical(self):
+        """Test mailto: and similar URLs pass through without a path rewrite."""
+        assert normalize("MAILTO:hi@example.com#x") == "mailto:hi@example.com"
+
+    def test_paths_stay_case_sensitive(self):
+        """Test paths differing only in case are different pages."""
+        assert not equivalent("https://example.com/About", "https://example.com/about")
+
+
+@pytest.mark.unit
+class TestDedupSet:
+    """Test the normalized URL set."""
+
+    def test_keeps_first_spelling(self):
+        """Test equivalent URLs are added once and the first spelling is kept."""
+        # Arrange
+        urls = DedupSet()
+
+        # Act
+        added = [
+            urls.add("https://Example.com/a?utm_source=x"),
+            urls.add("https://example.com/a#top"),
+            urls.add("https://example.com/b"),
+        ]
+
+        # Assert
+        assert added == [True, False, True]
+        assert list(urls) == ["https://Example.com/a?utm_source=x", "https://example.com/b"]
+        assert "https://example.com:443/a" in urls
+        assert urls.original("https://example.com/./a") == "https://Example.com/a?utm_source=x"
+        assert len(urls) == 2
+
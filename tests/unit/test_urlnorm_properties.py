@@ -0,0 +1,53 @@
+"""
+Property tests for URL normalization.
+"""
+import pytest
+
+pytest.importorskip("hypothesis")
+
+from hypothesis import given, settings  # noqa: E402
+from hypothesis import strategies as st  # noqa: E402
+
+from tinyseoai.utils.urlnorm import NormalizeOptions, equivalent, normalize  # noqa: E402
+
+SEGMENTS = st.sampled_from(["a", "B", ".", "..", "", "%7e", "%2F", "x y", "é", "~"])
+
+URLS = st.builds(
+    lambda scheme, host, port, path, query, fragment: (
+        f"{scheme}://{host}{port}/{'/'.join(path)}"
+        f"{'?' + query if query else ''}{'#' + fragment if fragment else ''}"
+    ),
+    st.sampled_from(["http", "HTTPS", "https"]),
+    st.sampled_from(["example.com", "WWW.Example.com", "bücher.de", "example.com."]),
+    st.sampled_from(["", ":80", ":443", ":8080"]),
+    st.lists(SEGMENTS, max_size=5),
+    st.sampled_from(["", "b=2&a=1", "utm_source=x&id=1", "a=&a=1", "q=a+b"]),
+    st.sampled_from(["", "top", "a/b"]),
+)
+
+OPTIONS = st.builds(
+    NormalizeOptions,
+    trailing_slash=st.sampled_from(["keep", "strip", "add"]),
+    sort_query=st.booleans(),
+    drop_www=st.booleans(),
+)
+
+
+@pytest.mark.unit
+class TestProperties:
+    """Test normalization is idempotent and equivalence is an equivalence relation."""
+
+    @settings(max_examples=300, deadline=None)
+    @given(URLS, OPTIONS)
+    def test_idempotent(self, url, options):
+        """Test normalizing a normalized URL changes nothing."""
+        once = normalize(url, options)
+        assert normalize(once, options) == once
+
+    @settings(max_examples=300, deadline=None)
+    @given(URLS, URLS, URLS, OPTIONS)
+    def test_equivalence_symmetric_and_transitive(self, a, b, c, options):
+        """Test a~b equals b~a, and a~b with b~c implies a~c."""
+        assert equivalent(a, b, options) == equivalent(b, a, options)
+        if equivalent(a, b, options) and equivalent(b, c, options):
+            assert equivalent(a, c, options)
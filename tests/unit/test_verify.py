@@ -0,0 +1,191 @@
+"""
+Unit tests for re-verifying page status.
+"""
+import asyncio
+
+import httpx
+import pytest
+
+from tinyseoai.audit.verify import (
+    AUTO_RESOLVED_KEY,
+    VerifyOptions,
+    affected_urls,
+    apply_verification,
+    verify_pages,
+)
+from tinyseoai.data.models import AuditResult, Issue
+
+
+def _result() -> AuditResult:
+    return AuditResult(
+        site="https://example.com/",
+        pages_scanned=3,
+        issues=[
+            {"url": "https://example.com/", "type": "broken_link", "severity": "medium", "detail": "https://example.com/old"},
+            {"url": "https://example.com/", "type": "broken_link", "severity": "medium", "detail": "https://example.com/gone"},
+            {"url": "https://example.com/down", "type": "http_error", "severity": "high", "detail": "Status 500"},
+            {"url": "https://example.com/", "type": "title_missing", "severity": "high"},
+        ],
+        meta={},
+    )
+
+
+class _Server:
+    """Scripted responses per path; a list is consumed one entry per request."""
+
+    def __init__(self, routes: dict):
+        self.routes = routes
+        self.requests: list[httpx.Request] = []
+        self.in_flight = 0
+        self.max_in_flight = 0
+
+    async def __call__(self, request: httpx.Request) -> httpx.Response:
+        self.requests.append(request)
+        self.in_flight += 1
+        self.max_in_flight = max(self.max_in_flight, self.in_flight)
+        try:
+            await asyncio.sleep(0)
+            route = self.routes.get(request.url.path, 404)
+            if isinstance(route, list):
+                route = route.pop(0) if len(route) > 1 else route[0]
+            if isinstance(route, Exception):
+                raise route
+            if isinstance(route, float):
+                await asyncio.sleep(route)
+                return httpx.Response(200)
+            if isinstance(route, str):
+                return httpx.Response(301, headers={"location": route})
+            if route == 405 and request.method == "GET":
+                return httpx.Response(200)
+            return httpx.Response(route)
+        finally:
+            self.in_flight -= 1
+
+    def client(self) -> httpx.AsyncClient:
+        return httpx.AsyncClient(transport=httpx.MockTransport(self))
+
+
+@pytest.mark.unit
+class TestVerifyPages:
+    """Test status collection and limits."""
+
+    def test_affected_urls(self):
+        """Test pages and broken-link targets are listed once each."""
+        assert affected_urls(_result()) == [
+            "https://example.com/",
+            "https://example.com/old",
+            "https://example.com/gone",
+            "https://example.com/down",
+        ]
+
+    def test_affected_urls_merges_spellings(self):
+        """Test spellings of one page (fragment, tracking parameters) are checked once."""
+        # Arrange
+        result = _result()
+        result.issues.append(Issue(url="https://example.com/down#main", type="meta_missing", severity="low"))
+        result.issues.append(Issue(url="https://EXAMPLE.com/?utm_source=x", type="h1_missing", severity="medium"))
+
+        # Act & Assert
+        assert len(affected_urls(result)) == 4
+
+    @pytest.mark.asyncio
+    async def test_statuses_and_redirects(self):
+        """Test status codes, redirect targets, and latency are recorded."""
+        # Arrange
+        server = _Server({"/": 200, "/old": "https://example.com/new", "/new": 200, "/gone": 404, "/down": 405})
+
+        # Act
+        report = await verify_pages(_result(), client=server.client())
+
+        # Assert
+        pages = report.by_url()
+        assert pages["https://example.com/gone"].status_code == 404
+        assert pages["https://example.com/old"].final_url == "https://example.com/new"
+        assert pages["https://example.com/down"].status_code == 200  # GET after HEAD 405
+        assert all(p.latency_ms is not None for p in report.pages)
+
+    @pytest.mark.asyncio
+    async def test_retry_once_on_reset(self):
+        """Test a connection reset is retried once, a second one is reported."""
+        # Arrange
+        reset = httpx.ReadError("connection reset")
+        server = _Server({"/": [reset, 200], "/old": [reset, reset, 200], "/gone": 200, "/down": 200})
+
+        # Act
+        report = await verify_pages(_result(), client=server.client())
+
+        # Assert
+        pages = report.by_url()
+        assert pages["https://example.com/"].status_code == 200
+        assert pages["https://example.com/old"].error == "ReadError"
+
+    @pytest.mark.asyncio
+    async def test_max_requests_and_concurrency(self):
+        """Test URLs past the cap are skipped and concurrency stays bounded."""
+        # Arrange
+        server = _Server({"/": 200, "/old": 200, "/gone": 200, "/down": 200})
+
+        # Act
+        report = await verify_pages(_result(), VerifyOptions(max_requests=3, concurrency=2), client=server.client())
+
+        # Assert
+        assert report.checked == 3
+        assert report.by_url()["https://example.com/down"].skipped == "max requests reached"
+        assert server.max_in_flight <= 2
+
+    @pytest.mark.asyncio
+    async def test_deadline(self):
+        """Test pages still pending at the deadline are skipped."""
+        # Arrange
+        server = _Server({"/": 200, "/old": 200, "/gone": 200, "/down": 5.0})
+
+        # Act
+        report = await verify_pages(_result(), VerifyOptions(deadline=0.2), client=server.client())
+
+        # Assert
+        assert report.deadline_exceeded
+        assert report.by_url()["https://example.com/down"].skipped == "deadline exceeded"
+
+    @pytest.mark.asyncio
+    async def test_robots(self):
+        """Test robots.txt-disallowed pages are not fetched."""
+        # Arrange
+        server = _Server({"/": 200, "/old": 200, "/gone": 200, "/down": 200})
+
+        async def robots(request):
+            if request.url.path == "/robots.txt":
+                return httpx.Response(200, text="User-agent: *\nDisallow: /down\n")
+            return await server(request)
+
+        client = httpx.AsyncClient(transport=httpx.MockTransport(robots))
+
+        # Act
+        report = await verify_pages(_result(), VerifyOptions(respect_robots=True), client=client)
+
+        # Assert
+        assert report.by_url()["https://example.com/down"].skipped == "disallowed by robots.txt"
+        assert all(r.url.path != "/down" for r in server.requests)
+
+
+@pytest.mark.unit
+class TestApplyVerification:
+    """Test auto-resolving issues that now load."""
+
+    @pytest.mark.asyncio
+    async def test_fixed_issues_removed(self):
+        """Test broken links and http errors that now return 2xx are resolved."""
+        # Arrange
+        result = _result()
+        server = _Server({"/": 200, "/old": 200, "/gone": 404, "/down": 200})
+        report = await verify_pages(result, client=server.client())
+
+        # Act
+        fixed = apply_verification(result, report)
+
+        # Assert
+        assert [(i.type, i.detail) for i in fixed] == [
+            ("broken_link", "https://example.com/old"),
+            ("http_error", "Status 500"),
+        ]
+        assert [i.type for i in result.issues] == ["broken_link", "title_missing"]
+        assert len(result.meta[AUTO_RESOLVED_KEY]) == 2
@@ -0,0 +1,176 @@
+"""
+Unit tests for WCAG criteria of accessibility issues and their exports.
+"""
+import csv
+import io
+import json
+
+import pytest
+
+from tinyseoai.data import wcag
+from tinyseoai.data.models import Issue
+from tinyseoai.data.query import issue_category
+from tinyseoai.data.severity import Category
+from tinyseoai.data.wcag import accessibility_rows, by_criterion, criterion, criterion_of
+from tinyseoai.reporting.tabular import CsvOptions, render_csv
+
+
+def _groups(issues) -> list[tuple[str | None, list[str]]]:
+    return [(c.number if c else None, [i.type for i in members]) for c, members in by_criterion(issues)]
+
+
+@pytest.mark.unit
+class TestCriteria:
+    """Test criteria are read from issues, or their type, and described."""
+
+    def test_known_and_unknown_numbers(self):
+        """Test known criteria have a name and level, and others show the bare number."""
+        assert criterion("1.4.3").label() == "1.4.3 Contrast (Minimum) (AA)"
+        assert criterion(" 2.4.4 ").level == "A"
+        assert criterion("9.9.9").label() == "9.9.9"
+
+    def test_field_wins_over_type(self):
+        """Test an issue's wcag field is used before its type's criterion."""
+        # Arrange
+        issue = Issue(url="https://pottery.example/", type="img_alt_missing", wcag="2.4.4")
+
+        # Act
+        found = criterion_of(issue)
+
+        # Assert
+        assert found.number == "2.4.4"
+        assert criterion_of(Issue(url="https://pottery.example/", type="img_alt_missing")).number == "1.1.1"
+        assert criterion_of(Issue(url="https://pottery.example/", type="title_missing")) is None
+
+    def test_unknown_type_with_criterion_is_accessibility(self):
+        """Test issue types this version does not know count as accessibility when they name a criterion."""
+        # Arrange
+        contrast = Issue(url="https://pottery.example/", type="color_contrast", wcag="1.4.3")
+        unknown = Issue(url="https://pottery.example/", type="color_contrast")
+
+        # Act / Assert
+        assert issue_category(contrast) == Category.ACCESSIBILITY
+        assert issue_category(unknown) == Category.OTHER
+
+
+@pytest.mark.unit
+class TestFields:
+    """Test the optional fields round-trip and stay out of older results."""
+
+    def test_round_trip(self, load_result_fixture):
+        """Test wcag and impact load from a result and are written back."""
+        # Act
+        result = load_result_fixture("accessibility_summary.json")
+        issues = json.loads(result.to_json())["issues"]
+
+        # Assert
+        assert (issues[0]["wcag"], issues[0]["impact"]) == ("1.4.3", "serious")
+        assert "impact" not in issues[3]
+        assert "wcag" not in issues[4] and "impact" not in issues[4]
+
+    def test_older_results_unchanged(self, load_result_fixture):
+        """Test results without the fields load and serialize without them."""
+        # Act
+        result = load_result_fixture("basic_summary.json")
+
+        # Assert
+        assert all(i.wcag is None and i.impact is None for i in result.issues)
+        assert '"wcag"' not in result.to_json()
+
+
+@pytest.mark.unit
+class TestGrouping:
+    """Test accessibility issues are grouped by criterion."""
+
+    def test_by_criterion(self, load_result_fixture):
+        """Test groups come in numeric order, with non-accessibility issues left out."""
+        # Arrange
+        result = load_result_fixture("accessibility_summary.json")
+
+        # Act
+        groups = _groups(result.issues)
+
+        # Assert
+        assert groups == [
+            ("1.1.1", ["img_alt_missing"]),
+            ("1.3.1", ["form_label_missing"]),
+            ("1.4.3", ["color_contrast"]),
+            ("1.4.10", ["reflow_horizontal_scroll"]),
+            ("3.1.1", ["missing_html_lang"]),
+        ]
+
+    def test_no_criterion_last(self, monkeypatch):
+        """Test accessibility issues with no criterion are grouped last under None."""
+        # Arrange
+        monkeypatch.delitem(wcag.ISSUE_CRITERIA, "missing_html_lang")
+        issues = [
+            Issue(url="https://pottery.example/", type="missing_html_lang"),
+            Issue(url="https://pottery.example/", type="img_alt_missing"),
+        ]
+
+        # Act / Assert
+        assert _groups(issues) == [("1.1.1", ["img_alt_missing"]), (None, ["missing_html_lang"])]
+
+    def test_none_without_accessibility_issues(self, load_result_fixture):
+        """Test a result with no accessibility issues has no groups, so no sheet."""
+        # Arrange
+        result = load_result_fixture("comprehensive_summary.json")
+
+        # Act / Assert
+        assert by_criterion(result.issues) == []
+        assert accessibility_rows(result.issues) == []
+
+
+@pytest.mark.unit
+class TestExports:
+    """Test the criterion reaches CSV and Excel exports."""
+
+    def test_csv_column(self, load_result_fixture):
+        """Test CSV rows carry the issue's criterion, or their type's, or nothing."""
+        # Arrange
+        result = load_result_fixture("accessibility_summary.json")
+
+        # Act
+        rows = list(csv.DictReader(io.StringIO(render_csv(result, CsvOptions(explode_pages=True)))))
+
+        # Assert
+        assert {r["type"]: r["wcag"] for r in rows} == {
+            "form_label_missing": "1.3.1",
+            "color_contrast": "1.4.3",
+            "img_alt_missing": "1.1.1",
+            "missing_html_lang": "3.1.1",
+            "reflow_horizontal_scroll": "1.4.10",
+            "meta_description_missing": "",
+        }
+
+    def test_accessibility_rows(self, load_result_fixture):
+        """Test sheet rows show the criterion's name and level, and a blank impact when missing."""
+        # Arrange
+        result = load_result_fixture("accessibility_summary.json")
+
+        # Act
+        rows = accessibility_rows(result.issues)
+
+        # Assert
+        assert rows[0][:6] == ["1.1.1", "Non-text Content", "A", "critical", "img_alt_missing", "medium"]
+        assert rows[3][:4] == ["1.4.10", "Reflow", "AA", ""]
+        assert rows[4][:4] == ["3.1.1", "Language of Page", "A", ""]
+
+    @pytest.mark.parametrize(
+        "name,sheet", [("accessibility_summary.json", True), ("basic_summary.json", False)]
+    )
+    def test_xlsx_sheet(self, results_fixtures_dir, tmp_path, name, sheet):
+        """Test the Accessibility sheet only appears for results with accessibility issues."""
+        # Arrange
+        openpyxl = pytest.importorskip("openpyxl")
+        from tinyseoai.reporting.excel import write_xlsx
+
+        summary = json.loads((results_fixtures_dir / name).read_text())
+
+        # Act
+        path = write_xlsx(summary, tmp_path / "report.xlsx")
+        workbook = openpyxl.load_workbook(path)
+
+        # Assert
+        assert ("Accessibility" in workbook.sheetnames) is sheet
+        assert [c.value for c in workbook["Issues"][1]][3] == "WCAG"
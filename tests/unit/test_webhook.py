@@ -0,0 +1,185 @@
+"""
+Unit tests for webhook notifications.
+"""
+import json
+
+import httpx
+import pytest
+
+from tinyseoai.data.models import AuditResult
+from tinyseoai.exceptions import IntegrationError
+from tinyseoai.integrations.webhook import (
+    Webhook,
+    WebhookConfig,
+    build_message,
+    detect_style,
+    redact_url,
+)
+
+SLACK_URL = "https://hooks.slack.com/services/T000/B000/secret"
+DISCORD_URL = "https://discord.com/api/webhooks/123/secret"
+GENERIC_URL = "https://ci.example.com/hooks/seo?token=secret"
+
+
+def _result(score: float = 72, high: int = 2) -> AuditResult:
+    issues = [
+        {"url": f"https://example.com/p{n}", "type": "title_missing", "severity": "high"} for n in range(high)
+    ] + [{"url": "https://example.com/", "type": "missing_hsts", "severity": "medium"}]
+    return AuditResult(
+        site="https://example.com/", pages_scanned=5, issues=issues, meta={"health_score": score}
+    )
+
+
+class _Recorder:
+    """Mock transport that replies with queued status codes and records requests."""
+
+    def __init__(self, *statuses: int):
+        self.statuses = list(statuses)
+        self.requests: list[httpx.Request] = []
+
+    def __call__(self, request: httpx.Request) -> httpx.Response:
+        self.requests.append(request)
+        return httpx.Response(self.statuses.pop(0) if self.statuses else 200)
+
+    def client(self) -> httpx.AsyncClient:
+        return httpx.AsyncClient(transport=httpx.MockTransport(self))
+
+
+async def _no_sleep(_seconds: float) -> None:
+    return None
+
+
+@pytest.mark.unit
+class TestPayloads:
+    """Test message content and per-style payload shape."""
+
+    def test_detect_style(self):
+        """Test the style is guessed from the URL."""
+        assert detect_style(SLACK_URL) == "slack"
+        assert detect_style(DISCORD_URL) == "discord"
+        assert detect_style(GENERIC_URL) == "generic"
+
+    def test_message(self):
+        """Test the message carries score, delta, and the top critical issues."""
+        # Act
+        message = build_message(_result(score=72, high=4), previous=_result(score=80), top_n=3)
+
+        # Assert
+        assert message.delta == -8
+        assert message.critical_total == 4
+        assert len(message.critical) == 3
+        assert message.headline == "SEO audit for https://example.com/: 72/100 (-8)"
+
+    def test_slack_payload(self):
+        """Test Slack payloads use text plus blocks with linked issues."""
+        # Act
+        payload = Webhook(WebhookConfig(url=SLACK_URL)).payload(_result())
+
+        # Assert
+        assert payload["text"].startswith("SEO audit for https://example.com/")
+        assert payload["blocks"][0]["type"] == "header"
+        assert "<https://example.com/p0|" in payload["blocks"][2]["text"]["text"]
+
+    def test_discord_payload(self):
+        """Test Discord payloads use one embed with a field per critical issue."""
+        # Act
+        payload = Webhook(WebhookConfig(url=DISCORD_URL, report_url="https://r.example.com/")).payload(_result())
+
+        # Assert
+        [embed] = payload["embeds"]
+        assert embed["url"] == "https://r.example.com/"
+        assert [f["value"] for f in embed["fields"]] == ["https://example.com/p0", "https://example.com/p1"]
+
+    def test_generic_payload(self):
+        """Test generic payloads are flat JSON with counts and issues."""
+        # Act
+        payload = Webhook(WebhookConfig(url=GENERIC_URL)).payload(_result(), previous=_result(score=70))
+
+        # Assert
+        assert payload["event"] == "audit.completed"
+        assert (payload["health_score"], payload["score_delta"], payload["critical_total"]) == (72, 2, 2)
+        json.dumps(payload)
+
+
+@pytest.mark.unit
+class TestDelivery:
+    """Test sending, retries, and dry runs."""
+
+    @pytest.mark.asyncio
+    async def test_dry_run(self):
+        """Test dry runs return the payload without any request."""
+        # Arrange
+        recorder = _Recorder()
+        webhook = Webhook(WebhookConfig(url=GENERIC_URL), client=recorder.client())
+
+        # Act
+        delivery = await webhook.send(_result(), dry_run=True)
+
+        # Assert
+        assert delivery.sent is False
+        assert delivery.payload["site"] == "https://example.com/"
+        assert recorder.requests == []
+
+    @pytest.mark.asyncio
+    async def test_posts_json(self):
+        """Test the payload is posted as JSON to the configured URL."""
+        # Arrange
+        recorder = _Recorder(204)
+        webhook = Webhook(WebhookConfig(url=SLACK_URL), client=recorder.client())
+
+        # Act
+        delivery = await webhook.send(_result())
+
+        # Assert
+        assert (delivery.sent, delivery.status_code, delivery.attempts) == (True, 204, 1)
+        [request] = recorder.requests
+        assert str(request.url) == SLACK_URL
+        assert json.loads(request.content) == delivery.payload
+
+    @pytest.mark.asyncio
+    async def test_retries_5xx_with_backoff(self):
+        """Test 5xx responses are retried with doubling delays."""
+        # Arrange
+        recorder = _Recorder(502, 503, 200)
+        delays = []
+
+        async def sleep(seconds):
+            delays.append(seconds)
+
+        webhook = Webhook(WebhookConfig(url=GENERIC_URL, backoff_seconds=0.5), client=recorder.client(), sleep=sleep)
+
+        # Act
+        delivery = await webhook.send(_result())
+
+        # Assert
+        assert delivery.attempts == 3
+        assert delays == [0.5, 1.0]
+
+    @pytest.mark.asyncio
+    async def test_gives_up_after_retries(self):
+        """Test exhausted retries raise without leaking the URL secret."""
+        # Arrange
+        recorder = _Recorder(500, 500, 500)
+        webhook = Webhook(WebhookConfig(url=GENERIC_URL, max_retries=2), client=recorder.client(), sleep=_no_sleep)
+
+        # Act / Assert
+        with pytest.raises(IntegrationError) as exc_info:
+            await webhook.send(_result())
+        assert len(recorder.requests) == 3
+        assert "secret" not in str(exc_info.value)
+
+    @pytest.mark.asyncio
+    async def test_4xx_not_retried(self):
+        """Test client errors fail immediately."""
+        # Arrange
+        recorder = _Recorder(404)
+        webhook = Webhook(WebhookConfig(url=GENERIC_URL), client=recorder.client(), sleep=_no_sleep)
+
+        # Act / Assert
+        with pytest.raises(IntegrationError):
+            await webhook.send(_result())
+        assert len(recorder.requests) == 1
+
+    def test_redact_url(self):
+        """Test redaction keeps only scheme and host."""
+        assert redact_url(SLACK_URL) == "https://hooks.slack.com/***"
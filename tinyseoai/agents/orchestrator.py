@@ -8,6 +8,7 @@ import json
 import time
 from typing import Any
 
+from ..data.severity import Severity, count_by_severity
 from ..utils.logging import get_logger
 from .base import AgentContext, BaseAgent
 from .models import (
@@ -61,10 +62,7 @@ class OrchestratorAgent(BaseAgent):
             issues = data.get("issues", [])
 
             # Count by severity
-            severity_counts = {"high": 0, "medium": 0, "low": 0, "info": 0}
-            for issue in issues:
-                severity = issue.get("severity", "info")
-                severity_counts[severity] = severity_counts.get(severity, 0) + 1
+            severity_counts = count_by_severity(issue.get("severity") for issue in issues)
 
             # Count by category
             category_counts: dict[str, int] = {}
@@ -300,7 +298,7 @@ class OrchestratorAgent(BaseAgent):
             )
 
         # Count high severity issues
-        high_severity = [i for i in issues if i.get("severity") == "high"]
+        high_severity = [i for i in issues if Severity.parse(i.get("severity")) is Severity.HIGH]
         if high_severity:
             insights.append(
                 f"Found {len(high_severity)} high-severity issues requiring immediate attention"
@@ -4,6 +4,8 @@ Agent prompts library with specialized prompts for each agent role.
 
 from typing import Any
 
+from ..data.severity import count_by_severity
+
 # ============================================================================
 # ORCHESTRATOR AGENT PROMPTS
 # ============================================================================
@@ -275,10 +277,7 @@ def format_orchestrator_planning_prompt(audit_data: dict[str, Any]) -> str:
     """Format the orchestrator task planning prompt with audit data."""
     # Count issues by severity
     issues = audit_data.get("issues", [])
-    severity_counts = {"high": 0, "medium": 0, "low": 0, "info": 0}
-    for issue in issues:
-        severity = issue.get("severity", "info")
-        severity_counts[severity] = severity_counts.get(severity, 0) + 1
+    severity_counts = count_by_severity(issue.get("severity") for issue in issues)
 
     issue_breakdown = "\n".join(
         [f"- {sev.capitalize()}: {count}" for sev, count in severity_counts.items() if count > 0]
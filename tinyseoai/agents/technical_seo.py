@@ -8,6 +8,7 @@ import json
 import time
 from typing import Any
 
+from ..data.severity import Severity
 from ..utils.logging import get_logger
 from .base import AgentContext, BaseAgent
 from .models import AgentProfile, AgentResult, AgentRole, AgentTask
@@ -62,7 +63,9 @@ class TechnicalSEOAgent(BaseAgent):
 
             analysis = {
                 "count": len(https_issues),
-                "critical": [i for i in https_issues if i.get("severity") == "high"],
+                "critical": [
+                    i for i in https_issues if Severity.parse(i.get("severity")) is Severity.HIGH
+                ],
                 "recommendations": [],
             }
 
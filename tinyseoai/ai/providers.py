@@ -0,0 +1,294 @@
+"""
+AI providers for the executive summary: an OpenAI-compatible chat endpoint,
+Anthropic's Messages API, or a local Ollama server.
+
+Each provider sends one system and one user message, asks for a JSON object,
+and returns it with the token usage the endpoint reports, so a summary
+records its cost the same way whichever model wrote it. Requests time out
+after ProviderSettings.timeout; 429 and 502/503/504 responses are retried
+with exponential backoff, honoring Retry-After.
+
+API keys are never stored in the config file; AppConfig reads them from the
+environment or the system keyring and passes them in.
+"""
+from __future__ import annotations
+
+import json
+import time
+from typing import Any, Literal
+
+import httpx
+from loguru import logger
+from pydantic import BaseModel, Field
+
+from ..exceptions import AIError
+from ..utils.http import RetryPolicy, retry_after_seconds
+
+ProviderName = Literal["openai", "anthropic", "ollama"]
+PROVIDERS: tuple[ProviderName, ...] = ("openai", "anthropic", "ollama")
+
+DEFAULT_URLS = {
+    "openai": "https://api.openai.com/v1",
+    "anthropic": "https://api.anthropic.com",
+    "ollama": "http://localhost:11434",
+}
+# OpenAI defaults to the plan's model (openai_model_free / openai_model_premium)
+DEFAULT_MODELS = {"anthropic": "claude-sonnet-4-5", "ollama": "llama3.1"}
+ANTHROPIC_VERSION = "2023-06-01"
+
+
+def _retry() -> RetryPolicy:
+    return RetryPolicy(backoff=2.0, max_backoff=30.0, max_retry_after=60.0, statuses=[429, 502, 503, 504])
+
+
+class ProviderSettings(BaseModel):
+    """Which model writes AI summaries, and how it is reached."""
+
+    provider: ProviderName = "openai"
+    model: str | None = None  # None: the plan's OpenAI model, or DEFAULT_MODELS
+    base_url: str | None = None  # None: DEFAULT_URLS; any OpenAI-compatible server for "openai"
+    timeout: float = Field(default=60.0, gt=0)  # seconds per request
+    retry: RetryPolicy = Field(default_factory=_retry)
+    prompt_token_budget: int = Field(default=6000, ge=500)  # estimated tokens of the prompt
+    redact_urls: bool = False  # mask hostnames and strip query strings before sending
+
+
+class Provider:
+    """Base of the providers: posts a request with retries and reads the JSON answer."""
+
+    name: ProviderName
+    path: str
+
+    def __init__(
+        self,
+        settings: ProviderSettings,
+        model: str,
+        api_key: str = "",
+        transport: httpx.BaseTransport | None = None,
+    ):
+        self.settings = settings
+        self.model = model
+        self.api_key = api_key
+        self.base_url = (settings.base_url or DEFAULT_URLS[self.name]).rstrip("/")
+        self._transport = transport
+
+    def __repr__(self) -> str:
+        return f"{type(self).__name__}(model={self.model!r}, base_url={self.base_url!r})"
+
+    def complete_json(
+        self,
+        prompt: str,
+        system: str,
+        temperature: float = 0.2,
+        max_output_tokens: int = 800,
+    ) -> tuple[dict[str, Any], dict[str, Any]]:
+        """
+        Ask the model for a JSON object.
+
+        Returns:
+            Tuple of (parsed JSON, usage dict with provider, model and token counts)
+
+        Raises:
+            AIError: On timeouts, HTTP errors left after retrying, and answers
+                that are not a JSON object
+        """
+        data = self._post(self._body(prompt, system, temperature, max_output_tokens))
+        try:
+            text, prompt_tokens, completion_tokens = self._parse(data)
+        except (KeyError, IndexError, TypeError) as e:
+            raise AIError(f"Could not parse {self.name} response structure: {e!r}") from e
+        if not text:
+            raise AIError(f"{self.name} returned an empty response")
+        try:
+            answer = json.loads(_strip_fence(text))
+        except json.JSONDecodeError as e:
+            raise AIError(f"Model did not return valid JSON: {e}\nRaw: {text[:400]}") from e
+        if not isinstance(answer, dict):
+            raise AIError(f"Model returned JSON {type(answer).__name__}, not an object")
+        return answer, {
+            "provider": self.name,
+            "model": self.model,
+            "prompt_tokens": prompt_tokens,
+            "completion_tokens": completion_tokens,
+            "total_tokens": prompt_tokens + completion_tokens,
+        }
+
+    def headers(self) -> dict[str, str]:
+        return {}
+
+    def _body(self, prompt: str, system: str, temperature: float, max_output_tokens: int) -> dict[str, Any]:
+        raise NotImplementedError
+
+    def _parse(self, data: dict[str, Any]) -> tuple[str, int, int]:
+        """The answer text, prompt tokens and completion tokens of a response."""
+        raise NotImplementedError
+
+    def _post(self, body: dict[str, Any]) -> dict[str, Any]:
+        url = self.base_url + self.path
+        retry = self.settings.retry
+        with httpx.Client(timeout=self.settings.timeout, transport=self._transport) as http:
+            attempt = 1
+            while True:
+                try:
+                    response = http.post(url, json=body, headers=self.headers())
+                except httpx.TimeoutException as e:
+                    raise AIError(f"{self.name} request timed out after {self.settings.timeout:g}s") from e
+                except httpx.HTTPError as e:
+                    raise AIError(f"{self.name} request to {self.base_url} failed: {type(e).__name__}: {e}") from e
+                status = response.status_code
+                if status not in retry.statuses or attempt >= retry.attempts:
+                    break
+                wait = retry.delay(attempt, retry_after_seconds(response.headers.get("retry-after")))
+                logger.warning(f"{self.name} answered HTTP {status}; retrying in {wait:.1f}s")
+                time.sleep(wait)
+                attempt += 1
+        if status >= 400:
+            raise AIError(f"{self.name} request failed with HTTP {status}: {_error_message(response)}")
+        try:
+            data = response.json()
+        except ValueError as e:
+            raise AIError(f"{self.name} returned a response that is not JSON") from e
+        if not isinstance(data, dict):
+            raise AIError(f"{self.name} returned an unexpected response")
+        return data
+
+
+class OpenAIProvider(Provider):
+    """Chat Completions, as served by OpenAI and compatible servers (vLLM, LM Studio, gateways)."""
+
+    name = "openai"
+    path = "/chat/completions"
+
+    def headers(self) -> dict[str, str]:
+        return {"Authorization": f"Bearer {self.api_key}"} if self.api_key else {}
+
+    def _body(self, prompt: str, system: str, temperature: float, max_output_tokens: int) -> dict[str, Any]:
+        return {
+            "model": self.model,
+            "messages": [
+                {"role": "system", "content": system},
+                {"role": "user", "content": prompt},
+            ],
+            "temperature": temperature,
+            "max_tokens": max_output_tokens,
+            "response_format": {"type": "json_object"},
+        }
+
+    def _parse(self, data: dict[str, Any]) -> tuple[str, int, int]:
+        usage = data.get("usage") or {}
+        return (
+            data["choices"][0]["message"]["content"],
+            usage.get("prompt_tokens") or 0,
+            usage.get("completion_tokens") or 0,
+        )
+
+
+class AnthropicProvider(Provider):
+    """Anthropic's Messages API; it has no JSON mode, so the answer is read from its text."""
+
+    name = "anthropic"
+    path = "/v1/messages"
+
+    def headers(self) -> dict[str, str]:
+        return {"x-api-key": self.api_key, "anthropic-version": ANTHROPIC_VERSION}
+
+    def _body(self, prompt: str, system: str, temperature: float, max_output_tokens: int) -> dict[str, Any]:
+        return {
+            "model": self.model,
+            "system": system,
+            "messages": [{"role": "user", "content": prompt}],
+            "temperature": temperature,
+            "max_tokens": max_output_tokens,
+        }
+
+    def _parse(self, data: dict[str, Any]) -> tuple[str, int, int]:
+        usage = data.get("usage") or {}
+        text = "".join(block["text"] for block in data["content"] if block.get("type") == "text")
+        return text, usage.get("input_tokens") or 0, usage.get("output_tokens") or 0
+
+
+class OllamaProvider(Provider):
+    """A local Ollama server's chat endpoint; nothing leaves the machine."""
+
+    name = "ollama"
+    path = "/api/chat"
+
+    def _body(self, prompt: str, system: str, temperature: float, max_output_tokens: int) -> dict[str, Any]:
+        return {
+            "model": self.model,
+            "messages": [
+                {"role": "system", "content": system},
+                {"role": "user", "content": prompt},
+            ],
+            "format": "json",
+            "stream": False,
+            "options": {"temperature": temperature, "num_predict": max_output_tokens},
+        }
+
+    def _parse(self, data: dict[str, Any]) -> tuple[str, int, int]:
+        return (
+            data["message"]["content"],
+            data.get("prompt_eval_count") or 0,
+            data.get("eval_count") or 0,
+        )
+
+
+_CLASSES: dict[str, type[Provider]] = {
+    "openai": OpenAIProvider,
+    "anthropic": AnthropicProvider,
+    "ollama": OllamaProvider,
+}
+
+
+def get_provider(
+    settings: ProviderSettings,
+    model: str | None = None,
+    api_key: str = "",
+    transport: httpx.BaseTransport | None = None,
+) -> Provider:
+    """
+    The provider the settings select.
+
+    Args:
+        settings: Provider, endpoint, timeout and retries
+        model: Model to use when settings.model is not set; None: DEFAULT_MODELS
+        api_key: Key of the provider; Ollama needs none, nor do some OpenAI-compatible servers
+        transport: HTTP transport (tests pass a mock one)
+
+    Raises:
+        AIError: If the provider needs a key and none was given
+    """
+    name = settings.provider
+    if name == "anthropic" and not api_key:
+        raise AIError("ANTHROPIC_API_KEY is not set (or run: keyring set tinyseoai anthropic)")
+    if name == "openai" and not api_key and settings.base_url is None:
+        raise AIError("OPENAI_API_KEY is not set (or run: keyring set tinyseoai openai)")
+    chosen = settings.model or model or DEFAULT_MODELS.get(name)
+    if not chosen:
+        raise AIError(f"No model configured for {name}")
+    return _CLASSES[name](settings, chosen, api_key, transport)
+
+
+def estimate_tokens(text: str) -> int:
+    """Rough token count of text (about four characters per token), for prompt budgets."""
+    return len(text) // 4 + 1
+
+
+def _strip_fence(text: str) -> str:
+    # Models without a JSON mode sometimes wrap the object in a ```json fence
+    text = text.strip()
+    if text.startswith("```"):
+        text = text.split("\n", 1)[1] if "\n" in text else ""
+        text = text.rsplit("```", 1)[0]
+    return text.strip()
+
+
+def _error_message(response: httpx.Response) -> str:
+    try:
+        data = response.json()
+    except ValueError:
+        return response.text[:200] or response.reason_phrase
+    error = data.get("error") if isinstance(data, dict) else None
+    if isinstance(error, dict):
+        return str(error.get("message") or error)[:200]
+    return str(error or data)[:200]
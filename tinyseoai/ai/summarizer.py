@@ -2,46 +2,24 @@ from __future__ import annotations
 
 import json
 from collections import Counter, defaultdict
+from datetime import datetime
 from typing import Any
+from urllib.parse import urlparse
 
-from ..config import get_config
+from ..config import AppConfig, get_config
 from ..data.models import AuditResult, Issue
-from .openai_client import call_ai_json
+from ..data.redact import RedactionOptions, Redactor, hosts_of
+from ..data.severity import count_by_severity
+from .providers import ProviderSettings, estimate_tokens, get_provider
 
+# Keys carried over into "history" when a summary is regenerated
+_HISTORY_KEYS = ("summary", "top_issues", "recommended_actions", "quick_wins",
+                 "risk_items", "language", "tone", "generated_at", "usage")
 
-def _compact_issues(issues: list[Issue], limit_per_type: int = 5) -> dict[str, Any]:
-    """
-    Compress issues for prompt efficiency:
-    - per-type counts
-    - sample URLs (up to limit_per_type)
-    """
-    counts = Counter(i.type for i in issues)
-    samples = defaultdict(list)
-    for i in issues:
-        if len(samples[i.type]) < limit_per_type:
-            samples[i.type].append(i.url)
+# Samples per issue type tried, largest first, before dropping the rarest types
+_SAMPLE_LIMITS = (5, 3, 1, 0)
 
-    return {
-        "by_type_counts": counts,
-        "by_type_samples": samples,
-        "total": len(issues),
-    }
-
-
-def build_prompt(result: AuditResult) -> str:
-    compact = _compact_issues(result.issues)
-    payload = {
-        "site": result.site,
-        "pages_scanned": result.pages_scanned,
-        "issues_compact": compact,
-        "metrics_hint": {
-            # Space for future numeric metrics if you add them later
-        },
-        "goal": "Create an executive summary for a client who is not deeply technical. "
-                "Focus on impact and next steps."
-    }
-    # Keep it concise and deterministic
-    instructions = """
+_INSTRUCTIONS = """
 Return STRICT JSON with this schema:
 {
   "site": string,
@@ -57,20 +35,184 @@ Return STRICT JSON with this schema:
 }
 Only include the JSON object. No commentary.
 """
-    return instructions + "\nINPUT:\n" + json.dumps(payload, ensure_ascii=False)
 
 
-def summarize_with_ai(result: AuditResult) -> dict[str, Any]:
+def _compact_issues(
+    issues: list[Issue], limit_per_type: int = 5, max_types: int | None = None
+) -> dict[str, Any]:
+    """
+    Compress issues for prompt efficiency:
+    - per-type counts, most frequent first (only the first max_types)
+    - sample URLs (up to limit_per_type)
+    """
+    counts = Counter(i.type for i in issues).most_common(max_types)
+    kept = {t for t, _ in counts}
+    samples = defaultdict(list)
+    if limit_per_type:
+        for i in issues:
+            if i.type in kept and len(samples[i.type]) < limit_per_type:
+                samples[i.type].append(i.url)
+
+    compact = {
+        "by_type_counts": dict(counts),
+        "by_type_samples": samples,
+        "total": len(issues),
+    }
+    omitted = len({i.type for i in issues}) - len(kept)
+    if omitted:
+        compact["types_omitted"] = omitted
+    return compact
+
+
+def _metrics(result: AuditResult) -> dict[str, Any]:
+    metrics: dict[str, Any] = {"by_severity": count_by_severity(i.severity for i in result.issues)}
+    score = result.meta.get("health_score")
+    if isinstance(score, int | float):
+        metrics["health_score"] = score
+    return metrics
+
+
+def build_prompt(
+    result: AuditResult,
+    language: str | None = None,
+    tone: str | None = None,
+    token_budget: int | None = None,
+    redact_urls: bool = False,
+) -> str:
+    """
+    The summary prompt: instructions plus the result's metrics and issues as compact JSON.
+
+    Args:
+        result: Audit to summarize
+        language: Language of the summary text
+        tone: Tone of the summary
+        token_budget: Estimated prompt tokens to stay within; fewer sample
+            URLs, then fewer (the rarest) issue types are sent until it fits
+        redact_urls: Mask hostnames and strip query strings, so no URL of
+            the site leaves the machine
+    """
+    goal = (
+        "Create an executive summary for a client who is not deeply technical. "
+        "Focus on impact and next steps."
+    )
+    if tone:
+        goal += f" Use a {tone} tone."
+    if language:
+        goal += f" Write every string value in {language}; keep JSON keys in English."
+
+    redactor = None
+    if redact_urls:
+        options = RedactionOptions(strip_query_strings=True, mask_hostnames=True)
+        site = urlparse(result.site).hostname
+        hosts = hosts_of([result.site, *(i.url for i in result.issues)])
+        redactor = Redactor(options, ([site] if site else []) + hosts)
+
+    types = len({i.type for i in result.issues})
+    steps = [(limit, None) for limit in _SAMPLE_LIMITS]
+    while types > 1:
+        types //= 2
+        steps.append((0, types))
+
+    for limit, max_types in steps:
+        payload = {
+            "site": result.site,
+            "pages_scanned": result.pages_scanned,
+            "issues_compact": _compact_issues(result.issues, limit, max_types),
+            "metrics_hint": _metrics(result),
+            "goal": goal,
+        }
+        if redactor is not None:
+            payload = redactor.value(payload)
+        # Keep it concise and deterministic
+        prompt = _INSTRUCTIONS + "\nINPUT:\n" + json.dumps(payload, ensure_ascii=False)
+        if token_budget is None or estimate_tokens(prompt) <= token_budget:
+            break
+    return prompt
+
+
+def _api_key(cfg: AppConfig, provider: str) -> str:
+    if provider == "openai":
+        return cfg.openai_api_key
+    if provider == "anthropic":
+        return cfg.anthropic_api_key
+    return ""
+
+
+def summarize_with_ai(
+    result: AuditResult,
+    language: str | None = None,
+    tone: str | None = None,
+    settings: ProviderSettings | None = None,
+) -> dict[str, Any]:
+    """
+    Write the executive summary of a result with the configured AI provider.
+
+    Args:
+        result: Audit to summarize
+        language: Language of the summary text
+        tone: Tone of the summary
+        settings: Provider to use instead of the config's "ai" section
+
+    Raises:
+        AIError: If the provider is not set up, or its request fails
+    """
     cfg = get_config()
     plan = cfg.plan  # 'free' or 'premium'
+    settings = settings or cfg.ai
 
-    prompt = build_prompt(result)
-    data = call_ai_json(
-        prompt=prompt,
-        plan=plan,
+    prompt = build_prompt(
+        result,
+        language=language,
+        tone=tone,
+        token_budget=settings.prompt_token_budget,
+        redact_urls=settings.redact_urls,
+    )
+    plan_model = cfg.openai_model_premium if plan == "premium" else cfg.openai_model_free
+    provider = get_provider(
+        settings,
+        model=plan_model if settings.provider == "openai" else None,
+        api_key=_api_key(cfg, settings.provider),
+    )
+    data, usage = provider.complete_json(
+        prompt,
         system="You are an expert technical SEO who writes concise, client-ready summaries.",
         temperature=0.2,
+        max_output_tokens=cfg.max_output_tokens,
     )
     # Attach plan + model info for traceability (optional)
     data["plan_used"] = plan
+    if language:
+        data["language"] = language
+    if tone:
+        data["tone"] = tone
+    if settings.redact_urls:
+        data["urls_redacted"] = True
+    data["usage"] = usage
+    data["generated_at"] = datetime.utcnow().isoformat() + "Z"
     return data
+
+
+def archive_previous_summary(
+    previous: dict[str, Any] | None, current: dict[str, Any]
+) -> dict[str, Any]:
+    """
+    Keep the previous AI summary when a new one replaces it.
+
+    The prior narrative is appended to ``current["history"]`` (oldest first),
+    together with any history it already carried, so regenerating a summary
+    in another language or tone never loses earlier text.
+
+    Args:
+        previous: Previously saved summary_with_ai.json content (or None)
+        current: Freshly generated summary
+
+    Returns:
+        The current summary with its history attached
+    """
+    if not previous:
+        return current
+
+    history = list(previous.get("history", []))
+    history.append({k: previous[k] for k in _HISTORY_KEYS if k in previous})
+    current["history"] = history
+    return current
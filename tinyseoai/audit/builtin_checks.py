@@ -0,0 +1,267 @@
+"""
+Built-in site-level checks, in the order the comprehensive audit runs them.
+
+Per-page checks still run during the crawl (engine_v2._run_page_checks);
+these need the crawl as a whole, or the network once the crawl is done.
+"""
+from __future__ import annotations
+
+from ..data.models import Issue
+from ..utils.http import client as http_client
+from .checks.amp import analyze as analyze_amp
+from .checks.anchors import analyze as analyze_anchors
+from .checks.caching import Resource
+from .checks.caching import analyze as analyze_caching
+from .checks.caching import collect as collect_assets
+from .checks.canonicalization import analyze as analyze_canonicalization
+from .checks.compression import analyze as analyze_compression
+from .checks.content import (
+    ContentAnalyzer,
+    DuplicateContentDetector,
+    boilerplate_issues,
+    visible_text,
+)
+from .checks.duplicates import PageMeta
+from .checks.duplicates import analyze as analyze_duplicates
+from .checks.feeds import analyze as analyze_feeds
+from .checks.icons import analyze as analyze_icons
+from .checks.indexability import analyze as analyze_indexability
+from .checks.keywords import analyze as analyze_keywords
+from .checks.lang import analyze as analyze_lang
+from .checks.linkgraph import analyze as analyze_link_graph
+from .checks.pagination import analyze as analyze_pagination
+from .checks.params import analyze as analyze_params
+from .checks.params import candidates, fingerprint
+from .checks.protocol import detect as detect_protocol
+from .checks.protocol import protocol_issues
+from .checks.redirects import analyze as analyze_redirect_types
+from .checks.soft404 import analyze as analyze_soft404
+from .checks.timing import measure, timing_issues
+from .checks.tls import analyze as analyze_tls
+from .checks_config import ChecksConfig
+from .crawler import extract_links
+from .registry import CheckContext, CheckRegistry, FunctionCheck, Requirements
+
+CRAWL = Requirements(crawl=True)
+COMPREHENSIVE = Requirements(crawl=True, comprehensive=True)
+NETWORK = Requirements(network=True, comprehensive=True)
+HOME_PAGE = Requirements(network=True, page=True, comprehensive=True)
+CRAWL_NETWORK = Requirements(crawl=True, network=True, comprehensive=True)
+
+
+def _settings(ctx: CheckContext) -> ChecksConfig:
+    return ctx.checks or ChecksConfig()
+
+
+def _indexability(ctx: CheckContext) -> list[Issue]:
+    return analyze_indexability(ctx.pages, ctx.robots, _settings(ctx).indexability)
+
+
+def _duplicate_meta(ctx: CheckContext) -> list[Issue]:
+    return analyze_duplicates(
+        [PageMeta(url=p.url, title=p.title, description=p.meta_desc) for p in ctx.pages],
+        _settings(ctx).duplicate_meta,
+    )
+
+
+def _duplicate_content(ctx: CheckContext) -> list[Issue]:
+    detector = DuplicateContentDetector()
+    for page in ctx.pages:
+        if page.html:
+            detector.add_page(page.url, ContentAnalyzer(page.html, page.url).text)
+    issues = detector.find_duplicates()
+    # Near-duplicates compare every pair, so only for smaller sites
+    settings = _settings(ctx).duplicate_content
+    if len(ctx.pages) < settings.max_near_pages:
+        issues.extend(detector.find_near_duplicates(threshold=settings.near_threshold))
+    return issues
+
+
+def _boilerplate(ctx: CheckContext) -> list[Issue]:
+    issues = []
+    texts = [(p.url, visible_text(p.html)) for p in ctx.pages if p.html]
+    for i, (url, text) in enumerate(texts):
+        others = [t for j, (_, t) in enumerate(texts) if j != i]
+        issues.extend(boilerplate_issues(url, text, others, _settings(ctx).content))
+    return issues
+
+
+def _pagination(ctx: CheckContext) -> list[Issue]:
+    return analyze_pagination(ctx.pages, _settings(ctx).pagination)
+
+
+def _params(ctx: CheckContext) -> list[Issue]:
+    settings = _settings(ctx).params
+    urls = [p.url for p in ctx.pages]
+    # Only paths crawled with parameters are compared, so only their pages are parsed
+    wanted = candidates(urls)
+    fingerprints = {
+        p.url: fingerprint(p, settings.shingle_size)
+        for p in ctx.pages
+        if p.url in wanted and p.html
+    }
+    return analyze_params(urls, fingerprints, settings)
+
+
+def _lang(ctx: CheckContext) -> list[Issue]:
+    return analyze_lang(ctx.pages, _settings(ctx).lang)
+
+
+def _keywords(ctx: CheckContext) -> list[Issue]:
+    if ctx.keywords is None:
+        return []
+    analysis = analyze_keywords(ctx.pages, ctx.keywords, _settings(ctx).keywords)
+    ctx.meta["keywords"] = analysis.meta()
+    return analysis.issues
+
+
+def _links(ctx: CheckContext) -> list[Issue]:
+    return analyze_link_graph(ctx.pages, ctx.seed_url, _settings(ctx).links)
+
+
+def _anchors(ctx: CheckContext) -> list[Issue]:
+    return analyze_anchors(ctx.pages, ctx.seed_url, _settings(ctx).anchors)
+
+
+async def _timing(ctx: CheckContext) -> list[Issue]:
+    # A fresh client, so the first sample includes DNS, TCP, and TLS
+    options = _settings(ctx).timing
+    async with http_client(ctx.http, follow_redirects=True) as client:
+        timing = await measure(ctx.seed_url, client, options)
+    ctx.meta["performance"] = timing.metrics()
+    return timing_issues(timing, options)
+
+
+async def _tls(ctx: CheckContext) -> list[Issue]:
+    return await analyze_tls(ctx.seed_url, _settings(ctx).tls)
+
+
+async def _protocol(ctx: CheckContext) -> list[Issue]:
+    # The home page's headers carry Alt-Svc; its host is where visitors end up
+    home = ctx.home
+    support = await detect_protocol(home.url, home.headers, _settings(ctx).protocol)
+    if support is not None:
+        ctx.meta.setdefault("performance", {})["protocol"] = support.metrics()
+    return protocol_issues(support, home.url)
+
+
+async def _icons(ctx: CheckContext) -> list[Issue]:
+    return await analyze_icons(ctx.home.html, ctx.home.url, ctx.client, _settings(ctx).icons)
+
+
+async def _compression(ctx: CheckContext) -> list[Issue]:
+    return await analyze_compression(ctx.home.url, ctx.client, _settings(ctx).compression)
+
+
+async def _caching(ctx: CheckContext) -> list[Issue]:
+    resources = [
+        Resource.from_headers(p.url, p.headers or {}, kind="document") for p in ctx.pages if p.html
+    ]
+    options = _settings(ctx).caching
+    resources.extend(await collect_assets(ctx.home.html, ctx.home.url, ctx.client, options))
+    return analyze_caching(resources, options)
+
+
+async def _soft404(ctx: CheckContext) -> list[Issue]:
+    return await analyze_soft404(ctx.pages, ctx.client, _settings(ctx).soft404)
+
+
+async def _redirect_types(ctx: CheckContext) -> list[Issue]:
+    # Crawled URLs that redirected, with the pages linking to them
+    redirected = {p.url: [] for p in ctx.pages if p.final_url}
+    for page in ctx.pages:
+        if page.html:
+            for link in extract_links(page.html, page.final_url or page.url):
+                if link in redirected and link != page.url:
+                    redirected[link].append(page.url)
+    return await analyze_redirect_types(redirected, ctx.client, _settings(ctx).redirect_types)
+
+
+async def _canonicalization(ctx: CheckContext) -> list[Issue]:
+    options = _settings(ctx).canonicalization
+    report = await analyze_canonicalization(ctx.seed_url, ctx.client, ctx.pages, options)
+    ctx.meta["canonicalization"] = report.meta()
+    return report.issues
+
+
+async def _feeds(ctx: CheckContext) -> list[Issue]:
+    return await analyze_feeds(ctx.home.html, ctx.home.url, ctx.client, _settings(ctx).feeds)
+
+
+async def _amp(ctx: CheckContext) -> list[Issue]:
+    return await analyze_amp(ctx.pages, ctx.client, _settings(ctx).amp)
+
+
+def builtin_checks() -> list[FunctionCheck]:
+    """The built-in checks, in run order."""
+    return [
+        FunctionCheck(
+            "indexability",
+            _indexability,
+            CRAWL,
+            "Robots meta and X-Robots-Tag together; noindex on important pages",
+        ),
+        FunctionCheck(
+            "duplicate_meta", _duplicate_meta, CRAWL, "Duplicate titles and descriptions"
+        ),
+        FunctionCheck(
+            "duplicate_content", _duplicate_content, COMPREHENSIVE, "Exact and near-duplicate pages"
+        ),
+        FunctionCheck(
+            "boilerplate", _boilerplate, COMPREHENSIVE, "Pages mostly made of shared text"
+        ),
+        FunctionCheck(
+            "pagination",
+            _pagination,
+            COMPREHENSIVE,
+            "Paginated series: canonicals, rel=next/prev chains, titles, noindex",
+        ),
+        FunctionCheck(
+            "params", _params, COMPREHENSIVE, "Query parameters that only duplicate content"
+        ),
+        FunctionCheck("lang", _lang, COMPREHENSIVE, "<html lang>, text language, and hreflang"),
+        FunctionCheck(
+            "keywords",
+            _keywords,
+            CRAWL,
+            "Target keywords (--keywords) in titles, H1s, first paragraphs, and descriptions",
+        ),
+        FunctionCheck(
+            "links", _links, COMPREHENSIVE, "Internal link graph: orphans, dead ends, click depth"
+        ),
+        FunctionCheck(
+            "anchors", _anchors, COMPREHENSIVE, "Anchor text of internal links, per target page"
+        ),
+        FunctionCheck("timing", _timing, NETWORK, "Response time of the start URL"),
+        FunctionCheck("tls", _tls, NETWORK, "TLS certificate, protocol, and DNS records"),
+        FunctionCheck(
+            "protocol", _protocol, HOME_PAGE, "HTTP/2 via ALPN, and HTTP/3 advertised by Alt-Svc"
+        ),
+        FunctionCheck("icons", _icons, HOME_PAGE, "Favicon, web app manifest, and theme-color"),
+        FunctionCheck(
+            "compression", _compression, HOME_PAGE, "Compression of the page and its CSS and JS"
+        ),
+        FunctionCheck("caching", _caching, HOME_PAGE, "Cache headers of pages and static assets"),
+        FunctionCheck("soft404", _soft404, HOME_PAGE, "Pages that return 200 but say not found"),
+        FunctionCheck(
+            "redirect_types",
+            _redirect_types,
+            CRAWL_NETWORK,
+            "Internal links through temporary or mixed-type redirects",
+        ),
+        FunctionCheck(
+            "canonicalization",
+            _canonicalization,
+            CRAWL_NETWORK,
+            "www/apex, http/https, and trailing-slash variants redirect one way",
+        ),
+        FunctionCheck("feeds", _feeds, HOME_PAGE, "RSS and Atom feeds the home page declares"),
+        FunctionCheck(
+            "amp", _amp, HOME_PAGE, "AMP versions: rel=amphtml links and their structure"
+        ),
+    ]
+
+
+def default_registry() -> CheckRegistry:
+    """A registry with every built-in check enabled."""
+    return CheckRegistry(builtin_checks())
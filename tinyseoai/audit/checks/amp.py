@@ -0,0 +1,173 @@
+"""
+AMP variants: follow rel=amphtml links and check the AMP pages' structure.
+
+For each crawled page with <link rel="amphtml">, the AMP page is fetched
+and checked for the basics a publisher most often breaks: the <html amp>
+(or <html ⚡>) attribute, the AMP runtime script, a canonical link back to
+the page that points at it, and no scripts AMP does not allow. This is a
+lightweight structural check, not the AMP validator, and the findings say
+so; a page passing it can still be invalid AMP.
+"""
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+from urllib.parse import urljoin, urlparse
+
+import httpx
+from bs4 import BeautifulSoup, Tag
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import msg
+from ...utils import urlnorm
+from ...utils.http import client as http_client
+
+if TYPE_CHECKING:
+    from ..crawler import FetchedPage
+
+AMP_CDN = "cdn.ampproject.org"
+# The runtime: https://cdn.ampproject.org/v0.js (or v0.mjs, or under /lts/)
+_RUNTIME_PATHS = ("/v0.js", "/v0.mjs", "/lts/v0.js", "/lts/v0.mjs")
+# Script types AMP allows inline: structured data, amp-state and friends, amp-script sources
+_DATA_SCRIPT_TYPES = ("application/ld+json", "application/json", "text/plain")
+
+
+class AmpOptions(BaseModel):
+    """Limits for analyze."""
+
+    max_pages: int = Field(default=50, ge=0)  # AMP pages fetched per audit
+    timeout: float = 10.0
+
+
+class AmpPage(BaseModel):
+    """The structure of one AMP page, as far as this check looks."""
+
+    url: str
+    amp_attribute: bool
+    runtime: bool
+    canonical: str | None = None  # resolved href of the single canonical link
+    disallowed_scripts: list[str] = Field(default_factory=list)  # src, or "inline"
+
+
+def amphtml_link(url: str, html: str) -> str | None:
+    """The absolute URL of a page's first rel=amphtml link, if any."""
+    soup = BeautifulSoup(html, "lxml")
+    for tag in soup.find_all("link", href=True):
+        if "amphtml" in [r.lower() for r in tag.get("rel") or []]:
+            href = tag["href"].strip()
+            if href:
+                return urljoin(url, href)
+    return None
+
+
+def _is_runtime(src: str) -> bool:
+    parsed = urlparse(src)
+    return parsed.netloc == AMP_CDN and parsed.path in _RUNTIME_PATHS
+
+
+def _allowed_script(tag: Tag) -> bool:
+    src = (tag.get("src") or "").strip()
+    if src:
+        extension = tag.has_attr("custom-element") or tag.has_attr("custom-template")
+        return urlparse(src).netloc == AMP_CDN and (extension or _is_runtime(src))
+    return (tag.get("type") or "").strip().lower() in _DATA_SCRIPT_TYPES
+
+
+def parse_amp(url: str, html: str) -> AmpPage:
+    """Read the attributes, scripts, and canonical link of an AMP page."""
+    soup = BeautifulSoup(html, "lxml")
+    root = soup.find("html")
+    attribute = root is not None and (root.has_attr("amp") or root.has_attr("⚡"))
+    scripts = soup.find_all("script")
+    canonicals = [
+        (tag.get("href") or "").strip()
+        for tag in soup.find_all("link", href=True)
+        if "canonical" in [r.lower() for r in tag.get("rel") or []]
+    ]
+    return AmpPage(
+        url=url,
+        amp_attribute=attribute,
+        runtime=any(_is_runtime((s.get("src") or "").strip()) for s in scripts),
+        canonical=urljoin(url, canonicals[0]) if len(canonicals) == 1 and canonicals[0] else None,
+        disallowed_scripts=[
+            (s.get("src") or "").strip() or "inline" for s in scripts if not _allowed_script(s)
+        ],
+    )
+
+
+def validate(page: AmpPage, original: str) -> list[Issue]:
+    """
+    Structural findings for an AMP page linked from original.
+
+    Returns:
+        amp_attribute_missing, amp_runtime_missing, and amp_disallowed_script
+        on the AMP page; amp_canonical_missing or amp_canonical_mismatch on
+        the original page when the AMP page does not point back to it
+    """
+    issues = []
+
+    def add(url: str, issue_type: str, severity: str, *args) -> None:
+        fields = msg(f"amp.{issue_type}", *args)
+        issues.append(Issue(url=url, type=issue_type, severity=severity, **fields))
+
+    if not page.amp_attribute:
+        add(page.url, "amp_attribute_missing", "high")
+    if not page.runtime:
+        add(page.url, "amp_runtime_missing", "high")
+    if page.disallowed_scripts:
+        scripts = page.disallowed_scripts
+        add(page.url, "amp_disallowed_script", "high", len(scripts), ", ".join(scripts))
+
+    if page.canonical is None:
+        add(original, "amp_canonical_missing", "medium", page.url)
+    elif urlnorm.normalize(page.canonical) != urlnorm.normalize(original):
+        # Tracking parameters are dropped, so ?utm_source=amp still points back
+        add(original, "amp_canonical_mismatch", "medium", page.url, page.canonical)
+    return issues
+
+
+async def analyze(
+    pages: list[FetchedPage],
+    client: httpx.AsyncClient | None = None,
+    options: AmpOptions | None = None,
+) -> list[Issue]:
+    """
+    Fetch the AMP version of every crawled page that links one and check it.
+
+    Args:
+        pages: Fetched pages (anything with url and html)
+        client: HTTP client (one is created when omitted)
+        options: Page limit and timeout
+
+    Returns:
+        amphtml_broken for AMP links that fail or do not return HTML, and the
+        structural findings of validate for the others
+    """
+    options = options or AmpOptions()
+    if client is None:
+        async with http_client() as own:
+            return await analyze(pages, own, options)
+
+    pairs = {}  # AMP URL -> the first page linking it
+    for page in pages:
+        amp_url = amphtml_link(page.url, page.html) if page.html else None
+        if amp_url and amp_url != page.url:
+            pairs.setdefault(amp_url, page.url)
+
+    issues = []
+    for amp_url, original in list(pairs.items())[: options.max_pages]:
+        try:
+            response = await client.get(amp_url, timeout=options.timeout, follow_redirects=True)
+        except httpx.HTTPError as e:
+            fields = msg("amp.amphtml_broken", amp_url, type(e).__name__)
+            issues.append(Issue(url=original, type="amphtml_broken", severity="high", **fields))
+            continue
+        content_type = response.headers.get("content-type", "text/html")
+        if response.status_code >= 400 or "html" not in content_type:
+            failed = response.status_code >= 400
+            reason = f"HTTP {response.status_code}" if failed else content_type
+            fields = msg("amp.amphtml_broken", amp_url, reason)
+            issues.append(Issue(url=original, type="amphtml_broken", severity="high", **fields))
+            continue
+        issues.extend(validate(parse_amp(str(response.url), response.text), original))
+    return issues
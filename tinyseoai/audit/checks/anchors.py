@@ -0,0 +1,279 @@
+"""
+Anchor text of internal links, aggregated per target page.
+
+Every internal link on the crawled pages is counted under the page it
+points to, with its text normalized (whitespace collapsed, case folded).
+An image link without text counts with the image's alt. Per target, the
+check reports:
+
+- generic_anchor_text: most links use a stop phrase such as "read more",
+  or the bare URL
+- inconsistent_anchor_text: many links, hardly two with the same text
+- empty_anchor_text: links with neither text nor image alt
+- long_anchor_text: links whose text runs past max_anchor_length
+
+Memory stays bounded on large crawls: at most max_targets pages are
+tracked, each with at most max_texts distinct texts and max_examples
+source pages per finding; links beyond that are counted but not kept.
+"""
+from __future__ import annotations
+
+import re
+from collections import Counter
+from dataclasses import dataclass, field
+from typing import TYPE_CHECKING
+from urllib.parse import urljoin, urlparse
+
+from bs4 import BeautifulSoup
+from loguru import logger
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import listing, msg
+from ...utils import urlnorm
+
+if TYPE_CHECKING:
+    from ..crawler import FetchedPage
+
+# Anchor texts that say nothing about the target, lowercase, by language
+GENERIC_ANCHORS = (
+    # English
+    "click here",
+    "here",
+    "read more",
+    "learn more",
+    "more",
+    "more info",
+    "continue reading",
+    "this",
+    "link",
+    "this link",
+    "go",
+    "details",
+    # Spanish
+    "haz clic aquí",
+    "clic aquí",
+    "aquí",
+    "leer más",
+    "seguir leyendo",
+    "más información",
+    "ver más",
+    "más",
+    # Portuguese, French, German
+    "clique aqui",
+    "saiba mais",
+    "cliquez ici",
+    "en savoir plus",
+    "lire la suite",
+    "hier klicken",
+    "mehr erfahren",
+    "weiterlesen",
+)
+
+_SKIPPED_HREFS = ("#", "javascript:", "mailto:", "tel:", "data:", "sms:")
+_EDGE_PUNCTUATION = re.compile(r"^[\W_]+|[\W_]+$")  # "Read more »" reads as "read more"
+_BARE_URL = re.compile(r"^(https?://|www\.)\S+$")
+
+
+class AnchorOptions(BaseModel):
+    """Thresholds and memory bounds for analyze."""
+
+    generic_phrases: list[str] = Field(default_factory=lambda: list(GENERIC_ANCHORS))
+    generic_share: float = Field(default=0.5, gt=0.0, le=1.0)  # of a target's links, to report
+    inconsistent_min_links: int = Field(default=5, ge=2)  # descriptive links before judging
+    inconsistent_top_share: float = Field(default=0.25, gt=0.0, le=1.0)  # most common text below
+    max_anchor_length: int = Field(default=100, ge=10)  # characters
+    max_targets: int = Field(default=50_000, ge=1)
+    max_texts: int = Field(default=50, ge=1)  # distinct texts kept per target
+    max_examples: int = Field(default=3, ge=1)  # source pages named per finding
+
+
+def normalize_anchor(text: str) -> str:
+    """Collapse whitespace and fold case: "  Read\\n MORE " -> "read more"."""
+    return " ".join(text.split()).casefold()
+
+
+def anchor_text(anchor) -> str:
+    """The text of an <a> element, else the alt of the images inside it; whitespace collapsed."""
+    text = " ".join(anchor.get_text(" ").split())
+    if text:
+        return text
+    alts = [img.get("alt", "").strip() for img in anchor.find_all("img")]
+    return " ".join(a for a in alts if a)
+
+
+@dataclass
+class TargetAnchors:
+    """Anchor texts of the links to one page."""
+
+    url: str  # as first linked
+    links: int = 0
+    texts: Counter = field(default_factory=Counter)  # normalized text -> links, at most max_texts
+    generic: Counter = field(default_factory=Counter)
+    empty: int = 0
+    long: list[str] = field(default_factory=list)  # examples
+    long_count: int = 0
+    sources: dict[str, list[str]] = field(default_factory=dict)  # finding -> example pages
+
+    def example(self, kind: str, source: str, limit: int) -> None:
+        examples = self.sources.setdefault(kind, [])
+        if source not in examples and len(examples) < limit:
+            examples.append(source)
+
+    @property
+    def descriptive(self) -> int:
+        return self.links - self.empty - sum(self.generic.values())
+
+
+class AnchorIndex:
+    """Anchor texts of internal links by target page, within the options' memory bounds."""
+
+    def __init__(self, host: str, options: AnchorOptions | None = None):
+        self.host = host
+        self.options = options or AnchorOptions()
+        self.generic = {_bare(normalize_anchor(p)) for p in self.options.generic_phrases}
+        self.targets: dict[str, TargetAnchors] = {}
+        self.dropped = 0  # links to targets past max_targets
+
+    def add(self, source: str, target_url: str, text: str) -> None:
+        """Count one link from source to target_url with its anchor text."""
+        key = urlnorm.normalize(target_url)
+        target = self.targets.get(key)
+        if target is None:
+            if len(self.targets) >= self.options.max_targets:
+                self.dropped += 1
+                return
+            target = self.targets[key] = TargetAnchors(url=target_url)
+        options = self.options
+        target.links += 1
+        normalized = normalize_anchor(text)
+        if not normalized:
+            target.empty += 1
+            target.example("empty", source, options.max_examples)
+            return
+        phrase = _bare(normalized)
+        if phrase in self.generic or _BARE_URL.match(normalized):
+            target.generic[phrase if phrase in self.generic else normalized] += 1
+            target.example("generic", source, options.max_examples)
+        elif normalized in target.texts or len(target.texts) < options.max_texts:
+            target.texts[normalized] += 1
+            target.example("inconsistent", source, options.max_examples)
+        if len(text) > options.max_anchor_length:
+            target.long_count += 1
+            if len(target.long) < options.max_examples:
+                target.long.append(text)
+            target.example("long", source, options.max_examples)
+
+    def add_page(self, page: FetchedPage) -> None:
+        """Count the internal links of a crawled page."""
+        soup = BeautifulSoup(page.html, "lxml")
+        source = urlnorm.normalize(page.url)
+        for anchor in soup.find_all("a", href=True):
+            href = anchor.get("href").strip()
+            if not href or href.lower().startswith(_SKIPPED_HREFS):
+                continue
+            target = urljoin(page.url, href)
+            if urlparse(target).netloc.lower() != self.host:
+                continue
+            if urlnorm.normalize(target) != source:  # links to the page itself say nothing
+                self.add(page.url, target, anchor_text(anchor))
+
+
+def _bare(text: str) -> str:
+    return _EDGE_PUNCTUATION.sub("", text)
+
+
+def target_issues(target: TargetAnchors, options: AnchorOptions) -> list[Issue]:
+    """Findings for the links to one page."""
+    issues = []
+    sources = {kind: listing(pages, options.max_examples) for kind, pages in target.sources.items()}
+    generic = sum(target.generic.values())
+    if generic and generic >= target.links * options.generic_share:
+        phrase = target.generic.most_common(1)[0][0]
+        issues.append(
+            Issue(
+                url=target.url,
+                type="generic_anchor_text",
+                severity="low",
+                **msg(
+                    "anchors.generic_anchor_text",
+                    generic,
+                    target.links,
+                    phrase,
+                    sources["generic"],
+                ),
+            )
+        )
+    if target.descriptive >= options.inconsistent_min_links and target.texts:
+        top = target.texts.most_common(1)[0][1]
+        if top < target.descriptive * options.inconsistent_top_share:
+            texts = [f'"{text}"' for text, _ in target.texts.most_common(options.max_examples)]
+            issues.append(
+                Issue(
+                    url=target.url,
+                    type="inconsistent_anchor_text",
+                    severity="info",
+                    **msg(
+                        "anchors.inconsistent_anchor_text",
+                        target.descriptive,
+                        len(target.texts),
+                        texts,
+                        sources["inconsistent"],
+                    ),
+                )
+            )
+    if target.empty:
+        issues.append(
+            Issue(
+                url=target.url,
+                type="empty_anchor_text",
+                severity="low",
+                **msg("anchors.empty_anchor_text", target.empty, sources["empty"]),
+            )
+        )
+    if target.long_count:
+        example = target.long[0][: options.max_anchor_length] + "…"
+        issues.append(
+            Issue(
+                url=target.url,
+                type="long_anchor_text",
+                severity="info",
+                **msg(
+                    "anchors.long_anchor_text",
+                    target.long_count,
+                    options.max_anchor_length,
+                    example,
+                    sources["long"],
+                ),
+            )
+        )
+    return issues
+
+
+def analyze(
+    pages: list[FetchedPage], home_url: str, options: AnchorOptions | None = None
+) -> list[Issue]:
+    """
+    Check the anchor text of internal links, per target page.
+
+    Args:
+        pages: Crawled pages; links are read from those that loaded as HTML
+        home_url: Start URL; links to its host are internal
+        options: Stop phrases, thresholds, and memory bounds
+
+    Returns:
+        Findings on the target pages, each naming example source pages
+    """
+    options = options or AnchorOptions()
+    index = AnchorIndex(urlparse(home_url).netloc.lower(), options)
+    for page in pages:
+        if page.html and 200 <= page.status < 300:
+            index.add_page(page)
+    if index.dropped:
+        logger.info(
+            f"Anchor text of {index.dropped} links past {options.max_targets} targets not checked"
+        )
+    issues = []
+    for target in index.targets.values():
+        issues.extend(target_issues(target, options))
+    return issues
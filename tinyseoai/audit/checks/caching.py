@@ -0,0 +1,267 @@
+"""
+Cache-Control and Expires on pages and their static assets.
+
+Assets whose filenames carry a content hash (app.3f9a1c2b.js,
+index-BxT9kq2L.css) never change at their URL, so they should be cached
+for a long time and marked immutable. Pages should not use no-store unless
+they are private. Header names are matched case-insensitively, directives
+case-insensitively, and when a directive repeats the first occurrence wins.
+"""
+from __future__ import annotations
+
+import re
+from datetime import datetime, timezone
+from email.utils import parsedate_to_datetime
+from urllib.parse import urljoin, urlparse
+
+import httpx
+from bs4 import BeautifulSoup
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import fragment, listing, msg
+from ...utils.http import client as http_client
+from .headers import HeaderInput, header_values
+
+_FINGERPRINT = re.compile(r"[.\-_~]([0-9a-f]{8,64}|[A-Za-z0-9]{8,32})\.[a-z0-9]{1,6}$")
+_DIRECTIVE = re.compile(r'\s*([^=,\s]+)(?:\s*=\s*("[^"]*"|[^,]*))?\s*(?:,|$)')
+
+
+class CachingOptions(BaseModel):
+    """Thresholds and probing limits for analyze and collect."""
+
+    short_max_age: int = Field(default=86_400, ge=0)  # fingerprinted assets cached less are short
+    max_assets: int = Field(default=20, ge=0)  # assets requested by collect
+    max_listed: int = Field(default=10, ge=1)  # URLs named in a finding before "and N more"
+    timeout: float = 10.0
+
+
+class Resource(BaseModel):
+    """Response headers of one page or asset."""
+
+    url: str
+    kind: str = "asset"  # "document" or "asset"
+    headers: dict[str, list[str]] = Field(default_factory=dict)  # lowercase name -> values
+
+    @classmethod
+    def from_headers(cls, url: str, headers: HeaderInput, kind: str = "asset") -> Resource:
+        return cls(url=url, kind=kind, headers=header_values(headers))
+
+    def header(self, name: str) -> str | None:
+        """The first value of a header, or None."""
+        values = self.headers.get(name.lower())
+        return values[0] if values else None
+
+
+def parse_cache_control(values: list[str] | str) -> dict[str, str | None]:
+    """
+    Directives of one or more Cache-Control values.
+
+    Names are lowercased and quotes removed from values; the first
+    occurrence of a repeated directive wins.
+    """
+    directives: dict[str, str | None] = {}
+    for value in [values] if isinstance(values, str) else values:
+        for match in _DIRECTIVE.finditer(value):
+            name, argument = match.group(1).lower(), match.group(2)
+            if argument is not None:
+                argument = argument.strip().strip('"')
+            directives.setdefault(name, argument)
+    return directives
+
+
+def _seconds(value: str | None) -> int | None:
+    return int(value) if value is not None and value.isdigit() else None
+
+
+def _date(value: str | None) -> datetime | None:
+    if not value:
+        return None
+    try:
+        parsed = parsedate_to_datetime(value)
+    except (TypeError, ValueError, IndexError):
+        return None
+    return parsed if parsed.tzinfo else parsed.replace(tzinfo=timezone.utc)
+
+
+def expires_lifetime(resource: Resource) -> int | None:
+    """
+    Seconds from Date (or now) to Expires; None without an Expires header.
+
+    An Expires value that is not a valid date ("0", "-1") means already
+    expired, so it counts as 0.
+    """
+    expires = resource.header("expires")
+    if expires is None:
+        return None
+    when = _date(expires)
+    if when is None:
+        return 0
+    now = _date(resource.header("date")) or datetime.now(timezone.utc)
+    return max(0, int((when - now).total_seconds()))
+
+
+def is_fingerprinted(url: str) -> bool:
+    """Heuristic: the filename carries a content hash, as bundlers name build output."""
+    match = _FINGERPRINT.search(urlparse(url).path)
+    if match is None:
+        return False
+    token = match.group(1)
+    if re.fullmatch(r"[0-9a-f]+", token):
+        return bool(re.search(r"\d", token) and re.search(r"[a-f]", token))
+    return all(re.search(chars, token) for chars in (r"\d", r"[a-z]", r"[A-Z]"))
+
+
+def _conflict(resource: Resource, directives: dict[str, str | None]) -> dict | None:
+    """How Cache-Control and Expires disagree on whether the response may be cached."""
+    lifetime = expires_lifetime(resource)
+    if lifetime is None or not directives:
+        return None
+    max_age = _seconds(directives.get("max-age"))
+    forbids = "no-store" in directives or "no-cache" in directives or max_age == 0
+    if forbids and lifetime > 0:
+        return fragment("caching.conflict_forbids", lifetime)
+    if max_age and lifetime == 0:
+        return fragment("caching.conflict_expired", max_age)
+    return None
+
+
+def analyze(resources: list[Resource], options: CachingOptions | None = None) -> list[Issue]:
+    """
+    Check caching headers of pages and static assets.
+
+    Args:
+        resources: Response headers of pages (kind "document") and assets
+        options: Thresholds and display limits
+
+    Returns:
+        One finding per problem class, on the first resource concerned,
+        listing every affected URL: asset_cache_headers_missing,
+        asset_cache_short, cache_immutable_missing (fingerprinted assets),
+        cache_no_store (pages without cookies), and cache_headers_conflict
+    """
+    options = options or CachingOptions()
+    found: dict[str, list[str]] = {}
+    notes: dict[str, list[dict]] = {}
+
+    for resource in resources:
+        directives = parse_cache_control(resource.headers.get("cache-control", []))
+        max_age = _seconds(directives.get("max-age"))
+        age: str | dict = f"max-age={max_age}"
+        if max_age is None:
+            max_age = expires_lifetime(resource)  # Expires applies when max-age is absent
+            age = (
+                fragment("caching.no_max_age")
+                if max_age is None
+                else fragment("caching.expires_in", max_age)
+            )
+        revalidated = sorted(directives.keys() & {"no-store", "no-cache"})
+        age = ", ".join(revalidated) or age
+
+        conflict = _conflict(resource, directives)
+        if conflict:
+            found.setdefault("cache_headers_conflict", []).append(resource.url)
+            notes.setdefault("cache_headers_conflict", []).append(
+                fragment("caching.note", resource.url, conflict)
+            )
+
+        if resource.kind == "document":
+            private = "private" in directives or "set-cookie" in resource.headers
+            if "no-store" in directives and not private:
+                found.setdefault("cache_no_store", []).append(resource.url)
+            continue
+        if not is_fingerprinted(resource.url):
+            continue
+        if not directives and resource.header("expires") is None:
+            found.setdefault("asset_cache_headers_missing", []).append(resource.url)
+        elif revalidated or max_age is None or max_age < options.short_max_age:
+            found.setdefault("asset_cache_short", []).append(resource.url)
+            notes.setdefault("asset_cache_short", []).append(
+                fragment("caching.note", resource.url, age)
+            )
+        elif "immutable" not in directives:
+            found.setdefault("cache_immutable_missing", []).append(resource.url)
+
+    # Severity, and arguments of the message besides the count and the listing
+    messages = {
+        "asset_cache_headers_missing": ("medium", ()),
+        "asset_cache_short": ("medium", (options.short_max_age,)),
+        "cache_immutable_missing": ("info", ()),
+        "cache_no_store": ("low", ()),
+        "cache_headers_conflict": ("low", ()),
+    }
+    issues = []
+    for issue_type, (severity, args) in messages.items():
+        urls = found.get(issue_type)
+        if not urls:
+            continue
+        listed = notes.get(issue_type, urls)
+        issues.append(
+            Issue(
+                url=urls[0],
+                type=issue_type,
+                severity=severity,
+                **msg(
+                    f"caching.{issue_type}",
+                    len(urls),
+                    listing(listed, options.max_listed),
+                    *args,
+                ),
+            )
+        )
+    return issues
+
+
+def asset_links(html: str, base_url: str) -> list[str]:
+    """Same-origin stylesheets, scripts, images, and preloads of a page, as absolute URLs."""
+    soup = BeautifulSoup(html, "lxml")
+    origin = urlparse(base_url).netloc
+    urls: dict[str, None] = {}
+    for tag in soup.find_all(["link", "script", "img"]):
+        if tag.name == "link":
+            rel = tag.get("rel") or []
+            rels = {r.lower() for r in (rel.split() if isinstance(rel, str) else rel)}
+            src = tag.get("href") if rels & {"stylesheet", "preload", "modulepreload"} else None
+        else:
+            src = tag.get("src")
+        if not (src or "").strip() or src.strip().startswith("data:"):
+            continue
+        url = urljoin(base_url, src.strip()).split("#")[0]
+        if urlparse(url).netloc == origin:
+            urls.setdefault(url)
+    return list(urls)
+
+
+async def collect(
+    html: str,
+    base_url: str,
+    client: httpx.AsyncClient | None = None,
+    options: CachingOptions | None = None,
+) -> list[Resource]:
+    """
+    Request the headers of a page's static assets with HEAD.
+
+    Args:
+        html: Page HTML
+        base_url: Page URL
+        client: HTTP client (one is created when omitted)
+        options: Asset limit and timeout
+
+    Returns:
+        Resources for assets that answered with 2xx, fingerprinted assets first
+    """
+    options = options or CachingOptions()
+    if client is None:
+        async with http_client() as own:
+            return await collect(html, base_url, own, options)
+
+    links = sorted(asset_links(html, base_url), key=lambda u: not is_fingerprinted(u))
+    resources = []
+    for url in links[: options.max_assets]:
+        try:
+            response = await client.head(url, timeout=options.timeout, follow_redirects=True)
+        except httpx.HTTPError:
+            continue
+        if response.is_success:
+            resources.append(Resource.from_headers(url, response.headers))
+    return resources
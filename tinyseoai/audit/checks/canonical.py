@@ -0,0 +1,239 @@
+"""
+Canonical tag validation across a set of crawled pages.
+
+Per-page checks (missing, empty, relative, conflicting, cross-host) only
+need the HTML; target status and canonical chains need the whole crawl,
+so analyze takes every fetched page at once.
+"""
+from __future__ import annotations
+
+import asyncio
+from urllib.parse import urljoin, urlparse
+
+import httpx
+from bs4 import BeautifulSoup
+from pydantic import BaseModel
+
+from ...data.models import Issue
+from ...i18n import fragment, msg
+from ...utils import urlnorm
+from ..crawler import FetchedPage
+from ..verify import PageStatus, VerifyOptions, fetch_status
+
+# A canonical that adds tracking parameters does not point at the page itself
+_URL_OPTIONS = urlnorm.NormalizeOptions(strip_params=())
+
+
+class CanonicalInfo(BaseModel):
+    """Canonical tags found on one page."""
+
+    url: str
+    hrefs: list[str]  # raw href values, in document order
+    target: str | None = None  # resolved absolute canonical when there is exactly one
+
+    @property
+    def self_referencing(self) -> bool:
+        return self.target is not None and _normalize(self.target) == _normalize(self.url)
+
+
+def _normalize(url: str) -> str:
+    return urlnorm.normalize(url, _URL_OPTIONS)
+
+
+def canonical_info(url: str, html: str) -> CanonicalInfo:
+    """Read the canonical link tags of a page."""
+    soup = BeautifulSoup(html, "lxml")
+    hrefs = [(tag.get("href") or "").strip() for tag in soup.find_all("link", rel="canonical")]
+    distinct = {_normalize(urljoin(url, h)) for h in hrefs if h}
+    target = urljoin(url, next(h for h in hrefs if h)) if len(distinct) == 1 else None
+    return CanonicalInfo(url=url, hrefs=hrefs, target=target)
+
+
+def page_issues(info: CanonicalInfo) -> list[Issue]:
+    """Findings that only need the page itself."""
+    if not info.hrefs:
+        return [
+            Issue(
+                url=info.url,
+                type="missing_canonical",
+                severity="medium",
+                **msg("canonical.missing_canonical"),
+            )
+        ]
+    distinct = list(dict.fromkeys(urljoin(info.url, h) for h in info.hrefs if h))
+    if not distinct:
+        return [
+            Issue(
+                url=info.url,
+                type="empty_canonical",
+                severity="high",
+                **msg("canonical.empty_canonical"),
+            )
+        ]
+    if len(distinct) > 1:
+        return [
+            Issue(
+                url=info.url,
+                type="conflicting_canonical_tags",
+                severity="high",
+                **msg("canonical.conflicting_canonical_tags", len(info.hrefs), distinct),
+            )
+        ]
+
+    issues = []
+    relative = [h for h in info.hrefs if h and not urlparse(h).scheme]
+    if relative:
+        issues.append(
+            Issue(
+                url=info.url,
+                type="canonical_relative",
+                severity="low",
+                **msg("canonical.canonical_relative", relative[0], info.target),
+            )
+        )
+    if urlparse(info.target).netloc.lower() != urlparse(info.url).netloc.lower():
+        issues.append(
+            Issue(
+                url=info.url,
+                type="canonical_cross_host",
+                severity="medium",
+                **msg("canonical.canonical_cross_host", info.target),
+            )
+        )
+    return issues
+
+
+def chain_issues(infos: list[CanonicalInfo]) -> list[Issue]:
+    """
+    Canonical loops and chains within the crawled set.
+
+    A loop is A -> B -> A; a chain is A -> B -> C where B is not
+    self-referencing. Each affected page gets its own finding.
+    """
+    targets = {
+        _normalize(i.url): _normalize(i.target)
+        for i in infos
+        if i.target is not None and not i.self_referencing
+    }
+    issues = []
+    for info in infos:
+        start = _normalize(info.url)
+        if start not in targets:
+            continue
+        path = [start]
+        current = targets[start]
+        while current in targets and current not in path:
+            path.append(current)
+            current = targets[current]
+        if current in path:
+            issues.append(
+                Issue(
+                    url=info.url,
+                    type="canonical_loop",
+                    severity="high",
+                    **msg("canonical.canonical_loop", " -> ".join(path + [current])),
+                )
+            )
+        elif len(path) > 1:
+            issues.append(
+                Issue(
+                    url=info.url,
+                    type="canonical_chain",
+                    severity="medium",
+                    **msg("canonical.canonical_chain", " -> ".join(path + [current]), current),
+                )
+            )
+    return issues
+
+
+def target_issues(infos: list[CanonicalInfo], statuses: dict[str, PageStatus]) -> list[Issue]:
+    """Canonicals whose target returns an error or redirects."""
+    issues = []
+    for info in infos:
+        if info.target is None or info.self_referencing:
+            continue
+        status = statuses.get(_normalize(info.target))
+        if status is None or status.skipped:
+            continue
+        if status.status_code is None or status.status_code >= 400:
+            issues.append(
+                Issue(
+                    url=info.url,
+                    type="canonical_target_broken",
+                    severity="high",
+                    **msg(
+                        "canonical.canonical_target_broken",
+                        info.target,
+                        status.status_code or status.error,
+                    ),
+                )
+            )
+        elif 300 <= status.status_code < 400 or (
+            status.final_url and _normalize(status.final_url) != _normalize(info.target)
+        ):
+            final = (
+                status.final_url
+                if status.final_url != status.url
+                else fragment("canonical.another_url")
+            )
+            issues.append(
+                Issue(
+                    url=info.url,
+                    type="canonical_target_redirects",
+                    severity="medium",
+                    **msg("canonical.canonical_target_redirects", info.target, final),
+                )
+            )
+    return issues
+
+
+async def analyze(
+    pages: list[FetchedPage],
+    client: httpx.AsyncClient | None = None,
+    options: VerifyOptions | None = None,
+) -> list[Issue]:
+    """
+    Check canonical tags across crawled pages.
+
+    Targets that were crawled use their crawl status; other targets are
+    fetched with the verification client (up to options.max_requests) when
+    a client is given, and left unchecked otherwise. Self-referencing
+    canonicals pass.
+
+    Args:
+        pages: Fetched pages (anything with url, html, and status)
+        client: HTTP client for checking uncrawled canonical targets
+        options: Concurrency, timeout, and request cap for those checks
+
+    Returns:
+        Canonical findings; each names the page and its canonical target
+    """
+    options = options or VerifyOptions()
+    infos = [canonical_info(p.url, p.html) for p in pages if p.html]
+    statuses = {
+        _normalize(p.url): PageStatus(url=p.url, status_code=p.status, final_url=p.url) for p in pages
+    }
+
+    if client is not None:
+        unknown = list(
+            dict.fromkeys(
+                i.target
+                for i in infos
+                if i.target and not i.self_referencing and _normalize(i.target) not in statuses
+            )
+        )[: options.max_requests]
+        semaphore = asyncio.Semaphore(options.concurrency)
+
+        async def check(url: str) -> PageStatus:
+            async with semaphore:
+                return await fetch_status(client, url, options.timeout)
+
+        for status in await asyncio.gather(*(check(u) for u in unknown)):
+            statuses[_normalize(status.url)] = status
+
+    issues = []
+    for info in infos:
+        issues.extend(page_issues(info))
+    issues.extend(target_issues(infos, statuses))
+    issues.extend(chain_issues(infos))
+    return issues
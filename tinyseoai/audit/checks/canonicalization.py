@@ -0,0 +1,303 @@
+"""
+URL canonicalization: www/apex, http/https, and trailing-slash consistency.
+
+The home page is requested on the four host and scheme combinations
+(http and https, with and without www), and a sample of crawled paths with
+and without a trailing slash, all without following redirects. Variants
+that each serve the page split its signals; redirects that disagree on the
+host or the slash, and canonical tags pointing at a URL the site redirects
+away from, send mixed ones. Every finding ends with the rule to enforce,
+e.g. "301 example.com→www.example.com, enforce trailing slash".
+"""
+from __future__ import annotations
+
+import asyncio
+import ipaddress
+from collections import Counter
+from typing import TYPE_CHECKING, Literal
+from urllib.parse import urljoin, urlparse
+
+import httpx
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import fragment, listing, msg
+from ...utils.http import client as http_client
+from .canonical import canonical_info
+from .tls import apex_and_www
+
+if TYPE_CHECKING:
+    from ..crawler import FetchedPage
+
+HOST_PROBES = 4  # http and https, with and without www
+SlashPolicy = Literal["add", "remove"]
+
+
+class CanonicalizationOptions(BaseModel):
+    """Probe budget for analyze."""
+
+    max_probes: int = Field(default=16, ge=HOST_PROBES)  # requests per audit, host variants first
+    sample_paths: int = Field(default=6, ge=0)  # crawled paths probed with and without a slash
+    max_listed: int = Field(default=5, ge=1)  # examples named in a finding
+    timeout: float = 10.0
+
+
+class Probe(BaseModel):
+    """One variant requested without following redirects."""
+
+    url: str
+    status: int | None = None
+    location: str | None = None  # absolute redirect target
+    error: str | None = None
+
+    @property
+    def served(self) -> bool:
+        """Answered 2xx itself, without redirecting."""
+        return self.status is not None and 200 <= self.status < 300
+
+
+class PathProbe(BaseModel):
+    """A crawled page's path requested without and with its trailing slash."""
+
+    page: str  # the crawled URL
+    bare: Probe
+    slashed: Probe
+
+    @property
+    def direction(self) -> SlashPolicy | None:
+        """Which way the site redirects between the two forms, if it does."""
+        if self.bare.location and urlparse(self.bare.location).path.endswith("/"):
+            return "add"
+        if self.slashed.location and not urlparse(self.slashed.location).path.endswith("/"):
+            return "remove"
+        return None
+
+
+class CanonicalizationReport(BaseModel):
+    """Probe results, the policy they imply, and the findings."""
+
+    hosts: list[Probe] = Field(default_factory=list)
+    paths: list[PathProbe] = Field(default_factory=list)
+    host: str | None = None  # preferred host
+    other_host: str | None = None
+    slash: SlashPolicy | None = None
+    issues: list[Issue] = Field(default_factory=list)
+
+    def rule(self) -> list[dict]:
+        """The rule to enforce, as fragments rendered comma-separated."""
+        parts = []
+        if self.host and self.other_host:
+            parts.append(fragment("canonicalization.rule_host", self.other_host, self.host))
+        if self.slash:
+            parts.append(fragment(f"canonicalization.rule_{self.slash}_slash"))
+        return parts
+
+    def meta(self) -> dict:
+        def row(probe: Probe) -> dict:
+            return probe.model_dump(exclude_none=True)
+
+        return {
+            "hosts": [row(p) for p in self.hosts],
+            "paths": [{"bare": row(p.bare), "slashed": row(p.slashed)} for p in self.paths],
+        }
+
+
+def _is_hostname(host: str) -> bool:
+    try:
+        ipaddress.ip_address(host)
+        return False
+    except ValueError:
+        return "." in host.strip(".")
+
+
+def _slashable(path: str) -> bool:
+    """Whether a path can take a trailing slash: not the root and not a file."""
+    return path not in ("", "/") and "." not in path.rstrip("/").rsplit("/", 1)[-1]
+
+
+def _toggled(url: str) -> tuple[str, str]:
+    """The URL without and with a trailing slash on its path (query dropped)."""
+    parsed = urlparse(url)
+    bare = parsed.path.rstrip("/")
+    base = f"{parsed.scheme}://{parsed.netloc}"
+    return f"{base}{bare}", f"{base}{bare}/"
+
+
+def _sample(pages: list[FetchedPage], limit: int) -> list[FetchedPage]:
+    """Crawled HTML pages whose paths can take a trailing slash (not /, not files)."""
+    sample, seen = [], set()
+    for page in pages:
+        path = urlparse(page.url).path
+        if not page.html or not _slashable(path):
+            continue
+        key = path.rstrip("/")
+        if key not in seen:
+            seen.add(key)
+            sample.append(page)
+        if len(sample) >= limit:
+            break
+    return sample
+
+
+async def _probe(url: str, client: httpx.AsyncClient, options: CanonicalizationOptions) -> Probe:
+    try:
+        response = await client.get(url, timeout=options.timeout, follow_redirects=False)
+    except httpx.HTTPError as e:
+        return Probe(url=url, error=type(e).__name__)
+    location = response.headers.get("location") if response.is_redirect else None
+    return Probe(
+        url=url,
+        status=response.status_code,
+        location=urljoin(url, location.strip()) if location else None,
+    )
+
+
+def _redirect_label(probe: Probe) -> str:
+    return f"{probe.url} → {probe.location}"
+
+
+def _host_moves(report: CanonicalizationReport) -> list[tuple[Probe, str]]:
+    """Host variants that redirect to the other host, with that host."""
+    moves = []
+    for probe in report.hosts:
+        target = urlparse(probe.location).hostname if probe.location else None
+        if target and target != urlparse(probe.url).hostname:
+            moves.append((probe, target))
+    return moves
+
+
+def _preferred_host(report: CanonicalizationReport, host: str) -> None:
+    apex, www = apex_and_www(host)
+    targets = Counter(target for _, target in _host_moves(report) if target in (apex, www))
+    if targets:
+        report.host = targets.most_common(1)[0][0]
+    else:
+        served = {urlparse(p.url).hostname for p in report.hosts if p.served}
+        report.host = host if host in served or not served else served.pop()
+    report.other_host = www if report.host == apex else apex
+
+
+def _preferred_slash(report: CanonicalizationReport) -> None:
+    directions = Counter(p.direction for p in report.paths if p.direction)
+    if directions:
+        report.slash = directions.most_common(1)[0][0]
+    elif report.paths:
+        slashed = sum(urlparse(p.page).path.endswith("/") for p in report.paths)
+        report.slash = "add" if slashed * 2 >= len(report.paths) else "remove"
+
+
+def _findings(
+    report: CanonicalizationReport,
+    base_url: str,
+    pages: list[FetchedPage],
+    options: CanonicalizationOptions,
+) -> None:
+    rule = report.rule()
+
+    def add(url: str, issue_type: str, severity: str, *args) -> None:
+        fields = msg(f"canonicalization.{issue_type}", *args, rule)
+        report.issues.append(Issue(url=url, type=issue_type, severity=severity, **fields))
+
+    served = [p.url for p in report.hosts if p.served]
+    if len(served) > 1:
+        add(base_url, "host_variants_duplicated", "high", len(served), served)
+
+    moves = _host_moves(report)
+    target_hosts = {target for _, target in moves}
+    if report.host and {report.host, report.other_host} <= target_hosts:
+        labels = [_redirect_label(p) for p, _ in moves]
+        add(
+            base_url,
+            "redirect_policy_inconsistent",
+            "medium",
+            fragment("canonicalization.the_host"),
+            listing(labels, options.max_listed),
+        )
+
+    for pair in report.paths:
+        if pair.bare.served and pair.slashed.served:
+            add(pair.page, "trailing_slash_duplicated", "medium", pair.bare.url, pair.slashed.url)
+    directions = {p.direction for p in report.paths if p.direction}
+    if len(directions) > 1:
+        labels = [
+            _redirect_label(p.bare if p.direction == "add" else p.slashed)
+            for p in report.paths
+            if p.direction
+        ]
+        add(
+            base_url,
+            "redirect_policy_inconsistent",
+            "medium",
+            fragment("canonicalization.trailing_slashes"),
+            listing(labels, options.max_listed),
+        )
+
+    # Canonicals are only judged against a policy the redirects actually enforce
+    host_enforced = target_hosts == {report.host}
+    slash_enforced = len(directions) == 1
+    for page in pages:
+        target = canonical_info(page.url, page.html).target if page.html else None
+        if not target:
+            continue
+        expected = urlparse(target)
+        if host_enforced and expected.hostname == report.other_host:
+            netloc = expected.netloc.replace(report.other_host, report.host, 1)
+            expected = expected._replace(netloc=netloc)
+        if slash_enforced and _slashable(expected.path):
+            bare = expected.path.rstrip("/")
+            expected = expected._replace(path=f"{bare}/" if report.slash == "add" else bare)
+        preferred = expected.geturl()
+        if preferred != target:
+            add(page.url, "canonical_conflicts_redirect_policy", "medium", target, preferred)
+
+
+async def analyze(
+    base_url: str,
+    client: httpx.AsyncClient | None = None,
+    pages: list[FetchedPage] | None = None,
+    options: CanonicalizationOptions | None = None,
+) -> CanonicalizationReport:
+    """
+    Probe host, scheme, and trailing-slash variants and check they agree.
+
+    Args:
+        base_url: Site URL; its host decides the apex and www names
+        client: HTTP client (one is created when omitted); redirects are not followed
+        pages: Crawled pages; a sample of their paths gets the slash probes, and
+            their canonical tags are compared with the redirect policy
+        options: Probe budget, sample size, and timeout
+
+    Returns:
+        The report; its issues are host_variants_duplicated,
+        trailing_slash_duplicated, redirect_policy_inconsistent, and
+        canonical_conflicts_redirect_policy
+    """
+    options = options or CanonicalizationOptions()
+    pages = pages or []
+    if client is None:
+        async with http_client() as own:
+            return await analyze(base_url, own, pages, options)
+
+    report = CanonicalizationReport()
+    host = (urlparse(base_url).hostname or "").lower()
+    budget = options.max_probes
+    probes = []
+    if _is_hostname(host):
+        apex, www = apex_and_www(host)
+        probes = [f"{scheme}://{name}/" for scheme in ("https", "http") for name in (apex, www)]
+        budget -= len(probes)
+    sample = _sample(pages, min(options.sample_paths, budget // 2))
+    for page in sample:
+        probes.extend(_toggled(page.url))
+
+    results = await asyncio.gather(*(_probe(url, client, options) for url in probes))
+    if _is_hostname(host):
+        report.hosts, results = list(results[:HOST_PROBES]), results[HOST_PROBES:]
+        _preferred_host(report, host)
+    report.paths = [
+        PathProbe(page=page.url, bare=results[2 * i], slashed=results[2 * i + 1])
+        for i, page in enumerate(sample)
+    ]
+    _preferred_slash(report)
+    _findings(report, base_url, pages, options)
+    return report
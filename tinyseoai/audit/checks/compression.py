@@ -0,0 +1,284 @@
+"""
+Compression and transfer size of a page and its largest same-origin CSS and JS.
+
+Each resource is requested twice: once accepting gzip and brotli, as a
+browser would, and once with Accept-Encoding: identity for its full size.
+Same-origin stylesheets and scripts are ranked by a HEAD request's
+Content-Length and only the largest are measured. Every request counts
+against options.max_requests, so a page linking dozens of files costs no
+more than one that links a few.
+"""
+from __future__ import annotations
+
+import gzip
+from urllib.parse import urljoin, urlparse
+
+import httpx
+from bs4 import BeautifulSoup
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import fragment, msg
+from ...utils.http import client as http_client
+
+_COMPRESSED_ENCODINGS = frozenset({"gzip", "br", "deflate", "zstd"})
+_BROWSER_ENCODINGS = "gzip, br"
+
+
+class CompressionOptions(BaseModel):
+    """Request budget and thresholds for analyze."""
+
+    max_requests: int = Field(default=10, ge=2)  # every GET and HEAD, the page's two included
+    max_resources: int = Field(default=2, ge=0)  # CSS and JS files measured
+    min_bytes: int = Field(default=1024, ge=0)  # smaller files are not worth compressing
+    max_document_transfer: int = Field(default=100 * 1024, ge=1)  # compressed HTML bytes
+    max_bytes: int = Field(default=5 * 1024 * 1024, ge=1024)  # read at most this much of any file
+    timeout: float = 10.0
+
+
+class Transfer(BaseModel):
+    """One resource fetched with and without compression."""
+
+    url: str
+    kind: str  # "document", "stylesheet", or "script"
+    status_code: int | None = None
+    content_encoding: str | None = None  # answering Accept-Encoding: gzip, br
+    transfer_bytes: int | None = None  # bytes on the wire, compressed if the server chose to
+    identity_bytes: int | None = None  # full size, answering Accept-Encoding: identity
+    gzip_bytes: int | None = None  # size after gzip -6, as an estimate of what gzip would save
+    error: str | None = None
+
+    @property
+    def compressed(self) -> bool:
+        return self.content_encoding in _COMPRESSED_ENCODINGS
+
+    def savings(self) -> float | None:
+        """Fraction of the full size gzip would save, when it was measured."""
+        if not self.identity_bytes or self.gzip_bytes is None:
+            return None
+        return 1 - self.gzip_bytes / self.identity_bytes
+
+
+class _Budget:
+    """Counts requests against options.max_requests."""
+
+    def __init__(self, limit: int):
+        self.left = limit
+
+    def take(self, n: int = 1) -> bool:
+        if self.left < n:
+            return False
+        self.left -= n
+        return True
+
+
+def _kb(size: int) -> str:
+    return f"{size / 1024:.1f} KB"
+
+
+async def _get(
+    client: httpx.AsyncClient, url: str, encoding: str, options: CompressionOptions
+) -> tuple[httpx.Response, bytes]:
+    """
+    GET url with the given Accept-Encoding, reading at most max_bytes.
+
+    Compressed responses are read as sent, since only their size is needed;
+    identity responses are decoded in case the server compressed them anyway.
+    """
+    headers = {"accept-encoding": encoding}
+    request = client.stream(
+        "GET", url, headers=headers, timeout=options.timeout, follow_redirects=True
+    )
+    async with request as response:
+        chunks = response.aiter_bytes() if encoding == "identity" else response.aiter_raw()
+        body = b""
+        async for chunk in chunks:
+            body += chunk
+            if len(body) > options.max_bytes:
+                break
+    return response, body[: options.max_bytes]
+
+
+async def measure(
+    url: str,
+    kind: str,
+    client: httpx.AsyncClient,
+    options: CompressionOptions | None = None,
+) -> tuple[Transfer, bytes]:
+    """
+    Fetch a resource as a browser would, then uncompressed.
+
+    Args:
+        url: Resource URL
+        kind: "document", "stylesheet", or "script"
+        client: HTTP client
+        options: Byte cap and timeout
+
+    Returns:
+        (Transfer, identity body); the body is empty when a request failed
+    """
+    options = options or CompressionOptions()
+    transfer = Transfer(url=url, kind=kind)
+    try:
+        response, _ = await _get(client, url, _BROWSER_ENCODINGS, options)
+        transfer.status_code = response.status_code
+        encoding = response.headers.get("content-encoding", "").strip().lower()
+        transfer.content_encoding = encoding or None
+        transfer.transfer_bytes = response.num_bytes_downloaded
+        response, body = await _get(client, url, "identity", options)
+    except httpx.DecodingError:
+        transfer.error = "could not decode the response"
+        return transfer, b""
+    except httpx.TimeoutException:
+        transfer.error = "timed out"
+        return transfer, b""
+    except httpx.HTTPError as e:
+        transfer.error = f"failed ({type(e).__name__})"
+        return transfer, b""
+    transfer.identity_bytes = len(body)
+    transfer.gzip_bytes = len(gzip.compress(body, compresslevel=6))
+    return transfer, body
+
+
+def resource_links(html: str, base_url: str) -> list[tuple[str, str]]:
+    """(absolute URL, kind) of the same-origin stylesheets and scripts a page loads."""
+    soup = BeautifulSoup(html, "lxml")
+    origin = urlparse(base_url).netloc
+    found: dict[str, str] = {}
+    for tag in soup.find_all(["link", "script"]):
+        if tag.name == "link":
+            rel = tag.get("rel") or []
+            rels = [r.lower() for r in (rel.split() if isinstance(rel, str) else rel)]
+            src, kind = tag.get("href"), "stylesheet" if "stylesheet" in rels else None
+        else:
+            src, kind = tag.get("src"), "script"
+        if kind is None or not (src or "").strip():
+            continue
+        url = urljoin(base_url, src.strip()).split("#")[0]
+        if urlparse(url).netloc == origin and urlparse(url).scheme in ("http", "https"):
+            found.setdefault(url, kind)
+    return list(found.items())
+
+
+async def _content_length(url: str, client: httpx.AsyncClient, options: CompressionOptions) -> int:
+    """Uncompressed size from a HEAD request's Content-Length; 0 when unknown."""
+    headers = {"accept-encoding": "identity"}
+    try:
+        response = await client.head(
+            url, headers=headers, timeout=options.timeout, follow_redirects=True
+        )
+    except httpx.HTTPError:
+        return 0
+    length = response.headers.get("content-length", "")
+    return int(length) if response.is_success and length.isdigit() else 0
+
+
+def transfer_issues(transfer: Transfer, page_url: str, options: CompressionOptions) -> list[Issue]:
+    """Findings for one measured resource, reported on the page that loads it."""
+    size = transfer.identity_bytes
+    if transfer.error or not transfer.status_code or transfer.status_code >= 400 or not size:
+        return []
+    subject = fragment(f"compression.subject_{transfer.kind}", transfer.url)
+    issues = []
+
+    def add(issue_type: str, severity: str, key: str, *args) -> None:
+        issues.append(
+            Issue(
+                url=page_url,
+                type=issue_type,
+                severity=severity,
+                **msg(f"compression.{key}", *args),
+            )
+        )
+
+    if not transfer.compressed and size >= options.min_bytes:
+        savings = transfer.savings()
+        severity = "high" if transfer.kind == "document" else "medium"
+        wire = _kb(transfer.transfer_bytes or size)
+        if savings and savings > 0:
+            add(
+                "no_compression",
+                severity,
+                "no_compression_estimate",
+                subject,
+                wire,
+                savings,
+                _kb(transfer.gzip_bytes),
+            )
+        else:
+            add("no_compression", severity, "no_compression", subject, wire)
+    elif transfer.content_encoding in ("gzip", "deflate") and size >= options.min_bytes:
+        add(
+            "compression_not_optimal",
+            "info",
+            "compression_not_optimal",
+            subject,
+            transfer.content_encoding,
+            _kb(transfer.transfer_bytes),
+            _kb(size),
+        )
+
+    if transfer.kind == "document" and transfer.compressed:
+        wire = transfer.transfer_bytes or 0
+        if wire > options.max_document_transfer:
+            add(
+                "large_transfer_size",
+                "medium",
+                "large_transfer_size",
+                _kb(wire),
+                transfer.content_encoding,
+                _kb(size),
+                _kb(options.max_document_transfer),
+            )
+    return issues
+
+
+async def analyze(
+    url: str,
+    client: httpx.AsyncClient | None = None,
+    options: CompressionOptions | None = None,
+) -> list[Issue]:
+    """
+    Check whether a page and its largest same-origin CSS and JS are compressed.
+
+    Args:
+        url: Page URL
+        client: HTTP client (one is created when omitted)
+        options: Request budget, size thresholds, and timeout
+
+    Returns:
+        no_compression (with the measured size and the estimated gzip
+        savings), compression_not_optimal for gzip where br was accepted,
+        and large_transfer_size for compressed pages still over the
+        threshold; all reported on the page
+    """
+    options = options or CompressionOptions()
+    if client is None:
+        async with http_client() as own:
+            return await analyze(url, own, options)
+
+    budget = _Budget(options.max_requests)
+    budget.take(2)
+    document, body = await measure(url, "document", client, options)
+    transfers = [document]
+
+    links = resource_links(body.decode("utf-8", "replace"), url) if body else []
+    if len(links) > options.max_resources:
+        # Rank by size with HEAD requests, keeping two requests per file to be measured;
+        # files left unsized once the budget runs out keep their page order at the end
+        reserved = 2 * min(options.max_resources, budget.left // 2)
+        sizes = {}
+        for link_url, _ in links:
+            if budget.left - 1 < reserved:
+                break
+            budget.take()
+            sizes[link_url] = await _content_length(link_url, client, options)
+        links.sort(key=lambda link: -sizes.get(link[0], -1))
+
+    for link_url, kind in links[: options.max_resources]:
+        if not budget.take(2):
+            break
+        transfer, _ = await measure(link_url, kind, client, options)
+        transfers.append(transfer)
+
+    return [issue for t in transfers for issue in transfer_issues(t, url, options)]
@@ -1,30 +1,199 @@
 """
 Content quality checks including readability, keyword analysis, and duplicate content detection.
+
+analyze counts the words of a page's visible text (navigation, header,
+footer, and sidebars removed) and compares that text with a sample of
+other crawled pages, so thin and boilerplate-dominated pages are flagged.
+Chinese and Japanese text has no spaces between words, so each CJK
+character counts as one word.
 """
 from __future__ import annotations
 
 import hashlib
 import re
 from collections import Counter
+from collections.abc import Sequence
 
 from bs4 import BeautifulSoup
+from pydantic import BaseModel, Field
 
 from ...data.models import Issue
+from ...i18n import fragment, msg
+
+_CJK = "\u3040-\u30ff\u3400-\u4dbf\u4e00-\u9fff\uf900-\ufaff"  # kana and CJK ideographs
+_TOKEN = re.compile(rf"[{_CJK}]|[^\s{_CJK}]+")
+_HAS_CJK = re.compile(rf"[{_CJK}]")
+_CHROME_TAGS = [
+    "head", "script", "style", "noscript", "template", "svg", "nav", "header", "footer", "aside"
+]
+_CHROME_ROLES = {"navigation", "banner", "contentinfo", "complementary"}
+# class or id names of navigation and other page chrome, e.g. "site-nav", "sidebar"
+_CHROME_HINT = re.compile(
+    r"(?:^|[-_])(?:nav|navbar|menu|footer|sidebar|breadcrumbs?|cookie)(?:$|[-_])", re.IGNORECASE
+)
+
+
+class ContentOptions(BaseModel):
+    """Thresholds for analyze."""
+
+    min_words: int = Field(default=300, ge=1)  # fewer is thin_content
+    very_thin_words: int = Field(default=100, ge=0)  # fewer is very_thin_content
+    min_unique_ratio: float = Field(default=0.25, ge=0, le=1)  # less is boilerplate_content
+    shingle_size: int = Field(default=5, ge=1)  # words per shingle
+    sample_size: int = Field(default=20, ge=1)  # other pages compared against
+
+
+def _is_chrome(tag) -> bool:
+    if tag.name in _CHROME_TAGS or (tag.get("role") or "").strip().lower() in _CHROME_ROLES:
+        return True
+    if tag.has_attr("hidden") or (tag.get("aria-hidden") or "").lower() == "true":
+        return True
+    classes = tag.get("class") or []
+    names = [*(classes if isinstance(classes, list) else classes.split()), tag.get("id") or ""]
+    return any(_CHROME_HINT.search(n) for n in names if n)
+
+
+def strip_chrome(soup: BeautifulSoup) -> BeautifulSoup:
+    """Remove scripts, navigation, header, footer, sidebars, and hidden elements, in place."""
+    for tag in [t for t in soup.find_all(True) if _is_chrome(t)]:
+        tag.decompose()
+    return soup
+
+
+def visible_text(html: str) -> str:
+    """Page text without scripts, navigation, header, footer, sidebars, and hidden elements."""
+    soup = strip_chrome(BeautifulSoup(html, "lxml"))
+    return " ".join(soup.get_text(" ").split())
+
+
+def words(text: str) -> list[str]:
+    """Words of a text; each CJK character is a word, and punctuation-only tokens are not."""
+    return [t for t in _TOKEN.findall(text) if any(c.isalnum() for c in t)]
+
+
+def word_count(text: str) -> int:
+    return len(words(text))
+
+
+def shingles(text: str, size: int = 5) -> set[int]:
+    """Hashes of each run of size consecutive words (case-folded)."""
+    tokens = [t.casefold() for t in words(text)]
+    if len(tokens) < size:
+        return {hash(tuple(tokens))} if tokens else set()
+    return {hash(tuple(tokens[i : i + size])) for i in range(len(tokens) - size + 1)}
+
+
+def sample(texts: Sequence[str], size: int) -> list[str]:
+    """Up to size texts spread evenly over the sequence (deterministic)."""
+    if len(texts) <= size:
+        return list(texts)
+    step = len(texts) / size
+    return [texts[int(i * step)] for i in range(size)]
+
+
+def unique_ratio(text: str, others: Sequence[str], size: int = 5) -> float:
+    """Share of a text's shingles that appear in none of the other texts."""
+    own = shingles(text, size)
+    if not own:
+        return 1.0
+    shared = set().union(*(shingles(o, size) for o in others)) if others else set()
+    return len(own - shared) / len(own)
+
+
+def thin_content_issues(url: str, text: str, options: ContentOptions | None = None) -> list[Issue]:
+    """thin_content / very_thin_content for a page's visible text."""
+    options = options or ContentOptions()
+    count = word_count(text)
+    counted = fragment("content.cjk_counted") if _HAS_CJK.search(text) else ""
+    if count < options.very_thin_words:
+        return [
+            Issue(
+                url=url,
+                type="very_thin_content",
+                severity="high",
+                **msg("content.very_thin_content", count, counted, options.min_words),
+            )
+        ]
+    if count < options.min_words:
+        return [
+            Issue(
+                url=url,
+                type="thin_content",
+                severity="medium",
+                **msg("content.thin_content", count, counted, options.min_words),
+            )
+        ]
+    return []
+
+
+def boilerplate_issues(
+    url: str, text: str, others: Sequence[str], options: ContentOptions | None = None
+) -> list[Issue]:
+    """boilerplate_content when little of a page's text is missing from a sample of other pages."""
+    options = options or ContentOptions()
+    compared = sample(others, options.sample_size)
+    own = shingles(text, options.shingle_size)
+    if not compared or len(own) < options.shingle_size:
+        return []
+    ratio = unique_ratio(text, compared, options.shingle_size)
+    if ratio >= options.min_unique_ratio:
+        return []
+    return [
+        Issue(
+            url=url,
+            type="boilerplate_content",
+            severity="medium",
+            **msg(
+                "content.boilerplate_content",
+                ratio,
+                word_count(text),
+                len(compared),
+                options.min_unique_ratio,
+            ),
+        )
+    ]
+
+
+def analyze(
+    html: str, url: str, options: ContentOptions | None = None, others: Sequence[str] = ()
+) -> list[Issue]:
+    """
+    Check a page for thin and boilerplate-dominated content.
+
+    Args:
+        html: HTML content
+        url: URL of the page
+        options: Word and uniqueness thresholds
+        others: Visible text (see visible_text) of other crawled pages; up
+            to options.sample_size of them are compared with this page
+
+    Returns:
+        thin_content or very_thin_content, and boilerplate_content; each
+        detail states the measured word count
+    """
+    options = options or ContentOptions()
+    text = visible_text(html)
+    return [
+        *thin_content_issues(url, text, options),
+        *boilerplate_issues(url, text, others, options),
+    ]
 
 
 class ContentAnalyzer:
     """Analyze content quality for SEO."""
 
-    def __init__(self, html: str, url: str):
+    def __init__(self, html: str, url: str, options: ContentOptions | None = None):
         """
         Initialize content analyzer.
 
         Args:
             html: HTML content to analyze
             url: URL of the page
+            options: Word count thresholds
         """
         self.html = html
         self.url = url
+        self.options = options or ContentOptions()
         self.soup = BeautifulSoup(html, "lxml")
         self.text = self._extract_text()
 
@@ -61,30 +230,7 @@ class ContentAnalyzer:
         Returns:
             List of content length issues
         """
-        issues = []
-
-        word_count = len(self.text.split())
-
-        if word_count < 300:
-            issues.append(
-                Issue(
-                    url=self.url,
-                    type="thin_content",
-                    severity="medium",
-                    detail=f"Page has only {word_count} words. Aim for at least 300 words.",
-                )
-            )
-        elif word_count < 100:
-            issues.append(
-                Issue(
-                    url=self.url,
-                    type="very_thin_content",
-                    severity="high",
-                    detail=f"Page has only {word_count} words. Very thin content may be penalized.",
-                )
-            )
-
-        return issues
+        return thin_content_issues(self.url, self.text, self.options)
 
     def check_readability(self) -> list[Issue]:
         """
@@ -112,8 +258,7 @@ class ContentAnalyzer:
                     url=self.url,
                     type="long_sentences",
                     severity="low",
-                    detail=f"Average sentence length is {avg_sentence_length:.1f} words. "
-                    "Consider shorter sentences for better readability.",
+                    **msg("content.long_sentences", avg_sentence_length),
                 )
             )
 
@@ -126,8 +271,7 @@ class ContentAnalyzer:
                     url=self.url,
                     type="complex_vocabulary",
                     severity="info",
-                    detail=f"Average word length is {avg_word_length:.1f} characters. "
-                    "Consider simpler words for broader accessibility.",
+                    **msg("content.complex_vocabulary", avg_word_length),
                 )
             )
 
@@ -217,8 +361,7 @@ class ContentAnalyzer:
                         url=self.url,
                         type="high_heading_ratio",
                         severity="low",
-                        detail=f"{heading_ratio * 100:.1f}% of content is headings. "
-                        "Ensure sufficient body content.",
+                        **msg("content.high_heading_ratio", heading_ratio * 100),
                     )
                 )
 
@@ -257,8 +400,9 @@ class ContentAnalyzer:
                         url=self.url,
                         type="potential_keyword_stuffing",
                         severity="medium",
-                        detail=f"Word '{word}' appears {count} times ({frequency * 100:.1f}% of content). "
-                        "May be keyword stuffing.",
+                        **msg(
+                            "content.potential_keyword_stuffing", word, count, frequency * 100
+                        ),
                     )
                 )
 
@@ -383,8 +527,11 @@ class DuplicateContentDetector:
                             url=url,
                             type="duplicate_content",
                             severity="high",
-                            detail=f"Exact duplicate of {len(urls) - 1} other page(s): "
-                            f"{', '.join(u for u in urls if u != url)[:200]}",
+                            **msg(
+                                "content.duplicate_content",
+                                len(urls) - 1,
+                                ", ".join(u for u in urls if u != url)[:200],
+                            ),
                         )
                     )
 
@@ -417,7 +564,7 @@ class DuplicateContentDetector:
                             url=url1,
                             type="near_duplicate_content",
                             severity="medium",
-                            detail=f"Near-duplicate ({similarity * 100:.1f}% similar) to: {url2}",
+                            **msg("content.near_duplicate_content", similarity * 100, url2),
                         )
                     )
 
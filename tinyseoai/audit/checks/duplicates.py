@@ -0,0 +1,178 @@
+"""
+Duplicate and near-duplicate titles and meta descriptions across a crawl.
+
+Texts are normalized (whitespace collapsed, case-folded) and grouped with a
+dict, so exact clusters take one pass however many pages there are. Near
+duplicates compare character trigram sets (Jaccard similarity) between the
+distinct texts; prefix filtering keeps that from comparing every pair.
+Paginated pages ("Blog – page 2") repeat their first page's title by
+design and are left out; pass is_paginated to change how they are spotted.
+"""
+from __future__ import annotations
+
+import math
+import re
+from collections import Counter
+from collections.abc import Callable
+
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import listing, msg
+
+_PAGINATED_TEXT = re.compile(r"\b(?:page|pg|p\.)\s*\d+\b|\b\d+\s*(?:/|of)\s*\d+\s*$", re.IGNORECASE)
+_PAGINATED_URL = re.compile(r"[?&](?:page|p|pg|paged)=\d+|/page/\d+/?$", re.IGNORECASE)
+
+
+class PageMeta(BaseModel):
+    """Title and meta description of one crawled page."""
+
+    url: str
+    title: str | None = None
+    description: str | None = None
+
+
+def is_paginated(page: PageMeta) -> bool:
+    """Default heuristic: "page 2" in the title or a page number in the URL."""
+    return bool(_PAGINATED_TEXT.search(page.title or "") or _PAGINATED_URL.search(page.url))
+
+
+class DuplicateOptions(BaseModel):
+    """Similarity threshold, pagination heuristic, and display limits for analyze."""
+
+    near_threshold: float | None = Field(default=0.75, gt=0, le=1)  # None disables near duplicates
+    is_paginated: Callable[[PageMeta], bool] = is_paginated
+    max_listed: int = Field(default=10, ge=1)  # pages named in a finding before "and N more"
+
+
+def normalize(text: str) -> str:
+    """Trim, collapse whitespace, and case-fold."""
+    return " ".join(text.split()).casefold()
+
+
+def trigrams(text: str) -> set[str]:
+    padded = f"  {text} "
+    return {padded[i : i + 3] for i in range(len(padded) - 2)}
+
+
+def jaccard(a: set[str], b: set[str]) -> float:
+    return len(a & b) / len(a | b) if a or b else 1.0
+
+
+def near_pairs(texts: list[str], threshold: float) -> list[tuple[int, int]]:
+    """
+    Index pairs of texts whose trigram Jaccard similarity is at least threshold.
+
+    Each text's trigrams are sorted rarest first. Two sets with Jaccard >= t
+    must share a trigram among the first len - ceil(t * len) + 1 of either,
+    so only those prefixes are indexed and only texts sharing a prefix
+    trigram are compared.
+    """
+    grams = [trigrams(t) for t in texts]
+    frequency = Counter(g for gs in grams for g in gs)
+    index: dict[str, list[int]] = {}
+    pairs = []
+    for i, gs in enumerate(grams):
+        ordered = sorted(gs, key=lambda g: (frequency[g], g))
+        prefix = ordered[: len(ordered) - math.ceil(threshold * len(ordered)) + 1]
+        candidates = set()
+        for gram in prefix:
+            candidates.update(index.get(gram, ()))
+            index.setdefault(gram, []).append(i)
+        for j in sorted(candidates):
+            # Sizes too far apart cannot reach the threshold
+            small, large = sorted((len(gs), len(grams[j])))
+            if small >= threshold * large and jaccard(gs, grams[j]) >= threshold:
+                pairs.append((j, i))
+    return pairs
+
+
+def _components(count: int, pairs: list[tuple[int, int]]) -> list[list[int]]:
+    parent = list(range(count))
+
+    def find(i: int) -> int:
+        while parent[i] != i:
+            parent[i] = parent[parent[i]]
+            i = parent[i]
+        return i
+
+    for a, b in pairs:
+        parent[find(a)] = find(b)
+    groups: dict[int, list[int]] = {}
+    for i in range(count):
+        groups.setdefault(find(i), []).append(i)
+    return [g for g in groups.values() if len(g) > 1]
+
+
+def _field_issues(
+    field: str, values: dict[str, list[str]], originals: dict[str, str], options: DuplicateOptions
+) -> list[Issue]:
+    issues = []
+    for key, urls in values.items():
+        if len(urls) > 1:
+            issues.append(
+                Issue(
+                    url=urls[0],
+                    type=f"duplicate_{field}",
+                    severity="low",
+                    **msg(
+                        f"duplicates.duplicate_{field}",
+                        len(urls),
+                        originals[key],
+                        listing(urls, options.max_listed),
+                    ),
+                )
+            )
+    if options.near_threshold is None:
+        return issues
+
+    keys = list(values)
+    for component in _components(len(keys), near_pairs(keys, options.near_threshold)):
+        urls = [u for i in component for u in values[keys[i]]]
+        variants = "; ".join(f'"{originals[keys[i]]}"' for i in component[:3])
+        issues.append(
+            Issue(
+                url=urls[0],
+                type=f"near_duplicate_{field}",
+                severity="info",
+                **msg(
+                    f"duplicates.near_duplicate_{field}",
+                    len(urls),
+                    variants,
+                    listing(urls, options.max_listed),
+                ),
+            )
+        )
+    return issues
+
+
+def analyze(pages: list[PageMeta], options: DuplicateOptions | None = None) -> list[Issue]:
+    """
+    Find pages sharing a title or meta description.
+
+    Args:
+        pages: Title and description of each crawled page
+        options: Near-duplicate threshold, pagination heuristic, and limits
+
+    Returns:
+        One Issue per cluster of two or more pages (duplicate_title,
+        duplicate_meta_description), then one per near-duplicate cluster
+        (near_duplicate_title, near_duplicate_meta_description) at info
+        severity; each lists the member pages
+    """
+    options = options or DuplicateOptions()
+    titles: dict[str, list[str]] = {}
+    descriptions: dict[str, list[str]] = {}
+    originals: dict[str, str] = {}
+    for page in pages:
+        if options.is_paginated(page):
+            continue
+        for text, groups in ((page.title, titles), (page.description, descriptions)):
+            key = normalize(text or "")
+            if key:
+                groups.setdefault(key, []).append(page.url)
+                originals.setdefault(key, " ".join(text.split()))
+    return [
+        *_field_issues("title", titles, originals, options),
+        *_field_issues("meta_description", descriptions, originals, options),
+    ]
@@ -0,0 +1,189 @@
+"""
+RSS and Atom feeds: discover the feeds a page declares and check they work.
+
+Feeds are the <link rel="alternate"> tags with an RSS or Atom type. Each is
+fetched (up to options.max_bytes) and parsed just enough to confirm an RSS
+2.0 or Atom document with at least one entry whose dates parse and are not
+in the future. A blog-like page (a /blog/ URL or link, <article> markup, or
+og:type article) that declares no feed is reported as well. Findings are
+filed under the feed's URL.
+"""
+from __future__ import annotations
+
+from datetime import datetime, timedelta, timezone
+from email.utils import parsedate_to_datetime
+from typing import Literal
+from urllib.parse import urljoin, urlparse
+from xml.etree import ElementTree
+
+import httpx
+from bs4 import BeautifulSoup
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import msg
+from ...utils.http import client as http_client
+from .icons import IconOptions, probe
+
+FEED_TYPES = ("application/rss+xml", "application/atom+xml")
+ATOM = "{http://www.w3.org/2005/Atom}"
+# Dates before this are placeholders (the Unix epoch, year 1), not publication dates
+EARLIEST = datetime(1995, 1, 1, tzinfo=timezone.utc)
+
+FeedKind = Literal["rss", "atom"]
+
+
+class FeedOptions(BaseModel):
+    """Limits for fetching and judging feeds."""
+
+    max_bytes: int = Field(default=2 * 1024 * 1024, ge=1024)  # larger feeds are not parsed
+    max_feeds: int = Field(default=5, ge=0)  # declared feeds fetched
+    future_days: int = Field(default=2, ge=0)  # dates further ahead are not sane
+    timeout: float = 10.0
+
+
+class FeedDocument(BaseModel):
+    """What parsing a feed found."""
+
+    kind: FeedKind | None = None  # None: not an RSS 2.0 or Atom document
+    error: str | None = None  # why the XML is not a feed
+    entries: int = 0
+    bad_dates: list[str] = Field(default_factory=list)  # raw values that fail to parse or are off
+
+
+def feed_links(html: str, base_url: str) -> list[str]:
+    """Absolute URLs of the RSS and Atom feeds a page declares, in order, without repeats."""
+    soup = BeautifulSoup(html, "lxml")
+    found = []
+    for tag in soup.find_all("link", href=True):
+        rel = [r.lower() for r in tag.get("rel") or []]
+        kind = (tag.get("type") or "").split(";")[0].strip().lower()
+        if "alternate" in rel and kind in FEED_TYPES and tag["href"].strip():
+            found.append(urljoin(base_url, tag["href"].strip()))
+    return list(dict.fromkeys(found))
+
+
+def looks_like_blog(html: str, base_url: str) -> bool:
+    """Heuristic: the URL or a link is under /blog/, or the page has article markup."""
+    if "/blog" in urlparse(base_url).path.lower():
+        return True
+    soup = BeautifulSoup(html, "lxml")
+    if soup.find("article") is not None:
+        return True
+    og_type = soup.find("meta", attrs={"property": "og:type"})
+    if og_type is not None and (og_type.get("content") or "").strip().lower() == "article":
+        return True
+    return any(
+        "/blog/" in urlparse(urljoin(base_url, a["href"])).path.lower()
+        for a in soup.find_all("a", href=True)
+    )
+
+
+def _date(raw: str, kind: FeedKind) -> datetime | None:
+    try:
+        parsed = (
+            parsedate_to_datetime(raw) if kind == "rss" else datetime.fromisoformat(raw.strip())
+        )
+    except (TypeError, ValueError, IndexError):
+        return None
+    return parsed if parsed.tzinfo else parsed.replace(tzinfo=timezone.utc)
+
+
+def parse_feed(body: bytes, options: FeedOptions | None = None) -> FeedDocument:
+    """
+    Parse an RSS 2.0 or Atom document far enough to count its entries and check their dates.
+
+    Dates are RSS pubDate (RFC 822) and Atom updated/published (RFC 3339).
+    """
+    options = options or FeedOptions()
+    try:
+        root = ElementTree.fromstring(body)
+    except ElementTree.ParseError as e:
+        return FeedDocument(error=f"invalid XML ({e})")
+
+    if root.tag == "rss":
+        if root.get("version") != "2.0":
+            return FeedDocument(error=f"RSS version {root.get('version') or 'missing'}, not 2.0")
+        channel = root.find("channel")
+        if channel is None:
+            return FeedDocument(error="RSS without a <channel>")
+        doc = FeedDocument(kind="rss")
+        entries = channel.findall("item")
+        dates = [e.findtext("pubDate") for e in entries]
+    elif root.tag == f"{ATOM}feed":
+        doc = FeedDocument(kind="atom")
+        entries = root.findall(f"{ATOM}entry")
+        dates = [
+            e.findtext(f"{ATOM}updated") or e.findtext(f"{ATOM}published") for e in entries
+        ]
+    else:
+        return FeedDocument(error=f"<{root.tag}> is neither RSS 2.0 nor Atom")
+
+    doc.entries = len(entries)
+    latest = datetime.now(timezone.utc) + timedelta(days=options.future_days)
+    for raw in dates:
+        if raw is None:
+            continue
+        parsed = _date(raw, doc.kind)
+        if parsed is None or not EARLIEST <= parsed <= latest:
+            doc.bad_dates.append(raw.strip())
+    return doc
+
+
+async def analyze(
+    html: str,
+    base_url: str,
+    client: httpx.AsyncClient | None = None,
+    options: FeedOptions | None = None,
+) -> list[Issue]:
+    """
+    Check the feeds a page declares, or that a blog-like page declares one.
+
+    Args:
+        html: Page HTML (usually the home page)
+        base_url: Page URL
+        client: HTTP client (one is created when omitted)
+        options: Byte cap, feed limit, date tolerance, and timeout
+
+    Returns:
+        feed_missing for a blog-like page without feeds; per feed, feed_broken
+        when it does not load, feed_served_as_html, feed_invalid, feed_empty,
+        and feed_bad_dates
+    """
+    options = options or FeedOptions()
+    if client is None:
+        async with http_client() as own:
+            return await analyze(html, base_url, own, options)
+
+    feeds = feed_links(html, base_url)
+    if not feeds:
+        if looks_like_blog(html, base_url):
+            fields = msg("feeds.feed_missing")
+            return [Issue(url=base_url, type="feed_missing", severity="low", **fields)]
+        return []
+
+    issues = []
+    fetch = IconOptions(max_bytes=options.max_bytes, timeout=options.timeout)
+    for url in feeds[: options.max_feeds]:
+
+        def add(issue_type: str, severity: str, *args, feed: str = url) -> None:
+            fields = msg(f"feeds.{issue_type}", *args)
+            issues.append(Issue(url=feed, type=issue_type, severity=severity, **fields))
+
+        result = await probe(url, client, fetch, body=True)
+        problem = result.failure()
+        if problem is not None:
+            add("feed_broken", "medium", problem)
+            continue
+        if (result.content_type or "").startswith("text/html"):
+            add("feed_served_as_html", "medium", result.content_type)
+        if result.truncated:
+            continue  # too large to parse; only a partial document was read
+        doc = parse_feed(result.body, options)
+        if doc.kind is None:
+            add("feed_invalid", "high", doc.error)
+        elif doc.entries == 0:
+            add("feed_empty", "medium")
+        elif doc.bad_dates:
+            add("feed_bad_dates", "low", len(doc.bad_dates), doc.entries, doc.bad_dates[0])
+    return issues
@@ -0,0 +1,228 @@
+"""
+Security response header checks.
+
+Header names are matched case-insensitively and repeated headers are kept
+(several Content-Security-Policy headers all apply; for HSTS only the
+first counts). Each finding includes a header line that can be pasted
+into the server configuration, and a summary finding grades the overall
+posture from A to F.
+"""
+from __future__ import annotations
+
+from collections.abc import Iterable, Mapping
+from urllib.parse import urlparse
+
+import httpx
+
+from ...data.models import Issue
+from ...i18n import fragment, msg
+
+HSTS_MIN_MAX_AGE = 15_552_000  # 180 days
+RECOMMENDED_HEADERS = {
+    "hsts": "Strict-Transport-Security: max-age=31536000; includeSubDomains",
+    "nosniff": "X-Content-Type-Options: nosniff",
+    "frame": "Content-Security-Policy: frame-ancestors 'self'",
+    "csp": "Content-Security-Policy: default-src 'self'; script-src 'self'; object-src 'none'; "
+    "base-uri 'self'; frame-ancestors 'self'",
+    "referrer": "Referrer-Policy: strict-origin-when-cross-origin",
+    "permissions": "Permissions-Policy: camera=(), microphone=(), geolocation=()",
+}
+_UNSAFE_REFERRER_POLICIES = frozenset({"unsafe-url", "no-referrer-when-downgrade"})
+
+HeaderInput = Mapping[str, str] | Iterable[tuple[str, str]] | httpx.Headers
+
+
+def header_values(headers: HeaderInput) -> dict[str, list[str]]:
+    """All values per lowercase header name, keeping repeats in order."""
+    if isinstance(headers, httpx.Headers):
+        items = headers.multi_items()
+    elif isinstance(headers, Mapping):
+        items = headers.items()
+    else:
+        items = headers
+    values: dict[str, list[str]] = {}
+    for name, value in items:
+        values.setdefault(name.strip().lower(), []).append(value.strip())
+    return values
+
+
+def parse_csp(policy: str) -> dict[str, list[str]]:
+    """Directives of one policy; the first occurrence of a directive wins."""
+    directives: dict[str, list[str]] = {}
+    for part in policy.split(";"):
+        tokens = part.split()
+        if tokens:
+            directives.setdefault(tokens[0].lower(), [t.lower() for t in tokens[1:]])
+    return directives
+
+
+def _allows_inline_scripts(directives: dict[str, list[str]]) -> bool | None:
+    """True/False for a policy that restricts scripts, None if it does not."""
+    sources = directives.get("script-src", directives.get("default-src"))
+    if sources is None:
+        return None
+    # A nonce or hash makes browsers ignore 'unsafe-inline'
+    neutralized = any(s.startswith(("'nonce-", "'sha256-", "'sha384-", "'sha512-")) for s in sources)
+    return "'unsafe-inline'" in sources and not neutralized
+
+
+def _hsts_max_age(value: str) -> int | None:
+    for part in value.split(";"):
+        name, _, raw = part.strip().partition("=")
+        if name.strip().lower() == "max-age":
+            raw = raw.strip().strip('"')
+            return int(raw) if raw.isdigit() else None
+    return None
+
+
+def grade(passed: int, total: int) -> str:
+    """Letter grade for the share of header checks that pass."""
+    share = passed / total if total else 1.0
+    for letter, minimum in (("A", 1.0), ("B", 0.8), ("C", 0.6), ("D", 0.4)):
+        if share >= minimum:
+            return letter
+    return "F"
+
+
+# (issue type, severity, detail fragment, recommended header line)
+Finding = tuple[str, str, dict, str]
+
+
+def _check_hsts(headers: dict[str, list[str]]) -> Finding | None:
+    hsts = headers.get("strict-transport-security")  # browsers only honor the first header
+    if not hsts:
+        detail = fragment("headers.missing_hsts")
+        return "missing_hsts", "medium", detail, RECOMMENDED_HEADERS["hsts"]
+    max_age = _hsts_max_age(hsts[0])
+    if max_age is None:
+        detail = fragment("headers.hsts_invalid", hsts[0])
+        return "hsts_invalid", "medium", detail, RECOMMENDED_HEADERS["hsts"]
+    if max_age < HSTS_MIN_MAX_AGE:
+        detail = fragment("headers.hsts_short_max_age", max_age, HSTS_MIN_MAX_AGE)
+        return "hsts_short_max_age", "low", detail, RECOMMENDED_HEADERS["hsts"]
+    if "includesubdomains" not in hsts[0].lower():
+        detail = fragment("headers.hsts_missing_include_subdomains")
+        return "hsts_missing_include_subdomains", "low", detail, RECOMMENDED_HEADERS["hsts"]
+    return None
+
+
+def _check_nosniff(headers: dict[str, list[str]]) -> Finding | None:
+    values = _tokens(headers.get("x-content-type-options", []))
+    if not values:
+        detail = fragment("headers.missing_x_content_type_options")
+        return "missing_x_content_type_options", "low", detail, RECOMMENDED_HEADERS["nosniff"]
+    if set(values) != {"nosniff"}:
+        detail = fragment("headers.invalid_x_content_type_options", values)
+        return "invalid_x_content_type_options", "low", detail, RECOMMENDED_HEADERS["nosniff"]
+    return None
+
+
+def _check_framing(headers: dict[str, list[str]]) -> Finding | None:
+    if any("frame-ancestors" in p for p in _policies(headers)):
+        return None
+    frame_options = sorted({v.upper() for v in _tokens(headers.get("x-frame-options", []))})
+    if not frame_options:
+        detail = fragment("headers.missing_clickjacking_protection")
+        return "missing_clickjacking_protection", "medium", detail, RECOMMENDED_HEADERS["frame"]
+    if len(frame_options) > 1 or frame_options[0] not in ("DENY", "SAMEORIGIN"):
+        detail = fragment("headers.invalid_x_frame_options", frame_options)
+        return "invalid_x_frame_options", "medium", detail, RECOMMENDED_HEADERS["frame"]
+    return None
+
+
+def _check_csp(headers: dict[str, list[str]]) -> Finding | None:
+    policies = _policies(headers)
+    if not policies:
+        detail = fragment("headers.missing_csp")
+        return "missing_csp", "low", detail, RECOMMENDED_HEADERS["csp"]
+    # Several policies all apply: inline scripts run only if every script policy allows them
+    inline = [a for a in (_allows_inline_scripts(p) for p in policies) if a is not None]
+    if not inline:
+        detail = fragment("headers.csp_no_script_restriction")
+        return "csp_no_script_restriction", "low", detail, RECOMMENDED_HEADERS["csp"]
+    if all(inline):
+        detail = fragment("headers.csp_unsafe_inline")
+        return "csp_unsafe_inline", "medium", detail, "Content-Security-Policy: script-src 'self' 'nonce-<random>'"
+    return None
+
+
+def _check_referrer(headers: dict[str, list[str]]) -> Finding | None:
+    values = _tokens(headers.get("referrer-policy", []))
+    if not values:
+        detail = fragment("headers.missing_referrer_policy")
+        return "missing_referrer_policy", "info", detail, RECOMMENDED_HEADERS["referrer"]
+    if values[-1] in _UNSAFE_REFERRER_POLICIES:  # browsers use the last value they understand
+        detail = fragment("headers.referrer_policy_unsafe", values[-1])
+        return "referrer_policy_unsafe", "low", detail, RECOMMENDED_HEADERS["referrer"]
+    return None
+
+
+def _check_permissions(headers: dict[str, list[str]]) -> Finding | None:
+    if headers.get("permissions-policy"):
+        return None
+    detail = fragment("headers.missing_permissions_policy")
+    if headers.get("feature-policy"):
+        detail = fragment("headers.feature_policy_only")
+    return "missing_permissions_policy", "info", detail, RECOMMENDED_HEADERS["permissions"]
+
+
+def _tokens(values: list[str]) -> list[str]:
+    """Comma-separated values across repeated headers, lowercased, in order."""
+    return [t.strip().lower() for value in values for t in value.split(",") if t.strip()]
+
+
+def _policies(headers: dict[str, list[str]]) -> list[dict[str, list[str]]]:
+    return [parse_csp(p) for p in headers.get("content-security-policy", []) if p]
+
+
+HEADER_CHECKS = {
+    "Strict-Transport-Security": _check_hsts,
+    "X-Content-Type-Options": _check_nosniff,
+    "Clickjacking protection": _check_framing,
+    "Content-Security-Policy": _check_csp,
+    "Referrer-Policy": _check_referrer,
+    "Permissions-Policy": _check_permissions,
+}
+
+
+def analyze(response_headers: HeaderInput, url: str) -> list[Issue]:
+    """
+    Check security headers of one response.
+
+    Args:
+        response_headers: Headers as a dict, httpx.Headers, or (name, value) pairs
+        url: URL the response came from (HSTS is only checked for HTTPS)
+
+    Returns:
+        One Issue per gap, each with a recommended header line, plus a
+        security_headers_grade summary when anything is missing
+    """
+    headers = header_values(response_headers)
+    issues = []
+    failed = []
+    checks = {
+        name: check
+        for name, check in HEADER_CHECKS.items()
+        if name != "Strict-Transport-Security" or urlparse(url).scheme == "https"
+    }
+    for name, check in checks.items():
+        finding = check(headers)
+        if finding is None:
+            continue
+        issue_type, severity, detail, fix = finding
+        failed.append(name)
+        text = msg("headers.finding", detail, fix)
+        issues.append(Issue(url=url, type=issue_type, severity=severity, **text))
+
+    if issues:
+        passed = len(checks) - len(failed)
+        letter = grade(passed, len(checks))
+        issues.append(
+            Issue(
+                url=url,
+                type="security_headers_grade",
+                severity="medium" if letter in ("D", "F") else "info",
+                **msg("headers.security_headers_grade", letter, passed, len(checks), failed),
+            )
+        )
+    return issues
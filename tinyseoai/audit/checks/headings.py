@@ -0,0 +1,140 @@
+"""
+Heading hierarchy and document outline.
+
+Only rendered headings count: <h1>-<h6> inside <template> or <noscript>
+are ignored. Elements with role="heading" are not part of the outline and
+are reported separately as info. Findings about the page structure carry
+the outline in their detail, e.g. 'Outline: H1 "Mugs" > H2 "Glazes"'.
+"""
+from __future__ import annotations
+
+from bs4 import BeautifulSoup, Tag
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import fragment, msg
+
+_HEADING_TAGS = ["h1", "h2", "h3", "h4", "h5", "h6"]
+_HIDDEN_CONTAINERS = ["template", "noscript"]
+
+
+class HeadingOptions(BaseModel):
+    """Limits for analyze."""
+
+    h1_max_length: int = Field(default=70, ge=1)
+    max_outline: int = Field(default=12, ge=1)  # headings shown in an outline before "and N more"
+    max_text: int = Field(default=40, ge=1)  # characters of heading text shown per entry
+
+
+class Heading(BaseModel):
+    """One rendered heading."""
+
+    level: int
+    text: str  # visible text, with image alt text standing in for images
+    image_without_alt: bool = False  # contains an <img> with no (or empty) alt
+
+
+def _is_hidden(tag: Tag) -> bool:
+    return tag.find_parent(_HIDDEN_CONTAINERS) is not None
+
+
+def headings(soup: BeautifulSoup) -> list[Heading]:
+    """Rendered <h1>-<h6> headings in document order."""
+    result = []
+    for tag in soup.find_all(_HEADING_TAGS):
+        if _is_hidden(tag):
+            continue
+        images = tag.find_all("img")
+        missing_alt = any(not (img.get("alt") or "").strip() for img in images)
+        alts = " ".join((img.get("alt") or "").strip() for img in images)
+        text = " ".join(f"{tag.get_text(' ')} {alts}".split())
+        result.append(Heading(level=int(tag.name[1]), text=text, image_without_alt=missing_alt))
+    return result
+
+
+def aria_headings(soup: BeautifulSoup) -> list[str]:
+    """Text of non-heading elements with role="heading" (hidden ones excluded)."""
+    return [
+        " ".join(tag.get_text(" ").split())
+        for tag in soup.find_all(attrs={"role": lambda r: r and r.strip().lower() == "heading"})
+        if tag.name not in _HEADING_TAGS and not _is_hidden(tag)
+    ]
+
+
+def _label(heading: Heading, options: HeadingOptions) -> str:
+    text = heading.text
+    if len(text) > options.max_text:
+        text = text[: options.max_text - 1].rstrip() + "…"
+    return f'H{heading.level} "{text}"' if text else f"H{heading.level} (empty)"
+
+
+def outline(items: list[Heading], options: HeadingOptions | None = None) -> str | dict:
+    """
+    The headings as one line, e.g. 'H1 "Mugs" > H2 "Glazes" > H2 "Care"'; a
+    fragment ending in "and N more" when there are more than max_outline.
+    """
+    options = options or HeadingOptions()
+    shown = " > ".join(_label(h, options) for h in items[: options.max_outline])
+    extra = len(items) - options.max_outline
+    return fragment("common.listing_more", shown, extra) if extra > 0 else shown
+
+
+def analyze(html: str, url: str, options: HeadingOptions | None = None) -> list[Issue]:
+    """
+    Check the heading structure of one page.
+
+    Args:
+        html: HTML content
+        url: URL of the page
+        options: Length and display limits
+
+    Returns:
+        Issues for a missing or repeated H1, skipped levels, empty headings,
+        image headings without alt text, an overlong H1, and role="heading"
+        elements (info)
+    """
+    options = options or HeadingOptions()
+    soup = BeautifulSoup(html, "lxml")
+    items = headings(soup)
+    issues = []
+
+    def add(issue_type: str, severity: str, *args, with_outline: bool = False) -> None:
+        key = f"headings.{issue_type}"
+        text = msg(key, *args)
+        if with_outline and items:
+            text = msg("headings.with_outline", fragment(key, *args), outline(items, options))
+        issues.append(Issue(url=url, type=issue_type, severity=severity, **text))
+
+    h1s = [h for h in items if h.level == 1]
+    if not h1s:
+        add("h1_missing", "medium", with_outline=True)
+    elif len(h1s) > 1:
+        add("multiple_h1", "low", len(h1s), with_outline=True)
+
+    skips = [
+        f"{_label(prev, options)} -> {_label(cur, options)}"
+        for prev, cur in zip(items, items[1:], strict=False)
+        if cur.level > prev.level + 1
+    ]
+    if skips:
+        add("heading_level_skipped", "low", len(skips), "; ".join(skips), with_outline=True)
+
+    empty = [h for h in items if not h.text and not h.image_without_alt]
+    if empty:
+        levels = ", ".join(f"H{h.level}" for h in empty)
+        add("heading_empty", "low", len(empty), levels)
+
+    image_only = [h for h in items if h.image_without_alt and not h.text]
+    if image_only:
+        levels = ", ".join(f"H{h.level}" for h in image_only)
+        add("heading_image_without_alt", "medium", len(image_only), levels)
+
+    long_h1s = [h for h in h1s if len(h.text) > options.h1_max_length]
+    if long_h1s:
+        add("h1_too_long", "low", len(long_h1s[0].text), options.h1_max_length, long_h1s[0].text)
+
+    aria = aria_headings(soup)
+    if aria:
+        names = ", ".join(f'"{t}"' for t in aria[: options.max_outline])
+        add("aria_heading", "info", len(aria), names)
+    return issues
@@ -0,0 +1,277 @@
+"""
+Favicon, apple-touch-icon, web app manifest, and theme-color checks.
+
+Files are probed HEAD first, falling back to a GET when HEAD is refused;
+manifests and manifest icons need their content and are fetched with GET.
+No response body is read past options.max_bytes.
+"""
+from __future__ import annotations
+
+import asyncio
+import json
+from urllib.parse import urljoin, urlparse
+
+import httpx
+from bs4 import BeautifulSoup
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import fragment, msg
+from ...utils.http import client as http_client
+from .social import image_size
+
+
+class IconOptions(BaseModel):
+    """Limits for probing icon and manifest files."""
+
+    max_bytes: int = Field(default=64 * 1024, ge=1024)  # read at most this much of any file
+    max_icons: int = Field(default=8, ge=0)  # manifest icons probed
+    timeout: float = 5.0
+
+
+class IconProbe(BaseModel):
+    """What requesting an icon or manifest found."""
+
+    url: str
+    status_code: int | None = None
+    content_type: str | None = None
+    body: bytes = Field(default=b"", exclude=True)
+    truncated: bool = False  # the body was longer than max_bytes
+    error: str | None = None  # "timeout", or the name of the HTTP error
+
+    @property
+    def ok(self) -> bool:
+        return self.status_code is not None and 200 <= self.status_code < 300
+
+    @property
+    def is_image(self) -> bool:
+        return (self.content_type or "").startswith("image/")
+
+    def failure(self) -> dict | None:
+        """Why the file cannot be used (a message fragment), or None."""
+        if self.error == "timeout":
+            return fragment("icons.timed_out")
+        if self.error:
+            return fragment("icons.failed", self.error)
+        if not self.ok:
+            return fragment("icons.http_status", self.status_code)
+        return None
+
+
+class IconLinks(BaseModel):
+    """Icon-related tags of a page, as absolute URLs."""
+
+    favicons: list[str] = []  # rel="icon" / "shortcut icon"
+    apple_touch_icons: list[str] = []
+    manifest: str | None = None
+    theme_color: str | None = None
+
+
+def _content_type(response: httpx.Response) -> str | None:
+    return response.headers.get("content-type", "").split(";")[0].strip() or None
+
+
+def _rels(tag) -> list[str]:
+    rel = tag.get("rel") or []
+    return [r.lower() for r in (rel.split() if isinstance(rel, str) else rel)]
+
+
+def icon_links(html: str, base_url: str) -> IconLinks:
+    """Read the favicon, apple-touch-icon, manifest, and theme-color tags."""
+    soup = BeautifulSoup(html, "lxml")
+    links = IconLinks()
+    for tag in soup.find_all("link"):
+        href = (tag.get("href") or "").strip()
+        if not href:
+            continue
+        rels = _rels(tag)
+        url = urljoin(base_url, href)
+        if "icon" in rels:
+            links.favicons.append(url)
+        if "apple-touch-icon" in rels or "apple-touch-icon-precomposed" in rels:
+            links.apple_touch_icons.append(url)
+        if "manifest" in rels and links.manifest is None:
+            links.manifest = url
+    theme = soup.find("meta", attrs={"name": lambda n: n and n.strip().lower() == "theme-color"})
+    if theme is not None and (theme.get("content") or "").strip():
+        links.theme_color = theme.get("content").strip()
+    return links
+
+
+async def probe(
+    url: str, client: httpx.AsyncClient, options: IconOptions | None = None, body: bool = False
+) -> IconProbe:
+    """
+    Request a file and record its status and content type.
+
+    Args:
+        url: File URL (data: URLs are not requested and always pass)
+        client: HTTP client
+        options: Byte cap and timeout
+        body: Read the body (up to options.max_bytes) instead of trying HEAD first
+
+    Returns:
+        IconProbe; the body is only filled when body is True
+    """
+    options = options or IconOptions()
+    result = IconProbe(url=url)
+    if url.startswith("data:"):
+        result.status_code = 200
+        result.content_type = url[5:].split(",")[0].split(";")[0] or None
+        return result
+    try:
+        if not body:
+            response = await client.head(url, timeout=options.timeout, follow_redirects=True)
+            if response.status_code not in (405, 501):  # HEAD supported
+                result.status_code = response.status_code
+                result.content_type = _content_type(response)
+                return result
+        request = client.stream("GET", url, timeout=options.timeout, follow_redirects=True)
+        async with request as response:
+            result.status_code = response.status_code
+            result.content_type = _content_type(response)
+            if not result.ok or not body:
+                return result
+            data = b""
+            async for chunk in response.aiter_bytes():
+                data += chunk
+                if len(data) > options.max_bytes:
+                    result.truncated = True
+                    break
+            result.body = data[: options.max_bytes]
+    except httpx.TimeoutException:
+        result.error = "timeout"
+    except httpx.HTTPError as e:
+        result.error = type(e).__name__
+    return result
+
+
+def _declared_sizes(value: str) -> list[tuple[int, int]] | None:
+    """Sizes from a manifest "sizes" value; None for "any" or nothing parseable."""
+    sizes = []
+    for token in value.lower().split():
+        if token == "any":
+            return None
+        width, _, height = token.partition("x")
+        if width.isdigit() and height.isdigit():
+            sizes.append((int(width), int(height)))
+    return sizes or None
+
+
+def _served_as(result: IconProbe) -> dict:
+    if result.content_type:
+        return fragment("icons.served_as", result.content_type)
+    return fragment("icons.no_content_type")
+
+
+async def _manifest_issues(
+    page_url: str, manifest_url: str, client: httpx.AsyncClient, options: IconOptions
+) -> list[Issue]:
+    issues = []
+
+    def add(issue_type: str, severity: str, *args) -> None:
+        text = msg(f"icons.{issue_type}", *args)
+        issues.append(Issue(url=page_url, type=issue_type, severity=severity, **text))
+
+    manifest = await probe(manifest_url, client, options, body=True)
+    problem = manifest.failure()
+    if manifest.truncated:
+        problem = fragment("icons.too_large", options.max_bytes)
+    if problem is None:
+        try:
+            data = json.loads(manifest.body.decode("utf-8-sig"))
+        except (UnicodeDecodeError, json.JSONDecodeError) as e:
+            problem = fragment("icons.invalid_json", str(e))
+        else:
+            if not isinstance(data, dict):
+                problem = fragment("icons.not_an_object")
+    if problem is not None:
+        add("manifest_broken", "medium", manifest_url, problem)
+        return issues
+
+    icons = [
+        i for i in data.get("icons") or [] if isinstance(i, dict) and isinstance(i.get("src"), str)
+    ]
+    if not icons:
+        add("manifest_icons_missing", "low", manifest_url)
+        return issues
+
+    icons = icons[: options.max_icons]
+    sources = [urljoin(manifest_url, i["src"].strip()) for i in icons]  # relative to the manifest
+    probes = await asyncio.gather(*(probe(src, client, options, body=True) for src in sources))
+    for icon, src, result in zip(icons, sources, probes, strict=True):
+        declared = str(icon.get("sizes") or "")
+        label = fragment("icons.label", src, declared) if declared else src
+        problem = result.failure()
+        if problem is None and not result.is_image:
+            problem = _served_as(result)
+        if problem is not None:
+            add("manifest_icon_broken", "medium", label, problem)
+            continue
+        sizes = _declared_sizes(declared)
+        actual = image_size(result.body)
+        if sizes and actual and actual not in sizes and result.content_type != "image/svg+xml":
+            add("manifest_icon_size_mismatch", "low", src, declared, actual[0], actual[1])
+    return issues
+
+
+async def analyze(
+    html: str,
+    base_url: str,
+    client: httpx.AsyncClient | None = None,
+    options: IconOptions | None = None,
+) -> list[Issue]:
+    """
+    Check a page's favicon, apple-touch-icon, web app manifest, and theme-color.
+
+    A page without a declared favicon passes when /favicon.ico on its host
+    returns an image. Manifest icons are resolved against the manifest URL
+    and must load as images of the declared sizes.
+
+    Args:
+        html: Page HTML
+        base_url: Page URL
+        client: HTTP client (one is created when omitted)
+        options: Byte cap, icon limit, and timeout
+
+    Returns:
+        missing_favicon, favicon_broken, missing_apple_touch_icon,
+        manifest_missing, manifest_broken, manifest_icons_missing,
+        manifest_icon_broken, manifest_icon_size_mismatch, and
+        theme_color_missing findings; each names the missing piece
+    """
+    options = options or IconOptions()
+    if client is None:
+        async with http_client() as own:
+            return await analyze(html, base_url, own, options)
+
+    links = icon_links(html, base_url)
+    issues = []
+
+    def add(issue_type: str, severity: str, *args) -> None:
+        text = msg(f"icons.{issue_type}", *args)
+        issues.append(Issue(url=base_url, type=issue_type, severity=severity, **text))
+
+    parsed = urlparse(base_url)
+    default = f"{parsed.scheme}://{parsed.netloc}/favicon.ico"
+    favicon = links.favicons[0] if links.favicons else default
+    result = await probe(favicon, client, options)
+    problem = result.failure()
+    if problem is None and not result.is_image:
+        problem = _served_as(result)
+    if problem is not None and not links.favicons:
+        add("missing_favicon", "low", favicon, problem)
+    elif problem is not None:
+        add("favicon_broken", "medium", favicon, problem)
+
+    if not links.apple_touch_icons:
+        add("missing_apple_touch_icon", "info")
+
+    if links.manifest is None:
+        add("manifest_missing", "info")
+    else:
+        issues.extend(await _manifest_issues(base_url, links.manifest, client, options))
+
+    if links.theme_color is None:
+        add("theme_color_missing", "info")
+    return issues
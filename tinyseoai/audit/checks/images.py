@@ -0,0 +1,214 @@
+"""
+Image audit: alt text, dimensions, file weight, formats, and lazy loading.
+
+Markup checks run on the HTML alone. With a client, each distinct image
+URL (up to a cap) gets one HEAD request that asks for AVIF/WebP, which
+gives both the file size and whether the server can serve a modern
+format. Findings are aggregated per problem, listing the affected images.
+"""
+from __future__ import annotations
+
+import asyncio
+from urllib.parse import urljoin
+
+import httpx
+from bs4 import BeautifulSoup
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import listing, msg
+
+MODERN_TYPES = ("image/avif", "image/webp")
+LEGACY_TYPES = ("image/jpeg", "image/png", "image/gif", "image/bmp")
+_MODERN_ACCEPT = "image/avif,image/webp,image/*;q=0.8"
+
+
+class ImageOptions(BaseModel):
+    """Thresholds and probe limits for analyze."""
+
+    max_bytes: int = Field(default=200 * 1024, ge=1)  # larger files are reported
+    eager_images: int = Field(default=3, ge=0)  # first N images count as above the fold
+    max_probes: int = Field(default=30, ge=0)  # distinct image URLs requested per page
+    concurrency: int = Field(default=4, ge=1)
+    timeout: float = 5.0
+    max_listed: int = Field(default=5, ge=1)  # images named in a finding before "and N more"
+
+
+class PageImage(BaseModel):
+    """One <img> on a page."""
+
+    src: str  # absolute URL ("" when the tag has no src)
+    alt: str | None  # None when the attribute is missing
+    has_dimensions: bool
+    loading: str | None = None
+    lazy_by_script: bool = False  # data-src and similar: a script swaps in the real image
+    modern_source: bool = False  # inside <picture> with an AVIF or WebP <source>
+    in_empty_link: bool = False  # the only content of a link
+
+    @property
+    def decorative(self) -> bool:
+        """Explicit alt="" marks an image as decorative."""
+        return self.alt is not None and not self.alt.strip()
+
+
+class ImageHead(BaseModel):
+    """Result of the HEAD request for one image."""
+
+    url: str
+    status_code: int | None = None
+    content_type: str | None = None
+    content_length: int | None = None
+    error: str | None = None
+
+
+def page_images(html: str, url: str) -> list[PageImage]:
+    """All <img> elements in document order, including those inside <picture>."""
+    soup = BeautifulSoup(html, "lxml")
+    images = []
+    for img in soup.find_all("img"):
+        src = (img.get("src") or "").strip()
+        picture = img.parent if img.parent is not None and img.parent.name == "picture" else None
+        modern = picture is not None and any(
+            (source.get("type") or "").lower() in MODERN_TYPES for source in picture.find_all("source")
+        )
+        link = img.parent
+        while link is not None and link.name not in ("a", "[document]"):
+            link = link.parent
+        in_empty_link = (
+            link is not None and link.name == "a" and not link.get_text(strip=True) and len(link.find_all("img")) == 1
+        )
+        images.append(
+            PageImage(
+                src=urljoin(url, src) if src else "",
+                alt=img.get("alt"),
+                has_dimensions=bool(img.get("width")) and bool(img.get("height")),
+                loading=(img.get("loading") or "").lower() or None,
+                lazy_by_script=bool(img.get("data-src") or img.get("data-lazy-src")),
+                modern_source=modern,
+                in_empty_link=in_empty_link,
+            )
+        )
+    return images
+
+
+async def head_image(url: str, client: httpx.AsyncClient, timeout: float = 5.0) -> ImageHead:
+    """HEAD an image, asking for AVIF/WebP so content negotiation shows up in the answer."""
+    head = ImageHead(url=url)
+    try:
+        response = await client.head(url, headers={"Accept": _MODERN_ACCEPT}, timeout=timeout, follow_redirects=True)
+    except httpx.TimeoutException:
+        head.error = "timeout"
+        return head
+    except httpx.HTTPError as e:
+        head.error = type(e).__name__
+        return head
+    head.status_code = response.status_code
+    head.content_type = response.headers.get("content-type", "").split(";")[0].strip().lower() or None
+    length = response.headers.get("content-length")
+    head.content_length = int(length) if length and length.isdigit() else None
+    return head
+
+
+def _label(image: PageImage) -> str:
+    return image.src or "<img> without src"
+
+
+def image_issues(
+    page_url: str,
+    images: list[PageImage],
+    heads: dict[str, ImageHead] | None = None,
+    options: ImageOptions | None = None,
+) -> list[Issue]:
+    """Aggregate per-image problems into one Issue per problem type."""
+    options = options or ImageOptions()
+    heads = heads or {}
+    found: dict[str, list[str]] = {}
+
+    def note(issue_type: str, item: str) -> None:
+        items = found.setdefault(issue_type, [])
+        if item not in items:
+            items.append(item)
+
+    for position, image in enumerate(images):
+        if image.alt is None:
+            note("img_alt_missing", _label(image))
+        elif image.decorative and image.in_empty_link:
+            note("img_alt_empty_in_link", _label(image))
+        if not image.has_dimensions:
+            note("images_without_dimensions", _label(image))
+        if position >= options.eager_images and image.loading != "lazy" and not image.lazy_by_script:
+            note("images_not_lazy_loaded", _label(image))
+
+        head = heads.get(image.src)
+        if head is None:
+            continue
+        if head.status_code is not None and head.status_code >= 400:
+            note("image_broken", f"{image.src} (HTTP {head.status_code})")
+            continue
+        if head.content_length is not None and head.content_length > options.max_bytes:
+            note("image_too_large", f"{image.src} ({head.content_length // 1024} KB)")
+        if head.content_type in LEGACY_TYPES and not image.modern_source:
+            note("image_legacy_format", f"{image.src} ({head.content_type.removeprefix('image/')})")
+
+    # Severity, and arguments of the message besides the count and the listing
+    descriptions = {
+        "img_alt_missing": ("low", ()),
+        "img_alt_empty_in_link": ("medium", ()),
+        "images_without_dimensions": ("medium", ()),
+        "images_not_lazy_loaded": ("low", ()),
+        "image_broken": ("medium", ()),
+        "image_too_large": ("medium", (options.max_bytes // 1024,)),
+        "image_legacy_format": ("low", ()),
+    }
+    issues = []
+    for issue_type, (severity, args) in descriptions.items():
+        items = found.get(issue_type)
+        if items:
+            issues.append(
+                Issue(
+                    url=page_url,
+                    type=issue_type,
+                    severity=severity,
+                    **msg(
+                        f"images.{issue_type}",
+                        len(items),
+                        listing(items, options.max_listed),
+                        *args,
+                    ),
+                )
+            )
+    return issues
+
+
+async def analyze(
+    html: str,
+    url: str,
+    client: httpx.AsyncClient | None = None,
+    options: ImageOptions | None = None,
+) -> list[Issue]:
+    """
+    Audit the images on a page.
+
+    Args:
+        html: Page HTML
+        url: Page URL
+        client: HTTP client for HEAD requests; markup checks only if omitted
+        options: Size threshold, fold heuristic, and probe limits
+
+    Returns:
+        One Issue per problem type, listing the affected images
+    """
+    options = options or ImageOptions()
+    images = page_images(html, url)
+    heads: dict[str, ImageHead] = {}
+    if client is not None and options.max_probes:
+        urls = list(dict.fromkeys(i.src for i in images if i.src.startswith(("http://", "https://"))))
+        semaphore = asyncio.Semaphore(options.concurrency)
+
+        async def probe(image_url: str) -> ImageHead:
+            async with semaphore:
+                return await head_image(image_url, client, options.timeout)
+
+        for head in await asyncio.gather(*(probe(u) for u in urls[: options.max_probes])):
+            heads[head.url] = head
+    return image_issues(url, images, heads, options)
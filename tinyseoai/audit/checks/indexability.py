@@ -1,13 +1,28 @@
 """
 Indexability checks including canonical tags, robots meta, and XML sitemap validation.
+
+analyze evaluates robots meta tags and X-Robots-Tag headers together the
+way search engines do: directives from every source that applies to the
+crawler are combined, and the most restrictive one wins.
 """
 from __future__ import annotations
 
+from typing import TYPE_CHECKING
 from urllib.parse import urljoin, urlparse
 
 from bs4 import BeautifulSoup
+from pydantic import BaseModel, Field
 
 from ...data.models import Issue
+from ...i18n import fragment, msg
+
+if TYPE_CHECKING:
+    from ..crawler import FetchedPage
+    from ..robots import RobotsRules
+
+# Directives whose value follows a colon; any other "name: ..." prefix names a crawler
+_VALUE_DIRECTIVES = {"max-snippet", "max-image-preview", "max-video-preview", "unavailable_after"}
+_SHORTHANDS = {"none": ("noindex", "nofollow"), "all": ("index", "follow")}
 
 
 class IndexabilityChecker:
@@ -36,7 +51,7 @@ class IndexabilityChecker:
         issues = []
 
         issues.extend(self.check_canonical())
-        issues.extend(self.check_robots_meta())
+        # Robots meta tags are evaluated with X-Robots-Tag headers by analyze
         issues.extend(self.check_meta_robots())
         issues.extend(self.check_x_robots_tag())
 
@@ -60,7 +75,7 @@ class IndexabilityChecker:
                     url=self.url,
                     type="missing_canonical",
                     severity="medium",
-                    detail="No canonical tag found. This can cause duplicate content issues.",
+                    **msg("indexability.missing_canonical"),
                 )
             )
         elif len(canonical_tags) > 1:
@@ -69,8 +84,7 @@ class IndexabilityChecker:
                     url=self.url,
                     type="multiple_canonical_tags",
                     severity="high",
-                    detail=f"Multiple canonical tags found ({len(canonical_tags)}). "
-                    "Only one canonical tag should be present.",
+                    **msg("indexability.multiple_canonical_tags", len(canonical_tags)),
                 )
             )
         else:
@@ -83,7 +97,7 @@ class IndexabilityChecker:
                         url=self.url,
                         type="empty_canonical",
                         severity="high",
-                        detail="Canonical tag is empty",
+                        **msg("indexability.empty_canonical"),
                     )
                 )
             else:
@@ -97,7 +111,7 @@ class IndexabilityChecker:
                             url=self.url,
                             type="canonical_not_absolute",
                             severity="medium",
-                            detail="Canonical URL should be absolute (include full URL)",
+                            **msg("indexability.canonical_not_absolute"),
                         )
                     )
 
@@ -111,7 +125,7 @@ class IndexabilityChecker:
                             url=self.url,
                             type="canonical_points_elsewhere",
                             severity="info",
-                            detail=f"Canonical points to different URL: {canonical_url}",
+                            **msg("indexability.canonical_points_elsewhere", canonical_url),
                         )
                     )
 
@@ -125,7 +139,7 @@ class IndexabilityChecker:
                             url=self.url,
                             type="canonical_http_on_https",
                             severity="high",
-                            detail="HTTPS page has HTTP canonical - this can cause indexing issues",
+                            **msg("indexability.canonical_http_on_https"),
                         )
                     )
 
@@ -148,7 +162,7 @@ class IndexabilityChecker:
                     url=self.url,
                     type="multiple_robots_meta",
                     severity="medium",
-                    detail=f"Multiple robots meta tags found ({len(robots_tags)})",
+                    **msg("indexability.multiple_robots_meta", len(robots_tags)),
                 )
             )
 
@@ -167,7 +181,7 @@ class IndexabilityChecker:
                         url=self.url,
                         type="conflicting_robots_directives",
                         severity="high",
-                        detail="Conflicting robots directives: both 'index' and 'noindex'",
+                        **msg("indexability.conflicting_robots_directives", "index", "noindex"),
                     )
                 )
 
@@ -177,7 +191,7 @@ class IndexabilityChecker:
                         url=self.url,
                         type="conflicting_robots_directives",
                         severity="high",
-                        detail="Conflicting robots directives: both 'follow' and 'nofollow'",
+                        **msg("indexability.conflicting_robots_directives", "follow", "nofollow"),
                     )
                 )
 
@@ -188,7 +202,7 @@ class IndexabilityChecker:
                         url=self.url,
                         type="noindex_directive",
                         severity="info",
-                        detail="Page has noindex directive - will not be indexed by search engines",
+                        **msg("indexability.noindex_directive"),
                     )
                 )
 
@@ -199,7 +213,7 @@ class IndexabilityChecker:
                         url=self.url,
                         type="nofollow_directive",
                         severity="info",
-                        detail="Page has nofollow directive - links will not be followed",
+                        **msg("indexability.nofollow_directive"),
                     )
                 )
 
@@ -210,7 +224,7 @@ class IndexabilityChecker:
                         url=self.url,
                         type="robots_none_directive",
                         severity="info",
-                        detail="Page has 'none' directive (equivalent to noindex, nofollow)",
+                        **msg("indexability.robots_none_directive"),
                     )
                 )
 
@@ -221,7 +235,7 @@ class IndexabilityChecker:
                         url=self.url,
                         type="noarchive_directive",
                         severity="info",
-                        detail="Page has noarchive directive - cached copy will not be available",
+                        **msg("indexability.noarchive_directive"),
                     )
                 )
 
@@ -250,7 +264,7 @@ class IndexabilityChecker:
                         url=self.url,
                         type="googlebot_noindex_mismatch",
                         severity="medium",
-                        detail="Googlebot meta has noindex but general robots meta does not",
+                        **msg("indexability.googlebot_noindex_mismatch"),
                     )
                 )
 
@@ -300,7 +314,7 @@ class SitemapValidator:
                     url=self.url,
                     type="invalid_sitemap_format",
                     severity="high",
-                    detail="Sitemap does not have valid urlset or sitemapindex root element",
+                    **msg("indexability.invalid_sitemap_format"),
                 )
             )
             return issues
@@ -313,7 +327,7 @@ class SitemapValidator:
                     url=self.url,
                     type="sitemap_too_many_urls",
                     severity="high",
-                    detail=f"Sitemap contains {len(urls)} URLs (max 50,000 recommended)",
+                    **msg("indexability.sitemap_too_many_urls", len(urls)),
                 )
             )
 
@@ -326,7 +340,7 @@ class SitemapValidator:
                         url=self.url,
                         type="sitemap_url_missing_loc",
                         severity="high",
-                        detail="Sitemap URL entry missing <loc> element",
+                        **msg("indexability.sitemap_url_missing_loc"),
                     )
                 )
                 continue
@@ -340,7 +354,7 @@ class SitemapValidator:
                         url=self.url,
                         type="sitemap_relative_url",
                         severity="high",
-                        detail=f"Sitemap contains relative URL: {url_value}",
+                        **msg("indexability.sitemap_relative_url", url_value),
                     )
                 )
 
@@ -351,7 +365,7 @@ class SitemapValidator:
                         url=self.url,
                         type="sitemap_url_has_spaces",
                         severity="high",
-                        detail=f"Sitemap URL contains spaces: {url_value}",
+                        **msg("indexability.sitemap_url_has_spaces", url_value),
                     )
                 )
 
@@ -366,7 +380,7 @@ class SitemapValidator:
                                 url=self.url,
                                 type="sitemap_invalid_priority",
                                 severity="medium",
-                                detail=f"Priority must be between 0.0 and 1.0, got {priority_val}",
+                                **msg("indexability.priority_out_of_range", priority_val),
                             )
                         )
                 except ValueError:
@@ -375,7 +389,7 @@ class SitemapValidator:
                             url=self.url,
                             type="sitemap_invalid_priority",
                             severity="medium",
-                            detail=f"Invalid priority value: {priority.string}",
+                            **msg("indexability.sitemap_invalid_priority", priority.string),
                         )
                     )
 
@@ -397,7 +411,7 @@ class SitemapValidator:
                             url=self.url,
                             type="sitemap_invalid_changefreq",
                             severity="low",
-                            detail=f"Invalid changefreq value: {changefreq.string}",
+                            **msg("indexability.sitemap_invalid_changefreq", changefreq.string),
                         )
                     )
 
@@ -430,7 +444,7 @@ def check_pagination(html: str, url: str) -> list[Issue]:
                     url=url,
                     type="pagination_next_empty",
                     severity="medium",
-                    detail="rel='next' link has no href attribute",
+                    **msg("indexability.pagination_next_empty"),
                 )
             )
 
@@ -440,8 +454,216 @@ def check_pagination(html: str, url: str) -> list[Issue]:
                     url=url,
                     type="pagination_prev_empty",
                     severity="medium",
-                    detail="rel='prev' link has no href attribute",
+                    **msg("indexability.pagination_prev_empty"),
+                )
+            )
+
+    return issues
+
+
+class IndexabilityOptions(BaseModel):
+    """Settings for analyze."""
+
+    user_agent: str = "googlebot"  # crawler whose own meta tags and header lines also apply
+    important_depth: int = Field(default=1, ge=0)  # noindex this close to the seed is critical
+
+
+class RobotsDirectives(BaseModel):
+    """Robots directives that apply to one crawler, by source."""
+
+    meta: set[str] = Field(default_factory=set)  # <meta name="robots"> and the crawler's own tag
+    header: set[str] = Field(default_factory=set)  # X-Robots-Tag
+
+    @property
+    def noindex(self) -> bool:
+        return "noindex" in self.meta | self.header
+
+    @property
+    def nofollow(self) -> bool:
+        return "nofollow" in self.meta | self.header
+
+    def sources(self, directive: str) -> str:
+        """Where a directive was found, e.g. "robots meta and X-Robots-Tag"."""
+        found = [
+            name
+            for name, values in (("robots meta", self.meta), ("X-Robots-Tag", self.header))
+            if directive in values
+        ]
+        return " and ".join(found)
+
+    def conflicts(self) -> list[tuple[str, str]]:
+        """(allowing, restricting) directive pairs that are both present."""
+        present = self.meta | self.header
+        return [
+            (allow, restrict)
+            for allow, restrict in (("index", "noindex"), ("follow", "nofollow"))
+            if allow in present and restrict in present
+        ]
+
+
+def parse_directives(value: str) -> dict[str, set[str]]:
+    """
+    Split a robots meta content or X-Robots-Tag value into directives per crawler.
+
+    Directives after a "name:" prefix (as in "googlebot: noindex") apply to
+    that crawler only, up to the next prefix; others are filed under "*".
+    "none" and "all" are expanded.
+
+    Args:
+        value: Comma-separated directives
+
+    Returns:
+        Lowercase directives keyed by lowercase crawler name
+    """
+    directives: dict[str, set[str]] = {}
+    agent = "*"
+    for token in value.lower().split(","):
+        name, colon, rest = token.partition(":")
+        if colon and name.strip() not in _VALUE_DIRECTIVES and rest.strip():
+            agent, token = name.strip(), rest
+        token = token.strip()
+        if token:
+            directives.setdefault(agent, set()).update(_SHORTHANDS.get(token, (token,)))
+    return directives
+
+
+def page_directives(
+    html: str, headers: dict[str, str], user_agent: str = "googlebot"
+) -> RobotsDirectives:
+    """
+    Combine the robots meta tags and X-Robots-Tag headers that apply to a crawler.
+
+    Args:
+        html: Page HTML (empty for non-HTML responses)
+        headers: Response headers, any case
+        user_agent: Crawler name; its own meta tag and header lines apply too
+
+    Returns:
+        RobotsDirectives with the general and crawler-specific directives of each source
+    """
+    agent = user_agent.lower()
+    directives = RobotsDirectives()
+    if html:
+        soup = BeautifulSoup(html, "lxml")
+        for tag in soup.find_all("meta", attrs={"name": True, "content": True}):
+            name = tag.get("name").strip().lower()
+            if name in ("robots", agent):
+                for values in parse_directives(tag.get("content")).values():
+                    directives.meta |= values
+    for key, value in headers.items():
+        if key.lower() == "x-robots-tag":
+            parsed = parse_directives(value)
+            directives.header |= parsed.get("*", set()) | parsed.get(agent, set())
+    return directives
+
+
+def _sources(directives: RobotsDirectives, directive: str) -> str | dict:
+    """RobotsDirectives.sources as a message argument, so "and" is translated."""
+    found = directives.sources(directive).split(" and ")
+    return fragment("indexability.both_sources", *found) if len(found) == 2 else found[0]
+
+
+def _blocked_signals(html: str, directives: RobotsDirectives) -> list[str]:
+    soup = BeautifulSoup(html, "lxml")
+    signals = []
+    if soup.find("link", rel="canonical"):
+        signals.append("canonical")
+    if soup.find("link", hreflang=True):
+        signals.append("hreflang")
+    if directives.noindex:
+        signals.append("noindex")
+    return signals
+
+
+def analyze(
+    pages: list[FetchedPage],
+    robots: RobotsRules | None = None,
+    options: IndexabilityOptions | None = None,
+) -> list[Issue]:
+    """
+    Check robots meta tags and X-Robots-Tag headers across crawled pages.
+
+    Directives from the meta tags and headers that apply to the crawler are
+    combined and the most restrictive wins, so noindex in either source
+    keeps a page out of the index whatever the other says. A page blocked
+    by robots.txt is never fetched by search engines, so its canonical,
+    hreflang, and noindex are never seen.
+
+    Args:
+        pages: Fetched pages (anything with url, html, headers, status, and depth)
+        robots: Parsed robots.txt of the site, if any
+        options: Crawler name and how deep a noindex counts as critical
+
+    Returns:
+        important_page_noindex (high) for the seed and pages near it,
+        noindex_directive, nofollow_directive, conflicting_robots_directives,
+        and robots_blocked_signals findings
+    """
+    options = options or IndexabilityOptions()
+    issues = []
+
+    def add(url: str, issue_type: str, severity: str, key: str, *args) -> None:
+        issues.append(
+            Issue(url=url, type=issue_type, severity=severity, **msg(f"indexability.{key}", *args))
+        )
+
+    for page in pages:
+        if not 200 <= page.status < 300:
+            continue
+        directives = page_directives(page.html, page.headers, options.user_agent)
+
+        if robots is not None and not robots.can_fetch(page.url, options.user_agent):
+            signals = _blocked_signals(page.html, directives) if page.html else []
+            if signals:
+                add(
+                    page.url,
+                    "robots_blocked_signals",
+                    "medium",
+                    "robots_blocked_signals",
+                    signals,
                 )
+            continue
+
+        for allow, restrict in directives.conflicts():
+            add(
+                page.url,
+                "conflicting_robots_directives",
+                "high",
+                "directive_conflict",
+                allow,
+                _sources(directives, allow),
+                restrict,
+                _sources(directives, restrict),
             )
 
+        if directives.noindex and page.depth <= options.important_depth:
+            where = (
+                fragment("indexability.start_page")
+                if page.depth == 0
+                else fragment("indexability.page_at_depth", page.depth)
+            )
+            add(
+                page.url,
+                "important_page_noindex",
+                "high",
+                "important_page_noindex",
+                where,
+                _sources(directives, "noindex"),
+            )
+        elif directives.noindex:
+            add(
+                page.url,
+                "noindex_directive",
+                "info",
+                "noindexed_by",
+                _sources(directives, "noindex"),
+            )
+        if directives.nofollow:
+            add(
+                page.url,
+                "nofollow_directive",
+                "info",
+                "nofollow_by",
+                _sources(directives, "nofollow"),
+            )
     return issues
@@ -0,0 +1,275 @@
+"""
+Target keyword coverage: are the keywords of an SEO brief where they count?
+
+Targets map URL patterns (globs or re: patterns, as for --include) to keyword
+lists, from a TOML file:
+
+    "/mugs/*" = ["stoneware mug", "handmade mug"]
+    "re:^https://example\\.com/$" = ["pottery studio"]
+
+or a CSV file with one pattern,keyword row per keyword (an optional
+"pattern,keyword" header is skipped). A page takes the keywords of every
+pattern it matches. It is covered in the title, the H1, the first
+paragraph, and the meta description when any of its keywords is there as
+whole words, compared case-folded (no stemming). Elements a page lacks are
+not reported here; the meta and headings checks report them already. A
+keyword making up more than max_density of the visible words is stuffing.
+"""
+from __future__ import annotations
+
+import csv
+import io
+import re
+import tomllib
+import unicodedata
+from collections.abc import Iterable
+from pathlib import Path
+
+from bs4 import BeautifulSoup
+from pydantic import BaseModel, Field, ValidationError, field_validator
+
+from ...data.models import Issue
+from ...exceptions import ConfigError
+from ...i18n import msg
+from ..scope import compile_pattern, url_matches
+from .content import _CJK, strip_chrome
+from .headings import headings
+
+# Where a target keyword is looked for, in report order
+PLACEMENTS = ("title", "h1", "first_paragraph", "meta_description")
+_SEVERITY = {"title": "medium", "h1": "low", "first_paragraph": "low", "meta_description": "low"}
+
+# Letters and digits; each CJK character is a token of its own
+_TOKEN = re.compile(rf"[{_CJK}]|[^\W_{_CJK}]+")
+
+
+class KeywordOptions(BaseModel):
+    """Thresholds for analyze."""
+
+    max_density: float = Field(default=0.03, gt=0, le=1)  # share of the visible words
+    min_words: int = Field(default=100, ge=1)  # shorter pages are not checked for stuffing
+
+
+class KeywordTarget(BaseModel):
+    """Keywords for the pages matching one URL pattern."""
+
+    pattern: str
+    keywords: list[str]
+
+    @field_validator("pattern")
+    @classmethod
+    def _pattern_compiles(cls, pattern: str) -> str:
+        compile_pattern(pattern)
+        return pattern
+
+    @field_validator("keywords")
+    @classmethod
+    def _keywords_have_words(cls, keywords: list[str]) -> list[str]:
+        for keyword in keywords:
+            if not tokens(keyword):
+                raise ValueError(f"keyword {keyword!r} has no words")
+        return keywords
+
+
+class KeywordTargets(BaseModel):
+    """Target keywords by URL pattern."""
+
+    targets: list[KeywordTarget] = Field(default_factory=list)
+
+    def for_url(self, url: str) -> list[str]:
+        """Keywords of every pattern the URL matches, without case-folded repeats."""
+        found: dict[tuple[str, ...], str] = {}
+        for target in self.targets:
+            if url_matches(url, [target.pattern]):
+                for keyword in target.keywords:
+                    found.setdefault(tuple(tokens(keyword)), keyword)
+        return list(found.values())
+
+    @classmethod
+    def from_toml(cls, text: str) -> KeywordTargets:
+        """Parse pattern = [keywords] pairs; raises ConfigError on invalid TOML or targets."""
+        try:
+            data = tomllib.loads(text)
+        except tomllib.TOMLDecodeError as e:
+            raise ConfigError(f"Invalid TOML: {e}") from e
+        return cls._build(data.items())
+
+    @classmethod
+    def from_csv(cls, text: str) -> KeywordTargets:
+        """Parse pattern,keyword rows; raises ConfigError on short rows or invalid targets."""
+        grouped: dict[str, list[str]] = {}
+        for n, row in enumerate(csv.reader(io.StringIO(text)), start=1):
+            if not any(cell.strip() for cell in row):
+                continue
+            if n == 1 and [c.strip().lower() for c in row[:2]] == ["pattern", "keyword"]:
+                continue
+            if len(row) < 2:
+                raise ConfigError(f"line {n}: expected pattern,keyword")
+            grouped.setdefault(row[0].strip(), []).append(row[1].strip())
+        return cls._build(grouped.items())
+
+    @classmethod
+    def _build(cls, items: Iterable[tuple[str, object]]) -> KeywordTargets:
+        targets = []
+        for pattern, keywords in items:
+            try:
+                targets.append(KeywordTarget(pattern=pattern, keywords=keywords))
+            except ValidationError as e:
+                raise ConfigError(f"{pattern}: {e.errors()[0]['msg']}") from e
+        return cls(targets=targets)
+
+    @classmethod
+    def load(cls, path: Path) -> KeywordTargets:
+        """Load targets from a .csv file, or TOML otherwise; raises ConfigError naming the file."""
+        try:
+            text = Path(path).read_text(encoding="utf-8-sig")
+        except OSError as e:
+            raise ConfigError(f"Cannot read {path}: {e.strerror or e}") from e
+        try:
+            if Path(path).suffix.lower() == ".csv":
+                return cls.from_csv(text)
+            return cls.from_toml(text)
+        except ConfigError as e:
+            raise ConfigError(f"{path}: {e}") from e
+
+
+class PageCoverage(BaseModel):
+    """Where one page's target keywords appear."""
+
+    url: str
+    keywords: list[str]
+    words: int  # visible words
+    placements: dict[str, bool | None]  # placement -> a keyword is there; None: no such element
+    density: dict[str, float]  # keyword -> share of the visible words
+
+
+class KeywordAnalysis(BaseModel):
+    """Issues and the coverage table of the pages that have target keywords."""
+
+    issues: list[Issue] = Field(default_factory=list)
+    pages: list[PageCoverage] = Field(default_factory=list)
+
+    def coverage(self) -> dict[str, list[int]]:
+        """Per placement: [pages with a keyword there, pages that have the element]."""
+        table = {}
+        for placement in PLACEMENTS:
+            values = [p.placements[placement] for p in self.pages]
+            present = [v for v in values if v is not None]
+            table[placement] = [sum(present), len(present)]
+        return table
+
+    def meta(self) -> dict:
+        """The coverage table as stored in meta["keywords"]."""
+        return {
+            "coverage": self.coverage(),
+            "pages": [p.model_dump() for p in self.pages],
+        }
+
+
+def tokens(text: str) -> list[str]:
+    """Case-folded words of a text (NFKC-normalized; CJK characters one by one)."""
+    return _TOKEN.findall(unicodedata.normalize("NFKC", text).casefold())
+
+
+def occurrences(words: list[str], keyword: list[str]) -> int:
+    """How often a tokenized keyword appears as consecutive words."""
+    n = len(keyword)
+    return sum(1 for i in range(len(words) - n + 1) if words[i : i + n] == keyword)
+
+
+def _text(tag) -> str | None:
+    return " ".join(tag.get_text(" ").split()) if tag is not None else None
+
+
+def placements(html: str) -> tuple[dict[str, str | None], str]:
+    """
+    The text of each placement (None when the page lacks it) and the visible text.
+
+    The first paragraph is the first non-empty <p> outside navigation and other
+    page chrome.
+    """
+    soup = BeautifulSoup(html, "lxml")
+    description = soup.find(
+        "meta", attrs={"name": lambda n: n and n.strip().lower() == "description"}
+    )
+    h1s = [h.text for h in headings(soup) if h.level == 1]
+    found: dict[str, str | None] = {
+        "title": _text(soup.find("title")),
+        "h1": " ".join(h1s) if h1s else None,
+        "meta_description": (description.get("content") or "").strip() if description else None,
+    }
+    strip_chrome(soup)
+    paragraphs = (_text(p) for p in soup.find_all("p"))
+    found["first_paragraph"] = next((p for p in paragraphs if p), None)
+    return found, " ".join(soup.get_text(" ").split())
+
+
+def analyze_page(
+    url: str, html: str, keywords: list[str], options: KeywordOptions | None = None
+) -> tuple[PageCoverage, list[Issue]]:
+    """Coverage of one page's keywords, with issues for missing placements and stuffing."""
+    options = options or KeywordOptions()
+    texts, visible = placements(html)
+    targets = [(k, tokens(k)) for k in keywords]
+    listed = ", ".join(keywords)
+    issues = []
+    covered: dict[str, bool | None] = {}
+    for placement in PLACEMENTS:
+        text = texts[placement]
+        if text is None:
+            covered[placement] = None
+            continue
+        words = tokens(text)
+        covered[placement] = any(occurrences(words, t) for _, t in targets)
+        if not covered[placement]:
+            issue_type = f"keyword_missing_{placement}"
+            fields = msg(f"keywords.{issue_type}", listed)
+            issues.append(
+                Issue(url=url, type=issue_type, severity=_SEVERITY[placement], **fields)
+            )
+
+    words = tokens(visible)
+    density = {}
+    for keyword, target in targets:
+        count = occurrences(words, target)
+        density[keyword] = round(count * len(target) / len(words), 4) if words else 0.0
+        if len(words) >= options.min_words and density[keyword] > options.max_density:
+            fields = msg(
+                "keywords.keyword_stuffing",
+                keyword,
+                count,
+                density[keyword] * 100,
+                len(words),
+                options.max_density * 100,
+            )
+            issues.append(Issue(url=url, type="keyword_stuffing", severity="medium", **fields))
+    coverage = PageCoverage(
+        url=url, keywords=keywords, words=len(words), placements=covered, density=density
+    )
+    return coverage, issues
+
+
+def analyze(
+    pages: Iterable, targets: KeywordTargets, options: KeywordOptions | None = None
+) -> KeywordAnalysis:
+    """
+    Check crawled pages against their target keywords.
+
+    Args:
+        pages: Crawled pages (url and html); pages no pattern matches are skipped
+        targets: Target keywords by URL pattern
+        options: Stuffing thresholds
+
+    Returns:
+        KeywordAnalysis with keyword_missing_<placement> and keyword_stuffing
+        issues, and a coverage row per targeted page
+    """
+    analysis = KeywordAnalysis()
+    for page in pages:
+        keywords = targets.for_url(page.url) if page.html else []
+        if not keywords:
+            continue
+        coverage, issues = analyze_page(page.url, page.html, keywords, options)
+        analysis.pages.append(coverage)
+        analysis.issues.extend(issues)
+    return analysis
@@ -0,0 +1,179 @@
+"""
+<html lang> checks: presence, BCP 47 syntax, and agreement with the text.
+
+The language of the body text is detected with langdetect when it is
+installed (pip install "tinyseoai[lang]"); without it only the attribute
+and hreflang checks run. Detection needs enough text to be reliable, so
+short pages are skipped and mismatches are reported as info with the
+detector's confidence.
+"""
+from __future__ import annotations
+
+import re
+from collections.abc import Callable
+from typing import TYPE_CHECKING
+
+from bs4 import BeautifulSoup
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import fragment, listing, msg
+from .content import visible_text, word_count
+
+if TYPE_CHECKING:
+    from ..crawler import FetchedPage
+
+# RFC 5646 tag syntax: language, extlang, script, region, variants, extensions, private use
+_LANGUAGE_TAG = re.compile(
+    r"^[a-z]{2,3}(?:-[a-z]{3}){0,3}"
+    r"(?:-[a-z]{4})?"
+    r"(?:-(?:[a-z]{2}|\d{3}))?"
+    r"(?:-(?:[a-z\d]{5,8}|\d[a-z\d]{3}))*"
+    r"(?:-[a-wyz\d](?:-[a-z\d]{2,8})+)*"
+    r"(?:-x(?:-[a-z\d]{1,8})+)?$",
+    re.IGNORECASE,
+)
+
+Detector = Callable[[str], tuple[str, float] | None]
+
+
+def is_valid_language_tag(tag: str) -> bool:
+    """Check a lang value against BCP 47 syntax ("en", "en-US", "zh-Hant-TW", "sr-Latn")."""
+    return bool(_LANGUAGE_TAG.match(tag))
+
+
+def detect_language(text: str) -> tuple[str, float] | None:
+    """
+    Detect the language of a text with langdetect.
+
+    Returns:
+        (ISO 639-1 code, probability), or None when langdetect is not
+        installed or cannot tell
+    """
+    try:
+        from langdetect import DetectorFactory, detect_langs
+        from langdetect.lang_detect_exception import LangDetectException
+    except ImportError:
+        return None
+    DetectorFactory.seed = 0  # deterministic results
+    try:
+        best = detect_langs(text)[0]
+    except (LangDetectException, IndexError):
+        return None
+    return best.lang.split("-")[0], best.prob
+
+
+class LangOptions(BaseModel):
+    """Settings for analyze."""
+
+    detector: Detector | None = detect_language  # None disables detection
+    min_words: int = Field(default=50, ge=1)  # shorter pages are not detected
+    min_confidence: float = Field(default=0.9, ge=0.0, le=1.0)  # less certain results are ignored
+    max_listed: int = Field(default=10, ge=1)  # pages named in a finding before "and N more"
+
+
+def declared_language(soup: BeautifulSoup) -> str | None:
+    """The lang attribute of <html>, stripped; "" when empty and None when absent."""
+    html = soup.find("html")
+    if html is None or html.get("lang") is None:
+        return None
+    return html.get("lang").strip()
+
+
+def attribute_issues(soup: BeautifulSoup, url: str) -> list[Issue]:
+    """Missing, empty, or malformed <html lang>."""
+    lang = declared_language(soup)
+    if not lang:
+        state = fragment("lang.empty" if lang == "" else "lang.missing")
+        return [
+            Issue(
+                url=url,
+                type="missing_html_lang",
+                severity="medium",
+                **msg("lang.missing_html_lang", state),
+            )
+        ]
+    if not is_valid_language_tag(lang):
+        hint = lang.replace("_", "-")
+        suggestion = fragment("lang.suggestion", hint) if is_valid_language_tag(hint) else ""
+        return [
+            Issue(
+                url=url,
+                type="html_lang_invalid",
+                severity="medium",
+                **msg("lang.html_lang_invalid", lang, suggestion),
+            )
+        ]
+    return []
+
+
+def analyze(pages: list[FetchedPage], options: LangOptions | None = None) -> list[Issue]:
+    """
+    Check the declared language of each page and across the site.
+
+    Args:
+        pages: Fetched pages (anything with url, html, and status)
+        options: Language detector, detection thresholds, and display limits
+
+    Returns:
+        missing_html_lang and html_lang_invalid per page, html_lang_mismatch
+        (info, with the confidence) where the text is in another language,
+        and mixed_languages_without_hreflang for sites declaring several
+        languages on pages without hreflang annotations
+    """
+    options = options or LangOptions()
+    issues = []
+    languages: dict[str, list[str]] = {}  # primary language -> pages declaring it
+    without_hreflang: set[str] = set()
+
+    for page in pages:
+        if not page.html or not 200 <= page.status < 300:
+            continue
+        soup = BeautifulSoup(page.html, "lxml")
+        found = attribute_issues(soup, page.url)
+        issues.extend(found)
+        if found:
+            continue
+
+        declared = declared_language(soup)
+        primary = declared.split("-")[0].lower()
+        languages.setdefault(primary, []).append(page.url)
+        if soup.find("link", hreflang=True) is None:
+            without_hreflang.add(page.url)
+
+        text = visible_text(page.html)
+        if options.detector is None or word_count(text) < options.min_words:
+            continue
+        detected = options.detector(text)
+        if detected is None:
+            continue
+        code, confidence = detected
+        if code.lower() != primary and confidence >= options.min_confidence:
+            issues.append(
+                Issue(
+                    url=page.url,
+                    type="html_lang_mismatch",
+                    severity="info",
+                    **msg("lang.html_lang_mismatch", declared, code, confidence),
+                )
+            )
+
+    if len(languages) > 1:
+        unannotated = [u for urls in languages.values() for u in urls if u in without_hreflang]
+        if unannotated:
+            counts = [fragment("lang.count", lang, len(urls)) for lang, urls in languages.items()]
+            issues.append(
+                Issue(
+                    url=unannotated[0],
+                    type="mixed_languages_without_hreflang",
+                    severity="low",
+                    **msg(
+                        "lang.mixed_languages_without_hreflang",
+                        len(languages),
+                        counts,
+                        len(unannotated),
+                        listing(unannotated, options.max_listed),
+                    ),
+                )
+            )
+    return issues
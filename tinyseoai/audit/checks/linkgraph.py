@@ -0,0 +1,159 @@
+"""
+Internal link graph: orphan pages, dead ends, click depth, and hubs.
+
+The crawled pages and their links to the same host form a directed graph,
+kept as adjacency sets keyed by normalized URL, so building it and walking
+it stay linear in pages plus links. A page reached through a redirect is
+one node under its final URL, and links to either URL count for it.
+
+- orphan_page: an HTML page other than the home page that no other
+  crawled page links to; such pages only enter the crawl from the sitemap
+- dead_end_page: an HTML page with no links to any other page of the site
+- page_too_deep: more than max_click_depth clicks from the home page
+- internal_link_hubs: the pages with the most inbound links, as a note
+"""
+from __future__ import annotations
+
+from collections import deque
+from dataclasses import dataclass, field
+from urllib.parse import urlparse
+
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import fragment, listing, msg
+from ...utils import urlnorm
+from ..crawler import extract_links
+
+
+class LinkGraphOptions(BaseModel):
+    """Settings for analyze."""
+
+    max_click_depth: int = Field(default=3, ge=1)  # deeper pages are page_too_deep
+    top_hubs: int = Field(default=5, ge=0)  # pages named in the hubs note; 0 leaves it out
+
+
+@dataclass
+class InternalLinkGraph:
+    """Internal links between crawled pages, by normalized URL."""
+
+    urls: dict[str, str] = field(default_factory=dict)  # node -> URL as crawled, for findings
+    aliases: dict[str, str] = field(default_factory=dict)  # normalized requested URL -> node
+    outlinks: dict[str, set[str]] = field(default_factory=dict)
+    inlinks: dict[str, set[str]] = field(default_factory=dict)
+    html: set[str] = field(default_factory=set)  # nodes that loaded as HTML and had links read
+
+    @classmethod
+    def build(cls, pages: list, home_url: str) -> InternalLinkGraph:
+        """
+        Build the graph from crawled pages.
+
+        Args:
+            pages: Crawled pages (url, status, html, final_url); the in-scope
+                links found during the crawl (page.links) are used when present
+            home_url: Start URL; links to hosts other than the one it ends up
+                on are left out
+        """
+        graph = cls()
+        for page in pages:
+            node = urlnorm.normalize(getattr(page, "final_url", None) or page.url)
+            graph.aliases[urlnorm.normalize(page.url)] = node
+            graph.urls.setdefault(node, page.url)
+            graph.outlinks.setdefault(node, set())
+            graph.inlinks.setdefault(node, set())
+        host = urlparse(graph.node(home_url)).netloc
+        for page in pages:
+            if not 200 <= page.status < 300 or not page.html:
+                continue
+            node = graph.node(page.url)
+            graph.html.add(node)
+            links = page.links if hasattr(page, "links") else extract_links(page.html, page.url)
+            for link in links:
+                target = graph.node(link)
+                if urlparse(target).netloc != host:
+                    continue
+                if target != node:
+                    graph.outlinks[node].add(target)
+                    graph.inlinks.setdefault(target, set()).add(node)
+        return graph
+
+    def node(self, url: str) -> str:
+        """The node of a URL: where it redirected to when it was crawled, else its normal form."""
+        normalized = urlnorm.normalize(url)
+        return self.aliases.get(normalized, normalized)
+
+    def depths(self, start: str) -> dict[str, int]:
+        """Clicks from start to every node reachable from it (breadth-first)."""
+        depths = {start: 0}
+        queue = deque([start])
+        while queue:
+            current = queue.popleft()
+            for target in self.outlinks.get(current, ()):
+                if target not in depths:
+                    depths[target] = depths[current] + 1
+                    queue.append(target)
+        return depths
+
+    def orphans(self, home: str) -> list[str]:
+        """HTML nodes other than home that no other node links to, in crawl order."""
+        return [n for n in self.urls if n in self.html and n != home and not self.inlinks[n]]
+
+    def dead_ends(self) -> list[str]:
+        """HTML nodes without links to other pages of the site, in crawl order."""
+        return [n for n in self.urls if n in self.html and not self.outlinks[n]]
+
+    def hubs(self, top: int) -> list[tuple[str, int]]:
+        """The crawled nodes with the most inbound links, most first; ties in crawl order."""
+        counts = [(n, len(self.inlinks[n])) for n in self.urls if self.inlinks[n]]
+        return sorted(counts, key=lambda item: -item[1])[:top]
+
+
+def analyze(pages: list, home_url: str, options: LinkGraphOptions | None = None) -> list[Issue]:
+    """
+    Find orphan, dead-end, and deep pages in the internal link graph.
+
+    Args:
+        pages: Crawled pages (see InternalLinkGraph.build)
+        home_url: Start URL of the crawl; click depth is counted from it
+        options: Click depth threshold and the number of hubs to name
+
+    Returns:
+        One finding per orphan, dead-end, and deep page, and an info note
+        on the home page naming the hubs
+    """
+    options = options or LinkGraphOptions()
+    graph = InternalLinkGraph.build(pages, home_url)
+    home = graph.node(home_url)
+
+    issues = [
+        Issue(url=graph.urls[n], type="orphan_page", severity="medium", **msg("links.orphan_page"))
+        for n in graph.orphans(home)
+    ]
+    issues.extend(
+        Issue(url=graph.urls[n], type="dead_end_page", severity="low", **msg("links.dead_end_page"))
+        for n in graph.dead_ends()
+    )
+    if home in graph.urls:
+        depths = graph.depths(home)
+        issues.extend(
+            Issue(
+                url=graph.urls[node],
+                type="page_too_deep",
+                severity="low",
+                **msg("links.page_too_deep", options.max_click_depth),
+            )
+            for node in graph.urls
+            if depths.get(node, 0) > options.max_click_depth
+        )
+    hubs = graph.hubs(options.top_hubs)
+    if hubs:
+        named = [fragment("links.hub", graph.urls[node], count) for node, count in hubs]
+        issues.append(
+            Issue(
+                url=graph.urls.get(home, home_url),
+                type="internal_link_hubs",
+                severity="info",
+                **msg("links.internal_link_hubs", listing(named, options.top_hubs)),
+            )
+        )
+    return issues
@@ -3,12 +3,27 @@ Comprehensive link analysis including link graphs, orphan pages, and redirect ch
 """
 from __future__ import annotations
 
+import asyncio
+import time
 from collections import defaultdict
+from urllib.parse import urldefrag, urljoin, urlparse
 
 import httpx
+from bs4 import BeautifulSoup
 from loguru import logger
+from pydantic import BaseModel
 
 from ...data.models import Issue
+from ...i18n import msg
+from ...utils.http import attempts_for
+from ...utils.http import client as http_client
+from ...utils.rate_limiter import RateLimiter
+from .redirects import REDIRECT_STATUSES, trace, trace_issues
+
+# Links with these schemes are never probed
+SKIPPED_SCHEMES = ("mailto:", "tel:", "javascript:", "data:", "sms:", "ftp:")
+# Severity of a broken link whose failures were all retryable
+_TRANSIENT_SEVERITY = {"medium": "low", "low": "info"}
 
 
 class LinkGraph:
@@ -169,123 +184,6 @@ class LinkChecker:
         self.base_url = base_url
         self.link_graph = LinkGraph()
 
-    def analyze_internal_linking(
-        self, pages_data: list[dict[str, any]]
-    ) -> list[Issue]:
-        """
-        Analyze internal linking structure.
-
-        Args:
-            pages_data: List of page data dictionaries with URLs and links
-
-        Returns:
-            List of internal linking issues
-        """
-        issues = []
-
-        # Build link graph
-        for page in pages_data:
-            url = page.get("url")
-            internal_links = page.get("internal_links", [])
-
-            self.link_graph.add_page(url)
-
-            for link_data in internal_links:
-                target = link_data.get("url")
-                anchor = link_data.get("anchor_text", "")
-                self.link_graph.add_link(url, target, anchor)
-
-        # Find orphan pages
-        orphans = self.link_graph.get_orphan_pages()
-        if len(orphans) > 1:  # More than just the homepage
-            for orphan in orphans[:10]:  # Sample first 10
-                issues.append(
-                    Issue(
-                        url=orphan,
-                        type="orphan_page",
-                        severity="medium",
-                        detail="Page has no internal links pointing to it (orphan page)",
-                    )
-                )
-
-        # Find pages too deep in site structure
-        if pages_data:
-            start_url = pages_data[0].get("url")  # Assume first is homepage
-            deep_pages = self.link_graph.get_pages_beyond_depth(start_url, max_depth=3)
-
-            for deep_page in deep_pages[:10]:  # Sample first 10
-                issues.append(
-                    Issue(
-                        url=deep_page,
-                        type="page_too_deep",
-                        severity="low",
-                        detail="Page is more than 3 clicks from homepage",
-                    )
-                )
-
-        return issues
-
-    def check_anchor_text(self, links: list[dict[str, str]], url: str) -> list[Issue]:
-        """
-        Check anchor text quality.
-
-        Args:
-            links: List of link dictionaries
-            url: URL of the page containing the links
-
-        Returns:
-            List of anchor text issues
-        """
-        issues = []
-
-        for link in links:
-            anchor = link.get("anchor_text", "").strip()
-            target = link.get("url", "")
-
-            # Check for empty anchor text
-            if not anchor:
-                issues.append(
-                    Issue(
-                        url=url,
-                        type="empty_anchor_text",
-                        severity="low",
-                        detail=f"Link to {target} has no anchor text",
-                    )
-                )
-
-            # Check for generic anchor text
-            generic_anchors = [
-                "click here",
-                "read more",
-                "learn more",
-                "here",
-                "this",
-                "link",
-                "more",
-            ]
-            if anchor.lower() in generic_anchors:
-                issues.append(
-                    Issue(
-                        url=url,
-                        type="generic_anchor_text",
-                        severity="low",
-                        detail=f"Generic anchor text '{anchor}' on link to {target}",
-                    )
-                )
-
-            # Check for over-optimized anchor text (all caps)
-            if anchor.isupper() and len(anchor) > 3:
-                issues.append(
-                    Issue(
-                        url=url,
-                        type="anchor_text_all_caps",
-                        severity="info",
-                        detail=f"Anchor text is all caps: '{anchor}'",
-                    )
-                )
-
-        return issues
-
     def check_link_attributes(
         self, links: list[dict[str, any]], url: str
     ) -> list[Issue]:
@@ -319,7 +217,7 @@ class LinkChecker:
                             url=url,
                             type="external_link_missing_noopener",
                             severity="medium",
-                            detail=f"External link with target='_blank' should have rel='noopener': {href}",
+                            **msg("links.external_link_missing_noopener", href),
                         )
                     )
 
@@ -340,84 +238,32 @@ async def check_redirect_chains(
     Returns:
         List of redirect issues
     """
-    issues = []
-    visited = []
-
-    try:
-        # Manually follow redirects to track the chain
-        current_url = url
-        redirect_count = 0
-
-        while redirect_count < max_redirects:
-            response = await client.get(
-                current_url, follow_redirects=False, timeout=10.0
+    result = await trace(url, client, max_hops=max_redirects)
+    if result.error:
+        logger.warning(f"Error checking redirects for {url}: {result.error}")
+    issues = trace_issues(result)
+
+    last = result.hops[-1] if result.hops else None
+    if last is not None and last.status_code in REDIRECT_STATUSES and not last.location:
+        issues.append(
+            Issue(
+                url=url,
+                type="redirect_missing_location",
+                severity="high",
+                **msg("links.redirect_missing_location", last.status_code),
             )
-            visited.append((current_url, response.status_code))
-
-            if response.status_code in (301, 302, 303, 307, 308):
-                redirect_count += 1
-                location = response.headers.get("location")
-
-                if not location:
-                    issues.append(
-                        Issue(
-                            url=url,
-                            type="redirect_missing_location",
-                            severity="high",
-                            detail=f"Redirect ({response.status_code}) without Location header",
-                        )
-                    )
-                    break
-
-                # Make location absolute
-                from urllib.parse import urljoin
-
-                next_url = urljoin(current_url, location)
-
-                # Check for redirect loop
-                if next_url in [v[0] for v in visited]:
-                    issues.append(
-                        Issue(
-                            url=url,
-                            type="redirect_loop",
-                            severity="high",
-                            detail=f"Redirect loop detected: {' -> '.join([v[0] for v in visited])}",
-                        )
-                    )
-                    break
-
-                current_url = next_url
-            else:
-                # Not a redirect, we're done
-                break
-
-        # Check if we have a redirect chain (more than 1 redirect)
-        if redirect_count > 1:
-            issues.append(
-                Issue(
-                    url=url,
-                    type="redirect_chain",
-                    severity="medium",
-                    detail=f"Redirect chain of {redirect_count} hops detected",
-                )
+        )
+
+    # Check for temporary redirects that should be permanent
+    if result.redirects and result.hops[0].status_code in (302, 307):
+        issues.append(
+            Issue(
+                url=url,
+                type="temporary_redirect",
+                severity="info",
+                **msg("links.temporary_redirect", result.hops[0].status_code),
             )
-
-        # Check for temporary redirects that should be permanent
-        if redirect_count > 0:
-            first_redirect_status = visited[0][1]
-            if first_redirect_status in (302, 307):
-                issues.append(
-                    Issue(
-                        url=url,
-                        type="temporary_redirect",
-                        severity="info",
-                        detail=f"Using temporary redirect ({first_redirect_status}). "
-                        "Consider 301 for permanent moves.",
-                    )
-                )
-
-    except Exception as e:
-        logger.warning(f"Error checking redirects for {url}: {e}")
+        )
 
     return issues
 
@@ -446,3 +292,210 @@ def analyze_link_distribution(pages_data: list[dict[str, any]]) -> dict[str, any
         "min_internal_links": min(outbound_counts) if outbound_counts else 0,
         "pages_with_no_internal_links": sum(1 for count in outbound_counts if count == 0),
     }
+
+
+class LinkCheckOptions(BaseModel):
+    """Limits and thresholds for check_links."""
+
+    concurrency: int = 8
+    timeout: float = 10.0
+    per_host_rps: float = 2.0  # requests per second to any one host
+    max_links: int = 200  # distinct targets probed per page
+    include_external: bool = True
+    check_fragments: bool = False  # fetch targets and look for the #fragment
+    internal_error_threshold: int = 1  # broken internal links before issues are raised
+    external_error_threshold: int = 1
+
+
+class LinkReport(BaseModel):
+    """Probe result for one link on a page."""
+
+    url: str  # absolute, with fragment
+    anchor_text: str = ""
+    internal: bool
+    nofollow: bool = False
+    status_code: int | None = None
+    redirected_to: str | None = None  # final URL, when different from url
+    fragment_found: bool | None = None  # None when not checked
+    latency_ms: float | None = None
+    error: str | None = None
+    skipped: str | None = None
+    attempts: int | None = None  # requests made, when the client retries
+    transient: bool = False  # still failing with retryable errors (timeouts, 502/503/504)
+
+    @property
+    def broken(self) -> bool:
+        if self.skipped:
+            return False
+        return self.error is not None or (self.status_code or 0) >= 400
+
+
+def page_links(page_url: str, html: str) -> list[LinkReport]:
+    """
+    Extract the distinct <a href> links of a page, resolved against page_url.
+
+    Fragment-only links are dropped; mailto:, tel:, javascript: and similar
+    links are returned as skipped.
+    """
+    soup = BeautifulSoup(html, "lxml")
+    host = urlparse(page_url).netloc.lower()
+    links: dict[str, LinkReport] = {}
+    for anchor in soup.find_all("a", href=True):
+        href = anchor.get("href").strip()
+        if not href or href.startswith("#"):
+            continue
+        rel = anchor.get("rel") or []
+        rel = rel.split() if isinstance(rel, str) else rel
+        if href.lower().startswith(SKIPPED_SCHEMES):
+            links.setdefault(
+                href,
+                LinkReport(url=href, internal=False, skipped=f"{href.split(':', 1)[0].lower()} link"),
+            )
+            continue
+        absolute = urljoin(page_url, href)
+        if not absolute.startswith(("http://", "https://")):
+            continue
+        links.setdefault(
+            absolute,
+            LinkReport(
+                url=absolute,
+                anchor_text=anchor.get_text(" ", strip=True),
+                internal=urlparse(absolute).netloc.lower() == host,
+                nofollow="nofollow" in [r.lower() for r in rel],
+            ),
+        )
+    return list(links.values())
+
+
+def _has_fragment(html: str, fragment: str) -> bool:
+    soup = BeautifulSoup(html, "lxml")
+    return soup.find(id=fragment) is not None or soup.find("a", attrs={"name": fragment}) is not None
+
+
+async def _probe(
+    client: httpx.AsyncClient, url: str, options: LinkCheckOptions, want_body: bool
+) -> tuple[httpx.Response | None, str | None, float]:
+    started = time.monotonic()
+    try:
+        if want_body:
+            response = await client.get(url, timeout=options.timeout, follow_redirects=True)
+        else:
+            response = await client.head(url, timeout=options.timeout, follow_redirects=True)
+            if response.status_code in (405, 501):
+                response = await client.get(url, timeout=options.timeout, follow_redirects=True)
+        return response, None, (time.monotonic() - started) * 1000
+    except httpx.TimeoutException:
+        return None, "timeout", (time.monotonic() - started) * 1000
+    except httpx.HTTPError as e:
+        return None, type(e).__name__, (time.monotonic() - started) * 1000
+
+
+async def check_links(
+    page_url: str,
+    html: str,
+    options: LinkCheckOptions | None = None,
+    client: httpx.AsyncClient | None = None,
+) -> list[LinkReport]:
+    """
+    Probe every link on a page.
+
+    Targets are fetched once each (ignoring fragments) with bounded
+    concurrency and at most per_host_rps requests per second to one host.
+
+    Args:
+        page_url: URL of the page, used to resolve relative links
+        html: HTML of the page
+        options: Concurrency, rate, and fragment options
+        client: HTTP client to use (one is created if omitted)
+
+    Returns:
+        One LinkReport per distinct link, in page order
+    """
+    options = options or LinkCheckOptions()
+    reports = page_links(page_url, html)
+
+    targets: dict[str, list[LinkReport]] = defaultdict(list)
+    for report in reports:
+        if report.skipped:
+            continue
+        if not report.internal and not options.include_external:
+            report.skipped = "external link"
+            continue
+        target = urldefrag(report.url).url
+        if target not in targets and len(targets) >= options.max_links:
+            report.skipped = "max links reached"
+            continue
+        targets[target].append(report)
+
+    limiters: dict[str, RateLimiter] = {}
+    semaphore = asyncio.Semaphore(options.concurrency)
+    own_client = client is None
+    client = client or http_client()
+
+    async def probe(target: str, linked: list[LinkReport]) -> None:
+        fragments = [urldefrag(r.url).fragment for r in linked]
+        want_body = options.check_fragments and any(fragments)
+        host = urlparse(target).netloc.lower()
+        limiter = limiters.setdefault(host, RateLimiter(options.per_host_rps))
+        await limiter.wait()
+        async with semaphore:
+            response, error, latency = await _probe(client, target, options, want_body)
+        attempts = attempts_for(client, target)
+        for report, fragment in zip(linked, fragments):
+            report.latency_ms = round(latency, 1)
+            report.error = error
+            if attempts is not None:
+                report.attempts = attempts.attempts
+                report.transient = attempts.outcome == "transient"
+            if response is None:
+                continue
+            report.status_code = response.status_code
+            final = str(response.url)
+            if final != target:
+                report.redirected_to = final
+            if want_body and fragment and response.status_code < 400:
+                report.fragment_found = _has_fragment(response.text, fragment)
+
+    try:
+        await asyncio.gather(*(probe(target, linked) for target, linked in targets.items()))
+    finally:
+        if own_client:
+            await client.aclose()
+    return reports
+
+
+def link_issues(page_url: str, reports: list[LinkReport], options: LinkCheckOptions | None = None) -> list[Issue]:
+    """
+    Turn link reports into issues.
+
+    Broken internal links become broken_link issues (detail = target) once
+    there are at least internal_error_threshold of them; external ones become
+    broken_external_link issues likewise. Links that only failed with
+    retryable errors are reported a severity lower, since the endpoint may
+    just be flaky. Missing #fragments are reported separately.
+    """
+    options = options or LinkCheckOptions()
+    issues = []
+    for internal, issue_type, severity, threshold in (
+        (True, "broken_link", "medium", options.internal_error_threshold),
+        (False, "broken_external_link", "low", options.external_error_threshold),
+    ):
+        broken = [r for r in reports if r.internal == internal and r.broken]
+        if not broken or len(broken) < threshold:
+            continue
+        for report in broken:
+            issues.append(
+                Issue(
+                    url=page_url,
+                    type=issue_type,
+                    severity=_TRANSIENT_SEVERITY[severity] if report.transient else severity,
+                    detail=report.url,
+                )
+            )
+
+    for report in reports:
+        if report.fragment_found is False:
+            issues.append(
+                Issue(url=page_url, type="missing_link_fragment", severity="low", detail=report.url)
+            )
+    return issues
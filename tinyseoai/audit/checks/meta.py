@@ -4,23 +4,45 @@ Meta tag validation including Open Graph, Twitter Cards, and other social meta t
 from __future__ import annotations
 
 from bs4 import BeautifulSoup
+from pydantic import BaseModel
 
 from ...data.models import Issue
+from ...i18n import msg
+from .indexability import IndexabilityChecker
+from .lang import attribute_issues
+from .mobile import viewport_issues
+
+# Recommended lengths in characters
+TITLE_MIN_LENGTH = 30
+TITLE_MAX_LENGTH = 60
+DESCRIPTION_MIN_LENGTH = 70
+DESCRIPTION_MAX_LENGTH = 160
+
+
+class MetaThresholds(BaseModel):
+    """Length limits for titles and meta descriptions."""
+
+    title_min: int = TITLE_MIN_LENGTH
+    title_max: int = TITLE_MAX_LENGTH
+    description_min: int = DESCRIPTION_MIN_LENGTH
+    description_max: int = DESCRIPTION_MAX_LENGTH
 
 
 class MetaTagChecker:
     """Check and validate meta tags for SEO and social sharing."""
 
-    def __init__(self, html: str, url: str):
+    def __init__(self, html: str, url: str, thresholds: MetaThresholds | None = None):
         """
         Initialize the meta tag checker.
 
         Args:
             html: HTML content to check
             url: URL of the page
+            thresholds: Title and description length limits
         """
         self.html = html
         self.url = url
+        self.thresholds = thresholds or MetaThresholds()
         self.soup = BeautifulSoup(html, "lxml")
 
     def check_all(self) -> list[Issue]:
@@ -35,12 +57,102 @@ class MetaTagChecker:
         issues.extend(self.check_basic_meta())
         issues.extend(self.check_open_graph())
         issues.extend(self.check_twitter_cards())
-        issues.extend(self.check_favicon())
-        issues.extend(self.check_language())
-        issues.extend(self.check_viewport())
+        # Favicons are checked once per site by icons.analyze, which also probes /favicon.ico
+        # The lang attribute is checked by lang.analyze along with the page text
+        issues.extend(self.check_hreflang())
+        # The viewport is checked by mobile.analyze along with the other mobile heuristics
+
+        return issues
+
+    def check_head(self) -> list[Issue]:
+        """
+        Check title, meta description, and H1 headings.
+
+        Returns:
+            List of title, description, and heading issues
+        """
+        issues = []
+        limits = self.thresholds
+
+        titles = [t.get_text(strip=True) for t in self.soup.find_all("title")]
+        issues.extend(
+            self._check_text(
+                "title", titles, limits.title_min, limits.title_max, missing_severity="medium"
+            )
+        )
+
+        descriptions = [
+            (tag.get("content") or "").strip()
+            for tag in self.soup.find_all(
+                "meta", attrs={"name": lambda n: n and n.lower() == "description"}
+            )
+        ]
+        issues.extend(
+            self._check_text(
+                "meta_description",
+                descriptions,
+                limits.description_min,
+                limits.description_max,
+                missing_severity="low",
+            )
+        )
+
+        h1_count = len(self.soup.find_all("h1"))
+        if h1_count == 0:
+            issues.append(
+                Issue(url=self.url, type="h1_missing", severity="medium", **msg("meta.h1_missing"))
+            )
+        elif h1_count > 1:
+            issues.append(
+                Issue(
+                    url=self.url,
+                    type="multiple_h1",
+                    severity="low",
+                    **msg("meta.multiple_h1", h1_count),
+                )
+            )
 
         return issues
 
+    def _check_text(
+        self, kind: str, values: list[str], min_len: int, max_len: int, missing_severity: str
+    ) -> list[Issue]:
+        """Missing, duplicate, and length checks for the title or description."""
+        present = [v for v in values if v]
+        if not present:
+            return [Issue(url=self.url, type=f"{kind}_missing", severity=missing_severity)]
+
+        issues = []
+        if len(values) > 1:
+            issues.append(
+                Issue(
+                    url=self.url,
+                    type=f"multiple_{kind}_tags",
+                    severity="medium",
+                    **msg(f"meta.multiple_{kind}_tags", len(values)),
+                )
+            )
+        length = len(present[0])
+        if length > max_len:
+            issues.append(
+                Issue(
+                    url=self.url,
+                    type=f"{kind}_too_long",
+                    severity="low",
+                    **msg(f"meta.{kind}_too_long", length, max_len),
+                )
+            )
+        elif length < min_len:
+            issues.append(
+                Issue(
+                    url=self.url,
+                    type=f"{kind}_too_short",
+                    severity="low",
+                    **msg(f"meta.{kind}_too_short", length, min_len),
+                )
+            )
+        return issues
+
     def check_basic_meta(self) -> list[Issue]:
         """
         Check basic meta tags.
@@ -63,7 +175,7 @@ class MetaTagChecker:
                         url=self.url,
                         type="missing_charset",
                         severity="low",
-                        detail="No charset meta tag found. Should declare UTF-8.",
+                        **msg("meta.missing_charset"),
                     )
                 )
 
@@ -78,22 +190,12 @@ class MetaTagChecker:
         """
         issues = []
 
-        # Required OG tags
-        required_og_tags = {
-            "og:title": "Open Graph title",
-            "og:type": "Open Graph type",
-            "og:image": "Open Graph image",
-            "og:url": "Open Graph URL",
-        }
-
-        # Optional but recommended
-        recommended_og_tags = {
-            "og:description": "Open Graph description",
-            "og:site_name": "Open Graph site name",
-        }
+        # Required OG tags, and optional but recommended ones
+        required_og_tags = ["og:title", "og:type", "og:image", "og:url"]
+        recommended_og_tags = ["og:description", "og:site_name"]
 
         # Check required tags
-        for property_name, display_name in required_og_tags.items():
+        for property_name in required_og_tags:
             og_tag = self.soup.find("meta", property=property_name)
             if not og_tag or not og_tag.get("content"):
                 issues.append(
@@ -101,12 +203,12 @@ class MetaTagChecker:
                         url=self.url,
                         type="missing_og_tag",
                         severity="medium",
-                        detail=f"Missing required {display_name} ({property_name})",
+                        **msg(f"meta.missing_{property_name.replace(':', '_')}"),
                     )
                 )
 
         # Check recommended tags
-        for property_name, display_name in recommended_og_tags.items():
+        for property_name in recommended_og_tags:
             og_tag = self.soup.find("meta", property=property_name)
             if not og_tag or not og_tag.get("content"):
                 issues.append(
@@ -114,7 +216,7 @@ class MetaTagChecker:
                         url=self.url,
                         type="missing_recommended_og_tag",
                         severity="low",
-                        detail=f"Missing recommended {display_name} ({property_name})",
+                        **msg(f"meta.missing_{property_name.replace(':', '_')}"),
                     )
                 )
 
@@ -130,7 +232,7 @@ class MetaTagChecker:
                         url=self.url,
                         type="og_image_not_absolute",
                         severity="medium",
-                        detail="Open Graph image URL should be absolute (include full URL)",
+                        **msg("meta.og_image_not_absolute"),
                     )
                 )
 
@@ -144,7 +246,7 @@ class MetaTagChecker:
                         url=self.url,
                         type="missing_og_image_dimensions",
                         severity="low",
-                        detail="Missing og:image:width and og:image:height for better social sharing",
+                        **msg("meta.missing_og_image_dimensions"),
                     )
                 )
 
@@ -167,8 +269,7 @@ class MetaTagChecker:
                     url=self.url,
                     type="missing_twitter_card",
                     severity="low",
-                    detail="Missing twitter:card meta tag. "
-                    "This controls how content is displayed when shared on Twitter/X.",
+                    **msg("meta.missing_twitter_card"),
                 )
             )
         else:
@@ -187,8 +288,9 @@ class MetaTagChecker:
                         url=self.url,
                         type="invalid_twitter_card_type",
                         severity="medium",
-                        detail=f"Invalid twitter:card type '{card_type}'. "
-                        f"Should be one of: {', '.join(valid_card_types)}",
+                        **msg(
+                            "meta.invalid_twitter_card_type", card_type, ", ".join(valid_card_types)
+                        ),
                     )
                 )
 
@@ -206,7 +308,7 @@ class MetaTagChecker:
                             url=self.url,
                             type="missing_twitter_title",
                             severity="info",
-                            detail="Missing twitter:title. Falls back to og:title if available.",
+                            **msg("meta.missing_twitter_title"),
                         )
                     )
 
@@ -216,8 +318,7 @@ class MetaTagChecker:
                             url=self.url,
                             type="missing_twitter_description",
                             severity="info",
-                            detail="Missing twitter:description. "
-                            "Falls back to og:description if available.",
+                            **msg("meta.missing_twitter_description"),
                         )
                     )
 
@@ -231,7 +332,7 @@ class MetaTagChecker:
                                 url=self.url,
                                 type="missing_twitter_image",
                                 severity="medium",
-                                detail="Missing twitter:image for summary_large_image card type",
+                                **msg("meta.missing_twitter_image"),
                             )
                         )
 
@@ -243,8 +344,7 @@ class MetaTagChecker:
                     url=self.url,
                     type="missing_twitter_site",
                     severity="info",
-                    detail="Missing twitter:site meta tag. "
-                    "This should be your Twitter/X username.",
+                    **msg("meta.missing_twitter_site"),
                 )
             )
 
@@ -278,7 +378,7 @@ class MetaTagChecker:
                     url=self.url,
                     type="missing_favicon",
                     severity="low",
-                    detail="No favicon found. Favicons improve brand recognition in browser tabs.",
+                    **msg("meta.missing_favicon"),
                 )
             )
 
@@ -288,7 +388,7 @@ class MetaTagChecker:
                     url=self.url,
                     type="missing_apple_touch_icon",
                     severity="info",
-                    detail="Missing apple-touch-icon for iOS home screen bookmarks",
+                    **msg("meta.missing_apple_touch_icon"),
                 )
             )
 
@@ -301,20 +401,16 @@ class MetaTagChecker:
         Returns:
             List of language-related issues
         """
-        issues = []
+        return [*attribute_issues(self.soup, self.url), *self.check_hreflang()]
 
-        html_tag = self.soup.find("html")
+    def check_hreflang(self) -> list[Issue]:
+        """
+        Check hreflang annotations have an x-default fallback.
 
-        if not html_tag or not html_tag.get("lang"):
-            issues.append(
-                Issue(
-                    url=self.url,
-                    type="missing_html_lang",
-                    severity="medium",
-                    detail="Missing lang attribute on <html> tag. "
-                    "This helps search engines and accessibility tools.",
-                )
-            )
+        Returns:
+            List of hreflang issues
+        """
+        issues = []
 
         # Check for hreflang tags (for international sites)
         hreflang_tags = self.soup.find_all("link", rel="alternate", hreflang=True)
@@ -331,7 +427,7 @@ class MetaTagChecker:
                         url=self.url,
                         type="missing_hreflang_x_default",
                         severity="low",
-                        detail="Using hreflang but missing x-default tag for fallback",
+                        **msg("meta.missing_hreflang_x_default"),
                     )
                 )
 
@@ -342,46 +438,9 @@ class MetaTagChecker:
         Check for viewport meta tag (mobile optimization).
 
         Returns:
-            List of viewport issues
+            List of viewport issues, including zoom restrictions
         """
-        issues = []
-
-        viewport = self.soup.find("meta", attrs={"name": "viewport"})
-
-        if not viewport or not viewport.get("content"):
-            issues.append(
-                Issue(
-                    url=self.url,
-                    type="missing_viewport",
-                    severity="high",
-                    detail="Missing viewport meta tag. Critical for mobile responsiveness.",
-                )
-            )
-        else:
-            content = viewport.get("content", "").lower()
-
-            # Check for recommended viewport settings
-            if "width=device-width" not in content:
-                issues.append(
-                    Issue(
-                        url=self.url,
-                        type="viewport_missing_device_width",
-                        severity="medium",
-                        detail="Viewport should include width=device-width for proper mobile scaling",
-                    )
-                )
-
-            if "initial-scale=1" not in content:
-                issues.append(
-                    Issue(
-                        url=self.url,
-                        type="viewport_missing_initial_scale",
-                        severity="low",
-                        detail="Viewport should include initial-scale=1 for proper mobile scaling",
-                    )
-                )
-
-        return issues
+        return viewport_issues(self.soup, self.url)
 
     def get_meta_summary(self) -> dict[str, any]:
         """
@@ -401,3 +460,29 @@ class MetaTagChecker:
         }
 
         return summary
+
+
+def analyze(html: str, url: str, thresholds: MetaThresholds | None = None) -> list[Issue]:
+    """
+    Run the page-level head checks on one HTML document.
+
+    Covers title and meta description (missing, duplicate, length), H1
+    count, canonical, robots meta directives, charset, and viewport.
+
+    Args:
+        html: HTML content, possibly malformed
+        url: URL of the page
+        thresholds: Title and description length limits
+
+    Returns:
+        List of issues found
+    """
+    meta = MetaTagChecker(html, url, thresholds)
+    indexability = IndexabilityChecker(html, url)
+    return [
+        *meta.check_head(),
+        *indexability.check_canonical(),
+        *indexability.check_robots_meta(),
+        *meta.check_basic_meta(),
+        *meta.check_viewport(),
+    ]
@@ -0,0 +1,210 @@
+"""
+Viewport and mobile-friendliness heuristics.
+
+Only the HTML is read, so layout findings are heuristics: fixed pixel
+widths in inline styles, tables used for page layout, and large images
+without srcset or <picture>. They are reported as medium or info.
+"""
+from __future__ import annotations
+
+import re
+
+from bs4 import BeautifulSoup, Tag
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import fragment, listing, msg
+
+_FIXED_WIDTH = re.compile(r"(?<![-\w])(?:min-)?width\s*:\s*(\d+(?:\.\d+)?)px", re.IGNORECASE)
+_LAYOUT_CONTENT = ["h1", "h2", "nav", "form", "header", "footer", "article"]
+
+
+class MobileOptions(BaseModel):
+    """Thresholds for analyze."""
+
+    max_fixed_width: int = Field(default=600, ge=1)  # wider fixed widths overflow a phone screen
+    large_image_width: int = Field(default=800, ge=1)  # images this wide should offer smaller sizes
+    max_listed: int = Field(default=5, ge=1)  # elements named in a finding before "and N more"
+
+
+def viewport_settings(soup: BeautifulSoup) -> dict[str, str] | None:
+    """The viewport meta content as lowercase key/value pairs, or None without one."""
+    tag = soup.find("meta", attrs={"name": lambda n: n and n.strip().lower() == "viewport"})
+    if tag is None or not (tag.get("content") or "").strip():
+        return None
+    settings = {}
+    for part in re.split(r"[,;]", tag.get("content").lower()):
+        key, _, value = part.partition("=")
+        if key.strip():
+            settings[key.strip()] = value.strip()
+    return settings
+
+
+def _describe(tag: Tag) -> str:
+    style = " ".join((tag.get("style") or "").split())
+    return f'<{tag.name} style="{style}">' if style else f"<{tag.name}>"
+
+
+def viewport_issues(soup: BeautifulSoup, url: str) -> list[Issue]:
+    """Missing viewport, fixed-width viewport, and zoom restrictions."""
+    settings = viewport_settings(soup)
+    if settings is None:
+        return [
+            Issue(
+                url=url,
+                type="missing_viewport",
+                severity="high",
+                **msg("mobile.missing_viewport"),
+            )
+        ]
+
+    issues = []
+    width = settings.get("width", "")
+    if width.isdigit():
+        issues.append(
+            Issue(
+                url=url,
+                type="viewport_fixed_width",
+                severity="medium",
+                **msg("mobile.viewport_fixed_width", width),
+            )
+        )
+    elif width != "device-width":
+        issues.append(
+            Issue(
+                url=url,
+                type="viewport_missing_device_width",
+                severity="medium",
+                **msg("mobile.viewport_missing_device_width"),
+            )
+        )
+    if "initial-scale" not in settings:
+        issues.append(
+            Issue(
+                url=url,
+                type="viewport_missing_initial_scale",
+                severity="low",
+                **msg("mobile.viewport_missing_initial_scale"),
+            )
+        )
+
+    if settings.get("user-scalable") in ("no", "0"):
+        issues.append(
+            Issue(
+                url=url,
+                type="viewport_zoom_disabled",
+                severity="medium",
+                **msg("mobile.viewport_zoom_disabled", settings["user-scalable"]),
+            )
+        )
+    try:
+        maximum = float(settings.get("maximum-scale", ""))
+    except ValueError:
+        maximum = None
+    if maximum is not None and maximum < 2:
+        issues.append(
+            Issue(
+                url=url,
+                type="viewport_zoom_limited",
+                severity="medium",
+                **msg("mobile.viewport_zoom_limited", settings["maximum-scale"]),
+            )
+        )
+    return issues
+
+
+def _fixed_width_elements(soup: BeautifulSoup, limit: int) -> list[Tag]:
+    """Elements whose inline style sets width or min-width above limit pixels (images excepted)."""
+    return [
+        tag
+        for tag in soup.find_all(style=True)
+        if tag.name != "img"
+        and any(float(w) > limit for w in _FIXED_WIDTH.findall(tag.get("style") or ""))
+    ]
+
+
+def _is_layout_table(table: Tag) -> bool:
+    """A table holding page structure rather than data."""
+    if (table.get("role") or "").strip().lower() in ("presentation", "none"):
+        return True
+    if table.find(["th", "caption", "thead"]):
+        return False
+    return table.find("table") is not None or table.find(_LAYOUT_CONTENT) is not None
+
+
+def _image_width(img: Tag) -> int | None:
+    width = (img.get("width") or "").strip()
+    if width.isdigit():
+        return int(width)
+    widths = _FIXED_WIDTH.findall(img.get("style") or "")
+    return int(float(widths[0])) if widths else None
+
+
+def analyze(html: str, url: str, options: MobileOptions | None = None) -> list[Issue]:
+    """
+    Check the viewport and look for layouts that do not adapt to phones.
+
+    Args:
+        html: HTML content
+        url: URL of the page
+        options: Width thresholds and display limits
+
+    Returns:
+        Viewport findings (missing_viewport, viewport_fixed_width,
+        viewport_missing_device_width, viewport_missing_initial_scale,
+        viewport_zoom_disabled, viewport_zoom_limited), then
+        fixed_width_layout, table_layout, and images_not_responsive (info)
+    """
+    options = options or MobileOptions()
+    soup = BeautifulSoup(html, "lxml")
+    issues = viewport_issues(soup, url)
+
+    fixed = _fixed_width_elements(soup, options.max_fixed_width)
+    if fixed:
+        elements = listing([_describe(t) for t in fixed], options.max_listed)
+        issues.append(
+            Issue(
+                url=url,
+                type="fixed_width_layout",
+                severity="medium",
+                **msg("mobile.fixed_width_layout", len(fixed), options.max_fixed_width, elements),
+            )
+        )
+
+    # Only outermost layout tables; nested ones are part of the same layout
+    layout_tables = [
+        t for t in soup.find_all("table") if _is_layout_table(t) and t.find_parent("table") is None
+    ]
+    if layout_tables:
+        issues.append(
+            Issue(
+                url=url,
+                type="table_layout",
+                severity="medium",
+                **msg("mobile.table_layout", len(layout_tables)),
+            )
+        )
+
+    large = [
+        img
+        for img in soup.find_all("img")
+        if (_image_width(img) or 0) >= options.large_image_width
+        and not img.get("srcset")
+        and img.find_parent("picture") is None
+    ]
+    if large:
+        names = [
+            fragment("mobile.image", img.get("src") or fragment("mobile.no_src"), _image_width(img))
+            for img in large
+        ]
+        issues.append(
+            Issue(
+                url=url,
+                type="images_not_responsive",
+                severity="info",
+                **msg(
+                    "mobile.images_not_responsive", len(large), listing(names, options.max_listed)
+                ),
+            )
+        )
+    return issues
@@ -0,0 +1,273 @@
+"""
+Paginated series across a crawl: canonicals, rel=next/prev chains, titles, and noindex.
+
+A series is a set of URLs differing only in a page number, either a query
+parameter (?page=2, ?paged=2) or a /page/2/ path segment. Detection is
+conservative: a series needs a page 2, and the ambiguous short parameters
+?p= and ?pg= (often post IDs) only count when rel=next/prev links join the
+pages. Zero-based numbering (Drupal's ?page=1 is the second page) is
+recognized from a ?page=0 URL or a rel=next from the first page. Findings are one per series, on its first page, listing the pages
+concerned.
+"""
+from __future__ import annotations
+
+import re
+from typing import TYPE_CHECKING
+from urllib.parse import parse_qsl, urlencode, urljoin, urlsplit, urlunsplit
+
+from bs4 import BeautifulSoup
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import fragment, listing, msg
+from ...utils import urlnorm
+from .indexability import page_directives
+
+if TYPE_CHECKING:
+    from ..crawler import FetchedPage
+
+_PAGE_PARAMS = {"page", "paged"}
+_AMBIGUOUS_PARAMS = {"p", "pg"}
+_PATH_PAGE = re.compile(r"^(.*/)page/(\d+)/?$", re.IGNORECASE)
+_PAGE_MARKER = re.compile(
+    r"\b(?:page|pg|p\.|seite|página|pagina)\s*\d+\b|\b\d+\s*(?:/|of|von|de|di)\s*\d+\b",
+    re.IGNORECASE,
+)
+
+
+class PaginationOptions(BaseModel):
+    """Display limits for analyze."""
+
+    max_listed: int = Field(default=10, ge=1)  # pages named in a finding before "and N more"
+
+
+class PageNumber(BaseModel):
+    """Where a URL sits in a possible series."""
+
+    key: str  # normalized URL without the page number; page 1 of the series
+    number: int
+    ambiguous: bool = False  # ?p= or ?pg=, which are often post IDs
+
+
+class Series(BaseModel):
+    """URLs of one paginated series, by page number."""
+
+    key: str
+    pages: dict[int, str]  # page number -> crawled URL, page 1 being the URL without a number
+    offset: int = 0  # added to numbers in URLs; 1 for zero-based series
+
+    @property
+    def first(self) -> str:
+        return self.pages[min(self.pages)]
+
+
+def page_number(url: str) -> PageNumber | None:
+    """The series key and page number of a URL, or None when it has no page number."""
+    parts = urlsplit(url)
+    match = _PATH_PAGE.match(parts.path)
+    if match:
+        base = urlunsplit((parts.scheme, parts.netloc, match.group(1), parts.query, ""))
+        return PageNumber(key=urlnorm.normalize(base), number=int(match.group(2)))
+
+    params = parse_qsl(parts.query, keep_blank_values=True)
+    numbered = [
+        (name, value)
+        for name, value in params
+        if name.lower() in _PAGE_PARAMS | _AMBIGUOUS_PARAMS and value.isdigit()
+    ]
+    if len(numbered) != 1:
+        return None
+    name, value = numbered[0]
+    rest = urlencode([(n, v) for n, v in params if n != name])
+    base = urlunsplit((parts.scheme, parts.netloc, parts.path, rest, ""))
+    return PageNumber(
+        key=urlnorm.normalize(base),
+        number=int(value),
+        ambiguous=name.lower() in _AMBIGUOUS_PARAMS,
+    )
+
+
+def group_series(urls: list[str], links: set[tuple[str, str]] | None = None) -> list[Series]:
+    """
+    Group URLs into paginated series.
+
+    Args:
+        urls: Crawled URLs
+        links: Normalized (from, to) pairs of rel=next/prev links, used to
+            confirm series numbered with ?p= or ?pg=
+
+    Returns:
+        Series with a page 2, in order of their first URL
+    """
+    links = links or set()
+    keys = {urlnorm.normalize(u): u for u in urls}
+    numbered: dict[str, dict[int, str]] = {}
+    ambiguous: set[str] = set()
+    for url in urls:
+        position = page_number(url)
+        if position is None:
+            continue
+        numbered.setdefault(position.key, {}).setdefault(position.number, url)
+        if position.ambiguous:
+            ambiguous.add(position.key)
+
+    series = []
+    for key, explicit in numbered.items():
+        second = explicit.get(1)
+        zero_based = 0 in explicit or (
+            second is not None and (key, urlnorm.normalize(second)) in links
+        )
+        offset = 1 if zero_based else 0
+        pages = {n + offset: u for n, u in explicit.items() if n + offset >= 1}
+        if key in keys:
+            pages.setdefault(1, keys[key])  # the URL without a number is page 1
+        if 2 not in pages or len(pages) < 2:
+            continue
+        if key in ambiguous:
+            members = {urlnorm.normalize(u) for u in pages.values()}
+            if not any(a in members and b in members for a, b in links):
+                continue
+        series.append(Series(key=key, pages=dict(sorted(pages.items())), offset=offset))
+    return series
+
+
+class _PageInfo(BaseModel):
+    url: str
+    title: str = ""
+    canonical: str | None = None
+    next: str | None = None
+    prev: str | None = None
+    noindex: bool = False
+
+
+def _page_info(page: FetchedPage) -> _PageInfo:
+    soup = BeautifulSoup(page.html, "lxml")
+    info = _PageInfo(url=page.url, noindex=page_directives(page.html, page.headers).noindex)
+    title = soup.find("title")
+    info.title = " ".join(title.get_text().split()) if title else ""
+    for rel in ("canonical", "next", "prev"):
+        tag = soup.find("link", rel=rel, href=True)
+        if tag is not None and tag.get("href").strip():
+            setattr(info, rel, urljoin(page.url, tag.get("href").strip()))
+    return info
+
+
+def _number_in(series: Series, url: str) -> int | None:
+    """The page number of url within series, or None when it is not part of it."""
+    if urlnorm.normalize(url) == series.key:
+        return 1
+    position = page_number(url)
+    if position is None or position.key != series.key:
+        return None
+    return position.number + series.offset
+
+
+def _chain_problems(
+    series: Series, infos: dict[str, _PageInfo], statuses: dict[str, int]
+) -> list[tuple[str, dict]]:
+    """(page URL, problem fragment) for rel=next/prev links that fail or skip pages."""
+    problems = []
+    for number, url in series.pages.items():
+        info = infos[urlnorm.normalize(url)]
+        for rel, step in (("next", 1), ("prev", -1)):
+            target = getattr(info, rel)
+            if target is None:
+                continue
+            status = statuses.get(urlnorm.normalize(target))
+            target_number = _number_in(series, target)
+            if status is not None and status >= 400:
+                problems.append((url, fragment("pagination.target_status", rel, target, status)))
+            elif target_number is not None and target_number != number + step:
+                problems.append(
+                    (url, fragment("pagination.skips_pages", rel, number, target_number))
+                )
+    return problems
+
+
+def analyze(pages: list[FetchedPage], options: PaginationOptions | None = None) -> list[Issue]:
+    """
+    Check paginated series found in a crawl.
+
+    Args:
+        pages: Fetched pages (anything with url, html, headers, and status)
+        options: Display limits
+
+    Returns:
+        pagination_canonical_to_first, pagination_broken_chain,
+        pagination_duplicate_titles, and pagination_first_page_noindex
+        findings, one per series on its first page
+    """
+    options = options or PaginationOptions()
+    statuses = {urlnorm.normalize(p.url): p.status for p in pages}
+    infos = {urlnorm.normalize(p.url): _page_info(p) for p in pages if p.html}
+    links = {
+        (key, urlnorm.normalize(target))
+        for key, info in infos.items()
+        for target in (info.next, info.prev)
+        if target
+    }
+    issues = []
+
+    def add(series: Series, issue_type: str, severity: str, *args) -> None:
+        issues.append(
+            Issue(
+                url=series.first,
+                type=issue_type,
+                severity=severity,
+                **msg(f"pagination.{issue_type}", *args),
+            )
+        )
+
+    crawled = [p.url for p in pages if p.html and p.status < 400]
+    for series in group_series(crawled, links):
+        members = {n: infos[urlnorm.normalize(u)] for n, u in series.pages.items()}
+
+        hidden = [
+            info.url
+            for n, info in members.items()
+            if n >= 2 and info.canonical and _number_in(series, info.canonical) == 1
+        ]
+        if hidden:
+            add(
+                series,
+                "pagination_canonical_to_first",
+                "medium",
+                len(hidden),
+                listing(hidden, options.max_listed),
+            )
+
+        problems = _chain_problems(series, infos, statuses)
+        if problems:
+            described = [fragment("pagination.problem", url, problem) for url, problem in problems]
+            add(
+                series,
+                "pagination_broken_chain",
+                "medium",
+                len(problems),
+                listing(described, options.max_listed),
+            )
+
+        titles: dict[str, list[str]] = {}
+        for info in members.values():
+            if info.title and not _PAGE_MARKER.search(info.title):
+                titles.setdefault(info.title.casefold(), []).append(info.url)
+        for urls in titles.values():
+            if len(urls) > 1:
+                title = infos[urlnorm.normalize(urls[0])].title
+                add(
+                    series,
+                    "pagination_duplicate_titles",
+                    "low",
+                    len(urls),
+                    title,
+                    listing(urls, options.max_listed),
+                )
+
+        if 1 in members and members[1].noindex:
+            add(
+                series,
+                "pagination_first_page_noindex",
+                "medium",
+                len(members),
+            )
+    return issues
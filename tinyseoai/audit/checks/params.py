@@ -0,0 +1,214 @@
+"""
+Duplicate content from URL parameters: faceted navigation, sort orders, and
+view switches that serve the same page under many URLs.
+
+URLs are bucketed by path. Within a bucket, for each parameter, URLs that
+become the same once that parameter is removed form a group; a URL whose
+content matches the group's representative (the URL without the parameter
+when it was crawled) is a duplicate the parameter generated. Each URL is
+compared with one representative per parameter, never with every other
+URL, and only when their text lengths are close, so large facet crawls stay
+cheap. Content is compared by word shingles, as the soft 404 check does.
+Findings are one per parameter, most duplicates first, with example pairs.
+"""
+from __future__ import annotations
+
+from collections import defaultdict
+from collections.abc import Iterable, Mapping
+from typing import TYPE_CHECKING
+from urllib.parse import parse_qsl, urlencode, urljoin, urlsplit, urlunsplit
+
+from bs4 import BeautifulSoup
+from loguru import logger
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import fragment, msg
+from .content import shingles, word_count
+from .soft404 import _text, similarity
+
+if TYPE_CHECKING:
+    from ..crawler import FetchedPage
+
+
+class ParamOptions(BaseModel):
+    """Thresholds for analyze."""
+
+    threshold: float = Field(default=0.9, ge=0.0, le=1.0)  # similarity counted as the same content
+    shingle_size: int = Field(default=5, ge=1)
+    length_tolerance: float = Field(default=0.2, ge=0.0)  # texts differing more in length are not compared
+    min_duplicates: int = Field(default=2, ge=1)  # parameters generating fewer are not reported
+    max_examples: int = Field(default=3, ge=1)  # example pairs per finding
+    max_comparisons: int = Field(default=50_000, ge=1)  # shingle comparisons per audit
+
+
+class PageFingerprint(BaseModel):
+    """What the analysis needs from one crawled page."""
+
+    words: int = 0
+    shingles: set[int] = Field(default_factory=set, exclude=True)
+    canonical: str | None = None  # absolute; a page canonicalized elsewhere is not a duplicate
+
+
+def fingerprint(page: FetchedPage, shingle_size: int = 5) -> PageFingerprint:
+    """Word count, title and text shingles, and canonical of a crawled page."""
+    text = _text(page.html)
+    tag = BeautifulSoup(page.html, "lxml").find("link", rel="canonical", href=True)
+    href = tag.get("href", "").strip() if tag is not None else ""
+    return PageFingerprint(
+        words=word_count(text),
+        shingles=shingles(text, shingle_size),
+        canonical=urljoin(page.url, href) if href else None,
+    )
+
+
+class ParamDuplicates(BaseModel):
+    """Duplicate URLs one parameter generated."""
+
+    param: str
+    duplicates: int = 0  # URLs whose content matches the URL without the parameter
+    paths: int = 0  # distinct paths they were found on
+    examples: list[tuple[str, str]] = Field(default_factory=list)  # (duplicate, representative)
+
+
+class ParamReport(BaseModel):
+    """What find_duplicates found, and how much it compared."""
+
+    params: list[ParamDuplicates] = Field(default_factory=list)  # most duplicates first
+    comparisons: int = 0
+    capped: bool = False  # max_comparisons was reached before every group was compared
+
+
+def _split(url: str) -> tuple[str, list[tuple[str, str]]]:
+    parts = urlsplit(url)
+    base = urlunsplit((parts.scheme, parts.netloc.lower(), parts.path or "/", "", ""))
+    return base, parse_qsl(parts.query, keep_blank_values=True)
+
+
+def _query_without(params: list[tuple[str, str]], name: str) -> str:
+    return urlencode(sorted((k, v) for k, v in params if k != name))
+
+
+def candidates(urls: Iterable[str]) -> set[str]:
+    """URLs worth fingerprinting: those on paths crawled with at least one query parameter."""
+    by_path: dict[str, list[str]] = defaultdict(list)
+    with_params = set()
+    for url in urls:
+        base, params = _split(url)
+        by_path[base].append(url)
+        if params:
+            with_params.add(base)
+    return {url for base in with_params for url in by_path[base]}
+
+
+def _close_in_length(a: PageFingerprint, b: PageFingerprint, tolerance: float) -> bool:
+    longer = max(a.words, b.words)
+    return longer == 0 or abs(a.words - b.words) <= tolerance * longer
+
+
+def find_duplicates(
+    urls: Iterable[str],
+    fingerprints: Mapping[str, PageFingerprint],
+    options: ParamOptions | None = None,
+) -> ParamReport:
+    """
+    Parameters whose removal leads to a crawled URL with the same content.
+
+    Args:
+        urls: Crawled URLs
+        fingerprints: Fingerprints by URL; URLs without one are not compared
+        options: Similarity threshold, length tolerance, and limits
+
+    Returns:
+        ParamReport with every parameter that generated a duplicate
+    """
+    options = options or ParamOptions()
+    report = ParamReport()
+    buckets: dict[str, list[tuple[str, list[tuple[str, str]]]]] = defaultdict(list)
+    for url in dict.fromkeys(urls):
+        if url in fingerprints:
+            base, params = _split(url)
+            buckets[base].append((url, params))
+
+    found: dict[str, ParamDuplicates] = {}
+    paths: dict[str, set[str]] = defaultdict(set)
+    for base, pages in sorted(buckets.items()):
+        names = sorted({name for _, params in pages for name, _ in params})
+        for name in names:
+            # URLs equal once the parameter is removed, and whether they have it
+            groups: dict[str, list[tuple[bool, str]]] = defaultdict(list)
+            for url, params in pages:
+                has = any(k == name for k, _ in params)
+                groups[_query_without(params, name)].append((has, url))
+            for members in groups.values():
+                if len(members) < 2 or not any(has for has, _ in members):
+                    continue
+                # The URL without the parameter represents the group when it was crawled
+                members.sort()
+                representative = members[0][1]
+                rep = fingerprints[representative]
+                for _, url in members[1:]:
+                    fp = fingerprints[url]
+                    if fp.canonical and fp.canonical != url:
+                        continue  # already canonicalized elsewhere
+                    if not _close_in_length(fp, rep, options.length_tolerance):
+                        continue
+                    if report.comparisons >= options.max_comparisons:
+                        report.capped = True
+                        break
+                    report.comparisons += 1
+                    if similarity(fp.shingles, rep.shingles) < options.threshold:
+                        continue
+                    entry = found.setdefault(name, ParamDuplicates(param=name))
+                    entry.duplicates += 1
+                    if len(entry.examples) < options.max_examples:
+                        entry.examples.append((url, representative))
+                    paths[name].add(base)
+    if report.capped:
+        logger.debug(f"Parameter duplicates: stopped after {report.comparisons} comparisons")
+    for name, entry in found.items():
+        entry.paths = len(paths[name])
+    report.params = sorted(found.values(), key=lambda p: (-p.duplicates, p.param))
+    return report
+
+
+def analyze(
+    urls: Iterable[str],
+    fingerprints: Mapping[str, PageFingerprint],
+    options: ParamOptions | None = None,
+) -> list[Issue]:
+    """
+    Check crawled URLs for parameters that only duplicate content.
+
+    Args:
+        urls: Crawled URLs
+        fingerprints: Fingerprints by URL, at least of the candidates among urls
+        options: Similarity threshold, length tolerance, and limits
+
+    Returns:
+        One parameter_duplicates finding per parameter generating at least
+        min_duplicates duplicate URLs, filed under its first example's
+        representative
+    """
+    options = options or ParamOptions()
+    report = find_duplicates(urls, fingerprints, options)
+    issues = []
+    for entry in report.params:
+        if entry.duplicates < options.min_duplicates:
+            continue
+        pairs = [fragment("params.pair", dup, rep) for dup, rep in entry.examples]
+        issues.append(
+            Issue(
+                url=entry.examples[0][1],
+                type="parameter_duplicates",
+                severity="medium",
+                **msg(
+                    "params.parameter_duplicates",
+                    entry.param,
+                    entry.duplicates,
+                    entry.paths,
+                    pairs,
+                ),
+            )
+        )
+    return issues
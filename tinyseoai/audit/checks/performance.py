@@ -7,14 +7,33 @@ import re
 from urllib.parse import urlparse
 
 from bs4 import BeautifulSoup
+from pydantic import BaseModel, Field
 
 from ...data.models import Issue
+from ...i18n import msg
+
+
+class PerformanceOptions(BaseModel):
+    """Thresholds for PerformanceChecker."""
+
+    large_html_kb: float = Field(default=500, gt=0)  # more is large_html_size
+    moderate_html_kb: float = Field(default=200, gt=0)  # more is moderate_html_size
+    max_blocking_css: int = Field(default=2, ge=0)  # more is render_blocking_css
+    max_css_files: int = Field(default=5, ge=0)  # more is too_many_css_files
+    max_js_files: int = Field(default=10, ge=0)  # more is too_many_js_files
+    eager_images: int = Field(default=3, ge=0)  # above-the-fold images allowed without lazy loading
 
 
 class PerformanceChecker:
     """Check performance-related SEO factors."""
 
-    def __init__(self, html: str, url: str, response_headers: dict | None = None):
+    def __init__(
+        self,
+        html: str,
+        url: str,
+        response_headers: dict | None = None,
+        options: PerformanceOptions | None = None,
+    ):
         """
         Initialize performance checker.
 
@@ -22,11 +41,13 @@ class PerformanceChecker:
             html: HTML content to check
             url: URL of the page
             response_headers: HTTP response headers
+            options: Size and count thresholds
         """
         self.html = html
         self.url = url
         self.soup = BeautifulSoup(html, "lxml")
         self.headers = response_headers or {}
+        self.options = options or PerformanceOptions()
 
     def check_all(self) -> list[Issue]:
         """
@@ -39,8 +60,8 @@ class PerformanceChecker:
 
         issues.extend(self.check_images())
         issues.extend(self.check_render_blocking_resources())
-        issues.extend(self.check_compression())
-        issues.extend(self.check_caching())
+        # Compression is checked by compression.analyze, which measures the page and its CSS and JS
+        # Caching headers are checked by caching.analyze across pages and their static assets
         issues.extend(self.check_page_size())
         issues.extend(self.check_preconnect())
 
@@ -84,19 +105,17 @@ class PerformanceChecker:
                     url=self.url,
                     type="images_without_dimensions",
                     severity="medium",
-                    detail=f"{images_without_dimensions} image(s) missing width/height attributes. "
-                    "This can cause Cumulative Layout Shift (CLS).",
+                    **msg("performance.images_without_dimensions", images_without_dimensions),
                 )
             )
 
-        if images_without_lazy_loading > 3:  # Allow a few above-fold images
+        if images_without_lazy_loading > self.options.eager_images:
             issues.append(
                 Issue(
                     url=self.url,
                     type="images_not_lazy_loaded",
                     severity="low",
-                    detail=f"{images_without_lazy_loading} image(s) not lazy loaded. "
-                    "Consider using loading='lazy' for off-screen images.",
+                    **msg("performance.images_not_lazy_loaded", images_without_lazy_loading),
                 )
             )
 
@@ -106,8 +125,7 @@ class PerformanceChecker:
                     url=self.url,
                     type="images_not_modern_format",
                     severity="info",
-                    detail=f"{images_without_modern_format} image(s) not using modern formats (WebP/AVIF). "
-                    "Modern formats can significantly reduce file size.",
+                    **msg("performance.images_not_modern_format", images_without_modern_format),
                 )
             )
 
@@ -132,14 +150,13 @@ class PerformanceChecker:
             if media == "all" or not media:
                 blocking_css += 1
 
-        if blocking_css > 2:
+        if blocking_css > self.options.max_blocking_css:
             issues.append(
                 Issue(
                     url=self.url,
                     type="render_blocking_css",
                     severity="medium",
-                    detail=f"{blocking_css} render-blocking CSS file(s). "
-                    "Consider inlining critical CSS or using media queries.",
+                    **msg("performance.render_blocking_css", blocking_css),
                 )
             )
 
@@ -160,8 +177,7 @@ class PerformanceChecker:
                     url=self.url,
                     type="render_blocking_javascript",
                     severity="high",
-                    detail=f"{blocking_scripts} render-blocking JavaScript file(s) in <head>. "
-                    "Add async or defer attributes, or move scripts to end of <body>.",
+                    **msg("performance.render_blocking_javascript", blocking_scripts),
                 )
             )
 
@@ -187,7 +203,7 @@ class PerformanceChecker:
                     url=self.url,
                     type="no_compression",
                     severity="high",
-                    detail="No compression detected. Enable gzip or Brotli compression to reduce transfer size.",
+                    **msg("performance.no_compression"),
                 )
             )
         elif content_encoding == "gzip":
@@ -197,7 +213,7 @@ class PerformanceChecker:
                     url=self.url,
                     type="compression_not_optimal",
                     severity="info",
-                    detail="Using gzip compression. Consider Brotli for better compression ratios.",
+                    **msg("performance.compression_not_optimal"),
                 )
             )
 
@@ -225,7 +241,7 @@ class PerformanceChecker:
                     url=self.url,
                     type="no_caching_headers",
                     severity="medium",
-                    detail="No caching headers found. Set Cache-Control or Expires for better performance.",
+                    **msg("performance.no_caching_headers"),
                 )
             )
         elif cache_control:
@@ -236,8 +252,7 @@ class PerformanceChecker:
                         url=self.url,
                         type="caching_disabled",
                         severity="medium",
-                        detail="Caching is disabled via Cache-Control header. "
-                        "Consider enabling caching for static resources.",
+                        **msg("performance.caching_disabled"),
                     )
                 )
 
@@ -251,8 +266,7 @@ class PerformanceChecker:
                             url=self.url,
                             type="short_cache_duration",
                             severity="low",
-                            detail=f"Cache duration is only {max_age} seconds. "
-                            "Consider longer cache for static resources.",
+                            **msg("performance.short_cache_duration", max_age),
                         )
                     )
 
@@ -263,7 +277,7 @@ class PerformanceChecker:
                     url=self.url,
                     type="missing_etag",
                     severity="info",
-                    detail="Missing ETag header. ETags help with cache validation.",
+                    **msg("performance.missing_etag"),
                 )
             )
 
@@ -281,23 +295,22 @@ class PerformanceChecker:
         html_size = len(self.html.encode("utf-8"))
         html_size_kb = html_size / 1024
 
-        if html_size_kb > 500:  # 500 KB
+        if html_size_kb > self.options.large_html_kb:
             issues.append(
                 Issue(
                     url=self.url,
                     type="large_html_size",
                     severity="high",
-                    detail=f"HTML size is {html_size_kb:.1f} KB. Large HTML can slow initial render. "
-                    "Consider code minification and removing unused code.",
+                    **msg("performance.large_html_size", html_size_kb),
                 )
             )
-        elif html_size_kb > 200:  # 200 KB
+        elif html_size_kb > self.options.moderate_html_kb:
             issues.append(
                 Issue(
                     url=self.url,
                     type="moderate_html_size",
                     severity="low",
-                    detail=f"HTML size is {html_size_kb:.1f} KB. Consider optimizing if possible.",
+                    **msg("performance.moderate_html_size", html_size_kb),
                 )
             )
 
@@ -305,24 +318,23 @@ class PerformanceChecker:
         external_css = len(self.soup.find_all("link", rel="stylesheet"))
         external_js = len(self.soup.find_all("script", src=True))
 
-        if external_css > 5:
+        if external_css > self.options.max_css_files:
             issues.append(
                 Issue(
                     url=self.url,
                     type="too_many_css_files",
                     severity="medium",
-                    detail=f"{external_css} external CSS files. Consider concatenation to reduce HTTP requests.",
+                    **msg("performance.too_many_css_files", external_css),
                 )
             )
 
-        if external_js > 10:
+        if external_js > self.options.max_js_files:
             issues.append(
                 Issue(
                     url=self.url,
                     type="too_many_js_files",
                     severity="medium",
-                    detail=f"{external_js} external JavaScript files. "
-                    "Consider bundling to reduce HTTP requests.",
+                    **msg("performance.too_many_js_files", external_js),
                 )
             )
 
@@ -361,8 +373,7 @@ class PerformanceChecker:
                     url=self.url,
                     type="missing_preconnect",
                     severity="info",
-                    detail=f"Consider adding preconnect hints for external domains: "
-                    f"{', '.join(sample_domains)}. This can improve loading performance.",
+                    **msg("performance.missing_preconnect", sample_domains),
                 )
             )
 
@@ -0,0 +1,237 @@
+"""
+HTTP/2 and HTTP/3 support of the audited host.
+
+HTTP/2 is read from ALPN in a TLS handshake of our own offering h2 and
+http/1.1, as tls.py does for the certificate: httpx does not say what a
+server would negotiate (and only speaks h2 with the h2 package). HTTP/3
+cannot be negotiated over TCP; servers advertise it in the Alt-Svc header,
+and the advertised endpoint is probed with a QUIC packet of a reserved
+version, which every QUIC server answers with a version negotiation packet
+before any handshake. No QUIC stack is needed for that.
+"""
+from __future__ import annotations
+
+import asyncio
+import os
+import socket
+import ssl
+from collections.abc import Mapping
+from urllib.parse import urlparse
+
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import msg
+
+ALPN_PROTOCOLS = ["h2", "http/1.1"]
+
+# A version of the form 0x?a?a?a?a is reserved, so no server supports it
+# and any QUIC server must reply with the versions it does (RFC 9000, 6 and 15)
+_PROBE_VERSION = bytes.fromhex("1a2a3a4a")
+_PROBE_SIZE = 1200  # smaller first datagrams may be dropped unanswered
+
+
+class ProtocolOptions(BaseModel):
+    """Connection settings for detect."""
+
+    timeout: float = Field(default=5.0, gt=0)
+    port: int = Field(default=443, ge=1, le=65535)
+    probe_h3: bool = True  # False trusts Alt-Svc without sending a UDP packet
+
+
+class AltService(BaseModel):
+    """One entry of an Alt-Svc header."""
+
+    protocol: str  # ALPN id, e.g. "h3" or "h3-29"
+    host: str | None = None  # None: the same host
+    port: int
+    max_age: int | None = None  # seconds, from ma=
+
+    @property
+    def http3(self) -> bool:
+        return self.protocol == "h3" or self.protocol.startswith("h3-")
+
+    def authority(self, default_host: str) -> str:
+        return f"{self.host or default_host}:{self.port}"
+
+
+class ProtocolSupport(BaseModel):
+    """What connecting to the host found."""
+
+    host: str
+    alpn: str | None = None  # negotiated protocol; None when no handshake completed
+    alt_svc: list[AltService] = Field(default_factory=list)
+    h3_endpoint: str | None = None  # host:port of the HTTP/3 endpoint probed
+    h3_reachable: bool | None = None  # None when no HTTP/3 is advertised or it was not probed
+    error: str | None = None  # why no handshake completed
+
+    @property
+    def http2(self) -> bool:
+        return self.alpn == "h2"
+
+    @property
+    def http3_advertised(self) -> bool:
+        return any(service.http3 for service in self.alt_svc)
+
+    def metrics(self) -> dict:
+        """The protocol part of the audit result's meta["performance"] block."""
+        return {
+            "negotiated": self.alpn,
+            "http2": self.http2,
+            "http3_advertised": self.http3_advertised,
+            "http3_endpoint": self.h3_endpoint,
+            "http3_reachable": self.h3_reachable,
+            "error": self.error,
+        }
+
+
+def describe(metrics: dict) -> str:
+    """One line for reports, e.g. "HTTP/2, HTTP/3 advertised"."""
+    if not metrics.get("negotiated"):
+        return "unknown"
+    parts = ["HTTP/2" if metrics.get("http2") else "HTTP/1.1 only"]
+    if metrics.get("http3_advertised"):
+        reachable = metrics.get("http3_reachable") is not False
+        parts.append("HTTP/3 advertised" if reachable else "HTTP/3 unreachable")
+    return ", ".join(parts)
+
+
+def parse_alt_svc(value: str | None) -> list[AltService]:
+    """
+    Entries of an Alt-Svc header value (RFC 7838); "clear" and malformed entries give none.
+
+    Example: 'h3=":443"; ma=86400, h3-29=":443"' gives h3 and h3-29 on port 443.
+    """
+    services = []
+    for entry in (value or "").split(","):
+        protocol, _, rest = entry.strip().partition("=")
+        if not rest:
+            continue
+        authority, *params = (part.strip() for part in rest.split(";"))
+        host, _, port = authority.strip('"').rpartition(":")
+        if not port.isdigit():
+            continue
+        max_age = None
+        for param in params:
+            name, _, number = param.partition("=")
+            if name.strip().lower() == "ma" and number.strip().isdigit():
+                max_age = int(number)
+        services.append(
+            AltService(protocol=protocol.strip().lower(), host=host or None, port=int(port), max_age=max_age)
+        )
+    return services
+
+
+def negotiate(host: str, options: ProtocolOptions | None = None) -> ProtocolSupport:
+    """
+    Connect to host over TLS offering h2 and http/1.1, and record which one the server picks.
+
+    Any certificate is accepted; the tls check reports certificate problems.
+    Blocking; detect runs it in a thread.
+    """
+    options = options or ProtocolOptions()
+    result = ProtocolSupport(host=host)
+    context = ssl.SSLContext(ssl.PROTOCOL_TLS_CLIENT)
+    context.check_hostname = False
+    context.verify_mode = ssl.CERT_NONE
+    context.set_alpn_protocols(ALPN_PROTOCOLS)
+    try:
+        with socket.create_connection((host, options.port), timeout=options.timeout) as sock:
+            with context.wrap_socket(sock, server_hostname=host) as tls:
+                # A server without ALPN speaks HTTP/1.1
+                result.alpn = tls.selected_alpn_protocol() or "http/1.1"
+    except (OSError, ssl.SSLError) as e:
+        result.error = str(e) or type(e).__name__
+    return result
+
+
+def _probe_packet(dcid: bytes, scid: bytes) -> bytes:
+    # Long header (form and fixed bits set), version, then both connection IDs with their lengths
+    header = bytes([0xC0]) + _PROBE_VERSION + bytes([len(dcid)]) + dcid + bytes([len(scid)]) + scid
+    return header.ljust(_PROBE_SIZE, b"\0")
+
+
+def _is_version_negotiation(data: bytes, scid: bytes) -> bool:
+    # Version 0 marks version negotiation; its destination ID echoes our source ID
+    if len(data) < 7 or not data[0] & 0x80 or data[1:5] != b"\0\0\0\0":
+        return False
+    length = data[5]
+    return data[6 : 6 + length] == scid
+
+
+def quic_reachable(host: str, port: int, timeout: float = 5.0) -> bool:
+    """
+    Whether a QUIC server answers on host:port (UDP).
+
+    Blocking; detect runs it in a thread.
+    """
+    dcid, scid = os.urandom(8), os.urandom(8)
+    try:
+        family, _, _, _, address = socket.getaddrinfo(host, port, type=socket.SOCK_DGRAM)[0]
+        with socket.socket(family, socket.SOCK_DGRAM) as sock:
+            sock.settimeout(timeout)
+            sock.connect(address)
+            sock.send(_probe_packet(dcid, scid))
+            for _ in range(3):  # skip stray datagrams, but not forever
+                if _is_version_negotiation(sock.recv(2048), scid):
+                    return True
+    except OSError:  # timeout, or port unreachable
+        pass
+    return False
+
+
+async def detect(
+    url: str, headers: Mapping[str, str] | None = None, options: ProtocolOptions | None = None
+) -> ProtocolSupport | None:
+    """
+    Find the HTTP versions the host of url supports.
+
+    Args:
+        url: Page URL; only https URLs are checked
+        headers: Response headers of url, for Alt-Svc (lowercase names)
+        options: Timeout, port, and whether to probe an advertised HTTP/3 endpoint
+
+    Returns:
+        ProtocolSupport, or None for plain http URLs
+    """
+    options = options or ProtocolOptions()
+    parsed = urlparse(url)
+    if parsed.scheme != "https" or not parsed.hostname:
+        return None
+    if parsed.port:
+        options = options.model_copy(update={"port": parsed.port})
+    host = parsed.hostname
+    support = await asyncio.to_thread(negotiate, host, options)
+    support.alt_svc = parse_alt_svc((headers or {}).get("alt-svc"))
+    h3 = next((service for service in support.alt_svc if service.http3), None)
+    if h3 is not None:
+        support.h3_endpoint = h3.authority(host)
+        if options.probe_h3:
+            support.h3_reachable = await asyncio.to_thread(
+                quic_reachable, h3.host or host, h3.port, options.timeout
+            )
+    return support
+
+
+def protocol_issues(support: ProtocolSupport | None, url: str) -> list[Issue]:
+    """
+    Findings for a host's protocol support, filed under url.
+
+    Returns:
+        http2_unsupported when the server only speaks HTTP/1.1, and
+        http3_unreachable when Alt-Svc advertises HTTP/3 that does not answer;
+        nothing when the handshake failed (the tls check reports that)
+    """
+    if support is None or support.alpn is None:
+        return []
+    issues = []
+
+    def add(issue_type: str, severity: str, *args) -> None:
+        fields = msg(f"protocol.{issue_type}", *args)
+        issues.append(Issue(url=url, type=issue_type, severity=severity, **fields))
+
+    if not support.http2:
+        add("http2_unsupported", "medium", support.host)
+    if support.h3_reachable is False:
+        add("http3_unreachable", "info", support.h3_endpoint)
+    return issues
@@ -0,0 +1,318 @@
+"""
+Redirect tracing: chains, loops, downgrades, and meta refreshes.
+
+trace follows redirects one request at a time (the client never follows
+them itself), so every hop's status, target, and latency is recorded.
+HTML pages that redirect with <meta http-equiv="refresh"> count as a hop
+too. Page verification and the crawler's redirect check both use it.
+
+analyze traces the internal links that redirected during the crawl and
+classifies the redirect types: temporary redirects to a page that loads
+are probably permanent moves, chains mixing permanent and temporary hops
+send search engines mixed signals, and 307/308 on endpoints that look like
+form targets are noted because they re-send the request body.
+"""
+from __future__ import annotations
+
+import re
+import time
+from urllib.parse import urljoin, urlparse
+
+import httpx
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import fragment, msg
+
+REDIRECT_STATUSES = frozenset({301, 302, 303, 307, 308})
+PERMANENT_STATUSES = frozenset({301, 308})
+TEMPORARY_STATUSES = frozenset({302, 303, 307})
+MAX_CHAIN_HOPS = 2  # longer chains are reported
+_META_TAG = re.compile(r"<meta\b[^>]*>", re.IGNORECASE)
+_HTTP_EQUIV_REFRESH = re.compile(r"""http-equiv\s*=\s*["']?refresh\b""", re.IGNORECASE)
+_CONTENT = re.compile(r"""content\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s>]+))""", re.IGNORECASE)
+_REFRESH_URL = re.compile(r"""^\s*\d*(?:\.\d+)?\s*[;,]\s*(?:url\s*=\s*)?['"]?([^'"]+)""", re.IGNORECASE)
+# Paths that look like form or API targets, where a method-preserving redirect re-sends the body
+_ACTION_PATH = re.compile(
+    r"/(?:api|submit|login|logout|signin|signup|register|subscribe|checkout|cart|comments?|"
+    r"contact|forms?|post)(?:/|\.|$)",
+    re.IGNORECASE,
+)
+
+
+class RedirectTypeOptions(BaseModel):
+    """Limits for analyze."""
+
+    max_urls: int = Field(default=100, ge=0)  # redirected URLs traced per audit
+    timeout: float = 10.0
+
+
+class RedirectHop(BaseModel):
+    """One request in a trace."""
+
+    url: str
+    status_code: int
+    location: str | None = None  # absolute target, when this hop redirects
+    latency_ms: float
+    meta_refresh: bool = False  # redirected by <meta http-equiv="refresh">, not by status
+
+
+class RedirectTrace(BaseModel):
+    """Every hop from the first URL to the final response (or the failure)."""
+
+    url: str
+    hops: list[RedirectHop] = Field(default_factory=list)
+    loop: bool = False
+    too_many_hops: bool = False
+    error: str | None = None  # transport error on the last request
+
+    @property
+    def redirects(self) -> list[RedirectHop]:
+        return [h for h in self.hops if h.location]
+
+    @property
+    def final_url(self) -> str:
+        return self.hops[-1].url if self.hops else self.url
+
+    @property
+    def final_status(self) -> int | None:
+        return self.hops[-1].status_code if self.hops and not self.error else None
+
+    @property
+    def latency_ms(self) -> float:
+        return round(sum(h.latency_ms for h in self.hops), 1)
+
+    @property
+    def downgrade(self) -> RedirectHop | None:
+        """First hop from an https URL to an http one."""
+        return next(
+            (h for h in self.redirects if urlparse(h.url).scheme == "https" and urlparse(h.location).scheme == "http"),
+            None,
+        )
+
+    def render(self) -> str:
+        """The chain as text, e.g. "http://a/ -301-> https://a/ -meta-> https://a/home (200)"."""
+        parts = []
+        for hop in self.hops:
+            parts.append(hop.url)
+            if hop.location:
+                parts.append(f"-{'meta' if hop.meta_refresh else hop.status_code}->")
+        if self.loop and self.hops:
+            parts.append(self.hops[-1].location or "")
+        elif self.error:
+            parts.append(f"({self.error})")
+        elif self.hops and not self.hops[-1].location:
+            parts.append(f"({self.hops[-1].status_code})")
+        return " ".join(parts)
+
+
+def meta_refresh_target(html: str, base_url: str) -> str | None:
+    """Absolute URL of a <meta http-equiv="refresh"> redirect, if the page has one."""
+    for tag in _META_TAG.findall(html):
+        if not _HTTP_EQUIV_REFRESH.search(tag):
+            continue
+        content = _CONTENT.search(tag)
+        if content is None:
+            continue
+        match = _REFRESH_URL.match(next(g for g in content.groups() if g is not None))
+        if match and match.group(1).strip():
+            return urljoin(base_url, match.group(1).strip())
+    return None
+
+
+def _is_html(response: httpx.Response) -> bool:
+    return "html" in response.headers.get("content-type", "").lower()
+
+
+async def trace(
+    url: str,
+    client: httpx.AsyncClient,
+    max_hops: int = 10,
+    method: str = "GET",
+    timeout: float = 10.0,
+) -> RedirectTrace:
+    """
+    Follow redirects from url hop by hop.
+
+    Meta refreshes are only seen with GET, since HEAD has no body. A
+    transport error ends the trace with error set; hops before it are kept.
+
+    Args:
+        url: URL to start from
+        client: HTTP client (its own redirect following is not used)
+        max_hops: Redirects to follow before giving up
+        method: "GET" or "HEAD"
+        timeout: Per-request timeout in seconds
+
+    Returns:
+        RedirectTrace with one RedirectHop per request
+    """
+    result = RedirectTrace(url=url)
+    seen = set()
+    current = url
+    while True:
+        seen.add(current)
+        started = time.monotonic()
+        try:
+            response = await client.request(method, current, timeout=timeout, follow_redirects=False)
+        except httpx.TimeoutException:
+            result.error = "timeout"
+            return result
+        except httpx.HTTPError as e:
+            result.error = type(e).__name__
+            return result
+        hop = RedirectHop(
+            url=current,
+            status_code=response.status_code,
+            latency_ms=round((time.monotonic() - started) * 1000, 1),
+        )
+        result.hops.append(hop)
+
+        location = response.headers.get("location")
+        if response.status_code in REDIRECT_STATUSES and location:
+            hop.location = urljoin(current, location.strip())
+        elif method == "GET" and response.status_code < 300 and _is_html(response):
+            hop.location = meta_refresh_target(response.text, current)
+            hop.meta_refresh = hop.location is not None
+        if not hop.location:
+            return result
+        if hop.location in seen:
+            result.loop = True
+            return result
+        if len(result.redirects) >= max_hops:
+            result.too_many_hops = True
+            return result
+        current = hop.location
+
+
+def trace_issues(result: RedirectTrace) -> list[Issue]:
+    """Findings for a trace: loops, long chains, downgrades, meta refreshes, and dead ends."""
+    issues = []
+    chain = result.render()
+    redirects = result.redirects
+    if result.loop:
+        issues.append(
+            Issue(
+                url=result.url,
+                type="redirect_loop",
+                severity="high",
+                **msg("redirects.redirect_loop", chain),
+            )
+        )
+    elif result.too_many_hops:
+        issues.append(
+            Issue(
+                url=result.url,
+                type="redirect_too_many_hops",
+                severity="high",
+                **msg("redirects.redirect_too_many_hops", len(redirects), chain),
+            )
+        )
+    elif len(redirects) > MAX_CHAIN_HOPS:
+        issues.append(
+            Issue(
+                url=result.url,
+                type="redirect_chain",
+                severity="medium",
+                **msg("redirects.redirect_chain", len(redirects), chain),
+            )
+        )
+    downgrade = result.downgrade
+    if downgrade is not None:
+        issues.append(
+            Issue(
+                url=result.url,
+                type="redirect_https_downgrade",
+                severity="high",
+                **msg("redirects.redirect_https_downgrade", downgrade.url, chain),
+            )
+        )
+    for hop in redirects:
+        if hop.meta_refresh:
+            issues.append(
+                Issue(
+                    url=hop.url,
+                    type="meta_refresh_redirect",
+                    severity="medium",
+                    **msg("redirects.meta_refresh_redirect", hop.location),
+                )
+            )
+    status = result.final_status
+    if redirects and not result.loop and status is not None and status >= 400:
+        issues.append(
+            Issue(
+                url=result.url,
+                type="redirect_to_error",
+                severity="high",
+                **msg("redirects.redirect_to_error", status, chain),
+            )
+        )
+    return issues
+
+
+def redirect_type_issues(result: RedirectTrace, sources: list[str]) -> list[Issue]:
+    """
+    Redirect type findings for the trace of a URL linked from sources.
+
+    Findings are filed under the first linking page (the URL itself when
+    nothing links it) and name the chain and the permanent target to use.
+
+    Returns:
+        temporary_redirect_stable for a chain that only uses temporary
+        redirects and ends at a page that loads (in one crawl, every such
+        302 looks stable), redirect_chain_mixed_types for chains mixing
+        permanent and temporary hops, and method_preserving_redirect (info)
+        for 307/308 hops from paths that look like form or API targets
+    """
+    issues = []
+    redirects = [h for h in result.redirects if not h.meta_refresh]
+    if not redirects or result.loop or result.too_many_hops:
+        return issues
+    source = sources[0] if sources else result.url
+    if len(sources) > 1:
+        subject = fragment("redirects.link_many", source, result.url, len(sources) - 1)
+    elif sources:
+        subject = fragment("redirects.link", source, result.url)
+    else:
+        subject = fragment("redirects.start_url", result.url)
+    chain = result.render()
+
+    def add(issue_type: str, severity: str, *args) -> None:
+        fields = msg(f"redirects.{issue_type}", subject, *args)
+        issues.append(Issue(url=source, type=issue_type, severity=severity, **fields))
+
+    statuses = {h.status_code for h in redirects}
+    status = result.final_status
+    stable = status is not None and status < 300
+    if stable and statuses <= TEMPORARY_STATUSES:
+        add("temporary_redirect_stable", "medium", chain, result.final_url)
+    elif statuses & PERMANENT_STATUSES and statuses & TEMPORARY_STATUSES:
+        add("redirect_chain_mixed_types", "low", chain, result.final_url)
+    for hop in redirects:
+        if hop.status_code in (307, 308) and _ACTION_PATH.search(urlparse(hop.url).path):
+            add("method_preserving_redirect", "info", hop.url, hop.status_code, hop.location)
+    return issues
+
+
+async def analyze(
+    redirected: dict[str, list[str]],
+    client: httpx.AsyncClient,
+    options: RedirectTypeOptions | None = None,
+) -> list[Issue]:
+    """
+    Trace URLs that redirected during the crawl and classify their redirect types.
+
+    Args:
+        redirected: Each URL that redirected, mapped to the pages linking it
+        client: HTTP client (its own redirect following is not used)
+        options: URL limit and timeout
+
+    Returns:
+        The findings of redirect_type_issues for each URL
+    """
+    options = options or RedirectTypeOptions()
+    issues = []
+    for url, sources in list(redirected.items())[: options.max_urls]:
+        result = await trace(url, client, timeout=options.timeout)
+        issues.extend(redirect_type_issues(result, sources))
+    return issues
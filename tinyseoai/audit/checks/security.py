@@ -9,6 +9,8 @@ import httpx
 from loguru import logger
 
 from ...data.models import Issue
+from ...i18n import msg
+from .headers import analyze as analyze_headers
 
 
 class SecurityChecker:
@@ -65,7 +67,7 @@ class SecurityChecker:
                     url=self.url,
                     type="no_https",
                     severity="high",
-                    detail="Site is not using HTTPS. This can negatively impact SEO and user trust.",
+                    **msg("security.no_https"),
                 )
             )
 
@@ -79,104 +81,9 @@ class SecurityChecker:
             headers: HTTP response headers
 
         Returns:
-            List of security header issues
+            List of security header issues (see checks.headers.analyze)
         """
-        issues = []
-
-        # Convert header keys to lowercase for case-insensitive comparison
-        headers_lower = {k.lower(): v for k, v in headers.items()}
-
-        # Check for HSTS (HTTP Strict Transport Security)
-        if self.is_https and "strict-transport-security" not in headers_lower:
-            issues.append(
-                Issue(
-                    url=self.url,
-                    type="missing_hsts",
-                    severity="medium",
-                    detail="Missing Strict-Transport-Security header. "
-                    "This header enforces HTTPS connections.",
-                )
-            )
-
-        # Check for X-Content-Type-Options
-        if "x-content-type-options" not in headers_lower:
-            issues.append(
-                Issue(
-                    url=self.url,
-                    type="missing_x_content_type_options",
-                    severity="low",
-                    detail="Missing X-Content-Type-Options header. "
-                    "Should be set to 'nosniff' to prevent MIME type sniffing.",
-                )
-            )
-
-        # Check for X-Frame-Options or CSP frame-ancestors
-        if (
-            "x-frame-options" not in headers_lower
-            and "content-security-policy" not in headers_lower
-        ):
-            issues.append(
-                Issue(
-                    url=self.url,
-                    type="missing_clickjacking_protection",
-                    severity="medium",
-                    detail="Missing X-Frame-Options or CSP frame-ancestors directive. "
-                    "This protects against clickjacking attacks.",
-                )
-            )
-
-        # Check for Content-Security-Policy
-        if "content-security-policy" not in headers_lower:
-            issues.append(
-                Issue(
-                    url=self.url,
-                    type="missing_csp",
-                    severity="low",
-                    detail="Missing Content-Security-Policy header. "
-                    "CSP helps prevent XSS and other code injection attacks.",
-                )
-            )
-
-        # Check for X-XSS-Protection
-        if "x-xss-protection" not in headers_lower:
-            issues.append(
-                Issue(
-                    url=self.url,
-                    type="missing_xss_protection",
-                    severity="low",
-                    detail="Missing X-XSS-Protection header. "
-                    "This header enables browser XSS filtering.",
-                )
-            )
-
-        # Check for Referrer-Policy
-        if "referrer-policy" not in headers_lower:
-            issues.append(
-                Issue(
-                    url=self.url,
-                    type="missing_referrer_policy",
-                    severity="info",
-                    detail="Missing Referrer-Policy header. "
-                    "This controls how much referrer information is shared.",
-                )
-            )
-
-        # Check for Permissions-Policy (formerly Feature-Policy)
-        if (
-            "permissions-policy" not in headers_lower
-            and "feature-policy" not in headers_lower
-        ):
-            issues.append(
-                Issue(
-                    url=self.url,
-                    type="missing_permissions_policy",
-                    severity="info",
-                    detail="Missing Permissions-Policy header. "
-                    "This controls which browser features can be used.",
-                )
-            )
-
-        return issues
+        return analyze_headers(headers, self.url)
 
     def check_mixed_content(self, html: str) -> list[Issue]:
         """
@@ -211,8 +118,7 @@ class SecurityChecker:
                     url=self.url,
                     type="mixed_content",
                     severity="high",
-                    detail=f"Found {len(matches)} HTTP resources on HTTPS page. "
-                    f"Examples: {', '.join(sample[:3])}",
+                    **msg("security.mixed_content", len(matches), sample[:3]),
                 )
             )
 
@@ -257,7 +163,7 @@ async def check_ssl_certificate(url: str) -> list[Issue]:
                             url=url,
                             type="ssl_expired",
                             severity="high",
-                            detail=f"SSL certificate expired {abs(days_until_expiry)} days ago",
+                            **msg("security.ssl_expired", abs(days_until_expiry)),
                         )
                     )
                 elif days_until_expiry < 30:
@@ -266,7 +172,7 @@ async def check_ssl_certificate(url: str) -> list[Issue]:
                             url=url,
                             type="ssl_expiring_soon",
                             severity="medium",
-                            detail=f"SSL certificate expires in {days_until_expiry} days",
+                            **msg("security.ssl_expiring_soon", days_until_expiry),
                         )
                     )
 
@@ -280,13 +186,13 @@ async def check_ssl_certificate(url: str) -> list[Issue]:
                             url=url,
                             type="self_signed_certificate",
                             severity="high",
-                            detail="SSL certificate is self-signed",
+                            **msg("security.self_signed_certificate"),
                         )
                     )
 
     except ssl.SSLError as e:
         issues.append(
-            Issue(url=url, type="ssl_error", severity="high", detail=f"SSL error: {str(e)}")
+            Issue(url=url, type="ssl_error", severity="high", **msg("security.ssl_error", str(e)))
         )
     except Exception as e:
         logger.warning(f"Could not check SSL certificate for {url}: {e}")
@@ -317,7 +223,7 @@ def check_cookies(headers: dict) -> list[Issue]:
                     url="",
                     type="cookie_missing_secure",
                     severity="medium",
-                    detail="Cookies are missing the Secure flag",
+                    **msg("security.cookie_missing_secure"),
                 )
             )
 
@@ -328,7 +234,7 @@ def check_cookies(headers: dict) -> list[Issue]:
                     url="",
                     type="cookie_missing_httponly",
                     severity="medium",
-                    detail="Cookies are missing the HttpOnly flag",
+                    **msg("security.cookie_missing_httponly"),
                 )
             )
 
@@ -339,7 +245,7 @@ def check_cookies(headers: dict) -> list[Issue]:
                     url="",
                     type="cookie_missing_samesite",
                     severity="low",
-                    detail="Cookies are missing the SameSite attribute",
+                    **msg("security.cookie_missing_samesite"),
                 )
             )
 
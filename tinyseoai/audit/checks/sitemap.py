@@ -0,0 +1,487 @@
+"""
+Sitemap discovery, validation, and coverage analysis.
+
+Sitemaps are found through robots.txt (falling back to /sitemap.xml),
+index files are followed, and each file is parsed as it streams in so
+50,000-URL sitemaps never sit in memory as one document. Listed URLs are
+then compared with the pages the crawler actually reached.
+"""
+from __future__ import annotations
+
+import asyncio
+import re
+import zlib
+from urllib.parse import urljoin
+from xml.etree.ElementTree import ParseError, XMLPullParser
+
+import httpx
+from loguru import logger
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import fragment, listing, msg
+from ...utils import urlnorm
+from ..robots import analyze_robots
+from ..verify import PageStatus, fetch_status
+
+# Sitemaps should list clean URLs, so tracking parameters are kept
+_URL_OPTIONS = urlnorm.NormalizeOptions(strip_params=())
+
+MAX_SITEMAP_URLS = 50_000  # per file, from the sitemaps.org protocol
+MAX_SITEMAP_BYTES = 50 * 1024 * 1024  # uncompressed, per file
+VALID_CHANGEFREQ = frozenset({"always", "hourly", "daily", "weekly", "monthly", "yearly", "never"})
+# W3C Datetime: YYYY, YYYY-MM, YYYY-MM-DD, or a full date-time with timezone
+_W3C_DATETIME = re.compile(
+    r"^\d{4}(-\d{2}(-\d{2}(T\d{2}:\d{2}(:\d{2}(\.\d+)?)?(Z|[+-]\d{2}:\d{2}))?)?)?$"
+)
+_GZIP_MAGIC = b"\x1f\x8b"
+
+
+class SitemapOptions(BaseModel):
+    """Limits for analyze."""
+
+    max_depth: int = Field(default=3, ge=0)  # levels of nested index files to follow
+    max_probes: int = Field(default=200, ge=0)  # uncrawled sitemap URLs checked for 404s and redirects
+    concurrency: int = Field(default=8, ge=1)
+    timeout: float = 15.0
+    max_listed: int = Field(default=5, ge=1)  # URLs named in a finding before "and N more"
+
+
+class SitemapEntry(BaseModel):
+    """One <url> entry, or one line of a plain-text sitemap."""
+
+    loc: str
+    lastmod: str | None = None
+    changefreq: str | None = None
+    priority: str | None = None
+
+
+class SitemapFile(BaseModel):
+    """One fetched sitemap or sitemap index."""
+
+    url: str
+    kind: str | None = None  # "urlset", "index", or "text"
+    status_code: int | None = None
+    url_count: int = 0
+    nested: list[str] = Field(default_factory=list)
+    error: str | None = None  # fetch or parse failure; entries before it are kept
+    truncated: bool = False
+
+
+class SitemapReport(BaseModel):
+    """Result of analyze."""
+
+    sitemaps: list[SitemapFile] = Field(default_factory=list)
+    entries: list[SitemapEntry] = Field(default_factory=list)
+    probes: list[PageStatus] = Field(default_factory=list)
+    issues: list[Issue] = Field(default_factory=list)
+
+    @property
+    def urls(self) -> list[str]:
+        return [e.loc for e in self.entries]
+
+
+def _local(tag: str) -> str:
+    """Tag name without its XML namespace."""
+    return tag.rsplit("}", 1)[-1]
+
+
+def _normalize(url: str) -> str:
+    """Comparable form of a URL (see urlnorm.normalize)."""
+    return urlnorm.normalize(url, _URL_OPTIONS)
+
+
+class SitemapStream:
+    """
+    Incremental sitemap parser.
+
+    Feed it raw response chunks; gzip is detected from the magic bytes and
+    XML is told apart from plain text by the first non-blank character.
+    Finished <url> elements are dropped from the tree as soon as they are
+    read, so memory stays flat however long the file is. A parse error
+    stops the stream but keeps everything read before it.
+    """
+
+    def __init__(self, max_bytes: int = MAX_SITEMAP_BYTES):
+        self.max_bytes = max_bytes
+        self.kind: str | None = None
+        self.entries: list[SitemapEntry] = []
+        self.nested: list[str] = []
+        self.error: str | None = None
+        self.truncated = False
+        self._size = 0
+        self._head = b""
+        self._inflate = None  # zlib decompressor once gzip is detected
+        self._parser: XMLPullParser | None = None
+        self._root = None
+        self._text = b""
+        self._done = False
+
+    def feed(self, chunk: bytes) -> None:
+        if self._done:
+            return
+        if self._inflate is None and self._head is not None:
+            self._head += chunk
+            if len(self._head) < 2:
+                return
+            chunk, self._head = self._head, None
+            if chunk.startswith(_GZIP_MAGIC):
+                self._inflate = zlib.decompressobj(wbits=31)
+        if self._inflate is not None:
+            try:
+                chunk = self._inflate.decompress(chunk)
+            except zlib.error as e:
+                self._stop(f"gzip: {e}")
+                return
+        self._consume(chunk)
+
+    def close(self) -> None:
+        if self._head:
+            chunk, self._head = self._head, None
+            self._consume(chunk)
+        if self._done:
+            return
+        if self._parser is not None:
+            try:
+                self._parser.close()
+                self._drain()
+            except ParseError as e:
+                self._stop(f"XML: {e}")
+        elif self.kind == "text":
+            self._read_lines(final=True)
+        self._done = True
+
+    def _stop(self, error: str) -> None:
+        self.error = error
+        self._done = True
+
+    def _consume(self, data: bytes) -> None:
+        if not data or self._done:
+            return
+        if self._size + len(data) > self.max_bytes:
+            data = data[: self.max_bytes - self._size]
+            self.truncated = True
+        self._size += len(data)
+        if self.kind is None:
+            stripped = data.lstrip()
+            if not stripped:
+                return
+            self.kind = "xml" if stripped.startswith((b"<", b"\xef\xbb\xbf<")) else "text"
+            if self.kind == "xml":
+                self._parser = XMLPullParser(events=("start", "end"))
+        if self._parser is not None:
+            try:
+                self._parser.feed(data)
+                self._drain()
+            except ParseError as e:
+                self._stop(f"XML: {e}")
+        else:
+            self._text += data
+            self._read_lines(final=False)
+        if self.truncated:
+            self.close()
+
+    def _read_lines(self, final: bool) -> None:
+        *lines, self._text = self._text.split(b"\n")
+        if final:
+            lines.append(self._text)
+            self._text = b""
+        for line in lines:
+            url = line.decode("utf-8", "replace").strip()
+            if url:
+                self.entries.append(SitemapEntry(loc=url))
+
+    def _drain(self) -> None:
+        for event, elem in self._parser.read_events():
+            tag = _local(elem.tag)
+            if event == "start":
+                if self._root is None:
+                    self._root = elem
+                    self.kind = "index" if tag == "sitemapindex" else "urlset" if tag == "urlset" else tag
+                continue
+            if tag not in ("url", "sitemap"):
+                continue
+            fields = {_local(child.tag): (child.text or "").strip() for child in elem}
+            if fields.get("loc"):
+                if tag == "sitemap":
+                    self.nested.append(fields["loc"])
+                else:
+                    self.entries.append(
+                        SitemapEntry(
+                            loc=fields["loc"],
+                            lastmod=fields.get("lastmod"),
+                            changefreq=fields.get("changefreq"),
+                            priority=fields.get("priority"),
+                        )
+                    )
+            self._root.clear()  # entries already read are not needed in the tree
+
+
+def parse_sitemap(content: bytes, max_bytes: int = MAX_SITEMAP_BYTES) -> SitemapStream:
+    """Parse a whole sitemap body (gzip, XML, or plain text) at once."""
+    stream = SitemapStream(max_bytes)
+    stream.feed(content)
+    stream.close()
+    return stream
+
+
+def entry_problems(entry: SitemapEntry) -> dict[str, str]:
+    """Invalid optional fields of an entry, keyed by issue type."""
+    problems = {}
+    if entry.lastmod and not _W3C_DATETIME.match(entry.lastmod):
+        problems["sitemap_invalid_lastmod"] = entry.lastmod
+    if entry.changefreq and entry.changefreq.lower() not in VALID_CHANGEFREQ:
+        problems["sitemap_invalid_changefreq"] = entry.changefreq
+    if entry.priority:
+        try:
+            valid = 0.0 <= float(entry.priority) <= 1.0
+        except ValueError:
+            valid = False
+        if not valid:
+            problems["sitemap_invalid_priority"] = entry.priority
+    return problems
+
+
+async def _fetch_sitemap(client: httpx.AsyncClient, url: str, timeout: float) -> tuple[SitemapFile, SitemapStream]:
+    sitemap = SitemapFile(url=url)
+    stream = SitemapStream()
+    try:
+        async with client.stream("GET", url, timeout=timeout, follow_redirects=True) as response:
+            sitemap.status_code = response.status_code
+            if response.status_code >= 400:
+                sitemap.error = f"HTTP {response.status_code}"
+                return sitemap, stream
+            async for chunk in response.aiter_bytes():
+                stream.feed(chunk)
+                if stream.truncated or stream.error:
+                    break
+    except httpx.HTTPError as e:
+        sitemap.error = type(e).__name__
+        return sitemap, stream
+    stream.close()
+    sitemap.kind = stream.kind if stream.kind in ("urlset", "index", "text") else None
+    sitemap.url_count = len(stream.entries)
+    sitemap.nested = stream.nested
+    sitemap.truncated = stream.truncated
+    if stream.error:
+        sitemap.error = stream.error
+    elif sitemap.kind is None:
+        sitemap.error = f"no <urlset> or <sitemapindex> root (found <{stream.kind}>)" if stream.kind else "empty"
+    return sitemap, stream
+
+
+def _file_issues(sitemap: SitemapFile) -> list[Issue]:
+    issues = []
+    if sitemap.status_code is None or sitemap.status_code >= 400:
+        issues.append(
+            Issue(
+                url=sitemap.url,
+                type="sitemap_fetch_error",
+                severity="medium",
+                **msg("sitemap.sitemap_fetch_error", sitemap.error),
+            )
+        )
+        return issues
+    if sitemap.error:
+        read = fragment("sitemap.read_before_error", sitemap.url_count) if sitemap.url_count else ""
+        issues.append(
+            Issue(
+                url=sitemap.url,
+                type="sitemap_malformed",
+                severity="high",
+                **msg("sitemap.sitemap_malformed", sitemap.error, read),
+            )
+        )
+    if sitemap.url_count > MAX_SITEMAP_URLS or sitemap.truncated:
+        issues.append(
+            Issue(
+                url=sitemap.url,
+                type="sitemap_too_many_urls",
+                severity="high",
+                **(
+                    msg("sitemap.truncated", MAX_SITEMAP_BYTES // (1024 * 1024))
+                    if sitemap.truncated
+                    else msg("sitemap.sitemap_too_many_urls", sitemap.url_count, MAX_SITEMAP_URLS)
+                ),
+            )
+        )
+    return issues
+
+
+def _value_issues(sitemap_url: str, entries: list[SitemapEntry], max_listed: int) -> list[Issue]:
+    bad: dict[str, list[dict]] = {}
+    relative = []
+    for entry in entries:
+        if not entry.loc.startswith(("http://", "https://")):
+            relative.append(entry.loc)
+        for issue_type, value in entry_problems(entry).items():
+            bad.setdefault(issue_type, []).append(fragment("sitemap.entry", entry.loc, value))
+    issues = []
+    if relative:
+        issues.append(
+            Issue(
+                url=sitemap_url,
+                type="sitemap_relative_url",
+                severity="high",
+                **msg("sitemap.sitemap_relative_url", len(relative), listing(relative, max_listed)),
+            )
+        )
+    severities = {
+        "sitemap_invalid_lastmod": "low",
+        "sitemap_invalid_changefreq": "low",
+        "sitemap_invalid_priority": "medium",
+    }
+    for issue_type, severity in severities.items():
+        if issue_type in bad:
+            urls = bad[issue_type]
+            issues.append(
+                Issue(
+                    url=sitemap_url,
+                    type=issue_type,
+                    severity=severity,
+                    **msg(f"sitemap.{issue_type}", len(urls), listing(urls, max_listed)),
+                )
+            )
+    return issues
+
+
+async def _probe(client: httpx.AsyncClient, urls: list[str], options: SitemapOptions) -> list[PageStatus]:
+    semaphore = asyncio.Semaphore(options.concurrency)
+
+    async def probe(url: str) -> PageStatus:
+        async with semaphore:
+            return await fetch_status(client, url, options.timeout)
+
+    return list(await asyncio.gather(*(probe(u) for u in urls)))
+
+
+def coverage_issues(
+    site_url: str,
+    entries: list[SitemapEntry],
+    crawled_pages: list[str],
+    probes: list[PageStatus],
+    max_listed: int = 5,
+) -> list[Issue]:
+    """
+    Compare sitemap URLs with crawled pages.
+
+    Listed URLs that return an error or redirect are orphaned entries;
+    crawled pages that no sitemap lists are reported as missing.
+    """
+    issues = []
+    broken = [
+        fragment("sitemap.entry", p.url, p.status_code or p.error)
+        for p in probes
+        if p.status_code is None or p.status_code >= 400
+    ]
+    redirected = [
+        fragment("sitemap.redirect", p.url, p.final_url)
+        for p in probes
+        if p.ok and p.final_url and _normalize(p.final_url) != _normalize(p.url)
+    ]
+    if broken:
+        issues.append(
+            Issue(
+                url=site_url,
+                type="sitemap_orphaned_url",
+                severity="medium",
+                **msg("sitemap.sitemap_orphaned_url", len(broken), listing(broken, max_listed)),
+            )
+        )
+    if redirected:
+        issues.append(
+            Issue(
+                url=site_url,
+                type="sitemap_redirected_url",
+                severity="low",
+                **msg(
+                    "sitemap.sitemap_redirected_url",
+                    len(redirected),
+                    listing(redirected, max_listed),
+                ),
+            )
+        )
+    listed = {_normalize(e.loc) for e in entries}
+    missing = [url for url in dict.fromkeys(crawled_pages) if _normalize(url) not in listed]
+    if entries and missing:
+        issues.append(
+            Issue(
+                url=site_url,
+                type="page_missing_from_sitemap",
+                severity="low",
+                **msg(
+                    "sitemap.page_missing_from_sitemap", len(missing), listing(missing, max_listed)
+                ),
+            )
+        )
+    return issues
+
+
+async def analyze(
+    base_url: str,
+    client: httpx.AsyncClient,
+    crawled_pages: list[str],
+    options: SitemapOptions | None = None,
+) -> SitemapReport:
+    """
+    Discover, parse, and check a site's sitemaps.
+
+    Sitemaps come from robots.txt Sitemap lines, or /sitemap.xml when there
+    are none. Index files are followed up to options.max_depth levels.
+    Malformed files still contribute the entries read before the error.
+    Up to options.max_probes listed URLs the crawl did not reach are
+    fetched to find entries that 404 or redirect.
+
+    Args:
+        base_url: Any URL of the site
+        client: HTTP client to use
+        crawled_pages: URLs the crawler fetched successfully
+        options: Depth, probe, and reporting limits
+
+    Returns:
+        SitemapReport with every file fetched, all entries, and findings
+    """
+    options = options or SitemapOptions()
+    report = SitemapReport()
+    robots = await analyze_robots(base_url, client)
+    queue = [(url, 0) for url in robots.rules.sitemaps] or [(urljoin(base_url, "/sitemap.xml"), 0)]
+    seen: set[str] = set()
+
+    while queue:
+        url, depth = queue.pop(0)
+        if url in seen:
+            continue
+        seen.add(url)
+        sitemap, stream = await _fetch_sitemap(client, url, options.timeout)
+        report.sitemaps.append(sitemap)
+        report.entries.extend(stream.entries)
+        report.issues.extend(_file_issues(sitemap))
+        report.issues.extend(_value_issues(url, stream.entries, options.max_listed))
+        if sitemap.nested:
+            if depth < options.max_depth:
+                queue.extend((nested, depth + 1) for nested in sitemap.nested)
+            else:
+                logger.warning(f"Not following {len(sitemap.nested)} sitemaps nested deeper than {options.max_depth}")
+
+    site_url = urljoin(base_url, "/")
+    if not report.entries:
+        default = report.sitemaps[0]
+        if not robots.rules.sitemaps and default.status_code is not None and default.status_code >= 400:
+            report.issues = [
+                Issue(
+                    url=site_url + "sitemap.xml",
+                    type="sitemap_missing",
+                    severity="low",
+                    **msg("sitemap.sitemap_missing"),
+                )
+            ]
+        return report
+
+    crawled = {_normalize(u) for u in crawled_pages}
+    uncrawled = list(
+        dict.fromkeys(e.loc for e in report.entries if e.loc.startswith(("http://", "https://")) and _normalize(e.loc) not in crawled)
+    )
+    report.probes = await _probe(client, uncrawled[: options.max_probes], options)
+    report.issues.extend(coverage_issues(site_url, report.entries, crawled_pages, report.probes, options.max_listed))
+    logger.info(f"Read {len(report.entries)} URLs from {len(report.sitemaps)} sitemap(s)")
+    return report
@@ -0,0 +1,307 @@
+"""
+Open Graph and Twitter Card checks for social link previews.
+
+analyze looks at the tags only; analyze_with_images additionally fetches
+the preview image (a bounded number of bytes, a bounded number of images)
+to confirm it loads and to read its dimensions. Where the fix can be
+derived from the page, the finding includes a ready-to-paste tag.
+"""
+from __future__ import annotations
+
+import asyncio
+import struct
+from html import escape
+from urllib.parse import urljoin
+
+import httpx
+from bs4 import BeautifulSoup
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import fragment, msg
+from ...utils import urlnorm
+
+# Tracking parameters in og:url are a mismatch of their own, so they are kept
+_URL_OPTIONS = urlnorm.NormalizeOptions(strip_params=())
+
+TWITTER_CARD_TYPES = frozenset({"summary", "summary_large_image", "app", "player"})
+# Tags that must appear once; og:image may repeat to offer alternatives
+SINGLE_TAGS = ("og:title", "og:description", "og:url", "og:type", "twitter:card")
+OG_TITLE_MAX_LENGTH = 95
+OG_DESCRIPTION_MAX_LENGTH = 200
+MIN_IMAGE_SIZE = (200, 200)  # smallest image Facebook renders a preview for
+LARGE_IMAGE_MIN_SIZE = (300, 157)  # summary_large_image minimum
+# Acceptable width / height ranges per card type
+ASPECT_RATIOS = {
+    "summary": (0.8, 1.25),  # square
+    "summary_large_image": (1.5, 2.2),  # about 1.91:1 to 2:1
+}
+
+
+class SocialOptions(BaseModel):
+    """Limits for image probing."""
+
+    max_images: int = Field(default=2, ge=0)  # og:image and twitter:image at most
+    max_image_bytes: int = Field(default=256 * 1024, ge=1024)  # read this much to find the dimensions
+    timeout: float = 5.0
+
+
+class ImageProbe(BaseModel):
+    """What fetching a preview image found."""
+
+    url: str
+    status_code: int | None = None
+    content_type: str | None = None
+    width: int | None = None
+    height: int | None = None
+    error: str | None = None
+
+    @property
+    def ok(self) -> bool:
+        return self.status_code is not None and 200 <= self.status_code < 300
+
+
+def _tag(key: str, content: str) -> str:
+    """A ready-to-paste meta tag (og:* use property=, twitter:* use name=)."""
+    attr = "name" if key.startswith("twitter:") else "property"
+    return f'<meta {attr}="{key}" content="{escape(content, quote=True)}">'
+
+
+def _normalize(url: str) -> str:
+    return urlnorm.normalize(url, _URL_OPTIONS)
+
+
+def social_tags(html: str) -> dict[str, list[str]]:
+    """All og:* and twitter:* meta values, keyed by lowercase property or name."""
+    soup = BeautifulSoup(html, "lxml")
+    tags: dict[str, list[str]] = {}
+    for meta in soup.find_all("meta"):
+        key = (meta.get("property") or meta.get("name") or "").strip().lower()
+        if key.startswith(("og:", "twitter:")):
+            tags.setdefault(key, []).append((meta.get("content") or "").strip())
+    return tags
+
+
+def _page_fallbacks(html: str, url: str) -> dict[str, str]:
+    """Values from the rest of the page that a missing social tag can copy."""
+    soup = BeautifulSoup(html, "lxml")
+    fallbacks = {}
+    title = soup.find("title")
+    if title and title.get_text(strip=True):
+        fallbacks["og:title"] = title.get_text(strip=True)
+    description = soup.find("meta", attrs={"name": "description"})
+    if description and (description.get("content") or "").strip():
+        fallbacks["og:description"] = description.get("content").strip()
+    canonical = soup.find("link", rel="canonical")
+    if canonical and (canonical.get("href") or "").strip():
+        fallbacks["og:url"] = urljoin(url, canonical.get("href").strip())
+    return fallbacks
+
+
+def _first(tags: dict[str, list[str]], key: str) -> str:
+    return next((v for v in tags.get(key, []) if v), "")
+
+
+def analyze(html: str, url: str, images: list[ImageProbe] | None = None) -> list[Issue]:
+    """
+    Check Open Graph and Twitter Card tags.
+
+    Args:
+        html: Page HTML
+        url: Page URL
+        images: Probe results from probe_image, if the images were fetched
+
+    Returns:
+        Social findings for the page
+    """
+    tags = social_tags(html)
+    fallbacks = _page_fallbacks(html, url)
+    issues = []
+
+    def add(issue_type: str, severity: str, detail: dict, fix: str | None = None) -> None:
+        if fix:
+            text = msg("social.suggested", detail, fix)
+        else:
+            text = msg(detail["message"], *detail["args"])
+        issues.append(Issue(url=url, type=issue_type, severity=severity, **text))
+
+    required = (("og:title", "medium"), ("og:description", "low"), ("og:image", "medium"), ("og:url", "low"))
+    for key, severity in required:
+        if not _first(tags, key):
+            fallback = fallbacks.get(key) or (url if key == "og:url" else None)
+            fix = _tag(key, fallback) if fallback else None
+            add(f"{key.replace(':', '_')}_missing", severity, fragment("social.no_tag", key), fix)
+
+    for key in SINGLE_TAGS:
+        values = tags.get(key, [])
+        if len(values) > 1:
+            shown = ", ".join(f'"{v}"' for v in dict.fromkeys(values))
+            detail = fragment("social.duplicated", key, len(values), shown)
+            issue_type = "og_tag_duplicated" if key.startswith("og:") else "twitter_tag_duplicated"
+            add(issue_type, "low", detail)
+
+    title = _first(tags, "og:title")
+    if len(title) > OG_TITLE_MAX_LENGTH:
+        detail = fragment("social.too_long", "og:title", len(title), OG_TITLE_MAX_LENGTH)
+        add("og_title_too_long", "low", detail)
+    description = _first(tags, "og:description")
+    if len(description) > OG_DESCRIPTION_MAX_LENGTH:
+        detail = fragment(
+            "social.too_long", "og:description", len(description), OG_DESCRIPTION_MAX_LENGTH
+        )
+        add("og_description_too_long", "low", detail)
+
+    for key in ("og:image", "twitter:image"):
+        value = _first(tags, key)
+        if value and not value.startswith(("http://", "https://")):
+            issue_type = "og_image_not_absolute" if key == "og:image" else "twitter_image_not_absolute"
+            detail = fragment("social.relative", key, value)
+            add(issue_type, "medium", detail, _tag(key, urljoin(url, value)))
+
+    og_url = _first(tags, "og:url")
+    if og_url:
+        expected = fallbacks.get("og:url", url)
+        if not og_url.startswith(("http://", "https://")):
+            detail = fragment("social.relative", "og:url", og_url)
+            add("og_url_not_absolute", "low", detail, _tag("og:url", expected))
+        elif _normalize(og_url) != _normalize(expected):
+            source = "social.canonical" if "og:url" in fallbacks else "social.page_url"
+            detail = fragment("social.og_url_mismatch", og_url, fragment(source), expected)
+            add("og_url_mismatch", "medium", detail, _tag("og:url", expected))
+
+    card = _first(tags, "twitter:card")
+    if not card:
+        suggested = "summary_large_image" if _first(tags, "og:image") else "summary"
+        detail = fragment("social.no_tag", "twitter:card")
+        add("twitter_card_missing", "low", detail, _tag("twitter:card", suggested))
+    elif card.lower() not in TWITTER_CARD_TYPES:
+        detail = fragment("social.twitter_card_invalid", card, sorted(TWITTER_CARD_TYPES))
+        add("twitter_card_invalid", "medium", detail, _tag("twitter:card", "summary_large_image"))
+
+    for probe in images or []:
+        issues.extend(image_issues(url, probe, card.lower() or "summary"))
+    return issues
+
+
+def image_issues(page_url: str, probe: ImageProbe, card: str = "summary") -> list[Issue]:
+    """Findings for a fetched preview image: unreachable, too small, wrong shape for the card."""
+    if not probe.ok:
+        reason = f"HTTP {probe.status_code}" if probe.status_code else probe.error
+        return [
+            Issue(
+                url=page_url,
+                type="og_image_unreachable",
+                severity="medium",
+                **msg("social.og_image_unreachable", probe.url, reason),
+            )
+        ]
+    if probe.content_type and not probe.content_type.startswith("image/"):
+        return [
+            Issue(
+                url=page_url,
+                type="og_image_not_image",
+                severity="medium",
+                **msg("social.og_image_not_image", probe.url, probe.content_type),
+            )
+        ]
+    if not probe.width or not probe.height:
+        return []
+
+    issues = []
+    size = f"{probe.width}x{probe.height}"
+    min_width, min_height = LARGE_IMAGE_MIN_SIZE if card == "summary_large_image" else MIN_IMAGE_SIZE
+    if probe.width < min_width or probe.height < min_height:
+        issues.append(
+            Issue(
+                url=page_url,
+                type="og_image_too_small",
+                severity="low",
+                **msg("social.og_image_too_small", probe.url, size, min_width, min_height, card),
+            )
+        )
+    if card in ASPECT_RATIOS:
+        low, high = ASPECT_RATIOS[card]
+        ratio = probe.width / probe.height
+        if not low <= ratio <= high:
+            issues.append(
+                Issue(
+                    url=page_url,
+                    type="twitter_image_aspect_ratio",
+                    severity="low",
+                    **msg(
+                        "social.twitter_image_aspect_ratio", probe.url, size, ratio, card, low, high
+                    ),
+                )
+            )
+    return issues
+
+
+def image_size(data: bytes) -> tuple[int, int] | None:
+    """Width and height from the start of a PNG, GIF, JPEG, or WebP file."""
+    if data.startswith(b"\x89PNG\r\n\x1a\n") and len(data) >= 24:
+        return struct.unpack(">II", data[16:24])
+    if data[:6] in (b"GIF87a", b"GIF89a") and len(data) >= 10:
+        return struct.unpack("<HH", data[6:10])
+    if data.startswith(b"RIFF") and data[8:12] == b"WEBP" and len(data) >= 30:
+        chunk = data[12:16]
+        if chunk == b"VP8 ":
+            width, height = struct.unpack("<HH", data[26:30])
+            return width & 0x3FFF, height & 0x3FFF
+        if chunk == b"VP8L":
+            bits = int.from_bytes(data[21:25], "little")
+            return (bits & 0x3FFF) + 1, ((bits >> 14) & 0x3FFF) + 1
+        if chunk == b"VP8X":
+            return int.from_bytes(data[24:27], "little") + 1, int.from_bytes(data[27:30], "little") + 1
+    if data.startswith(b"\xff\xd8"):
+        offset = 2
+        while offset + 9 <= len(data):
+            if data[offset] != 0xFF:
+                return None
+            marker = data[offset + 1]
+            length = struct.unpack(">H", data[offset + 2 : offset + 4])[0]
+            if marker in (0xC0, 0xC1, 0xC2, 0xC3, 0xC5, 0xC6, 0xC7, 0xC9, 0xCA, 0xCB, 0xCD, 0xCE, 0xCF):
+                height, width = struct.unpack(">HH", data[offset + 5 : offset + 9])
+                return width, height
+            offset += 2 + length
+    return None
+
+
+async def probe_image(url: str, client: httpx.AsyncClient, options: SocialOptions | None = None) -> ImageProbe:
+    """Fetch the start of an image, enough to confirm it loads and read its size."""
+    options = options or SocialOptions()
+    probe = ImageProbe(url=url)
+    size = None
+    try:
+        async with client.stream("GET", url, timeout=options.timeout, follow_redirects=True) as response:
+            probe.status_code = response.status_code
+            probe.content_type = response.headers.get("content-type", "").split(";")[0].strip() or None
+            if not probe.ok:
+                return probe
+            data = b""
+            async for chunk in response.aiter_bytes():
+                data += chunk
+                size = image_size(data)
+                if size or len(data) >= options.max_image_bytes:
+                    break
+    except httpx.TimeoutException:
+        probe.error = "timeout"
+        return probe
+    except httpx.HTTPError as e:
+        probe.error = type(e).__name__
+        return probe
+    if size:
+        probe.width, probe.height = size
+    return probe
+
+
+async def analyze_with_images(
+    html: str, url: str, client: httpx.AsyncClient, options: SocialOptions | None = None
+) -> list[Issue]:
+    """analyze, after fetching up to options.max_images distinct preview images."""
+    options = options or SocialOptions()
+    tags = social_tags(html)
+    images = list(
+        dict.fromkeys(urljoin(url, v) for v in (_first(tags, "og:image"), _first(tags, "twitter:image")) if v)
+    )[: options.max_images]
+    probes = await asyncio.gather(*(probe_image(image, client, options) for image in images))
+    return analyze(html, url, list(probes))
@@ -0,0 +1,217 @@
+"""
+Soft 404 detection: pages that return 200 but say "not found".
+
+The site's error page is learned by requesting a random path that cannot
+exist; crawled pages whose text closely matches it are probable soft 404s.
+Pages whose title or main heading carries a strong not-found phrase are
+flagged too. Both are heuristics, so findings are medium and include the
+evidence.
+"""
+from __future__ import annotations
+
+import uuid
+from typing import TYPE_CHECKING
+from urllib.parse import urlparse
+
+import httpx
+from bs4 import BeautifulSoup
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import fragment, msg
+from ...utils.http import client as http_client
+from .content import shingles, visible_text, word_count
+
+if TYPE_CHECKING:
+    from ..crawler import FetchedPage
+
+# Phrases that only appear on error pages, lowercase with straight apostrophes
+NOT_FOUND_PHRASES = (
+    # English
+    "page not found",
+    "404 not found",
+    "page does not exist",
+    "page doesn't exist",
+    "page cannot be found",
+    "page could not be found",
+    # Spanish, Portuguese, French, Italian
+    "página no encontrada",
+    "página não encontrada",
+    "page introuvable",
+    "page non trouvée",
+    "pagina non trovata",
+    # German, Dutch
+    "seite nicht gefunden",
+    "seite wurde nicht gefunden",
+    "pagina niet gevonden",
+    # Japanese, Chinese
+    "ページが見つかりません",
+    "页面不存在",
+    "找不到页面",
+)
+
+
+class Soft404Options(BaseModel):
+    """Thresholds for analyze."""
+
+    threshold: float = Field(default=0.8, ge=0.0, le=1.0)  # similarity to the error page flagged
+    shingle_size: int = Field(default=5, ge=1)
+    max_phrase_words: int = Field(default=150, ge=0)  # body text is searched only on short pages
+    timeout: float = 10.0
+
+
+class NotFoundFingerprint(BaseModel):
+    """What a URL that cannot exist returns on one host."""
+
+    url: str  # the probed URL
+    status_code: int | None = None
+    final_url: str | None = None  # where redirects ended
+    title: str = ""
+    shingles: set[int] = Field(default_factory=set, exclude=True)
+    error: str | None = None
+
+
+def _title(soup: BeautifulSoup) -> str:
+    title = soup.find("title")
+    return " ".join(title.get_text().split()) if title else ""
+
+
+def _text(html: str) -> str:
+    """Title and visible text, compared as one."""
+    return f"{_title(BeautifulSoup(html, 'lxml'))} {visible_text(html)}".strip()
+
+
+def similarity(a: set[int], b: set[int]) -> float:
+    """Jaccard similarity of two shingle sets."""
+    if not a or not b:
+        return 0.0
+    return len(a & b) / len(a | b)
+
+
+def not_found_phrase(html: str, max_words: int = 150) -> str | None:
+    """
+    The not-found phrase in a page's title or first heading, if any.
+
+    The visible text is searched as well when it is at most max_words long,
+    so articles that merely mention "page not found" are not matched.
+    """
+    soup = BeautifulSoup(html, "lxml")
+    heading = soup.find(["h1", "h2"])
+    places = [_title(soup), heading.get_text(" ") if heading else ""]
+    text = visible_text(html)
+    if word_count(text) <= max_words:
+        places.append(text)
+    haystack = " ".join(places).lower().replace("’", "'")
+    return next((p for p in NOT_FOUND_PHRASES if p in haystack), None)
+
+
+async def fingerprint(
+    origin: str, client: httpx.AsyncClient, options: Soft404Options | None = None
+) -> NotFoundFingerprint:
+    """
+    Request a random path on a host to learn what its error page looks like.
+
+    Args:
+        origin: Scheme and host, e.g. "https://example.com"
+        client: HTTP client
+        options: Shingle size and timeout
+
+    Returns:
+        NotFoundFingerprint with the status, final URL, title, and text shingles
+    """
+    options = options or Soft404Options()
+    url = f"{origin.rstrip('/')}/{uuid.uuid4().hex}-does-not-exist"
+    result = NotFoundFingerprint(url=url)
+    try:
+        response = await client.get(url, timeout=options.timeout, follow_redirects=True)
+    except httpx.TimeoutException:
+        result.error = "timed out"
+        return result
+    except httpx.HTTPError as e:
+        result.error = f"failed ({type(e).__name__})"
+        return result
+    result.status_code = response.status_code
+    result.final_url = str(response.url)
+    if "html" in response.headers.get("content-type", "html"):
+        result.title = _title(BeautifulSoup(response.text, "lxml"))
+        result.shingles = shingles(_text(response.text), options.shingle_size)
+    return result
+
+
+def _origin(url: str) -> str:
+    parsed = urlparse(url)
+    return f"{parsed.scheme}://{parsed.netloc}"
+
+
+async def analyze(
+    pages: list[FetchedPage],
+    client: httpx.AsyncClient | None = None,
+    options: Soft404Options | None = None,
+) -> list[Issue]:
+    """
+    Flag crawled 200 pages that are probably "not found" pages.
+
+    Each host is probed once with a random path. A host answering that
+    probe with 200 is reported itself. When unknown URLs redirect, the
+    landing page is a real page, so only the phrase check applies.
+
+    Args:
+        pages: Fetched pages (anything with url, html, and status)
+        client: HTTP client (one is created when omitted)
+        options: Similarity threshold, shingle size, and timeout
+
+    Returns:
+        soft_404_catch_all for hosts that answer unknown URLs with 200, and
+        soft_404 for pages matching the error page (with the similarity) or
+        showing a not-found phrase
+    """
+    options = options or Soft404Options()
+    if client is None:
+        async with http_client() as own:
+            return await analyze(pages, own, options)
+
+    candidates = [p for p in pages if 200 <= p.status < 300 and p.html]
+    fingerprints: dict[str, NotFoundFingerprint] = {}
+    for origin in dict.fromkeys(_origin(p.url) for p in candidates):
+        fingerprints[origin] = await fingerprint(origin, client, options)
+
+    issues = []
+    for origin, probe in fingerprints.items():
+        if probe.status_code is not None and 200 <= probe.status_code < 300:
+            redirected = probe.final_url != probe.url
+            landed = fragment("soft404.redirected", probe.final_url) if redirected else ""
+            issues.append(
+                Issue(
+                    url=origin + "/",
+                    type="soft_404_catch_all",
+                    severity="medium",
+                    **msg("soft404.soft_404_catch_all", probe.url, probe.status_code, landed),
+                )
+            )
+
+    for page in candidates:
+        probe = fingerprints[_origin(page.url)]
+        # Unknown URLs that redirect land on a real page (often the homepage), not an error page
+        error_page = probe.shingles if probe.final_url == probe.url else set()
+        score = similarity(shingles(_text(page.html), options.shingle_size), error_page)
+        if score >= options.threshold:
+            issues.append(
+                Issue(
+                    url=page.url,
+                    type="soft_404",
+                    severity="medium",
+                    **msg("soft404.soft_404", page.status, score),
+                )
+            )
+            continue
+        phrase = not_found_phrase(page.html, options.max_phrase_words)
+        if phrase is not None:
+            issues.append(
+                Issue(
+                    url=page.url,
+                    type="soft_404",
+                    severity="medium",
+                    **msg("soft404.soft_404_phrase", page.status, phrase, score),
+                )
+            )
+    return issues
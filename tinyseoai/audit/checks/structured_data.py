@@ -0,0 +1,262 @@
+"""
+JSON-LD structured data validation.
+
+Every <script type="application/ld+json"> block is parsed on its own, so
+one broken block does not hide problems in the others. Nodes are found in
+top-level objects, arrays, @graph containers, and nested properties, and
+checked against STRUCTURED_DATA_RULES. Supporting another schema.org type
+is a new table entry (plus a fixture), not new code.
+"""
+from __future__ import annotations
+
+import json
+from typing import Any
+
+from bs4 import BeautifulSoup
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import fragment, msg
+
+# Per-type rules: "required" properties must be present and non-empty,
+# "recommended" ones are reported at low severity, and each "one_of" group
+# needs at least one property present.
+STRUCTURED_DATA_RULES: dict[str, dict[str, list]] = {
+    "Organization": {"required": ["name", "url"], "recommended": ["logo"]},
+    "Article": {
+        "required": ["headline", "image", "datePublished"],
+        "recommended": ["author", "dateModified"],
+    },
+    "Product": {
+        "required": ["name"],
+        "recommended": ["image", "description"],
+        "one_of": [["offers", "review", "aggregateRating"]],
+    },
+    "Offer": {"required": ["price", "priceCurrency"], "recommended": ["availability"]},
+    "BreadcrumbList": {"required": ["itemListElement"]},
+    "ListItem": {"required": ["position"], "one_of": [["name", "item"]]},
+    "FAQPage": {"required": ["mainEntity"]},
+    "Question": {"required": ["name", "acceptedAnswer"]},
+    "Answer": {"required": ["text"]},
+}
+# Types checked with another type's rules
+TYPE_ALIASES = {
+    "BlogPosting": "Article",
+    "NewsArticle": "Article",
+    "TechArticle": "Article",
+    "Corporation": "Organization",
+    "LocalBusiness": "Organization",
+}
+_SCHEMA_PREFIXES = ("https://schema.org/", "http://schema.org/", "schema:")
+
+
+class TypeRules(BaseModel):
+    """Rules for one schema.org type."""
+
+    required: list[str] = Field(default_factory=list)
+    recommended: list[str] = Field(default_factory=list)
+    one_of: list[list[str]] = Field(default_factory=list)
+
+
+class JsonLdBlock(BaseModel):
+    """One ld+json script block."""
+
+    index: int  # 1-based position among the page's JSON-LD blocks
+    data: Any = None
+    error: str | None = None  # why the block could not be parsed
+
+    @property
+    def nodes(self) -> list[dict]:
+        return list(_nodes(self.data)) if self.error is None else []
+
+
+def rules_for(schema_type: str) -> TypeRules | None:
+    """Rules for a type name (aliases resolved), or None if the type is not checked."""
+    rules = STRUCTURED_DATA_RULES.get(TYPE_ALIASES.get(schema_type, schema_type))
+    return TypeRules(**rules) if rules is not None else None
+
+
+def _type_names(node: dict) -> list[str]:
+    raw = node.get("@type")
+    names = raw if isinstance(raw, list) else [raw] if raw else []
+    result = []
+    for name in names:
+        if isinstance(name, str):
+            for prefix in _SCHEMA_PREFIXES:
+                name = name.removeprefix(prefix)
+            result.append(name)
+    return result
+
+
+def _nodes(data: Any):
+    """Every dict with an @type, depth-first, including @graph members and nested values."""
+    if isinstance(data, list):
+        for item in data:
+            yield from _nodes(item)
+    elif isinstance(data, dict):
+        if "@type" in data:
+            yield data
+        for key, value in data.items():
+            if key != "@context":
+                yield from _nodes(value)
+
+
+def _position(text: str, index: int) -> str:
+    line = text.count("\n", 0, index) + 1
+    column = index - (text.rfind("\n", 0, index) + 1) + 1
+    return f"line {line}, column {column}"
+
+
+def _syntax_problem(text: str) -> tuple[str, int] | None:
+    """First comment or trailing comma outside a string, with its offset."""
+    in_string = escaped = False
+    last_comma = None
+    for i, ch in enumerate(text):
+        if in_string:
+            if escaped:
+                escaped = False
+            elif ch == "\\":
+                escaped = True
+            elif ch == '"':
+                in_string = False
+            continue
+        if ch == '"':
+            in_string = True
+            last_comma = None
+        elif ch == "/" and text[i + 1 : i + 2] in ("/", "*"):
+            return "comment", i
+        elif ch == ",":
+            last_comma = i
+        elif ch in "}]":
+            if last_comma is not None:
+                return "trailing comma", last_comma
+        elif not ch.isspace():
+            last_comma = None
+    return None
+
+
+def parse_block(text: str) -> tuple[Any, str | None]:
+    """
+    Parse one JSON-LD block.
+
+    Returns:
+        (data, None) on success, or (None, error) where the error names the
+        problem and its line and column within the block
+    """
+    try:
+        return json.loads(text), None
+    except json.JSONDecodeError as e:
+        problem = _syntax_problem(text)
+        if problem is not None and problem[1] <= e.pos:
+            kind, index = problem
+            return None, f"{kind} at {_position(text, index)}"
+        return None, f"{e.msg} at line {e.lineno}, column {e.colno}"
+
+
+def extract_json_ld(html: str) -> list[JsonLdBlock]:
+    """All JSON-LD blocks of a page, parsed independently."""
+    soup = BeautifulSoup(html, "lxml")
+    blocks = []
+    for index, script in enumerate(soup.find_all("script", type="application/ld+json"), start=1):
+        text = script.string or script.get_text() or ""
+        data, error = parse_block(text) if text.strip() else (None, "empty block")
+        blocks.append(JsonLdBlock(index=index, data=data, error=error))
+    return blocks
+
+
+def schema_types(blocks: list[JsonLdBlock]) -> list[str]:
+    """Distinct @type values across all blocks, in document order."""
+    return list(dict.fromkeys(name for block in blocks for node in block.nodes for name in _type_names(node)))
+
+
+def _empty(value: Any) -> bool:
+    return value is None or (isinstance(value, str | list | dict) and not value)
+
+
+def node_issues(url: str, block: int, node: dict) -> list[Issue]:
+    """Rule violations for one node, one Issue per type and property."""
+    issues = []
+    for name in _type_names(node):
+        rules = rules_for(name)
+        if rules is None:
+            continue
+        where = fragment("structured_data.where", name, block)
+        for prop in rules.required:
+            if prop not in node:
+                issues.append(
+                    Issue(
+                        url=url,
+                        type="structured_data_missing_property",
+                        severity="high",
+                        **msg("structured_data.missing_required", where, prop),
+                    )
+                )
+            elif _empty(node[prop]):
+                issues.append(
+                    Issue(
+                        url=url,
+                        type="structured_data_empty_property",
+                        severity="high",
+                        **msg("structured_data.structured_data_empty_property", where, prop),
+                    )
+                )
+        for group in rules.one_of:
+            if all(_empty(node.get(prop)) for prop in group):
+                issues.append(
+                    Issue(
+                        url=url,
+                        type="structured_data_missing_property",
+                        severity="high",
+                        **msg("structured_data.missing_one_of", where, [repr(p) for p in group]),
+                    )
+                )
+        for prop in rules.recommended:
+            if _empty(node.get(prop)):
+                issues.append(
+                    Issue(
+                        url=url,
+                        type="structured_data_missing_recommended",
+                        severity="low",
+                        **msg("structured_data.structured_data_missing_recommended", where, prop),
+                    )
+                )
+    return issues
+
+
+def analyze(html: str, url: str) -> list[Issue]:
+    """
+    Validate a page's JSON-LD structured data.
+
+    Args:
+        html: Page HTML
+        url: Page URL
+
+    Returns:
+        Parse errors and rule violations, each naming the block, type, and property
+    """
+    issues = []
+    for block in extract_json_ld(html):
+        if block.error is not None:
+            issues.append(
+                Issue(
+                    url=url,
+                    type="structured_data_invalid_json",
+                    severity="high",
+                    **msg("structured_data.structured_data_invalid_json", block.index, block.error),
+                )
+            )
+            continue
+        top_level = block.data if isinstance(block.data, list) else [block.data]
+        for item in top_level:
+            if isinstance(item, dict) and "@type" not in item and "@graph" not in item:
+                issues.append(
+                    Issue(
+                        url=url,
+                        type="structured_data_missing_type",
+                        severity="medium",
+                        **msg("structured_data.structured_data_missing_type", block.index),
+                    )
+                )
+        for node in block.nodes:
+            issues.extend(node_issues(url, block.index, node))
+    return issues
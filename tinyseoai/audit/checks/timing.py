@@ -0,0 +1,171 @@
+"""
+Response time and time-to-first-byte measurement.
+
+measure sends a few GET requests to one URL and reports the median of each
+timing. The first sample is taken on a fresh connection (DNS, TCP, and TLS
+included); later samples reuse it, as a browser would. Timings come from
+httpx's trace hooks where the transport provides them, so connect_ms is
+None for transports without a network connection (e.g. in tests).
+"""
+from __future__ import annotations
+
+import statistics
+import time
+
+import httpx
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import msg
+from ...utils.http import client as http_client
+
+_COMPRESSED_ENCODINGS = frozenset({"gzip", "br", "deflate", "zstd"})
+
+
+class TimingOptions(BaseModel):
+    """Sampling and thresholds for measure."""
+
+    samples: int = Field(default=3, ge=1)
+    ttfb_warning_ms: float = 800.0
+    ttfb_critical_ms: float = 1800.0
+    timeout: float = 15.0
+
+
+class TimingSample(BaseModel):
+    """One GET request."""
+
+    connect_ms: float | None = None  # DNS + TCP + TLS; None when the connection was reused
+    ttfb_ms: float
+    total_ms: float
+    status_code: int
+    body_bytes: int  # decoded body size
+    transfer_bytes: int  # bytes on the wire
+    content_encoding: str | None = None
+
+
+class TimingReport(BaseModel):
+    """All samples for one URL, with medians."""
+
+    url: str
+    samples: list[TimingSample] = Field(default_factory=list)
+    error: str | None = None
+
+    def _median(self, field: str) -> float | None:
+        values = [getattr(s, field) for s in self.samples if getattr(s, field) is not None]
+        return round(statistics.median(values), 1) if values else None
+
+    @property
+    def ttfb_ms(self) -> float | None:
+        return self._median("ttfb_ms")
+
+    @property
+    def total_ms(self) -> float | None:
+        return self._median("total_ms")
+
+    @property
+    def connect_ms(self) -> float | None:
+        return self._median("connect_ms")
+
+    @property
+    def compressed(self) -> bool:
+        return bool(self.samples) and self.samples[-1].content_encoding in _COMPRESSED_ENCODINGS
+
+    def metrics(self) -> dict:
+        """Medians and sizes for the audit result's meta["performance"] block."""
+        last = self.samples[-1] if self.samples else None
+        return {
+            "url": self.url,
+            "samples": len(self.samples),
+            "ttfb_ms": self.ttfb_ms,
+            "total_ms": self.total_ms,
+            "connect_ms": self.connect_ms,
+            "cold_ttfb_ms": self.samples[0].ttfb_ms if self.samples else None,
+            "body_bytes": last.body_bytes if last else None,
+            "transfer_bytes": last.transfer_bytes if last else None,
+            "content_encoding": last.content_encoding if last else None,
+            "compressed": self.compressed,
+            "error": self.error,
+        }
+
+
+async def _sample(client: httpx.AsyncClient, url: str, timeout: float) -> TimingSample:
+    marks: dict[str, float] = {}
+
+    async def on_event(name: str, info: dict) -> None:
+        marks[name] = time.monotonic()
+
+    started = time.monotonic()
+    async with client.stream("GET", url, timeout=timeout, extensions={"trace": on_event}) as response:
+        first_byte = time.monotonic()
+        body = await response.aread()
+        finished = time.monotonic()
+
+    connect_ms = None
+    connected = marks.get("connection.start_tls.complete", marks.get("connection.connect_tcp.complete"))
+    if connected is not None:
+        connect_ms = round((connected - started) * 1000, 1)
+    return TimingSample(
+        connect_ms=connect_ms,
+        ttfb_ms=round((first_byte - started) * 1000, 1),
+        total_ms=round((finished - started) * 1000, 1),
+        status_code=response.status_code,
+        body_bytes=len(body),
+        transfer_bytes=response.num_bytes_downloaded,
+        content_encoding=response.headers.get("content-encoding", "").strip().lower() or None,
+    )
+
+
+async def measure(
+    url: str,
+    client: httpx.AsyncClient | None = None,
+    options: TimingOptions | None = None,
+) -> TimingReport:
+    """
+    Time several GET requests to one URL.
+
+    Args:
+        url: URL to measure
+        client: HTTP client; without one a fresh client is used, so the first
+            sample pays for DNS, TCP, and TLS (a passed client may already hold
+            a warm connection)
+        options: Sample count, thresholds, and timeout
+
+    Returns:
+        TimingReport; error is set and sampling stops on a transport error
+    """
+    options = options or TimingOptions()
+    report = TimingReport(url=url)
+    owned = client is None
+    client = client or http_client(follow_redirects=True)
+    try:
+        for _ in range(options.samples):
+            try:
+                report.samples.append(await _sample(client, url, options.timeout))
+            except httpx.TimeoutException:
+                report.error = "timeout"
+                break
+            except httpx.HTTPError as e:
+                report.error = type(e).__name__
+                break
+    finally:
+        if owned:
+            await client.aclose()
+    return report
+
+
+def timing_issues(report: TimingReport, options: TimingOptions | None = None) -> list[Issue]:
+    """Findings for slow time to first byte, judged on the median."""
+    options = options or TimingOptions()
+    ttfb = report.ttfb_ms
+    if ttfb is None or ttfb < options.ttfb_warning_ms:
+        return []
+    critical = ttfb >= options.ttfb_critical_ms
+    limit = options.ttfb_critical_ms if critical else options.ttfb_warning_ms
+    return [
+        Issue(
+            url=report.url,
+            type="slow_ttfb",
+            severity="high" if critical else "medium",
+            **msg("timing.slow_ttfb", ttfb, len(report.samples), limit, report.total_ms),
+        )
+    ]
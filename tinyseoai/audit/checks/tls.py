@@ -0,0 +1,317 @@
+"""
+TLS certificate and DNS hygiene for the audited host.
+
+The certificate is read from a handshake of our own rather than through
+httpx, which hides the negotiated protocol and fails outright on a bad
+certificate: one handshake accepts any certificate (so an expired or
+mismatched one can still be inspected, and TLS 1.0/1.1 still negotiated),
+a second verifies the chain against the trust store. DNS covers the apex
+and www names: both should resolve, the apex should not be a CNAME, and an
+apex without an IPv6 address is noted.
+"""
+from __future__ import annotations
+
+import asyncio
+import ipaddress
+import socket
+import ssl
+from datetime import datetime, timezone
+from urllib.parse import urlparse
+
+import certifi
+from cryptography import x509
+from cryptography.x509.oid import ExtensionOID, NameOID
+from pydantic import BaseModel, Field
+
+from ...data.models import Issue
+from ...i18n import msg
+
+LEGACY_PROTOCOLS = ("TLSv1", "TLSv1.1")
+_PROTOCOL_NAMES = {"TLSv1": "TLS 1.0", "TLSv1.1": "TLS 1.1"}
+# OpenSSL's X509_V_ERR_CERT_HAS_EXPIRED; expiry has its own finding
+_EXPIRED = 10
+
+
+class TlsOptions(BaseModel):
+    """Thresholds and connection settings for analyze."""
+
+    warn_days: int = Field(default=21, ge=0)  # certificates expiring sooner are flagged
+    port: int = Field(default=443, ge=1, le=65535)
+    timeout: float = 10.0
+    ca_file: str | None = None  # trust store for the chain; None uses certifi's
+    dns: bool = True  # False skips the DNS lookups
+
+
+class Certificate(BaseModel):
+    """The parts of the leaf certificate this check looks at."""
+
+    subject: str  # common name, or the full subject when there is none
+    issuer: str
+    not_after: datetime
+    names: list[str] = Field(default_factory=list)  # DNS and IP subject alternative names
+
+    @classmethod
+    def from_der(cls, der: bytes) -> Certificate:
+        cert = x509.load_der_x509_certificate(der)
+        try:
+            san = cert.extensions.get_extension_for_oid(ExtensionOID.SUBJECT_ALTERNATIVE_NAME)
+            names = san.value.get_values_for_type(x509.DNSName) + [
+                str(ip) for ip in san.value.get_values_for_type(x509.IPAddress)
+            ]
+        except x509.ExtensionNotFound:
+            names = []
+        return cls(
+            subject=_common_name(cert.subject),
+            issuer=_common_name(cert.issuer),
+            not_after=cert.not_valid_after_utc,
+            names=names,
+        )
+
+
+class Handshake(BaseModel):
+    """What connecting to the host found."""
+
+    host: str
+    protocol: str | None = None  # e.g. "TLSv1.3"; None when the handshake failed
+    certificate: Certificate | None = None
+    chain_error: str | None = None  # why the chain does not verify; None when it does
+    error: str | None = None  # why no handshake completed
+
+
+def _common_name(name: x509.Name) -> str:
+    cn = name.get_attributes_for_oid(NameOID.COMMON_NAME)
+    return str(cn[0].value) if cn else name.rfc4514_string()
+
+
+def days_left(not_after: datetime, now: datetime | None = None) -> int:
+    """Whole days until not_after; negative once it has passed (-1 on the day after)."""
+    now = now or datetime.now(timezone.utc)
+    return int((not_after - now).total_seconds() // 86400)
+
+
+def hostname_matches(host: str, names: list[str]) -> bool:
+    """
+    Whether a certificate naming names covers host (RFC 6125).
+
+    A wildcard stands for exactly one whole left-most label, so *.example.com
+    covers www.example.com but neither example.com nor a.b.example.com. IP
+    addresses match IP entries only.
+    """
+    host = host.rstrip(".").lower()
+    try:
+        ip = ipaddress.ip_address(host)
+    except ValueError:
+        ip = None
+    for name in names:
+        name = name.rstrip(".").lower()
+        if ip is not None:
+            try:
+                if ipaddress.ip_address(name) == ip:
+                    return True
+            except ValueError:
+                pass
+            continue
+        if name == host:
+            return True
+        if name.startswith("*.") and "." in host:
+            label, rest = host.split(".", 1)
+            if label and rest == name[2:] and "." in rest:
+                return True
+    return False
+
+
+def _legacy_context() -> ssl.SSLContext:
+    # Accept any certificate and every protocol, so old servers can be inspected
+    context = ssl.SSLContext(ssl.PROTOCOL_TLS_CLIENT)
+    context.check_hostname = False
+    context.verify_mode = ssl.CERT_NONE
+    context.minimum_version = ssl.TLSVersion.MINIMUM_SUPPORTED
+    context.set_ciphers("ALL:@SECLEVEL=0")
+    return context
+
+
+def _connect(host: str, context: ssl.SSLContext, options: TlsOptions) -> ssl.SSLSocket:
+    sock = socket.create_connection((host, options.port), timeout=options.timeout)
+    try:
+        return context.wrap_socket(sock, server_hostname=host)
+    except BaseException:
+        sock.close()
+        raise
+
+
+def handshake(host: str, options: TlsOptions | None = None) -> Handshake:
+    """
+    Connect to host over TLS and record the protocol, certificate, and chain validity.
+
+    Blocking; analyze runs it in a thread.
+    """
+    options = options or TlsOptions()
+    result = Handshake(host=host)
+    try:
+        with _connect(host, _legacy_context(), options) as tls:
+            result.protocol = tls.version()
+            der = tls.getpeercert(binary_form=True)
+    except (OSError, ssl.SSLError) as e:
+        result.error = str(e) or type(e).__name__
+        return result
+    if der:
+        result.certificate = Certificate.from_der(der)
+
+    verifying = ssl.create_default_context(cafile=options.ca_file or certifi.where())
+    verifying.check_hostname = False  # hostname_matches reports a mismatch separately
+    if result.protocol in LEGACY_PROTOCOLS:
+        # Let the old protocol through here too; tls_legacy_protocol reports it
+        verifying.minimum_version = ssl.TLSVersion.MINIMUM_SUPPORTED
+        verifying.set_ciphers("ALL:@SECLEVEL=0")
+    try:
+        with _connect(host, verifying, options):
+            pass
+    except ssl.SSLCertVerificationError as e:
+        if e.verify_code != _EXPIRED:
+            result.chain_error = e.verify_message or str(e)
+    except (OSError, ssl.SSLError) as e:
+        # The permissive handshake worked, so this is the verifying context refusing the server
+        result.chain_error = str(e) or type(e).__name__
+    return result
+
+
+def tls_issues(
+    result: Handshake, url: str, options: TlsOptions | None = None, now: datetime | None = None
+) -> list[Issue]:
+    """
+    Findings for a handshake, filed under url.
+
+    Returns:
+        tls_handshake_failed when no handshake completed; otherwise
+        tls_certificate_expired or tls_certificate_expiring,
+        tls_hostname_mismatch, tls_chain_invalid, and tls_legacy_protocol
+    """
+    options = options or TlsOptions()
+    issues = []
+
+    def add(issue_type: str, severity: str, *args) -> None:
+        fields = msg(f"tls.{issue_type}", *args)
+        issues.append(Issue(url=url, type=issue_type, severity=severity, **fields))
+
+    if result.error is not None:
+        add("tls_handshake_failed", "high", result.host, result.error)
+        return issues
+
+    cert = result.certificate
+    if cert is not None:
+        days = days_left(cert.not_after, now)
+        expiry = cert.not_after.strftime("%Y-%m-%d")
+        if days < 0:
+            add("tls_certificate_expired", "high", -days, expiry)
+        elif days < options.warn_days:
+            add("tls_certificate_expiring", "medium", days, expiry)
+        if not hostname_matches(result.host, cert.names):
+            add("tls_hostname_mismatch", "high", result.host, ", ".join(cert.names) or cert.subject)
+    if result.chain_error is not None:
+        add("tls_chain_invalid", "high", result.chain_error)
+    if result.protocol in LEGACY_PROTOCOLS:
+        add("tls_legacy_protocol", "medium", _PROTOCOL_NAMES[result.protocol])
+    return issues
+
+
+class Resolver:
+    """DNS lookups through the system resolver; tests substitute their own answers."""
+
+    def addresses(self, host: str, family: socket.AddressFamily) -> list[str]:
+        try:
+            infos = socket.getaddrinfo(host, None, family, socket.SOCK_STREAM)
+        except (OSError, UnicodeError):
+            return []
+        return list(dict.fromkeys(info[4][0] for info in infos))
+
+    def canonical(self, host: str) -> str | None:
+        """The name host is an alias (CNAME) of, or None when it is not an alias."""
+        try:
+            name, _, _ = socket.gethostbyname_ex(host)
+        except (OSError, UnicodeError):
+            return None
+        name = name.rstrip(".").lower()
+        return name if name != host.rstrip(".").lower() else None
+
+
+def apex_and_www(host: str) -> tuple[str, str]:
+    """The bare and www forms of a host name: example.com and www.example.com."""
+    host = host.rstrip(".").lower()
+    apex = host[4:] if host.startswith("www.") else host
+    return apex, f"www.{apex}"
+
+
+def dns_issues(host: str, url: str, resolver: Resolver | None = None) -> list[Issue]:
+    """
+    DNS findings for the apex and www names of host, filed under url.
+
+    IP addresses, single-label hosts (localhost), and hosts deeper than a www
+    name are not looked up; which names are apexes needs the public suffix
+    list, so a host like shop.example.co.uk is treated as its own apex.
+
+    Returns:
+        dns_unresolved for an apex or www name without an address,
+        dns_cname_at_apex, and dns_no_ipv6 (info) for an apex without AAAA
+    """
+    resolver = resolver or Resolver()
+    try:
+        ipaddress.ip_address(host)
+        return []
+    except ValueError:
+        pass
+    if "." not in host.strip("."):
+        return []
+
+    issues = []
+
+    def add(issue_type: str, severity: str, *args) -> None:
+        fields = msg(f"tls.{issue_type}", *args)
+        issues.append(Issue(url=url, type=issue_type, severity=severity, **fields))
+
+    apex, www = apex_and_www(host)
+    ipv4 = {name: resolver.addresses(name, socket.AF_INET) for name in (apex, www)}
+    ipv6 = {name: resolver.addresses(name, socket.AF_INET6) for name in (apex, www)}
+    resolved = {name: bool(ipv4[name] or ipv6[name]) for name in (apex, www)}
+    if any(resolved.values()):
+        for name in (apex, www):
+            if not resolved[name]:
+                other = www if name == apex else apex
+                add("dns_unresolved", "medium", name, other)
+
+    if resolved[apex]:
+        target = resolver.canonical(apex)
+        if target is not None:
+            add("dns_cname_at_apex", "medium", apex, target)
+        if not ipv6[apex]:
+            add("dns_no_ipv6", "info", apex)
+    return issues
+
+
+async def analyze(
+    url: str, options: TlsOptions | None = None, resolver: Resolver | None = None
+) -> list[Issue]:
+    """
+    Check the certificate, protocol, and DNS of the host of url.
+
+    Args:
+        url: Site URL; the TLS part runs only for https URLs
+        options: Expiry warning, port, timeout, trust store, and whether to look up DNS
+        resolver: DNS resolver (the system resolver when omitted)
+
+    Returns:
+        The findings of tls_issues and dns_issues
+    """
+    options = options or TlsOptions()
+    parsed = urlparse(url)
+    host = parsed.hostname
+    if not host:
+        return []
+    issues = []
+    if parsed.scheme == "https":
+        if parsed.port:
+            options = options.model_copy(update={"port": parsed.port})
+        result = await asyncio.to_thread(handshake, host, options)
+        issues.extend(tls_issues(result, url, options))
+    if options.dns:
+        issues.extend(await asyncio.to_thread(dns_issues, host, url, resolver))
+    return issues
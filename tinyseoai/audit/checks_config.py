@@ -0,0 +1,277 @@
+"""
+Per-check thresholds loaded from a checks.toml file.
+
+One table per check id, holding that check's thresholds and an enabled
+flag; keys left out keep their defaults, so ChecksConfig() is exactly the
+built-in behavior. Unknown tables and keys are rejected naming the check
+id and key, so a typo does not silently leave a default in place.
+
+    [meta]
+    title_max = 65
+
+    [timing]
+    ttfb_warning_ms = 600
+
+    [soft404]
+    enabled = false
+"""
+from __future__ import annotations
+
+import json
+import tomllib
+from pathlib import Path
+
+from pydantic import BaseModel, ConfigDict, Field, ValidationError
+
+from ..data.effort import EffortOptions
+from ..exceptions import ConfigError
+from .checks.amp import AmpOptions
+from .checks.anchors import AnchorOptions
+from .checks.caching import CachingOptions
+from .checks.canonicalization import CanonicalizationOptions
+from .checks.compression import CompressionOptions
+from .checks.content import ContentOptions
+from .checks.duplicates import DuplicateOptions
+from .checks.feeds import FeedOptions
+from .checks.icons import IconOptions
+from .checks.indexability import IndexabilityOptions
+from .checks.keywords import KeywordOptions
+from .checks.lang import LangOptions
+from .checks.linkgraph import LinkGraphOptions
+from .checks.meta import MetaThresholds
+from .checks.mobile import MobileOptions
+from .checks.pagination import PaginationOptions
+from .checks.params import ParamOptions
+from .checks.performance import PerformanceOptions
+from .checks.protocol import ProtocolOptions
+from .checks.redirects import RedirectTypeOptions
+from .checks.soft404 import Soft404Options
+from .checks.timing import TimingOptions
+from .checks.tls import TlsOptions
+
+
+class CheckSettings(BaseModel):
+    """A check's table: the enabled flag, plus its options in subclasses."""
+
+    model_config = ConfigDict(extra="forbid")
+
+    enabled: bool = True
+
+
+class MetaSettings(CheckSettings, MetaThresholds):
+    pass
+
+
+class ContentSettings(CheckSettings, ContentOptions):
+    """Thin content word counts, and the boilerplate thresholds."""
+
+
+class MobileSettings(CheckSettings, MobileOptions):
+    pass
+
+
+class PerformanceSettings(CheckSettings, PerformanceOptions):
+    pass
+
+
+class IndexabilitySettings(CheckSettings, IndexabilityOptions):
+    pass
+
+
+class DuplicateMetaSettings(CheckSettings, DuplicateOptions):
+    pass
+
+
+class DuplicateContentSettings(CheckSettings):
+    near_threshold: float = Field(default=0.8, gt=0, le=1)  # similarity reported as near-duplicate
+    max_near_pages: int = Field(default=100, ge=0)  # every pair is compared, so larger crawls skip it
+
+
+class PaginationSettings(CheckSettings, PaginationOptions):
+    pass
+
+
+class ParamSettings(CheckSettings, ParamOptions):
+    pass
+
+
+class LangSettings(CheckSettings, LangOptions):
+    pass
+
+
+class KeywordSettings(CheckSettings, KeywordOptions):
+    pass
+
+
+class TimingSettings(CheckSettings, TimingOptions):
+    pass
+
+
+class IconSettings(CheckSettings, IconOptions):
+    pass
+
+
+class CompressionSettings(CheckSettings, CompressionOptions):
+    pass
+
+
+class CachingSettings(CheckSettings, CachingOptions):
+    pass
+
+
+class Soft404Settings(CheckSettings, Soft404Options):
+    pass
+
+
+class AmpSettings(CheckSettings, AmpOptions):
+    pass
+
+
+class LinkGraphSettings(CheckSettings, LinkGraphOptions):
+    pass
+
+
+class AnchorSettings(CheckSettings, AnchorOptions):
+    pass
+
+
+class FeedSettings(CheckSettings, FeedOptions):
+    pass
+
+
+class TlsSettings(CheckSettings, TlsOptions):
+    pass
+
+
+class ProtocolSettings(CheckSettings, ProtocolOptions):
+    pass
+
+
+class RedirectTypeSettings(CheckSettings, RedirectTypeOptions):
+    pass
+
+
+class CanonicalizationSettings(CheckSettings, CanonicalizationOptions):
+    pass
+
+
+class EffortSettings(CheckSettings, EffortOptions):
+    """Effort overrides by issue type; enabled = false leaves issues without an effort."""
+
+
+class ChecksConfig(BaseModel):
+    """
+    Thresholds and enabled flags by check id.
+
+    meta, content, mobile, performance, and security run on every page
+    during the crawl; the rest are the site-level checks of the registry
+    (see `tinyseoai checks`). indexability and pagination cover both their
+    per-page and site-level parts. effort is not a check: it holds the fix
+    effort overrides applied to every finding.
+    """
+
+    model_config = ConfigDict(extra="forbid")
+
+    meta: MetaSettings = Field(default_factory=MetaSettings)
+    content: ContentSettings = Field(default_factory=ContentSettings)
+    mobile: MobileSettings = Field(default_factory=MobileSettings)
+    performance: PerformanceSettings = Field(default_factory=PerformanceSettings)
+    security: CheckSettings = Field(default_factory=CheckSettings)
+    indexability: IndexabilitySettings = Field(default_factory=IndexabilitySettings)
+    duplicate_meta: DuplicateMetaSettings = Field(default_factory=DuplicateMetaSettings)
+    duplicate_content: DuplicateContentSettings = Field(default_factory=DuplicateContentSettings)
+    boilerplate: CheckSettings = Field(default_factory=CheckSettings)
+    pagination: PaginationSettings = Field(default_factory=PaginationSettings)
+    params: ParamSettings = Field(default_factory=ParamSettings)
+    lang: LangSettings = Field(default_factory=LangSettings)
+    keywords: KeywordSettings = Field(default_factory=KeywordSettings)
+    links: LinkGraphSettings = Field(default_factory=LinkGraphSettings)
+    anchors: AnchorSettings = Field(default_factory=AnchorSettings)
+    timing: TimingSettings = Field(default_factory=TimingSettings)
+    icons: IconSettings = Field(default_factory=IconSettings)
+    compression: CompressionSettings = Field(default_factory=CompressionSettings)
+    caching: CachingSettings = Field(default_factory=CachingSettings)
+    soft404: Soft404Settings = Field(default_factory=Soft404Settings)
+    amp: AmpSettings = Field(default_factory=AmpSettings)
+    feeds: FeedSettings = Field(default_factory=FeedSettings)
+    tls: TlsSettings = Field(default_factory=TlsSettings)
+    protocol: ProtocolSettings = Field(default_factory=ProtocolSettings)
+    redirect_types: RedirectTypeSettings = Field(default_factory=RedirectTypeSettings)
+    canonicalization: CanonicalizationSettings = Field(default_factory=CanonicalizationSettings)
+    effort: EffortSettings = Field(default_factory=EffortSettings)
+
+    def is_enabled(self, check_id: str) -> bool:
+        """False only for a check whose table sets enabled = false; other ids are enabled."""
+        if check_id not in type(self).model_fields:
+            return True
+        return getattr(self, check_id).enabled
+
+    def disabled(self) -> list[str]:
+        """Ids of the checks turned off."""
+        return [name for name in type(self).model_fields if not getattr(self, name).enabled]
+
+    @classmethod
+    def from_toml(cls, text: str) -> ChecksConfig:
+        """
+        Parse a checks config.
+
+        Raises:
+            ConfigError: On invalid TOML, unknown check ids or keys, or bad
+                values, naming the check id and key
+        """
+        try:
+            data = tomllib.loads(text)
+        except tomllib.TOMLDecodeError as e:
+            raise ConfigError(f"Invalid TOML: {e}") from e
+        try:
+            return cls.model_validate(data)
+        except ValidationError as e:
+            raise ConfigError("; ".join(_describe(err) for err in e.errors())) from e
+
+    @classmethod
+    def load(cls, path: Path) -> ChecksConfig:
+        """Load a checks config from a TOML file; raises ConfigError naming the file."""
+        try:
+            return cls.from_toml(path.read_text(encoding="utf-8"))
+        except OSError as e:
+            raise ConfigError(f"Cannot read {path}: {e.strerror or e}") from e
+        except ConfigError as e:
+            raise ConfigError(f"{path}: {e}") from e
+
+    def dumps(self) -> str:
+        """
+        This config as TOML, one table per check with every key.
+
+        Settings TOML cannot express (the language detector, the pagination
+        heuristic) are left out and keep their defaults when loaded back.
+        """
+        lines = [
+            "# Per-check thresholds for tinyseoai (audit-full --checks-config).",
+            "# Keys left out keep these defaults; set enabled = false to turn a check off.",
+        ]
+        for name in type(self).model_fields:
+            lines.extend(["", f"[{name}]"])
+            values = getattr(self, name).model_dump()
+            for key, value in {"enabled": values.pop("enabled"), **values}.items():
+                if isinstance(value, (bool, int, float, str)):
+                    lines.append(f"{key} = {_toml_value(value)}")
+        return "\n".join(lines) + "\n"
+
+
+def _toml_value(value: bool | int | float | str) -> str:
+    if isinstance(value, bool):
+        return "true" if value else "false"
+    if isinstance(value, str):
+        return json.dumps(value)  # a valid TOML basic string
+    return repr(value)
+
+
+def _describe(error: dict) -> str:
+    loc = [str(part) for part in error["loc"]]
+    if error["type"] == "extra_forbidden":
+        if len(loc) == 1:
+            return f"unknown check id [{loc[0]}]"
+        return f"[{loc[0]}] unknown key {'.'.join(loc[1:])!r}"
+    if len(loc) == 1:
+        return f"[{loc[0]}] {error['msg']}"
+    return f"[{loc[0]}] {'.'.join(loc[1:])}: {error['msg']}"
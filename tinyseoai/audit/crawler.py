@@ -1,15 +1,32 @@
 """
 Web crawler functionality for fetching and extracting page data.
+
+Crawl is the breadth-first crawler behind the native checks: it collects a
+FetchedPage per URL and reports progress as typed events on a queue.
 """
 from __future__ import annotations
 
+import asyncio
 import re
-from urllib.parse import urljoin
+import time
+from collections.abc import AsyncIterator
+from typing import Literal
+from urllib.parse import urljoin, urlparse
 
 import httpx
 from bs4 import BeautifulSoup
+from loguru import logger
+from pydantic import BaseModel, Field
+
+from ..progress import Phase, ProgressEmitter
+from ..utils.http import retry_after_seconds
+from ..utils.rate_limiter import RateLimiter
+from ..utils.url import URLValidationError, normalize_url
+from ..utils.urlnorm import DedupSet
+from .robots import RobotsReport, analyze_robots
+from .scope import CrawlOptions
 
-from ..utils.url import normalize_url
+_RETRY_STATUSES = frozenset({429, 503})
 
 
 async def fetch_page(
@@ -75,7 +92,11 @@ def extract_links(html: str, base: str) -> set[str]:
 
         # Convert to absolute URL and normalize
         absolute_url = urljoin(base, href)
-        links.add(normalize_url(absolute_url))
+        try:
+            links.add(normalize_url(absolute_url))
+        except URLValidationError:
+            # mailto:, tel: and other links without a host
+            continue
 
     return links
 
@@ -112,3 +133,304 @@ def extract_meta(html: str) -> tuple[str | None, str | None, bool]:
             noindex = True
 
     return title, meta_desc, noindex
+
+
+class FetchedPage(BaseModel):
+    """One fetched URL. Non-HTML responses are recorded with an empty body."""
+
+    url: str
+    status: int = 200
+    headers: dict[str, str] = Field(default_factory=dict)
+    body: str = ""
+    depth: int = 0
+    content_type: str | None = None
+    final_url: str | None = None  # where redirects ended, when it differs from url
+
+    @property
+    def is_html(self) -> bool:
+        return self.content_type is None or "html" in self.content_type.lower()
+
+    @property
+    def html(self) -> str:
+        return self.body if self.is_html else ""
+
+
+class PolitenessOptions(BaseModel):
+    """How hard Crawl may hit a site."""
+
+    per_host_rps: float = Field(default=2.0, gt=0)  # requests per second to any one host
+    delay: float | None = Field(default=None, ge=0)  # seconds between requests to one host; overrides per_host_rps
+    burst: int = Field(default=1, ge=1)  # requests that may go out back to back before pacing applies
+    respect_robots: bool = True  # False skips robots.txt entirely (Disallow and Crawl-delay)
+    user_agent: str = "TinySEOAI"  # product token matched against robots.txt groups
+    max_crawl_delay: float = Field(default=10.0, ge=0)  # longer robots.txt Crawl-delay values are capped
+    max_retries: int = Field(default=2, ge=0)  # retries after 429/503
+    max_retry_after: float = Field(default=60.0, ge=0)  # longer Retry-After values are capped
+
+    @property
+    def requests_per_second(self) -> float:
+        return 1 / self.delay if self.delay else self.per_host_rps
+
+
+class PageFetched(BaseModel):
+    kind: Literal["page_fetched"] = "page_fetched"
+    page: FetchedPage
+
+
+class PageFailed(BaseModel):
+    kind: Literal["page_failed"] = "page_failed"
+    url: str
+    depth: int
+    error: str
+
+
+class PageSkipped(BaseModel):
+    kind: Literal["page_skipped"] = "page_skipped"
+    url: str
+    depth: int
+    reason: str
+
+
+class Finished(BaseModel):
+    kind: Literal["finished"] = "finished"
+    pages: int
+    failed: int
+    reason: Literal["complete", "max_pages", "max_duration", "cancelled"]
+    duration_seconds: float
+
+
+CrawlEvent = PageFetched | PageFailed | PageSkipped | Finished
+
+
+def _describe(event: CrawlEvent) -> str:
+    """The message of the progress event for a crawl event."""
+    if isinstance(event, PageFetched):
+        return event.page.url
+    if isinstance(event, PageFailed):
+        return f"{event.url} failed: {event.error}"
+    if isinstance(event, PageSkipped):
+        return f"{event.url} skipped: {event.reason}"
+    return f"Crawl finished ({event.reason}): {event.pages} pages, {event.failed} failed"
+
+
+class Crawl:
+    """
+    Breadth-first crawl of one site.
+
+    URLs are deduplicated after normalization and kept within scope (host,
+    depth, include/exclude). max_pages caps fetch attempts, failed ones
+    included. Requests are paced per host, robots.txt of each host is
+    honored (Disallow, and Crawl-delay up to a ceiling), and 429/503
+    responses are retried after their Retry-After. Every URL produces a
+    PageFetched, PageFailed, or PageSkipped (robots.txt) event on events,
+    followed by one Finished event. Extra start URLs (e.g. a sitemap's) are
+    queued after the seed as one hop from it.
+
+    Usage:
+        crawl = Crawl("https://example.com", client, CrawlOptions(max_pages=20))
+        async for event in crawl.stream():
+            ...
+    """
+
+    def __init__(
+        self,
+        seed_url: str,
+        client: httpx.AsyncClient,
+        options: CrawlOptions | None = None,
+        concurrency: int = 4,
+        max_duration: float | None = None,
+        timeout: float = 10.0,
+        politeness: PolitenessOptions | None = None,
+        progress: ProgressEmitter | None = None,
+        extra_urls: list[str] | None = None,
+    ):
+        """
+        Initialize a crawl.
+
+        Args:
+            seed_url: Start URL (depth 0)
+            client: HTTP client shared by all requests
+            options: Page/depth limits and URL patterns
+            concurrency: Requests in flight at once
+            max_duration: Seconds after which no new requests are started
+            timeout: Per-request timeout in seconds
+            politeness: Per-host pacing, robots.txt, and retry settings
+            progress: Receives a crawling event for every event on events
+            extra_urls: More start URLs, at depth 1, kept to the same scope as links
+        """
+        self.seed_url = normalize_url(seed_url)
+        self.client = client
+        self.options = options or CrawlOptions()
+        self.concurrency = max(1, concurrency)
+        self.max_duration = max_duration
+        self.timeout = timeout
+        self.politeness = politeness or PolitenessOptions()
+        self.progress = progress or ProgressEmitter()
+        self.extra_urls = extra_urls or []
+        self.events: asyncio.Queue[CrawlEvent] = asyncio.Queue()
+        self.pages: list[FetchedPage] = []
+        self.failed: list[PageFailed] = []
+        self.skipped: list[PageSkipped] = []
+        self._host = urlparse(self.seed_url).netloc
+        self._seen = DedupSet()  # /a#top and /b/../a are the same page
+        self._started = 0
+        self._stop_reason: str | None = None
+        self._cancelled = asyncio.Event()
+        self._limiters: dict[str, RateLimiter] = {}
+        self._robots: dict[str, asyncio.Task[RobotsReport]] = {}
+
+    def cancel(self) -> None:
+        """Stop starting new requests; in-flight requests finish and Finished is emitted."""
+        self._cancelled.set()
+
+    async def run(self) -> list[FetchedPage]:
+        """
+        Crawl until the frontier is empty, a limit is hit, or the crawl is cancelled.
+
+        Returns:
+            Fetched pages in the order they completed
+        """
+        started = time.monotonic()
+        queue: asyncio.Queue[tuple[str, int]] = asyncio.Queue()
+        self._seen.add(self.seed_url)
+        queue.put_nowait((self.seed_url, 0))
+        for url in self.extra_urls:
+            if urlparse(url).scheme not in ("http", "https") or not self.options.allows(url, self._host, 1):
+                continue
+            if self._seen.add(url):
+                queue.put_nowait((url, 1))
+        workers = [asyncio.create_task(self._worker(queue, started)) for _ in range(self.concurrency)]
+        try:
+            await queue.join()
+        finally:
+            for worker in workers:
+                worker.cancel()
+            await asyncio.gather(*workers, return_exceptions=True)
+            await self._publish(
+                Finished(
+                    pages=len(self.pages),
+                    failed=len(self.failed),
+                    reason=self._stop_reason or "complete",
+                    duration_seconds=round(time.monotonic() - started, 2),
+                )
+            )
+        return self.pages
+
+    async def stream(self) -> AsyncIterator[CrawlEvent]:
+        """Run the crawl in the background and yield its events, ending with Finished."""
+        task = asyncio.create_task(self.run())
+        try:
+            while True:
+                event = await self.events.get()
+                yield event
+                if isinstance(event, Finished):
+                    break
+        finally:
+            if not task.done():
+                self.cancel()
+            await asyncio.gather(task, return_exceptions=True)
+
+    def _should_stop(self, started: float) -> bool:
+        if self._cancelled.is_set():
+            self._stop_reason = "cancelled"
+        elif self._started >= self.options.max_pages:
+            self._stop_reason = self._stop_reason or "max_pages"
+        elif self.max_duration is not None and time.monotonic() - started >= self.max_duration:
+            self._stop_reason = "max_duration"
+        return self._stop_reason is not None
+
+    async def _worker(self, queue: asyncio.Queue[tuple[str, int]], started: float) -> None:
+        while True:
+            url, depth = await queue.get()
+            try:
+                if self._should_stop(started):
+                    continue  # drain the frontier
+                if not await self._allowed(url):
+                    event = PageSkipped(url=url, depth=depth, reason="disallowed by robots.txt")
+                    self.skipped.append(event)
+                    await self._publish(event)
+                    continue
+                # No await between the check and the count, so max_pages is exact
+                if self._should_stop(started):
+                    continue
+                self._started += 1
+                page = await self._fetch(url, depth)
+                if page is None:
+                    continue
+                self.pages.append(page)
+                await self._publish(PageFetched(page=page))
+                if page.html:
+                    for link in self._links(page):
+                        if self._seen.add(link):
+                            queue.put_nowait((link, depth + 1))
+            finally:
+                queue.task_done()
+
+    async def _publish(self, event: CrawlEvent) -> None:
+        await self.events.put(event)
+        done = len(self.pages) + len(self.failed)  # fetch attempts, which max_pages caps
+        self.progress(Phase.CRAWLING, _describe(event), done=done, total=self.options.max_pages)
+
+    def _limiter(self, host: str) -> RateLimiter:
+        if host not in self._limiters:
+            self._limiters[host] = RateLimiter(self.politeness.requests_per_second, burst=self.politeness.burst)
+        return self._limiters[host]
+
+    async def _allowed(self, url: str) -> bool:
+        """Check robots.txt of the URL's host, fetching it once per host."""
+        if not self.politeness.respect_robots:
+            return True
+        host = urlparse(url).netloc.lower()
+        if host not in self._robots:
+            self._robots[host] = asyncio.create_task(analyze_robots(url, self.client))
+        report = await self._robots[host]
+        group = report.rules.group_for(self.politeness.user_agent) if report.found else None
+        if group is not None and group.crawl_delay:
+            self._limiter(host).set_crawl_delay(min(group.crawl_delay, self.politeness.max_crawl_delay))
+        return report.can_fetch(url, self.politeness.user_agent)
+
+    async def _fetch(self, url: str, depth: int) -> FetchedPage | None:
+        limiter = self._limiter(urlparse(url).netloc.lower())
+        for attempt in range(self.politeness.max_retries + 1):
+            await limiter.wait()
+            try:
+                async with self.client.stream("GET", url, timeout=self.timeout, follow_redirects=True) as response:
+                    if response.status_code in _RETRY_STATUSES and attempt < self.politeness.max_retries:
+                        wait = retry_after_seconds(response.headers.get("retry-after"))
+                        limiter.defer(min(wait if wait is not None else 2**attempt, self.politeness.max_retry_after))
+                        continue
+                    content_type = response.headers.get("content-type")
+                    page = FetchedPage(
+                        url=url,
+                        status=response.status_code,
+                        headers=dict(response.headers),
+                        depth=depth,
+                        content_type=content_type,
+                    )
+                    if page.is_html:
+                        await response.aread()
+                        page.body = response.text
+                    if str(response.url) != url:
+                        page.final_url = str(response.url)
+                    return page
+            except httpx.TimeoutException:
+                return await self._failed(url, depth, "timeout")
+            except httpx.HTTPError as e:
+                logger.debug(f"HTTP error fetching {url}: {e}")
+                return await self._failed(url, depth, type(e).__name__)
+
+    async def _failed(self, url: str, depth: int, error: str) -> None:
+        event = PageFailed(url=url, depth=depth, error=error)
+        self.failed.append(event)
+        await self._publish(event)
+        return None
+
+    def _links(self, page: FetchedPage) -> list[str]:
+        links = []
+        # Links on a redirected page are relative to where it ended up
+        for link in sorted(extract_links(page.html, page.final_url or page.url)):
+            if link in self._seen or urlparse(link).scheme not in ("http", "https"):
+                continue
+            if self.options.allows(link, self._host, page.depth + 1):
+                links.append(link)
+        return links
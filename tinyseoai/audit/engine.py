@@ -3,7 +3,7 @@ Core SEO audit engine that coordinates crawling and checks.
 """
 from __future__ import annotations
 
-from collections import deque
+import time
 from datetime import datetime
 from urllib.parse import urlparse
 
@@ -11,15 +11,18 @@ import httpx
 from bs4 import BeautifulSoup
 
 from ..data.models import AuditResult, Issue
-from ..utils.url import normalize_url, same_host
-from .crawler import extract_links, extract_meta, fetch_page
+from ..data.score_model import compute as compute_score
+from ..i18n import Locale, localize, msg
+from ..utils.http import HttpOptions, attempts_for, flaky_urls, tls_issues
+from ..utils.http import client as http_client
+from ..utils.url import normalize_url
+from .checks.duplicates import PageMeta
+from .checks.duplicates import analyze as analyze_duplicates
+from .crawler import Crawl, PageFailed, PageFetched, PolitenessOptions, extract_links, extract_meta
+from .scope import CrawlOptions
 
 # Constants
 DEFAULT_MAX_PAGES = 50
-USER_AGENT = (
-    "TinySEOAI/0.1 (+https://tinyseoai.com; cli) "
-    "python-httpx"
-)
 
 
 class Page:
@@ -42,76 +45,87 @@ class Page:
         self.links = links if links is not None else set()
 
 
-async def audit_site(seed_url: str, max_pages: int = DEFAULT_MAX_PAGES) -> AuditResult:
+async def audit_site(
+    seed_url: str,
+    max_pages: int = DEFAULT_MAX_PAGES,
+    options: CrawlOptions | None = None,
+    http: HttpOptions | None = None,
+    locale: Locale = Locale.EN,
+    politeness: PolitenessOptions | None = None,
+) -> AuditResult:
     """
     Main audit engine that crawls a website and performs SEO checks.
 
     Args:
         seed_url: The starting URL to audit
-        max_pages: Maximum number of pages to crawl
+        max_pages: Maximum number of pages to crawl (ignored when options are given)
+        options: Crawl scope (depth, include/exclude patterns, subdomains)
+        http: User agent, contact, TLS, and credential settings;
+            verify_tls=False is flagged in the result
+        locale: Language of the findings' details (recorded in meta["locale"])
+        politeness: Per-host pacing, robots.txt, and Retry-After handling of the
+            crawl (the defaults, matching robots.txt against the audit's
+            User-Agent, when omitted)
 
     Returns:
         AuditResult containing all findings and metadata
     """
+    started = time.monotonic()
     seed_url = normalize_url(seed_url)
     origin = urlparse(seed_url)
     host = origin.netloc
+    if options is not None:
+        max_pages = options.max_pages
+    http = (http or HttpOptions()).for_site(seed_url)
 
-    headers = {
-        "User-Agent": USER_AGENT,
-        "Accept": "text/html,application/xhtml+xml;q=0.9,*/*;q=0.8"
-    }
+    headers = {"Accept": "text/html,application/xhtml+xml;q=0.9,*/*;q=0.8"}
 
-    visited: set[str] = set()
-    to_visit: deque[str] = deque([seed_url])
+    scope = options or CrawlOptions(max_pages=max_pages)
+    politeness = politeness or PolitenessOptions(user_agent=http.agent)
     pages: list[Page] = []
-    issues: list[Issue] = []
-
-    # --- robots.txt & sitemap checks (best-effort) ---
-    # BUGFIX: Use specific exception handling (See: BUGFIXES.md #4)
     site_root = f"{origin.scheme}://{origin.netloc}"
-    try:
-        rob = await httpx.AsyncClient(headers=headers, http2=True).get(f"{site_root}/robots.txt", timeout=10.0)
-        if rob.status_code >= 400:
-            issues.append(Issue(url=site_root + "/robots.txt", type="robots_missing", severity="low"))
-        else:
-            # parse simple "Sitemap:" lines
-            text = rob.text.lower()
-            has_sitemap_hint = "sitemap:" in text
-            if not has_sitemap_hint:
-                # also check direct /sitemap.xml
-                sm = await httpx.AsyncClient(headers=headers, http2=True).get(f"{site_root}/sitemap.xml", timeout=10.0, follow_redirects=True)
-                if sm.status_code >= 400:
-                    issues.append(Issue(url=site_root + "/sitemap.xml", type="sitemap_missing", severity="low"))
-    except (httpx.HTTPError, httpx.TimeoutException, OSError) as e:
-        # Network errors are expected for some sites
-        issues.append(Issue(url=site_root + "/robots.txt", type="robots_check_error", severity="low", detail=str(e)[:100]))
-
-    async with httpx.AsyncClient(headers=headers, http2=True) as client:
-        while to_visit and len(pages) < max_pages:
-            url = to_visit.popleft()
-            if url in visited:
-                continue
-            visited.add(url)
+    issues: list[Issue] = tls_issues(site_root, http)
 
-            # Fetch the page
-            resp = await fetch_page(client, url)
-            if resp is None:
+    async with http_client(http, headers=headers) as client:
+        # --- robots.txt & sitemap checks (best-effort) ---
+        # BUGFIX: Use specific exception handling (See: BUGFIXES.md #4)
+        try:
+            rob = await client.get(f"{site_root}/robots.txt", timeout=10.0)
+            if rob.status_code >= 400:
+                issues.append(Issue(url=site_root + "/robots.txt", type="robots_missing", severity="low"))
+            else:
+                # parse simple "Sitemap:" lines
+                text = rob.text.lower()
+                has_sitemap_hint = "sitemap:" in text
+                if not has_sitemap_hint:
+                    # also check direct /sitemap.xml
+                    sm = await client.get(f"{site_root}/sitemap.xml", timeout=10.0, follow_redirects=True)
+                    if sm.status_code >= 400:
+                        issues.append(Issue(url=site_root + "/sitemap.xml", type="sitemap_missing", severity="low"))
+        except (httpx.HTTPError, httpx.TimeoutException, OSError) as e:
+            # Network errors are expected for some sites
+            issues.append(Issue(url=site_root + "/robots.txt", type="robots_check_error", severity="low", detail=str(e)[:100]))
+
+        crawl = Crawl(seed_url, client, scope, politeness=politeness)
+        async for event in crawl.stream():
+            if isinstance(event, PageFailed):
                 issues.append(
-                    Issue(url=url, type="fetch_error", severity="high", detail="Request failed")
+                    Issue(url=event.url, type="fetch_error", severity="high", **msg("crawl.fetch_error"))
                 )
                 continue
+            if not isinstance(event, PageFetched):
+                continue
 
-            status = resp.status_code
-            html = resp.text if resp.headers.get("content-type", "").startswith("text/html") else ""
+            url = event.page.url
+            status = event.page.status
+            html = event.page.html
 
             page = Page(url=url, status=status, links=set())
 
             # Check for HTTP errors
             if status >= 400:
-                issues.append(
-                    Issue(url=url, type="http_error", severity="high", detail=f"Status {status}")
-                )
+                fields = msg("crawl.http_error", status)
+                issues.append(Issue(url=url, type="http_error", severity="high", **fields))
             else:
                 # Extract metadata
                 title, meta_desc, noindex = extract_meta(html)
@@ -135,17 +149,10 @@ async def audit_site(seed_url: str, max_pages: int = DEFAULT_MAX_PAGES) -> Audit
                 issues.extend(_check_meta_description(url, meta_desc))
                 issues.extend(_check_noindex(url, noindex))
 
-                # Extract and queue internal links
-                # BUGFIX: Cap queue size to prevent unbounded memory growth
-                # See: BUGFIXES.md #6
-                links = extract_links(html, url)
-                page.links = set()
-                for link in links:
-                    if same_host(link, host):
+                # In-scope links of the page (the crawl follows them itself)
+                for link in extract_links(html, event.page.final_url or url):
+                    if scope.allows(link, host, event.page.depth + 1):
                         page.links.add(link)
-                        # Only add to queue if we haven't visited and queue isn't full
-                        if link not in visited and len(to_visit) < max_pages:
-                            to_visit.append(link)
 
                 # Quick broken-link check for internal links (sampled)
                 broken_link_issues = await _check_broken_links(client, url, list(page.links)[:10])
@@ -154,18 +161,31 @@ async def audit_site(seed_url: str, max_pages: int = DEFAULT_MAX_PAGES) -> Audit
             pages.append(page)
 
     # Post-crawl checks
-    issues.extend(_check_duplicate_titles(pages))
-    issues.extend(_check_duplicate_meta_descriptions(pages))
+    issues.extend(
+        analyze_duplicates([PageMeta(url=p.url, title=p.title, description=p.meta_desc) for p in pages])
+    )
 
+    meta = {
+        "max_pages": max_pages,
+        "timestamp": datetime.utcnow().isoformat() + "Z",
+        "agent": "tinyseoai/0.1.0",
+        "duration_seconds": round(time.monotonic() - started, 2),
+        **compute_score(issues, len(pages)).meta(),
+        "user_agent": http.agent,
+        "tls_verification": http.verify_tls,
+        "auth_host": http.auth_host if http.has_credentials else None,
+        "flaky_urls": flaky_urls(client),  # URLs that needed retries, with their outcome
+        "locale": locale.value,
+    }
+    if options is not None:
+        meta["crawl_scope"] = options.model_dump()
+
+    localize(issues, locale)
     return AuditResult(
         site=seed_url,
         pages_scanned=len(pages),
         issues=issues,
-        meta={
-            "max_pages": max_pages,
-            "timestamp": datetime.utcnow().isoformat() + "Z",
-            "agent": "tinyseoai/0.1.0",
-        },
+        meta=meta,
     )
 
 
@@ -206,65 +226,23 @@ def _check_noindex(url: str, noindex: bool) -> list[Issue]:
 async def _check_broken_links(
     client: httpx.AsyncClient, page_url: str, links: list[str]
 ) -> list[Issue]:
-    """Check a sample of links for broken status."""
+    """
+    Check a sample of links for broken status.
+
+    Links that only failed with retryable errors (timeouts, 502/503/504)
+    are reported as low, since the endpoint may just be flaky.
+    """
     issues = []
 
     for link in links:
         try:
             r = await client.head(link, timeout=10.0, follow_redirects=True)
-            if r.status_code >= 400:
-                issues.append(
-                    Issue(url=page_url, type="broken_link", severity="medium", detail=link)
-                )
+            if r.status_code < 400:
+                continue
         except Exception:
-            issues.append(
-                Issue(url=page_url, type="broken_link", severity="medium", detail=link)
-            )
-
-    return issues
-
-
-def _check_duplicate_titles(pages: list[Page]) -> list[Issue]:
-    """Check for duplicate title tags across pages."""
-    issues = []
-    titles: dict[str, list[str]] = {}
-
-    for page in pages:
-        if page.title:
-            normalized_title = page.title.strip().lower()
-            titles.setdefault(normalized_title, []).append(page.url)
-
-    for title_text, urls in titles.items():
-        if len(urls) > 1:
-            for url in urls:
-                issues.append(
-                    Issue(url=url, type="duplicate_title", severity="low", detail=title_text[:80])
-                )
-
-    return issues
-
-
-def _check_duplicate_meta_descriptions(pages: list[Page]) -> list[Issue]:
-    """Check for duplicate meta descriptions across pages."""
-    issues = []
-    mds: dict[str, list[str]] = {}
-
-    for page in pages:
-        if page.meta_desc:
-            key = page.meta_desc.strip().lower()
-            if key:
-                mds.setdefault(key, []).append(page.url)
-
-    for desc, urls in mds.items():
-        if len(urls) > 1:
-            for url in urls:
-                issues.append(
-                    Issue(
-                        url=url,
-                        type="duplicate_meta_description",
-                        severity="low",
-                        detail=(desc[:120] + ("…" if len(desc) > 120 else ""))
-                    )
-                )
+            pass
+        attempts = attempts_for(client, link)
+        severity = "low" if attempts is not None and attempts.outcome == "transient" else "medium"
+        issues.append(Issue(url=page_url, type="broken_link", severity=severity, detail=link))
 
     return issues
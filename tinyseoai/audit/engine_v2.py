@@ -6,31 +6,51 @@ Enhanced SEO audit engine integrating all check modules.
 """
 from __future__ import annotations
 
-from collections import deque
+import time
+from contextlib import asynccontextmanager
 from datetime import datetime
+from pathlib import Path
 from urllib.parse import urlparse
 
 import httpx
 from loguru import logger
 from rich.progress import Progress, SpinnerColumn, TextColumn, BarColumn, TaskProgressColumn
 
+from ..data.diagnostics import Diagnostics
+from ..data.effort import annotate as annotate_effort
 from ..data.models import AuditResult, Issue
 from ..data.scoring import HealthScoreCalculator, prioritize_issues
-from ..utils.rate_limiter import RateLimiter
-from ..utils.url import normalize_url, same_host
-from .checks.content import ContentAnalyzer, DuplicateContentDetector
+from ..i18n import Locale, localize, msg
+from ..progress import Phase, ProgressEmitter, ProgressHook
+from ..store.snapshots import SnapshotArchive, SnapshotOptions
+from ..utils.http import HttpOptions, flaky_urls, tls_issues
+from ..utils.http import client as http_client
+from ..utils.url import normalize_url
+from .builtin_checks import default_registry
+from .checks.content import ContentAnalyzer
 from .checks.indexability import IndexabilityChecker, check_pagination
-from .checks.links import LinkChecker
-from .checks.meta import MetaTagChecker
+from .checks.keywords import KeywordTargets
+from .checks.meta import TITLE_MAX_LENGTH, MetaTagChecker
+from .checks.mobile import analyze as analyze_mobile
 from .checks.performance import PerformanceChecker
 from .checks.security import SecurityChecker, check_ssl_certificate
-from .crawler import extract_links, extract_meta, fetch_page
-from .parser import HTMLParser
+from .checks_config import ChecksConfig
+from .crawler import (
+    Crawl,
+    Finished,
+    PageFailed,
+    PageSkipped,
+    PolitenessOptions,
+    extract_links,
+    extract_meta,
+)
+from .generate import indexable_entries, render_sitemap, write_sitemap
+from .registry import CheckContext, CheckRegistry
 from .robots import RobotsAnalyzer, discover_sitemaps
+from .scope import CrawlOptions
 
 # Constants
 DEFAULT_MAX_PAGES = 50
-USER_AGENT = "TinySEOAI/0.2 (+https://tinyseoai.com; cli) python-httpx"
 
 
 class _DummyProgress:
@@ -64,6 +84,7 @@ class EnhancedPage:
         noindex: bool = False,
         html: str = "",
         headers: dict = None,
+        depth: int = 0,
     ):
         self.url = url
         self.status = status
@@ -72,16 +93,39 @@ class EnhancedPage:
         self.noindex = noindex
         self.html = html
         self.headers = headers or {}
+        self.depth = depth
+        self.final_url: str | None = None  # where redirects ended, when it differs from url
         self.links: set[str] = set()
         self.internal_links: list[dict] = []
         self.external_links: list[dict] = []
 
 
+@asynccontextmanager
+async def _client_context(client: httpx.AsyncClient | None, http: HttpOptions, headers: dict):
+    """Use the given client, or create one that is closed afterwards."""
+    if client is not None:
+        yield client
+        return
+    async with http_client(http, headers=headers) as own:
+        yield own
+
+
 async def comprehensive_audit(
     seed_url: str,
     max_pages: int = DEFAULT_MAX_PAGES,
     enable_all_checks: bool = True,
-    show_progress: bool = True
+    show_progress: bool = True,
+    options: CrawlOptions | None = None,
+    registry: CheckRegistry | None = None,
+    client: httpx.AsyncClient | None = None,
+    checks: ChecksConfig | None = None,
+    http: HttpOptions | None = None,
+    on_progress: ProgressHook | None = None,
+    job_id: str | None = None,
+    locale: Locale = Locale.EN,
+    snapshots: SnapshotOptions | None = None,
+    suggest_sitemap: Path | None = None,
+    politeness: PolitenessOptions | None = None,
 ) -> AuditResult:
     """
     Comprehensive SEO audit using all available check modules.
@@ -91,30 +135,69 @@ async def comprehensive_audit(
         max_pages: Maximum pages to crawl
         enable_all_checks: If False, only run basic checks (faster)
         show_progress: If True, display progress bar during crawl
+        options: Crawl scope (depth, include/exclude patterns, subdomains);
+            its max_pages overrides the max_pages argument, and its
+            keyword_targets file feeds the keywords check
+        registry: Site-level checks run after the crawl (default_registry()
+            when omitted); what each did, with its duration, requests, and
+            bytes fetched, is recorded in meta["diagnostics"]
+        client: HTTP client for the crawl and the checks (one is created
+            when omitted)
+        checks: Per-check thresholds and enabled flags (the built-in
+            defaults when omitted)
+        http: User agent, contact, TLS, and credential settings for the clients the
+            audit creates; verify_tls=False is flagged in the result
+        on_progress: Called with a ProgressEvent as the audit moves through
+            fetching, crawling (each page), checking (each check), analyzing,
+            and done
+        job_id: Set on every progress event
+        locale: Language of the findings' details (recorded in meta["locale"]);
+            fingerprints do not depend on it
+        snapshots: Archive the HTML and headers of pages with findings there
+            and link them from the issues (see store.snapshots)
+        suggest_sitemap: When no sitemap was found, write one of the crawled
+            indexable pages to this folder (see generate.sitemap); recorded
+            in meta["suggested_sitemap"]
+        politeness: Per-host pacing, robots.txt, and Retry-After handling of the
+            crawl (the defaults, matching robots.txt against the audit's
+            User-Agent, when omitted)
 
     Returns:
         Enhanced AuditResult with all findings and scores
+
+    Raises:
+        ConfigError: If the keyword targets file cannot be read or parsed
     """
     logger.info(f"Starting comprehensive audit of {seed_url}")
+    started = time.monotonic()
     seed_url = normalize_url(seed_url)
     origin = urlparse(seed_url)
     host = origin.netloc
     site_root = f"{origin.scheme}://{origin.netloc}"
+    keywords = None
+    if options is not None:
+        max_pages = options.max_pages
+        if options.keyword_targets:
+            keywords = KeywordTargets.load(Path(options.keyword_targets).expanduser())
 
-    headers = {
-        "User-Agent": USER_AGENT,
-        "Accept": "text/html,application/xhtml+xml;q=0.9,*/*;q=0.8",
-    }
+    http = (http or HttpOptions()).for_site(seed_url)
+    headers = {"Accept": "text/html,application/xhtml+xml;q=0.9,*/*;q=0.8"}
 
-    all_issues: list[Issue] = []
+    all_issues: list[Issue] = tls_issues(site_root, http)
     pages: list[EnhancedPage] = []
 
+    progress = ProgressEmitter(on_progress, job_id)
+
     # Phase 1: Analyze robots.txt
     logger.info("Phase 1: Analyzing robots.txt...")
+    progress(Phase.FETCHING, "Analyzing robots.txt")
     robots_analyzer = RobotsAnalyzer(site_root)
-    rate_limiter = RateLimiter(requests_per_second=2.0)
 
-    async with httpx.AsyncClient(headers=headers, http2=True) as client:
+    registry = registry or default_registry()
+    checks = checks or ChecksConfig()
+    effort = checks.effort
+
+    async with _client_context(client, http, headers) as client:
         robots_exists = await robots_analyzer.fetch_and_parse(client)
 
         if not robots_exists:
@@ -123,33 +206,31 @@ async def comprehensive_audit(
                     url=f"{site_root}/robots.txt",
                     type="robots_missing",
                     severity="low",
-                    detail="No robots.txt found",
+                    **msg("robots.not_found"),
                 )
             )
 
-        # Update rate limiter if robots.txt specifies crawl delay
-        if robots_analyzer.crawl_delay:
-            rate_limiter.set_crawl_delay(robots_analyzer.crawl_delay)
-
         # Discover sitemaps if enabled
         sitemap_urls = []
         if enable_all_checks:
             logger.info("Discovering sitemaps...")
+            progress(Phase.FETCHING, "Discovering sitemaps")
             sitemap_urls = await discover_sitemaps(site_root, client, max_depth=1)
             logger.info(f"Found {len(sitemap_urls)} URLs in sitemaps")
 
         # Phase 2: Crawl pages
         logger.info(f"Phase 2: Crawling up to {max_pages} pages...")
-        visited: set[str] = set()
-        to_visit: deque[str] = deque([seed_url])
-
-        # Prioritize sitemap URLs if available
-        if sitemap_urls:
-            # Add high-priority sitemap URLs to the front of the queue
-            for sitemap_url in sitemap_urls[:max_pages]:
-                if sitemap_url not in to_visit:
-                    to_visit.append(sitemap_url)
-
+        progress(Phase.CRAWLING, done=0, total=max_pages)
+        scope = options or CrawlOptions(max_pages=max_pages)
+        # Sitemap URLs are queued after the seed, one hop from it
+        crawl = Crawl(
+            seed_url,
+            client,
+            scope,
+            politeness=politeness or PolitenessOptions(user_agent=http.agent),
+            progress=progress,
+            extra_urls=sitemap_urls,
+        )
         crawl_count = 0
 
         # Create progress bar context (or dummy context if disabled)
@@ -165,113 +246,118 @@ async def comprehensive_audit(
             else _DummyProgress()
         )
 
-        with progress_context as progress:
-            crawl_task = progress.add_task(
+        with progress_context as bar:
+            crawl_task = bar.add_task(
                 "[cyan]Crawling pages...",
                 total=max_pages,
                 current_url=""
             ) if show_progress else None
 
-            while to_visit and crawl_count < max_pages:
-                url = to_visit.popleft()
-                if url in visited:
+            async for event in crawl.stream():
+                if isinstance(event, PageSkipped):
+                    logger.debug(f"Skipping {event.url} ({event.reason})")
                     continue
-                visited.add(url)
-
-                # Check robots.txt permission
-                if robots_exists and not robots_analyzer.can_fetch(url):
-                    logger.debug(f"Skipping {url} (disallowed by robots.txt)")
+                if isinstance(event, Finished):
+                    logger.debug(f"Crawl finished ({event.reason}) after {event.duration_seconds}s")
                     continue
 
-                # Update progress bar with current URL
-                if show_progress:
-                    short_url = url if len(url) <= 50 else url[:47] + "..."
-                    progress.update(crawl_task, current_url=short_url)
-
-                # Respect politeness policies before each request
-                await rate_limiter.wait()
-
-                # Fetch page
-                resp = await fetch_page(client, url)
-                if resp is None:
+                if isinstance(event, PageFailed):
+                    url = event.url
                     all_issues.append(
-                        Issue(url=url, type="fetch_error", severity="high", detail="Request failed")
-                    )
-                    if show_progress:
-                        progress.advance(crawl_task)
-                    crawl_count += 1
-                    continue
-
-                status = resp.status_code
-                response_headers = dict(resp.headers)
-                html = (
-                    resp.text
-                    if resp.headers.get("content-type", "").startswith("text/html")
-                    else ""
-                )
-
-                page = EnhancedPage(
-                    url=url, status=status, html=html, headers=response_headers
-                )
-
-                # Check for HTTP errors
-                if status >= 400:
-                    all_issues.append(
-                        Issue(url=url, type="http_error", severity="high", detail=f"Status {status}")
+                        Issue(
+                            url=url, type="fetch_error", severity="high", **msg("crawl.fetch_error")
+                        )
                     )
                 else:
-                    # Extract basic metadata
-                    title, meta_desc, noindex = extract_meta(html)
-                    page.title = title
-                    page.meta_desc = meta_desc
-                    page.noindex = noindex
-
-                    # Run comprehensive checks on this page
-                    page_issues = await _run_page_checks(
-                        page, site_root, enable_all_checks, client
+                    fetched = event.page
+                    url = fetched.url
+                    page = EnhancedPage(
+                        url=url,
+                        status=fetched.status,
+                        html=fetched.html,
+                        headers=fetched.headers,
+                        depth=fetched.depth,
                     )
-                    all_issues.extend(page_issues)
-
-                    # Extract links for crawling
-                    # BUGFIX: Cap queue size to prevent unbounded memory growth
-                    # See: BUGFIXES.md #6
-                    links = extract_links(html, url)
-                    for link in links:
-                        if same_host(link, host):
-                            page.links.add(link)
-                            # Only add to queue if we haven't visited and queue isn't full
-                            if link not in visited and len(to_visit) < max_pages:
-                                to_visit.append(link)
-
-                pages.append(page)
+                    page.final_url = fetched.final_url
+
+                    # Check for HTTP errors
+                    if page.status >= 400:
+                        all_issues.append(
+                            Issue(
+                                url=url,
+                                type="http_error",
+                                severity="high",
+                                **msg("crawl.http_error", page.status),
+                            )
+                        )
+                    else:
+                        # Extract basic metadata
+                        title, meta_desc, noindex = extract_meta(page.html)
+                        page.title = title
+                        page.meta_desc = meta_desc
+                        page.noindex = noindex
+
+                        # Run comprehensive checks on this page
+                        page_issues = await _run_page_checks(
+                            page, site_root, enable_all_checks, client, checks
+                        )
+                        all_issues.extend(page_issues)
+
+                        # In-scope links of the page (the crawl follows them itself)
+                        for link in extract_links(page.html, page.final_url or url):
+                            if scope.allows(link, host, page.depth + 1):
+                                page.links.add(link)
+
+                    pages.append(page)
+
                 crawl_count += 1
 
                 # Update progress bar
                 if show_progress:
-                    progress.advance(crawl_task)
+                    short_url = url if len(url) <= 50 else url[:47] + "..."
+                    bar.update(crawl_task, current_url=short_url)
+                    bar.advance(crawl_task)
                 elif crawl_count % 10 == 0:
                     # Keep old logging behavior when progress is disabled
                     logger.info(f"Crawled {crawl_count}/{max_pages} pages...")
 
-    logger.info("Phase 3: Running post-crawl analysis...")
-
-    # Phase 3: Post-crawl checks
-    post_crawl_issues = await _run_post_crawl_checks(pages, seed_url, enable_all_checks)
-    all_issues.extend(post_crawl_issues)
+        logger.info("Phase 3: Running post-crawl analysis...")
+        progress(Phase.CHECKING, "Running post-crawl analysis")
+
+        # Phase 3: Site-level checks from the registry, sharing the crawl's client
+        context = CheckContext(
+            seed_url=seed_url,
+            pages=pages,
+            robots=robots_analyzer.rules if robots_analyzer.content else None,
+            client=client,
+            checks=checks,
+            keywords=keywords,
+            http=http,
+            comprehensive=enable_all_checks,
+            progress=progress,
+            locale=locale,
+        )
+        checks = await registry.run(context)
+        localize(all_issues, locale)  # the registry renders its own findings in the locale
+        all_issues.extend(checks.issues)
 
     # Phase 4: Calculate scores
     logger.info("Phase 4: Calculating health scores...")
+    progress(Phase.ANALYZING, "Calculating health scores")
     health_calc = HealthScoreCalculator()
     health_score = health_calc.calculate_health_score(all_issues, len(pages))
 
     # Prioritize issues
     prioritize_issues(all_issues)
+    if effort.enabled:
+        annotate_effort(all_issues, effort)
 
     # Build enhanced metadata
     meta = {
         "max_pages": max_pages,
         "timestamp": datetime.utcnow().isoformat() + "Z",
         "agent": "tinyseoai/0.2.0",
+        "duration_seconds": round(time.monotonic() - started, 2),
         "robots_txt_exists": robots_analyzer.content is not None,
         "sitemaps_found": len(robots_analyzer.get_sitemap_urls()),
         "crawl_delay": robots_analyzer.crawl_delay,
@@ -280,154 +366,142 @@ async def comprehensive_audit(
         "health_grade": health_score["grade"],
         "category_scores": health_score.get("category_scores", {}),
         "top_recommendations": health_score.get("recommendations", [])[:5],
+        "user_agent": http.agent,
+        "tls_verification": http.verify_tls,
+        "auth_host": http.auth_host if http.has_credentials else None,
+        "flaky_urls": flaky_urls(client),  # URLs that needed retries, with their outcome
+        "locale": locale.value,
     }
-
-    logger.info(
+    meta.update(context.meta)
+    if suggest_sitemap is not None and enable_all_checks and not sitemap_urls:
+        meta["suggested_sitemap"] = _suggest_sitemap(pages, suggest_sitemap)
+    meta["diagnostics"] = Diagnostics(checks=checks.runs).model_dump()
+    if options is not None:
+        meta["crawl_scope"] = options.model_dump()
+
+    summary = (
         f"Audit complete: {len(pages)} pages, {len(all_issues)} issues, "
         f"health score: {health_score['overall_score']}/100 ({health_score['grade']})"
     )
-
-    return AuditResult(
-        site=seed_url, pages_scanned=len(pages), issues=all_issues, meta=meta
-    )
+    logger.info(summary)
+    result = AuditResult(site=seed_url, pages_scanned=len(pages), issues=all_issues, meta=meta)
+    if snapshots is not None:
+        try:
+            SnapshotArchive(snapshots).save(result, pages)
+        except OSError as e:
+            logger.warning(f"Snapshots not saved: {e}")
+    progress(Phase.DONE, summary, done=len(pages), total=max_pages)
+    return result
+
+
+def _suggest_sitemap(pages: list[EnhancedPage], directory: Path) -> dict | None:
+    """Write a sitemap of the crawl to a folder; its files and URL count, or None."""
+    entries = indexable_entries(pages)
+    if not entries:
+        logger.info("No indexable pages to suggest a sitemap of")
+        return None
+    try:
+        paths = write_sitemap(render_sitemap(entries), directory)
+    except OSError as e:
+        logger.warning(f"Suggested sitemap not written: {e}")
+        return None
+    return {"files": [str(p) for p in paths], "urls": len(entries)}
 
 
 async def _run_page_checks(
-    page: EnhancedPage, site_root: str, enable_all: bool, client: httpx.AsyncClient
+    page: EnhancedPage,
+    site_root: str,
+    enable_all: bool,
+    client: httpx.AsyncClient,
+    checks: ChecksConfig | None = None,
 ) -> list[Issue]:
-    """Run all checks for a single page."""
+    """Run all checks for a single page, skipping those disabled in the checks config."""
     issues = []
+    checks = checks or ChecksConfig()
 
     # Basic checks (always run)
-    issues.extend(_check_title(page.url, page.title))
-    issues.extend(_check_meta_description(page.url, page.meta_desc))
-    issues.extend(_check_noindex(page.url, page.noindex))
+    if checks.meta.enabled:
+        issues.extend(_check_title(page.url, page.title, checks.meta.title_max))
+        issues.extend(_check_meta_description(page.url, page.meta_desc))
 
     if not enable_all or not page.html:
         return issues
 
     # Enhanced checks
-    try:
-        # Security checks
-        security_checker = SecurityChecker(page.url)
-        sec_issues = await security_checker.check_all(client)
-        issues.extend(sec_issues)
-
-        # SSL certificate check (only for HTTPS)
-        if page.url.startswith("https://"):
-            ssl_issues = await check_ssl_certificate(page.url)
-            issues.extend(ssl_issues)
-
-    except Exception as e:
-        logger.warning(f"Security checks failed for {page.url}: {e}")
-
-    try:
-        # Meta tag checks
-        meta_checker = MetaTagChecker(page.html, page.url)
-        meta_issues = meta_checker.check_all()
-        issues.extend(meta_issues)
-    except Exception as e:
-        logger.warning(f"Meta tag checks failed for {page.url}: {e}")
+    if checks.security.enabled:
+        try:
+            # Security checks
+            security_checker = SecurityChecker(page.url)
+            sec_issues = await security_checker.check_all(client)
+            issues.extend(sec_issues)
+
+            # SSL certificate check (only for HTTPS)
+            if page.url.startswith("https://"):
+                ssl_issues = await check_ssl_certificate(page.url)
+                issues.extend(ssl_issues)
+
+        except Exception as e:
+            logger.warning(f"Security checks failed for {page.url}: {e}")
+
+    if checks.meta.enabled:
+        try:
+            # Meta tag checks
+            meta_checker = MetaTagChecker(page.html, page.url, checks.meta)
+            meta_issues = meta_checker.check_all()
+            issues.extend(meta_issues)
+        except Exception as e:
+            logger.warning(f"Meta tag checks failed for {page.url}: {e}")
+
+    if checks.mobile.enabled:
+        try:
+            # Viewport and mobile layout checks
+            issues.extend(analyze_mobile(page.html, page.url, checks.mobile))
+        except Exception as e:
+            logger.warning(f"Mobile checks failed for {page.url}: {e}")
 
     try:
         # Indexability checks
-        index_checker = IndexabilityChecker(page.html, page.url)
-        index_issues = index_checker.check_all()
-        issues.extend(index_issues)
+        if checks.indexability.enabled:
+            index_checker = IndexabilityChecker(page.html, page.url)
+            index_issues = index_checker.check_all()
+            issues.extend(index_issues)
 
         # Pagination checks
-        pagination_issues = check_pagination(page.html, page.url)
-        issues.extend(pagination_issues)
+        if checks.pagination.enabled:
+            pagination_issues = check_pagination(page.html, page.url)
+            issues.extend(pagination_issues)
     except Exception as e:
         logger.warning(f"Indexability checks failed for {page.url}: {e}")
 
-    try:
-        # Content quality checks
-        content_analyzer = ContentAnalyzer(page.html, page.url)
-        content_issues = content_analyzer.check_all()
-        issues.extend(content_issues)
-    except Exception as e:
-        logger.warning(f"Content checks failed for {page.url}: {e}")
-
-    try:
-        # Performance checks
-        perf_checker = PerformanceChecker(page.html, page.url, page.headers)
-        perf_issues = perf_checker.check_all()
-        issues.extend(perf_issues)
-    except Exception as e:
-        logger.warning(f"Performance checks failed for {page.url}: {e}")
-
-    return issues
-
-
-async def _run_post_crawl_checks(
-    pages: list[EnhancedPage], seed_url: str, enable_all: bool
-) -> list[Issue]:
-    """Run checks that require analysis across all pages."""
-    issues = []
-
-    # Duplicate title/description checks (always run)
-    issues.extend(_check_duplicate_titles(pages))
-    issues.extend(_check_duplicate_meta_descriptions(pages))
-
-    if not enable_all:
-        return issues
-
-    try:
-        # Duplicate content detection
-        dup_detector = DuplicateContentDetector()
-        for page in pages:
-            if page.html:
-                content_analyzer = ContentAnalyzer(page.html, page.url)
-                text = content_analyzer.text
-                dup_detector.add_page(page.url, text)
-
-        # Find exact duplicates
-        dup_issues = dup_detector.find_duplicates()
-        issues.extend(dup_issues)
-
-        # Find near-duplicates (may be slow for many pages)
-        if len(pages) < 100:  # Only for smaller sites
-            near_dup_issues = dup_detector.find_near_duplicates(threshold=0.8)
-            issues.extend(near_dup_issues)
-
-    except Exception as e:
-        logger.warning(f"Duplicate content detection failed: {e}")
-
-    try:
-        # Link graph analysis
-        link_checker = LinkChecker(seed_url)
-
-        # Build page data for link analysis
-        pages_data = []
-        for page in pages:
-            if page.html:
-                parser = HTMLParser(page.html, page.url)
-                page.internal_links = parser.content_parser.extract_internal_links(seed_url)
-                page.external_links = parser.content_parser.extract_external_links(seed_url)
-
-                pages_data.append({
-                    "url": page.url,
-                    "internal_links": page.internal_links,
-                    "external_links": page.external_links,
-                })
-
-        # Analyze internal linking
-        link_issues = link_checker.analyze_internal_linking(pages_data)
-        issues.extend(link_issues)
-
-    except Exception as e:
-        logger.warning(f"Link analysis failed: {e}")
+    if checks.content.enabled:
+        try:
+            # Content quality checks
+            content_analyzer = ContentAnalyzer(page.html, page.url, checks.content)
+            content_issues = content_analyzer.check_all()
+            issues.extend(content_issues)
+        except Exception as e:
+            logger.warning(f"Content checks failed for {page.url}: {e}")
+
+    if checks.performance.enabled:
+        try:
+            # Performance checks
+            perf_checker = PerformanceChecker(
+                page.html, page.url, page.headers, checks.performance
+            )
+            perf_issues = perf_checker.check_all()
+            issues.extend(perf_issues)
+        except Exception as e:
+            logger.warning(f"Performance checks failed for {page.url}: {e}")
 
     return issues
 
 
-def _check_title(url: str, title: str | None) -> list[Issue]:
+def _check_title(url: str, title: str | None, max_length: int = TITLE_MAX_LENGTH) -> list[Issue]:
     """Check title tag."""
     issues = []
     if not title:
         issues.append(Issue(url=url, type="title_missing", severity="medium"))
-    elif len(title) > 60:
+    elif len(title) > max_length:
         issues.append(
             Issue(url=url, type="title_too_long", severity="low", detail=str(len(title)))
         )
@@ -441,55 +515,3 @@ def _check_meta_description(url: str, meta_desc: str | None) -> list[Issue]:
         issues.append(Issue(url=url, type="meta_description_missing", severity="low"))
     return issues
 
-
-def _check_noindex(url: str, noindex: bool) -> list[Issue]:
-    """Check noindex directive."""
-    issues = []
-    if noindex:
-        issues.append(Issue(url=url, type="noindex", severity="info"))
-    return issues
-
-
-def _check_duplicate_titles(pages: list[EnhancedPage]) -> list[Issue]:
-    """Check for duplicate titles."""
-    issues = []
-    titles: dict[str, list[str]] = {}
-
-    for page in pages:
-        if page.title:
-            key = page.title.strip().lower()
-            titles.setdefault(key, []).append(page.url)
-
-    for title_text, urls in titles.items():
-        if len(urls) > 1:
-            for url in urls:
-                issues.append(
-                    Issue(url=url, type="duplicate_title", severity="low", detail=title_text[:80])
-                )
-
-    return issues
-
-
-def _check_duplicate_meta_descriptions(pages: list[EnhancedPage]) -> list[Issue]:
-    """Check for duplicate meta descriptions."""
-    issues = []
-    descriptions: dict[str, list[str]] = {}
-
-    for page in pages:
-        if page.meta_desc:
-            key = page.meta_desc.strip().lower()
-            descriptions.setdefault(key, []).append(page.url)
-
-    for desc_text, urls in descriptions.items():
-        if len(urls) > 1:
-            for url in urls:
-                issues.append(
-                    Issue(
-                        url=url,
-                        type="duplicate_meta_description",
-                        severity="low",
-                        detail=desc_text[:120],
-                    )
-                )
-
-    return issues
@@ -0,0 +1,168 @@
+"""
+Files generated from the crawl, ready to publish as fixes.
+
+sitemap() writes a sitemap.xml of the crawled pages that belong in one:
+HTML pages that loaded with a 2xx status, without a redirect, and without
+a noindex in the robots meta tags or X-Robots-Tag. lastmod comes from the
+Last-Modified header where the server sent one. Past 50,000 URLs or 50 MB
+the entries are split over sitemap-1.xml, sitemap-2.xml, ... and
+sitemap.xml becomes the index listing them.
+"""
+from __future__ import annotations
+
+from datetime import timezone
+from email.utils import parsedate_to_datetime
+from pathlib import Path
+from urllib.parse import urlparse
+from xml.sax.saxutils import escape
+
+from pydantic import BaseModel, Field
+
+from ..utils import urlnorm
+from .checks.indexability import page_directives
+from .checks.sitemap import MAX_SITEMAP_BYTES, MAX_SITEMAP_URLS, SitemapEntry
+
+SITEMAP_NS = "http://www.sitemaps.org/schemas/sitemap/0.9"
+MAX_LOC_LENGTH = 2048  # longer URLs are not allowed in <loc>
+
+_PROLOG = '<?xml version="1.0" encoding="UTF-8"?>\n'
+_URLSET_OPEN = f'{_PROLOG}<urlset xmlns="{SITEMAP_NS}">\n'
+_URLSET_CLOSE = "</urlset>\n"
+# Pages are told apart as the sitemap check compares them, keeping tracking parameters
+_URL_OPTIONS = urlnorm.NormalizeOptions(strip_params=())
+
+
+class SitemapGenOptions(BaseModel):
+    """Where the files will be published and how large each may get."""
+
+    base_url: str | None = None  # folder the files are served from; the site root by default
+    max_urls: int = Field(default=MAX_SITEMAP_URLS, ge=1)  # per file
+    max_bytes: int = Field(default=MAX_SITEMAP_BYTES, ge=1024)  # per file, uncompressed
+
+
+def _lastmod(headers: dict[str, str]) -> str | None:
+    """Last-Modified as a W3C datetime in UTC, or None when missing or unreadable."""
+    value = next((v for k, v in headers.items() if k.lower() == "last-modified"), None)
+    if not value:
+        return None
+    try:
+        modified = parsedate_to_datetime(value)
+    except (TypeError, ValueError, IndexError):
+        return None
+    if modified.tzinfo is None:
+        modified = modified.replace(tzinfo=timezone.utc)
+    return modified.astimezone(timezone.utc).strftime("%Y-%m-%dT%H:%M:%S+00:00")
+
+
+def indexable_entries(pages: list) -> list[SitemapEntry]:
+    """
+    Sitemap entries for the crawled pages that belong in a sitemap, in crawl order.
+
+    Args:
+        pages: Crawled pages (url, status, html, headers, final_url)
+
+    Returns:
+        One entry per distinct URL, with lastmod where Last-Modified was sent
+    """
+    entries: dict[str, SitemapEntry] = {}
+    for page in pages:
+        if not 200 <= page.status < 300 or not page.html or getattr(page, "final_url", None):
+            continue
+        if not page.url.startswith(("http://", "https://")) or len(page.url) > MAX_LOC_LENGTH:
+            continue
+        if getattr(page, "noindex", False) or page_directives(page.html, page.headers).noindex:
+            continue
+        key = urlnorm.normalize(page.url, _URL_OPTIONS)
+        entries.setdefault(key, SitemapEntry(loc=page.url, lastmod=_lastmod(page.headers)))
+    return list(entries.values())
+
+
+def _url_xml(entry: SitemapEntry) -> str:
+    lastmod = f"    <lastmod>{entry.lastmod}</lastmod>\n" if entry.lastmod else ""
+    return f"  <url>\n    <loc>{escape(entry.loc)}</loc>\n{lastmod}  </url>\n"
+
+
+def _split(entries: list[SitemapEntry], options: SitemapGenOptions) -> list[list[str]]:
+    """Entry XML in files of at most max_urls entries and max_bytes bytes."""
+    budget = options.max_bytes - len((_URLSET_OPEN + _URLSET_CLOSE).encode("utf-8"))
+    files: list[list[str]] = [[]]
+    size = 0
+    for entry in entries:
+        xml = _url_xml(entry)
+        length = len(xml.encode("utf-8"))
+        if files[-1] and (len(files[-1]) >= options.max_urls or size + length > budget):
+            files.append([])
+            size = 0
+        files[-1].append(xml)
+        size += length
+    return files
+
+
+def _index(names: list[str], lastmods: list[str | None], base_url: str) -> str:
+    lines = [_PROLOG, f'<sitemapindex xmlns="{SITEMAP_NS}">\n']
+    for name, lastmod in zip(names, lastmods, strict=True):
+        lines.append(f"  <sitemap>\n    <loc>{escape(base_url + name)}</loc>\n")
+        if lastmod:
+            lines.append(f"    <lastmod>{lastmod}</lastmod>\n")
+        lines.append("  </sitemap>\n")
+    lines.append("</sitemapindex>\n")
+    return "".join(lines)
+
+
+def sitemap(pages: list, options: SitemapGenOptions | None = None) -> dict[str, str]:
+    """
+    Write a sitemap of the crawled indexable pages.
+
+    Args:
+        pages: Crawled pages (see indexable_entries)
+        options: Base URL of the published files and per-file limits
+
+    Returns:
+        File name -> XML (see render_sitemap); empty when no page belongs in a sitemap
+    """
+    return render_sitemap(indexable_entries(pages), options)
+
+
+def render_sitemap(
+    entries: list[SitemapEntry], options: SitemapGenOptions | None = None
+) -> dict[str, str]:
+    """
+    Write sitemap files for a list of entries.
+
+    Returns:
+        File name -> XML: just sitemap.xml when the entries fit in one file,
+        else sitemap.xml as the index of sitemap-1.xml, sitemap-2.xml, ...
+    """
+    options = options or SitemapGenOptions()
+    if not entries:
+        return {}
+    chunks = _split(entries, options)
+    if len(chunks) == 1:
+        return {"sitemap.xml": _URLSET_OPEN + "".join(chunks[0]) + _URLSET_CLOSE}
+
+    origin = urlparse(entries[0].loc)
+    base_url = options.base_url or f"{origin.scheme}://{origin.netloc}/"
+    if not base_url.endswith("/"):
+        base_url += "/"
+    files: dict[str, str] = {}
+    names, lastmods = [], []
+    start = 0
+    for number, chunk in enumerate(chunks, 1):
+        name = f"sitemap-{number}.xml"
+        dates = [e.lastmod for e in entries[start : start + len(chunk)] if e.lastmod]
+        start += len(chunk)
+        names.append(name)
+        lastmods.append(max(dates) if dates else None)
+        files[name] = _URLSET_OPEN + "".join(chunk) + _URLSET_CLOSE
+    return {"sitemap.xml": _index(names, lastmods, base_url), **files}
+
+
+def write_sitemap(files: dict[str, str], directory: Path) -> list[Path]:
+    """Write the files of sitemap() to a folder, creating it; returns their paths."""
+    directory.mkdir(parents=True, exist_ok=True)
+    paths = []
+    for name, xml in files.items():
+        path = directory / name
+        path.write_text(xml, encoding="utf-8")
+        paths.append(path)
+    return paths
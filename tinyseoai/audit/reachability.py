@@ -0,0 +1,126 @@
+"""
+Quick reachability probes of sites (see data.reachability).
+
+Each URL gets one HEAD request (GET when HEAD is refused), following
+redirects, with a short timeout and one retry, through a single shared
+client. For https URLs the certificate is read once per host, for its
+days left. No pages are parsed and no checks run, so a probe costs a
+request or two per site.
+"""
+from __future__ import annotations
+
+import asyncio
+from collections.abc import Callable
+from datetime import datetime, timezone
+from urllib.parse import urlparse
+
+import httpx
+from loguru import logger
+from pydantic import BaseModel, Field
+
+from ..data.reachability import Probe
+from ..utils.http import HttpOptions, RetryPolicy
+from ..utils.http import client as http_client
+from .checks.tls import Handshake, TlsOptions, days_left, handshake
+from .verify import PageStatus, fetch_status
+
+
+class ReachabilityOptions(BaseModel):
+    """Timeouts and limits for run_reachability_check."""
+
+    timeout: float = Field(default=5.0, gt=0)  # per request, seconds
+    retries: int = Field(default=1, ge=0)  # extra tries of a URL that is not up
+    retry_delay: float = Field(default=1.0, ge=0)  # seconds before a retry
+    concurrency: int = Field(default=8, ge=1)
+    tls: bool = True  # read the certificate of https hosts for its days left
+    http: HttpOptions = Field(default_factory=HttpOptions)  # user agent, contact, and TLS
+
+
+# Reads a host's certificate; blocking, run in a thread
+HandshakeFn = Callable[[str, TlsOptions], Handshake]
+
+
+def _up(status: PageStatus) -> bool:
+    return status.status_code is not None and status.status_code < 400
+
+
+async def _probe(client: httpx.AsyncClient, url: str, options: ReachabilityOptions) -> PageStatus:
+    status = await fetch_status(client, url, options.timeout, retry=False)
+    for _ in range(options.retries):
+        if _up(status):
+            break
+        logger.debug(f"Reachability: retrying {url} ({status.error or status.status_code})")
+        await asyncio.sleep(options.retry_delay)
+        status = await fetch_status(client, url, options.timeout, retry=False)
+    return status
+
+
+async def _tls_days(
+    hosts: set[str], options: ReachabilityOptions, read: HandshakeFn, now: datetime
+) -> dict[str, int | None]:
+    tls_options = TlsOptions(timeout=options.timeout, dns=False)
+    results = await asyncio.gather(*(asyncio.to_thread(read, host, tls_options) for host in hosts))
+    return {
+        result.host: days_left(result.certificate.not_after, now) if result.certificate else None
+        for result in results
+    }
+
+
+async def run_reachability_check(
+    urls: list[str],
+    options: ReachabilityOptions | None = None,
+    client: httpx.AsyncClient | None = None,
+    read_certificate: HandshakeFn = handshake,
+) -> list[Probe]:
+    """
+    Probe each URL once and record whether it is up.
+
+    Args:
+        urls: Sites or pages to probe
+        options: Timeout, retries, concurrency, and whether to read certificates
+        client: HTTP client to share (one is created if omitted)
+        read_certificate: Reads a host's certificate (tls.handshake by default)
+
+    Returns:
+        One Probe per URL, in the order given
+    """
+    options = options or ReachabilityOptions()
+    now = datetime.now(timezone.utc)
+    own_client = client is None
+    # The retry here is the only one, so a down site is reported within seconds
+    client = client or http_client(options.http.model_copy(update={"retry": RetryPolicy(attempts=1)}))
+    semaphore = asyncio.Semaphore(options.concurrency)
+
+    async def check(url: str) -> PageStatus:
+        async with semaphore:
+            return await _probe(client, url, options)
+
+    try:
+        statuses = await asyncio.gather(*(check(url) for url in urls))
+    finally:
+        if own_client:
+            await client.aclose()
+
+    tls_days: dict[str, int | None] = {}
+    if options.tls:
+        hosts = {urlparse(url).hostname for url in urls if urlparse(url).scheme == "https"}
+        tls_days = await _tls_days({h for h in hosts if h}, options, read_certificate, now)
+    checked_at = now.isoformat()
+    probes = []
+    for url, status in zip(urls, statuses, strict=True):
+        redirected = status.final_url if status.final_url and status.final_url != url else None
+        probes.append(
+            Probe(
+                url=url,
+                checked_at=checked_at,
+                up=_up(status),
+                status_code=status.status_code,
+                latency_ms=status.latency_ms,
+                final_url=redirected,
+                tls_days_left=tls_days.get(urlparse(url).hostname or ""),
+                error=status.error,
+            )
+        )
+    down = sum(1 for p in probes if not p.up)
+    logger.info(f"Reachability: {len(probes) - down} of {len(probes)} up")
+    return probes
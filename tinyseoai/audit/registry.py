@@ -0,0 +1,232 @@
+"""
+Registry of site-level checks run after the crawl.
+
+A check declares what it needs (network access, the full crawl, a fetched
+page, comprehensive mode) and the registry runs the enabled checks in
+registration order, skipping those whose requirements the audit cannot
+meet and recording why. Each run is metered (duration, requests, bytes)
+for the result's diagnostics, and findings are rendered in the context's
+locale. Built-in checks are registered by
+builtin_checks.default_registry; their ids are what the disabled_checks
+config setting and --skip-check refer to.
+"""
+from __future__ import annotations
+
+import inspect
+import time
+from abc import ABC, abstractmethod
+from collections.abc import Awaitable, Callable, Iterable
+from dataclasses import dataclass, field
+from typing import TYPE_CHECKING, Any
+
+import httpx
+from loguru import logger
+from pydantic import BaseModel, Field
+
+from ..data.diagnostics import CheckRun
+from ..data.models import Issue
+from ..i18n import Locale, localize
+from ..progress import Phase, ProgressEmitter
+from ..utils.http import RequestMeter, metered
+
+if TYPE_CHECKING:
+    from ..config import AppConfig
+    from ..utils.http import HttpOptions
+    from .checks.keywords import KeywordTargets
+    from .checks_config import ChecksConfig
+    from .robots import RobotsRules
+
+
+class Requirements(BaseModel):
+    """What a check needs from the audit to run."""
+
+    network: bool = False  # makes its own HTTP requests
+    crawl: bool = False  # compares pages, so needs the full crawl rather than one page
+    page: bool = False  # needs at least one fetched HTML page
+    comprehensive: bool = False  # skipped with --fast
+
+
+@dataclass
+class CheckContext:
+    """What a check can read: the crawl, robots.txt, an HTTP client, config, and thresholds."""
+
+    seed_url: str
+    pages: list  # crawled pages (url, status, html, headers, title, meta_desc, depth)
+    robots: RobotsRules | None = None
+    client: httpx.AsyncClient | None = None  # None when the audit may not make requests
+    http: HttpOptions | None = None  # identity and TLS for checks that need a client of their own
+    config: AppConfig | None = None
+    checks: ChecksConfig | None = None  # per-check thresholds; None uses the defaults
+    keywords: KeywordTargets | None = None  # target keywords per URL pattern, from the crawl scope
+    comprehensive: bool = True  # False with --fast
+    full_crawl: bool = True  # False when pages are a single page or a sample
+    meta: dict[str, Any] = field(default_factory=dict)  # merged into the result's meta
+    # Set by the registry while a check runs; requests of clients from utils.http.client are
+    # counted already, so only a check using another client needs to add to it
+    meter: RequestMeter | None = None
+    progress: ProgressEmitter = field(default_factory=ProgressEmitter)  # a checking event per check
+    locale: Locale = Locale.EN  # language of the findings' details
+
+    @property
+    def home(self):
+        """The first page crawled with HTML, or None."""
+        return next((p for p in self.pages if p.html), None)
+
+
+class Check(ABC):
+    """A site-level check; subclasses set id (and usually requires) and implement run."""
+
+    id: str
+    description: str = ""
+    requires: Requirements = Requirements()
+
+    @abstractmethod
+    async def run(self, ctx: CheckContext) -> list[Issue]:
+        """Run the check and return its findings."""
+
+
+class FunctionCheck(Check):
+    """A check made from a function of the context, plain or async."""
+
+    def __init__(
+        self,
+        id: str,
+        fn: Callable[[CheckContext], list[Issue] | Awaitable[list[Issue]]],
+        requires: Requirements | None = None,
+        description: str = "",
+    ):
+        self.id = id
+        self.fn = fn
+        self.requires = requires or Requirements()
+        self.description = description
+
+    async def run(self, ctx: CheckContext) -> list[Issue]:
+        result = self.fn(ctx)
+        return await result if inspect.isawaitable(result) else result
+
+
+class CheckResults(BaseModel):
+    """Findings of all checks that ran, and a record of every check."""
+
+    issues: list[Issue] = Field(default_factory=list)
+    runs: list[CheckRun] = Field(default_factory=list)
+
+
+def unmet(requires: Requirements, ctx: CheckContext) -> str | None:
+    """Why a check with these requirements cannot run in this context, or None."""
+    if requires.comprehensive and not ctx.comprehensive:
+        return "comprehensive checks are off (--fast)"
+    if requires.network and ctx.client is None:
+        return "no network access"
+    if requires.crawl and not ctx.full_crawl:
+        return "needs the full crawl"
+    if requires.page and ctx.home is None:
+        return "no HTML page was fetched"
+    return None
+
+
+class CheckRegistry:
+    """Checks by id, in the order they run, with the set of disabled ids."""
+
+    def __init__(self, checks: Iterable[Check] = ()):
+        self._checks: dict[str, Check] = {}
+        self._disabled: set[str] = set()
+        for check in checks:
+            self.register(check)
+
+    def __contains__(self, check_id: str) -> bool:
+        return check_id in self._checks
+
+    def __iter__(self):
+        return iter(self._checks.values())
+
+    def ids(self) -> list[str]:
+        return list(self._checks)
+
+    def register(self, check: Check, replace: bool = False) -> None:
+        """
+        Add a check after those already registered.
+
+        Raises:
+            ValueError: If the id is taken and replace is False
+        """
+        if check.id in self._checks and not replace:
+            raise ValueError(f"A check with id {check.id!r} is already registered")
+        self._checks[check.id] = check
+
+    def _known(self, ids: Iterable[str]) -> list[str]:
+        ids = list(ids)
+        unknown = [i for i in ids if i not in self._checks]
+        if unknown:
+            raise ValueError(
+                f"Unknown check id(s): {', '.join(unknown)}. Available: {', '.join(self._checks)}"
+            )
+        return ids
+
+    def enable(self, *ids: str) -> None:
+        """Enable checks by id; raises ValueError for unknown ids."""
+        self._disabled.difference_update(self._known(ids))
+
+    def disable(self, *ids: str) -> None:
+        """Disable checks by id; raises ValueError for unknown ids."""
+        self._disabled.update(self._known(ids))
+
+    def is_enabled(self, check_id: str) -> bool:
+        return check_id in self._checks and check_id not in self._disabled
+
+    async def run(self, ctx: CheckContext) -> CheckResults:
+        """
+        Run the enabled checks in order.
+
+        A check that raises is logged and recorded as failed; the others
+        still run. A checking progress event is emitted as each check starts.
+        """
+        results = CheckResults()
+        blocked = {check.id: self._blocked(check, ctx) for check in self._checks.values()}
+        runnable = [check_id for check_id, run in blocked.items() if run is None]
+        for check in self._checks.values():
+            if blocked[check.id] is not None:
+                results.runs.append(blocked[check.id])
+                continue
+            number = runnable.index(check.id) + 1
+            logger.info(f"Running check {check.id} ({number}/{len(runnable)})")
+            ctx.progress(Phase.CHECKING, check_id=check.id, done=number, total=len(runnable))
+            results.runs.append(await self._run_one(check, ctx, results))
+        return results
+
+    def _blocked(self, check: Check, ctx: CheckContext) -> CheckRun | None:
+        """The record of a check that will not run (disabled or unmet requirements), or None."""
+        if check.id in self._disabled:
+            return CheckRun(id=check.id, status="disabled")
+        if ctx.checks is not None and not ctx.checks.is_enabled(check.id):
+            reason = "enabled = false in checks config"
+            return CheckRun(id=check.id, status="disabled", reason=reason)
+        reason = unmet(check.requires, ctx)
+        if reason is not None:
+            return CheckRun(id=check.id, status="skipped", reason=reason)
+        return None
+
+    async def _run_one(self, check: Check, ctx: CheckContext, results: CheckResults) -> CheckRun:
+        started = time.monotonic()
+        with metered() as meter:
+            ctx.meter = meter
+            try:
+                issues = await check.run(ctx)
+            except Exception as e:
+                logger.warning(f"Check {check.id} failed: {e}")
+                status, reason, issues = "failed", str(e) or type(e).__name__, []
+            else:
+                status, reason = "ran", None
+                localize(issues, ctx.locale)
+                results.issues.extend(issues)
+            finally:
+                ctx.meter = None
+        return CheckRun(
+            id=check.id,
+            status=status,
+            reason=reason,
+            issues=len(issues),
+            duration_ms=round((time.monotonic() - started) * 1000, 1),
+            requests=meter.requests,
+            bytes_fetched=meter.bytes_fetched,
+        )
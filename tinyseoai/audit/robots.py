@@ -4,11 +4,283 @@ Robots.txt parsing and sitemap discovery.
 from __future__ import annotations
 
 import re
-from urllib.parse import urlparse
+from urllib.parse import urljoin, urlparse
 from urllib.robotparser import RobotFileParser
 
 import httpx
 from loguru import logger
+from pydantic import BaseModel, Field, PrivateAttr
+
+from ..data.models import Issue
+from ..i18n import msg
+
+# Parsers must read at least this much of robots.txt (RFC 9309, section 2.5);
+# anything after it is ignored.
+MAX_ROBOTS_BYTES = 500 * 1024
+
+# Path fragments that usually hold CSS/JS needed to render pages
+_RESOURCE_HINTS = (".css", ".js", "/css", "/js", "/static", "/assets", "/wp-includes", "/wp-content")
+
+
+class RobotsRule(BaseModel):
+    """One Allow or Disallow line."""
+
+    allow: bool
+    path: str  # may contain * (any characters) and a trailing $ (end of URL)
+
+    _regex: re.Pattern | None = PrivateAttr(default=None)
+
+    def matches(self, path: str) -> bool:
+        """Check whether a URL path (with query) matches this rule."""
+        if self._regex is None:
+            pattern = self.path
+            anchored = pattern.endswith("$")
+            if anchored:
+                pattern = pattern[:-1]
+            body = ".*".join(re.escape(part) for part in pattern.split("*"))
+            self._regex = re.compile(body + ("$" if anchored else ""))
+        return self._regex.match(path) is not None
+
+    @property
+    def specificity(self) -> int:
+        """Rule length used to pick between matching rules; longer wins."""
+        return len(self.path)
+
+
+class RobotsGroup(BaseModel):
+    """Rules for one or more user agents."""
+
+    user_agents: list[str]
+    rules: list[RobotsRule] = Field(default_factory=list)
+    crawl_delay: float | None = None
+
+
+class RobotsRules(BaseModel):
+    """Parsed robots.txt."""
+
+    groups: list[RobotsGroup] = Field(default_factory=list)
+    sitemaps: list[str] = Field(default_factory=list)
+    truncated: bool = False
+
+    def group_for(self, user_agent: str = "*") -> RobotsGroup | None:
+        """
+        Rules that apply to a user agent.
+
+        The groups naming the longest matching product token win and are
+        merged; otherwise the * groups apply.
+        """
+        token = user_agent.split("/")[0].strip().lower()
+        best: list[RobotsGroup] = []
+        best_len = 0
+        for group in self.groups:
+            for agent in group.user_agents:
+                name = agent.lower()
+                if name != "*" and token.startswith(name) and len(name) >= best_len:
+                    if len(name) > best_len:
+                        best, best_len = [], len(name)
+                    best.append(group)
+        if not best:
+            best = [g for g in self.groups if "*" in g.user_agents]
+        if not best:
+            return None
+        delays = [g.crawl_delay for g in best if g.crawl_delay is not None]
+        return RobotsGroup(
+            user_agents=sorted({a for g in best for a in g.user_agents}),
+            rules=[r for g in best for r in g.rules],
+            crawl_delay=delays[0] if delays else None,
+        )
+
+    def can_fetch(self, url: str, user_agent: str = "*") -> bool:
+        """
+        Check a URL against the rules.
+
+        The longest matching rule decides; on a tie Allow wins. URLs no
+        rule matches are allowed, and /robots.txt itself always is.
+        """
+        parsed = urlparse(url)
+        path = parsed.path or "/"
+        if parsed.query:
+            path += "?" + parsed.query
+        if path == "/robots.txt":
+            return True
+        group = self.group_for(user_agent)
+        if group is None:
+            return True
+        matching = [r for r in group.rules if r.matches(path)]
+        if not matching:
+            return True
+        winner = max(matching, key=lambda r: (r.specificity, r.allow))
+        return winner.allow
+
+
+def parse_robots(text: str, max_bytes: int = MAX_ROBOTS_BYTES) -> RobotsRules:
+    """
+    Parse robots.txt content.
+
+    Consecutive User-agent lines start one group; Allow, Disallow, and
+    Crawl-delay lines apply to the current group. Sitemap lines are global.
+    Unknown lines, comments, and rules outside a group are ignored.
+
+    Args:
+        text: robots.txt body
+        max_bytes: Bytes to read; the rest is dropped
+
+    Returns:
+        Parsed rules
+    """
+    data = text.encode("utf-8")
+    truncated = len(data) > max_bytes
+    if truncated:
+        text = data[:max_bytes].decode("utf-8", errors="ignore")
+        # Drop the partial last line
+        text = text.rsplit("\n", 1)[0] if "\n" in text else ""
+
+    rules = RobotsRules(truncated=truncated)
+    current: RobotsGroup | None = None
+    in_agents = False
+    for raw in text.splitlines():
+        line = raw.split("#", 1)[0].strip()
+        if ":" not in line:
+            continue
+        key, value = (part.strip() for part in line.split(":", 1))
+        key = key.lower()
+        if key == "user-agent":
+            if not in_agents:
+                current = RobotsGroup(user_agents=[])
+                rules.groups.append(current)
+                in_agents = True
+            current.user_agents.append(value)
+            continue
+        in_agents = False
+        if key == "sitemap":
+            if value:
+                rules.sitemaps.append(value)
+        elif current is None:
+            continue
+        elif key in ("allow", "disallow"):
+            if value:  # an empty Disallow allows everything
+                current.rules.append(RobotsRule(allow=key == "allow", path=value))
+        elif key == "crawl-delay":
+            try:
+                current.crawl_delay = float(value)
+            except ValueError:
+                pass
+    return rules
+
+
+class RobotsReport(BaseModel):
+    """Result of analyze_robots."""
+
+    url: str
+    status_code: int | None = None
+    found: bool = False
+    served_as_html: bool = False
+    rules: RobotsRules = Field(default_factory=RobotsRules)
+    issues: list[Issue] = Field(default_factory=list)
+
+    def can_fetch(self, url: str, user_agent: str = "*") -> bool:
+        """Check a URL; without a usable robots.txt everything is allowed."""
+        return not self.found or self.rules.can_fetch(url, user_agent)
+
+
+def _looks_like_html(response: httpx.Response) -> bool:
+    content_type = response.headers.get("content-type", "").lower()
+    start = response.text.lstrip()[:100].lower()
+    return "text/html" in content_type or start.startswith(("<!doctype html", "<html"))
+
+
+def robots_issues(robots_url: str, rules: RobotsRules) -> list[Issue]:
+    """Findings for a parsed robots.txt: blanket blocks, blocked resources, no sitemap."""
+    issues = []
+    group = rules.group_for("*")
+    if group is not None:
+        if any(not r.allow and r.path in ("/", "/*") for r in group.rules) and not any(
+            r.allow and r.path not in ("", "/robots.txt") for r in group.rules
+        ):
+            issues.append(
+                Issue(
+                    url=robots_url,
+                    type="robots_disallow_all",
+                    severity="high",
+                    **msg("robots.robots_disallow_all"),
+                )
+            )
+        blocked = [
+            r.path
+            for r in group.rules
+            if not r.allow and r.path not in ("/", "/*") and any(h in r.path.lower() for h in _RESOURCE_HINTS)
+        ]
+        if blocked:
+            issues.append(
+                Issue(
+                    url=robots_url,
+                    type="robots_blocks_resources",
+                    severity="medium",
+                    **msg("robots.robots_blocks_resources", blocked[:5]),
+                )
+            )
+    if not rules.sitemaps:
+        issues.append(
+            Issue(
+                url=robots_url,
+                type="robots_sitemap_missing",
+                severity="low",
+                **msg("robots.robots_sitemap_missing"),
+            )
+        )
+    return issues
+
+
+async def analyze_robots(base_url: str, client: httpx.AsyncClient) -> RobotsReport:
+    """
+    Fetch, parse, and check a site's robots.txt.
+
+    Args:
+        base_url: Any URL of the site
+        client: HTTP client to use
+
+    Returns:
+        RobotsReport with the parsed rules and any findings
+    """
+    robots_url = urljoin(base_url, "/robots.txt")
+    report = RobotsReport(url=robots_url)
+    try:
+        response = await client.get(robots_url, timeout=10.0, follow_redirects=True)
+    except httpx.HTTPError as e:
+        report.issues.append(
+            Issue(url=robots_url, type="robots_check_error", severity="low", detail=type(e).__name__)
+        )
+        return report
+
+    report.status_code = response.status_code
+    if response.status_code >= 400:
+        report.issues.append(
+            Issue(
+                url=robots_url,
+                type="robots_missing",
+                severity="low",
+                **msg("robots.robots_missing", response.status_code),
+            )
+        )
+        return report
+    if _looks_like_html(response):
+        report.served_as_html = True
+        report.issues.append(
+            Issue(
+                url=robots_url,
+                type="robots_served_as_html",
+                severity="medium",
+                **msg("robots.robots_served_as_html"),
+            )
+        )
+        return report
+
+    report.found = True
+    report.rules = parse_robots(response.text)
+    if report.rules.truncated:
+        logger.warning(f"{robots_url} is larger than {MAX_ROBOTS_BYTES} bytes; the rest is ignored")
+    report.issues.extend(robots_issues(robots_url, report.rules))
+    return report
 
 
 class RobotsAnalyzer:
@@ -31,6 +303,8 @@ class RobotsAnalyzer:
         self.crawl_delay: float | None = None
         self.disallowed_paths: list[str] = []
         self.allowed_paths: list[str] = []
+        self._rules: RobotsRules | None = None
+        self._rules_for: str | None = None
 
     async def fetch_and_parse(self, client: httpx.AsyncClient) -> bool:
         """
@@ -79,7 +353,15 @@ class RobotsAnalyzer:
             # No robots.txt means everything is allowed
             return True
 
-        return self.parser.can_fetch(user_agent, url)
+        return self.rules.can_fetch(url, user_agent)
+
+    @property
+    def rules(self) -> RobotsRules:
+        """Parsed rules (with * and $ support) for the fetched content."""
+        if self._rules is None or self._rules_for != self.content:
+            self._rules = parse_robots(self.content or "")
+            self._rules_for = self.content
+        return self._rules
 
     def _extract_sitemaps(self) -> None:
         """Extract sitemap URLs from robots.txt content."""
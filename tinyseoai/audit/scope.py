@@ -0,0 +1,139 @@
+"""
+Crawl scope options: page/depth limits and URL include/exclude patterns.
+"""
+from __future__ import annotations
+
+import fnmatch
+import re
+from urllib.parse import urlparse
+
+from pydantic import BaseModel, Field, field_validator
+
+# Same default as the audit engines
+DEFAULT_MAX_PAGES = 50
+MAX_PAGES_LIMIT = 10_000
+MAX_DEPTH_LIMIT = 100
+
+# Patterns starting with this prefix are regular expressions, everything else is a glob
+REGEX_PREFIX = "re:"
+
+
+def compile_pattern(pattern: str) -> re.Pattern[str]:
+    """
+    Compile an include/exclude pattern.
+
+    Globs (e.g. ``/blog/*``) are matched against the URL path plus query,
+    ``re:`` patterns are searched anywhere in the full URL.
+
+    Args:
+        pattern: Glob or ``re:``-prefixed regular expression
+
+    Returns:
+        Compiled regular expression
+
+    Raises:
+        ValueError: If the pattern is empty or does not compile
+    """
+    if not pattern or not pattern.strip():
+        raise ValueError("Empty URL pattern")
+
+    if pattern.startswith(REGEX_PREFIX):
+        try:
+            return re.compile(pattern[len(REGEX_PREFIX):])
+        except re.error as e:
+            raise ValueError(f"Invalid regex pattern '{pattern}': {e}") from e
+
+    return re.compile(fnmatch.translate(pattern))
+
+
+def url_matches(url: str, patterns: list[str]) -> bool:
+    """True if the URL matches any of the patterns (see compile_pattern)."""
+    parsed = urlparse(url)
+    path = parsed.path or "/"
+    if parsed.query:
+        path += "?" + parsed.query
+
+    for pattern in patterns:
+        regex = compile_pattern(pattern)
+        if pattern.startswith(REGEX_PREFIX):
+            if regex.search(url):
+                return True
+        elif regex.match(path):
+            return True
+    return False
+
+
+class CrawlOptions(BaseModel):
+    """What a crawl is allowed to visit. Recorded in audit metadata as ``crawl_scope``."""
+
+    max_pages: int = Field(DEFAULT_MAX_PAGES, ge=1, le=MAX_PAGES_LIMIT)
+    max_depth: int | None = Field(None, ge=0, le=MAX_DEPTH_LIMIT)
+    include: list[str] = Field(default_factory=list)
+    exclude: list[str] = Field(default_factory=list)
+    follow_subdomains: bool = False
+    keyword_targets: str | None = None  # TOML or CSV file of target keywords per URL pattern
+
+    @field_validator("include", "exclude")
+    @classmethod
+    def _patterns_compile(cls, patterns: list[str]) -> list[str]:
+        for pattern in patterns:
+            compile_pattern(pattern)
+        return patterns
+
+    def describe(self) -> str:
+        """Return a one-line, human-readable description of the scope for reports."""
+        parts = [f"up to {self.max_pages} pages"]
+        if self.max_depth is not None:
+            parts.append(f"depth ≤ {self.max_depth}")
+        if self.include:
+            parts.append("include " + ", ".join(self.include))
+        if self.exclude:
+            parts.append("exclude " + ", ".join(self.exclude))
+        if self.follow_subdomains:
+            parts.append("subdomains followed")
+        if self.keyword_targets:
+            parts.append(f"keyword targets from {self.keyword_targets}")
+        return "; ".join(parts)
+
+    def host_in_scope(self, url: str, seed_host: str) -> bool:
+        """
+        Check whether a URL's host belongs to the crawl.
+
+        Args:
+            url: Candidate URL
+            seed_host: Host (netloc) of the seed URL
+
+        Returns:
+            True for the seed host, or a subdomain of it when follow_subdomains is on
+        """
+        host = urlparse(url).netloc.lower()
+        seed_host = seed_host.lower()
+        if host == seed_host:
+            return True
+        if not self.follow_subdomains:
+            return False
+
+        base = seed_host[4:] if seed_host.startswith("www.") else seed_host
+        return host == base or host.endswith("." + base)
+
+    def allows(self, url: str, seed_host: str, depth: int = 0) -> bool:
+        """
+        Check whether a discovered URL should be crawled.
+
+        Args:
+            url: Candidate URL
+            seed_host: Host (netloc) of the seed URL
+            depth: Link depth of the URL (seed is 0)
+
+        Returns:
+            True if the URL is within host, depth, and pattern scope
+        """
+        if not self.host_in_scope(url, seed_host):
+            return False
+        if self.max_depth is not None and depth > self.max_depth:
+            return False
+        if self.include and not url_matches(url, self.include):
+            return False
+        if self.exclude and url_matches(url, self.exclude):
+            return False
+        return True
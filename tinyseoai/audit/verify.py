@@ -0,0 +1,215 @@
+"""
+Re-check the HTTP status of pages named in an audit result.
+
+Findings go stale quickly; verify_pages fetches every affected page (and
+every broken-link target) again so fixed pages can be recognized without a
+full re-crawl.
+"""
+from __future__ import annotations
+
+import asyncio
+from urllib.parse import urlparse
+
+import httpx
+from loguru import logger
+from pydantic import BaseModel, Field
+
+from ..data.models import AuditResult, Issue
+from ..utils.http import HttpOptions
+from ..utils.http import client as http_client
+from ..utils.urlnorm import DedupSet
+from .checks.redirects import trace
+from .robots import RobotsReport, analyze_robots
+
+# Issue types that say "this URL does not load"; a 2xx now means fixed
+STATUS_ISSUE_TYPES = frozenset({"http_error", "fetch_error"})
+# Issue types whose detail is the URL of a failing link target
+LINK_TARGET_ISSUE_TYPES = frozenset({"broken_link"})
+AUTO_RESOLVED_KEY = "auto_resolved_issues"
+_RETRY_ERRORS = frozenset({"ReadError", "RemoteProtocolError", "ConnectError"})
+
+
+class VerifyOptions(BaseModel):
+    """Limits for verify_pages."""
+
+    concurrency: int = Field(default=8, ge=1)
+    timeout: float = 10.0  # per request, seconds
+    deadline: float = 120.0  # whole run, seconds
+    max_requests: int = Field(default=500, ge=1)  # distinct URLs checked; the rest are skipped
+    respect_robots: bool = False
+    http: HttpOptions = Field(default_factory=HttpOptions)  # user agent, contact, and TLS
+
+
+class PageStatus(BaseModel):
+    """Outcome for one URL."""
+
+    url: str
+    status_code: int | None = None
+    final_url: str | None = None  # after redirects
+    latency_ms: float | None = None
+    error: str | None = None
+    skipped: str | None = None  # reason the URL was not fetched
+
+    @property
+    def ok(self) -> bool:
+        return self.status_code is not None and 200 <= self.status_code < 300
+
+
+class VerificationReport(BaseModel):
+    """Result of verify_pages."""
+
+    pages: list[PageStatus] = Field(default_factory=list)
+    deadline_exceeded: bool = False
+
+    def by_url(self) -> dict[str, PageStatus]:
+        return {p.url: p for p in self.pages}
+
+    @property
+    def checked(self) -> int:
+        return sum(1 for p in self.pages if p.skipped is None)
+
+
+def affected_urls(result: AuditResult) -> list[str]:
+    """
+    Distinct pages named by issues plus broken-link targets, in first-seen order.
+
+    Spellings of the same page (fragment, tracking parameters, ...) are merged;
+    the first one seen is kept.
+    """
+    urls = DedupSet()
+    for issue in result.issues:
+        if issue.url.startswith(("http://", "https://")):
+            urls.add(issue.url)
+        if issue.type in LINK_TARGET_ISSUE_TYPES and issue.detail and issue.detail.startswith(("http://", "https://")):
+            urls.add(issue.detail)
+    return list(urls)
+
+
+async def fetch_status(client: httpx.AsyncClient, url: str, timeout: float, retry: bool = True) -> PageStatus:
+    """Status of one URL, following redirects; HEAD first, GET if HEAD is refused."""
+    result = await trace(url, client, method="HEAD", timeout=timeout)
+    if result.final_status in (405, 501):  # HEAD not supported
+        result = await trace(url, client, method="GET", timeout=timeout)
+    if result.error in _RETRY_ERRORS and retry:  # connection reset: retry once
+        logger.debug(f"Retrying {url} after {result.error}")
+        return await fetch_status(client, url, timeout, retry=False)
+    if result.error:
+        return PageStatus(url=url, error=result.error, latency_ms=result.latency_ms)
+    return PageStatus(
+        url=url,
+        status_code=result.final_status,
+        final_url=result.final_url,
+        latency_ms=result.latency_ms,
+    )
+
+
+async def _robots_filter(client: httpx.AsyncClient, urls: list[str], user_agent: str) -> set[str]:
+    """URLs disallowed by their host's robots.txt."""
+    reports: dict[str, RobotsReport] = {}
+    blocked = set()
+    for url in urls:
+        parsed = urlparse(url)
+        origin = f"{parsed.scheme}://{parsed.netloc}"
+        if origin not in reports:
+            reports[origin] = await analyze_robots(origin, client)
+        if not reports[origin].can_fetch(url, user_agent):
+            blocked.add(url)
+    return blocked
+
+
+async def verify_pages(
+    result: AuditResult, options: VerifyOptions | None = None, client: httpx.AsyncClient | None = None
+) -> VerificationReport:
+    """
+    Fetch every affected page of an audit again and record its status.
+
+    HEAD is tried first (GET when the server refuses HEAD); connection
+    resets are retried once. URLs beyond max_requests, disallowed by
+    robots.txt, or still pending at the deadline are reported as skipped.
+
+    Args:
+        result: Audit result whose pages to check
+        options: Concurrency, timeouts, and limits
+        client: HTTP client to use (one is created if omitted)
+
+    Returns:
+        VerificationReport with one PageStatus per distinct URL
+    """
+    options = options or VerifyOptions()
+    urls = affected_urls(result)
+    statuses: dict[str, PageStatus] = {}
+    for url in urls[options.max_requests :]:
+        statuses[url] = PageStatus(url=url, skipped="max requests reached")
+
+    own_client = client is None
+    client = client or http_client(options.http)
+    deadline_exceeded = False
+    try:
+        to_check = urls[: options.max_requests]
+        if options.respect_robots:
+            blocked = await _robots_filter(client, to_check, options.http.agent)
+            for url in blocked:
+                statuses[url] = PageStatus(url=url, skipped="disallowed by robots.txt")
+            to_check = [u for u in to_check if u not in blocked]
+
+        semaphore = asyncio.Semaphore(options.concurrency)
+
+        async def check(url: str) -> None:
+            async with semaphore:
+                statuses[url] = await fetch_status(client, url, options.timeout)
+
+        tasks = [asyncio.create_task(check(url)) for url in to_check]
+        if tasks:
+            _, pending = await asyncio.wait(tasks, timeout=options.deadline)
+            for task in pending:
+                task.cancel()
+            if pending:
+                deadline_exceeded = True
+                await asyncio.gather(*pending, return_exceptions=True)
+        for url in to_check:
+            statuses.setdefault(url, PageStatus(url=url, skipped="deadline exceeded"))
+    finally:
+        if own_client:
+            await client.aclose()
+
+    return VerificationReport(pages=[statuses[url] for url in urls], deadline_exceeded=deadline_exceeded)
+
+
+def verified_fixed(result: AuditResult, report: VerificationReport) -> list[Issue]:
+    """
+    Issues the verification shows are fixed.
+
+    These are broken links whose target now answers 2xx, and http/fetch
+    errors on pages that now load.
+    """
+    statuses = report.by_url()
+    fixed = []
+    for issue in result.issues:
+        if issue.type in LINK_TARGET_ISSUE_TYPES:
+            target = statuses.get(issue.detail or "")
+        elif issue.type in STATUS_ISSUE_TYPES:
+            target = statuses.get(issue.url)
+        else:
+            continue
+        if target is not None and target.ok:
+            fixed.append(issue)
+    return fixed
+
+
+def apply_verification(result: AuditResult, report: VerificationReport) -> list[Issue]:
+    """
+    Drop issues the verification shows are fixed.
+
+    Removed issues are kept under meta["auto_resolved_issues"], so a later
+    diff against the previous audit lists them as resolved.
+
+    Returns:
+        The removed issues
+    """
+    fixed = verified_fixed(result, report)
+    if not fixed:
+        return []
+    fixed_ids = {id(i) for i in fixed}
+    result.issues = [i for i in result.issues if id(i) not in fixed_ids]
+    result.meta.setdefault(AUTO_RESOLVED_KEY, []).extend(i.model_dump() for i in fixed)
+    return fixed
@@ -0,0 +1,279 @@
+"""
+Backups of everything tinyseoai keeps on this machine, in one zip file.
+
+A backup holds the history database, the settings (config.json), the check
+thresholds (checks.toml), and a baseline, with a manifest naming the app
+version, the history schema, and when it was made. The database is copied
+with SQLite's backup API, so a backup taken while an audit is recording is
+still consistent. Secrets stay out, as in a config bundle (config_bundle):
+they live in the environment or the system keyring, and the manifest lists
+them as needing re-entry after a restore.
+
+Restoring replaces the local data, or merges into it: the backup's audits,
+comparisons, and probes are added to the local history (audits already there are
+skipped), and the settings, thresholds, and baseline are only restored
+where there are none locally.
+"""
+from __future__ import annotations
+
+import json
+import os
+import tempfile
+import zipfile
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Literal
+
+from platformdirs import user_config_dir
+from pydantic import BaseModel, Field, ValidationError
+
+from .config import APP_NAME, AppConfig
+from .config_bundle import EXCLUDED_SECRETS
+from .data.baseline import Baseline
+from .exceptions import ConfigError
+from .store.history import SCHEMA_VERSION, HistoryStore, MergeResult, default_path
+from .version import package_version
+
+BACKUP_VERSION = 1
+
+MANIFEST = "manifest.json"
+HISTORY = "history.sqlite3"
+CONFIG = "config.json"
+CHECKS = "checks.toml"
+BASELINE = "baseline.json"
+
+RestoreMode = Literal["replace", "merge"]
+
+
+class BackupManifest(BaseModel):
+    """manifest.json of a backup: what made it, when, and what is inside."""
+
+    version: int = BACKUP_VERSION
+    app_version: str
+    created_at: str
+    schema_version: int  # of the history database
+    files: list[str] = Field(default_factory=list)  # archive members besides the manifest
+    needs_reentry: list[str] = Field(default_factory=lambda: list(EXCLUDED_SECRETS))
+    note: str = "Secrets are not included; set them again after restoring."
+
+
+class BackupPaths(BaseModel):
+    """Where the backed-up data lives on this machine."""
+
+    config_dir: Path = Field(default_factory=lambda: Path(user_config_dir(APP_NAME)))
+    history: Path = Field(default_factory=default_path)
+    baseline: Path | None = None  # None: no baseline is backed up or restored
+
+    @property
+    def config(self) -> Path:
+        return self.config_dir / CONFIG
+
+    def checks(self, config: AppConfig | None) -> Path | None:
+        """The checks.toml the config points at; None: it points at none."""
+        if config is None or not config.checks_config:
+            return None
+        return Path(config.checks_config).expanduser()
+
+
+class RestoreResult(BaseModel):
+    """What restore_backup did."""
+
+    manifest: BackupManifest
+    mode: RestoreMode
+    restored: list[str] = Field(default_factory=list)  # files written, e.g. "config.json"
+    kept: list[str] = Field(default_factory=list)  # local files a merge left as they were
+    history: MergeResult | None = None  # None: the history was replaced
+
+
+def _load_config(path: Path) -> AppConfig | None:
+    if not path.exists():
+        return None
+    try:
+        return AppConfig(**json.loads(path.read_text(encoding="utf-8")))
+    except (OSError, ValueError) as e:
+        raise ConfigError(f"Cannot read {path}: {e}") from e
+
+
+def create_backup(dest: Path, paths: BackupPaths | None = None) -> BackupManifest:
+    """
+    Write a backup of the history, settings, thresholds, and baseline to dest.
+
+    The archive is written next to dest first and moved into place, so an
+    interrupted backup never leaves a truncated file behind.
+
+    Args:
+        dest: Zip file to create (replaced if it exists)
+        paths: Where the data lives; default the usual locations, without a baseline
+
+    Returns:
+        The manifest written into the backup
+
+    Raises:
+        StoreError: If the history database cannot be read
+        ConfigError: If the settings cannot be read
+    """
+    paths = paths or BackupPaths()
+    config = _load_config(paths.config)
+    dest.parent.mkdir(parents=True, exist_ok=True)
+    with tempfile.TemporaryDirectory(dir=dest.parent, prefix=".tinyseoai-backup-") as tmp:
+        snapshot = Path(tmp) / HISTORY
+        with HistoryStore(paths.history) as store:
+            store.backup_to(snapshot)
+            schema_version = store.schema_version
+
+        members = {HISTORY: snapshot}
+        if config is not None:
+            members[CONFIG] = paths.config
+        checks = paths.checks(config)
+        if checks is not None and checks.exists():
+            members[CHECKS] = checks
+        if paths.baseline is not None and paths.baseline.exists():
+            members[BASELINE] = paths.baseline
+
+        manifest = BackupManifest(
+            app_version=package_version(),
+            created_at=datetime.now(timezone.utc).isoformat(),
+            schema_version=schema_version,
+            files=sorted(members),
+        )
+        partial = Path(tmp) / dest.name
+        with zipfile.ZipFile(partial, "w", compression=zipfile.ZIP_DEFLATED) as archive:
+            archive.writestr(MANIFEST, manifest.model_dump_json(indent=2) + "\n")
+            for name, source in members.items():
+                archive.write(source, name)
+        os.replace(partial, dest)
+    return manifest
+
+
+def read_manifest(archive: zipfile.ZipFile, path: Path) -> BackupManifest:
+    """
+    The manifest of an open backup, checked against what this version can restore.
+
+    Raises:
+        ConfigError: If the manifest is missing or malformed, a listed file is
+            missing, or the backup is from a newer version
+    """
+    try:
+        data = json.loads(archive.read(MANIFEST))
+    except KeyError as e:
+        raise ConfigError(f"{path} is not a tinyseoai backup: it has no {MANIFEST}") from e
+    except ValueError as e:
+        raise ConfigError(f"{path}: invalid {MANIFEST}: {e}") from e
+    version = data.get("version") if isinstance(data, dict) else None
+    if isinstance(version, int) and version > BACKUP_VERSION:
+        raise ConfigError(
+            f"{path} is a version {version} backup; this tinyseoai reads up to version "
+            f"{BACKUP_VERSION}. Upgrade tinyseoai to restore it."
+        )
+    try:
+        manifest = BackupManifest.model_validate(data)
+    except ValidationError as e:
+        error = e.errors()[0]
+        field = ".".join(map(str, error["loc"])) or "manifest"
+        raise ConfigError(f"{path}: invalid {MANIFEST}: {field}: {error['msg']}") from e
+    if manifest.schema_version > SCHEMA_VERSION:
+        raise ConfigError(
+            f"{path} was made by tinyseoai {manifest.app_version}, whose history database "
+            f"(version {manifest.schema_version}) is newer than this tinyseoai supports "
+            f"({SCHEMA_VERSION}). Upgrade tinyseoai, then restore it."
+        )
+    missing = sorted(set(manifest.files) - set(archive.namelist()))
+    if HISTORY not in manifest.files:
+        missing.append(HISTORY)
+    if missing:
+        raise ConfigError(f"{path} is incomplete: missing {', '.join(missing)}")
+    return manifest
+
+
+def restore_backup(
+    path: Path, mode: RestoreMode = "replace", paths: BackupPaths | None = None
+) -> RestoreResult:
+    """
+    Restore a backup written by create_backup.
+
+    The manifest is checked before anything local is touched. "replace"
+    swaps in the backup's history and writes its settings, thresholds, and
+    baseline over the local ones; "merge" adds the backup's audits,
+    comparisons, and probes to the local history and writes the other files only where
+    none exist. A restored checks.toml goes where the local settings point,
+    else next to config.json, since its original path was on the machine
+    that was backed up.
+
+    Args:
+        path: Zip file written by create_backup
+        mode: "replace" or "merge"
+        paths: Where to restore to; default the usual locations, without a baseline
+
+    Returns:
+        RestoreResult with what was restored and what was kept
+
+    Raises:
+        ConfigError: If the backup is malformed or from a newer version
+        StoreError: If the history database cannot be written
+    """
+    paths = paths or BackupPaths()
+    try:
+        archive = zipfile.ZipFile(path)
+    except (OSError, zipfile.BadZipFile) as e:
+        raise ConfigError(f"Cannot read backup {path}: {e}") from e
+    with archive, tempfile.TemporaryDirectory(prefix=".tinyseoai-restore-") as tmp:
+        manifest = read_manifest(archive, path)
+        result = RestoreResult(manifest=manifest, mode=mode)
+
+        config = None
+        if CONFIG in manifest.files:
+            try:
+                config = AppConfig(**json.loads(archive.read(CONFIG)))
+            except ValueError as e:
+                raise ConfigError(f"{path}: invalid {CONFIG}: {e}") from e
+        baseline = None
+        if BASELINE in manifest.files:
+            try:
+                baseline = Baseline.model_validate_json(archive.read(BASELINE))
+            except ValueError as e:
+                raise ConfigError(f"{path}: invalid {BASELINE}: {e}") from e
+
+        local_config = _load_config(paths.config)
+
+        snapshot = Path(archive.extract(HISTORY, tmp))
+        with HistoryStore(paths.history) as store:
+            if mode == "merge":
+                result.history = store.merge_from(snapshot)
+            else:
+                store.replace_from(snapshot)
+        result.restored.append(HISTORY)
+
+        if config is not None:
+            if mode == "merge" and local_config is not None:
+                result.kept.append(CONFIG)
+            else:
+                # checks_config named a path on the machine that was backed up
+                local_config = config.model_copy(update={"checks_config": None})
+                result.restored.append(CONFIG)
+        if CHECKS in manifest.files and local_config is not None:
+            checks = paths.checks(local_config) or paths.config_dir / CHECKS
+            if mode == "merge" and checks.exists():
+                result.kept.append(CHECKS)
+            else:
+                checks.parent.mkdir(parents=True, exist_ok=True)
+                checks.write_bytes(archive.read(CHECKS))
+                local_config.checks_config = str(checks)
+                result.restored.append(CHECKS)
+        if CONFIG in result.restored or CHECKS in result.restored:
+            _write_config(paths.config, local_config)
+        if baseline is not None and paths.baseline is not None:
+            if mode == "merge" and paths.baseline.exists():
+                result.kept.append(BASELINE)
+            else:
+                baseline.save(paths.baseline)
+                result.restored.append(BASELINE)
+    return result
+
+
+def _write_config(path: Path, config: AppConfig) -> None:
+    path.parent.mkdir(parents=True, exist_ok=True)
+    try:
+        path.write_text(config.model_dump_json(indent=2), encoding="utf-8")
+    except OSError as e:
+        raise ConfigError(f"Cannot write {path}: {e.strerror or e}") from e
+
@@ -4,27 +4,84 @@
 from __future__ import annotations
 
 import asyncio
+import base64
 import json
+import os
 import sys
+import time
+from datetime import date
 from pathlib import Path
 from urllib.parse import urlparse
 
 import bs4
 import httpx
 import typer
-from pydantic import ValidationError
+from pydantic import SecretStr, ValidationError
 from rich.console import Console
+from rich.markup import escape
 from rich.table import Table
 
-from .ai.summarizer import summarize_with_ai
+from .ai.providers import ProviderSettings
+from .ai.summarizer import archive_previous_summary, summarize_with_ai
+from .audit.builtin_checks import default_registry
+from .audit.checks.keywords import KeywordTargets
+from .audit.checks_config import ChecksConfig
+from .audit.comparison import run_comparison
 from .audit.engine import DEFAULT_MAX_PAGES, audit_site
 from .audit.engine_v2 import comprehensive_audit
-from .config import get_config, save_config
+from .audit.reachability import ReachabilityOptions, run_reachability_check
+from .audit.registry import CheckRegistry
+from .audit.scope import CrawlOptions
+from .audit.verify import VerifyOptions, apply_verification, verify_pages
+from .backup import BackupPaths, create_backup, restore_backup
+from .config import SmtpConfig, get_config, save_config
+from .config_bundle import export_config, import_config, shared_checks_path
+from .data.baseline import Baseline, apply_baseline, suppressed_issues, update_baseline
+from .data.comparison import SiteComparison
+from .data.dedupe import Consolidation, Finding, consolidate, finding_counts
+from .data.diagnostics import Diagnostics, format_bytes
+from .data.diff import diff
+from .data.effort import efforts
+from .data.merge import merge as merge_results
+from .data.migrate import migrate, upgrade
 from .data.models import AuditResult
+from .data.policy import Policy
+from .data.reachability import Probe, transitions, uptime_share
+from .data.recommendations import issue_title
+from .data.redact import RedactionOptions, redact_summaries
+from .data.schema import schema as result_schema
+from .data.schema import validate as validate_result
+from .data.score_model import Contribution, ScoreModel, recompute_score
+from .data.score_model import explain as explain_score
+from .data.scoring import HealthScoreCalculator, quick_wins
+from .data.severity import OTHER as OTHER_SEVERITY
+from .data.severity import Severity, count_by_severity
+from .exceptions import ConfigError, IntegrationError, StoreError
+from .i18n import Locale
+from .integrations import smtp
+from .integrations.github_issues import GitHubConfig, GitHubIssues, issue_payload, select_issues
+from .integrations.lighthouse import LighthouseOptions, LighthouseRun, merge_run
+from .integrations.lighthouse import run as run_lighthouse
+from .integrations.pagespeed import PageSpeedResult, enrich, field_summary, stored_results
+from .integrations.pagespeed import fetch as fetch_pagespeed
+from .integrations.smtp import EmailOptions
+from .integrations.webhook import Webhook, WebhookConfig
+from .progress import ProgressEvent, ProgressHook
+from .reporting.bundle import BundleOptions, export_bundle, write_bundle
 from .reporting.excel import write_xlsx
-from .reporting.pdf import write_pdf
-from .utils.io import ensure_dir, write_json
+from .reporting.html_report import HtmlOptions, write_comparison_html, write_html
+from .reporting.junit import JunitOptions, write_junit
+from .reporting.markdown import RenderOptions, write_markdown
+from .reporting.pdf import PdfOptions, export_pdf, write_pdf
+from .reporting.sarif import write_sarif
+from .reporting.tabular import CsvOptions, write_csv_file
+from .reporting.text import TextOptions, render_ansi, write_text
+from .store.history import HistoryFilter, HistoryStore, PortfolioFilter, ProbeFilter
+from .store.snapshots import SnapshotOptions, missing_snapshots
+from .utils.http import HttpOptions
+from .utils.io import Codec, check_codec, ensure_dir, read_json, rewrite_json, write_json, write_json_atomic
 from .utils.url import URLValidationError, validate_url
+from .version import package_version
 
 try:
     from .agents.coordinator import MultiAgentCoordinator
@@ -39,11 +96,346 @@ app = typer.Typer(help="TinySEO AI — Local SEO Audit Agent (Enhanced)")
 console = Console()
 
 
+def _print_version(value: bool) -> None:
+    if value:
+        typer.echo(f"tinyseoai {package_version()}")
+        raise typer.Exit()
+
+
+@app.callback()
+def main(
+    version: bool = typer.Option(
+        False, "--version", callback=_print_version, is_eager=True, help="Print the tinyseoai version and exit"
+    ),
+):
+    """TinySEO AI — Local SEO Audit Agent (Enhanced)"""
+
+
+def _warn_schema_issues(payload: object, source: Path) -> None:
+    """Print path-level schema mismatches of a loaded result when TINYSEOAI_DEBUG is set."""
+    if not os.getenv("TINYSEOAI_DEBUG"):
+        return
+    for issue in validate_result(payload):
+        console.print(f"[yellow]Schema warning ({source.name}):[/] {issue}")
+
+
+def _resolve_crawl_options(
+    url: str,
+    pages: int | None,
+    max_depth: int | None,
+    include: list[str] | None,
+    exclude: list[str] | None,
+    subdomains: bool | None,
+    save_scope: bool,
+    keywords: Path | None = None,
+) -> CrawlOptions:
+    """
+    Merge scope flags over the saved per-site defaults and validate them.
+
+    Flags that were not given (None) keep the saved value for this host, or
+    the CrawlOptions default (DEFAULT_MAX_PAGES pages) when there is none.
+    Exits with code 2 naming the offending value if the scope is invalid.
+    """
+    cfg = get_config()
+    host = urlparse(url).netloc
+    scope = cfg.site_defaults.get(host, CrawlOptions()).model_dump()
+
+    if pages is not None:
+        scope["max_pages"] = pages
+    if max_depth is not None:
+        scope["max_depth"] = max_depth
+    if include:
+        scope["include"] = include
+    if exclude:
+        scope["exclude"] = exclude
+    if subdomains is not None:
+        scope["follow_subdomains"] = subdomains
+    if keywords is not None:
+        scope["keyword_targets"] = str(keywords.expanduser().resolve())
+
+    try:
+        options = CrawlOptions(**scope)
+    except ValidationError as e:
+        for err in e.errors():
+            field = ".".join(str(part) for part in err["loc"])
+            console.print(f"[red]Invalid crawl scope ({field}):[/] {err['msg']}")
+        raise typer.Exit(code=2)
+
+    if save_scope:
+        cfg.site_defaults[host] = options
+        save_config(cfg)
+        console.print(f"[green]Saved crawl scope as default for {host}[/]")
+
+    if cfg.plan == "free" and options.max_pages > DEFAULT_MAX_PAGES:
+        console.print(f"[yellow]Free plan limit {DEFAULT_MAX_PAGES} pages — using {DEFAULT_MAX_PAGES}.[/]")
+        options.max_pages = DEFAULT_MAX_PAGES
+
+    return options
+
+
+def _junit_options(warnings_as: str) -> JunitOptions:
+    """Build JUnit options from CLI flags, exiting with code 2 on bad values."""
+    try:
+        return JunitOptions(warnings_as=warnings_as.lower())
+    except ValidationError:
+        console.print(f"[red]Invalid --junit-warnings:[/] {warnings_as} (use failure or skipped)")
+        raise typer.Exit(code=2)
+
+
+def _check_registry(skip: list[str] | None) -> CheckRegistry:
+    """Built-in checks less those disabled in config or skipped; exits with code 2 on unknown ids."""
+    registry = default_registry()
+    try:
+        registry.disable(*get_config().disabled_checks, *(skip or []))
+    except ValueError as e:
+        console.print(f"[red]Invalid check id:[/] {e}")
+        raise typer.Exit(code=2)
+    return registry
+
+
+def _checks_config(path: Path | None) -> ChecksConfig:
+    """Load --checks-config, else the configured file, else the defaults; exits with code 2 on errors."""
+    configured = get_config().checks_config
+    path = path or (Path(configured).expanduser() if configured else None)
+    if path is None:
+        return ChecksConfig()
+    try:
+        return ChecksConfig.load(path)
+    except ConfigError as e:
+        console.print(f"[red]Invalid checks config:[/] {escape(str(e))}")
+        raise typer.Exit(code=2)
+
+
+def _check_keyword_targets(options: CrawlOptions) -> None:
+    """Load the scope's keyword targets once so a bad file exits with code 2 before the crawl."""
+    if not options.keyword_targets:
+        return
+    try:
+        KeywordTargets.load(Path(options.keyword_targets).expanduser())
+    except ConfigError as e:
+        console.print(f"[red]Invalid keyword targets:[/] {escape(str(e))}")
+        raise typer.Exit(code=2)
+
+
+def _locale(value: str | None) -> Locale:
+    """Parse --locale, else the configured locale; exits with code 2 on an unknown one."""
+    try:
+        return Locale.parse(value or get_config().locale)
+    except ValueError as e:
+        console.print(f"[red]Invalid locale:[/] {escape(str(e))}")
+        raise typer.Exit(code=2)
+
+
+def _consolidation(value: str) -> Consolidation:
+    """Parse --consolidate; exits with code 2 on an unknown policy."""
+    try:
+        return Consolidation(value.lower())
+    except ValueError:
+        console.print(f"[red]Invalid --consolidate:[/] {value} (use exact, aggressive, or off)")
+        raise typer.Exit(code=2)
+
+
+def _compression(value: str | None) -> Codec | None:
+    """Parse --compress; exits with code 2 on an unknown codec, or zstd without a zstd module."""
+    if value is None:
+        return None
+    try:
+        check_codec(value.lower())
+    except ValueError as e:
+        console.print(f"[red]Invalid --compress:[/] {e}")
+        raise typer.Exit(code=2)
+    return value.lower()
+
+
+def _findings_table(findings: list[Finding], limit: int = 10) -> Table:
+    table = Table(title=f"Top findings (first {limit})")
+    table.add_column("Finding", style="magenta")
+    table.add_column("Severity", style="red")
+    table.add_column("Pages", justify="right")
+    table.add_column("Example", style="white", overflow="fold")
+    for finding in findings[:limit]:
+        table.add_row(finding.title, finding.severity, str(len(finding.pages)), finding.pages[0])
+    return table
+
+
+def _keyword_table(keywords: dict) -> Table:
+    pages = len(keywords.get("pages") or [])
+    table = Table(title=f"Keyword coverage ({pages} pages with targets)")
+    table.add_column("Placement", style="cyan")
+    table.add_column("Pages with a target keyword", justify="right")
+    for placement, (covered, present) in keywords["coverage"].items():
+        missing = pages - present
+        note = f" [dim]({missing} without one)[/]" if missing else ""
+        table.add_row(placement.replace("_", " ").capitalize(), f"{covered}/{present}{note}")
+    return table
+
+
+def _snapshot_options(directory: Path | None, max_mb: float, keep: int) -> SnapshotOptions | None:
+    """Build --snapshots options (None without a folder); exits with code 2 on invalid limits."""
+    if directory is None:
+        return None
+    try:
+        return SnapshotOptions(directory=directory, max_bytes=int(max_mb * 1024 * 1024), keep_audits=keep)
+    except ValidationError as e:
+        console.print(f"[red]Invalid snapshot options:[/] {e.errors()[0]['msg']}")
+        raise typer.Exit(code=2)
+
+
+def _redaction(
+    strip_query: bool, mask_hosts: bool, patterns: list[str] | None
+) -> RedactionOptions:
+    """Build the report redaction options; exits with code 2 on an invalid pattern."""
+    try:
+        return RedactionOptions(
+            strip_query_strings=strip_query, mask_hostnames=mask_hosts, custom_patterns=patterns or []
+        )
+    except ValidationError as e:
+        console.print(f"[red]Invalid --redact:[/] {e.errors()[0]['msg']}")
+        raise typer.Exit(code=2)
+
+
+def _http_options(
+    user_agent: str | None,
+    contact: str | None,
+    accept_language: str | None,
+    insecure: bool,
+    basic_auth: str | None = None,
+    cookie: str | None = None,
+    header: list[str] | None = None,
+    auth_host: str | None = None,
+) -> HttpOptions:
+    """Request identity flags over the configured http settings; warns when TLS checks are off."""
+    http = get_config().http.model_copy()
+    if basic_auth:
+        username, sep, password = basic_auth.partition(":")
+        if not sep:
+            console.print("[red]--basic-auth must be user:password[/]")
+            raise typer.Exit(code=2)
+        http.basic_auth = (username, SecretStr(password))
+    if cookie:
+        http.cookie = SecretStr(cookie)
+    for line in header or []:
+        name, sep, value = line.partition(":")
+        if not sep or not name.strip():
+            console.print(f"[red]--header must be 'Name: value', got:[/] {escape(name.strip() or line)}")
+            raise typer.Exit(code=2)
+        http.extra_headers = {**http.extra_headers, name.strip(): SecretStr(value.strip())}
+    if auth_host:
+        http.auth_host = auth_host
+    if user_agent:
+        http.user_agent = user_agent
+    if contact:
+        http.contact = contact
+    if accept_language:
+        http.accept_language = accept_language
+    if insecure:
+        http.verify_tls = False
+    if not http.verify_tls:
+        console.print(
+            "[bold yellow]⚠ TLS certificate verification is OFF[/] — for staging only; "
+            "the result is marked with tls_verification_disabled."
+        )
+    return http
+
+
+def _progress_hook(enabled: bool) -> ProgressHook | None:
+    """A hook writing each progress event as a JSON line to stderr, for --progress-json."""
+    if not enabled:
+        return None
+
+    def write(event: ProgressEvent) -> None:
+        sys.stderr.write(event.to_line() + "\n")
+        sys.stderr.flush()
+
+    return write
+
+
+def _record_history(result: AuditResult) -> None:
+    """Save a result to the local history database."""
+    try:
+        with HistoryStore() as store:
+            audit_id = store.insert(result)
+    except StoreError as e:
+        console.print(f"[yellow]History not saved:[/] {e}")
+        return
+    console.print(f"🗂️ Recorded in history as #{audit_id}")
+
+
+def _diagnostics_table(diagnostics: Diagnostics) -> Table:
+    """What each site-level check did, with its time, requests, and bytes."""
+    table = Table(title="Check diagnostics")
+    table.add_column("Check", style="cyan")
+    table.add_column("Status")
+    table.add_column("Time", justify="right")
+    table.add_column("Requests", justify="right")
+    table.add_column("Downloaded", justify="right")
+    table.add_column("Findings", justify="right")
+    table.add_column("Note", overflow="fold")
+    for run in diagnostics.checks:
+        ran = run.status in ("ran", "failed")
+        table.add_row(
+            run.id,
+            run.status,
+            f"{run.duration_ms:.0f} ms" if ran else "-",
+            str(run.requests) if ran else "-",
+            format_bytes(run.bytes_fetched) if ran else "-",
+            str(run.issues) if ran else "-",
+            escape(run.reason or ""),
+        )
+    table.add_section()
+    table.add_row(
+        "Total",
+        "",
+        f"{diagnostics.duration_ms:.0f} ms",
+        str(diagnostics.requests),
+        format_bytes(diagnostics.bytes_fetched),
+        str(sum(run.issues for run in diagnostics.checks)),
+        "",
+    )
+    return table
+
+
+def _apply_baseline_file(result: AuditResult, path: Path) -> None:
+    """Apply a baseline file to a result in place and print what it did."""
+    try:
+        baseline = Baseline.load(path)
+    except (OSError, ValueError) as e:
+        console.print(f"[red]Invalid baseline ({path}):[/] {e}")
+        raise typer.Exit(code=2)
+    report = apply_baseline(result, baseline)
+    console.print(f"🧊 Baseline: {len(report.suppressed)} issue(s) suppressed")
+    if report.expired:
+        console.print(f"[yellow]Expired baseline entries ignored:[/] {len(report.expired)}")
+    if report.unmatched:
+        console.print(f"[dim]Baseline entries matching no issue (fixed?): {len(report.unmatched)}[/]")
+
+
 @app.command()
 def audit(
     url: str = typer.Argument(..., help="Website to audit (e.g., https://example.com)"),
-    pages: int = typer.Option(DEFAULT_MAX_PAGES, "--pages", "-p", help="Max pages to scan"),
+    pages: int = typer.Option(None, "--pages", "-p", help=f"Max pages to scan (default: the saved scope, else {DEFAULT_MAX_PAGES})"),
     out: Path = typer.Option(Path("reports"), "--out", "-o", help="Output folder"),
+    max_depth: int = typer.Option(None, "--max-depth", help="Max link depth from the start URL"),
+    include: list[str] = typer.Option(None, "--include", help="Only crawl URLs matching this glob (or re:<regex>); repeatable"),
+    exclude: list[str] = typer.Option(None, "--exclude", help="Skip URLs matching this glob (or re:<regex>); repeatable"),
+    subdomains: bool = typer.Option(None, "--subdomains/--no-subdomains", help="Follow links to subdomains"),
+    save_scope: bool = typer.Option(False, "--save-scope", help="Save these scope options as defaults for this site"),
+    sarif: Path = typer.Option(None, "--sarif", help="Also write a SARIF 2.1.0 log to this path (for code scanning)"),
+    junit: Path = typer.Option(None, "--junit", help="Also write a JUnit XML report to this path (for CI)"),
+    junit_warnings: str = typer.Option("failure", "--junit-warnings", help="Report medium issues in JUnit as: failure|skipped"),
+    baseline: Path = typer.Option(None, "--baseline", help="Suppress issues listed in this baseline file"),
+    record: bool = typer.Option(False, "--record", help="Also save the result to the local history database"),
+    user_agent: str = typer.Option(None, "--user-agent", help="User-Agent for every request (default: tinyseoai-audit/<version>)"),
+    contact: str = typer.Option(None, "--contact", help="Contact address sent as the From header"),
+    accept_language: str = typer.Option(None, "--accept-language", help="Accept-Language for every request (e.g. de-DE)"),
+    insecure: bool = typer.Option(False, "--insecure", help="Accept invalid TLS certificates (staging only; flagged in results)"),
+    basic_auth: str = typer.Option(None, "--basic-auth", envvar="TINYSEOAI_BASIC_AUTH", help="user:password for a site behind basic auth (or TINYSEOAI_BASIC_AUTH)"),
+    cookie: str = typer.Option(None, "--cookie", envvar="TINYSEOAI_COOKIE", help="Cookie header sent to the site, e.g. a session (or TINYSEOAI_COOKIE)"),
+    header: list[str] = typer.Option(None, "--header", help="Extra 'Name: value' header sent to the site; repeatable"),
+    auth_host: str = typer.Option(None, "--auth-host", help="Host suffix credentials are sent to (default: the audited host)"),
+    locale: str = typer.Option(None, "--locale", help="Language of issue details and titles: en|es (default: config)"),
+    consolidate_by: str = typer.Option("exact", "--consolidate", help="Group repeated issues into findings: exact|aggressive|off"),
+    compress: str = typer.Option(None, "--compress", help="Write the result JSON compressed: gzip|zstd (adds .gz/.zst)"),
 ):
     """
     Crawl and run basic SEO checks. Writes JSON to reports/<slug>/summary.json
@@ -55,22 +447,28 @@ def audit(
         console.print(f"[red]Invalid URL:[/] {e}")
         raise typer.Exit(code=1)
 
+    options = _resolve_crawl_options(url, pages, max_depth, include, exclude, subdomains, save_scope)
+    policy = _consolidation(consolidate_by)
+    codec = _compression(compress)
     cfg = get_config()
     plan = cfg.plan
-    if plan == "free" and pages > DEFAULT_MAX_PAGES:
-        console.print(f"[yellow]Free plan limit {DEFAULT_MAX_PAGES} pages — using {DEFAULT_MAX_PAGES}.[/]")
-        pages = DEFAULT_MAX_PAGES
 
     console.rule(f"[bold green]TinySEO AI — Audit[/]  [white]({plan.upper()} mode)")
 
-    result: AuditResult = asyncio.run(audit_site(url, max_pages=pages))
+    http = _http_options(
+        user_agent, contact, accept_language, insecure, basic_auth, cookie, header, auth_host
+    )
+    result: AuditResult = asyncio.run(
+        audit_site(url, options=options, http=http, locale=_locale(locale))
+    )
+    if baseline is not None:
+        _apply_baseline_file(result, baseline)
 
     # Prepare output path
     slug = urlparse(result.site).netloc.replace(":", "_")
     target_dir = (out / slug)
     ensure_dir(target_dir)
-    out_json = target_dir / "summary.json"
-    write_json(out_json, json.loads(result.model_dump_json()))
+    out_json = write_json(target_dir / "summary.json", json.loads(result.model_dump_json()), codec)
 
     # Pretty print summary
     table = Table(title=f"Summary — {result.site}")
@@ -78,11 +476,16 @@ def audit(
     table.add_column("Value", style="white")
     table.add_row("Pages scanned", str(result.pages_scanned))
     table.add_row("Issues found", str(len(result.issues)))
+    findings = consolidate(result.issues, policy) if policy is not Consolidation.OFF else []
+    if findings:
+        table.add_row("Distinct findings", str(len(findings)))
     console.print(table)
 
-    # Show top 10 issues
+    # Show top 10 findings, or issues when not consolidating
     top = result.issues[:10]
-    if top:
+    if findings:
+        console.print(_findings_table(findings))
+    elif top:
         it = Table(title="Sample issues (first 10)")
         it.add_column("Type", style="magenta")
         it.add_column("Severity", style="red")
@@ -92,15 +495,50 @@ def audit(
         console.print(it)
 
     console.print(f"📁 Saved: [bold]{out_json}[/]")
+    if record:
+        _record_history(result)
+    if sarif is not None:
+        console.print(f"🔎 SARIF saved → [bold]{write_sarif(result, sarif)}[/]")
+    if junit is not None:
+        path = write_junit(result, junit, _junit_options(junit_warnings))
+        console.print(f"🧪 JUnit saved → [bold]{path}[/]")
 
 
 @app.command("audit-full")
 def audit_full(
     url: str = typer.Argument(..., help="Website to audit (e.g., https://example.com)"),
-    pages: int = typer.Option(DEFAULT_MAX_PAGES, "--pages", "-p", help="Max pages to scan"),
+    pages: int = typer.Option(None, "--pages", "-p", help=f"Max pages to scan (default: the saved scope, else {DEFAULT_MAX_PAGES})"),
     out: Path = typer.Option(Path("reports"), "--out", "-o", help="Output folder"),
     fast: bool = typer.Option(False, "--fast", help="Skip comprehensive checks for faster audit"),
     no_progress: bool = typer.Option(False, "--no-progress", help="Disable progress bar"),
+    progress_json: bool = typer.Option(False, "--progress-json", help="Write progress events as JSON lines to stderr (no bar)"),
+    job_id: str = typer.Option(None, "--job-id", help="Job id set on every progress event"),
+    max_depth: int = typer.Option(None, "--max-depth", help="Max link depth from the start URL"),
+    include: list[str] = typer.Option(None, "--include", help="Only crawl URLs matching this glob (or re:<regex>); repeatable"),
+    exclude: list[str] = typer.Option(None, "--exclude", help="Skip URLs matching this glob (or re:<regex>); repeatable"),
+    subdomains: bool = typer.Option(None, "--subdomains/--no-subdomains", help="Follow links to subdomains"),
+    save_scope: bool = typer.Option(False, "--save-scope", help="Save these scope options as defaults for this site"),
+    keywords: Path = typer.Option(None, "--keywords", help="TOML or CSV file of target keywords per URL pattern (saved with --save-scope)"),
+    record: bool = typer.Option(False, "--record", help="Also save the result to the local history database"),
+    skip_check: list[str] = typer.Option(None, "--skip-check", help="Skip a site-level check by id (see `tinyseoai checks`); repeatable"),
+    checks_config: Path = typer.Option(None, "--checks-config", help="checks.toml with per-check thresholds (see dump-default-config)"),
+    show_diagnostics: bool = typer.Option(False, "--diagnostics", help="Print the time, requests, and bytes of each check"),
+    user_agent: str = typer.Option(None, "--user-agent", help="User-Agent for every request (default: tinyseoai-audit/<version>)"),
+    contact: str = typer.Option(None, "--contact", help="Contact address sent as the From header"),
+    accept_language: str = typer.Option(None, "--accept-language", help="Accept-Language for every request (e.g. de-DE)"),
+    insecure: bool = typer.Option(False, "--insecure", help="Accept invalid TLS certificates (staging only; flagged in results)"),
+    basic_auth: str = typer.Option(None, "--basic-auth", envvar="TINYSEOAI_BASIC_AUTH", help="user:password for a site behind basic auth (or TINYSEOAI_BASIC_AUTH)"),
+    cookie: str = typer.Option(None, "--cookie", envvar="TINYSEOAI_COOKIE", help="Cookie header sent to the site, e.g. a session (or TINYSEOAI_COOKIE)"),
+    header: list[str] = typer.Option(None, "--header", help="Extra 'Name: value' header sent to the site; repeatable"),
+    auth_host: str = typer.Option(None, "--auth-host", help="Host suffix credentials are sent to (default: the audited host)"),
+    locale: str = typer.Option(None, "--locale", help="Language of issue details and titles: en|es (default: config)"),
+    consolidate_by: str = typer.Option("exact", "--consolidate", help="Group repeated issues into findings: exact|aggressive|off"),
+    pagespeed_strategy: list[str] = typer.Option(None, "--pagespeed", help="Add PageSpeed Insights data: mobile | desktop (repeatable)"),
+    snapshot_dir: Path = typer.Option(None, "--snapshots", help="Archive the HTML and headers of pages with findings in this folder"),
+    snapshot_max_mb: float = typer.Option(200.0, "--snapshot-max-mb", help="Size cap of the snapshot archive; oldest audits are evicted"),
+    snapshot_keep: int = typer.Option(5, "--snapshot-keep", help="Audits per site kept in the snapshot archive"),
+    suggest_sitemap: Path = typer.Option(None, "--suggest-sitemap", help="Write a sitemap.xml of the crawled indexable pages to this folder when the site has none"),
+    compress: str = typer.Option(None, "--compress", help="Write the result JSON compressed: gzip|zstd (adds .gz/.zst)"),
 ):
     """
     Comprehensive SEO audit with all checks (security, performance, content quality, etc.)
@@ -112,11 +550,23 @@ def audit_full(
         console.print(f"[red]Invalid URL:[/] {e}")
         raise typer.Exit(code=1)
 
+    options = _resolve_crawl_options(
+        url, pages, max_depth, include, exclude, subdomains, save_scope, keywords
+    )
+    _check_keyword_targets(options)
+    registry = _check_registry(skip_check)
+    checks = _checks_config(checks_config)
+    http = _http_options(
+        user_agent, contact, accept_language, insecure, basic_auth, cookie, header, auth_host
+    )
+    policy = _consolidation(consolidate_by)
+    codec = _compression(compress)
+    strategies = _strategies(pagespeed_strategy) if pagespeed_strategy else []
+    snapshots = _snapshot_options(snapshot_dir, snapshot_max_mb, snapshot_keep)
+    if suggest_sitemap is not None and fast:
+        console.print("[yellow]--fast skips sitemap discovery; no sitemap will be suggested[/]")
     cfg = get_config()
     plan = cfg.plan
-    if plan == "free" and pages > DEFAULT_MAX_PAGES:
-        console.print(f"[yellow]Free plan limit {DEFAULT_MAX_PAGES} pages — using {DEFAULT_MAX_PAGES}.[/]")
-        pages = DEFAULT_MAX_PAGES
 
     console.rule(f"[bold green]TinySEO AI — Comprehensive Audit[/]  [white]({plan.upper()} mode)")
 
@@ -124,15 +574,31 @@ def audit_full(
         console.print("[cyan]Running full audit with all checks (security, performance, content, links)...[/]")
 
     result: AuditResult = asyncio.run(
-        comprehensive_audit(url, max_pages=pages, enable_all_checks=not fast, show_progress=not no_progress)
+        comprehensive_audit(
+            url,
+            enable_all_checks=not fast,
+            show_progress=not (no_progress or progress_json),
+            options=options,
+            registry=registry,
+            checks=checks,
+            http=http,
+            on_progress=_progress_hook(progress_json),
+            job_id=job_id,
+            locale=_locale(locale),
+            snapshots=snapshots,
+            suggest_sitemap=suggest_sitemap,
+        )
     )
+    if strategies and not cfg.pagespeed_api_key:
+        console.print("[yellow]No PageSpeed Insights key (PAGESPEED_API_KEY or the keyring); skipping --pagespeed[/]")
+    elif strategies:
+        _enrich_pagespeed(result, cfg.pagespeed_api_key, strategies, None)
 
     # Prepare output path
     slug = urlparse(result.site).netloc.replace(":", "_")
     target_dir = out / slug
     ensure_dir(target_dir)
-    out_json = target_dir / "comprehensive_summary.json"
-    write_json(out_json, json.loads(result.model_dump_json()))
+    out_json = write_json(target_dir / "comprehensive_summary.json", json.loads(result.model_dump_json()), codec)
 
     # Pretty print summary with health score
     table = Table(title=f"Comprehensive Audit — {result.site}")
@@ -140,6 +606,9 @@ def audit_full(
     table.add_column("Value", style="white")
     table.add_row("Pages scanned", str(result.pages_scanned))
     table.add_row("Total issues", str(len(result.issues)))
+    findings = consolidate(result.issues, policy) if policy is not Consolidation.OFF else []
+    if findings:
+        table.add_row("Distinct findings", str(len(findings)))
 
     # Show health score if available
     if "health_score" in result.meta:
@@ -154,57 +623,126 @@ def audit_full(
     if "sitemaps_found" in result.meta:
         table.add_row("Sitemaps", str(result.meta["sitemaps_found"]))
 
+    if result.meta.get("snapshots"):
+        info = result.meta["snapshots"]
+        table.add_row("Snapshots", f"{info['pages']} pages, {format_bytes(info['bytes'])} ({info['audit_id']})")
+
+    if result.meta.get("suggested_sitemap"):
+        info = result.meta["suggested_sitemap"]
+        table.add_row("Suggested sitemap", f"{info['urls']} URLs in {info['files'][0]}")
+
     console.print(table)
+    if result.meta.get("suggested_sitemap"):
+        console.print(
+            "[dim]No sitemap was found: publish the suggested one at the site root "
+            "and add a Sitemap: line for it to robots.txt.[/]"
+        )
 
     # Show issues by severity
-    severity_counts = {"high": 0, "medium": 0, "low": 0, "info": 0}
-    for issue in result.issues:
-        severity_counts[issue.severity] += 1
+    severity_counts = count_by_severity(issue.severity for issue in result.issues)
+    finding_totals = finding_counts(findings)
 
     if any(severity_counts.values()):
         sev_table = Table(title="Issues by Severity")
         sev_table.add_column("Severity", style="bold")
         sev_table.add_column("Count", style="white")
-
-        if severity_counts["high"] > 0:
-            sev_table.add_row("[red]High[/]", str(severity_counts["high"]))
-        if severity_counts["medium"] > 0:
-            sev_table.add_row("[yellow]Medium[/]", str(severity_counts["medium"]))
-        if severity_counts["low"] > 0:
-            sev_table.add_row("[blue]Low[/]", str(severity_counts["low"]))
-        if severity_counts["info"] > 0:
-            sev_table.add_row("[dim]Info[/]", str(severity_counts["info"]))
+        if findings:
+            sev_table.add_column("Findings", style="white")
+
+        def counts(key: str) -> list[str]:
+            row = [str(severity_counts[key])]
+            return row + [str(finding_totals.get(key, 0))] if findings else row
+
+        for severity in Severity:
+            if severity_counts[severity.value] > 0:
+                sev_table.add_row(
+                    f"[{severity.color}]{severity.value.capitalize()}[/]",
+                    *counts(severity.value),
+                )
+        if severity_counts.get(OTHER_SEVERITY):
+            sev_table.add_row("Other", *counts(OTHER_SEVERITY))
 
         console.print(sev_table)
 
+    if findings:
+        console.print(_findings_table(findings))
+
+    if result.meta.get("keywords"):
+        console.print(_keyword_table(result.meta["keywords"]))
+
+    effort_by_type = efforts(result.issues)
+
     # Show top recommendations if available
     if "top_recommendations" in result.meta and result.meta["top_recommendations"]:
         rec_table = Table(title="Top Priority Fixes")
         rec_table.add_column("Issue Type", style="magenta")
         rec_table.add_column("Impact", style="red")
         rec_table.add_column("Effort", style="yellow")
+        rec_table.add_column("Fix", style="yellow")
         rec_table.add_column("Priority", style="green")
 
         for rec in result.meta["top_recommendations"][:5]:
+            fix = effort_by_type.get(rec["issue_type"])
             rec_table.add_row(
                 rec["issue_type"].replace("_", " ").title(),
                 f"{rec['impact']:.1f}",
                 f"{rec['effort']:.1f}",
+                fix.value if fix else "-",
                 f"{rec['priority']:.1f}"
             )
 
         console.print(rec_table)
 
+    wins = quick_wins(result.issues)
+    if wins:
+        wins_table = Table(title="Quick Wins")
+        wins_table.add_column("Severity", style="bold")
+        wins_table.add_column("Issue", style="magenta")
+        wins_table.add_column("Pages", justify="right")
+        wins_table.add_column("Effort")
+        for win in wins:
+            level = Severity.parse(win["severity"])
+            label = f"[{level.color}]{level.value.capitalize()}[/]" if level else win["severity"]
+            wins_table.add_row(
+                label, issue_title(win["issue_type"]), str(win["pages"]), effort_by_type[win["issue_type"]].value
+            )
+        console.print(wins_table)
+
+    diagnostics = Diagnostics.from_meta(result.meta)
+    if show_diagnostics and diagnostics is not None:
+        console.print(_diagnostics_table(diagnostics))
+
     console.print(f"📁 Saved: [bold]{out_json}[/]")
+    if record:
+        _record_history(result)
 
 
 @app.command("audit-ai")
 def audit_ai(
     url: str = typer.Argument(..., help="Website to audit (e.g., https://example.com)"),
-    pages: int = typer.Option(DEFAULT_MAX_PAGES, "--pages", "-p", help="Max pages to scan"),
+    pages: int = typer.Option(None, "--pages", "-p", help=f"Max pages to scan (default: the saved scope, else {DEFAULT_MAX_PAGES})"),
     out: Path = typer.Option(Path("reports"), "--out", "-o", help="Output folder"),
     no_fixes: bool = typer.Option(False, "--no-fixes", help="Skip code fix generation"),
     no_progress: bool = typer.Option(False, "--no-progress", help="Disable progress bar"),
+    progress_json: bool = typer.Option(False, "--progress-json", help="Write progress events as JSON lines to stderr (no bar)"),
+    job_id: str = typer.Option(None, "--job-id", help="Job id set on every progress event"),
+    max_depth: int = typer.Option(None, "--max-depth", help="Max link depth from the start URL"),
+    include: list[str] = typer.Option(None, "--include", help="Only crawl URLs matching this glob (or re:<regex>); repeatable"),
+    exclude: list[str] = typer.Option(None, "--exclude", help="Skip URLs matching this glob (or re:<regex>); repeatable"),
+    subdomains: bool = typer.Option(None, "--subdomains/--no-subdomains", help="Follow links to subdomains"),
+    save_scope: bool = typer.Option(False, "--save-scope", help="Save these scope options as defaults for this site"),
+    keywords: Path = typer.Option(None, "--keywords", help="TOML or CSV file of target keywords per URL pattern (saved with --save-scope)"),
+    checks_config: Path = typer.Option(None, "--checks-config", help="checks.toml with per-check thresholds (see dump-default-config)"),
+    user_agent: str = typer.Option(None, "--user-agent", help="User-Agent for every request (default: tinyseoai-audit/<version>)"),
+    contact: str = typer.Option(None, "--contact", help="Contact address sent as the From header"),
+    accept_language: str = typer.Option(None, "--accept-language", help="Accept-Language for every request (e.g. de-DE)"),
+    insecure: bool = typer.Option(False, "--insecure", help="Accept invalid TLS certificates (staging only; flagged in results)"),
+    basic_auth: str = typer.Option(None, "--basic-auth", envvar="TINYSEOAI_BASIC_AUTH", help="user:password for a site behind basic auth (or TINYSEOAI_BASIC_AUTH)"),
+    cookie: str = typer.Option(None, "--cookie", envvar="TINYSEOAI_COOKIE", help="Cookie header sent to the site, e.g. a session (or TINYSEOAI_COOKIE)"),
+    header: list[str] = typer.Option(None, "--header", help="Extra 'Name: value' header sent to the site; repeatable"),
+    auth_host: str = typer.Option(None, "--auth-host", help="Host suffix credentials are sent to (default: the audited host)"),
+    locale: str = typer.Option(None, "--locale", help="Language of issue details and titles: en|es (default: config)"),
+    compress: str = typer.Option(None, "--compress", help="Write the result JSON compressed: gzip|zstd (adds .gz/.zst)"),
 ):
     """
     🤖 AI-Powered Multi-Agent SEO Audit
@@ -226,6 +764,7 @@ def audit_ai(
         console.print(f"[red]Invalid URL:[/] {e}")
         raise typer.Exit(code=1)
 
+    codec = _compression(compress)
     cfg = get_config()
 
     if not _MULTI_AGENT_AVAILABLE:
@@ -251,9 +790,10 @@ def audit_ai(
         raise typer.Exit(code=1)
 
     plan = cfg.plan
-    if plan == "free" and pages > DEFAULT_MAX_PAGES:
-        console.print(f"[yellow]Free plan limit {DEFAULT_MAX_PAGES} pages — using {DEFAULT_MAX_PAGES}.[/]")
-        pages = DEFAULT_MAX_PAGES
+    options = _resolve_crawl_options(
+        url, pages, max_depth, include, exclude, subdomains, save_scope, keywords
+    )
+    _check_keyword_targets(options)
 
     console.rule(f"[bold magenta]🤖 TinySEO AI — Multi-Agent Analysis[/]  [white]({plan.upper()} mode)")
     console.print("[cyan]Initializing AI agents...[/]\n")
@@ -261,15 +801,27 @@ def audit_ai(
     # Phase 1: Run comprehensive audit
     console.print("📊 [bold]Phase 1:[/] Running comprehensive SEO audit...")
     result: AuditResult = asyncio.run(
-        comprehensive_audit(url, max_pages=pages, enable_all_checks=True, show_progress=not no_progress)
+        comprehensive_audit(
+            url,
+            enable_all_checks=True,
+            show_progress=not (no_progress or progress_json),
+            on_progress=_progress_hook(progress_json),
+            job_id=job_id,
+            options=options,
+            registry=_check_registry(None),
+            checks=_checks_config(checks_config),
+            http=_http_options(
+                user_agent, contact, accept_language, insecure, basic_auth, cookie, header, auth_host
+            ),
+            locale=_locale(locale),
+        )
     )
 
     # Save base audit results
     slug = urlparse(result.site).netloc.replace(":", "_")
     target_dir = out / slug
     ensure_dir(target_dir)
-    out_json = target_dir / "comprehensive_summary.json"
-    write_json(out_json, json.loads(result.model_dump_json()))
+    out_json = write_json(target_dir / "comprehensive_summary.json", json.loads(result.model_dump_json()), codec)
 
     console.print(f"✅ Audit complete: {len(result.issues)} issues found\n")
 
@@ -367,14 +919,60 @@ def audit_ai(
 def config(
     show: bool = typer.Option(False, "--show", help="Print current config"),
     plan: str = typer.Option(None, "--plan", help="Force plan (free/premium) for local testing"),
+    export: Path = typer.Option(None, "--export", help="Write settings, check thresholds, and the baseline to this file for teammates (no secrets)"),
+    import_from: Path = typer.Option(None, "--import", help="Apply a file written by --export (replaces local settings unless --merge)"),
+    merge: bool = typer.Option(False, "--merge", help="With --import: keep local values, add the file's, and report differences"),
+    baseline: Path = typer.Option(Path(".tinyseoai-baseline.json"), "--baseline", help="Baseline file to export, or to import into"),
 ):
     """
     Manage local config stored at ~/.config/tinyseoai/config.json
     """
     cfg = get_config()
+    if export is not None and import_from is not None:
+        console.print("[red]Use either --export or --import[/]")
+        raise typer.Exit(code=2)
     if plan:
         cfg.plan = plan
         save_config(cfg)
+
+    if export is not None or import_from is not None:
+        local_checks = _checks_config(None) if cfg.checks_config else None
+        try:
+            local_baseline = Baseline.load(baseline) if baseline.exists() else None
+        except ValueError as e:
+            console.print(f"[red]Invalid baseline ({baseline}):[/] {e}")
+            raise typer.Exit(code=2)
+
+    if export is not None:
+        path = export_config(export, cfg, local_checks, local_baseline)
+        console.print(f"📦 Config exported → [bold]{path}[/]")
+        console.print("[dim]API keys, tokens, and HTTP credentials are not included; set them on each machine.[/]")
+
+    if import_from is not None:
+        try:
+            imported = import_config(import_from, cfg, local_checks, local_baseline, merge=merge)
+        except ConfigError as e:
+            console.print(f"[red]Cannot import config:[/] {escape(str(e))}")
+            raise typer.Exit(code=2)
+        cfg = imported.config
+        if imported.checks is not None:
+            checks_path = Path(cfg.checks_config).expanduser() if cfg.checks_config else shared_checks_path()
+            checks_path.parent.mkdir(parents=True, exist_ok=True)
+            checks_path.write_text(imported.checks.dumps(), encoding="utf-8")
+            cfg.checks_config = cfg.checks_config or str(checks_path)
+        if imported.baseline is not None:
+            imported.baseline.save(baseline)
+        save_config(cfg)
+        console.print(f"📥 Config {'merged' if merge else 'imported'} from [bold]{import_from}[/]")
+        if imported.conflicts:
+            table = Table(title="Kept local values (differ from the imported file)")
+            table.add_column("Setting", style="cyan")
+            table.add_column("Local")
+            table.add_column("Imported", style="yellow")
+            for conflict in imported.conflicts:
+                table.add_row(conflict.key, json.dumps(conflict.current), json.dumps(conflict.incoming))
+            console.print(table)
+
     if show or plan:
         console.print(cfg.model_dump_json(indent=2))
 
@@ -385,48 +983,142 @@ def doctor():
     table = Table(title="Environment")
     table.add_column("Check")
     table.add_column("Status")
+    table.add_row("tinyseoai", package_version())
     table.add_row("Python", f"{sys.version.split()[0]}")
     table.add_row("httpx", httpx.__version__)
     table.add_row("BeautifulSoup", getattr(bs4, "__version__", "unknown"))
     console.print(table)
     console.print("[green]OK[/] — try: tinyseoai audit https://example.com")
 
+
+@app.command()
+def checks():
+    """List the site-level checks audit-full runs, by id."""
+    cfg = get_config()
+    thresholds = _checks_config(None)
+    table = Table(title="Site-level checks")
+    table.add_column("ID", style="cyan")
+    table.add_column("Needs")
+    table.add_column("Description")
+    table.add_column("Enabled")
+    for check in default_registry():
+        needs = [name for name, on in check.requires.model_dump().items() if on]
+        on = check.id not in cfg.disabled_checks and thresholds.is_enabled(check.id)
+        enabled = "[green]yes[/]" if on else "[red]no[/]"
+        table.add_row(check.id, ", ".join(needs) or "-", check.description, enabled)
+    console.print(table)
+    console.print("Skip one run with --skip-check ID, or always via disabled_checks in the config file.")
+
+
+@app.command("dump-default-config")
+def dump_default_config(
+    out: Path = typer.Option(None, "--out", "-o", help="Write to this file instead of stdout"),
+):
+    """
+    Print a checks.toml with every check's default thresholds, to edit and pass to --checks-config.
+    """
+    doc = ChecksConfig().dumps()
+    if out is None:
+        typer.echo(doc, nl=False)
+        return
+    out.parent.mkdir(parents=True, exist_ok=True)
+    out.write_text(doc)
+    console.print(f"📁 Saved: [bold]{out}[/]")
+
+
+@app.command()
+def schema(
+    out: Path = typer.Option(None, "--out", "-o", help="Write the schema to this file instead of stdout"),
+):
+    """
+    Print the JSON Schema for audit result files (summary.json, comprehensive_summary.json).
+    """
+    doc = json.dumps(result_schema(), indent=2)
+    if out is None:
+        typer.echo(doc)
+        return
+    out.parent.mkdir(parents=True, exist_ok=True)
+    out.write_text(doc + "\n")
+    console.print(f"📁 Saved: [bold]{out}[/]")
+
 # --- NEW: AI summary command -----------------------------------------------
 
 
+def _ai_settings(provider: str | None, model: str | None, redact_urls: bool) -> ProviderSettings:
+    """The config's AI provider settings with the flags applied; exits with code 2 on an unknown provider."""
+    settings = get_config().ai
+    update: dict = {}
+    if provider and provider.lower() != settings.provider:
+        # The configured model and endpoint belong to the other provider
+        update = {"provider": provider.lower(), "model": None, "base_url": None}
+    if model:
+        update["model"] = model
+    if redact_urls:
+        update["redact_urls"] = True
+    try:
+        return ProviderSettings(**{**settings.model_dump(), **update})
+    except ValidationError:
+        console.print(f"[red]Unknown --ai-provider {provider!r}:[/] use openai, anthropic, or ollama")
+        raise typer.Exit(code=2)
+
+
 @app.command()
 def explain(
     json_report: Path = typer.Argument(..., help="Path to a previous summary.json"),
     out: Path = typer.Option(None, "--out", "-o", help="Output file (default: alongside input)"),
+    language: str = typer.Option(None, "--language", "-l", help="Write the summary in this language (e.g. Spanish)"),
+    tone: str = typer.Option(None, "--tone", help="Tone of the summary (e.g. executive, technical, friendly)"),
+    ai_provider: str = typer.Option(None, "--ai-provider", help="openai | anthropic | ollama (default: the config's ai.provider)"),
+    ai_model: str = typer.Option(None, "--ai-model", help="Model of the provider (default: the config's, or the provider's default)"),
+    redact_urls: bool = typer.Option(False, "--redact-urls", help="Mask hostnames and strip query strings before sending the prompt"),
 ):
     """
-    Use OpenAI to produce an executive summary & recommended actions
-    from an existing crawl JSON (summary.json).
+    Use an AI model (OpenAI, Anthropic, or a local Ollama) to produce an
+    executive summary & recommended actions from an existing crawl JSON
+    (summary.json).
+
+    Re-running replaces the summary without re-crawling; the previous
+    text is kept under "history" in the output file.
     """
     console.rule("[bold blue]AI Summary[/]")
+    settings = _ai_settings(ai_provider, ai_model, redact_urls)
     if not json_report.exists():
         typer.echo(f"File not found: {json_report}")
         raise typer.Exit(code=2)
 
     try:
-        raw = json.loads(json_report.read_text())
-        result = AuditResult(**raw)
-    except (json.JSONDecodeError, ValidationError) as e:
+        raw = read_json(json_report)
+    except (OSError, ValueError) as e:
         typer.echo(f"Invalid report JSON: {e}")
         raise typer.Exit(code=2)
 
+    _warn_schema_issues(raw, json_report)
     try:
-        ai = summarize_with_ai(result)
-    except Exception as e:
-        console.print(f"[red]AI summary failed:[/] {e}")
-        raise typer.Exit(code=1)
+        result = migrate(raw)
+    except ValueError as e:
+        typer.echo(f"Invalid report JSON: {e}")
+        raise typer.Exit(code=2)
 
     # Write output next to input by default
     if out is None:
         out = json_report.parent / "summary_with_ai.json"
 
-    with open(out, "w") as f:
-        json.dump(ai, f, indent=2)
+    previous = None
+    if out.exists():
+        try:
+            previous = json.loads(out.read_text())
+        except json.JSONDecodeError:
+            console.print(f"[yellow]Note:[/] Could not parse {out.name}, previous summary not kept.")
+
+    # On failure we exit before touching the existing summary file
+    try:
+        ai = summarize_with_ai(result, language=language, tone=tone, settings=settings)
+    except Exception as e:
+        console.print(f"[red]AI summary failed:[/] {e}")
+        raise typer.Exit(code=1)
+
+    ai = archive_previous_summary(previous, ai)
+    write_json_atomic(out, ai)
 
     # Console preview
     table = Table(title="Executive Summary")
@@ -437,6 +1129,9 @@ def explain(
     table.add_row("Summary", summary)
     table.add_row("Top issues", str(len(ai.get("top_issues", []))))
     table.add_row("Actions", str(len(ai.get("recommended_actions", []))))
+    table.add_row("Tokens used", str(ai.get("usage", {}).get("total_tokens", 0)))
+    if ai.get("history"):
+        table.add_row("Previous versions", str(len(ai["history"])))
     console.print(table)
 
     console.print(f"🧠 Saved AI summary → [bold]{out}[/]")
@@ -448,21 +1143,44 @@ def explain(
 @app.command()
 def report(
     src: Path = typer.Argument(..., help="Folder containing summary.json OR path to summary.json"),
-    format: str = typer.Option("xlsx", "--format", "-f", help="Report format: xlsx|pdf"),
+    format: str = typer.Option("xlsx", "--format", "-f", help="Report format: xlsx|pdf|md|html|csv|tsv|sarif|junit|txt|bundle"),
     out: Path = typer.Option(None, "--out", "-o", help="Output file path"),
     ai: bool = typer.Option(False, "--with-ai", help="Generate AI summary on the fly (uses your OpenAI key)"),
+    compare: Path = typer.Option(None, "--compare", help="Earlier summary.json to include a 'changes' section (md)"),
+    max_pages_per_issue: int = typer.Option(10, "--max-pages-per-issue", help="Pages listed per issue (md/html/pdf)"),
+    logo: str = typer.Option(None, "--logo", help="Logo for white-label HTML: https:// URL or local image file"),
+    byline: str = typer.Option(None, "--byline", help="Footer byline for white-label HTML (e.g. 'Prepared by Acme')"),
+    explode_pages: bool = typer.Option(False, "--explode-pages", help="One row per issue and page (csv/tsv)"),
+    delimiter: str = typer.Option("comma", "--delimiter", help="CSV delimiter: comma|semicolon|tab"),
+    bom: bool = typer.Option(False, "--bom", help="Write a UTF-8 BOM so Excel detects the encoding (csv/tsv)"),
+    junit_warnings: str = typer.Option("failure", "--junit-warnings", help="Report medium issues as: failure|skipped (junit)"),
+    baseline: Path = typer.Option(None, "--baseline", help="Suppress issues listed in this baseline file"),
+    with_diagnostics: bool = typer.Option(False, "--diagnostics", help="Add the per-check time, requests, and bytes (md)"),
+    locale: str = typer.Option(None, "--locale", help="Language of issue details and titles: en|es (md/html/pdf; default: the audit's)"),
+    score_breakdown: bool = typer.Option(False, "--score-breakdown", help="Add the issue types costing the most points (md/html)"),
+    model: Path = typer.Option(None, "--model", "-m", help="Score model of the breakdown (.toml or .json); defaults to config"),
+    strip_query: bool = typer.Option(False, "--strip-query", help="Remove query strings from every URL in the report"),
+    mask_hosts: bool = typer.Option(False, "--mask-hosts", help="Replace hostnames with host1.example, host2.example, ..."),
+    redact_pattern: list[str] = typer.Option(None, "--redact", help="Replace text matching this regex with [redacted]; repeatable"),
+    include_snapshots: bool = typer.Option(False, "--include-snapshots", help="Link pages to their archived HTML (html; audits run with --snapshots)"),
+    width: int = typer.Option(None, "--width", help="Line width of the text report (txt; default: the terminal's, or 100 in a file)"),
+    color: bool = typer.Option(None, "--color/--no-color", help="ANSI colours in the text report (txt; default: only on a terminal)"),
 ):
     """
-    Build a client-friendly report (XLSX or PDF).
+    Build a client-friendly report (XLSX, PDF, Markdown, standalone HTML, CSV/TSV,
+    plain text, or a zip bundle of several) or a machine-readable SARIF log / JUnit XML report.
+    - With --format txt and no --out, prints the report when stdout is a terminal
     - Reads summary.json
     - If summary_with_ai.json exists OR --with-ai is set, merges AI results under 'ai_summary'
     """
     console.rule("[bold green]Build Report[/]")
+    redaction = _redaction(strip_query, mask_hosts, redact_pattern)
+    if include_snapshots and redaction.active:
+        console.print("[red]--include-snapshots links unredacted pages; drop it from redacted reports[/]")
+        raise typer.Exit(code=2)
 
     # Accept either a folder (containing summary.json) or a direct summary.json path
-    summary_path = src
-    if summary_path.is_dir():
-        summary_path = summary_path / "summary.json"
+    summary_path = _result_path(src, ("summary.json",))
     if not summary_path.exists():
         console.print(f"[red]Missing report input:[/] {summary_path}")
         console.print("Hint: run 'tinyseoai audit <url>' or 'tinyseoai audit-report <url>' first.")
@@ -470,7 +1188,22 @@ def report(
 
     folder = summary_path.parent
 
-    data = json.loads(summary_path.read_text())
+    try:
+        data = read_json(summary_path)
+    except (OSError, ValueError) as e:
+        console.print(f"[red]Invalid report JSON ({summary_path}):[/] {escape(str(e))}")
+        raise typer.Exit(code=2)
+    _warn_schema_issues(data, summary_path)
+    try:
+        data = upgrade(data)
+    except ValueError as e:
+        console.print(f"[red]Invalid report JSON ({summary_path}):[/] {escape(str(e))}")
+        raise typer.Exit(code=2)
+
+    if baseline is not None:
+        baselined = AuditResult(**data)
+        _apply_baseline_file(baselined, baseline)
+        data = json.loads(baselined.to_json())
 
     # Merge AI (cached or live)
     ai_path = folder / "summary_with_ai.json"
@@ -483,17 +1216,25 @@ def report(
 
     if ai or merged_ai is None:
         try:
-            from .data.models import AuditResult
             result = AuditResult(**data)
-            merged_ai = summarize_with_ai(result)
-            ai_path.write_text(json.dumps(merged_ai, indent=2))
+            merged_ai = archive_previous_summary(merged_ai, summarize_with_ai(result))
+            write_json_atomic(ai_path, merged_ai)
         except Exception as e:
             console.print(f"[yellow]AI step skipped:[/] {e}")
 
     if merged_ai:
         data["ai_summary"] = merged_ai
 
+    # Redact last, so the AI summary and the compared audit are covered with the same masks
+    compared = _load_result(compare) if compare is not None else None
+    if redaction.active:
+        others = [json.loads(compared.to_json())] if compared is not None else []
+        data, *others = redact_summaries([data, *others], redaction)
+        compared = AuditResult(**others[0]) if others else None
+
     fmt = format.lower()
+    report_locale = _locale(locale) if locale else None
+    score_model = _score_model(model)
     site_slug = urlparse(data.get("site", "")).netloc or "site"
 
     if fmt == "xlsx":
@@ -504,20 +1245,117 @@ def report(
     elif fmt == "pdf":
         if out is None:
             out = folder / f"{site_slug}-report.pdf"
-        path = write_pdf(data, out)
+        options = PdfOptions(max_pages_per_issue=max_pages_per_issue, locale=report_locale)
+        path = write_pdf(data, out, options)
         console.print(f"🖨️ PDF saved → [bold]{path}[/]")
+    elif fmt == "md":
+        if out is None:
+            out = folder / f"{site_slug}-report.md"
+        options = RenderOptions(
+            max_pages_per_issue=max_pages_per_issue,
+            suppressed=suppressed_issues(AuditResult(**data)) or None,
+            include_diagnostics=with_diagnostics,
+            locale=report_locale,
+            score_breakdown=score_breakdown,
+            score_model=score_model,
+        )
+        if compared is not None:
+            options.diff = diff(compared, AuditResult(**data))
+        path = write_markdown(data, out, options)
+        console.print(f"📝 Markdown saved → [bold]{path}[/]")
+    elif fmt == "html":
+        if out is None:
+            out = folder / f"{site_slug}-report.html"
+        logo_src = logo
+        if logo and not logo.startswith("https://"):
+            logo_path = Path(logo)
+            mime = "image/svg+xml" if logo_path.suffix.lower() == ".svg" else f"image/{logo_path.suffix.lstrip('.').lower()}"
+            logo_src = f"data:{mime};base64,{base64.b64encode(logo_path.read_bytes()).decode('ascii')}"
+        options = HtmlOptions(
+            max_pages_per_issue=max_pages_per_issue,
+            logo_src=logo_src,
+            byline=byline,
+            locale=report_locale,
+            score_breakdown=score_breakdown,
+            score_model=score_model,
+            include_snapshots=include_snapshots,
+        )
+        if include_snapshots:
+            missing = missing_snapshots(AuditResult(**data))
+            if missing:
+                console.print(f"[yellow]{len(missing)} issue(s) link to snapshots that are no longer archived[/]")
+        path = write_html(data, out, options)
+        console.print(f"🌐 HTML saved → [bold]{path}[/]")
+    elif fmt in ("csv", "tsv"):
+        if out is None:
+            out = folder / f"{site_slug}-issues.{fmt}"
+        try:
+            options = CsvOptions(
+                explode_pages=explode_pages,
+                delimiter="tab" if fmt == "tsv" else delimiter,
+                bom=bom,
+            )
+        except ValidationError as e:
+            console.print(f"[red]Invalid CSV options:[/] {e.errors()[0]['msg']}")
+            raise typer.Exit(code=2)
+        path = write_csv_file(AuditResult(**data), out, options)
+        console.print(f"📄 {fmt.upper()} saved → [bold]{path}[/]")
+    elif fmt == "bundle":
+        if out is None:
+            out = folder / f"{site_slug}-report.zip"
+        try:
+            csv_options = CsvOptions(explode_pages=explode_pages, delimiter=delimiter, bom=bom)
+        except ValidationError as e:
+            console.print(f"[red]Invalid CSV options:[/] {e.errors()[0]['msg']}")
+            raise typer.Exit(code=2)
+        options = BundleOptions(max_pages_per_issue=max_pages_per_issue, locale=report_locale, csv=csv_options)
+        write_bundle(data, out, options)
+        console.print(f"🗜️ Bundle saved → [bold]{out}[/]")
+    elif fmt == "sarif":
+        if out is None:
+            out = folder / f"{site_slug}.sarif"
+        path = write_sarif(AuditResult(**data), out)
+        console.print(f"🔎 SARIF saved → [bold]{path}[/]")
+    elif fmt == "junit":
+        if out is None:
+            out = folder / f"{site_slug}-junit.xml"
+        path = write_junit(AuditResult(**data), out, _junit_options(junit_warnings))
+        console.print(f"🧪 JUnit saved → [bold]{path}[/]")
+    elif fmt == "txt":
+        options = TextOptions(
+            max_pages_per_issue=max_pages_per_issue,
+            locale=report_locale,
+            ai=data.get("ai_summary"),
+        )
+        if out is None and sys.stdout.isatty():
+            text_color = True if color is None else color
+            sys.stdout.write(render_ansi(AuditResult(**data), width or console.width, text_color, options))
+        else:
+            if out is None:
+                out = folder / f"{site_slug}-report.txt"
+            path = write_text(AuditResult(**data), out, width or 100, bool(color), options)
+            console.print(f"📃 Text saved → [bold]{path}[/]")
     else:
-        console.print("[red]Unsupported format. Use xlsx or pdf.[/]")
+        console.print("[red]Unsupported format. Use xlsx, pdf, md, html, csv, tsv, sarif, junit, txt, or bundle.[/]")
         raise typer.Exit(code=2)
 
 # --- NEW: one-shot pipeline --------------------------------------------------
 @app.command("audit-report")
 def audit_report(
     url: str = typer.Argument(..., help="Site to audit (e.g., https://example.com)"),
-    pages: int = typer.Option(DEFAULT_MAX_PAGES, "--pages", "-p", help="Max pages to scan"),
+    pages: int = typer.Option(None, "--pages", "-p", help=f"Max pages to scan (default: the saved scope, else {DEFAULT_MAX_PAGES})"),
     outdir: Path = typer.Option(Path("reports"), "--outdir", "-o", help="Reports root folder"),
     format: str = typer.Option("pdf", "--format", "-f", help="Report format: pdf|xlsx"),
     with_ai: bool = typer.Option(True, "--with-ai/--no-ai", help="Include AI summary"),
+    max_depth: int = typer.Option(None, "--max-depth", help="Max link depth from the start URL"),
+    include: list[str] = typer.Option(None, "--include", help="Only crawl URLs matching this glob (or re:<regex>); repeatable"),
+    exclude: list[str] = typer.Option(None, "--exclude", help="Skip URLs matching this glob (or re:<regex>); repeatable"),
+    subdomains: bool = typer.Option(None, "--subdomains/--no-subdomains", help="Follow links to subdomains"),
+    save_scope: bool = typer.Option(False, "--save-scope", help="Save these scope options as defaults for this site"),
+    strip_query: bool = typer.Option(False, "--strip-query", help="Remove query strings from every URL in the report"),
+    mask_hosts: bool = typer.Option(False, "--mask-hosts", help="Replace hostnames with host1.example, host2.example, ..."),
+    redact_pattern: list[str] = typer.Option(None, "--redact", help="Replace text matching this regex with [redacted]; repeatable"),
+    compress: str = typer.Option(None, "--compress", help="Write the result JSON compressed: gzip|zstd (adds .gz/.zst)"),
 ):
     """
     Crawl -> explain (AI) -> export report in one shot.
@@ -530,32 +1368,42 @@ def audit_report(
         console.print(f"[red]Invalid URL:[/] {e}")
         raise typer.Exit(code=1)
 
+    options = _resolve_crawl_options(url, pages, max_depth, include, exclude, subdomains, save_scope)
+    redaction = _redaction(strip_query, mask_hosts, redact_pattern)
+    codec = _compression(compress)
+
     console.rule("[bold green]Audit → Explain → Report[/]")
 
     # 1) audit
-    result: AuditResult = asyncio.run(audit_site(url, max_pages=pages))
+    result: AuditResult = asyncio.run(audit_site(url, options=options))
     slug = urlparse(result.site).netloc.replace(":", "_")
     folder = outdir / slug
     ensure_dir(folder)
-    summary_path = folder / "summary.json"
-    write_json(summary_path, json.loads(result.model_dump_json()))
+    summary_path = write_json(folder / "summary.json", json.loads(result.model_dump_json()), codec)
     console.print(f"✅ Audit saved → [bold]{summary_path}[/]")
 
     # 2) explain (AI)
     merged_ai = None
     if with_ai:
+        ai_path = folder / "summary_with_ai.json"
+        previous = None
+        if ai_path.exists():
+            try:
+                previous = json.loads(ai_path.read_text())
+            except json.JSONDecodeError:
+                console.print("[yellow]Note:[/] Could not parse summary_with_ai.json, previous summary not kept.")
         try:
-            from .ai.summarizer import summarize_with_ai
-            merged_ai = summarize_with_ai(result)
-            (folder / "summary_with_ai.json").write_text(json.dumps(merged_ai, indent=2))
+            merged_ai = archive_previous_summary(previous, summarize_with_ai(result))
+            write_json_atomic(ai_path, merged_ai)
             console.print("🧠 AI summary saved → summary_with_ai.json")
         except Exception as e:
             console.print(f"[yellow]AI step skipped:[/] {e}")
 
     # 3) report
-    data = json.loads(summary_path.read_text())
+    data = read_json(summary_path)
     if merged_ai:
         data["ai_summary"] = merged_ai
+    data = redact_summaries([data], redaction)[0]
 
     site_slug = urlparse(data.get("site", "")).netloc or "site"
     if format.lower() == "pdf":
@@ -571,3 +1419,1145 @@ def audit_report(
     else:
         console.print("[red]Unsupported format. Use pdf or xlsx.[/]")
         raise typer.Exit(code=2)
+
+
+def _result_path(path: Path, names: tuple[str, ...] = ("comprehensive_summary.json", "summary.json")) -> Path:
+    """The summary.json a path names: the file itself, or the one in its folder, plain or compressed."""
+    if path.is_dir():
+        for name in names:
+            for candidate in (name, f"{name}.gz", f"{name}.zst"):
+                if (path / candidate).exists():
+                    return path / candidate
+        return path / names[-1]
+    return path
+
+
+def _load_result(path: Path) -> AuditResult:
+    """Load a summary.json (or its folder) for commands that read existing audits."""
+    path = _result_path(path)
+    if not path.exists():
+        console.print(f"[red]Missing audit file:[/] {path}")
+        raise typer.Exit(code=2)
+    try:
+        data = read_json(path)
+    except (OSError, ValueError) as e:
+        console.print(f"[red]Invalid report JSON ({path}):[/] {e}")
+        raise typer.Exit(code=2)
+    _warn_schema_issues(data, path)
+    try:
+        return migrate(data)
+    except ValueError as e:
+        console.print(f"[red]Invalid report JSON ({path}):[/] {e}")
+        raise typer.Exit(code=2)
+
+
+# --- Compare two audits -------------------------------------------------------
+@app.command()
+def compare(
+    base: Path = typer.Argument(..., help="Earlier summary.json (or its folder)"),
+    head: Path = typer.Argument(..., help="Later summary.json (or its folder)"),
+    out: Path = typer.Option(None, "--out", "-o", help="Write the full diff as JSON"),
+    limit: int = typer.Option(10, "--limit", help="Max issues to list per section"),
+):
+    """
+    Show what changed between two audits: new, resolved, and persisting issues.
+    """
+    base_result = _load_result(base)
+    head_result = _load_result(head)
+    result = diff(base_result, head_result)
+
+    console.rule(f"[bold green]Compare[/]  [white]{result.base_site}")
+
+    table = Table(title="Changes")
+    table.add_column("Metric", style="cyan")
+    table.add_column("Change", style="white")
+    table.add_row("Pages scanned", f"{result.metrics.pages_scanned:+d}")
+    table.add_row("Issues", f"{result.metrics.issues:+d}")
+    if result.metrics.health_score is not None:
+        table.add_row("Health score", f"{result.metrics.health_score:+.1f}")
+    table.add_row("New issues", str(len(result.new)))
+    table.add_row("Resolved issues", str(len(result.resolved)))
+    table.add_row("Persisting issues", str(len(result.persisting)))
+    console.print(table)
+
+    for title, issues, style in (
+        ("New issues", result.new, "red"),
+        ("Resolved issues", result.resolved, "green"),
+    ):
+        if not issues:
+            continue
+        it = Table(title=f"{title} (first {min(limit, len(issues))} of {len(issues)})")
+        it.add_column("Type", style=style)
+        it.add_column("Severity")
+        it.add_column("URL", style="white", overflow="fold")
+        for iss in issues[:limit]:
+            it.add_row(iss.type, iss.severity, iss.url)
+        console.print(it)
+
+    if out:
+        write_json(out, json.loads(result.model_dump_json()))
+        console.print(f"📁 Saved: [bold]{out}[/]")
+
+
+def _comparison_table(comparison: SiteComparison) -> Table:
+    a, b = comparison.a, comparison.b
+    table = Table(title="Side by side")
+    table.add_column("Metric", style="cyan")
+    table.add_column(escape(urlparse(a.site).netloc or a.site), style="white")
+    table.add_column(escape(urlparse(b.site).netloc or b.site), style="white")
+    if a.health_score is not None and b.health_score is not None:
+        table.add_row("Health score", f"{a.health_score:g}", f"{b.health_score:g}")
+    table.add_row("Pages scanned", str(a.pages_scanned), str(b.pages_scanned))
+    table.add_row("Issues", str(a.issues), str(b.issues))
+    table.add_row("Issues per page", f"{a.issues_per_page:g}", f"{b.issues_per_page:g}")
+    table.add_row("High issues", str(a.severities["high"]), str(b.severities["high"]))
+    for verdict in comparison.categories:
+        marks = {"a": ("[green]", "[/]", "", ""), "b": ("", "", "[green]", "[/]")}
+        a_on, a_off, b_on, b_off = marks.get(verdict.winner, ("", "", "", ""))
+        table.add_row(
+            f"{verdict.category.capitalize()} score",
+            f"{a_on}{verdict.a_score}{a_off}",
+            f"{b_on}{verdict.b_score}{b_off}",
+        )
+    return table
+
+
+@app.command("compare-sites")
+def compare_sites_cmd(
+    url_a: str = typer.Argument(..., help="First site, e.g. the prospect (https://example.com)"),
+    url_b: str = typer.Argument(..., help="Second site, e.g. a competitor"),
+    pages: int = typer.Option(None, "--pages", "-p", help=f"Max pages to scan on each site (default: the saved scope, else {DEFAULT_MAX_PAGES})"),
+    max_depth: int = typer.Option(None, "--max-depth", help="Max link depth from each start URL"),
+    fast: bool = typer.Option(False, "--fast", help="Skip comprehensive checks on both sites"),
+    out: Path = typer.Option(Path("reports"), "--out", "-o", help="Output folder"),
+    checks_config: Path = typer.Option(None, "--checks-config", help="checks.toml with per-check thresholds, for both sites"),
+    user_agent: str = typer.Option(None, "--user-agent", help="User-Agent for every request (default: tinyseoai-audit/<version>)"),
+    contact: str = typer.Option(None, "--contact", help="Contact address sent as the From header"),
+    locale: str = typer.Option(None, "--locale", help="Language of issue details and titles: en|es (default: config)"),
+    record: bool = typer.Option(False, "--record", help="Also save the comparison to the local history database"),
+    label: str = typer.Option(None, "--label", help="Label of the recorded comparison, e.g. 'pitch for Acme'"),
+    limit: int = typer.Option(10, "--limit", help="Max issue types to list per site"),
+):
+    """
+    Crawl two sites and compare them: metrics, category scores, and issues only one has.
+
+    Writes both results, comparison.json, and a two-column comparison.html.
+    """
+    urls = []
+    for url in (url_a, url_b):
+        try:
+            urls.append(validate_url(url))
+        except URLValidationError as e:
+            console.print(f"[red]Invalid URL:[/] {e}")
+            raise typer.Exit(code=1)
+    options = [
+        _resolve_crawl_options(url, pages, max_depth, None, None, None, False) for url in urls
+    ]
+    checks = _checks_config(checks_config)
+    http = _http_options(user_agent, contact, None, False)
+    mode = "fast" if fast else "full"
+
+    console.rule("[bold green]TinySEO AI — Compare sites[/]")
+    sites = [
+        f"[bold]{escape(url)}[/] (up to {o.max_pages} pages)"
+        for url, o in zip(urls, options, strict=True)
+    ]
+    console.print(
+        f"[bold yellow]This crawls both sites:[/] {' and '.join(sites)}. "
+        "Each crawl follows robots.txt and the usual request pacing, one site at a time. "
+        "Make sure you may audit a site you do not own."
+    )
+
+    crawled = []
+
+    def started(url: str) -> None:
+        crawled.append(url)
+        console.print(f"🔎 Crawling {escape(url)} ({len(crawled)}/2)...")
+
+    run = asyncio.run(
+        run_comparison(
+            urls[0],
+            urls[1],
+            mode,
+            options_a=options[0],
+            options_b=options[1],
+            checks=checks,
+            http=http,
+            locale=_locale(locale),
+            on_site=started,
+        )
+    )
+    comparison = run.comparison
+
+    slugs = [urlparse(r.site).netloc.replace(":", "_") for r in (run.a, run.b)]
+    target_dir = out / f"{slugs[0]}-vs-{slugs[1]}"
+    ensure_dir(target_dir)
+    for slug, result in zip(slugs, (run.a, run.b), strict=True):
+        write_json(target_dir / f"{slug}.json", json.loads(result.model_dump_json()))
+    write_json(target_dir / "comparison.json", json.loads(comparison.model_dump_json()))
+    html_path = write_comparison_html(
+        comparison, target_dir / "comparison.html", HtmlOptions(locale=_locale(locale))
+    )
+
+    console.print(_comparison_table(comparison))
+    for site, gaps in ((comparison.a.site, comparison.only_a), (comparison.b.site, comparison.only_b)):
+        if not gaps:
+            continue
+        gt = Table(title=f"Only on {escape(site)} (first {min(limit, len(gaps))} of {len(gaps)})")
+        gt.add_column("Issue", style="magenta")
+        gt.add_column("Severity", style="red")
+        gt.add_column("Pages", justify="right")
+        for gap in gaps[:limit]:
+            gt.add_row(gap.title, gap.severity, str(gap.pages))
+        console.print(gt)
+
+    console.print(f"📁 Saved: [bold]{target_dir}[/]")
+    console.print(f"🌐 HTML comparison → [bold]{html_path}[/]")
+    if record:
+        try:
+            with HistoryStore() as store:
+                comparison_id = store.insert_comparison(comparison, label=label)
+        except StoreError as e:
+            console.print(f"[yellow]Could not record the comparison:[/] {e}")
+        else:
+            console.print(f"🗂️ Recorded in history as comparison #{comparison_id}")
+
+
+# --- Merge several audits -----------------------------------------------------
+@app.command("merge")
+def merge_cmd(
+    sources: list[Path] = typer.Argument(..., help="summary.json files (or their folders) to combine"),
+    out: Path = typer.Option(Path("reports/merged_summary.json"), "--out", "-o", help="Output file"),
+    compress: str = typer.Option(None, "--compress", help="Write the result JSON compressed: gzip|zstd (adds .gz/.zst)"),
+):
+    """
+    Combine several audits into one roll-up result (e.g. for a multi-site report).
+    """
+    codec = _compression(compress)
+    results = [_load_result(src) for src in sources]
+    merged = merge_results(results)
+    out = write_json(out, json.loads(merged.model_dump_json()), codec)
+
+    table = Table(title=f"Merged — {len(results)} audits")
+    table.add_column("Site", style="cyan", overflow="fold")
+    table.add_column("Pages", style="white")
+    table.add_column("Issues", style="white")
+    table.add_column("Score", style="white")
+    for site in merged.meta.get("sites", []):
+        score = site.get("health_score")
+        table.add_row(site["site"], str(site["pages_scanned"]), str(site["issues"]), "-" if score is None else str(score))
+    console.print(table)
+
+    if merged.meta.get("merge_conflicts"):
+        console.print(
+            f"[yellow]{len(merged.meta['merge_conflicts'])} duplicate issue(s) disagreed; "
+            "kept the most severe (see meta.merge_conflicts).[/]"
+        )
+    console.print(f"📁 Saved: [bold]{out}[/]")
+
+
+# --- Policy check (CI) ------------------------------------------------------------
+@app.command()
+def check(
+    src: Path = typer.Argument(..., help="summary.json (or its folder) to check"),
+    policy: Path = typer.Option(Path(".tinyseoai-policy.toml"), "--policy", "-p", help="Policy file (TOML)"),
+    compare: Path = typer.Option(None, "--compare", help="Previous summary.json, for fail_on_new_issues"),
+    baseline: Path = typer.Option(None, "--baseline", help="Suppress issues listed in this baseline file first"),
+):
+    """
+    Check an audit against a policy. Exit code 0 = pass, 1 = policy violated, 2 = bad input.
+    """
+    result = _load_result(src)
+    try:
+        rules = Policy.load(policy)
+    except (OSError, ValueError) as e:
+        console.print(f"[red]Invalid policy ({policy}):[/] {e}")
+        raise typer.Exit(code=2)
+    if baseline is not None:
+        _apply_baseline_file(result, baseline)
+
+    changes = diff(_load_result(compare), result) if compare is not None else None
+    verdict = rules.evaluate(result, changes)
+
+    for note in verdict.skipped:
+        console.print(f"[yellow]Skipped:[/] {note}")
+    if verdict.passed:
+        console.print(f"[green]✔ Policy passed[/] — {result.site}")
+        return
+
+    table = Table(title=f"Policy violations — {result.site}")
+    table.add_column("Rule", style="magenta")
+    table.add_column("Actual", style="red")
+    table.add_column("Allowed", style="white")
+    table.add_column("Details", style="white")
+    for v in verdict.violations:
+        table.add_row(v.rule, f"{v.actual:g}", f"{v.allowed:g}", v.message)
+    console.print(table)
+    raise typer.Exit(code=1)
+
+
+# --- Baselines ------------------------------------------------------------------
+@app.command("baseline")
+def baseline_cmd(
+    src: Path = typer.Argument(..., help="summary.json (or its folder) whose issues to accept"),
+    out: Path = typer.Option(Path(".tinyseoai-baseline.json"), "--out", "-o", help="Baseline file to create or update"),
+    reason: str = typer.Option(None, "--reason", help="Why these issues are accepted"),
+    author: str = typer.Option(None, "--author", help="Who accepted them"),
+    expires: str = typer.Option(None, "--expires", help="Stop suppressing after this date (YYYY-MM-DD)"),
+):
+    """
+    Accept the current issues of an audit so later audits only show new ones.
+    """
+    result = _load_result(src)
+    try:
+        expiry = date.fromisoformat(expires) if expires else None
+        existing = Baseline.load(out) if out.exists() else Baseline()
+    except ValueError as e:
+        console.print(f"[red]Invalid baseline input:[/] {e}")
+        raise typer.Exit(code=2)
+
+    updated = update_baseline(existing, result.issues, reason=reason, author=author, expires=expiry)
+    updated.save(out)
+    added = len(updated.entries) - len(existing.entries)
+    console.print(f"🧊 Baseline: added {added}, total {len(updated.entries)} → [bold]{out}[/]")
+
+
+# --- Custom health score ------------------------------------------------------
+def _score_model(path: Path | None) -> ScoreModel | None:
+    """Load --model, else the configured model (None: default score); exits with code 2 on errors."""
+    if path is None:
+        return get_config().score_model
+    try:
+        return ScoreModel.load(path)
+    except (OSError, ValueError) as e:
+        console.print(f"[red]Invalid score model ({path}):[/] {e}")
+        raise typer.Exit(code=2)
+
+
+def _contributions_table(contributions: list[Contribution]) -> Table:
+    table = Table(title="Largest score contributors")
+    table.add_column("Issue type", style="magenta")
+    table.add_column("Issues", justify="right")
+    table.add_column("Weight x page factor", justify="right")
+    table.add_column("Points", justify="right", style="red")
+    for c in contributions:
+        table.add_row(c.issue_type, str(c.issues), c.formula, f"-{c.points:.2f}")
+    return table
+
+
+@app.command()
+def score(
+    src: Path = typer.Argument(..., help="summary.json (or its folder)"),
+    model: Path = typer.Option(None, "--model", "-m", help="Score model file (.toml or .json); defaults to config"),
+    top: int = typer.Option(5, "--top", min=0, help="Issue types listed as the largest score contributors"),
+):
+    """
+    Show a custom health score (from a score model) next to the original score,
+    and the issue types that cost the most points.
+    """
+    result = _load_result(src)
+    score_model = _score_model(model)
+
+    original = result.meta.get("health_score")
+    if original is None:
+        original = HealthScoreCalculator().calculate_health_score(result.issues, result.pages_scanned)["overall_score"]
+
+    table = Table(title=f"Health score — {result.site}")
+    table.add_column("Score", style="cyan")
+    table.add_column("Value", style="white")
+    table.add_row("Original", f"{original}/100")
+    if score_model is None:
+        table.add_row("Custom", "- (no --model and no score_model in config)")
+    else:
+        table.add_row("Custom", f"{recompute_score(result, score_model)}/100")
+    console.print(table)
+
+    contributions = explain_score(result, score_model)[:top]
+    if contributions:
+        console.print(_contributions_table(contributions))
+        gain = sum(c.points for c in contributions[:3])
+        count = min(3, len(contributions))
+        console.print(
+            f"Fixing the top {count} issue type{'s' if count != 1 else ''} gains ~{gain:.0f} points"
+        )
+
+
+@app.command()
+def diagnostics(
+    src: Path = typer.Argument(..., help="summary.json (or its folder) of an audit-full or audit-ai run"),
+):
+    """
+    Show how long each site-level check took, and the requests and bytes it needed.
+    """
+    result = _load_result(src)
+    found = Diagnostics.from_meta(result.meta)
+    if found is None:
+        console.print("[yellow]No diagnostics in this result[/] (recorded by audit-full and audit-ai).")
+        return
+    console.print(_diagnostics_table(found))
+    slowest = found.slowest(1)
+    if slowest:
+        console.print(f"🐢 Slowest: [bold]{slowest[0].id}[/] ({slowest[0].duration_ms:.0f} ms)")
+
+
+# --- Verification -----------------------------------------------------------------
+@app.command()
+def verify(
+    src: Path = typer.Argument(..., help="summary.json (or its folder) whose pages to re-check"),
+    concurrency: int = typer.Option(8, "--concurrency", help="Requests in flight at once"),
+    timeout: float = typer.Option(10.0, "--timeout", help="Per-request timeout in seconds"),
+    deadline: float = typer.Option(120.0, "--deadline", help="Stop after this many seconds"),
+    max_requests: int = typer.Option(500, "--max-requests", help="Check at most this many URLs"),
+    robots: bool = typer.Option(False, "--robots", help="Skip URLs disallowed by robots.txt"),
+    out: Path = typer.Option(None, "--out", "-o", help="Write the result with fixed issues resolved"),
+):
+    """
+    Re-check the HTTP status of every page named in an audit.
+    """
+    result = _load_result(src)
+    try:
+        options = VerifyOptions(
+            concurrency=concurrency,
+            timeout=timeout,
+            deadline=deadline,
+            max_requests=max_requests,
+            respect_robots=robots,
+            http=get_config().http,
+        )
+    except ValidationError as e:
+        console.print(f"[red]Invalid options:[/] {e.errors()[0]['msg']}")
+        raise typer.Exit(code=2)
+
+    report = asyncio.run(verify_pages(result, options))
+
+    table = Table(title=f"Page status — {result.site}")
+    table.add_column("URL", style="cyan")
+    table.add_column("Status")
+    table.add_column("Final URL", style="white")
+    table.add_column("ms", justify="right")
+    for page in report.pages:
+        if page.skipped:
+            status = f"[dim]skipped: {page.skipped}[/]"
+        elif page.error:
+            status = f"[red]{page.error}[/]"
+        else:
+            status = f"[{'green' if page.ok else 'red'}]{page.status_code}[/]"
+        final = page.final_url if page.final_url and page.final_url != page.url else ""
+        latency = f"{page.latency_ms:.0f}" if page.latency_ms is not None else ""
+        table.add_row(page.url, status, final, latency)
+    console.print(table)
+    if report.deadline_exceeded:
+        console.print(f"[yellow]Deadline of {deadline:g}s reached; remaining pages skipped[/]")
+
+    fixed = apply_verification(result, report)
+    console.print(f"✔ {len(fixed)} issue(s) now resolved (broken links and page errors that load again)")
+    if out is not None:
+        write_json(out, json.loads(result.to_json()))
+        console.print(f"📁 Saved: [bold]{out}[/]")
+
+
+# --- Notifications ----------------------------------------------------------------
+@app.command()
+def notify(
+    src: Path = typer.Argument(..., help="summary.json (or its folder) to announce"),
+    webhook: str = typer.Option(
+        None, "--webhook", envvar="TINYSEOAI_WEBHOOK_URL", help="Webhook URL (or TINYSEOAI_WEBHOOK_URL)"
+    ),
+    style: str = typer.Option(None, "--style", help="slack | discord | generic (default: from the URL)"),
+    previous: Path = typer.Option(None, "--previous", help="Previous summary.json, for the score change"),
+    top: int = typer.Option(5, "--top", help="Critical issues to list"),
+    report_url: str = typer.Option(None, "--report-url", help="Link to the full report"),
+    dry_run: bool = typer.Option(False, "--dry-run", help="Print the payload instead of sending it"),
+):
+    """
+    Post an audit summary to a Slack, Discord, or generic JSON webhook.
+    """
+    result = _load_result(src)
+    prior = _load_result(previous) if previous is not None else None
+    if not webhook and not dry_run:
+        console.print("[red]No webhook URL: pass --webhook or set TINYSEOAI_WEBHOOK_URL[/]")
+        raise typer.Exit(code=2)
+    try:
+        config = WebhookConfig(url=webhook or "", style=style, top_n=top, report_url=report_url)
+    except ValidationError as e:
+        console.print(f"[red]Invalid webhook options:[/] {e.errors()[0]['msg']}")
+        raise typer.Exit(code=2)
+
+    try:
+        delivery = asyncio.run(Webhook(config).send(result, prior, dry_run=dry_run))
+    except IntegrationError as e:
+        console.print(f"[red]{e}[/]")
+        raise typer.Exit(code=1)
+    if dry_run:
+        typer.echo(json.dumps(delivery.payload, indent=2, ensure_ascii=False))
+        return
+    console.print(f"📣 Notification sent ({config.resolved_style})")
+
+
+# --- Reachability -----------------------------------------------------------------
+def _probe_table(probes: list[Probe], title: str) -> Table:
+    table = Table(title=title)
+    table.add_column("URL", style="cyan")
+    table.add_column("Status")
+    table.add_column("ms", justify="right")
+    table.add_column("Redirect", style="white")
+    table.add_column("TLS days", justify="right")
+    for probe in probes:
+        status = f"[{'green' if probe.up else 'red'}]{escape(probe.outcome)}[/]"
+        latency = f"{probe.latency_ms:.0f}" if probe.latency_ms is not None else ""
+        tls = "" if probe.tls_days_left is None else str(probe.tls_days_left)
+        if probe.tls_days_left is not None and probe.tls_days_left < 14:
+            tls = f"[yellow]{tls}[/]"
+        table.add_row(probe.url, status, latency, probe.final_url or "", tls)
+    return table
+
+
+@app.command()
+def uptime(
+    urls: list[str] = typer.Argument(..., help="Sites or pages to probe"),
+    every: float = typer.Option(None, "--every", help="Probe again every this many seconds until stopped"),
+    count: int = typer.Option(None, "--count", help="With --every, stop after this many rounds"),
+    timeout: float = typer.Option(5.0, "--timeout", help="Per-request timeout in seconds"),
+    tls: bool = typer.Option(True, "--tls/--no-tls", help="Read certificates for their days left"),
+    record: bool = typer.Option(True, "--record/--no-record", help="Save the samples in the history"),
+    webhook: str = typer.Option(
+        None, "--webhook", envvar="TINYSEOAI_WEBHOOK_URL", help="Notify this webhook when a site goes down or recovers"
+    ),
+    style: str = typer.Option(None, "--style", help="slack | discord | generic (default: from the URL)"),
+):
+    """
+    Check that sites answer: status, latency, redirect, and certificate days left.
+
+    Much cheaper than an audit, so it can run every few minutes (--every, or
+    cron). Samples go to the history ('history uptime'); a site that was up
+    at its previous sample and now fails, or the reverse, is notified.
+    """
+    try:
+        urls = [validate_url(url) for url in urls]
+        options = ReachabilityOptions(timeout=timeout, tls=tls, http=get_config().http)
+        notifier = Webhook(WebhookConfig(url=webhook, style=style)) if webhook else None
+    except URLValidationError as e:
+        console.print(f"[red]Invalid URL:[/] {e}")
+        raise typer.Exit(code=1)
+    except ValidationError as e:
+        console.print(f"[red]Invalid options:[/] {e.errors()[0]['msg']}")
+        raise typer.Exit(code=2)
+    if every is not None and every <= 0:
+        console.print("[red]--every must be a positive number of seconds[/]")
+        raise typer.Exit(code=2)
+
+    store = _open_history() if record else None
+    previous = store.latest_probes(urls) if store else {}
+    probes: list[Probe] = []
+    rounds = 0
+    try:
+        while True:
+            probes = asyncio.run(run_reachability_check(urls, options))
+            if store:
+                store.insert_probes(probes)
+            console.print(_probe_table(probes, f"Reachability — {probes[0].checked_at[:19]}"))
+            changes = transitions(previous, probes)
+            for change in changes:
+                color = "red" if change.kind == "down" else "green"
+                console.print(f"[{color}]{change.url} {change.kind} ({change.probe.outcome})[/]")
+            if changes and notifier:
+                try:
+                    asyncio.run(notifier.send_transitions(changes))
+                except IntegrationError as e:
+                    console.print(f"[red]{e}[/]")
+            previous.update({probe.url: probe for probe in probes})
+            rounds += 1
+            if every is None or (count is not None and rounds >= count):
+                break
+            time.sleep(every)
+    except KeyboardInterrupt:
+        pass
+    finally:
+        if store:
+            store.close()
+    if every is None and not all(probe.up for probe in probes):
+        raise typer.Exit(code=1)
+
+
+# --- PageSpeed Insights ------------------------------------------------------------
+def _strategies(values: list[str] | None) -> list[str]:
+    """Parse --strategy (default: mobile); exits with code 2 on an unknown one."""
+    strategies = [v.lower() for v in values or ["mobile"]]
+    unknown = [s for s in strategies if s not in ("mobile", "desktop")]
+    if unknown:
+        console.print(f"[red]Invalid --strategy:[/] {unknown[0]} (use mobile or desktop)")
+        raise typer.Exit(code=2)
+    return list(dict.fromkeys(strategies))
+
+
+def _pagespeed_table(results: list[PageSpeedResult]) -> Table:
+    table = Table(title="PageSpeed Insights")
+    table.add_column("Strategy", style="cyan")
+    table.add_column("Field data (p75)", style="white")
+    table.add_column("Lab scores", style="white")
+    for psi in results:
+        field = field_summary(psi) or f"[yellow]{escape(psi.error or psi.status)}[/]"
+        scores = ", ".join(f"{name} {score}" for name, score in psi.lab_scores.items())
+        table.add_row(psi.strategy, field, scores or "-")
+    return table
+
+
+def _enrich_pagespeed(result: AuditResult, key: str, strategies: list[str], url: str | None) -> None:
+    """Add PageSpeed Insights data to a result and print it; API problems become info issues."""
+    with console.status("Querying PageSpeed Insights..."):
+        for strategy in strategies:
+            enrich(result, asyncio.run(fetch_pagespeed(url or result.site, key, strategy)))
+    console.print(_pagespeed_table(stored_results(result.meta)))
+
+
+@app.command()
+def pagespeed(
+    src: Path = typer.Argument(..., help="summary.json (or its folder) to enrich"),
+    strategy: list[str] = typer.Option(None, "--strategy", help="mobile | desktop (repeatable; default: mobile)"),
+    url: str = typer.Option(None, "--url", help="Page to analyze (default: the audited site)"),
+    out: Path = typer.Option(None, "--out", "-o", help="Write the enriched result here (default: update SRC)"),
+):
+    """
+    Add Core Web Vitals and Lighthouse scores from PageSpeed Insights to an audit.
+    Key: PAGESPEED_API_KEY or the keyring.
+    """
+    path = _result_path(src)
+    result = _load_result(path)
+    strategies = _strategies(strategy)
+    key = get_config().pagespeed_api_key
+    if not key:
+        console.print("[red]No PageSpeed Insights key: set PAGESPEED_API_KEY or store one with 'keyring set tinyseoai pagespeed'[/]")
+        raise typer.Exit(code=2)
+    _enrich_pagespeed(result, key, strategies, url)
+    if out is None:
+        target = rewrite_json(path, json.loads(result.model_dump_json()))
+    else:
+        target = write_json(out, json.loads(result.model_dump_json()))
+    console.print(f"📁 Saved: [bold]{target}[/]")
+
+
+# --- Lighthouse -------------------------------------------------------------------
+def _lighthouse_table(run: LighthouseRun, limit: int) -> Table:
+    table = Table(title=f"Lighthouse {run.version or ''} ({run.preset}) — {run.url}")
+    table.add_column("Audit", style="magenta")
+    table.add_column("Score", justify="right")
+    table.add_column("Result", style="white")
+    for audit in (run.opportunities + run.diagnostics)[:limit]:
+        table.add_row(audit.title, f"{audit.score * 100:.0f}", audit.display_value or "")
+    return table
+
+
+@app.command()
+def lighthouse(
+    src: Path = typer.Argument(..., help="summary.json (or its folder) to add the run to"),
+    url: str = typer.Option(None, "--url", help="Page to test (default: the audited site)"),
+    preset: str = typer.Option("mobile", "--preset", help="mobile | desktop"),
+    binary: str = typer.Option(None, "--binary", help="lighthouse executable (default: lighthouse_path in the config, else PATH)"),
+    timeout: float = typer.Option(180.0, "--timeout", help="Seconds before the run is stopped"),
+    top: int = typer.Option(10, "--top", help="Failed audits to list"),
+    out: Path = typer.Option(None, "--out", "-o", help="Write the result here (default: update SRC)"),
+):
+    """
+    Run a locally installed Lighthouse CLI and add its failed audits to an audit.
+    """
+    path = _result_path(src)
+    result = _load_result(path)
+    try:
+        options = LighthouseOptions(
+            binary=binary or get_config().lighthouse_path, preset=preset.lower(), timeout=timeout
+        )
+    except ValidationError as e:
+        console.print(f"[red]Invalid options:[/] {e.errors()[0]['msg']}")
+        raise typer.Exit(code=2)
+
+    with console.status("Running Lighthouse..."):
+        run = run_lighthouse(url or result.site, options)
+    merge_run(result, run)
+    if run.status != "ok":
+        console.print(f"[yellow]Lighthouse did not run: {escape(run.error or run.status)}[/]")
+    else:
+        scores = ", ".join(f"{name} {score}" for name, score in run.scores.items())
+        console.print(f"🔦 Scores: {scores}")
+        console.print(_lighthouse_table(run, top))
+    if out is None:
+        target = rewrite_json(path, json.loads(result.model_dump_json()))
+    else:
+        target = write_json(out, json.loads(result.model_dump_json()))
+    console.print(f"📁 Saved: [bold]{target}[/]")
+
+
+@app.command("github-issues")
+def github_issues(
+    src: Path = typer.Argument(..., help="summary.json (or its folder) with the findings"),
+    repo: str = typer.Option(..., "--repo", help="GitHub repository, owner/name"),
+    compare: Path = typer.Option(None, "--compare", help="Previous summary.json; only file new findings"),
+    min_severity: str = typer.Option("high", "--min-severity", help="Lowest severity to file"),
+    label: list[str] = typer.Option(None, "--label", help="Extra label (repeatable)"),
+    dry_run: bool = typer.Option(False, "--dry-run", help="List what would be filed without calling GitHub"),
+):
+    """
+    Create or update GitHub issues for audit findings. Token: GITHUB_TOKEN or the keyring.
+    """
+    result = _load_result(src)
+    findings = diff(_load_result(compare), result).new if compare is not None else result.issues
+    try:
+        selected = select_issues(findings, min_severity)
+    except ValueError as e:
+        console.print(f"[red]{e}[/]")
+        raise typer.Exit(code=2)
+    if not selected:
+        console.print("✔ Nothing to file")
+        return
+    if dry_run:
+        for issue in selected:
+            console.print(f"- {issue_payload(issue, label)['title']}")
+        return
+
+    token = get_config().github_token
+    if not token:
+        console.print("[red]No GitHub token: set GITHUB_TOKEN or store one with 'keyring set tinyseoai github'[/]")
+        raise typer.Exit(code=2)
+    try:
+        filer = GitHubIssues(GitHubConfig(repo=repo, token=token, extra_labels=label or []))
+        report = asyncio.run(filer.file(selected))
+    except IntegrationError as e:
+        console.print(f"[red]{e}[/]")
+        raise typer.Exit(code=1)
+
+    table = Table(title=f"GitHub issues — {repo}")
+    table.add_column("Finding", style="white")
+    table.add_column("Result", style="magenta")
+    table.add_column("Issue", style="cyan")
+    for r in report.results:
+        table.add_row(r.title, r.action if r.error is None else f"{r.action}: {r.error}", r.html_url or "")
+    console.print(table)
+    if not report.ok:
+        raise typer.Exit(code=1)
+
+
+# --- History ----------------------------------------------------------------------
+history_app = typer.Typer(help="Browse audits saved with --record or 'history add'")
+app.add_typer(history_app, name="history")
+
+
+def _open_history() -> HistoryStore:
+    try:
+        return HistoryStore()
+    except StoreError as e:
+        console.print(f"[red]{e}[/]")
+        raise typer.Exit(code=2)
+
+
+@history_app.command("add")
+def history_add(
+    src: Path = typer.Argument(..., help="summary.json (or its folder) to store"),
+    label: str = typer.Option(None, "--label", help="Free-text label, e.g. 'after redesign'"),
+):
+    """Save an existing audit result to the history."""
+    result = _load_result(src)
+    with _open_history() as store:
+        audit_id = store.insert(result, label=label)
+    console.print(f"🗂️ Recorded in history as #{audit_id}")
+
+
+@history_app.command("import")
+def history_import(
+    paths: list[Path] = typer.Argument(..., help="Result JSON files, or folders to search for *.json"),
+    label: str = typer.Option(None, "--label", help="Label for every imported audit"),
+):
+    """Import audit results saved earlier, of any format version, skipping ones already stored."""
+    with _open_history() as store, console.status("Importing...") as status:
+
+        def progress(done: int, total: int) -> None:
+            status.update(f"Importing {done}/{total}...")
+
+        outcomes = store.import_files(paths, label=label, on_progress=progress)
+
+    table = Table(title="Import")
+    for column in ("File", "Status", "ID", "Reason"):
+        table.add_column(column)
+    styles = {"imported": "green", "duplicate": "yellow", "failed": "red"}
+    for outcome in outcomes:
+        table.add_row(
+            escape(outcome.path),
+            f"[{styles[outcome.status]}]{outcome.status}[/]",
+            "" if outcome.audit_id is None else str(outcome.audit_id),
+            escape(outcome.reason or ""),
+        )
+    console.print(table)
+    counts = {status: sum(o.status == status for o in outcomes) for status in styles}
+    console.print(
+        f"🗂️ {counts['imported']} imported, {counts['duplicate']} already in history, "
+        f"{counts['failed']} failed"
+    )
+    if counts["failed"]:
+        raise typer.Exit(code=1)
+
+
+@history_app.command("list")
+def history_list(
+    site: str = typer.Option(None, "--site", help="Only this host (e.g. example.com)"),
+    limit: int = typer.Option(20, "--limit", help="Max entries to show"),
+):
+    """List saved audits, newest first."""
+    with _open_history() as store:
+        rows = store.list(HistoryFilter(host=site, limit=limit))
+    table = Table(title="Audit history")
+    for column in ("ID", "Date", "Site", "Pages", "Issues", "High", "Score", "Label", "Origin"):
+        table.add_column(column)
+    for row in rows:
+        table.add_row(
+            str(row.id),
+            row.created_at[:19],
+            row.host,
+            str(row.pages_scanned),
+            str(row.issues),
+            str(row.high),
+            "-" if row.health_score is None else f"{row.health_score:g}",
+            row.label or "",
+            row.origin or "",
+        )
+    console.print(table)
+
+
+@history_app.command("portfolio")
+def history_portfolio(
+    site: str = typer.Option(None, "--site", help="Only this host (e.g. example.com)"),
+    limit: int = typer.Option(20, "--limit", help="Max sites to show"),
+    offset: int = typer.Option(0, "--offset", min=0, help="Skip this many sites (for paging)"),
+):
+    """Show the latest audit of every site, worst first."""
+    with _open_history() as store:
+        entries = store.portfolio(PortfolioFilter(host=site, limit=limit, offset=offset))
+    table = Table(title="Portfolio")
+    for column in ("Site", "Score", "Change", "High", "New high", "Last audited", "Audits", "ID"):
+        table.add_column(column)
+    for entry in entries:
+        delta = "-" if entry.score_delta is None else f"{entry.score_delta:+g}"
+        if entry.score_delta is not None and entry.score_delta < 0:
+            delta = f"[red]{delta}[/]"
+        new_high = "-" if entry.new_high is None else str(entry.new_high)
+        if entry.new_high:
+            new_high = f"[red]{new_high}[/]"
+        table.add_row(
+            entry.host,
+            "-" if entry.health_score is None else f"{entry.health_score:g}",
+            delta,
+            str(entry.high),
+            new_high,
+            entry.last_audited[:19],
+            str(entry.audits),
+            str(entry.audit_id),
+        )
+    console.print(table)
+
+
+@history_app.command("show")
+def history_show(
+    audit_id: int = typer.Argument(..., help="History ID"),
+    out: Path = typer.Option(None, "--out", "-o", help="Write the stored result as JSON"),
+    compress: str = typer.Option(None, "--compress", help="With --out: write it compressed: gzip|zstd (adds .gz/.zst)"),
+):
+    """Print or export a saved audit."""
+    codec = _compression(compress)
+    with _open_history() as store:
+        try:
+            result = store.get(audit_id)
+        except StoreError as e:
+            console.print(f"[red]{e}[/]")
+            raise typer.Exit(code=2)
+    if out is None:
+        typer.echo(result.to_json())
+        return
+    out = write_json(out, json.loads(result.to_json()), codec)
+    console.print(f"📁 Saved: [bold]{out}[/]")
+
+
+@history_app.command("pdf")
+def history_pdf(
+    audit_id: int = typer.Argument(..., help="History ID"),
+    out: Path = typer.Option(None, "--out", "-o", help="PDF path (default: audit-<id>-report.pdf)"),
+    max_pages_per_issue: int = typer.Option(None, "--max-pages-per-issue", min=1, help="Pages listed per issue (default: all)"),
+    locale: str = typer.Option(None, "--locale", help="Language of issue details and titles: en|es (default: the audit's)"),
+):
+    """Export a saved audit as a paginated PDF report."""
+    options = PdfOptions(
+        max_pages_per_issue=max_pages_per_issue,
+        locale=_locale(locale) if locale else None,
+    )
+    with _open_history() as store:
+        try:
+            path = export_pdf(audit_id, out or Path(f"audit-{audit_id}-report.pdf"), options, store=store)
+        except StoreError as e:
+            console.print(f"[red]{e}[/]")
+            raise typer.Exit(code=2)
+        except RuntimeError as e:
+            console.print(f"[red]{escape(str(e))}[/]")
+            raise typer.Exit(code=1)
+    console.print(f"🖨️ PDF saved → [bold]{path}[/]")
+
+
+@history_app.command("bundle")
+def history_bundle(
+    audit_id: int = typer.Argument(..., help="History ID"),
+    out: Path = typer.Option(None, "--out", "-o", help="Zip path (default: audit-<id>-bundle.zip)"),
+    max_pages_per_issue: int = typer.Option(10, "--max-pages-per-issue", min=1, help="Pages listed per issue (html/md)"),
+    locale: str = typer.Option(None, "--locale", help="Language of issue details and titles: en|es (default: the audit's)"),
+):
+    """Export a saved audit as one zip: result JSON, HTML, CSV, Markdown, and a manifest."""
+    options = BundleOptions(
+        max_pages_per_issue=max_pages_per_issue,
+        locale=_locale(locale) if locale else None,
+    )
+    path = out or Path(f"audit-{audit_id}-bundle.zip")
+    with _open_history() as store:
+        try:
+            export_bundle(audit_id, path, options, store=store)
+        except StoreError as e:
+            console.print(f"[red]{e}[/]")
+            raise typer.Exit(code=2)
+    console.print(f"🗜️ Bundle saved → [bold]{path}[/]")
+
+
+@history_app.command("trend")
+def history_trend(site: str = typer.Argument(..., help="Host (e.g. example.com)")):
+    """Show how a site's health score changed over time."""
+    with _open_history() as store:
+        points = store.trend(site)
+    table = Table(title=f"Score trend — {site}")
+    table.add_column("ID")
+    table.add_column("Date")
+    table.add_column("Score")
+    table.add_column("Issues")
+    for point in points:
+        score = "-" if point.health_score is None else f"{point.health_score:g}"
+        table.add_row(str(point.id), point.created_at[:19], score, str(point.issues))
+    console.print(table)
+
+
+@history_app.command("uptime")
+def history_uptime(
+    site: str = typer.Argument(None, help="URL or host (default: every probed URL)"),
+    since: str = typer.Option(None, "--since", help="Only samples from this ISO date or timestamp on"),
+    limit: int = typer.Option(None, "--limit", help="Only the newest samples"),
+    samples: bool = typer.Option(False, "--samples", help="List every sample instead of per-URL uptime"),
+):
+    """Show reachability samples saved by 'uptime', or the uptime of each URL."""
+    if site is None:
+        filter = ProbeFilter(since=since, limit=limit)
+    elif "://" in site:
+        filter = ProbeFilter(url=site, since=since, limit=limit)
+    else:
+        filter = ProbeFilter(host=site, since=since, limit=limit)
+    with _open_history() as store:
+        probes = store.probes(filter)
+    if not probes:
+        console.print("No reachability samples; run 'tinyseoai uptime URL' first")
+        return
+    if samples:
+        console.print(_probe_table(probes, "Reachability samples"))
+        return
+    by_url: dict[str, list[Probe]] = {}
+    for probe in probes:
+        by_url.setdefault(probe.url, []).append(probe)
+    table = Table(title="Uptime")
+    for column in ("URL", "Samples", "Uptime", "Median ms", "Last", "Since"):
+        table.add_column(column)
+    for url, rows in by_url.items():
+        latencies = sorted(p.latency_ms for p in rows if p.up and p.latency_ms is not None)
+        median = f"{latencies[len(latencies) // 2]:.0f}" if latencies else ""
+        last = rows[-1]
+        status = f"[{'green' if last.up else 'red'}]{escape(last.outcome)}[/]"
+        table.add_row(url, str(len(rows)), f"{uptime_share(rows):g}%", median, status, rows[0].checked_at[:19])
+    console.print(table)
+
+
+@history_app.command("comparisons")
+def history_comparisons(limit: int = typer.Option(20, "--limit", help="Max entries to show")):
+    """List saved site comparisons (compare-sites --record), newest first."""
+    with _open_history() as store:
+        rows = store.comparisons(limit)
+    table = Table(title="Comparisons")
+    for column in ("ID", "Date", "Site A", "Site B", "Mode", "Label"):
+        table.add_column(column)
+    for row in rows:
+        table.add_row(
+            str(row.id), row.created_at[:19], row.site_a, row.site_b, row.mode, row.label or ""
+        )
+    console.print(table)
+
+
+@history_app.command("comparison")
+def history_comparison(
+    comparison_id: int = typer.Argument(..., help="Comparison ID (see 'history comparisons')"),
+    out: Path = typer.Option(None, "--out", "-o", help="Write the comparison as JSON"),
+    html: Path = typer.Option(None, "--html", help="Write the two-column HTML report"),
+):
+    """Print or export a saved site comparison."""
+    with _open_history() as store:
+        try:
+            comparison = store.get_comparison(comparison_id)
+        except StoreError as e:
+            console.print(f"[red]{e}[/]")
+            raise typer.Exit(code=2)
+    if out is None and html is None:
+        console.print(_comparison_table(comparison))
+        return
+    if out is not None:
+        write_json(out, json.loads(comparison.model_dump_json()))
+        console.print(f"📁 Saved: [bold]{out}[/]")
+    if html is not None:
+        console.print(f"🌐 HTML comparison → [bold]{write_comparison_html(comparison, html)}[/]")
+
+
+@history_app.command("delete")
+def history_delete(audit_id: int = typer.Argument(..., help="History ID")):
+    """Delete a saved audit."""
+    with _open_history() as store:
+        deleted = store.delete(audit_id)
+    if not deleted:
+        console.print(f"[red]No audit with id {audit_id}[/]")
+        raise typer.Exit(code=2)
+    console.print(f"🗑️ Deleted #{audit_id}")
+
+
+# --- Email -------------------------------------------------------------------------
+email_app = typer.Typer(help="Email reports of saved audits over SMTP")
+app.add_typer(email_app, name="email")
+
+
+def _smtp_config() -> SmtpConfig:
+    cfg = get_config()
+    if cfg.smtp is None:
+        console.print("[red]No mail server set up: run 'tinyseoai email setup' first[/]")
+        raise typer.Exit(code=2)
+    return cfg.smtp
+
+
+@email_app.command("setup")
+def email_setup(
+    host: str = typer.Option(..., "--host", help="SMTP server (e.g. smtp.example.com)"),
+    sender: str = typer.Option(..., "--from", help="From address of the emails"),
+    port: int = typer.Option(587, "--port", help="Server port (587 for starttls, 465 for ssl)"),
+    tls: str = typer.Option("starttls", "--tls", help="starttls | ssl | none"),
+    username: str = typer.Option(None, "--username", help="Login name; the password is read from the keyring"),
+):
+    """
+    Save the mail server settings. Password: TINYSEOAI_SMTP_PASSWORD or 'keyring set tinyseoai smtp'.
+    """
+    try:
+        settings = SmtpConfig(host=host, port=port, tls=tls.lower(), username=username, from_address=sender)
+    except ValidationError as e:
+        console.print(f"[red]Invalid mail settings:[/] {e.errors()[0]['msg']}")
+        raise typer.Exit(code=2)
+    cfg = get_config()
+    cfg.smtp = settings
+    save_config(cfg)
+    console.print(f"📮 Mail server saved: {host}:{port} ({settings.tls}). Check it with 'tinyseoai email test'.")
+
+
+@email_app.command("test")
+def email_test():
+    """Connect and log in to the mail server without sending anything."""
+    settings = _smtp_config()
+    try:
+        smtp.test_smtp_settings(settings, get_config().smtp_password)
+    except IntegrationError as e:
+        console.print(f"[red]{escape(str(e))}[/]")
+        raise typer.Exit(code=1)
+    console.print(f"[green]OK[/] — {settings.host}:{settings.port} accepted the connection")
+
+
+@email_app.command("send")
+def email_send(
+    audit_id: int = typer.Argument(..., help="History ID"),
+    to: list[str] = typer.Option(..., "--to", help="Recipient; repeatable"),
+    format: str = typer.Option("html", "--format", "-f", help="html (report in the body) | pdf (attached)"),
+    message: str = typer.Option(None, "--message", "-m", help="Note shown above the report"),
+    subject: str = typer.Option(None, "--subject", help="Subject (default: site and score)"),
+    pdf: Path = typer.Option(None, "--pdf", help="Attach this PDF instead of rendering one (pdf)"),
+):
+    """Email the report of a saved audit; every attempt is logged (see 'email log')."""
+    settings = _smtp_config()
+    try:
+        options = EmailOptions(recipients=to, format=format.lower(), message=message, subject=subject, pdf_path=pdf)
+    except ValidationError as e:
+        console.print(f"[red]Invalid email options:[/] {e.errors()[0]['msg']}")
+        raise typer.Exit(code=2)
+    with _open_history() as store:
+        try:
+            smtp.send_report_email(audit_id, options, settings, get_config().smtp_password, store)
+        except StoreError as e:
+            console.print(f"[red]{e}[/]")
+            raise typer.Exit(code=2)
+        except IntegrationError as e:
+            console.print(f"[red]{escape(str(e))}[/]")
+            raise typer.Exit(code=1)
+    console.print(f"📧 Report of #{audit_id} sent to {', '.join(to)}")
+
+
+@email_app.command("log")
+def email_log(audit_id: int = typer.Argument(..., help="History ID")):
+    """List the delivery attempts of a saved audit."""
+    with _open_history() as store:
+        deliveries = store.deliveries(audit_id)
+    table = Table(title=f"Deliveries of #{audit_id}")
+    for column in ("Date", "Channel", "Recipients", "Result"):
+        table.add_column(column)
+    for delivery in deliveries:
+        status = "[green]sent[/]" if delivery.ok else f"[red]{escape(delivery.error or '')}[/]"
+        table.add_row(delivery.created_at[:19], delivery.channel, ", ".join(delivery.recipients), status)
+    console.print(table)
+
+
+# --- Backup ------------------------------------------------------------------------
+backup_app = typer.Typer(help="Back up and restore the history, settings, and baseline")
+app.add_typer(backup_app, name="backup")
+
+
+@backup_app.command("create")
+def backup_create(
+    dest: Path = typer.Argument(..., help="Zip file to write, e.g. tinyseoai-backup.zip"),
+    baseline: Path = typer.Option(Path(".tinyseoai-baseline.json"), "--baseline", help="Baseline file to include, if it exists"),
+):
+    """Write the history, settings, check thresholds, and baseline to one file (no secrets)."""
+    try:
+        manifest = create_backup(dest, BackupPaths(baseline=baseline))
+    except (ConfigError, StoreError) as e:
+        console.print(f"[red]Cannot back up:[/] {escape(str(e))}")
+        raise typer.Exit(code=2)
+    console.print(f"💾 Backup written → [bold]{dest}[/] ({', '.join(manifest.files)})")
+    console.print("[dim]Not included, set again after restoring:[/]")
+    for secret in manifest.needs_reentry:
+        console.print(f"[dim]  • {secret}[/]")
+
+
+@backup_app.command("restore")
+def backup_restore(
+    src: Path = typer.Argument(..., help="Zip file written by 'backup create'"),
+    merge: bool = typer.Option(False, "--merge", help="Add the backup's audits to the local history and keep local settings, instead of replacing them"),
+    baseline: Path = typer.Option(Path(".tinyseoai-baseline.json"), "--baseline", help="Baseline file to restore into"),
+):
+    """Restore a backup, replacing the local history and settings unless --merge."""
+    try:
+        restored = restore_backup(src, "merge" if merge else "replace", BackupPaths(baseline=baseline))
+    except (ConfigError, StoreError) as e:
+        console.print(f"[red]Cannot restore:[/] {escape(str(e))}")
+        raise typer.Exit(code=2)
+    manifest = restored.manifest
+    console.print(
+        f"📥 Restored backup of {manifest.created_at[:19]} (tinyseoai {manifest.app_version}): "
+        f"{', '.join(restored.restored)}"
+    )
+    if restored.history is not None:
+        merged = restored.history
+        console.print(
+            f"History: {merged.audits} audit(s), {merged.comparisons} comparison(s), "
+            f"and {merged.probes} probe(s) added, "
+            f"{merged.duplicates} already here"
+        )
+    if restored.kept:
+        console.print(f"Kept local: {', '.join(restored.kept)}")
+    console.print("[yellow]Set these again on this machine:[/]")
+    for secret in manifest.needs_reentry:
+        console.print(f"  • {secret}")
@@ -6,12 +6,18 @@ from __future__ import annotations
 import json
 import os
 from pathlib import Path
+from typing import Literal
 
 from dotenv import load_dotenv
 from loguru import logger
 from platformdirs import user_config_dir
 from pydantic import BaseModel, Field
 
+from .ai.providers import ProviderSettings
+from .audit.scope import CrawlOptions
+from .data.score_model import ScoreModel
+from .utils.http import HttpOptions
+
 # Load .env file from project root
 load_dotenv()
 
@@ -24,6 +30,17 @@ class BrandConfig(BaseModel):
     show_bot_logo: bool = True          # toggle inline SVG logo in PDF
 
 
+class SmtpConfig(BaseModel):
+    """Outgoing mail server for emailed reports; the password is not stored here."""
+
+    host: str
+    port: int = 587
+    tls: Literal["starttls", "ssl", "none"] = "starttls"  # ssl: implicit TLS, usually port 465
+    username: str | None = None  # None: send without logging in
+    from_address: str
+    timeout: float = 30.0
+
+
 class AppConfig(BaseModel):
     api_base: str = "https://api.tinyseoai.com"
     plan: str = "free"  # free | premium
@@ -31,17 +48,43 @@ class AppConfig(BaseModel):
     openai_model_premium: str = "gpt-5"
     max_output_tokens: int = 800
     brand: BrandConfig = Field(default_factory=BrandConfig)
+    site_defaults: dict[str, CrawlOptions] = Field(default_factory=dict)  # keyed by host
+    score_model: ScoreModel | None = None  # custom score shown next to the CLI score
+    disabled_checks: list[str] = Field(default_factory=list)  # check ids audit-full skips
+    checks_config: str | None = None  # checks.toml used when --checks-config is not given
+    http: HttpOptions = Field(default_factory=HttpOptions)  # user agent, contact, and TLS of requests
+    locale: str = "en"  # language of issue details and report titles (en, es)
+    lighthouse_path: str | None = None  # lighthouse executable; None: look it up on PATH
+    smtp: SmtpConfig | None = None  # mail server of 'email send'; None: not set up
+    ai: ProviderSettings = Field(default_factory=ProviderSettings)  # model writing AI summaries
 
     # AI Agent API Keys (loaded from .env file or environment variables)
     @property
     def openai_api_key(self) -> str:
-        """Get OpenAI API key from environment."""
-        return os.getenv("OPENAI_API_KEY", "")
+        """Get the OpenAI API key from the environment, then the system keyring."""
+        return os.getenv("OPENAI_API_KEY") or _keyring_secret("openai")
 
     @property
     def anthropic_api_key(self) -> str:
-        """Get Anthropic API key from environment (optional)."""
-        return os.getenv("ANTHROPIC_API_KEY", "")
+        """Get the Anthropic API key from the environment, then the system keyring (optional)."""
+        return os.getenv("ANTHROPIC_API_KEY") or _keyring_secret("anthropic")
+
+    @property
+    def github_token(self) -> str:
+        """Get the GitHub token from the environment, then the system keyring."""
+        token = os.getenv("TINYSEOAI_GITHUB_TOKEN") or os.getenv("GITHUB_TOKEN")
+        return token or _keyring_secret("github")
+
+    @property
+    def pagespeed_api_key(self) -> str:
+        """Get the PageSpeed Insights API key from the environment, then the system keyring."""
+        key = os.getenv("TINYSEOAI_PAGESPEED_API_KEY") or os.getenv("PAGESPEED_API_KEY")
+        return key or _keyring_secret("pagespeed")
+
+    @property
+    def smtp_password(self) -> str:
+        """Get the SMTP password from the environment, then the system keyring."""
+        return os.getenv("TINYSEOAI_SMTP_PASSWORD") or _keyring_secret("smtp")
 
     # Multi-agent settings
     enable_multi_agent: bool = True
@@ -49,6 +92,19 @@ class AppConfig(BaseModel):
     max_concurrent_agents: int = 3
 
 
+def _keyring_secret(name: str) -> str:
+    """A secret stored with `keyring set tinyseoai <name>`, or "" when unavailable."""
+    try:
+        import keyring
+    except ImportError:
+        return ""
+    try:
+        return keyring.get_password(APP_NAME, name) or ""
+    except Exception as e:  # no usable keyring backend
+        logger.debug(f"Keyring lookup failed: {e}")
+        return ""
+
+
 def _cfg_path() -> Path:
     cfg_dir = Path(user_config_dir(APP_NAME))
     cfg_dir.mkdir(parents=True, exist_ok=True)
@@ -0,0 +1,221 @@
+"""
+Team configuration bundles: settings, check thresholds, and a baseline in one file.
+
+A bundle is a versioned JSON file a team commits or passes around so every
+machine audits the same way. Secrets never go in: API keys and tokens are
+read from the environment or the system keyring, and HTTP credentials are
+excluded from the config model, so neither is part of what is dumped; the
+bundle lists what was left out. Machine-specific paths (the checks.toml
+location) are not exported either, since the thresholds travel inside.
+
+Importing replaces the local setup, or merges into it: values only the
+bundle sets are taken, and values both sides set differently are kept as
+they are and reported as conflicts.
+"""
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from typing import Any
+
+from platformdirs import user_config_dir
+from pydantic import BaseModel, ConfigDict, Field, ValidationError
+
+from .audit.checks_config import ChecksConfig
+from .config import APP_NAME, AppConfig
+from .data.baseline import Baseline, BaselineEntry
+from .exceptions import ConfigError
+
+BUNDLE_VERSION = 1
+LOCAL_SETTINGS = {"checks_config"}  # paths on this machine, not shared
+
+EXCLUDED_SECRETS = [
+    "OPENAI_API_KEY",
+    "ANTHROPIC_API_KEY",
+    "GitHub token (TINYSEOAI_GITHUB_TOKEN, GITHUB_TOKEN, or the keyring)",
+    "PageSpeed Insights key (PAGESPEED_API_KEY or the keyring)",
+    "SMTP password (TINYSEOAI_SMTP_PASSWORD or the keyring)",
+    "HTTP credentials (basic auth, cookies, extra headers)",
+]
+
+
+class ConfigBundle(BaseModel):
+    """The exported file: settings and thresholds that differ from the defaults."""
+
+    model_config = ConfigDict(extra="forbid")
+
+    version: int = BUNDLE_VERSION
+    settings: dict[str, Any] = Field(default_factory=dict)  # AppConfig, minus LOCAL_SETTINGS
+    checks: dict[str, Any] | None = None  # ChecksConfig; None: no checks config was exported
+    baseline: Baseline | None = None
+    secrets_excluded: list[str] = Field(default_factory=lambda: list(EXCLUDED_SECRETS))
+    note: str = "Secrets are not included; set them on each machine."
+
+
+class Conflict(BaseModel):
+    """A value both the local setup and the bundle set, differently; the local one is kept."""
+
+    key: str  # e.g. "checks.timing.ttfb_warning_ms" or "baseline.<fingerprint>"
+    current: Any
+    incoming: Any
+
+
+class ImportResult(BaseModel):
+    """The setup after an import, with what a merge could not reconcile."""
+
+    config: AppConfig
+    checks: ChecksConfig | None = None  # None: neither side has a checks config
+    baseline: Baseline | None = None  # None: neither side has a baseline
+    conflicts: list[Conflict] = Field(default_factory=list)
+
+
+def shared_checks_path() -> Path:
+    """Where an imported checks config is written when none is configured."""
+    return Path(user_config_dir(APP_NAME)) / "checks.toml"
+
+
+def _settings(config: AppConfig) -> dict[str, Any]:
+    return config.model_dump(mode="json", exclude_defaults=True, exclude=LOCAL_SETTINGS)
+
+
+def _checks(checks: ChecksConfig) -> dict[str, Any]:
+    return checks.model_dump(mode="json", exclude_defaults=True)
+
+
+def export_config(
+    path: Path,
+    config: AppConfig,
+    checks: ChecksConfig | None = None,
+    baseline: Baseline | None = None,
+) -> Path:
+    """
+    Write a bundle of config, checks, and baseline to path, returning the path.
+
+    Only values that differ from the defaults are written, so a bundle stays
+    small and keeps working when defaults change.
+    """
+    bundle = ConfigBundle(
+        settings=_settings(config),
+        checks=_checks(checks) if checks is not None else None,
+        baseline=baseline,
+    )
+    path.parent.mkdir(parents=True, exist_ok=True)
+    path.write_text(bundle.model_dump_json(indent=2, exclude_none=True) + "\n", encoding="utf-8")
+    return path
+
+
+def load_bundle(path: Path) -> ConfigBundle:
+    """
+    Read a bundle written by export_config.
+
+    Raises:
+        ConfigError: If the file cannot be read, is malformed, or is from a newer version
+    """
+    try:
+        data = json.loads(path.read_text(encoding="utf-8"))
+    except OSError as e:
+        raise ConfigError(f"Cannot read {path}: {e.strerror or e}") from e
+    except json.JSONDecodeError as e:
+        raise ConfigError(f"{path}: invalid JSON: {e}") from e
+    version = data.get("version") if isinstance(data, dict) else None
+    if isinstance(version, int) and version > BUNDLE_VERSION:
+        raise ConfigError(
+            f"{path}: bundle version {version} is newer than supported ({BUNDLE_VERSION})"
+        )
+    try:
+        return ConfigBundle.model_validate(data)
+    except ValidationError as e:
+        raise ConfigError(f"{path}: {e.errors()[0]['msg']}") from e
+
+
+def _merge(
+    current: dict[str, Any], incoming: dict[str, Any], prefix: str, conflicts: list[Conflict]
+) -> dict[str, Any]:
+    """current with the keys only incoming sets; differing leaves are kept and reported."""
+    merged = dict(current)
+    for key, value in incoming.items():
+        if key not in current:
+            merged[key] = value
+        elif isinstance(value, dict) and isinstance(current[key], dict):
+            merged[key] = _merge(current[key], value, f"{prefix}.{key}", conflicts)
+        elif current[key] != value:
+            conflicts.append(Conflict(key=f"{prefix}.{key}", current=current[key], incoming=value))
+    return merged
+
+
+def _merge_baselines(current: Baseline, incoming: Baseline, conflicts: list[Conflict]) -> Baseline:
+    entries = {entry.fingerprint: entry for entry in current.entries}
+    merged: list[BaselineEntry] = list(current.entries)
+    for entry in incoming.entries:
+        local = entries.get(entry.fingerprint)
+        if local is None:
+            merged.append(entry)
+        elif local != entry:
+            conflicts.append(
+                Conflict(
+                    key=f"baseline.{entry.fingerprint}",
+                    current=local.model_dump(mode="json", exclude_none=True),
+                    incoming=entry.model_dump(mode="json", exclude_none=True),
+                )
+            )
+    return Baseline(entries=merged)
+
+
+def _validated(model: type[BaseModel], data: dict[str, Any], what: str) -> Any:
+    try:
+        return model.model_validate(data)
+    except ValidationError as e:
+        raise ConfigError(f"Invalid {what} in bundle: {e.errors()[0]['msg']}") from e
+
+
+def import_config(
+    path: Path,
+    config: AppConfig,
+    checks: ChecksConfig | None = None,
+    baseline: Baseline | None = None,
+    merge: bool = False,
+) -> ImportResult:
+    """
+    Apply a bundle to the local config, checks config, and baseline.
+
+    Args:
+        path: Bundle written by export_config
+        config: The local config; its local paths (checks_config) are kept either way
+        checks: The local checks config, if there is one
+        baseline: The local baseline, if there is one
+        merge: Keep local values and add the bundle's; otherwise the bundle replaces them
+
+    Returns:
+        The resulting setup, for the caller to save, and the merge conflicts
+
+    Raises:
+        ConfigError: If the bundle is unreadable, from a newer version, or invalid
+    """
+    bundle = load_bundle(path)
+    conflicts: list[Conflict] = []
+    local = {key: getattr(config, key) for key in LOCAL_SETTINGS}
+
+    if merge:
+        settings = _merge(_settings(config), bundle.settings, "settings", conflicts)
+    else:
+        settings = bundle.settings
+    result = ImportResult(config=_validated(AppConfig, {**settings, **local}, "settings"))
+
+    if bundle.checks is None:
+        # Replacing with a bundle that has no thresholds means the defaults
+        result.checks = checks if merge or checks is None else ChecksConfig()
+    elif merge and checks is not None:
+        values = _merge(_checks(checks), bundle.checks, "checks", conflicts)
+        result.checks = _validated(ChecksConfig, values, "checks config")
+    else:
+        result.checks = _validated(ChecksConfig, bundle.checks, "checks config")
+
+    if bundle.baseline is None:
+        result.baseline = baseline if merge or baseline is None else Baseline()
+    elif merge and baseline is not None:
+        result.baseline = _merge_baselines(baseline, bundle.baseline, conflicts)
+    else:
+        result.baseline = bundle.baseline
+
+    result.conflicts = conflicts
+    return result
@@ -0,0 +1,168 @@
+"""
+Baselines: fingerprints of known issues to suppress in later audits.
+"""
+from __future__ import annotations
+
+import json
+import tomllib
+from collections.abc import Iterable
+from datetime import date
+from pathlib import Path
+
+from pydantic import BaseModel, Field
+
+from .fingerprint import FingerprintSet, fingerprint
+from .models import AuditResult, Issue
+
+BASELINE_VERSION = 1
+SUPPRESSED_KEY = "suppressed_issues"  # meta key holding issues moved out by a baseline
+
+
+class BaselineEntry(BaseModel):
+    """One suppressed issue."""
+
+    fingerprint: str
+    type: str | None = None  # for humans reading the file
+    url: str | None = None
+    reason: str | None = None
+    author: str | None = None
+    expires: date | None = None
+
+    def expired(self, today: date) -> bool:
+        """True once the expiry date has passed (an entry is valid on its expiry day)."""
+        return self.expires is not None and self.expires < today
+
+
+class Baseline(BaseModel):
+    """A versioned list of baseline entries."""
+
+    version: int = BASELINE_VERSION
+    entries: list[BaselineEntry] = Field(default_factory=list)
+
+    @classmethod
+    def load(cls, path: Path) -> Baseline:
+        """
+        Load a baseline from a .json or .toml file.
+
+        Raises:
+            ValueError: If the file is malformed or from a newer version
+        """
+        text = path.read_text(encoding="utf-8")
+        data = tomllib.loads(text) if path.suffix.lower() == ".toml" else json.loads(text)
+        baseline = cls.model_validate(data)
+        if baseline.version > BASELINE_VERSION:
+            raise ValueError(
+                f"Baseline version {baseline.version} is newer than supported ({BASELINE_VERSION})"
+            )
+        return baseline
+
+    def save(self, path: Path) -> Path:
+        """Write the baseline as JSON, returning the path."""
+        path.parent.mkdir(parents=True, exist_ok=True)
+        path.write_text(self.model_dump_json(indent=2, exclude_none=True) + "\n", encoding="utf-8")
+        return path
+
+
+class SuppressionReport(BaseModel):
+    """What apply_baseline did."""
+
+    suppressed: list[Issue] = Field(default_factory=list)
+    expired: list[BaselineEntry] = Field(default_factory=list)  # ignored, still listed in the file
+    unmatched: list[BaselineEntry] = Field(default_factory=list)  # active entries matching no issue
+
+
+def apply_baseline(
+    result: AuditResult, baseline: Baseline, today: date | None = None
+) -> SuppressionReport:
+    """
+    Move issues matching active baseline entries out of result.issues.
+
+    Matched issues are kept under meta["suppressed_issues"] and counted under
+    meta["baseline"]. Expired entries suppress nothing.
+
+    Args:
+        result: Audit result to modify in place
+        baseline: Baseline to apply
+        today: Date used for expiry checks (defaults to today)
+
+    Returns:
+        SuppressionReport listing suppressed issues and expired/unmatched entries
+    """
+    today = today or date.today()
+    report = SuppressionReport()
+
+    active: dict[str, BaselineEntry] = {}
+    for entry in baseline.entries:
+        if entry.expired(today):
+            report.expired.append(entry)
+        else:
+            active[entry.fingerprint] = entry
+    fingerprints = FingerprintSet(set(active))
+
+    matched: set[str] = set()
+    kept: list[Issue] = []
+    for issue in result.issues:
+        hit = fingerprints.match(issue)
+        if hit is None:
+            kept.append(issue)
+        else:
+            matched.add(hit)
+            report.suppressed.append(issue)
+
+    report.unmatched = [entry for fp, entry in active.items() if fp not in matched]
+
+    result.issues = kept
+    previous = [Issue.model_validate(i) for i in result.meta.get(SUPPRESSED_KEY, [])]
+    result.meta[SUPPRESSED_KEY] = [i.model_dump() for i in previous + report.suppressed]
+    result.meta["baseline"] = {
+        "suppressed": len(previous) + len(report.suppressed),
+        "expired_entries": len(report.expired),
+        "unmatched_entries": len(report.unmatched),
+    }
+    return report
+
+
+def suppressed_issues(result: AuditResult) -> list[Issue]:
+    """Return the issues a baseline moved out of the result."""
+    return [Issue.model_validate(i) for i in result.meta.get(SUPPRESSED_KEY, [])]
+
+
+def update_baseline(
+    baseline: Baseline,
+    additions: Iterable[Issue | BaselineEntry],
+    reason: str | None = None,
+    author: str | None = None,
+    expires: date | None = None,
+) -> Baseline:
+    """
+    Return a new baseline with additions appended.
+
+    Issues are converted to entries using reason/author/expires. Entries whose
+    fingerprint is already present are skipped, so updating is idempotent.
+
+    Args:
+        baseline: Existing baseline (not modified)
+        additions: Issues or entries to add
+        reason: Reason recorded on entries created from issues
+        author: Author recorded on entries created from issues
+        expires: Expiry recorded on entries created from issues
+
+    Returns:
+        Updated baseline
+    """
+    entries = [entry.model_copy() for entry in baseline.entries]
+    seen = {entry.fingerprint for entry in entries}
+    for item in additions:
+        if isinstance(item, Issue):
+            item = BaselineEntry(
+                fingerprint=fingerprint(item),
+                type=item.type,
+                url=item.url,
+                reason=reason,
+                author=author,
+                expires=expires,
+            )
+        if item.fingerprint not in seen:
+            seen.add(item.fingerprint)
+            entries.append(item)
+    return Baseline(version=BASELINE_VERSION, entries=entries)
@@ -0,0 +1,173 @@
+"""
+Compare the audits of two different sites, e.g. a prospect and a competitor.
+
+Unlike diff(), which lines up two audits of the same site issue by issue,
+the pages of two sites differ, so issues are matched loosely: by category
+and issue type (whose title is what a reader sees), not by URL or detail.
+"""
+from __future__ import annotations
+
+from collections import defaultdict
+from datetime import datetime, timezone
+from typing import Literal
+
+from pydantic import BaseModel, Field
+
+from .models import AuditResult, Issue
+from .query import issue_category
+from .recommendations import issue_title
+from .score_model import compute
+from .severity import Category, count_by_severity, severity_rank
+
+Side = Literal["a", "b"]
+
+
+class SiteMetrics(BaseModel):
+    """Headline numbers of one side."""
+
+    site: str
+    timestamp: str | None = None
+    pages_scanned: int
+    issues: int
+    severities: dict[str, int]  # high/medium/low/info
+    issues_per_page: float
+    health_score: float | None = None  # as the audit recorded it
+
+
+class CategoryVerdict(BaseModel):
+    """How both sites do in one category; the higher category score wins."""
+
+    category: str
+    a_issues: int
+    b_issues: int
+    a_score: int  # 0–100, from the category's issues alone
+    b_score: int
+    winner: Side | None = None  # None: a tie
+
+
+class IssueGap(BaseModel):
+    """An issue type one site has and the other does not."""
+
+    category: str
+    type: str
+    title: str
+    severity: str  # the most severe seen
+    pages: int
+    examples: list[str] = Field(default_factory=list)  # affected URLs
+
+
+class SiteComparison(BaseModel):
+    """Result of compare_sites(a, b). Serializes to JSON unchanged."""
+
+    mode: str = "full"  # how both sites were audited, e.g. "full" or "fast"
+    created_at: str
+    a: SiteMetrics
+    b: SiteMetrics
+    categories: list[CategoryVerdict] = Field(default_factory=list)
+    only_a: list[IssueGap] = Field(default_factory=list)  # on A, not on B
+    only_b: list[IssueGap] = Field(default_factory=list)
+
+    def wins(self, side: Side) -> list[str]:
+        """Categories the side wins."""
+        return [v.category for v in self.categories if v.winner == side]
+
+
+def _metrics(result: AuditResult) -> SiteMetrics:
+    score = result.meta.get("health_score")
+    pages = result.pages_scanned
+    return SiteMetrics(
+        site=result.site,
+        timestamp=result.meta.get("timestamp"),
+        pages_scanned=pages,
+        issues=len(result.issues),
+        severities=count_by_severity(i.severity for i in result.issues),
+        issues_per_page=round(len(result.issues) / pages, 2) if pages else 0.0,
+        health_score=score if isinstance(score, int | float) else None,
+    )
+
+
+def _by_category(issues: list[Issue]) -> dict[Category, list[Issue]]:
+    grouped: dict[Category, list[Issue]] = defaultdict(list)
+    for issue in issues:
+        grouped[issue_category(issue)].append(issue)
+    return grouped
+
+
+def _verdicts(a: AuditResult, b: AuditResult) -> list[CategoryVerdict]:
+    a_issues, b_issues = _by_category(a.issues), _by_category(b.issues)
+    verdicts = []
+    for category in Category:
+        if category not in a_issues and category not in b_issues:
+            continue
+        a_score = compute(a_issues.get(category, []), a.pages_scanned).score
+        b_score = compute(b_issues.get(category, []), b.pages_scanned).score
+        verdicts.append(
+            CategoryVerdict(
+                category=category.value,
+                a_issues=len(a_issues.get(category, [])),
+                b_issues=len(b_issues.get(category, [])),
+                a_score=a_score,
+                b_score=b_score,
+                winner="a" if a_score > b_score else "b" if b_score > a_score else None,
+            )
+        )
+    return verdicts
+
+
+def _gaps(issues: list[Issue], other: list[Issue], examples: int) -> list[IssueGap]:
+    """Issue types in issues with no issue of the same category and type in other."""
+    present = {(issue_category(i), i.type) for i in other}
+    gaps: dict[tuple[Category, str], IssueGap] = {}
+    urls: dict[tuple[Category, str], set[str]] = defaultdict(set)
+    for issue in issues:
+        key = (issue_category(issue), issue.type)
+        if key in present:
+            continue
+        gap = gaps.get(key)
+        if gap is None:
+            gap = gaps[key] = IssueGap(
+                category=key[0].value,
+                type=issue.type,
+                title=issue_title(issue.type),
+                severity=issue.severity,
+                pages=0,
+            )
+        elif severity_rank(issue.severity) > severity_rank(gap.severity):
+            gap.severity = issue.severity
+        if issue.url not in urls[key]:
+            urls[key].add(issue.url)
+            gap.pages += 1
+            if len(gap.examples) < examples:
+                gap.examples.append(issue.url)
+    return sorted(gaps.values(), key=lambda g: (-severity_rank(g.severity), -g.pages, g.type))
+
+
+def compare_sites(
+    a: AuditResult, b: AuditResult, mode: str = "full", examples: int = 3
+) -> SiteComparison:
+    """
+    Compare the audits of two sites side by side.
+
+    Category scores come from each site's issues in that category alone,
+    scored per page like the health score, so a larger crawl is not
+    penalized for finding more pages. Issue gaps ignore URLs: an issue type
+    counts as shared when both sites have it in any page.
+
+    Args:
+        a: Audit of the first site (e.g. the prospect)
+        b: Audit of the second site (e.g. the competitor)
+        mode: How both were audited, recorded with the comparison
+        examples: Affected URLs kept per issue gap
+
+    Returns:
+        SiteComparison with deterministic ordering
+    """
+    return SiteComparison(
+        mode=mode,
+        created_at=datetime.now(timezone.utc).isoformat(),
+        a=_metrics(a),
+        b=_metrics(b),
+        categories=_verdicts(a, b),
+        only_a=_gaps(a.issues, b.issues, examples),
+        only_b=_gaps(b.issues, a.issues, examples),
+    )
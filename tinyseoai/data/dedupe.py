@@ -0,0 +1,111 @@
+"""
+Consolidate per-page issues into findings: one entry per problem with the
+pages it affects, for summaries that would otherwise repeat "Meta
+description missing" once per page.
+
+Findings group issues by category and normalized title. With the exact
+policy titles must match; with the aggressive policy a title whose words are
+a subset of another's joins it ("Noindex" and "Noindex directive", "H1
+missing" and "Missing h1"), and issues the scorer files under "other" (mostly
+older type names) may join any category. Saved results keep one issue per page, so
+fingerprints and baselines are unaffected.
+"""
+from __future__ import annotations
+
+import re
+from collections.abc import Iterable
+from enum import Enum
+
+from pydantic import BaseModel, Field
+
+from .models import Issue
+from .query import issue_category
+from .recommendations import issue_title, recommendation_for
+from .severity import Category, count_by_severity, severity_rank
+
+
+class Consolidation(str, Enum):
+    """How issues are grouped into findings."""
+
+    OFF = "off"  # one finding per issue
+    EXACT = "exact"  # same category and title
+    AGGRESSIVE = "aggressive"  # related category, one title's words within the other's
+
+
+class Finding(BaseModel):
+    """Issues consolidated into one problem."""
+
+    title: str
+    category: str
+    types: list[str]  # issue types merged, in order of first appearance
+    severity: str  # the most severe of the issues
+    pages: list[str] = Field(default_factory=list)  # affected pages, without repeats
+    detail: str | None = None  # the most detailed of the issues' details
+    recommendation: str | None = None
+    issues: int = 0  # issues consolidated
+
+
+def _words(title: str) -> frozenset[str]:
+    return frozenset(re.findall(r"[a-z0-9]+", title.lower()))
+
+
+def _related(a: str, b: str) -> bool:
+    return a == b or Category.OTHER.value in (a, b)
+
+
+def _longest(a: str | None, b: str | None) -> str | None:
+    return b if len(b or "") > len(a or "") else a
+
+
+def consolidate(
+    issues: Iterable[Issue], policy: Consolidation = Consolidation.EXACT
+) -> list[Finding]:
+    """
+    Group issues into findings, most severe and widespread first.
+
+    Args:
+        issues: Issues to consolidate
+        policy: Grouping policy (see Consolidation)
+
+    Returns:
+        Findings; ties keep the order of the issues
+    """
+    findings: list[Finding] = []
+    keys: list[tuple[str, frozenset[str]]] = []
+    for issue in issues:
+        title = issue_title(issue.type)
+        key = (issue_category(issue).value, _words(title))
+        index = None
+        if policy is not Consolidation.OFF:
+            index = next(
+                (
+                    i
+                    for i, (category, words) in enumerate(keys)
+                    if category == key[0]
+                    and words == key[1]
+                    or policy is Consolidation.AGGRESSIVE
+                    and _related(category, key[0])
+                    and (words <= key[1] or key[1] <= words)
+                ),
+                None,
+            )
+        if index is None:
+            keys.append(key)
+            findings.append(Finding(title=title, category=key[0], types=[], severity=issue.severity))
+            index = len(findings) - 1
+        finding = findings[index]
+        finding.issues += 1
+        if issue.type not in finding.types:
+            finding.types.append(issue.type)
+            finding.recommendation = _longest(finding.recommendation, recommendation_for(issue.type))
+        if issue.url not in finding.pages:
+            finding.pages.append(issue.url)
+        if severity_rank(issue.severity) > severity_rank(finding.severity):
+            finding.severity = issue.severity
+        finding.detail = _longest(finding.detail, issue.detail)
+    return sorted(findings, key=lambda f: (-severity_rank(f.severity), -len(f.pages)))
+
+
+def finding_counts(findings: list[Finding]) -> dict[str, int]:
+    """Findings per severity, as count_by_severity counts issues."""
+    return count_by_severity(f.severity for f in findings)
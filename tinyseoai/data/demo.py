@@ -0,0 +1,48 @@
+"""
+Demo audit result for documentation, screenshots, and tests that should not crawl.
+"""
+from __future__ import annotations
+
+from .models import AuditResult, Issue
+
+DEMO_SITE = "https://demo.tinyseoai.com/"
+
+
+def build_demo_result(site: str = DEMO_SITE) -> AuditResult:
+    """
+    Build a small, deterministic audit result covering every severity.
+
+    Args:
+        site: Site URL to use in the result
+
+    Returns:
+        AuditResult shaped like the output of a comprehensive audit
+    """
+    root = site.rstrip("/")
+    issues = [
+        Issue(url=f"{root}/", type="missing_canonical", severity="medium",
+              detail="No canonical tag found. This can cause duplicate content issues."),
+        Issue(url=f"{root}/pricing", type="title_missing", severity="high"),
+        Issue(url=f"{root}/blog", type="meta_description_missing", severity="low"),
+        Issue(url=f"{root}/blog", type="img_alt_missing", severity="low", detail="/img/hero.png"),
+        Issue(url=f"{root}/about", type="broken_link", severity="medium", detail=f"{root}/team"),
+        Issue(url=f"{root}/", type="missing_hsts", severity="medium",
+              detail="Missing Strict-Transport-Security header. "
+              "This header enforces HTTPS connections."),
+        Issue(url=f"{root}/docs", type="noindex", severity="info"),
+    ]
+    return AuditResult(
+        site=site,
+        pages_scanned=5,
+        issues=issues,
+        meta={
+            "max_pages": 50,
+            "timestamp": "2025-01-01T00:00:00Z",
+            "agent": "tinyseoai/0.2.0",
+            "demo": True,
+            "health_score": 72,
+            "health_grade": "C",
+            "category_scores": {},
+            "top_recommendations": [],
+        },
+    )
@@ -0,0 +1,70 @@
+"""
+Per-check diagnostics stored under meta["diagnostics"] of an audit result.
+
+The check registry records what each site-level check did: how long it
+took, the HTTP requests it made and bytes it downloaded, its findings, and
+why it did not run when it was skipped. Results from before diagnostics
+existed have no such section; Diagnostics.from_meta returns None for them.
+"""
+from __future__ import annotations
+
+from typing import Any, Literal
+
+from pydantic import BaseModel, Field, ValidationError
+
+META_KEY = "diagnostics"
+
+
+class CheckRun(BaseModel):
+    """What happened to one check in a run."""
+
+    id: str
+    status: Literal["ran", "skipped", "disabled", "failed"]
+    reason: str | None = None  # why it was skipped, or the error
+    issues: int = 0
+    duration_ms: float = 0.0
+    requests: int = 0  # HTTP requests made, counting retries and redirect hops
+    bytes_fetched: int = 0  # response bytes downloaded, as sent (before decompression)
+
+
+class Diagnostics(BaseModel):
+    """Check runs of one audit, in the order they ran."""
+
+    checks: list[CheckRun] = Field(default_factory=list)
+
+    @property
+    def duration_ms(self) -> float:
+        return round(sum(c.duration_ms for c in self.checks), 1)
+
+    @property
+    def requests(self) -> int:
+        return sum(c.requests for c in self.checks)
+
+    @property
+    def bytes_fetched(self) -> int:
+        return sum(c.bytes_fetched for c in self.checks)
+
+    def slowest(self, n: int = 3) -> list[CheckRun]:
+        """The n checks that took longest, slowest first."""
+        ran = [c for c in self.checks if c.status in ("ran", "failed")]
+        return sorted(ran, key=lambda c: c.duration_ms, reverse=True)[:n]
+
+    @classmethod
+    def from_meta(cls, meta: dict[str, Any] | None) -> Diagnostics | None:
+        """The diagnostics of a result's meta; None when absent (older results) or malformed."""
+        section = (meta or {}).get(META_KEY)
+        if not isinstance(section, dict):
+            return None
+        try:
+            return cls.model_validate(section)
+        except ValidationError:
+            return None
+
+
+def format_bytes(size: int) -> str:
+    """A byte count for humans: 512 B, 14.2 KB, 3.1 MB."""
+    if size < 1024:
+        return f"{size} B"
+    if size < 1024 * 1024:
+        return f"{size / 1024:.1f} KB"
+    return f"{size / (1024 * 1024):.1f} MB"
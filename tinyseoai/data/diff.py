@@ -0,0 +1,149 @@
+"""
+Compare two audit results of the same site.
+"""
+from __future__ import annotations
+
+from collections import Counter, defaultdict
+
+from pydantic import BaseModel, Field
+
+from .fingerprint import fingerprints
+from .models import AuditResult, Issue
+from .severity import count_by_severity, severity_rank
+
+
+class MetricsDelta(BaseModel):
+    """Head minus base for the headline numbers."""
+
+    pages_scanned: int
+    issues: int
+    health_score: float | None = None  # only when both audits carry a score
+
+
+class PageChanges(BaseModel):
+    """Pages that started or stopped showing an issue type."""
+
+    type: str
+    added_pages: list[str] = Field(default_factory=list)
+    removed_pages: list[str] = Field(default_factory=list)
+
+
+class DiffSummary(BaseModel):
+    """Counts per severity of new and resolved issues."""
+
+    new: dict[str, int]
+    resolved: dict[str, int]
+
+
+class AuditDiff(BaseModel):
+    """Result of diff(base, head). Serializes to JSON unchanged."""
+
+    base_site: str
+    head_site: str
+    base_timestamp: str | None = None
+    head_timestamp: str | None = None
+    metrics: MetricsDelta
+    new: list[Issue] = Field(default_factory=list)
+    resolved: list[Issue] = Field(default_factory=list)
+    persisting: list[Issue] = Field(default_factory=list)
+    page_changes: list[PageChanges] = Field(default_factory=list)
+    summary: DiffSummary
+
+
+def _issue_order(issue: Issue) -> tuple:
+    return (-severity_rank(issue.severity), issue.type, issue.url, issue.detail or "")
+
+
+def _match(base: list[Issue], head: list[Issue]) -> tuple[list[Issue], list[Issue], list[Issue]]:
+    """
+    Multiset match by fingerprint; returns (new, resolved, persisting).
+
+    Issues left over are matched again by the fingerprints older versions
+    gave them, so a result saved before issues carried catalog messages
+    still lines up with a current one.
+    """
+    keys = {id(issue): fingerprints(issue) for issue in (*base, *head)}
+    new = sorted(head, key=_issue_order)
+    resolved = sorted(base, key=_issue_order)
+    persisting: list[Issue] = []
+    for version in range(max(map(len, keys.values()), default=0)):
+        remaining = Counter(keys[id(issue)][version] for issue in resolved)
+        unmatched: list[Issue] = []
+        for issue in new:
+            fp = keys[id(issue)][version]
+            if remaining[fp] > 0:
+                remaining[fp] -= 1
+                persisting.append(issue)
+            else:
+                unmatched.append(issue)
+        new = unmatched
+
+        left: list[Issue] = []
+        for issue in resolved:
+            fp = keys[id(issue)][version]
+            if remaining[fp] > 0:
+                remaining[fp] -= 1
+                left.append(issue)
+        resolved = left
+    return new, resolved, sorted(persisting, key=_issue_order)
+
+
+def _page_changes(base: list[Issue], head: list[Issue]) -> list[PageChanges]:
+    base_pages: dict[str, set[str]] = defaultdict(set)
+    head_pages: dict[str, set[str]] = defaultdict(set)
+    for issue in base:
+        base_pages[issue.type].add(issue.url)
+    for issue in head:
+        head_pages[issue.type].add(issue.url)
+
+    changes = []
+    for issue_type in sorted(base_pages.keys() | head_pages.keys()):
+        added = sorted(head_pages[issue_type] - base_pages[issue_type])
+        removed = sorted(base_pages[issue_type] - head_pages[issue_type])
+        if added or removed:
+            changes.append(PageChanges(type=issue_type, added_pages=added, removed_pages=removed))
+    return changes
+
+
+def diff(base: AuditResult, head: AuditResult) -> AuditDiff:
+    """
+    Compare two audits: what is new, resolved, and still present.
+
+    Issues are matched by fingerprint, so the order of issues in either
+    result does not matter. A changed detail (e.g. a renamed duplicate
+    title) shows up as one resolved and one new issue.
+
+    Args:
+        base: Earlier audit
+        head: Later audit
+
+    Returns:
+        AuditDiff with deterministic ordering (severity, type, url, detail)
+    """
+    new, resolved, persisting = _match(base.issues, head.issues)
+
+    base_score = base.meta.get("health_score")
+    head_score = head.meta.get("health_score")
+    score_delta = None
+    if isinstance(base_score, int | float) and isinstance(head_score, int | float):
+        score_delta = round(head_score - base_score, 1)
+
+    return AuditDiff(
+        base_site=base.site,
+        head_site=head.site,
+        base_timestamp=base.meta.get("timestamp"),
+        head_timestamp=head.meta.get("timestamp"),
+        metrics=MetricsDelta(
+            pages_scanned=head.pages_scanned - base.pages_scanned,
+            issues=len(head.issues) - len(base.issues),
+            health_score=score_delta,
+        ),
+        new=new,
+        resolved=resolved,
+        persisting=persisting,
+        page_changes=_page_changes(base.issues, head.issues),
+        summary=DiffSummary(
+            new=count_by_severity(i.severity for i in new),
+            resolved=count_by_severity(i.severity for i in resolved),
+        ),
+    )
@@ -0,0 +1,165 @@
+"""
+Estimated effort to fix an issue: trivial, moderate, or involved.
+
+Effort depends on the issue type and on how many pages show it: a missing
+meta description on three pages is a quick edit, on three thousand it is a
+project, unless one template fix covers them all. RULES is checked in
+order and the first matching rule wins; types no rule matches are graded
+from their ISSUE_EFFORT_SCORES, one level harder from BULK_PAGES pages on.
+
+The [effort] table of the checks config maps issue types (or fnmatch
+patterns such as "title_*") to an effort, ahead of the rules. An effort
+already set on an issue, e.g. by someone triaging the result, is kept.
+"""
+from __future__ import annotations
+
+from collections import defaultdict
+from collections.abc import Iterable
+from enum import Enum
+from fnmatch import fnmatchcase
+
+from pydantic import BaseModel, Field
+
+from .models import Issue
+from .query import issue_category
+from .scoring import ISSUE_EFFORT_SCORES, TEMPLATE_FIXES
+from .severity import Category
+
+
+class Effort(str, Enum):
+    """Estimated fix effort, easiest first."""
+
+    TRIVIAL = "trivial"
+    MODERATE = "moderate"
+    INVOLVED = "involved"
+
+    @classmethod
+    def parse(cls, value: str | None) -> Effort | None:
+        """Parse an effort case-insensitively; None if unknown."""
+        try:
+            return cls(value.strip().lower()) if value else None
+        except ValueError:
+            return None
+
+    def harder(self) -> Effort:
+        """The next level up; INVOLVED stays INVOLVED."""
+        levels = list(Effort)
+        return levels[min(levels.index(self) + 1, len(levels) - 1)]
+
+
+class EffortRule(BaseModel):
+    """One row of the rules table: issues matching every condition get its effort."""
+
+    types: tuple[str, ...] = ("*",)  # fnmatch patterns on the issue type
+    category: Category | None = None
+    min_pages: int = 1
+    max_pages: int | None = None
+    effort: Effort
+
+    def matches(self, issue_type: str, category: Category, pages: int) -> bool:
+        return (
+            any(fnmatchcase(issue_type, pattern) for pattern in self.types)
+            and (self.category is None or category is self.category)
+            and pages >= self.min_pages
+            and (self.max_pages is None or pages <= self.max_pages)
+        )
+
+
+# Edits in the page head: quick by hand on a few pages, a project across many
+META_TAG_TYPES = (
+    "title_*",
+    "*meta_description*",
+    "duplicate_title",
+    "multiple_title_tags",
+    "missing_og_*",
+    "missing_twitter_*",
+    "og_*",
+    "twitter_*",
+    "img_alt_missing",
+    "h1_missing",
+    "multiple_h1",
+)
+
+RULES: list[EffortRule] = [
+    # One template or server setting fixes every page
+    EffortRule(types=tuple(sorted(TEMPLATE_FIXES)), effort=Effort.TRIVIAL),
+    # Site-wide performance work
+    EffortRule(category=Category.PERFORMANCE, min_pages=10, effort=Effort.INVOLVED),
+    EffortRule(types=META_TAG_TYPES, max_pages=9, effort=Effort.TRIVIAL),
+    EffortRule(types=META_TAG_TYPES, max_pages=99, effort=Effort.MODERATE),
+    EffortRule(types=META_TAG_TYPES, effort=Effort.INVOLVED),
+]
+
+# From this many pages, types graded by their effort score are one level harder
+BULK_PAGES = 100
+
+
+class EffortOptions(BaseModel):
+    """Effort overrides by issue type or fnmatch pattern; exact types win over patterns."""
+
+    types: dict[str, Effort] = Field(default_factory=dict)
+
+    def override(self, issue_type: str) -> Effort | None:
+        if issue_type in self.types:
+            return self.types[issue_type]
+        for pattern, effort in self.types.items():
+            if fnmatchcase(issue_type, pattern):
+                return effort
+        return None
+
+
+def _from_score(issue_type: str) -> Effort:
+    score = ISSUE_EFFORT_SCORES.get(issue_type, 5)
+    if score <= 3:
+        return Effort.TRIVIAL
+    return Effort.MODERATE if score <= 6 else Effort.INVOLVED
+
+
+def estimate(issue: Issue, pages: int = 1, options: EffortOptions | None = None) -> Effort:
+    """
+    Estimate the effort to fix an issue type.
+
+    Args:
+        issue: An issue of the type
+        pages: Pages showing the type in the result
+        options: Overrides from the checks config
+
+    Returns:
+        The override for the type, else the first matching rule's effort,
+        else the grade of its effort score
+    """
+    overridden = (options or EffortOptions()).override(issue.type)
+    if overridden is not None:
+        return overridden
+    category = issue_category(issue)
+    for rule in RULES:
+        if rule.matches(issue.type, category, pages):
+            return rule.effort
+    effort = _from_score(issue.type)
+    return effort.harder() if pages >= BULK_PAGES else effort
+
+
+def efforts(issues: Iterable[Issue], options: EffortOptions | None = None) -> dict[str, Effort]:
+    """
+    The effort of every issue type in a result.
+
+    An effort set on any issue of a type (by triage, or an earlier run) is
+    used as is; other types are estimated from their page count.
+    """
+    by_type: dict[str, list[Issue]] = defaultdict(list)
+    for issue in issues:
+        by_type[issue.type].append(issue)
+    result = {}
+    for issue_type, group in by_type.items():
+        assigned = next((e for e in (Effort.parse(i.effort) for i in group) if e is not None), None)
+        pages = len({i.url for i in group})
+        result[issue_type] = assigned or estimate(group[0], pages, options)
+    return result
+
+
+def annotate(issues: list[Issue], options: EffortOptions | None = None) -> None:
+    """Set the effort of issues that have none, in place; efforts already set are kept."""
+    by_type = efforts(issues, options)
+    for issue in issues:
+        if issue.effort is None:
+            issue.effort = by_type[issue.type].value
@@ -0,0 +1,191 @@
+"""
+Stable identifiers for issues, used to match them across audits.
+
+Fingerprints are versioned: the first two hex characters are the algorithm
+version, followed by 16 hex characters of SHA-256. Unprefixed 16-character
+fingerprints (written before versioning) are version 0. FingerprintSet still
+matches those, so older baselines and acknowledgements keep working.
+
+Version 2 hashes an issue's catalog message and arguments rather than its
+detail, so the same finding has the same fingerprint in every language.
+Issues without a message (older results, plain-text details) hash the
+detail as version 1 did. Older versions are computed from the English
+detail, so a baseline written by an English run still matches a Spanish one.
+"""
+from __future__ import annotations
+
+import hashlib
+import json
+import re
+from collections.abc import Iterable, Iterator
+from urllib.parse import parse_qsl, urlencode, urlsplit
+
+from pydantic import RootModel, field_serializer
+
+from ..i18n import english_detail
+from ..utils.urlnorm import NormalizeOptions, normalize
+from .models import Issue
+
+FINGERPRINT_VERSION = 2
+LEGACY_VERSION = 0
+
+# Query parameters that vary per visit and never identify a page
+TRACKING_PARAMS = frozenset(
+    {"gclid", "fbclid", "msclkid", "dclid", "yclid", "mc_cid", "mc_eid", "_ga", "_gl", "igshid", "ref_src"}
+)
+_TRACKING_PREFIXES = ("utm_",)
+_URL_OPTIONS = NormalizeOptions(
+    trailing_slash="strip", strip_params=("utm_*", *sorted(TRACKING_PARAMS)), drop_www=True
+)
+
+_NUMBER = re.compile(r"\d+(?:[.,]\d+)*")
+
+
+def normalize_url(url: str) -> str:
+    """
+    Normalize a page URL for fingerprinting.
+
+    Applies urlnorm.normalize (without "www." and tracking parameters),
+    then drops the scheme and any trailing slash.
+    """
+    normalized = normalize(url, _URL_OPTIONS)
+    page = f"{normalized.host}{normalized.path.rstrip('/')}"
+    return f"{page}?{normalized.query}" if normalized.query else page
+
+
+def _normalize_url_v1(url: str) -> str:
+    """
+    The URL normalization of version 1, frozen so its fingerprints never change.
+
+    Drops the scheme, fragment, trailing slash, and tracking parameters,
+    lowercases the host, and sorts the remaining query parameters; ports,
+    dot segments, and the path's encoding are kept as written.
+    """
+    parts = urlsplit(url.strip())
+    host = parts.netloc.lower()
+    if host.startswith("www."):
+        host = host[4:]
+    path = parts.path.rstrip("/")
+    query = sorted(
+        (k, v)
+        for k, v in parse_qsl(parts.query, keep_blank_values=True)
+        if k.lower() not in TRACKING_PARAMS and not k.lower().startswith(_TRACKING_PREFIXES)
+    )
+    normalized = f"{host}{path}"
+    if query:
+        normalized += f"?{urlencode(query)}"
+    return normalized
+
+
+def normalize_text(text: str | None) -> str:
+    """Lowercase, collapse whitespace, and replace numbers (e.g. "5 pages") with "#"."""
+    return _NUMBER.sub("#", " ".join((text or "").split()).lower())
+
+
+def _digest(*parts: str) -> str:
+    return hashlib.sha256("\x1f".join(parts).encode("utf-8")).hexdigest()[:16]
+
+
+def _fingerprint_v0(issue: Issue) -> str:
+    """The original, unversioned algorithm; kept to match old baselines."""
+    return _digest(
+        issue.type.strip().lower(),
+        issue.url.strip().rstrip("/").lower(),
+        " ".join((english_detail(issue) or "").split()).lower(),
+    )
+
+
+def _fingerprint_v1(issue: Issue) -> str:
+    """Version 1 hashed the (English) detail; kept to match baselines written before version 2."""
+    detail = normalize_text(english_detail(issue))
+    return f"{1:02x}{_digest(normalize_text(issue.type), _normalize_url_v1(issue.url), detail)}"
+
+
+def _identity(issue: Issue) -> str:
+    """What distinguishes an issue from others of its type on the same page."""
+    if issue.message is None:
+        return normalize_text(issue.detail)
+    return normalize_text(json.dumps([issue.message, issue.args or []], default=str))
+
+
+def fingerprint(issue: Issue) -> str:
+    """
+    Fingerprint an issue by type, page, and message (or detail).
+
+    Severity is deliberately excluded so re-rating an issue does not make it "new",
+    numbers in the arguments are ignored so "72 chars" and "75 chars" match, and
+    the language the detail is written in does not matter.
+
+    Args:
+        issue: Issue to fingerprint
+
+    Returns:
+        Version-prefixed hex digest identifying the issue
+    """
+    digest = _digest(normalize_text(issue.type), normalize_url(issue.url), _identity(issue))
+    return f"{FINGERPRINT_VERSION:02x}{digest}"
+
+
+def fingerprints(issue: Issue) -> tuple[str, ...]:
+    """An issue's fingerprint followed by those older versions gave it, newest first."""
+    return fingerprint(issue), _fingerprint_v1(issue), _fingerprint_v0(issue)
+
+
+def fingerprint_version(value: str) -> int:
+    """
+    Return the algorithm version of a fingerprint.
+
+    Raises:
+        ValueError: If the value is not a fingerprint
+    """
+    if len(value) == 16 and all(c in "0123456789abcdef" for c in value):
+        return LEGACY_VERSION
+    if len(value) == 18 and all(c in "0123456789abcdef" for c in value):
+        return int(value[:2], 16)
+    raise ValueError(f"Not a fingerprint: {value!r}")
+
+
+class FingerprintSet(RootModel[set[str]]):
+    """
+    A set of fingerprints, serialized as a sorted list.
+
+    Membership checks accept issues or fingerprint strings. Issues also match
+    fingerprints written by older versions.
+    """
+
+    root: set[str] = set()
+
+    @classmethod
+    def of(cls, items: Iterable[Issue | str]) -> FingerprintSet:
+        """Build a set from issues and/or fingerprint strings."""
+        return cls({fingerprint(i) if isinstance(i, Issue) else i for i in items})
+
+    @field_serializer("root")
+    def _sorted(self, value: set[str]) -> list[str]:
+        return sorted(value)
+
+    def add(self, item: Issue | str) -> None:
+        """Add an issue's fingerprint (or a fingerprint string)."""
+        self.root.add(fingerprint(item) if isinstance(item, Issue) else item)
+
+    def match(self, issue: Issue) -> str | None:
+        """Return the stored fingerprint that matches an issue, if any."""
+        for candidate in fingerprints(issue):
+            if candidate in self.root:
+                return candidate
+        return None
+
+    def __contains__(self, item: object) -> bool:
+        if isinstance(item, Issue):
+            return self.match(item) is not None
+        return item in self.root
+
+    def __iter__(self) -> Iterator[str]:  # type: ignore[override]
+        return iter(sorted(self.root))
+
+    def __len__(self) -> int:
+        return len(self.root)
+
+    def legacy(self) -> list[str]:
+        """Fingerprints written by an older algorithm version."""
+        return [f for f in self if fingerprint_version(f) < FINGERPRINT_VERSION]
@@ -0,0 +1,136 @@
+"""
+Roll several audit results (e.g. one per site or per batch URL) into one.
+"""
+from __future__ import annotations
+
+from datetime import datetime
+from urllib.parse import urlparse
+
+from ..exceptions import AuditError
+from .fingerprint import fingerprint
+from .models import AuditResult, Issue
+from .score_model import compute as compute_score
+from .scoring import HealthScoreCalculator
+from .severity import severity_rank
+
+
+def _merge_issues(results: list[AuditResult]) -> tuple[list[Issue], list[dict]]:
+    """Dedupe issues by fingerprint; on disagreement keep the most severe / longest detail."""
+    merged: dict[str, Issue] = {}
+    conflicts: list[dict] = []
+
+    for result in results:
+        for issue in result.issues:
+            fp = fingerprint(issue)
+            kept = merged.get(fp)
+            if kept is None:
+                merged[fp] = issue.model_copy()
+                continue
+
+            if kept.severity != issue.severity or (kept.detail or "") != (issue.detail or ""):
+                conflicts.append(
+                    {
+                        "fingerprint": fp,
+                        "type": issue.type,
+                        "url": issue.url,
+                        "severities": sorted({kept.severity, issue.severity}),
+                    }
+                )
+            if severity_rank(issue.severity) > severity_rank(kept.severity):
+                kept.severity = issue.severity
+            if len(issue.detail or "") > len(kept.detail or ""):
+                kept.detail = issue.detail
+
+    return list(merged.values()), conflicts
+
+
+def absorb(result: AuditResult, partial: AuditResult) -> list[dict]:
+    """
+    Add the issues and meta of a partial result (e.g. an external tool's) to a
+    result in place.
+
+    Issues are deduped by fingerprint as merge does; the result keeps its site,
+    page count, score, and any other meta key it already has.
+
+    Returns:
+        Duplicate issues that disagreed, as in meta["merge_conflicts"] of merge
+    """
+    result.issues, conflicts = _merge_issues([result, partial])
+    for key, value in partial.meta.items():
+        result.meta.setdefault(key, value)
+    return conflicts
+
+
+def _weighted_score(results: list[AuditResult]) -> float | None:
+    scored = [
+        (r.meta["health_score"], r.pages_scanned)
+        for r in results
+        if isinstance(r.meta.get("health_score"), int | float)
+    ]
+    if not scored:
+        return None
+    total_pages = sum(pages for _, pages in scored)
+    if total_pages == 0:
+        return round(sum(score for score, _ in scored) / len(scored), 1)
+    return round(sum(score * pages for score, pages in scored) / total_pages, 1)
+
+
+def merge(results: list[AuditResult]) -> AuditResult:
+    """
+    Merge audit results into a single aggregate.
+
+    Pages are summed, issues sharing a fingerprint are kept once, and the
+    health score is the page-weighted average of the inputs (computed from
+    the merged issues when no input has a score). Per-site
+    numbers and the source list are kept in meta.
+
+    Args:
+        results: Results to merge, in the order they should be listed
+
+    Returns:
+        Aggregate AuditResult (the input itself if only one is given)
+
+    Raises:
+        AuditError: If results is empty
+    """
+    if not results:
+        raise AuditError("Nothing to merge: no audit results given")
+    if len(results) == 1:
+        return results[0]
+
+    issues, conflicts = _merge_issues(results)
+    hosts = {urlparse(r.site).netloc for r in results}
+    site = results[0].site if len(hosts) == 1 else f"{len(hosts)} sites"
+
+    meta: dict = {
+        "timestamp": datetime.utcnow().isoformat() + "Z",
+        "agent": results[0].meta.get("agent", "tinyseoai"),
+        "merged_from": [r.site for r in results],
+        "sites": [
+            {
+                "site": r.site,
+                "pages_scanned": r.pages_scanned,
+                "issues": len(r.issues),
+                "health_score": r.meta.get("health_score"),
+                "health_grade": r.meta.get("health_grade"),
+                "timestamp": r.meta.get("timestamp"),
+            }
+            for r in results
+        ],
+    }
+    pages_scanned = sum(r.pages_scanned for r in results)
+    score = _weighted_score(results)
+    if score is not None:
+        meta["health_score"] = score
+        meta["health_grade"] = HealthScoreCalculator()._get_letter_grade(score)
+    else:  # no input was scored (e.g. native audits): score the merged issues
+        meta.update(compute_score(issues, pages_scanned).meta())
+    if conflicts:
+        meta["merge_conflicts"] = conflicts
+
+    return AuditResult(
+        site=site,
+        pages_scanned=pages_scanned,
+        issues=issues,
+        meta=meta,
+    )
@@ -0,0 +1,78 @@
+"""
+Upgrading saved audit results to the current format.
+
+Results are saved with a format_version; files and history rows from before
+the field existed are version 0. Every load path goes through migrate(),
+which applies the steps below in order, so the rest of the code only sees
+the current format. Steps are pure: each takes a decoded result of one
+version and returns a new one of the next.
+"""
+from __future__ import annotations
+
+import copy
+from collections.abc import Callable
+from typing import Any
+
+from .models import FORMAT_VERSION, AuditResult
+
+Step = Callable[[dict[str, Any]], dict[str, Any]]
+
+
+def v0_to_v1(value: dict[str, Any]) -> dict[str, Any]:
+    """
+    Version 0 (no format_version) to 1.
+
+    The check registry's runs moved from meta["checks"], a list, to
+    meta["diagnostics"]["checks"].
+    """
+    value = copy.deepcopy(value)
+    meta = value.get("meta")
+    if isinstance(meta, dict) and isinstance(meta.get("checks"), list):
+        runs = meta.pop("checks")
+        meta.setdefault("diagnostics", {"checks": runs})
+    return value
+
+
+# STEPS[n] upgrades version n to n + 1
+STEPS: list[Step] = [v0_to_v1]
+
+
+def version_of(value: dict[str, Any]) -> int:
+    """The format version of a decoded result; 0 when it has none."""
+    version = value.get("format_version", 0)
+    if isinstance(version, bool) or not isinstance(version, int) or version < 0:
+        raise ValueError(f"format_version must be a whole number, got {version!r}")
+    return version
+
+
+def upgrade(value: Any) -> dict[str, Any]:
+    """
+    Bring a decoded result up to the current format, as a new dict.
+
+    Keys the model does not know (such as "ai_summary") are kept.
+
+    Raises:
+        ValueError: If the value is not an object, or is from a newer version
+    """
+    if not isinstance(value, dict):
+        raise ValueError(f"Expected an audit result object, got {type(value).__name__}")
+    version = version_of(value)
+    if version > FORMAT_VERSION:
+        raise ValueError(
+            f"Result format version {version} is newer than supported ({FORMAT_VERSION}); "
+            "upgrade tinyseoai to read it"
+        )
+    for step in STEPS[version:FORMAT_VERSION]:
+        value = step(value)
+    return {**value, "format_version": FORMAT_VERSION}
+
+
+def migrate(value: Any) -> AuditResult:
+    """
+    Load a decoded result of any version as the current model.
+
+    Raises:
+        ValueError: If the value cannot be upgraded, or does not match the model
+            (pydantic's ValidationError is a ValueError)
+    """
+    return AuditResult.model_validate(upgrade(value))
@@ -1,6 +1,18 @@
 from __future__ import annotations
 
-from pydantic import BaseModel
+import json
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+from pydantic import BaseModel, field_serializer, model_serializer
+
+from .severity import Severity
+
+if TYPE_CHECKING:
+    from .query import IssueQuery
+
+# Version of the saved result format; bump it with a step in migrate.py
+FORMAT_VERSION = 1
 
 
 class Issue(BaseModel):
@@ -8,9 +20,59 @@ class Issue(BaseModel):
     type: str
     severity: str = "low"
     detail: str | None = None
+    message: str | None = None  # catalog message the detail was rendered from (see i18n)
+    args: list[str | int | float | dict | list] | None = None  # its arguments (see i18n)
+    snapshot: str | None = None  # hash of the page's archived HTML (see store.snapshots)
+    effort: str | None = None  # trivial, moderate, or involved (see data.effort)
+    wcag: str | None = None  # WCAG success criterion, e.g. "1.1.1" (see data.wcag)
+    impact: str | None = None  # accessibility impact: minor, moderate, serious, or critical
+
+    @model_serializer(mode="wrap")
+    def _omit_plain_text(self, handler):
+        """Unset optional fields are left out: message and args, snapshot, effort, wcag, and impact."""
+        data = handler(self)
+        if self.message is None:
+            data.pop("message", None)
+            data.pop("args", None)
+        if self.snapshot is None:
+            data.pop("snapshot", None)
+        for name in ("effort", "wcag", "impact"):
+            if getattr(self, name) is None:
+                data.pop(name, None)
+        return data
+
+    @property
+    def level(self) -> Severity | None:
+        """Parsed severity (case-insensitive, aliases like "critical"); None if unknown."""
+        return Severity.parse(self.severity)
+
 
 class AuditResult(BaseModel):
+    format_version: int = FORMAT_VERSION
     site: str
     pages_scanned: int
     issues: list[Issue]
     meta: dict
+
+    @field_serializer("format_version")
+    def _newest_version(self, version: int) -> int:
+        """Results are always written in the current format."""
+        return FORMAT_VERSION
+
+    @classmethod
+    def load(cls, path: Path) -> AuditResult:
+        """Load a result from a summary.json / comprehensive_summary.json file, plain or compressed, of any version."""
+        from ..utils.io import read_json
+        from .migrate import migrate
+
+        return migrate(read_json(Path(path)))
+
+    def query(self) -> IssueQuery:
+        """Start a filter/sort query over this result's issues."""
+        from .query import IssueQuery
+
+        return IssueQuery(self.issues)
+
+    def to_json(self) -> str:
+        """Serialize exactly as the CLI writes it (2-space indent, no trailing newline)."""
+        return json.dumps(json.loads(self.model_dump_json()), indent=2)
@@ -0,0 +1,138 @@
+"""
+Executive summary written from the audit data, for reports without AI analysis.
+
+generate() turns a result into two to four sentences: how the score reads,
+which categories cost the most points, the single most costly issue type,
+and, given a diff, how things moved since the previous audit. Sentences
+come from the [summary] table of the locale catalogs. Most have a few
+phrasings; which one is used is picked from a hash of the result, so the
+same audit always reads the same while different audits do not all read
+alike.
+"""
+from __future__ import annotations
+
+import hashlib
+from collections import defaultdict
+from urllib.parse import urlparse
+
+from ..i18n import Locale, catalog, text
+from .baseline import suppressed_issues
+from .diff import AuditDiff
+from .models import AuditResult
+from .query import issue_category
+from .recommendations import issue_title
+from .score_model import explain
+
+# Lowest score of each band, best first
+_BANDS = [("excellent", 90), ("good", 75), ("fair", 50), ("poor", 0)]
+
+# Categories named in the sentence: the largest, until they cover this share of the points lost
+_DOMINANT_SHARE = 0.6
+_MAX_CATEGORIES = 2
+
+
+def _seed(result: AuditResult) -> int:
+    score = result.meta.get("health_score")
+    key = f"{result.site}|{score}|{result.pages_scanned}|{len(result.issues)}"
+    return int.from_bytes(hashlib.sha256(key.encode("utf-8")).digest()[:8], "big")
+
+
+def _phrase(kind: str, seed: int, salt: int, locale: Locale, *args) -> str:
+    """One phrasing of a sentence: kind_1, kind_2, ... from the locale's catalog, else English."""
+    prefix = f"{kind}_"
+    for source in (locale, Locale.EN):
+        table = catalog(source).get("summary", {})
+        variants = sorted(k for k in table if k.startswith(prefix) and k[len(prefix):].isdigit())
+        if variants:
+            key = variants[(seed >> (salt * 8)) % len(variants)]
+            return text(f"summary.{key}", args, source)
+    raise KeyError(f"No summary sentence {kind!r} in the catalog")
+
+
+def _count(noun: str, n: float, locale: Locale) -> str:
+    """"1 page", "3 pages", and so on."""
+    if n == 1:
+        return text(f"summary.{noun}_one", (), locale)
+    return text(f"summary.{noun}_other", (n,), locale)
+
+
+def _categories(names: list[str], locale: Locale) -> str:
+    labels = [text(f"summary.category_{name}", (), locale) for name in names]
+    return labels[0] if len(labels) == 1 else text("summary.and", labels, locale)
+
+
+def generate(result: AuditResult, locale: Locale = Locale.EN, changes: AuditDiff | None = None) -> str:
+    """
+    Write an executive summary of an audit from its data alone.
+
+    Args:
+        result: Audit result to summarize
+        locale: Language to write in
+        changes: Diff from the previous audit of the site, for a trend sentence
+
+    Returns:
+        Two to four sentences on one line; the same input always gives the same text
+    """
+    seed = _seed(result)
+    site = urlparse(result.site).netloc or result.site
+    if result.pages_scanned <= 0:
+        return _phrase("no_pages", seed, 0, locale, site)
+
+    pages = _count("pages", result.pages_scanned, locale)
+    score = result.meta.get("health_score")
+    if isinstance(score, int | float):
+        band = next(name for name, lowest in _BANDS if score >= lowest)
+        grade = result.meta.get("health_grade", "?")
+        sentences = [_phrase(f"score_{band}", seed, 0, locale, site, f"{score:g}", grade, pages)]
+    else:
+        issues = _count("issues", len(result.issues), locale)
+        sentences = [_phrase("unscored", seed, 0, locale, site, pages, issues)]
+
+    contributions = [c for c in explain(result) if c.points > 0]
+    if not result.issues:
+        sentences.append(_phrase("clean", seed, 1, locale))
+    elif contributions:
+        lost = sum(c.points for c in contributions)
+        by_category: dict[str, float] = defaultdict(float)
+        types = {i.type: issue_category(i).value for i in [*result.issues, *suppressed_issues(result)]}
+        for c in contributions:
+            by_category[types.get(c.issue_type, "other")] += c.points
+        ranked = sorted(by_category.items(), key=lambda item: (-item[1], item[0]))
+        dominant, covered = [], 0.0
+        for name, points in ranked[:_MAX_CATEGORIES]:
+            dominant.append(name)
+            covered += points
+            if covered >= lost * _DOMINANT_SHARE:
+                break
+        sentences.append(
+            _phrase(
+                "categories", seed, 1, locale,
+                _categories(dominant, locale),
+                round(covered),
+                _count("points", round(lost), locale),
+            )
+        )
+
+        top = contributions[0]
+        affected = len({i.url for i in result.issues if i.type == top.issue_type})
+        sentences.append(
+            _phrase(
+                "top_issue", seed, 2, locale,
+                issue_title(top.issue_type, locale),
+                _count("pages", affected, locale),
+                _count("points", round(top.points, 1), locale),
+            )
+        )
+
+    if changes is not None:
+        delta = changes.metrics.health_score
+        trend = "same" if not delta else "up" if delta > 0 else "down"
+        sentences.append(
+            _phrase(
+                f"trend_{trend}", seed, 3, locale,
+                _count("points", round(abs(delta or 0), 1), locale),
+                _count("resolved", len(changes.resolved), locale),
+                _count("new", len(changes.new), locale),
+            )
+        )
+    return " ".join(sentences)
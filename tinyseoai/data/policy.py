@@ -0,0 +1,159 @@
+"""
+Pass/fail policies for CI checks and notifications.
+"""
+from __future__ import annotations
+
+import tomllib
+from pathlib import Path
+
+from pydantic import BaseModel, Field
+
+from .diff import AuditDiff
+from .models import AuditResult
+from .query import IssueQuery
+from .scoring import HealthScoreCalculator
+from .severity import Category, Severity
+
+
+class CategoryPolicy(BaseModel):
+    """Limits that apply to one category only."""
+
+    max_critical: int | None = None
+    max_warnings: int | None = None
+
+
+class Violation(BaseModel):
+    """One policy rule the result breaks."""
+
+    rule: str  # e.g. "max_critical" or "categories.security.max_warnings"
+    actual: float
+    allowed: float
+    message: str
+
+
+class PolicyVerdict(BaseModel):
+    """Result of Policy.evaluate."""
+
+    violations: list[Violation] = Field(default_factory=list)
+    skipped: list[str] = Field(default_factory=list)  # rules that could not be checked
+
+    @property
+    def passed(self) -> bool:
+        return not self.violations
+
+
+class Policy(BaseModel):
+    """
+    Limits an audit must stay within.
+
+    "Critical" means high-severity issues and "warnings" medium-severity ones.
+    Unset limits are not checked.
+    """
+
+    max_critical: int | None = None
+    max_warnings: int | None = None
+    min_score: float | None = None
+    fail_on_new_issues: bool = False
+    categories: dict[str, CategoryPolicy] = Field(default_factory=dict)  # keyed by Category value
+
+    @classmethod
+    def from_toml(cls, text: str) -> Policy:
+        """
+        Parse a policy from TOML, either at the top level or under [policy].
+
+        Raises:
+            ValueError: On invalid TOML, unknown categories, or bad values
+        """
+        data = tomllib.loads(text)
+        policy = cls.model_validate(data.get("policy", data))
+        unknown = [
+            name
+            for name in policy.categories
+            if Category.parse(name) is Category.OTHER and name.lower() != Category.OTHER.value
+        ]
+        if unknown:
+            raise ValueError(f"Unknown categories in policy: {', '.join(sorted(unknown))}")
+        return policy
+
+    @classmethod
+    def load(cls, path: Path) -> Policy:
+        """Load a policy from a TOML file."""
+        return cls.from_toml(path.read_text(encoding="utf-8"))
+
+    def evaluate(self, result: AuditResult, diff: AuditDiff | None = None) -> PolicyVerdict:
+        """
+        Check a result (and optionally its diff against a previous audit).
+
+        Args:
+            result: Audit result to check
+            diff: Diff from the previous audit, needed for fail_on_new_issues
+
+        Returns:
+            Verdict listing every violated rule with actual and allowed values
+        """
+        verdict = PolicyVerdict()
+        query = result.query()
+        self._check_counts(verdict, "", query, self.max_critical, self.max_warnings)
+
+        for name, limits in self.categories.items():
+            scoped = query.category(name)
+            self._check_counts(
+                verdict, f"categories.{name}.", scoped, limits.max_critical, limits.max_warnings
+            )
+
+        if self.min_score is not None:
+            score = result.meta.get("health_score")
+            if score is None:
+                score = HealthScoreCalculator().calculate_health_score(
+                    result.issues, result.pages_scanned
+                )["overall_score"]
+            if score < self.min_score:
+                verdict.violations.append(
+                    Violation(
+                        rule="min_score",
+                        actual=score,
+                        allowed=self.min_score,
+                        message=f"Health score {score} is below {self.min_score}",
+                    )
+                )
+
+        if self.fail_on_new_issues:
+            if diff is None:
+                verdict.skipped.append("fail_on_new_issues: no previous audit to compare against")
+            elif diff.new:
+                verdict.violations.append(
+                    Violation(
+                        rule="fail_on_new_issues",
+                        actual=len(diff.new),
+                        allowed=0,
+                        message=f"{len(diff.new)} new issue(s) since the previous audit",
+                    )
+                )
+
+        return verdict
+
+    @staticmethod
+    def _check_counts(
+        verdict: PolicyVerdict,
+        prefix: str,
+        query: IssueQuery,
+        max_critical: int | None,
+        max_warnings: int | None,
+    ) -> None:
+        scope = f" in {prefix.split('.')[1]}" if prefix else ""
+        for rule, limit, severity, label in (
+            ("max_critical", max_critical, Severity.HIGH, "critical"),
+            ("max_warnings", max_warnings, Severity.MEDIUM, "warning"),
+        ):
+            if limit is None:
+                continue
+            count = query.severity(severity).count()
+            if count > limit:
+                verdict.violations.append(
+                    Violation(
+                        rule=f"{prefix}{rule}",
+                        actual=count,
+                        allowed=limit,
+                        message=f"{count} {label} issue(s){scope}, at most {limit} allowed",
+                    )
+                )
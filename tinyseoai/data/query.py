@@ -0,0 +1,138 @@
+"""
+Filtering and sorting of audit issues without copying them.
+"""
+from __future__ import annotations
+
+from collections import Counter
+from collections.abc import Callable, Iterator, Sequence
+from enum import Enum
+
+from .models import Issue
+from .scoring import IssueScorer
+from .severity import Category, Severity, count_by_severity, severity_rank
+
+
+class SortKey(str, Enum):
+    """Sort orders for IssueQuery.sort_by."""
+
+    SEVERITY = "severity"  # most severe first
+    TYPE = "type"
+    URL = "url"
+    PAGE_COUNT = "page_count"  # issue types affecting the most pages first
+    PRIORITY = "priority"  # IssueScorer priority, highest first
+
+
+_scorer = IssueScorer()
+
+
+def issue_category(issue: Issue) -> Category:
+    """Return the scoring category of an issue; unknown types naming a WCAG criterion are accessibility."""
+    category = Category.parse(_scorer._categorize_issue(issue.type))
+    if category == Category.OTHER and issue.wcag:
+        return Category.ACCESSIBILITY
+    return category
+
+
+class IssueQuery:
+    """
+    Chainable, lazy view over a list of issues.
+
+    Filters are applied while iterating; results are the original Issue
+    objects (or their indices), never copies. Ties keep input order, so
+    output is deterministic.
+
+    Example:
+        result.query().severity_at_least("medium").category("links").page_contains("/blog")
+    """
+
+    def __init__(self, issues: Sequence[Issue]):
+        self._issues = issues
+        self._filters: list[Callable[[Issue], bool]] = []
+        self._sort: tuple[SortKey, bool] | None = None
+
+    def _with(self, predicate: Callable[[Issue], bool]) -> IssueQuery:
+        query = IssueQuery(self._issues)
+        query._filters = [*self._filters, predicate]
+        query._sort = self._sort
+        return query
+
+    def severity_at_least(self, severity: Severity | str) -> IssueQuery:
+        """Keep issues at or above a severity (unknown severities are dropped)."""
+        minimum = severity if isinstance(severity, Severity) else Severity.parse(severity)
+        if minimum is None:
+            raise ValueError(f"Unknown severity: {severity}")
+        return self._with(lambda i: severity_rank(i.severity) >= minimum.rank)
+
+    def severity(self, severity: Severity | str) -> IssueQuery:
+        """Keep issues of exactly this severity."""
+        wanted = severity if isinstance(severity, Severity) else Severity.parse(severity)
+        if wanted is None:
+            raise ValueError(f"Unknown severity: {severity}")
+        return self._with(lambda i: Severity.parse(i.severity) is wanted)
+
+    def category(self, category: Category | str) -> IssueQuery:
+        """Keep issues in a scoring category (case-insensitive name or Category)."""
+        wanted = category if isinstance(category, Category) else Category.parse(category)
+        return self._with(lambda i: issue_category(i) is wanted)
+
+    def issue_type(self, *types: str) -> IssueQuery:
+        """Keep issues of the given types."""
+        wanted = set(types)
+        return self._with(lambda i: i.type in wanted)
+
+    def page_contains(self, text: str) -> IssueQuery:
+        """Keep issues whose page URL contains text."""
+        return self._with(lambda i: text in i.url)
+
+    def sort_by(self, key: SortKey | str, reverse: bool = False) -> IssueQuery:
+        """Sort the results; reverse flips the key's natural order."""
+        query = IssueQuery(self._issues)
+        query._filters = list(self._filters)
+        query._sort = (SortKey(key), reverse)
+        return query
+
+    def _matching(self) -> Iterator[int]:
+        for index, issue in enumerate(self._issues):
+            if all(predicate(issue) for predicate in self._filters):
+                yield index
+
+    def indices(self) -> list[int]:
+        """Return indices into the original issue list, filtered and sorted."""
+        if self._sort is None:
+            return list(self._matching())
+
+        key, reverse = self._sort
+        matching = list(self._matching())
+        issues = self._issues
+        pages = Counter(issues[i].type for i in matching)
+        sort_keys: dict[SortKey, Callable[[int], object]] = {
+            SortKey.SEVERITY: lambda i: -severity_rank(issues[i].severity),
+            SortKey.TYPE: lambda i: issues[i].type,
+            SortKey.URL: lambda i: issues[i].url,
+            SortKey.PAGE_COUNT: lambda i: -pages[issues[i].type],
+            SortKey.PRIORITY: lambda i: -_scorer.score_issue(issues[i])["priority"],
+        }
+        # sorted() is stable, so ties keep input order in both directions
+        return sorted(matching, key=sort_keys[key], reverse=reverse)
+
+    def __iter__(self) -> Iterator[Issue]:
+        if self._sort is None:
+            return (self._issues[i] for i in self._matching())
+        return (self._issues[i] for i in self.indices())
+
+    def collect(self) -> list[Issue]:
+        """Return the matching issues (same objects as in the result)."""
+        return list(self)
+
+    def count(self) -> int:
+        """Number of matching issues."""
+        return sum(1 for _ in self._matching())
+
+    def counts_by_severity(self) -> dict[str, int]:
+        """Matching issues per severity bucket."""
+        return count_by_severity(self._issues[i].severity for i in self._matching())
+
+    def counts_by_category(self) -> dict[str, int]:
+        """Matching issues per category, in Category order, omitting empty ones."""
+        counts = Counter(issue_category(self._issues[i]) for i in self._matching())
+        return {c.value: counts[c] for c in Category if counts[c]}
@@ -0,0 +1,85 @@
+"""
+Reachability samples of sites, taken between full audits.
+
+A Probe is one quick request to a URL: whether it answered, with what
+status, how fast, where it redirected, and how many days its certificate
+has left. Probes are cheap enough to take every few minutes; the history
+keeps them apart from audits (HistoryStore.insert_probes), and
+transitions() tells which sites went down, or came back, since the
+previous sample, so only changes are notified.
+"""
+from __future__ import annotations
+
+from collections.abc import Iterable
+from typing import Literal
+
+from pydantic import BaseModel
+
+TransitionKind = Literal["down", "recovered"]
+
+
+class Probe(BaseModel):
+    """One reachability sample of a URL."""
+
+    url: str
+    checked_at: str  # ISO timestamp
+    up: bool  # answered with a status below 400, after redirects
+    status_code: int | None = None  # final status; None when no response came
+    latency_ms: float | None = None
+    final_url: str | None = None  # where redirects led; None when there were none
+    tls_days_left: int | None = None  # None for http:// URLs or when the handshake failed
+    error: str | None = None  # e.g. "timeout" or "ConnectError"
+
+    @property
+    def outcome(self) -> str:
+        """Status code or error, for tables and notifications."""
+        if self.status_code is not None:
+            return str(self.status_code)
+        return self.error or "no response"
+
+
+class Transition(BaseModel):
+    """A URL whose state changed between its previous sample and this one."""
+
+    url: str
+    kind: TransitionKind
+    probe: Probe  # the new sample
+    since: str | None = None  # when the previous state was sampled
+
+
+def transitions(previous: dict[str, Probe], current: Iterable[Probe]) -> list[Transition]:
+    """
+    URLs that went down or recovered since their previous sample.
+
+    A URL with no previous sample has no transition, so the first run
+    after adding a site does not notify even when it is down.
+
+    Args:
+        previous: Latest earlier sample per URL
+        current: New samples
+
+    Returns:
+        One Transition per URL whose up state changed, in the order of current
+    """
+    changes = []
+    for probe in current:
+        before = previous.get(probe.url)
+        if before is None or before.up == probe.up:
+            continue
+        changes.append(
+            Transition(
+                url=probe.url,
+                kind="recovered" if probe.up else "down",
+                probe=probe,
+                since=before.checked_at,
+            )
+        )
+    return changes
+
+
+def uptime_share(probes: Iterable[Probe]) -> float | None:
+    """Percentage of samples that were up, to one decimal; None without samples."""
+    probes = list(probes)
+    if not probes:
+        return None
+    return round(100 * sum(p.up for p in probes) / len(probes), 1)
@@ -0,0 +1,29 @@
+"""
+Short, human-readable fix recommendations per issue type for reports.
+"""
+from __future__ import annotations
+
+from ..i18n import Locale, catalog, lookup
+
+# English advice, from the [recommendations] table of locales/en.toml
+ISSUE_RECOMMENDATIONS: dict[str, str] = catalog(Locale.EN)["recommendations"]
+
+
+def recommendation_for(issue_type: str, locale: Locale = Locale.EN) -> str | None:
+    """
+    Look up the fix recommendation for an issue type.
+
+    Args:
+        issue_type: Issue type, e.g. "title_missing"
+        locale: Language of the advice (English when the locale has none)
+
+    Returns:
+        Recommendation text, or None if there is no canned advice
+    """
+    return lookup("recommendations", issue_type, locale)
+
+
+def issue_title(issue_type: str, locale: Locale = Locale.EN) -> str:
+    """Turn an issue type into a heading, e.g. "title_missing" -> "Title missing"."""
+    title = lookup("titles", issue_type, locale)
+    return title if title is not None else issue_type.replace("_", " ").strip().capitalize()
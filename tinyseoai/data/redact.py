@@ -0,0 +1,167 @@
+"""
+Redact audit results before they are shared: mask hostnames, strip query
+strings, and remove custom patterns from every string of a result, including
+issue details, meta, and the AI summary.
+
+Hostnames are masked consistently: each host found in a URL of the result
+gets one mask (the audited site's host is host1.example), and every mention
+of it, in a URL or in prose, is replaced by that mask, so the report still
+reads coherently.
+"""
+from __future__ import annotations
+
+import json
+import re
+from collections.abc import Iterable
+from typing import Any
+from urllib.parse import urlparse
+
+from pydantic import BaseModel, Field, field_validator
+
+from ..i18n import result_locale, text
+from .models import AuditResult
+
+REDACTED = "[redacted]"
+MASK_DOMAIN = "example"  # reserved for documentation (RFC 2606), never a real site
+
+_URL_HOST = re.compile(r"https?://(?:[^@/\s\"'<>]*@)?([A-Za-z0-9.-]+)", re.IGNORECASE)
+# An absolute URL, or a path at the start of a word, followed by a query string
+_QUERY = re.compile(r"(https?://[^\s?#\"'<>]*|(?<![\w/.])/[^\s?#\"'<>]*)\?[^\s#\"'<>]*")
+
+
+class RedactionOptions(BaseModel):
+    """What to remove from a result before rendering it for someone else."""
+
+    strip_query_strings: bool = False  # may carry tokens or session ids
+    mask_hostnames: bool = False  # example.com -> host1.example, everywhere
+    custom_patterns: list[str] = Field(default_factory=list)  # regexes replaced by [redacted]
+
+    @field_validator("custom_patterns")
+    @classmethod
+    def _compiles(cls, patterns: list[str]) -> list[str]:
+        for pattern in patterns:
+            try:
+                re.compile(pattern)
+            except re.error as e:
+                raise ValueError(f"invalid pattern {pattern!r}: {e}") from None
+        return patterns
+
+    @property
+    def active(self) -> bool:
+        """True when redaction changes anything."""
+        return self.strip_query_strings or self.mask_hostnames or bool(self.custom_patterns)
+
+
+class Redactor:
+    """Redacts strings with one host-to-mask mapping, so every mention of a host agrees."""
+
+    def __init__(self, options: RedactionOptions, hosts: Iterable[str] = ()):
+        self.options = options
+        self.masks: dict[str, str] = {}
+        if options.mask_hostnames:
+            for host in hosts:
+                host = host.lower().strip(".")
+                if host and host not in self.masks:
+                    self.masks[host] = f"host{len(self.masks) + 1}.{MASK_DOMAIN}"
+        # Longest first, so www.example.com is not masked as www.<mask of example.com>
+        names = sorted(self.masks, key=len, reverse=True)
+        self._hosts = (
+            re.compile(
+                r"(?<![\w-])(" + "|".join(map(re.escape, names)) + r")(?![\w-])", re.IGNORECASE
+            )
+            if names
+            else None
+        )
+        self._patterns = [re.compile(p) for p in options.custom_patterns]
+
+    def text(self, value: str) -> str:
+        """Redact one string."""
+        if self._hosts is not None:
+            value = self._hosts.sub(lambda m: self.masks[m.group(1).lower()], value)
+        if self.options.strip_query_strings:
+            value = _QUERY.sub(r"\1", value)
+        for pattern in self._patterns:
+            value = pattern.sub(REDACTED, value)
+        return value
+
+    def value(self, value: Any) -> Any:
+        """Redact every string (dict keys included) of a decoded JSON value, as a copy."""
+        if isinstance(value, str):
+            return self.text(value)
+        if isinstance(value, dict):
+            return {
+                self.text(k) if isinstance(k, str) else k: self.value(v) for k, v in value.items()
+            }
+        if isinstance(value, list):
+            return [self.value(item) for item in value]
+        return value
+
+
+def hosts_of(value: Any) -> list[str]:
+    """Hosts of the URLs in a decoded JSON value, in order of first appearance."""
+    found: dict[str, None] = {}
+
+    def walk(item: Any) -> None:
+        if isinstance(item, str):
+            for host in _URL_HOST.findall(item):
+                found.setdefault(host.lower().strip("."))
+        elif isinstance(item, dict):
+            for key, child in item.items():
+                walk(key)
+                walk(child)
+        elif isinstance(item, list):
+            for child in item:
+                walk(child)
+
+    walk(value)
+    return list(found)
+
+
+def redact_summaries(
+    summaries: list[dict[str, Any]], options: RedactionOptions
+) -> list[dict[str, Any]]:
+    """
+    Redact loaded results (summary.json, optionally with "ai_summary") as new dicts.
+
+    The results share one host mapping, so an audit and the one it is compared
+    with mask the same host the same way. Issue details are redacted as
+    written; an issue whose catalog arguments would render differently is kept
+    with its plain detail only, so a report in another language cannot bring
+    redacted text back.
+    """
+    if not options.active:
+        return summaries
+    sites = [urlparse(s.get("site", "")).hostname for s in summaries]
+    redactor = Redactor(options, [h for h in sites if h] + hosts_of(summaries))
+    return [_consistent(redactor.value(summary)) for summary in summaries]
+
+
+def redact_summary(summary: dict[str, Any], options: RedactionOptions) -> dict[str, Any]:
+    """Redact one loaded result as a new dict (see redact_summaries)."""
+    return redact_summaries([summary], options)[0]
+
+
+def _consistent(redacted: dict[str, Any]) -> dict[str, Any]:
+    locale = result_locale(redacted.get("meta"))
+    for issue in redacted.get("issues", []):
+        if not isinstance(issue, dict) or not issue.get("message"):
+            continue
+        try:
+            rendered = text(issue["message"], issue.get("args") or (), locale)
+        except (KeyError, IndexError, ValueError):
+            rendered = None
+        if rendered != issue.get("detail"):
+            issue.pop("message")
+            issue.pop("args", None)
+    return redacted
+
+
+def redact(result: AuditResult, options: RedactionOptions) -> None:
+    """Redact an audit result in place (see redact_summary)."""
+    if not options.active:
+        return
+    redacted = AuditResult.model_validate(
+        redact_summary(json.loads(result.model_dump_json()), options)
+    )
+    for name in AuditResult.model_fields:
+        setattr(result, name, getattr(redacted, name))
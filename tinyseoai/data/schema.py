@@ -0,0 +1,104 @@
+"""
+JSON Schema for the audit result format and lenient payload validation.
+"""
+from __future__ import annotations
+
+from typing import Any
+
+from pydantic import BaseModel, ValidationError
+
+from .models import AuditResult
+
+SCHEMA_ID = "https://tinyseoai.com/schemas/audit-result.schema.json"
+
+# Friendly names for pydantic's type error codes
+_EXPECTED_TYPES = {
+    "string_type": "string",
+    "int_type": "integer",
+    "int_parsing": "integer",
+    "int_from_float": "integer",
+    "dict_type": "object",
+    "list_type": "array",
+    "model_type": "object",
+    "model_attributes_type": "object",
+}
+
+
+class ValidationIssue(BaseModel):
+    """A single mismatch between a payload and the audit result schema."""
+
+    path: str
+    message: str
+
+    def __str__(self) -> str:
+        return f"{self.path}: {self.message}"
+
+
+def schema() -> dict[str, Any]:
+    """
+    Return the JSON Schema document for AuditResult.
+
+    Returns:
+        JSON Schema (draft 2020-12) as a dict
+    """
+    doc = AuditResult.model_json_schema()
+    return {
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$id": SCHEMA_ID,
+        **doc,
+    }
+
+
+def _json_type(value: Any) -> str:
+    if value is None:
+        return "null"
+    if isinstance(value, bool):
+        return "boolean"
+    if isinstance(value, int | float):
+        return "number"
+    if isinstance(value, str):
+        return "string"
+    if isinstance(value, list):
+        return "array"
+    if isinstance(value, dict):
+        return "object"
+    return type(value).__name__
+
+
+def _format_path(loc: tuple) -> str:
+    path = ""
+    for part in loc:
+        if isinstance(part, int):
+            path += f"[{part}]"
+        else:
+            path += f".{part}" if path else str(part)
+    return path or "$"
+
+
+def validate(value: Any) -> list[ValidationIssue]:
+    """
+    Check a decoded JSON payload against the audit result schema.
+
+    Never raises; every mismatch is reported with its path so callers can
+    warn and still use whatever parsed.
+
+    Args:
+        value: Decoded JSON (usually a dict)
+
+    Returns:
+        List of issues, empty if the payload is valid
+    """
+    try:
+        AuditResult.model_validate(value)
+    except ValidationError as e:
+        issues = []
+        for err in e.errors():
+            if err["type"] == "missing":
+                message = "required field is missing"
+            elif err["type"] in _EXPECTED_TYPES:
+                message = f"expected {_EXPECTED_TYPES[err['type']]}, got {_json_type(err['input'])}"
+            else:
+                message = err["msg"]
+            issues.append(ValidationIssue(path=_format_path(err["loc"]), message=message))
+        return issues
+    return []
@@ -0,0 +1,234 @@
+"""
+Configurable health-score model for "custom scores" shown next to the CLI score.
+"""
+from __future__ import annotations
+
+import json
+import math
+import tomllib
+from collections import defaultdict
+from pathlib import Path
+from typing import Literal
+
+from pydantic import BaseModel, Field, field_validator
+
+from .baseline import suppressed_issues
+from .models import AuditResult, Issue
+from .query import issue_category
+from .scoring import HealthScoreCalculator, IssueScorer
+from .severity import Severity
+
+_scorer = IssueScorer()
+
+# HealthScoreCalculator's severity multipliers
+DEFAULT_SEVERITY_WEIGHTS = {"high": 1.2, "medium": 1.0, "low": 0.8, "info": 0.5}
+
+# Page-count dampening curves: how much n affected pages of one issue type count
+_DAMPENING = {
+    "linear": lambda n: float(n),
+    "sqrt": lambda n: math.sqrt(n),
+    "log": lambda n: 1 + math.log(n),
+}
+
+
+class ScoreModel(BaseModel):
+    """
+    Weights for recompute_score.
+
+    The defaults reproduce HealthScoreCalculator: each issue costs
+    (impact x severity weight x impact_factor + density_penalty) / pages,
+    with impact taken from ISSUE_IMPACT_SCORES.
+    """
+
+    severity_weights: dict[str, float] = Field(default_factory=lambda: dict(DEFAULT_SEVERITY_WEIGHTS))
+    unknown_severity_weight: float = 1.0
+    category_multipliers: dict[str, float] = Field(default_factory=dict)  # Category value -> multiplier
+    page_dampening: Literal["linear", "sqrt", "log"] = "linear"
+    impact_factor: float = 2.0
+    density_penalty: float = 5.0
+    exclude_suppressed: bool = False  # leave baselined issues out of the score
+
+    @field_validator("severity_weights")
+    @classmethod
+    def _fill_severity_defaults(cls, value: dict[str, float]) -> dict[str, float]:
+        # Overriding one severity keeps the defaults for the others
+        return {**DEFAULT_SEVERITY_WEIGHTS, **{k.lower(): v for k, v in value.items()}}
+
+    @classmethod
+    def load(cls, path: Path) -> ScoreModel:
+        """
+        Load a score model from a .toml or .json file.
+
+        Args:
+            path: Model file
+
+        Returns:
+            Parsed ScoreModel (missing keys keep their defaults)
+        """
+        text = path.read_text(encoding="utf-8")
+        data = tomllib.loads(text) if path.suffix.lower() == ".toml" else json.loads(text)
+        return cls.model_validate(data)
+
+    def issue_cost(self, issue: Issue) -> float:
+        """Undampened score cost of one issue, before dividing by pages."""
+        level = issue.level
+        weight = self.severity_weights[level.value] if level else self.unknown_severity_weight
+        multiplier = self.category_multipliers.get(issue_category(issue).value, 1.0)
+        impact = _scorer.get_impact_score(issue.type)
+        return impact * weight * multiplier * self.impact_factor + self.density_penalty
+
+
+class ScoreMetrics(BaseModel):
+    """Issue counts and the 0–100 score computed from them."""
+
+    total: int = 0
+    critical: int = 0  # high
+    warning: int = 0  # medium and low, plus unknown severities
+    info: int = 0
+    score: int = 0
+    grade: str = "F"
+    deductions: dict[str, float] = Field(default_factory=dict)  # points lost per issue type
+
+    def meta(self) -> dict:
+        """The keys audit results carry in meta."""
+        return {"health_score": self.score, "health_grade": self.grade}
+
+
+class Contribution(BaseModel):
+    """Points one issue type costs the score, with the math behind them."""
+
+    issue_type: str
+    issues: int  # issues of the type, usually one per affected page
+    weight: float  # summed issue costs / pages scanned: the points before dampening
+    page_factor: float  # dampened issue count / issue count (1.0 with linear dampening)
+    points: float  # weight x page_factor, scaled down when all points exceed 100
+
+    @property
+    def formula(self) -> str:
+        """The math, e.g. "4.84 x 1.00"."""
+        return f"{self.weight:.2f} x {self.page_factor:.2f}"
+
+
+def _type_costs(
+    issues: list[Issue], pages_scanned: int, model: ScoreModel
+) -> dict[str, tuple[int, float, float]]:
+    """Issue count, weight, and page factor per issue type (see Contribution)."""
+    by_type: dict[str, list[Issue]] = defaultdict(list)
+    for issue in issues:
+        by_type[issue.type].append(issue)
+    damp = _DAMPENING[model.page_dampening]
+    return {
+        issue_type: (
+            len(group),
+            sum(model.issue_cost(i) for i in group) / pages_scanned,
+            damp(len(group)) / len(group),
+        )
+        for issue_type, group in by_type.items()
+    }
+
+
+def compute(issues: list[Issue], pages_scanned: int, model: ScoreModel | None = None) -> ScoreMetrics:
+    """
+    Count issues by severity and score them under a score model.
+
+    Each issue costs (impact x severity weight x category multiplier x
+    impact_factor + density_penalty). Issues of one type are then dampened
+    by how many there are (page_dampening; "linear" adds them up), and the
+    total is divided by pages_scanned and taken off 100. So with the
+    defaults one high-severity issue of impact 8 on one of ten pages costs
+    (8 x 1.2 x 2 + 5) / 10 = 2.42 points.
+
+    The result depends only on the arguments. No issues on a scanned site
+    scores 100; a result with no pages scanned scores 0.
+
+    Args:
+        issues: Issues to score (suppression is up to the caller)
+        pages_scanned: Pages the issues were found on
+        model: Score model (defaults reproduce the CLI score)
+
+    Returns:
+        ScoreMetrics with counts, the rounded score clamped to 0–100, its
+        letter grade, and the points deducted per issue type
+    """
+    model = model or ScoreModel()
+    metrics = ScoreMetrics(total=len(issues))
+    for issue in issues:
+        level = issue.level
+        if level is Severity.HIGH:
+            metrics.critical += 1
+        elif level is Severity.INFO:
+            metrics.info += 1
+        else:
+            metrics.warning += 1
+    if pages_scanned <= 0:
+        return metrics
+
+    penalty = 0.0
+    for issue_type, (_, weight, page_factor) in _type_costs(issues, pages_scanned, model).items():
+        cost = weight * page_factor
+        metrics.deductions[issue_type] = round(cost, 2)
+        penalty += cost
+
+    score = 100 - penalty
+    metrics.score = int(round(min(100.0, max(0.0, score))))
+    metrics.grade = HealthScoreCalculator()._get_letter_grade(metrics.score)
+    return metrics
+
+
+def _scored_issues(result: AuditResult, model: ScoreModel) -> list[Issue]:
+    issues = list(result.issues)
+    if not model.exclude_suppressed:
+        issues += suppressed_issues(result)
+    return issues
+
+
+def explain(result: AuditResult, model: ScoreModel | None = None) -> list[Contribution]:
+    """
+    Attribute the points a result loses to its issue types, largest first.
+
+    The points add up to 100 minus the unrounded score: when the issues cost
+    more than 100 points (the score is clamped at 0), every contribution is
+    scaled down by the same factor.
+
+    Args:
+        result: Audit result to explain (suppressed issues count as in recompute_score)
+        model: Score model (defaults reproduce the CLI score)
+
+    Returns:
+        One Contribution per issue type; empty when no pages were scanned
+    """
+    model = model or ScoreModel()
+    if result.pages_scanned <= 0:
+        return []
+    costs = _type_costs(_scored_issues(result, model), result.pages_scanned, model)
+    penalty = sum(weight * factor for _, weight, factor in costs.values())
+    scale = min(1.0, 100 / penalty) if penalty > 0 else 1.0
+    contributions = [
+        Contribution(
+            issue_type=issue_type,
+            issues=count,
+            weight=round(weight, 4),
+            page_factor=round(factor, 4),
+            points=round(weight * factor * scale, 2),
+        )
+        for issue_type, (count, weight, factor) in costs.items()
+    ]
+    return sorted(contributions, key=lambda c: (-c.points, c.issue_type))
+
+
+def recompute_score(result: AuditResult, model: ScoreModel | None = None) -> int:
+    """
+    Recompute a 0–100 health score for a result under a score model.
+
+    This never changes the result; callers show it alongside meta.health_score.
+    Issues suppressed by a baseline still count unless model.exclude_suppressed.
+
+    Args:
+        result: Audit result to score
+        model: Score model (defaults reproduce the CLI score)
+
+    Returns:
+        Score clamped to 0–100
+    """
+    model = model or ScoreModel()
+    return compute(_scored_issues(result, model), result.pages_scanned, model).score
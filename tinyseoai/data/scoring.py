@@ -3,7 +3,11 @@ Scoring algorithms for SEO impact/effort analysis and health score calculation.
 """
 from __future__ import annotations
 
+import math
+from collections import defaultdict
+
 from .models import Issue
+from .severity import Category, Severity
 
 # Impact scores for different issue types (1-10, higher = more impact)
 ISSUE_IMPACT_SCORES = {
@@ -11,7 +15,11 @@ ISSUE_IMPACT_SCORES = {
     "no_https": 10,
     "duplicate_content": 10,
     "noindex_directive": 10,
+    "important_page_noindex": 10,
     "ssl_expired": 10,
+    "tls_certificate_expired": 10,
+    "tls_hostname_mismatch": 10,
+    "tls_handshake_failed": 10,
     "missing_viewport": 10,
     "render_blocking_javascript": 9,
     "multiple_canonical_tags": 9,
@@ -32,9 +40,22 @@ ISSUE_IMPACT_SCORES = {
     "meta_description_missing": 6,
     "missing_hsts": 6,
     "render_blocking_css": 6,
+    "large_transfer_size": 5,
+    "http2_unsupported": 5,
+    "asset_cache_headers_missing": 5,
+    "asset_cache_short": 5,
     "orphan_page": 6,
     "thin_content": 6,
+    "robots_blocked_signals": 5,
+    "soft_404": 6,
+    "soft_404_catch_all": 6,
+    "pagination_canonical_to_first": 6,
+    "pagination_first_page_noindex": 6,
+    "pagination_broken_chain": 5,
+    "parameter_duplicates": 6,
     "potential_keyword_stuffing": 6,
+    "keyword_stuffing": 6,
+    "keyword_missing_title": 5,
     "near_duplicate_content": 6,
     "broken_link": 5,
     "missing_html_lang": 5,
@@ -48,9 +69,12 @@ ISSUE_IMPACT_SCORES = {
     "missing_twitter_card": 4,
     "missing_favicon": 4,
     "page_too_deep": 4,
+    "dead_end_page": 3,
     "empty_anchor_text": 3,
     "generic_anchor_text": 3,
     "missing_etag": 3,
+    "cache_no_store": 3,
+    "cache_headers_conflict": 3,
     "long_sentences": 3,
 
     # Info/Minor (1-2)
@@ -58,9 +82,13 @@ ISSUE_IMPACT_SCORES = {
     "noarchive_directive": 2,
     "missing_preconnect": 2,
     "compression_not_optimal": 2,
+    "cache_immutable_missing": 2,
     "missing_twitter_site": 1,
     "missing_apple_touch_icon": 1,
     "complex_vocabulary": 1,
+    "tls_verification_disabled": 1,
+    "inconsistent_anchor_text": 1,
+    "long_anchor_text": 1,
 }
 
 
@@ -71,6 +99,7 @@ ISSUE_EFFORT_SCORES = {
     "duplicate_content": 8,  # May require content rewriting
     "orphan_page": 8,  # Requires site structure changes
     "page_too_deep": 7,  # Requires navigation restructuring
+    "dead_end_page": 4,  # Add links to related pages
     "near_duplicate_content": 7,  # Content rewriting
     "potential_keyword_stuffing": 7,  # Content rewriting
     "thin_content": 7,  # Content creation
@@ -82,6 +111,7 @@ ISSUE_EFFORT_SCORES = {
     "large_html_size": 5,  # Code optimization
     "broken_link": 4,  # Find and fix or redirect
     "missing_canonical": 4,  # Template modification
+    "parameter_duplicates": 4,  # Canonicals or links in templates
     "missing_og_tag": 4,  # Template modification
 
     # Low Effort (1-3)
@@ -94,18 +124,44 @@ ISSUE_EFFORT_SCORES = {
     "missing_viewport": 1,  # Add meta tag
     "missing_html_lang": 1,  # Add attribute
     "no_compression": 3,  # Server configuration
+    "http2_unsupported": 3,  # Server configuration
     "missing_hsts": 2,  # Server configuration
     "missing_csp": 3,  # Server configuration
     "missing_favicon": 2,  # Upload file
     "missing_twitter_card": 2,  # Add meta tags
     "empty_anchor_text": 1,  # Edit text
     "generic_anchor_text": 1,  # Edit text
+    "inconsistent_anchor_text": 2,  # Edit text on several pages
+    "long_anchor_text": 1,  # Edit text
     "images_without_dimensions": 2,  # Add attributes
     "missing_preconnect": 1,  # Add link tag
     "missing_etag": 2,  # Server configuration
 }
 
 
+# Fixed once in a shared template or server setting, however many pages they affect
+TEMPLATE_FIXES = frozenset(
+    {
+        "title_missing",
+        "meta_description_missing",
+        "missing_canonical",
+        "missing_viewport",
+        "missing_html_lang",
+        "missing_og_tag",
+        "missing_twitter_card",
+        "missing_twitter_site",
+        "missing_favicon",
+        "missing_apple_touch_icon",
+        "missing_preconnect",
+        "missing_hsts",
+        "missing_csp",
+        "missing_etag",
+        "no_compression",
+    }
+)
+TEMPLATE_BONUS = 1.25
+
+
 class IssueScorer:
     """Score issues based on impact and effort."""
 
@@ -174,12 +230,12 @@ class IssueScorer:
 
         # Adjust for severity
         severity_multipliers = {
-            "high": 1.2,
-            "medium": 1.0,
-            "low": 0.8,
-            "info": 0.5,
+            Severity.HIGH: 1.2,
+            Severity.MEDIUM: 1.0,
+            Severity.LOW: 0.8,
+            Severity.INFO: 0.5,
         }
-        multiplier = severity_multipliers.get(issue.severity, 1.0)
+        multiplier = severity_multipliers.get(issue.level, 1.0)
 
         return {
             "type": issue.type,
@@ -192,61 +248,197 @@ class IssueScorer:
         }
 
     def _categorize_issue(self, issue_type: str) -> str:
-        """Categorize issue into broad categories."""
+        """Categorize issue into broad categories (a Category value)."""
         categories = {
-            "content": [
+            Category.CONTENT: [
                 "title_missing",
                 "title_too_long",
                 "meta_description_missing",
                 "duplicate_title",
                 "duplicate_meta_description",
+                "near_duplicate_title",
+                "near_duplicate_meta_description",
                 "thin_content",
                 "very_thin_content",
+                "boilerplate_content",
                 "duplicate_content",
                 "near_duplicate_content",
                 "potential_keyword_stuffing",
+                "keyword_missing_title",
+                "keyword_missing_h1",
+                "keyword_missing_first_paragraph",
+                "keyword_missing_meta_description",
+                "keyword_stuffing",
+                "feed_missing",
+                "feed_broken",
+                "feed_served_as_html",
+                "feed_invalid",
+                "feed_empty",
+                "feed_bad_dates",
+                "h1_missing",
+                "multiple_h1",
+                "h1_too_long",
+                "heading_level_skipped",
+                "heading_empty",
+                "heading_image_without_alt",
+                "aria_heading",
             ],
-            "technical": [
+            Category.TECHNICAL: [
                 "no_https",
                 "ssl_expired",
                 "missing_canonical",
                 "multiple_canonical_tags",
+                "host_variants_duplicated",
+                "trailing_slash_duplicated",
+                "redirect_policy_inconsistent",
+                "canonical_conflicts_redirect_policy",
                 "noindex_directive",
+                "important_page_noindex",
+                "nofollow_directive",
                 "conflicting_robots_directives",
-                "missing_viewport",
+                "robots_blocked_signals",
+                "soft_404",
+                "soft_404_catch_all",
+                "pagination_canonical_to_first",
+                "pagination_broken_chain",
+                "pagination_duplicate_titles",
+                "pagination_first_page_noindex",
+                "parameter_duplicates",
+                "mixed_languages_without_hreflang",
+                "amphtml_broken",
+                "amp_attribute_missing",
+                "amp_runtime_missing",
+                "amp_disallowed_script",
+                "amp_canonical_missing",
+                "amp_canonical_mismatch",
             ],
-            "links": [
+            Category.LINKS: [
                 "broken_link",
                 "orphan_page",
+                "redirect_chain",
+                "redirect_loop",
+                "redirect_too_many_hops",
+                "redirect_https_downgrade",
+                "redirect_to_error",
+                "meta_refresh_redirect",
+                "temporary_redirect_stable",
+                "redirect_chain_mixed_types",
+                "method_preserving_redirect",
                 "page_too_deep",
+                "dead_end_page",
+                "internal_link_hubs",
                 "empty_anchor_text",
                 "generic_anchor_text",
+                "inconsistent_anchor_text",
+                "long_anchor_text",
             ],
-            "performance": [
+            Category.PERFORMANCE: [
                 "large_html_size",
                 "no_compression",
+                "compression_not_optimal",
+                "large_transfer_size",
+                "asset_cache_headers_missing",
+                "asset_cache_short",
+                "cache_immutable_missing",
+                "cache_no_store",
+                "cache_headers_conflict",
                 "render_blocking_css",
                 "render_blocking_javascript",
                 "images_without_dimensions",
+                "images_not_lazy_loaded",
+                "image_too_large",
+                "image_legacy_format",
+                "slow_ttfb",
+                "http2_unsupported",
+                "http3_unreachable",
             ],
-            "social": [
+            Category.SOCIAL: [
                 "missing_og_tag",
                 "missing_twitter_card",
                 "missing_favicon",
+                "favicon_broken",
+                "missing_apple_touch_icon",
+                "manifest_missing",
+                "manifest_broken",
+                "manifest_icons_missing",
+                "manifest_icon_broken",
+                "manifest_icon_size_mismatch",
+                "theme_color_missing",
+                "og_title_missing",
+                "og_description_missing",
+                "og_image_missing",
+                "og_url_missing",
+                "og_tag_duplicated",
+                "og_title_too_long",
+                "og_description_too_long",
+                "og_image_not_absolute",
+                "og_image_unreachable",
+                "og_image_not_image",
+                "og_image_too_small",
+                "og_url_not_absolute",
+                "og_url_mismatch",
+                "twitter_card_missing",
+                "twitter_card_invalid",
+                "twitter_tag_duplicated",
+                "twitter_image_not_absolute",
+                "twitter_image_aspect_ratio",
             ],
-            "security": [
+            Category.SECURITY: [
                 "no_https",
                 "ssl_expired",
                 "missing_hsts",
                 "missing_csp",
+                "hsts_invalid",
+                "hsts_short_max_age",
+                "hsts_missing_include_subdomains",
+                "missing_x_content_type_options",
+                "invalid_x_content_type_options",
+                "missing_clickjacking_protection",
+                "invalid_x_frame_options",
+                "csp_unsafe_inline",
+                "csp_no_script_restriction",
+                "missing_referrer_policy",
+                "referrer_policy_unsafe",
+                "missing_permissions_policy",
+                "security_headers_grade",
+                "tls_verification_disabled",
+                "tls_handshake_failed",
+                "tls_certificate_expired",
+                "tls_certificate_expiring",
+                "tls_hostname_mismatch",
+                "tls_chain_invalid",
+                "tls_legacy_protocol",
+                "dns_unresolved",
+                "dns_cname_at_apex",
+                "dns_no_ipv6",
+            ],
+            Category.ACCESSIBILITY: [
+                "img_alt_missing",
+                "img_alt_empty_in_link",
+                "missing_html_lang",
+                "html_lang_invalid",
+                "html_lang_mismatch",
+            ],
+            Category.MOBILE: [
+                "missing_viewport",
+                "viewport_missing_device_width",
+                "viewport_missing_initial_scale",
+                "viewport_fixed_width",
+                "viewport_zoom_disabled",
+                "viewport_zoom_limited",
+                "fixed_width_layout",
+                "table_layout",
+                "images_not_responsive",
             ],
         }
 
         for category, issue_types in categories.items():
             if issue_type in issue_types:
-                return category
+                return category.value
+        if issue_type.startswith("lighthouse_"):  # failed Lighthouse audits
+            return Category.PERFORMANCE.value
 
-        return "other"
+        return Category.OTHER.value
 
 
 class HealthScoreCalculator:
@@ -301,7 +493,7 @@ class HealthScoreCalculator:
             "pages_scanned": pages_scanned,
             "issues_per_page": round(issue_density, 2),
             "category_scores": category_scores,
-            "critical_issues": sum(1 for i in issues if i.severity == "high"),
+            "critical_issues": sum(1 for i in issues if i.level is Severity.HIGH),
             "recommendations": self._generate_recommendations(scored_issues),
         }
 
@@ -405,3 +597,44 @@ def prioritize_issues(issues: list[Issue]) -> list[tuple[Issue, dict[str, any]]]
     scored.sort(key=lambda x: x[1]["priority"], reverse=True)
 
     return scored
+
+
+def quick_wins(issues: list[Issue], limit: int = 3) -> list[dict[str, any]]:
+    """
+    Pick the issue types that are easiest to fix for what they are worth.
+
+    Each type starts from the priority score of its most severe issue, then
+    is divided by the square root of its page count (each page is another
+    fix), unless it is in TEMPLATE_FIXES, where one fix covers every page and
+    the score gets TEMPLATE_BONUS instead. Info issues are left out.
+
+    Args:
+        issues: List of issues
+        limit: Number of issue types to return
+
+    Returns:
+        Up to limit dicts (issue_type, severity, pages, priority, score), best first;
+        fewer when fewer types qualify
+    """
+    scorer = IssueScorer()
+    by_type: dict[str, list[Issue]] = defaultdict(list)
+    for issue in issues:
+        if issue.level is not Severity.INFO:
+            by_type[issue.type].append(issue)
+
+    wins = []
+    for issue_type, group in by_type.items():
+        worst = max(group, key=lambda i: i.level.rank if i.level else -1)
+        priority = scorer.score_issue(worst)["priority"]
+        pages = len({i.url for i in group})
+        ease = TEMPLATE_BONUS if issue_type in TEMPLATE_FIXES else 1 / math.sqrt(pages)
+        wins.append({
+            "issue_type": issue_type,
+            "severity": worst.severity,
+            "pages": pages,
+            "priority": priority,
+            "score": round(priority * ease, 2),
+        })
+
+    wins.sort(key=lambda w: (-w["score"], w["issue_type"]))
+    return wins[:limit]
@@ -0,0 +1,177 @@
+"""
+Severity and category vocabularies with tolerant parsing.
+
+Issue.severity stays a plain string so results round-trip unchanged; use
+these helpers whenever severities are compared, sorted, counted, or coloured.
+"""
+from __future__ import annotations
+
+from collections.abc import Iterable
+from enum import Enum
+
+
+class Severity(str, Enum):
+    """Known issue severities, most severe first."""
+
+    HIGH = "high"
+    MEDIUM = "medium"
+    LOW = "low"
+    INFO = "info"
+
+    @classmethod
+    def parse(cls, value: str | None) -> Severity | None:
+        """
+        Parse a severity string case-insensitively.
+
+        Args:
+            value: Raw severity, e.g. "high", "Critical", "WARNING"
+
+        Returns:
+            Matching Severity, or None if the value is unknown
+        """
+        if not value:
+            return None
+        key = value.strip().lower()
+        try:
+            return cls(key)
+        except ValueError:
+            return _SEVERITY_ALIASES.get(key)
+
+    @property
+    def rank(self) -> int:
+        """Numeric rank, higher is more severe."""
+        return _SEVERITY_RANKS[self]
+
+    @property
+    def color(self) -> str:
+        """Rich color used when displaying this severity."""
+        return _SEVERITY_COLORS[self]
+
+    # Every comparison is by rank; str's own would order them alphabetically
+    def __lt__(self, other: object) -> bool:
+        if not isinstance(other, Severity):
+            return NotImplemented
+        return self.rank < other.rank
+
+    def __le__(self, other: object) -> bool:
+        if not isinstance(other, Severity):
+            return NotImplemented
+        return self.rank <= other.rank
+
+    def __gt__(self, other: object) -> bool:
+        if not isinstance(other, Severity):
+            return NotImplemented
+        return self.rank > other.rank
+
+    def __ge__(self, other: object) -> bool:
+        if not isinstance(other, Severity):
+            return NotImplemented
+        return self.rank >= other.rank
+
+
+_SEVERITY_ALIASES = {
+    "critical": Severity.HIGH,
+    "error": Severity.HIGH,
+    "severe": Severity.HIGH,
+    "warning": Severity.MEDIUM,
+    "warn": Severity.MEDIUM,
+    "moderate": Severity.MEDIUM,
+    "minor": Severity.LOW,
+    "notice": Severity.LOW,
+    "information": Severity.INFO,
+    "informational": Severity.INFO,
+}
+
+_SEVERITY_RANKS = {Severity.HIGH: 3, Severity.MEDIUM: 2, Severity.LOW: 1, Severity.INFO: 0}
+
+_SEVERITY_COLORS = {
+    Severity.HIGH: "red",
+    Severity.MEDIUM: "yellow",
+    Severity.LOW: "blue",
+    Severity.INFO: "dim",
+}
+
+# Bucket used for severities no alias matches
+OTHER = "other"
+
+
+def severity_rank(value: str | None) -> int:
+    """
+    Rank a raw severity string for sorting (unknown values sort below info).
+
+    Args:
+        value: Raw severity string
+
+    Returns:
+        3 for high down to 0 for info, -1 for unknown
+    """
+    severity = Severity.parse(value)
+    return severity.rank if severity else -1
+
+
+def count_by_severity(values: Iterable[str | None]) -> dict[str, int]:
+    """
+    Count raw severity strings into the known buckets.
+
+    Args:
+        values: Raw severity strings
+
+    Returns:
+        Counts keyed high/medium/low/info, plus "other" when unknown values were seen
+    """
+    counts = {s.value: 0 for s in Severity}
+    for value in values:
+        severity = Severity.parse(value)
+        if severity:
+            counts[severity.value] += 1
+        else:
+            counts[OTHER] = counts.get(OTHER, 0) + 1
+    return counts
+
+
+class Category(str, Enum):
+    """Broad issue categories used in scoring and reports."""
+
+    CONTENT = "content"
+    TECHNICAL = "technical"
+    LINKS = "links"
+    PERFORMANCE = "performance"
+    SOCIAL = "social"
+    SECURITY = "security"
+    ACCESSIBILITY = "accessibility"
+    MOBILE = "mobile"
+    OTHER = "other"
+
+    @classmethod
+    def parse(cls, value: str | None) -> Category:
+        """
+        Parse a category name case-insensitively ("Meta Tags", "meta-tags", "LINKS").
+
+        Args:
+            value: Raw category name
+
+        Returns:
+            Matching Category, or Category.OTHER if the value is unknown
+        """
+        if not value:
+            return cls.OTHER
+        key = value.strip().lower().replace("-", "_").replace(" ", "_")
+        try:
+            return cls(key)
+        except ValueError:
+            return _CATEGORY_ALIASES.get(key, cls.OTHER)
+
+
+_CATEGORY_ALIASES = {
+    "meta": Category.CONTENT,
+    "meta_tags": Category.CONTENT,
+    "content_quality": Category.CONTENT,
+    "technical_seo": Category.TECHNICAL,
+    "indexability": Category.TECHNICAL,
+    "link": Category.LINKS,
+    "link_analysis": Category.LINKS,
+    "speed": Category.PERFORMANCE,
+    "a11y": Category.ACCESSIBILITY,
+    "mobile_friendliness": Category.MOBILE,
+    "mobile_usability": Category.MOBILE,
+}
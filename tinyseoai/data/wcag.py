@@ -0,0 +1,147 @@
+"""
+WCAG success criteria of accessibility findings.
+
+An issue names its criterion in the optional wcag field ("1.1.1"); built-in
+accessibility issue types without one fall back to ISSUE_CRITERIA, and
+anything else is grouped as having no criterion. CRITERIA holds the name and
+conformance level of the criteria automated checks commonly report; other
+numbers are shown as given, without a name or level.
+"""
+from __future__ import annotations
+
+from collections import defaultdict
+from collections.abc import Iterable
+
+from pydantic import BaseModel
+
+from .models import Issue
+from .query import issue_category
+from .severity import Category, severity_rank
+
+
+class Criterion(BaseModel):
+    """One WCAG 2.2 success criterion."""
+
+    number: str  # e.g. "1.4.3"
+    name: str | None = None  # None for numbers not in CRITERIA
+    level: str | None = None  # A, AA, or AAA
+
+    def label(self) -> str:
+        """E.g. "1.4.3 Contrast (Minimum) (AA)", or the bare number when unknown."""
+        if self.name is None:
+            return self.number
+        return f"{self.number} {self.name} ({self.level})"
+
+
+CRITERIA = {
+    number: Criterion(number=number, name=name, level=level)
+    for number, name, level in [
+        ("1.1.1", "Non-text Content", "A"),
+        ("1.2.2", "Captions (Prerecorded)", "A"),
+        ("1.3.1", "Info and Relationships", "A"),
+        ("1.3.4", "Orientation", "AA"),
+        ("1.3.5", "Identify Input Purpose", "AA"),
+        ("1.4.1", "Use of Color", "A"),
+        ("1.4.3", "Contrast (Minimum)", "AA"),
+        ("1.4.4", "Resize Text", "AA"),
+        ("1.4.6", "Contrast (Enhanced)", "AAA"),
+        ("1.4.10", "Reflow", "AA"),
+        ("1.4.11", "Non-text Contrast", "AA"),
+        ("1.4.12", "Text Spacing", "AA"),
+        ("2.1.1", "Keyboard", "A"),
+        ("2.2.1", "Timing Adjustable", "A"),
+        ("2.4.1", "Bypass Blocks", "A"),
+        ("2.4.2", "Page Titled", "A"),
+        ("2.4.3", "Focus Order", "A"),
+        ("2.4.4", "Link Purpose (In Context)", "A"),
+        ("2.4.6", "Headings and Labels", "AA"),
+        ("2.4.7", "Focus Visible", "AA"),
+        ("2.5.3", "Label in Name", "A"),
+        ("2.5.8", "Target Size (Minimum)", "AA"),
+        ("3.1.1", "Language of Page", "A"),
+        ("3.1.2", "Language of Parts", "AA"),
+        ("3.2.2", "On Input", "A"),
+        ("3.3.1", "Error Identification", "A"),
+        ("3.3.2", "Labels or Instructions", "A"),
+        ("4.1.2", "Name, Role, Value", "A"),
+        ("4.1.3", "Status Messages", "AA"),
+    ]
+}
+
+# Criteria of built-in issue types, for issues without a wcag field
+ISSUE_CRITERIA = {
+    "img_alt_missing": "1.1.1",
+    "img_alt_empty_in_link": "2.4.4",
+    "missing_html_lang": "3.1.1",
+    "html_lang_invalid": "3.1.1",
+    "html_lang_mismatch": "3.1.1",
+}
+
+
+def criterion(number: str) -> Criterion:
+    """The criterion for a number, with its name and level when known."""
+    number = number.strip()
+    return CRITERIA.get(number) or Criterion(number=number)
+
+
+def criterion_of(issue: Issue) -> Criterion | None:
+    """The criterion an issue names, or that its type maps to; None when neither."""
+    number = issue.wcag or ISSUE_CRITERIA.get(issue.type)
+    return criterion(number) if number else None
+
+
+def is_accessibility(issue: Issue) -> bool:
+    return issue_category(issue) == Category.ACCESSIBILITY
+
+
+def _order(number: str) -> tuple:
+    # Numeric, so 1.4.10 sorts after 1.4.4; malformed numbers go last
+    parts = number.split(".")
+    return (0, [int(p) for p in parts]) if all(p.isdigit() for p in parts) else (1, number)
+
+
+def by_criterion(issues: Iterable[Issue]) -> list[tuple[Criterion | None, list[Issue]]]:
+    """
+    Accessibility issues grouped by WCAG criterion.
+
+    Returns:
+        (criterion, issues) pairs in criterion order, issues most severe
+        first; issues with no criterion come last under None. Empty when
+        there are no accessibility issues.
+    """
+    groups: dict[str | None, list[Issue]] = defaultdict(list)
+    for issue in issues:
+        if is_accessibility(issue):
+            found = criterion_of(issue)
+            groups[found.number if found else None].append(issue)
+    numbers = sorted((n for n in groups if n is not None), key=_order)
+    if None in groups:
+        numbers.append(None)
+    return [
+        (
+            criterion(number) if number is not None else None,
+            sorted(groups[number], key=lambda i: (-severity_rank(i.severity), i.type, i.url)),
+        )
+        for number in numbers
+    ]
+
+
+ACCESSIBILITY_HEADERS = ["Criterion", "Name", "Level", "Impact", "Type", "Severity", "URL", "Detail"]
+
+
+def accessibility_rows(issues: Iterable[Issue]) -> list[list[str]]:
+    """Rows of the Accessibility sheet of Excel reports (see by_criterion); empty when there are none."""
+    rows = []
+    for found, members in by_criterion(issues):
+        for issue in members:
+            rows.append([
+                found.number if found else "",
+                (found.name if found else None) or "",
+                (found.level if found else None) or "",
+                issue.impact or "",
+                issue.type,
+                issue.severity,
+                issue.url,
+                issue.detail or "",
+            ])
+    return rows
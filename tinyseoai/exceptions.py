@@ -49,3 +49,15 @@ class AuditError(TinySEOError):
     """Raised when audit operations fail."""
 
     pass
+
+
+class StoreError(TinySEOError):
+    """Raised when the audit history store cannot read or write."""
+
+    pass
+
+
+class IntegrationError(TinySEOError):
+    """Raised when an external integration (webhook, GitHub, SMTP) fails."""
+
+    pass
@@ -0,0 +1,157 @@
+"""
+Localized issue text.
+
+Checks do not write English sentences into issues; they name a catalog
+message ("meta.title_too_long", a check id and a message id) and pass its
+arguments (lengths, counts, URLs) with msg(); an argument that is itself
+text is a fragment (another message and its arguments) rendered in the same
+language, and a list argument is rendered comma-separated. The issue keeps the message
+and arguments next to the rendered detail, so the text can be rendered
+again in another language (localize) and fingerprints can ignore the
+language altogether.
+
+Catalogs live in tinyseoai/locales/<locale>.toml: one table per check id,
+mapping message ids to templates with positional fields ({0}, {1:.1f}),
+plus [titles] and [recommendations] keyed by issue type. English is
+complete; other catalogs may be partial, and any message, title, or
+recommendation they lack falls back to English.
+"""
+from __future__ import annotations
+
+import tomllib
+from collections.abc import Iterable, Sequence
+from enum import Enum
+from functools import cache
+from pathlib import Path
+from typing import Any
+
+from .data.models import Issue
+
+_LOCALES = Path(__file__).parent / "locales"
+
+
+class Locale(str, Enum):
+    """Languages issue text and reports can be written in."""
+
+    EN = "en"
+    ES = "es"
+
+    @classmethod
+    def parse(cls, value: str | Locale | None) -> Locale:
+        """
+        Parse a locale name, ignoring case and a region ("es-MX" is Spanish).
+
+        Raises:
+            ValueError: For a language without a catalog
+        """
+        if isinstance(value, Locale):
+            return value
+        if not value:
+            return cls.EN
+        language = value.strip().lower().replace("_", "-").split("-")[0]
+        try:
+            return cls(language)
+        except ValueError:
+            known = ", ".join(locale.value for locale in cls)
+            raise ValueError(f"Unknown locale {value!r} (known: {known})") from None
+
+
+@cache
+def catalog(locale: Locale) -> dict[str, dict[str, str]]:
+    """A locale's templates, by table (a check id, "titles", or "recommendations") and key."""
+    with open(_LOCALES / f"{locale.value}.toml", "rb") as f:
+        return tomllib.load(f)
+
+
+def lookup(table: str, key: str, locale: Locale = Locale.EN) -> str | None:
+    """A template from a catalog table, falling back to English; None when neither has it."""
+    for source in (locale, Locale.EN):
+        template = catalog(source).get(table, {}).get(key)
+        if template is not None:
+            return template
+    return None
+
+
+def text(key: str, args: Sequence[Any] = (), locale: Locale = Locale.EN) -> str:
+    """
+    Render a catalog message, falling back to English when the locale lacks it.
+
+    Args:
+        key: "<check id>.<message id>"
+        args: Positional arguments of the template
+        locale: Language to render in
+
+    Raises:
+        KeyError: If not even the English catalog has the message
+    """
+    table, _, message = key.partition(".")
+    template = lookup(table, message, locale)
+    if template is None:
+        raise KeyError(f"No message {key!r} in the catalog")
+    return template.format(*(_render(arg, locale) for arg in args))
+
+
+def _render(arg: Any, locale: Locale) -> Any:
+    if isinstance(arg, dict):
+        return text(arg["message"], arg.get("args", ()), locale)
+    if isinstance(arg, list):
+        return ", ".join(str(_render(item, locale)) for item in arg)
+    return arg
+
+
+def fragment(key: str, *args: Any) -> dict[str, Any]:
+    """A message used as an argument of another, rendered in the same locale."""
+    return {"message": key, "args": list(args)}
+
+
+def listing(items: list[Any], limit: int) -> list[Any] | dict[str, Any]:
+    """Items (strings or fragments) to render comma-separated, capped at limit with "and N more"."""
+    if len(items) > limit:
+        return fragment("common.listing_more", items[:limit], len(items) - limit)
+    return list(items)
+
+
+def msg(key: str, *args: Any) -> dict[str, Any]:
+    """
+    Issue fields for a catalog message, rendered in English:
+    Issue(url=url, type="multiple_h1", severity="low", **msg("meta.multiple_h1", count)).
+    """
+    return {"message": key, "args": list(args), "detail": text(key, args)}
+
+
+def detail_text(
+    message: str, args: Sequence[Any] | None, detail: str | None, locale: Locale = Locale.EN
+) -> str | None:
+    """
+    Render an issue's message, or return its stored detail when this catalog cannot.
+
+    Results written by another version may name messages this catalog lacks,
+    or arguments its template does not take; their detail is kept as written.
+    """
+    try:
+        return text(message, args or (), locale)
+    except (KeyError, IndexError, ValueError):
+        return detail
+
+
+def localize(issues: Iterable[Issue], locale: Locale) -> None:
+    """Render the detail of issues that carry a catalog message in a locale, in place."""
+    for issue in issues:
+        if issue.message is not None:
+            issue.detail = detail_text(issue.message, issue.args, issue.detail, locale)
+
+
+def result_locale(meta: dict[str, Any] | None) -> Locale:
+    """The locale an audit result was written in (meta["locale"]); English when unknown."""
+    try:
+        return Locale.parse((meta or {}).get("locale"))
+    except ValueError:
+        return Locale.EN
+
+
+def english_detail(issue: Issue) -> str | None:
+    """The detail of an issue as an English run writes it, whatever language it is in."""
+    if issue.message is None:
+        return issue.detail
+    return detail_text(issue.message, issue.args, issue.detail)
+
@@ -0,0 +1,237 @@
+"""
+File audit findings as GitHub issues.
+
+Each GitHub issue carries a hidden fingerprint marker in its body, so filing
+the same finding again updates (and if needed reopens) the existing issue
+instead of opening a duplicate.
+"""
+from __future__ import annotations
+
+import re
+from typing import Any, Literal
+
+import httpx
+from loguru import logger
+from pydantic import BaseModel, Field
+
+from ..data.fingerprint import fingerprint, fingerprints
+from ..data.models import Issue
+from ..data.query import IssueQuery, issue_category
+from ..data.recommendations import issue_title
+from ..data.severity import Severity
+from ..exceptions import IntegrationError
+from ..reporting.markdown import render_issue_markdown
+
+MARKER_LABEL = "tinyseoai"
+_MARKER = "<!-- tinyseoai:fingerprint={} -->"
+_MARKER_RE = re.compile(r"<!-- tinyseoai:fingerprint=([0-9a-f]+) -->")
+
+FilingAction = Literal["created", "updated", "failed", "rate_limited", "skipped"]
+
+
+class GitHubConfig(BaseModel):
+    """Target repository and credentials."""
+
+    repo: str  # "owner/name"
+    token: str
+    api_url: str = "https://api.github.com"
+    extra_labels: list[str] = Field(default_factory=list)
+    timeout: float = 15.0
+
+
+class FilingResult(BaseModel):
+    """What happened to one finding."""
+
+    fingerprint: str
+    title: str
+    action: FilingAction
+    number: int | None = None
+    html_url: str | None = None
+    error: str | None = None
+
+
+class FilingReport(BaseModel):
+    """Per-finding outcome of GitHubIssues.file."""
+
+    results: list[FilingResult] = Field(default_factory=list)
+
+    def count(self, action: FilingAction) -> int:
+        return sum(1 for r in self.results if r.action == action)
+
+    @property
+    def ok(self) -> bool:
+        return not any(r.action in ("failed", "rate_limited") for r in self.results)
+
+
+class _RateLimited(Exception):
+    pass
+
+
+def marker(fp: str) -> str:
+    """Hidden HTML comment identifying the finding behind a GitHub issue."""
+    return _MARKER.format(fp)
+
+
+def find_marker(body: str | None) -> str | None:
+    """Extract the fingerprint marker from an issue body."""
+    match = _MARKER_RE.search(body or "")
+    return match.group(1) if match else None
+
+
+def issue_labels(issue: Issue, extra: list[str] | None = None) -> list[str]:
+    """Labels for a finding: the marker label, severity, category, and any extras."""
+    level = issue.level
+    labels = [
+        MARKER_LABEL,
+        f"severity:{level.value if level else 'unknown'}",
+        f"seo:{issue_category(issue).value}",
+    ]
+    return labels + [label for label in extra or [] if label not in labels]
+
+
+def issue_payload(issue: Issue, extra_labels: list[str] | None = None) -> dict[str, Any]:
+    """Title, body, and labels of the GitHub issue for a finding."""
+    title = f"SEO: {issue_title(issue.type)} — {issue.url}"
+    body = f"{render_issue_markdown(issue)}\n_Filed by TinySEO AI._\n\n{marker(fingerprint(issue))}\n"
+    return {"title": title[:256], "body": body, "labels": issue_labels(issue, extra_labels)}
+
+
+def select_issues(issues: list[Issue], min_severity: Severity | str = Severity.HIGH) -> list[Issue]:
+    """
+    Keep findings at or above a severity, one per fingerprint.
+
+    Raises:
+        ValueError: If min_severity is unknown
+    """
+    seen: set[str] = set()
+    selected = []
+    for issue in IssueQuery(issues).severity_at_least(min_severity):
+        fp = fingerprint(issue)
+        if fp not in seen:
+            seen.add(fp)
+            selected.append(issue)
+    return selected
+
+
+class GitHubIssues:
+    """
+    Creates or updates GitHub issues for audit findings.
+
+    Failures are recorded per finding and do not stop the run. Once GitHub
+    reports the rate limit as exhausted, the remaining findings are marked
+    rate_limited without further requests.
+    """
+
+    def __init__(self, config: GitHubConfig, client: httpx.AsyncClient | None = None):
+        if "/" not in config.repo:
+            raise IntegrationError(f"Repository must be 'owner/name', got {config.repo!r}")
+        self.config = config
+        self._client = client
+
+    def _headers(self) -> dict[str, str]:
+        return {
+            "Authorization": f"Bearer {self.config.token}",
+            "Accept": "application/vnd.github+json",
+            "X-GitHub-Api-Version": "2022-11-28",
+        }
+
+    def _url(self, path: str) -> str:
+        return f"{self.config.api_url.rstrip('/')}/repos/{self.config.repo}{path}"
+
+    @staticmethod
+    def _check(response: httpx.Response) -> None:
+        if response.status_code == 429 or (
+            response.status_code == 403 and response.headers.get("x-ratelimit-remaining") == "0"
+        ):
+            reset = response.headers.get("x-ratelimit-reset")
+            raise _RateLimited(f"GitHub rate limit exceeded (resets at {reset})" if reset else "GitHub rate limit exceeded")
+        if response.status_code >= 400:
+            try:
+                message = response.json().get("message", "")
+            except ValueError:
+                message = response.text[:200]
+            raise IntegrationError(f"HTTP {response.status_code}: {message}".rstrip(": "))
+
+    async def existing(self, client: httpx.AsyncClient) -> dict[str, dict[str, Any]]:
+        """Issues previously filed by TinySEO AI, keyed by fingerprint."""
+        found: dict[str, dict[str, Any]] = {}
+        page = 1
+        while True:
+            response = await client.get(
+                self._url("/issues"),
+                headers=self._headers(),
+                params={"labels": MARKER_LABEL, "state": "all", "per_page": 100, "page": page},
+            )
+            self._check(response)
+            batch = response.json()
+            for item in batch:
+                fp = find_marker(item.get("body"))
+                if fp and "pull_request" not in item:
+                    found.setdefault(fp, item)
+            if len(batch) < 100:
+                return found
+            page += 1
+
+    async def file(self, issues: list[Issue]) -> FilingReport:
+        """
+        Create or update one GitHub issue per finding.
+
+        Args:
+            issues: Findings to file (see select_issues)
+
+        Returns:
+            FilingReport with one entry per finding
+
+        Raises:
+            IntegrationError: If existing issues cannot be listed at all
+        """
+        report = FilingReport()
+        client = self._client or httpx.AsyncClient(timeout=self.config.timeout)
+        try:
+            try:
+                existing = await self.existing(client)
+            except _RateLimited as e:
+                raise IntegrationError(str(e)) from e
+            except httpx.HTTPError as e:
+                raise IntegrationError(f"Cannot reach GitHub: {e}") from e
+
+            rate_limited: str | None = None
+            for issue in issues:
+                fp = fingerprint(issue)
+                payload = issue_payload(issue, self.config.extra_labels)
+                result = FilingResult(fingerprint=fp, title=payload["title"], action="skipped")
+                report.results.append(result)
+                if rate_limited:
+                    result.action, result.error = "rate_limited", rate_limited
+                    continue
+
+                # Issues filed before the current fingerprint version carry an older marker
+                current = next((existing[f] for f in fingerprints(issue) if f in existing), None)
+                try:
+                    if current is None:
+                        response = await client.post(self._url("/issues"), headers=self._headers(), json=payload)
+                        result.action = "created"
+                    else:
+                        payload["state"] = "open"
+                        response = await client.patch(
+                            self._url(f"/issues/{current['number']}"), headers=self._headers(), json=payload
+                        )
+                        result.action = "updated"
+                    self._check(response)
+                except _RateLimited as e:
+                    rate_limited = str(e)
+                    result.action, result.error = "rate_limited", rate_limited
+                    logger.warning(rate_limited)
+                    continue
+                except (IntegrationError, httpx.HTTPError) as e:
+                    result.action, result.error = "failed", str(e) or type(e).__name__
+                    logger.warning(f"Filing {fp} failed: {result.error}")
+                    continue
+
+                data = response.json()
+                result.number, result.html_url = data.get("number"), data.get("html_url")
+                existing[fp] = data
+        finally:
+            if self._client is None:
+                await client.aclose()
+        return report
@@ -0,0 +1,199 @@
+"""
+Run a locally installed Lighthouse CLI and turn its report into issues.
+
+For teams that already have Node and Lighthouse: the lab run's category
+scores, its top opportunities and diagnostics, and one performance issue per
+failed audit (typed "lighthouse_<audit id>", so the Lighthouse id survives).
+A missing binary, a timeout, or a crash becomes an informational issue
+rather than a failed audit.
+"""
+from __future__ import annotations
+
+import json
+import shutil
+import subprocess
+from pathlib import Path
+from typing import Any, Literal
+
+from loguru import logger
+from pydantic import BaseModel, Field
+
+from ..data.merge import absorb
+from ..data.models import AuditResult, Issue
+from ..i18n import msg
+
+ISSUE_PREFIX = "lighthouse_"
+UNAVAILABLE = "lighthouse_unavailable"  # binary not found
+FAILED = "lighthouse_failed"  # timeout, crash, or unreadable report
+
+# Score display modes of audits that can fail (others are informative or manual)
+_SCORED_MODES = ("numeric", "binary", "metricSavings")
+PASSING_SCORE = 0.9  # Lighthouse shows audits below this as failed
+
+LighthouseStatus = Literal["ok", "not_installed", "timeout", "failed"]
+
+
+class LighthouseOptions(BaseModel):
+    """How to run Lighthouse."""
+
+    binary: str | None = None  # path to the lighthouse executable; None: look it up on PATH
+    preset: Literal["mobile", "desktop"] = "mobile"
+    timeout: float = 180.0  # seconds for the whole run
+    extra_args: list[str] = Field(default_factory=list)
+
+
+class LighthouseAudit(BaseModel):
+    """A failed Lighthouse audit."""
+
+    id: str  # e.g. "render-blocking-resources"
+    title: str
+    score: float
+    display_value: str | None = None  # e.g. "Potential savings of 1,210 ms"
+    savings_ms: float = 0.0
+
+
+class LighthouseRun(BaseModel):
+    """Scores and failed performance audits of one Lighthouse run, or why it has none."""
+
+    url: str
+    status: LighthouseStatus = "ok"
+    error: str | None = None
+    version: str | None = None
+    preset: str = "mobile"
+    scores: dict[str, int] = Field(default_factory=dict)  # category id -> 0-100
+    opportunities: list[LighthouseAudit] = Field(default_factory=list)  # biggest savings first
+    diagnostics: list[LighthouseAudit] = Field(default_factory=list)  # failed, without savings
+
+
+def find_binary(path: str | None = None) -> str | None:
+    """The lighthouse executable: the configured path if it exists, else the one on PATH."""
+    if path:
+        return path if Path(path).is_file() else shutil.which(path)
+    return shutil.which("lighthouse")
+
+
+def command(binary: str, url: str, options: LighthouseOptions) -> list[str]:
+    """Command line of a Lighthouse run writing its JSON report to stdout."""
+    args = [binary, url, "--output=json", "--output-path=stdout", "--quiet"]
+    args.append("--chrome-flags=--headless")
+    if options.preset == "desktop":
+        args.append("--preset=desktop")
+    return args + options.extra_args
+
+
+def lighthouse_audit_id(issue_type: str) -> str | None:
+    """The Lighthouse audit id of an issue type made by lighthouse_issues, else None."""
+    if not issue_type.startswith(ISSUE_PREFIX) or issue_type in (UNAVAILABLE, FAILED):
+        return None
+    return issue_type[len(ISSUE_PREFIX) :].replace("_", "-")
+
+
+def parse_report(url: str, report: dict[str, Any], preset: str = "mobile") -> LighthouseRun:
+    """Build a run from a Lighthouse JSON report (LHR)."""
+    run = LighthouseRun(url=url, version=report.get("lighthouseVersion"), preset=preset)
+    categories = report.get("categories") or {}
+    for name, category in categories.items():
+        if isinstance(category, dict) and category.get("score") is not None:
+            run.scores[name] = round(category["score"] * 100)
+
+    audits = report.get("audits") or {}
+    refs = (categories.get("performance") or {}).get("auditRefs") or []
+    for ref in refs:
+        # Metrics carry weight and are summarized by the score; hidden audits are raw data
+        if ref.get("weight") or ref.get("group") == "hidden":
+            continue
+        audit = audits.get(ref.get("id")) or {}
+        score = audit.get("score")
+        if audit.get("scoreDisplayMode") not in _SCORED_MODES or score is None:
+            continue
+        if score >= PASSING_SCORE:
+            continue
+        details = audit.get("details") or {}
+        failed = LighthouseAudit(
+            id=audit["id"],
+            title=audit.get("title") or audit["id"],
+            score=score,
+            display_value=audit.get("displayValue"),
+            savings_ms=details.get("overallSavingsMs") or 0.0,
+        )
+        (run.opportunities if failed.savings_ms > 0 else run.diagnostics).append(failed)
+    run.opportunities.sort(key=lambda a: -a.savings_ms)
+    return run
+
+
+def run(url: str, options: LighthouseOptions | None = None) -> LighthouseRun:
+    """
+    Run Lighthouse against a URL.
+
+    Args:
+        url: Page to test
+        options: Binary, preset, and timeout
+
+    Returns:
+        LighthouseRun; its status says why data is missing, it never raises
+    """
+    options = options or LighthouseOptions()
+    binary = find_binary(options.binary)
+    if binary is None:
+        error = f"{options.binary or 'lighthouse'} not found (npm install -g lighthouse)"
+        return LighthouseRun(url=url, status="not_installed", error=error, preset=options.preset)
+
+    args = command(binary, url, options)
+    logger.debug(f"Running {' '.join(args)}")
+    try:
+        completed = subprocess.run(args, capture_output=True, text=True, timeout=options.timeout)
+    except subprocess.TimeoutExpired:
+        error = f"Lighthouse did not finish within {options.timeout:g}s"
+        return LighthouseRun(url=url, status="timeout", error=error, preset=options.preset)
+    except OSError as e:
+        return LighthouseRun(url=url, status="failed", error=str(e), preset=options.preset)
+
+    if completed.returncode != 0:
+        lines = (completed.stderr or "").strip().splitlines()
+        error = f"exit code {completed.returncode}" + (f": {lines[-1]}" if lines else "")
+        return LighthouseRun(url=url, status="failed", error=error, preset=options.preset)
+    try:
+        report = json.loads(completed.stdout)
+    except ValueError:
+        error = "Lighthouse did not write a JSON report"
+        return LighthouseRun(url=url, status="failed", error=error, preset=options.preset)
+    if report.get("runtimeError"):
+        error = report["runtimeError"].get("message") or report["runtimeError"].get("code")
+        return LighthouseRun(url=url, status="failed", error=error, preset=options.preset)
+    return parse_report(url, report, options.preset)
+
+
+def lighthouse_issues(run: LighthouseRun) -> list[Issue]:
+    """One issue per failed audit, or an informational one when Lighthouse did not run."""
+    if run.status == "not_installed":
+        fields = msg("lighthouse.not_installed", run.error or "")
+        return [Issue(url=run.url, type=UNAVAILABLE, severity="info", **fields)]
+    if run.status != "ok":
+        fields = msg("lighthouse.failed", run.error or "")
+        return [Issue(url=run.url, type=FAILED, severity="info", **fields)]
+    issues = []
+    for audit in run.opportunities + run.diagnostics:
+        if audit.display_value:
+            fields = msg("lighthouse.audit_failed", audit.title, audit.display_value)
+        else:
+            fields = msg("lighthouse.audit_failed_plain", audit.title)
+        severity = "medium" if audit.score < 0.5 else "low"
+        issue_type = ISSUE_PREFIX + audit.id.replace("-", "_")
+        issues.append(Issue(url=run.url, type=issue_type, severity=severity, **fields))
+    return issues
+
+
+def merge_run(audit: AuditResult, run: LighthouseRun) -> None:
+    """
+    Add a Lighthouse run to an audit result in place.
+
+    The run goes to meta["lighthouse"][url]; it and its issues replace those of
+    an earlier run on the same URL, and the issues are merged like any other
+    result's (see absorb).
+    """
+    audit.issues = [
+        i for i in audit.issues if not (i.type.startswith(ISSUE_PREFIX) and i.url == run.url)
+    ]
+    audit.meta.setdefault("lighthouse", {})[run.url] = run.model_dump(mode="json")
+    partial = AuditResult(site=audit.site, pages_scanned=0, issues=lighthouse_issues(run), meta={})
+    absorb(audit, partial)
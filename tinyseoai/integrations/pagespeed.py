@@ -0,0 +1,223 @@
+"""
+Core Web Vitals from the PageSpeed Insights v5 API.
+
+Field data comes from the Chrome UX Report (the page's own, else its
+origin's); lab data is the Lighthouse category scores of one run. Problems
+with the API (no field data, quota, a bad key) are reported in the result and
+become informational issues, so enriching an audit never fails it.
+"""
+from __future__ import annotations
+
+from typing import Any, Literal
+
+import httpx
+from loguru import logger
+from pydantic import BaseModel, Field
+
+from ..data.models import AuditResult, Issue
+from ..i18n import msg
+
+PSI_URL = "https://www.googleapis.com/pagespeedonline/v5/runPagespeed"
+
+Strategy = Literal["mobile", "desktop"]
+PageSpeedStatus = Literal["ok", "no_field_data", "quota_exceeded", "invalid_key", "failed"]
+
+# CrUX metric ids -> names used in results
+FIELD_METRICS = {
+    "LARGEST_CONTENTFUL_PAINT_MS": "lcp_ms",
+    "CUMULATIVE_LAYOUT_SHIFT_SCORE": "cls",
+    "INTERACTION_TO_NEXT_PAINT": "inp_ms",
+    "EXPERIMENTAL_TIME_TO_FIRST_BYTE": "ttfb_ms",
+}
+LAB_CATEGORIES = ("performance", "accessibility", "best-practices", "seo")
+
+METRIC_LABELS = {"lcp_ms": "LCP", "cls": "CLS", "inp_ms": "INP", "ttfb_ms": "TTFB"}
+
+# Issue type per status; "ok" adds none
+STATUS_ISSUES = {
+    "no_field_data": "field_data_unavailable",
+    "quota_exceeded": "pagespeed_quota_exceeded",
+    "invalid_key": "pagespeed_key_invalid",
+    "failed": "pagespeed_failed",
+}
+
+
+class FieldMetric(BaseModel):
+    """75th percentile of a metric over 28 days of Chrome users."""
+
+    percentile: float  # CLS as the unitless score, others in milliseconds
+    category: str | None = None  # FAST, AVERAGE, or SLOW
+
+
+class PageSpeedResult(BaseModel):
+    """Field metrics and lab scores of one URL, or why they are missing."""
+
+    url: str
+    strategy: Strategy = "mobile"
+    status: PageSpeedStatus = "ok"
+    error: str | None = None
+    field: dict[str, FieldMetric] = Field(default_factory=dict)  # keyed as in FIELD_METRICS
+    origin_fallback: bool = False  # field data is the whole origin's, not the page's
+    overall_category: str | None = None
+    lab_scores: dict[str, int] = Field(default_factory=dict)  # Lighthouse category -> 0-100
+
+
+def format_metric(name: str, value: float) -> str:
+    """Human-readable metric value: "2.1 s", "0.05", or "180 ms"."""
+    if name == "cls":
+        return f"{value:.2f}"
+    if name == "lcp_ms":
+        return f"{value / 1000:.1f} s"
+    return f"{value:.0f} ms"
+
+
+def field_summary(result: PageSpeedResult) -> str:
+    """One-line field metrics, e.g. "LCP 2.1 s (FAST) · CLS 0.05 (FAST)"; "" without data."""
+    parts = []
+    for name, metric in result.field.items():
+        category = f" ({metric.category})" if metric.category else ""
+        parts.append(f"{METRIC_LABELS[name]} {format_metric(name, metric.percentile)}{category}")
+    summary = " · ".join(parts)
+    return f"{summary} (origin)" if summary and result.origin_fallback else summary
+
+
+def stored_results(meta: dict[str, Any]) -> list[PageSpeedResult]:
+    """Results saved in meta["pagespeed"] by enrich, one per strategy."""
+    return [PageSpeedResult.model_validate(r) for r in (meta.get("pagespeed") or {}).values()]
+
+
+def _field(experience: dict[str, Any] | None) -> dict[str, FieldMetric]:
+    metrics = (experience or {}).get("metrics") or {}
+    field = {}
+    for metric_id, name in FIELD_METRICS.items():
+        data = metrics.get(metric_id)
+        if not data or data.get("percentile") is None:
+            continue
+        percentile = float(data["percentile"])
+        if name == "cls":
+            percentile /= 100  # CrUX reports CLS multiplied by 100
+        field[name] = FieldMetric(percentile=percentile, category=data.get("category"))
+    return field
+
+
+def parse_response(url: str, strategy: Strategy, data: dict[str, Any]) -> PageSpeedResult:
+    """Build a result from a successful runPagespeed response."""
+    result = PageSpeedResult(url=url, strategy=strategy)
+    experience = data.get("loadingExperience")
+    result.field = _field(experience)
+    if not result.field:
+        experience = data.get("originLoadingExperience")
+        result.field = _field(experience)
+        result.origin_fallback = bool(result.field)
+    if result.field:
+        result.overall_category = (experience or {}).get("overall_category")
+    else:
+        result.status = "no_field_data"
+        result.error = "The Chrome UX Report has no field data for this page or its origin"
+
+    categories = (data.get("lighthouseResult") or {}).get("categories") or {}
+    for name in LAB_CATEGORIES:
+        score = (categories.get(name) or {}).get("score")
+        if score is not None:
+            result.lab_scores[name] = round(score * 100)
+    return result
+
+
+def _api_error(response: httpx.Response) -> tuple[PageSpeedStatus, str]:
+    try:
+        error = response.json().get("error") or {}
+    except ValueError:
+        error = {}
+    message = error.get("message") or f"HTTP {response.status_code}"
+    reasons = {e.get("reason") for e in error.get("errors") or [] if isinstance(e, dict)}
+    reasons |= {d.get("reason") for d in error.get("details") or [] if isinstance(d, dict)}
+    if response.status_code == 429 or reasons & {
+        "rateLimitExceeded",
+        "dailyLimitExceeded",
+        "RATE_LIMIT_EXCEEDED",
+    }:
+        return "quota_exceeded", message
+    if reasons & {"keyInvalid", "API_KEY_INVALID"} or "API key not valid" in message:
+        return "invalid_key", message
+    return "failed", message
+
+
+async def fetch(
+    url: str,
+    api_key: str,
+    strategy: Strategy = "mobile",
+    *,
+    client: httpx.AsyncClient | None = None,
+    timeout: float = 60.0,
+    api_url: str = PSI_URL,
+) -> PageSpeedResult:
+    """
+    Fetch Core Web Vitals and Lighthouse scores for a URL.
+
+    Lighthouse runs take a while, hence the long timeout. A timeout, network
+    error, or server error is retried once; quota and key errors are not.
+
+    Args:
+        url: Page to analyze
+        api_key: PageSpeed Insights API key
+        strategy: "mobile" or "desktop"
+        client: HTTP client to use (tests pass a mock transport)
+        timeout: Seconds per attempt
+        api_url: runPagespeed endpoint
+
+    Returns:
+        PageSpeedResult; its status says why data is missing, it never raises
+    """
+    params = [("url", url), ("key", api_key), ("strategy", strategy)]
+    params += [("category", c) for c in LAB_CATEGORIES]
+    owned = client is None
+    client = client or httpx.AsyncClient(timeout=timeout)
+    try:
+        for attempt in (1, 2):
+            try:
+                response = await client.get(api_url, params=params)
+            except httpx.TransportError as e:
+                status, error = "failed", f"{type(e).__name__}: {e}".rstrip(": ")
+            else:
+                if response.status_code < 400:
+                    try:
+                        return parse_response(url, strategy, response.json())
+                    except ValueError:
+                        status, error = "failed", "Invalid JSON response"
+                        break
+                status, error = _api_error(response)
+                if response.status_code < 500 or status != "failed":
+                    break
+            if attempt == 1:
+                logger.warning(f"PageSpeed Insights request failed ({error}); retrying")
+    finally:
+        if owned:
+            await client.aclose()
+    return PageSpeedResult(url=url, strategy=strategy, status=status, error=error)
+
+
+def pagespeed_issues(result: PageSpeedResult) -> list[Issue]:
+    """Informational issues for a result that lacks data; none when it is complete."""
+    issue_type = STATUS_ISSUES.get(result.status)
+    if issue_type is None:
+        return []
+    if result.status == "no_field_data":
+        fields = msg("pagespeed.no_field_data", result.strategy)
+    elif result.status == "quota_exceeded":
+        fields = msg("pagespeed.quota_exceeded")
+    else:
+        fields = msg(f"pagespeed.{result.status}", result.error or "")
+    return [Issue(url=result.url, type=issue_type, severity="info", **fields)]
+
+
+def enrich(audit: AuditResult, result: PageSpeedResult) -> None:
+    """
+    Add PageSpeed data to an audit result in place.
+
+    The data goes to meta["pagespeed"][strategy]; issues from an earlier
+    enrichment of the same URL are replaced.
+    """
+    audit.meta.setdefault("pagespeed", {})[result.strategy] = result.model_dump(mode="json")
+    types = set(STATUS_ISSUES.values())
+    kept = [i for i in audit.issues if not (i.type in types and i.url == result.url)]
+    audit.issues = kept + pagespeed_issues(result)
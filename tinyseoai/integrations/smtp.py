@@ -0,0 +1,230 @@
+"""
+Email delivery of audit reports over SMTP.
+
+A report goes out as the standalone HTML report with a plain-text
+alternative, or as a short note with the PDF attached. Every attempt is
+recorded in the history's deliveries table, and server problems come back
+as errors that say what to change in the settings.
+"""
+from __future__ import annotations
+
+import json
+import smtplib
+import ssl
+import tempfile
+from email.message import EmailMessage
+from email.utils import formatdate, make_msgid
+from pathlib import Path
+from typing import Any, Literal
+from urllib.parse import urlparse
+
+from loguru import logger
+from pydantic import BaseModel, Field
+
+from ..config import SmtpConfig
+from ..data.models import AuditResult
+from ..exceptions import IntegrationError
+from ..reporting.html_report import HtmlOptions, render_html
+from ..reporting.pdf import write_pdf
+from ..reporting.text import TextOptions, render_ansi
+from ..store.history import Delivery, HistoryStore
+
+ReportFormat = Literal["html", "pdf"]
+
+PASSWORD_HINT = "set TINYSEOAI_SMTP_PASSWORD or store one with 'keyring set tinyseoai smtp'"
+
+
+class EmailOptions(BaseModel):
+    """Who gets the report and in which form."""
+
+    recipients: list[str] = Field(min_length=1)
+    format: ReportFormat = "html"  # html: report in the body; pdf: report attached
+    message: str | None = None  # personal note shown above the report
+    subject: str | None = None  # None: "SEO audit for <host>: <score>/100"
+    pdf_path: Path | None = None  # existing PDF to attach; None: render one
+
+
+def _subject(result: AuditResult) -> str:
+    host = urlparse(result.site).netloc or result.site
+    score = result.meta.get("health_score")
+    return f"SEO audit for {host}: {score:g}/100" if isinstance(score, int | float) else f"SEO audit for {host}"
+
+
+def build_email(summary: dict[str, Any], sender: str, options: EmailOptions) -> EmailMessage:
+    """
+    Build the message for an audit without sending it.
+
+    Args:
+        summary: Loaded audit JSON (optionally with "ai_summary")
+        sender: From address
+        options: Recipients, format, and note
+
+    Returns:
+        EmailMessage ready for send_email
+    """
+    result = AuditResult(**{k: v for k, v in summary.items() if k != "ai_summary"})
+    message = EmailMessage()
+    message["Subject"] = options.subject or _subject(result)
+    message["From"] = sender
+    message["To"] = ", ".join(options.recipients)
+    message["Date"] = formatdate(localtime=True)
+    message["Message-ID"] = make_msgid(domain=sender.rpartition("@")[2] or None)
+    note = f"{options.message.strip()}\n\n" if options.message else ""
+
+    if options.format == "pdf":
+        message.set_content(f"{note}{_subject(result)}. The full report is attached.\n")
+        if options.pdf_path is not None:
+            data = options.pdf_path.read_bytes()
+        else:
+            with tempfile.TemporaryDirectory() as tmp:
+                data = write_pdf(summary, Path(tmp) / "report.pdf").read_bytes()
+        host = urlparse(result.site).netloc or "site"
+        message.add_attachment(data, maintype="application", subtype="pdf", filename=f"{host}-report.pdf")
+        return message
+
+    text = render_ansi(result, width=78, color=False, options=TextOptions(ai=summary.get("ai_summary")))
+    message.set_content(note + text)
+    message.add_alternative(render_html(summary, HtmlOptions(intro=options.message)), subtype="html")
+    return message
+
+
+def _error(e: Exception, config: SmtpConfig) -> IntegrationError:
+    """Turn an SMTP or socket failure into an error naming the setting to fix."""
+    server = f"{config.host}:{config.port}"
+    if isinstance(e, ssl.SSLError):
+        return IntegrationError(
+            f"TLS handshake with {server} failed ({e.reason or e}): port 465 usually needs "
+            "tls 'ssl' and port 587 'starttls'"
+        )
+    if isinstance(e, smtplib.SMTPResponseException) and (
+        e.smtp_code == 530 or b"STARTTLS" in (e.smtp_error or b"").upper()
+    ):
+        return IntegrationError(f"STARTTLS required by {server}: set tls to 'starttls'")
+    if isinstance(e, smtplib.SMTPAuthenticationError):
+        return IntegrationError(
+            f"Authentication failed for {config.username} on {server}: check the username, "
+            f"and the password ({PASSWORD_HINT})"
+        )
+    if isinstance(e, smtplib.SMTPNotSupportedError) and "STARTTLS" in str(e).upper():
+        return IntegrationError(
+            f"{server} does not offer STARTTLS: use tls 'ssl' (usually port 465) or 'none'"
+        )
+    if isinstance(e, smtplib.SMTPRecipientsRefused):
+        refused = ", ".join(sorted(e.recipients))
+        return IntegrationError(f"{server} refused the recipients: {refused}")
+    if isinstance(e, smtplib.SMTPSenderRefused):
+        return IntegrationError(
+            f"{server} refused the sender {e.sender}: check from_address "
+            f"({e.smtp_error.decode(errors='replace')})"
+        )
+    if isinstance(e, smtplib.SMTPException):
+        return IntegrationError(f"SMTP error from {server}: {e}")
+    return IntegrationError(f"Cannot connect to {server}: {e}")
+
+
+def _connect(config: SmtpConfig, password: str) -> smtplib.SMTP:
+    """Open a session: connect, upgrade to TLS, and log in as configured."""
+    if config.username and not password:
+        raise IntegrationError(f"No SMTP password for {config.username}: {PASSWORD_HINT}")
+    try:
+        if config.tls == "ssl":
+            client: smtplib.SMTP = smtplib.SMTP_SSL(
+                config.host, config.port, timeout=config.timeout, context=ssl.create_default_context()
+            )
+        else:
+            client = smtplib.SMTP(config.host, config.port, timeout=config.timeout)
+    except (smtplib.SMTPException, OSError) as e:
+        raise _error(e, config) from e
+
+    try:
+        client.ehlo()
+        if config.tls == "starttls":
+            client.starttls(context=ssl.create_default_context())
+            client.ehlo()
+        if config.username:
+            if not client.has_extn("auth") and client.has_extn("starttls"):
+                raise IntegrationError(
+                    f"STARTTLS required by {config.host}:{config.port} before logging in: "
+                    "set tls to 'starttls'"
+                )
+            client.login(config.username, password)
+    except IntegrationError:
+        client.close()
+        raise
+    except (smtplib.SMTPException, OSError) as e:
+        client.close()
+        raise _error(e, config) from e
+    return client
+
+
+def test_smtp_settings(config: SmtpConfig, password: str = "") -> None:
+    """
+    Check the settings by connecting and logging in, without sending anything.
+
+    Raises:
+        IntegrationError: Saying what failed and which setting to change
+    """
+    client = _connect(config, password)
+    try:
+        client.noop()
+        client.quit()
+    except (smtplib.SMTPException, OSError) as e:
+        raise _error(e, config) from e
+    finally:
+        client.close()
+
+
+def send_email(config: SmtpConfig, password: str, message: EmailMessage) -> None:
+    """
+    Send a built message.
+
+    Raises:
+        IntegrationError: If the server cannot be reached, refuses the login, or rejects the message
+    """
+    client = _connect(config, password)
+    try:
+        client.send_message(message)
+        client.quit()
+    except (smtplib.SMTPException, OSError) as e:
+        raise _error(e, config) from e
+    finally:
+        client.close()
+
+
+def send_report_email(
+    audit_id: int,
+    options: EmailOptions,
+    config: SmtpConfig,
+    password: str,
+    store: HistoryStore,
+) -> Delivery:
+    """
+    Email the report of a stored audit and record the attempt.
+
+    Args:
+        audit_id: History ID
+        options: Recipients, format, and note
+        config: Mail server settings
+        password: SMTP password ("" when the server needs no login)
+        store: History holding the audit; the delivery is recorded there
+
+    Returns:
+        The recorded delivery
+
+    Raises:
+        StoreError: If the audit does not exist
+        IntegrationError: If sending failed; the failure is recorded first
+    """
+    result = store.get(audit_id)
+    try:
+        message = build_email(json.loads(result.to_json()), config.from_address, options)
+        send_email(config, password, message)
+    except IntegrationError as e:
+        store.record_delivery(audit_id, "email", options.recipients, str(e))
+        raise
+    except (RuntimeError, OSError) as e:  # PDF renderer missing, unreadable --pdf file
+        error = IntegrationError(f"Cannot build the report: {e}")
+        store.record_delivery(audit_id, "email", options.recipients, str(error))
+        raise error from e
+    logger.info(f"Emailed audit {audit_id} to {len(options.recipients)} recipient(s)")
+    return store.record_delivery(audit_id, "email", options.recipients)
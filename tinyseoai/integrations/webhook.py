@@ -0,0 +1,313 @@
+"""
+Webhook notifications (Slack, Discord, or generic JSON) after an audit, or
+when a site goes down or recovers between reachability probes.
+"""
+from __future__ import annotations
+
+import asyncio
+from collections.abc import Awaitable, Callable
+from typing import Any, Literal
+from urllib.parse import urlsplit
+
+import httpx
+from loguru import logger
+from pydantic import BaseModel
+
+from ..data.models import AuditResult, Issue
+from ..data.reachability import Transition
+from ..data.recommendations import issue_title
+from ..data.severity import Severity
+from ..exceptions import IntegrationError
+
+WebhookStyle = Literal["slack", "discord", "generic"]
+
+
+def detect_style(url: str) -> WebhookStyle:
+    """Guess the payload style from the webhook URL."""
+    host = urlsplit(url).netloc.lower()
+    if host.endswith("hooks.slack.com"):
+        return "slack"
+    if host.endswith(("discord.com", "discordapp.com")) and "/api/webhooks/" in url:
+        return "discord"
+    return "generic"
+
+
+def redact_url(url: str) -> str:
+    """Hide the secret part (path and query) of a webhook URL for logs."""
+    parts = urlsplit(url)
+    return f"{parts.scheme}://{parts.netloc}/***" if parts.netloc else "***"
+
+
+class WebhookConfig(BaseModel):
+    """Where to send notifications and what to include."""
+
+    url: str
+    style: WebhookStyle | None = None  # None = detect from the URL
+    top_n: int = 5  # critical issues listed in the message
+    report_url: str | None = None  # link to the full report, if published
+    max_retries: int = 3
+    backoff_seconds: float = 1.0
+    timeout: float = 10.0
+
+    @property
+    def resolved_style(self) -> WebhookStyle:
+        return self.style or detect_style(self.url)
+
+
+class WebhookMessage(BaseModel):
+    """Style-neutral notification content."""
+
+    site: str
+    score: float | None = None
+    grade: str | None = None
+    delta: float | None = None  # score change vs. the previous audit
+    pages_scanned: int
+    issues: int
+    critical: list[Issue]
+    critical_total: int
+    report_url: str | None = None
+
+    @property
+    def headline(self) -> str:
+        score = f"{self.score:g}/100" if self.score is not None else "no score"
+        if self.delta:
+            score += f" ({self.delta:+g})"
+        return f"SEO audit for {self.site}: {score}"
+
+    def summary_line(self) -> str:
+        return f"{self.pages_scanned} pages, {self.issues} issues, {self.critical_total} critical"
+
+
+class WebhookDelivery(BaseModel):
+    """Outcome of Webhook.send."""
+
+    payload: dict[str, Any]
+    sent: bool
+    attempts: int = 0
+    status_code: int | None = None
+
+
+def build_message(
+    result: AuditResult, previous: AuditResult | None = None, top_n: int = 5, report_url: str | None = None
+) -> WebhookMessage:
+    """
+    Collect the facts a notification needs.
+
+    Args:
+        result: Audit result to announce
+        previous: Earlier audit of the same site, for the score delta
+        top_n: Number of critical issues to include
+        report_url: Link to the full report
+
+    Returns:
+        WebhookMessage
+    """
+    critical = result.query().severity(Severity.HIGH).collect()
+    score = result.meta.get("health_score")
+    delta = None
+    if previous is not None and score is not None and previous.meta.get("health_score") is not None:
+        delta = round(score - previous.meta["health_score"], 1)
+    return WebhookMessage(
+        site=result.site,
+        score=score,
+        grade=result.meta.get("health_grade"),
+        delta=delta,
+        pages_scanned=result.pages_scanned,
+        issues=len(result.issues),
+        critical=critical[:top_n],
+        critical_total=len(critical),
+        report_url=report_url,
+    )
+
+
+def _slack_payload(message: WebhookMessage) -> dict[str, Any]:
+    lines = [f"• <{i.url}|{issue_title(i.type)}>" for i in message.critical]
+    if message.critical_total > len(message.critical):
+        lines.append(f"…and {message.critical_total - len(message.critical)} more")
+    blocks: list[dict[str, Any]] = [
+        {"type": "header", "text": {"type": "plain_text", "text": message.headline[:150]}},
+        {"type": "section", "text": {"type": "mrkdwn", "text": message.summary_line()}},
+    ]
+    if lines:
+        blocks.append({"type": "section", "text": {"type": "mrkdwn", "text": "*Critical issues*\n" + "\n".join(lines)}})
+    if message.report_url:
+        blocks.append({"type": "section", "text": {"type": "mrkdwn", "text": f"<{message.report_url}|Full report>"}})
+    return {"text": message.headline, "blocks": blocks}
+
+
+def _discord_payload(message: WebhookMessage) -> dict[str, Any]:
+    if message.score is None:
+        color = 0x95A5A6
+    elif message.score >= 80:
+        color = 0x2ECC71
+    elif message.score >= 60:
+        color = 0xF1C40F
+    else:
+        color = 0xE74C3C
+    fields = [
+        {"name": issue_title(i.type)[:256], "value": i.url[:1024], "inline": False}
+        for i in message.critical
+    ]
+    embed: dict[str, Any] = {
+        "title": message.headline[:256],
+        "description": message.summary_line(),
+        "color": color,
+        "fields": fields,
+    }
+    if message.report_url:
+        embed["url"] = message.report_url
+    return {"embeds": [embed]}
+
+
+def _generic_payload(message: WebhookMessage) -> dict[str, Any]:
+    return {
+        "event": "audit.completed",
+        "text": f"{message.headline} — {message.summary_line()}",
+        "site": message.site,
+        "health_score": message.score,
+        "health_grade": message.grade,
+        "score_delta": message.delta,
+        "pages_scanned": message.pages_scanned,
+        "issues": message.issues,
+        "critical_total": message.critical_total,
+        "critical": [i.model_dump() for i in message.critical],
+        "report_url": message.report_url,
+    }
+
+
+_PAYLOADS = {"slack": _slack_payload, "discord": _discord_payload, "generic": _generic_payload}
+
+
+def build_payload(message: WebhookMessage, style: WebhookStyle) -> dict[str, Any]:
+    """Render a message as the JSON body for a webhook style."""
+    return _PAYLOADS[style](message)
+
+
+def _transition_line(change: Transition) -> str:
+    probe = change.probe
+    if change.kind == "down":
+        return f"{change.url} is down ({probe.outcome})"
+    latency = f", {probe.latency_ms:.0f} ms" if probe.latency_ms is not None else ""
+    return f"{change.url} is back up ({probe.outcome}{latency})"
+
+
+def build_transition_payload(changes: list[Transition], style: WebhookStyle) -> dict[str, Any]:
+    """
+    Render sites that went down or recovered as the JSON body for a webhook style.
+
+    Args:
+        changes: Transitions from data.reachability.transitions
+        style: Payload style
+
+    Returns:
+        JSON body for the webhook
+    """
+    down = sum(1 for c in changes if c.kind == "down")
+    headline = f"Reachability: {down} site(s) down, {len(changes) - down} recovered"
+    lines = [_transition_line(c) for c in changes]
+    if style == "slack":
+        return {
+            "text": headline,
+            "blocks": [
+                {"type": "header", "text": {"type": "plain_text", "text": headline[:150]}},
+                {"type": "section", "text": {"type": "mrkdwn", "text": "\n".join(f"• {line}" for line in lines)}},
+            ],
+        }
+    if style == "discord":
+        color = 0xE74C3C if down else 0x2ECC71
+        return {"embeds": [{"title": headline[:256], "description": "\n".join(lines)[:4096], "color": color}]}
+    return {
+        "event": "reachability.changed",
+        "text": headline,
+        "changes": [
+            {"url": c.url, "state": c.kind, "since": c.since, "probe": c.probe.model_dump()} for c in changes
+        ],
+    }
+
+
+class Webhook:
+    """
+    Sends audit and reachability notifications to one webhook URL.
+
+    5xx responses and network errors are retried with exponential backoff;
+    other 4xx responses fail immediately. The URL is never logged in full.
+    """
+
+    def __init__(
+        self,
+        config: WebhookConfig,
+        client: httpx.AsyncClient | None = None,
+        sleep: Callable[[float], Awaitable[Any]] = asyncio.sleep,
+    ):
+        self.config = config
+        self._client = client
+        self._sleep = sleep
+
+    def payload(self, result: AuditResult, previous: AuditResult | None = None) -> dict[str, Any]:
+        """Build the payload that send() would post."""
+        message = build_message(result, previous, self.config.top_n, self.config.report_url)
+        return build_payload(message, self.config.resolved_style)
+
+    async def send(
+        self, result: AuditResult, previous: AuditResult | None = None, dry_run: bool = False
+    ) -> WebhookDelivery:
+        """
+        Post a notification for an audit.
+
+        Args:
+            result: Audit result to announce
+            previous: Earlier audit, for the score delta
+            dry_run: Build the payload without sending it
+
+        Returns:
+            WebhookDelivery with the payload and outcome
+
+        Raises:
+            IntegrationError: If the webhook rejects the message or retries run out
+        """
+        payload = self.payload(result, previous)
+        if dry_run:
+            return WebhookDelivery(payload=payload, sent=False)
+        return await self._post(payload)
+
+    async def send_transitions(self, changes: list[Transition], dry_run: bool = False) -> WebhookDelivery:
+        """
+        Post a notification for sites that went down or recovered.
+
+        Raises:
+            IntegrationError: If the webhook rejects the message or retries run out
+        """
+        payload = build_transition_payload(changes, self.config.resolved_style)
+        if dry_run:
+            return WebhookDelivery(payload=payload, sent=False)
+        return await self._post(payload)
+
+    async def _post(self, payload: dict[str, Any]) -> WebhookDelivery:
+        target = redact_url(self.config.url)
+        client = self._client or httpx.AsyncClient(timeout=self.config.timeout)
+        try:
+            attempts = 0
+            while True:
+                attempts += 1
+                try:
+                    response = await client.post(self.config.url, json=payload)
+                except httpx.TransportError as e:
+                    status, reason = None, type(e).__name__
+                else:
+                    if response.status_code < 400:
+                        logger.info(f"Webhook delivered to {target} ({response.status_code})")
+                        return WebhookDelivery(
+                            payload=payload, sent=True, attempts=attempts, status_code=response.status_code
+                        )
+                    status, reason = response.status_code, f"HTTP {response.status_code}"
+                    if status < 500 and status != 429:
+                        raise IntegrationError(f"Webhook {target} rejected the message: {reason}")
+
+                if attempts > self.config.max_retries:
+                    raise IntegrationError(f"Webhook {target} failed after {attempts} attempts: {reason}")
+                delay = self.config.backoff_seconds * 2 ** (attempts - 1)
+                logger.warning(f"Webhook {target} failed ({reason}); retrying in {delay:g}s")
+                await self._sleep(delay)
+        finally:
+            if self._client is None:
+                await client.aclose()
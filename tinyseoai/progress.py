@@ -0,0 +1,132 @@
+"""
+Progress events shared by the audit engine, the crawler, the check runner,
+and anything watching an audit from outside.
+
+In-process callers pass a hook (on_progress) and receive ProgressEvent
+objects. Front ends running the CLI as a subprocess use --progress-json,
+which writes one event per line to stderr, and read them back with
+ProgressEvent.from_line; it also understands the engine's plain log lines,
+so output captured without --progress-json converts to the same phases.
+"""
+from __future__ import annotations
+
+import json
+import re
+from collections.abc import Callable, Iterable
+from datetime import datetime, timezone
+from enum import Enum
+
+from loguru import logger
+from pydantic import BaseModel, Field, ValidationError
+
+
+class Phase(str, Enum):
+    """Where an audit is, in the order the phases happen."""
+
+    FETCHING = "fetching"  # robots.txt and sitemaps
+    CRAWLING = "crawling"
+    CHECKING = "checking"  # site-level checks; check_id names the one running
+    ANALYZING = "analyzing"  # scoring and prioritizing
+    DONE = "done"
+
+
+class ProgressEvent(BaseModel):
+    """One step of an audit."""
+
+    phase: Phase
+    check_id: str | None = None  # the check starting, in the checking phase
+    done: int | None = None  # pages crawled, or checks started, so far
+    total: int | None = None  # the page limit, or the number of checks
+    message: str = ""
+    job_id: str | None = None  # set by whoever started the audit, to tell runs apart
+    timestamp: datetime = Field(default_factory=lambda: datetime.now(timezone.utc))
+
+    def to_line(self) -> str:
+        """This event as one JSON line (without the newline)."""
+        return self.model_dump_json(exclude_none=True)
+
+    @classmethod
+    def from_line(cls, line: str, job_id: str | None = None) -> ProgressEvent | None:
+        """
+        Parse a line of CLI output: a --progress-json line, or one of the
+        engine's progress log lines. Other lines give None.
+
+        Args:
+            line: One line of the CLI's stderr (or stdout)
+            job_id: Set on events parsed from log lines, which carry none
+        """
+        line = line.strip()
+        if line.startswith("{"):
+            try:
+                return cls.model_validate(json.loads(line))
+            except (ValueError, ValidationError):
+                return None
+        stamp = _LOG_PREFIX.match(line)
+        message = line.split(" - ", 1)[1].strip() if stamp and " - " in line else line
+        for pattern, phase, fields in _LOG_LINES:
+            match = pattern.fullmatch(message)
+            if match is None:
+                continue
+            event = cls(phase=phase, message=message, job_id=job_id, **fields(match))
+            if stamp:
+                event.timestamp = datetime.fromisoformat(stamp.group(1))
+            return event
+        return None
+
+
+ProgressHook = Callable[[ProgressEvent], None]
+
+
+class ProgressEmitter:
+    """Builds events for a hook, stamping the job id; does nothing without a hook."""
+
+    def __init__(self, hook: ProgressHook | None = None, job_id: str | None = None):
+        self.hook = hook
+        self.job_id = job_id
+
+    def __call__(self, phase: Phase, message: str = "", **fields) -> None:
+        if self.hook is None:
+            return
+        try:
+            self.hook(ProgressEvent(phase=phase, message=message, job_id=self.job_id, **fields))
+        except Exception as e:  # a broken listener must not stop the audit
+            logger.warning(f"Progress hook failed: {e}")
+
+
+def parse_stream(lines: Iterable[str], job_id: str | None = None) -> list[ProgressEvent]:
+    """The progress events in captured CLI output, in order; other lines are ignored."""
+    events = (ProgressEvent.from_line(line, job_id) for line in lines)
+    return [event for event in events if event is not None]
+
+
+# loguru's default format: "2024-05-01 10:00:00.123 | INFO     | module:function:12 - message"
+_LOG_PREFIX = re.compile(r"(\d{4}-\d\d-\d\d \d\d:\d\d:\d\d(?:\.\d+)?) \| ")
+
+
+def _no_fields(match: re.Match) -> dict:
+    return {}
+
+
+_LOG_LINES: list[tuple[re.Pattern, Phase, Callable[[re.Match], dict]]] = [
+    (re.compile(r"Phase 1: Analyzing robots\.txt\.\.\."), Phase.FETCHING, _no_fields),
+    (re.compile(r"Discovering sitemaps\.\.\."), Phase.FETCHING, _no_fields),
+    (re.compile(r"Found \d+ URLs in sitemaps"), Phase.FETCHING, _no_fields),
+    (
+        re.compile(r"Phase 2: Crawling up to (\d+) pages\.\.\."),
+        Phase.CRAWLING,
+        lambda m: {"done": 0, "total": int(m.group(1))},
+    ),
+    (
+        re.compile(r"Crawled (\d+)/(\d+) pages\.\.\."),
+        Phase.CRAWLING,
+        lambda m: {"done": int(m.group(1)), "total": int(m.group(2))},
+    ),
+    (re.compile(r"Phase 3: Running post-crawl analysis\.\.\."), Phase.CHECKING, _no_fields),
+    (
+        re.compile(r"Running check (\S+) \((\d+)/(\d+)\)"),
+        Phase.CHECKING,
+        lambda m: {"check_id": m.group(1), "done": int(m.group(2)), "total": int(m.group(3))},
+    ),
+    (re.compile(r"Phase 4: Calculating health scores\.\.\."), Phase.ANALYZING, _no_fields),
+    (re.compile(r"Audit complete: .*"), Phase.DONE, _no_fields),
+]
@@ -0,0 +1,173 @@
+"""
+A report bundle: everything about one audit in a single zip file, for clients.
+
+The bundle holds the result JSON, the standalone HTML report, the CSV of
+issues, and the Markdown report, each made by the same renderer as the
+report command, plus a manifest naming the audit, the app version, and
+each file. Member names are fixed, so scripts can read a bundle without the
+manifest. Parts are written into the archive one at a time, through the
+zip writer's streams; the JSON and CSV are never held whole in memory.
+"""
+from __future__ import annotations
+
+import io
+import json
+import os
+import tempfile
+import zipfile
+from collections.abc import Callable
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Any, TextIO
+
+from pydantic import BaseModel, Field
+
+from ..data.models import FORMAT_VERSION, AuditResult
+from ..i18n import Locale
+from ..store.history import HistoryStore
+from ..version import package_version
+from .html_report import HtmlOptions, render_html
+from .markdown import RenderOptions, render_markdown
+from .tabular import CsvOptions, write_csv
+
+BUNDLE_VERSION = 1
+
+MANIFEST = "manifest.json"
+RESULT = "result.json"
+REPORT = "report.html"
+ISSUES = "issues.csv"
+SUMMARY = "summary.md"
+
+
+class BundleOptions(BaseModel):
+    """What goes into the reports of a bundle."""
+
+    max_pages_per_issue: int = Field(default=10, ge=1)  # in the HTML and Markdown reports
+    locale: Locale | None = None  # language of the reports; None: the result's
+    csv: CsvOptions = Field(default_factory=CsvOptions)
+
+
+class BundleFile(BaseModel):
+    """One member of a bundle."""
+
+    name: str
+    media_type: str
+    description: str
+    size: int = 0  # bytes, uncompressed
+
+
+class BundleManifest(BaseModel):
+    """manifest.json of a bundle: the audit, what made the bundle, and what is inside."""
+
+    version: int = BUNDLE_VERSION
+    app_version: str
+    created_at: str
+    audit_id: int | None = None  # history ID, when exported from the history
+    site: str
+    audited_at: str | None = None  # the audit's timestamp
+    pages_scanned: int
+    issues: int
+    health_score: float | None = None
+    format_version: int = FORMAT_VERSION  # of result.json
+    files: list[BundleFile] = Field(default_factory=list)  # members besides the manifest
+
+
+def _write_text(archive: zipfile.ZipFile, name: str, write: Callable[[TextIO], Any]) -> None:
+    with archive.open(name, "w") as raw, io.TextIOWrapper(raw, encoding="utf-8", newline="") as fh:
+        write(fh)
+
+
+def write_bundle(
+    summary: dict[str, Any],
+    dest: Path,
+    options: BundleOptions | None = None,
+    audit_id: int | None = None,
+) -> BundleManifest:
+    """
+    Write the bundle of an audit to dest.
+
+    The archive is written next to dest first and moved into place, so an
+    interrupted export never leaves a truncated file behind.
+
+    Args:
+        summary: Loaded audit JSON (optionally with "ai_summary")
+        dest: Zip file to create (replaced if it exists)
+        options: Page limit and locale of the reports, and the CSV options
+        audit_id: History ID to record in the manifest
+
+    Returns:
+        The manifest written into the bundle
+    """
+    options = options or BundleOptions()
+    result = AuditResult(**summary)
+    meta = summary.get("meta", {})
+    score = meta.get("health_score")
+    manifest = BundleManifest(
+        app_version=package_version(),
+        created_at=datetime.now(timezone.utc).isoformat(),
+        audit_id=audit_id,
+        site=result.site,
+        audited_at=meta.get("timestamp"),
+        pages_scanned=result.pages_scanned,
+        issues=len(result.issues),
+        health_score=score if isinstance(score, int | float) else None,
+    )
+    rows = "issue and page" if options.csv.explode_pages else "issue type"
+    html_options = HtmlOptions(max_pages_per_issue=options.max_pages_per_issue, locale=options.locale)
+    md_options = RenderOptions(max_pages_per_issue=options.max_pages_per_issue, locale=options.locale)
+    parts = [
+        (
+            BundleFile(name=RESULT, media_type="application/json", description="The audit result"),
+            lambda fh: json.dump(summary, fh, indent=2, ensure_ascii=False),
+        ),
+        (
+            BundleFile(name=REPORT, media_type="text/html", description="Standalone HTML report"),
+            lambda fh: fh.write(render_html(summary, html_options)),
+        ),
+        (
+            BundleFile(name=ISSUES, media_type="text/csv", description=f"Issues, one row per {rows}"),
+            lambda fh: write_csv(result, fh, options.csv),
+        ),
+        (
+            BundleFile(name=SUMMARY, media_type="text/markdown", description="Markdown report"),
+            lambda fh: fh.write(render_markdown(summary, md_options)),
+        ),
+    ]
+
+    dest.parent.mkdir(parents=True, exist_ok=True)
+    with tempfile.TemporaryDirectory(dir=dest.parent, prefix=".tinyseoai-bundle-") as tmp:
+        partial = Path(tmp) / dest.name
+        with zipfile.ZipFile(partial, "w", compression=zipfile.ZIP_DEFLATED) as archive:
+            for member, write in parts:
+                _write_text(archive, member.name, write)
+                member.size = archive.getinfo(member.name).file_size
+                manifest.files.append(member)
+            archive.writestr(MANIFEST, manifest.model_dump_json(indent=2) + "\n")
+        os.replace(partial, dest)
+    return manifest
+
+
+def export_bundle(
+    audit_id: int,
+    dest: Path,
+    options: BundleOptions | None = None,
+    store: HistoryStore | None = None,
+) -> BundleManifest:
+    """
+    Write the bundle of an audit saved in the history.
+
+    Args:
+        audit_id: History ID
+        dest: Zip file to create
+        options: Page limit and locale of the reports, and the CSV options
+        store: History to read from; None opens the default one
+
+    Raises:
+        StoreError: If the audit does not exist or is corrupted
+    """
+    if store is None:
+        with HistoryStore() as own:
+            result = own.get(audit_id)
+    else:
+        result = store.get(audit_id)
+    return write_bundle(json.loads(result.to_json()), dest, options, audit_id=audit_id)
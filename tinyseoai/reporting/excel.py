@@ -7,6 +7,11 @@ from openpyxl import Workbook
 from openpyxl.styles import Alignment, Font
 from openpyxl.utils import get_column_letter
 
+from ..audit.scope import CrawlOptions
+from ..data.effort import efforts
+from ..data.models import Issue
+from ..data.wcag import ACCESSIBILITY_HEADERS, accessibility_rows, criterion_of
+
 
 def _autofit(ws, cols: int, min_w: int = 10, max_w: int = 80):
     widths = [0] * (cols + 1)
@@ -54,6 +59,11 @@ def write_xlsx(summary: dict[str, Any], out_path: Path) -> Path:
     ws["A4"].font = Font(bold=True)
     ws["B4"] = meta.get("timestamp", "")
 
+    scope = meta.get("crawl_scope")
+    if scope:
+        ws["A5"] = "Crawl scope"
+        ws["B5"] = CrawlOptions(**scope).describe()
+
     ai = summary.get("ai_summary")
     if ai:
         ws["A6"] = "AI summary"
@@ -68,19 +78,35 @@ def write_xlsx(summary: dict[str, Any], out_path: Path) -> Path:
 
     # Issues sheet
     wi = wb.create_sheet("Issues")
-    headers = ["Type", "Severity", "URL", "Detail"]
+    headers = ["Type", "Severity", "Effort", "WCAG", "URL", "Detail"]
+    parsed = [Issue(**it) for it in issues]
+    effort_by_type = efforts(parsed)
     wi.append(headers)
     for c in range(1, len(headers) + 1):
         wi.cell(row=1, column=c).font = Font(bold=True)
-    for it in issues:
+    for it, issue in zip(issues, parsed, strict=True):
+        found = criterion_of(issue)
         wi.append([
             it.get("type", ""),
             it.get("severity", ""),
+            effort_by_type[it.get("type", "")].value,
+            found.number if found else "",
             it.get("url", ""),
             it.get("detail", ""),
         ])
     _autofit(wi, len(headers))
 
+    # Accessibility sheet, only when there are accessibility issues
+    accessibility = accessibility_rows(parsed)
+    if accessibility:
+        wx = wb.create_sheet("Accessibility")
+        wx.append(ACCESSIBILITY_HEADERS)
+        for c in range(1, len(ACCESSIBILITY_HEADERS) + 1):
+            wx.cell(row=1, column=c).font = Font(bold=True)
+        for row in accessibility:
+            wx.append(row)
+        _autofit(wx, len(ACCESSIBILITY_HEADERS))
+
     # If AI summary is merged, add a sheet
     ai = summary.get("ai_summary")
     if ai:
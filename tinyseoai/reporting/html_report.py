@@ -1,14 +1,44 @@
 from __future__ import annotations
 
 import base64
+import re
+from collections import defaultdict
 from datetime import datetime
 from importlib import resources as importlib_resources
 from pathlib import Path
 from typing import Any
 
 from jinja2 import Environment, FileSystemLoader, select_autoescape
+from pydantic import BaseModel
 
+from ..audit.scope import CrawlOptions
 from ..config import get_config
+from ..data.comparison import SiteComparison
+from ..data.migrate import migrate
+from ..data.narrative import generate
+from ..data.recommendations import issue_title, recommendation_for
+from ..data.score_model import ScoreModel, explain
+from ..data.severity import OTHER, Severity
+from ..i18n import Locale, detail_text, result_locale
+from ..store.snapshots import snapshot_archive
+
+_HEX_COLOR = re.compile(r"^#[0-9a-fA-F]{3,8}$")
+
+
+class HtmlOptions(BaseModel):
+    """White-label and layout options for the standalone HTML report."""
+
+    title: str | None = None
+    byline: str | None = None  # replaces "Generated by <brand>" in the footer
+    logo_src: str | None = None  # data:image/... URI or https:// URL
+    accent_hex: str | None = None
+    max_pages_per_issue: int = 50
+    include_ai: bool = True
+    locale: Locale | None = None  # language of titles, details, and advice; None: the result's
+    score_breakdown: bool = False  # the issue types costing the most points
+    score_model: ScoreModel | None = None  # model of the breakdown; None: the default score
+    include_snapshots: bool = False  # link each page to its archived HTML (audit-full --snapshots)
+    intro: str | None = None  # note above the overview, e.g. the message of an emailed report
 
 
 def _templates_path() -> str:
@@ -42,16 +72,21 @@ def _font_base64() -> str | None:
         return None
 
 
-def build_html(summary: dict[str, Any]) -> str:
-    cfg = get_config()
-    env = Environment(
+def env() -> Environment:
+    """The Jinja environment of the report templates, shared by the HTML and PDF reports."""
+    return Environment(
         loader=FileSystemLoader(_templates_path()),
         autoescape=select_autoescape(["html", "xml"]),
         trim_blocks=True,
         lstrip_blocks=True,
     )
-    tpl = env.get_template("report.html")
+
+
+def build_html(summary: dict[str, Any]) -> str:
+    cfg = get_config()
+    tpl = env().get_template("report.html")
     site = summary.get("site", "")
+    scope = summary.get("meta", {}).get("crawl_scope")
     ctx = {
         "title": f"{cfg.brand.name} Report",
         "subtitle": site,
@@ -60,6 +95,7 @@ def build_html(summary: dict[str, Any]) -> str:
         "pages_scanned": summary.get("pages_scanned", 0),
         "issues": summary.get("issues", []),
         "meta": summary.get("meta", {}),
+        "scope": CrawlOptions(**scope).describe() if scope else None,
         "ai": summary.get("ai_summary"),
         "font_data": _font_base64(),
         "brand": {
@@ -69,3 +105,248 @@ def build_html(summary: dict[str, Any]) -> str:
         },
     }
     return tpl.render(**ctx)
+
+
+def _safe_logo(src: str | None) -> str | None:
+    """Only allow image data URIs and https URLs as logo sources."""
+    if src and (src.startswith("data:image/") or src.startswith("https://")):
+        return src
+    return None
+
+
+def localized(issues: list[dict[str, Any]], locale: Locale) -> list[dict[str, Any]]:
+    """Issues with details rendered in a locale; those without a catalog message are kept as-is."""
+    return [
+        {**issue, "detail": detail_text(issue["message"], issue.get("args"), issue.get("detail"), locale)}
+        if issue.get("message")
+        else issue
+        for issue in issues
+    ]
+
+
+def _with_snapshots(issues: list[dict[str, Any]], meta: dict[str, Any]) -> list[dict[str, Any]]:
+    """Issues with a file link to their snapshot, or marked missing when it is gone."""
+    archive = snapshot_archive(meta)
+    linked = []
+    for issue in issues:
+        if issue.get("snapshot"):
+            path = archive.path_of(issue["snapshot"]) if archive else None
+            issue = {
+                **issue,
+                "snapshot_href": path.resolve().as_uri() if path else None,
+                "snapshot_missing": path is None,
+            }
+        linked.append(issue)
+    return linked
+
+
+def severity_sections(
+    issues: list[dict[str, Any]], max_pages: int, locale: Locale = Locale.EN
+) -> list[dict[str, Any]]:
+    """Issues grouped by severity, then type (most pages first), listing up to max_pages pages each."""
+    buckets: dict[str, dict[str, list[dict[str, Any]]]] = defaultdict(lambda: defaultdict(list))
+    for issue in issues:
+        level = Severity.parse(issue.get("severity"))
+        buckets[level.value if level else OTHER][issue.get("type", "")].append(issue)
+
+    sections = []
+    for key in [s.value for s in Severity] + [OTHER]:
+        by_type = buckets.get(key)
+        if not by_type:
+            continue
+        groups = []
+        for issue_type in sorted(by_type, key=lambda t: (-len(by_type[t]), t)):
+            pages = by_type[issue_type]
+            groups.append(
+                {
+                    "type": issue_type,
+                    "title": issue_title(issue_type, locale),
+                    "pages": pages,
+                    "shown": pages[:max_pages],
+                    "hidden": max(0, len(pages) - max_pages),
+                    "recommendation": recommendation_for(issue_type, locale),
+                }
+            )
+        sections.append(
+            {
+                "key": key,
+                "label": key.capitalize(),
+                "count": sum(len(g["pages"]) for g in groups),
+                "groups": groups,
+            }
+        )
+    return sections
+
+
+def render_html(summary: dict[str, Any], options: HtmlOptions | None = None) -> str:
+    """
+    Render a single-file HTML report with inline CSS, a score dial, and severity tabs.
+
+    All audit content is autoescaped, so crawled titles cannot inject markup.
+
+    Args:
+        summary: Loaded audit JSON (optionally with "ai_summary")
+        options: White-label and layout options
+
+    Returns:
+        Self-contained HTML document
+    """
+    options = options or HtmlOptions()
+    cfg = get_config()
+    meta = summary.get("meta", {})
+    site = summary.get("site", "")
+    score = meta.get("health_score")
+    accent = options.accent_hex if options.accent_hex and _HEX_COLOR.match(options.accent_hex) else None
+    scope = meta.get("crawl_scope")
+    locale = options.locale or result_locale(meta)
+    issues = localized(summary.get("issues", []), locale)
+    if options.include_snapshots:
+        issues = _with_snapshots(issues, meta)
+    result = migrate(summary)
+    ai = summary.get("ai_summary") if options.include_ai else None
+    contributions = explain(result, options.score_model)[:10] if options.score_breakdown else []
+
+    tpl = env().get_template("standalone.html")
+    return tpl.render(
+        title=options.title or f"{cfg.brand.name} Report",
+        subtitle=site,
+        ts=meta.get("timestamp") or datetime.utcnow().isoformat() + "Z",
+        site=site,
+        pages_scanned=summary.get("pages_scanned", 0),
+        issues=issues,
+        meta=meta,
+        scope=CrawlOptions(**scope).describe() if scope else None,
+        score=score if isinstance(score, int | float) else None,
+        grade=meta.get("health_grade", "?"),
+        dial_length=round(3.1416 * 100 * max(0, min(100, score)) / 100, 2)
+        if isinstance(score, int | float)
+        else 0,
+        severities=severity_sections(issues, options.max_pages_per_issue, locale),
+        contributions=[
+            {**c.model_dump(), "title": issue_title(c.issue_type, locale), "formula": c.formula}
+            for c in contributions
+        ],
+        score_gain=sum(c.points for c in contributions[:3]),
+        ai=ai,
+        narrative=None if ai else generate(result, locale),
+        font_data=_font_base64(),
+        logo_src=_safe_logo(options.logo_src),
+        byline=options.byline,
+        intro=options.intro,
+        brand={
+            "name": cfg.brand.name,
+            "accent_hex": accent or cfg.brand.accent_hex,
+            "show_bot_logo": cfg.brand.show_bot_logo,
+        },
+    )
+
+
+def write_html(summary: dict[str, Any], out_path: Path, options: HtmlOptions | None = None) -> Path:
+    """Render and write the standalone HTML report, returning the output path."""
+    out_path.parent.mkdir(parents=True, exist_ok=True)
+    out_path.write_text(render_html(summary, options), encoding="utf-8")
+    return out_path
+
+
+def _ahead(a: float, b: float, higher_is_better: bool = False) -> str | None:
+    """The side with the better value, or None on a tie."""
+    if a == b:
+        return None
+    return "a" if (a > b) == higher_is_better else "b"
+
+
+def _comparison_metrics(comparison: SiteComparison) -> list[dict[str, Any]]:
+    """Side-by-side rows, each marking the side that does better (if either)."""
+    a, b = comparison.a, comparison.b
+    rows = []
+    if a.health_score is not None and b.health_score is not None:
+        rows.append(
+            {
+                "label": "Health score",
+                "a": f"{a.health_score:g}",
+                "b": f"{b.health_score:g}",
+                "winner": _ahead(a.health_score, b.health_score, higher_is_better=True),
+            }
+        )
+    rows.append({"label": "Pages scanned", "a": a.pages_scanned, "b": b.pages_scanned, "winner": None})
+    rows.append({"label": "Issues", "a": a.issues, "b": b.issues, "winner": None})
+    rows.append(
+        {
+            "label": "Issues per page",
+            "a": f"{a.issues_per_page:g}",
+            "b": f"{b.issues_per_page:g}",
+            "winner": _ahead(a.issues_per_page, b.issues_per_page),
+        }
+    )
+    for severity in ("high", "medium"):
+        a_count, b_count = a.severities.get(severity, 0), b.severities.get(severity, 0)
+        rows.append(
+            {
+                "label": f"{severity.capitalize()} issues",
+                "a": a_count,
+                "b": b_count,
+                "winner": _ahead(a_count, b_count),
+            }
+        )
+    a_wins, b_wins = len(comparison.wins("a")), len(comparison.wins("b"))
+    rows.append(
+        {
+            "label": "Categories ahead",
+            "a": a_wins,
+            "b": b_wins,
+            "winner": _ahead(a_wins, b_wins, higher_is_better=True),
+        }
+    )
+    return rows
+
+
+def render_comparison_html(comparison: SiteComparison, options: HtmlOptions | None = None) -> str:
+    """
+    Render a two-column HTML report comparing two sites (see data.comparison).
+
+    Uses the white-label options of render_html; layout options that only
+    apply to one audit's issue list are ignored.
+
+    Returns:
+        Self-contained HTML document
+    """
+    options = options or HtmlOptions()
+    cfg = get_config()
+    accent = options.accent_hex if options.accent_hex and _HEX_COLOR.match(options.accent_hex) else None
+    locale = options.locale or Locale.EN
+
+    def titled(gaps):
+        return [{**g.model_dump(), "title": issue_title(g.type, locale)} for g in gaps]
+
+    tpl = env().get_template("comparison.html")
+    return tpl.render(
+        title=options.title or f"{cfg.brand.name} Comparison",
+        subtitle=f"{comparison.a.site} vs {comparison.b.site}",
+        ts=comparison.created_at,
+        mode=comparison.mode,
+        a=comparison.a,
+        b=comparison.b,
+        metrics=_comparison_metrics(comparison),
+        categories=comparison.categories,
+        gaps=[
+            {"site": comparison.a.site, "issues": titled(comparison.only_a)},
+            {"site": comparison.b.site, "issues": titled(comparison.only_b)},
+        ],
+        font_data=_font_base64(),
+        logo_src=_safe_logo(options.logo_src),
+        byline=options.byline,
+        brand={
+            "name": cfg.brand.name,
+            "accent_hex": accent or cfg.brand.accent_hex,
+            "show_bot_logo": cfg.brand.show_bot_logo,
+        },
+    )
+
+
+def write_comparison_html(
+    comparison: SiteComparison, out_path: Path, options: HtmlOptions | None = None
+) -> Path:
+    """Render and write the comparison report, returning the output path."""
+    out_path.parent.mkdir(parents=True, exist_ok=True)
+    out_path.write_text(render_comparison_html(comparison, options), encoding="utf-8")
+    return out_path
@@ -0,0 +1,156 @@
+"""
+JUnit XML export so CI servers (Jenkins, GitLab) show SEO checks as tests.
+"""
+from __future__ import annotations
+
+import re
+import xml.etree.ElementTree as ET
+from collections import defaultdict
+from datetime import datetime
+from pathlib import Path
+from typing import Literal
+from urllib.parse import urlparse
+
+from pydantic import BaseModel
+
+from ..data.models import AuditResult, Issue
+from ..data.query import issue_category
+from ..data.recommendations import issue_title, recommendation_for
+from ..data.severity import Category, Severity
+
+# Characters XML 1.0 cannot represent, even escaped
+_ILLEGAL_XML = re.compile("[\x00-\x08\x0b\x0c\x0e-\x1f\ufffe\uffff]")
+
+
+class JunitOptions(BaseModel):
+    """Options for the JUnit renderer."""
+
+    warnings_as: Literal["failure", "skipped"] = "failure"  # how medium issues are reported
+    suite_prefix: str = "seo"
+
+
+def _clean(text: str | None) -> str:
+    return _ILLEGAL_XML.sub("", text or "")
+
+
+def _timestamp(value: str | None) -> str | None:
+    """JUnit timestamps are ISO 8601 without timezone or fractions."""
+    if not value:
+        return None
+    try:
+        parsed = datetime.fromisoformat(value.replace("Z", "+00:00"))
+    except ValueError:
+        return None
+    return parsed.strftime("%Y-%m-%dT%H:%M:%S")
+
+
+def _outcome(issue: Issue, options: JunitOptions) -> str | None:
+    level = issue.level
+    if level is Severity.HIGH:
+        return "failure"
+    if level is Severity.MEDIUM or level is None:
+        return options.warnings_as
+    return None  # low/info pass, with the detail in system-out
+
+
+def _testcase(suite: ET.Element, classname: str, issue: Issue, options: JunitOptions) -> str | None:
+    case = ET.SubElement(
+        suite, "testcase", classname=_clean(classname), name=_clean(issue.url), time="0"
+    )
+    body = _clean(issue.detail or issue_title(issue.type))
+    recommendation = recommendation_for(issue.type)
+    if recommendation:
+        body += f"\nRecommendation: {recommendation}"
+    message = _clean(f"{issue_title(issue.type)} ({issue.severity})")
+
+    outcome = _outcome(issue, options)
+    if outcome == "failure":
+        failure = ET.SubElement(case, "failure", message=message, type=_clean(issue.type))
+        failure.text = body
+    elif outcome == "skipped":
+        ET.SubElement(case, "skipped", message=message)
+        ET.SubElement(case, "system-out").text = body
+    else:
+        ET.SubElement(case, "system-out").text = body
+    return outcome
+
+
+def render_junit(result: AuditResult, options: JunitOptions | None = None) -> str:
+    """
+    Convert an audit result into JUnit XML.
+
+    There is one testsuite per issue category and one testcase per issue.
+    High issues are failures, medium (and unknown) issues are failures or
+    skipped per options.warnings_as, and low/info issues pass. Categories
+    without issues get a single passing testcase so no suite is empty.
+
+    Args:
+        result: Audit result to export
+        options: Rendering options
+
+    Returns:
+        XML document as a string
+    """
+    options = options or JunitOptions()
+    by_category: dict[Category, list[Issue]] = defaultdict(list)
+    for issue in result.issues:
+        by_category[issue_category(issue)].append(issue)
+
+    categories = [c for c in Category if c is not Category.OTHER or by_category.get(c)]
+    duration = result.meta.get("duration_seconds")
+    total_cases = sum(max(len(by_category.get(c, [])), 1) for c in categories)
+    timestamp = _timestamp(result.meta.get("timestamp"))
+    hostname = urlparse(result.site).netloc or result.site
+
+    root = ET.Element("testsuites", name=_clean(f"{options.suite_prefix} audit {result.site}"))
+    totals = {"tests": 0, "failure": 0}
+
+    for category in categories:
+        issues = by_category.get(category, [])
+        name = f"{options.suite_prefix}.{category.value}"
+        suite = ET.SubElement(root, "testsuite", name=_clean(name))
+        counts = {"failure": 0, "skipped": 0}
+
+        if issues:
+            for issue in issues:
+                outcome = _testcase(suite, f"{name}.{issue.type}", issue, options)
+                if outcome:
+                    counts[outcome] += 1
+        else:
+            ET.SubElement(
+                suite,
+                "testcase",
+                classname=_clean(name),
+                name=f"No {category.value} issues",
+                time="0",
+            )
+
+        cases = max(len(issues), 1)
+        suite.set("tests", str(cases))
+        suite.set("failures", str(counts["failure"]))
+        suite.set("errors", "0")
+        suite.set("skipped", str(counts["skipped"]))
+        if isinstance(duration, int | float):
+            suite.set("time", f"{duration * cases / total_cases:.3f}")
+        if timestamp:
+            suite.set("timestamp", timestamp)
+        suite.set("hostname", _clean(hostname))
+
+        totals["tests"] += cases
+        totals["failure"] += counts["failure"]
+
+    root.set("tests", str(totals["tests"]))
+    root.set("failures", str(totals["failure"]))
+    root.set("errors", "0")
+    if isinstance(duration, int | float):
+        root.set("time", f"{duration:.3f}")
+
+    ET.indent(root)
+    return '<?xml version="1.0" encoding="UTF-8"?>\n' + ET.tostring(root, encoding="unicode") + "\n"
+
+
+def write_junit(result: AuditResult, out_path: Path, options: JunitOptions | None = None) -> Path:
+    """Write a JUnit XML report to a file, returning the output path."""
+    out_path.parent.mkdir(parents=True, exist_ok=True)
+    out_path.write_text(render_junit(result, options), encoding="utf-8")
+    return out_path
@@ -0,0 +1,308 @@
+"""
+Markdown report renderer.
+"""
+from __future__ import annotations
+
+from collections import defaultdict
+from pathlib import Path
+from typing import Any
+
+from pydantic import BaseModel
+
+from ..audit.checks.protocol import describe as describe_protocol
+from ..audit.scope import CrawlOptions
+from ..data.diagnostics import Diagnostics, format_bytes
+from ..data.diff import AuditDiff
+from ..data.migrate import migrate
+from ..data.models import Issue
+from ..data.narrative import generate
+from ..data.recommendations import issue_title, recommendation_for
+from ..data.score_model import Contribution, ScoreModel, explain
+from ..data.severity import OTHER, Severity, count_by_severity
+from ..i18n import Locale, localize, result_locale
+from ..integrations.pagespeed import field_summary, stored_results
+
+_ESCAPES = {
+    "\\": "\\\\",
+    "`": "\\`",
+    "|": "\\|",
+    "*": "\\*",
+    "_": "\\_",
+    "[": "\\[",
+    "]": "\\]",
+    "<": "&lt;",
+    ">": "&gt;",
+}
+
+
+class RenderOptions(BaseModel):
+    """Options shared by the text report renderers."""
+
+    max_pages_per_issue: int = 10
+    include_ai: bool = True
+    diff: AuditDiff | None = None
+    suppressed: list[Issue] | None = None  # baselined issues to list separately
+    include_diagnostics: bool = False  # per-check time, requests, and bytes, when recorded
+    locale: Locale | None = None  # language of titles, details, and advice; None: the result's
+    score_breakdown: bool = False  # the issue types costing the most points
+    score_model: ScoreModel | None = None  # model of the breakdown; None: the default score
+
+
+def escape_md(text: str | None) -> str:
+    """Escape text so it renders literally inside Markdown paragraphs and tables."""
+    if not text:
+        return ""
+    text = " ".join(str(text).splitlines())
+    return "".join(_ESCAPES.get(ch, ch) for ch in text)
+
+
+def _group_by_severity(issues: list[Issue]) -> dict[str, dict[str, list[Issue]]]:
+    groups: dict[str, dict[str, list[Issue]]] = defaultdict(lambda: defaultdict(list))
+    for issue in issues:
+        level = Severity.parse(issue.severity)
+        groups[level.value if level else OTHER][issue.type].append(issue)
+    return groups
+
+
+def _issue_section(
+    issue_type: str, issues: list[Issue], options: RenderOptions, locale: Locale = Locale.EN
+) -> list[str]:
+    pages = len({i.url for i in issues})
+    title = issue_title(issue_type, locale)
+    lines = [f"### {escape_md(title)} ({pages} page{'s' if pages != 1 else ''})", ""]
+    lines.append(f"Type: `{issue_type.replace('`', '')}`")
+    lines.append("")
+
+    shown = issues[: options.max_pages_per_issue]
+    for issue in shown:
+        line = f"- {escape_md(issue.url)}"
+        if issue.detail:
+            line += f" — {escape_md(issue.detail)}"
+        lines.append(line)
+    if len(issues) > len(shown):
+        lines.append(f"- …and {len(issues) - len(shown)} more")
+    lines.append("")
+
+    recommendation = recommendation_for(issue_type, locale)
+    if recommendation:
+        lines.append(f"**Recommendation:** {escape_md(recommendation)}")
+        lines.append("")
+    return lines
+
+
+def _ai_section(ai: dict[str, Any]) -> list[str]:
+    lines = ["## AI analysis", ""]
+    if ai.get("summary"):
+        lines += [ai["summary"], ""]
+    if ai.get("top_issues"):
+        lines += ["### Top issues", ""]
+        for item in ai["top_issues"]:
+            lines.append(f"- **{item.get('type', '')}** — {item.get('why_it_matters', '')}")
+        lines.append("")
+    if ai.get("recommended_actions"):
+        lines += ["### Recommended actions", ""]
+        for step in ai["recommended_actions"]:
+            lines.append(
+                f"- {step.get('action', '')} (impact: {step.get('impact', '?')}, "
+                f"effort: {step.get('effort', '?')})"
+            )
+        lines.append("")
+    if ai.get("quick_wins"):
+        lines += ["### Quick wins", ""]
+        lines += [f"- {q}" for q in ai["quick_wins"]]
+        lines.append("")
+    return lines
+
+
+def _diff_section(diff: AuditDiff, locale: Locale) -> list[str]:
+    lines = ["## Changes since previous audit", ""]
+    lines.append("| Metric | Change |")
+    lines.append("| --- | --- |")
+    lines.append(f"| Pages scanned | {diff.metrics.pages_scanned:+d} |")
+    lines.append(f"| Issues | {diff.metrics.issues:+d} |")
+    if diff.metrics.health_score is not None:
+        lines.append(f"| Health score | {diff.metrics.health_score:+.1f} |")
+    lines.append(f"| New issues | {len(diff.new)} |")
+    lines.append(f"| Resolved issues | {len(diff.resolved)} |")
+    lines.append("")
+    for title, issues in (("New", diff.new), ("Resolved", diff.resolved)):
+        if issues:
+            lines += [f"### {title}", ""]
+            lines += [
+                f"- {escape_md(issue_title(i.type, locale))} ({escape_md(i.severity)}) — "
+                f"{escape_md(i.url)}"
+                for i in issues
+            ]
+            lines.append("")
+    return lines
+
+
+def _suppressed_section(issues: list[Issue], locale: Locale) -> list[str]:
+    lines = [f"## Baselined issues ({len(issues)})", ""]
+    lines.append("These issues match the baseline and are not counted above.")
+    lines.append("")
+    lines += [f"- {escape_md(issue_title(i.type, locale))} — {escape_md(i.url)}" for i in issues]
+    lines.append("")
+    return lines
+
+
+def render_issue_markdown(issue: Issue) -> str:
+    """Render one finding as a standalone Markdown section (e.g. an issue tracker body)."""
+    lines = _issue_section(issue.type, [issue], RenderOptions())
+    lines[0] = f"### {escape_md(issue_title(issue.type))}"
+    lines[2] += f" · Severity: {escape_md(issue.severity)}"
+    return "\n".join(lines).rstrip("\n") + "\n"
+
+
+def _diagnostics_section(diagnostics: Diagnostics) -> list[str]:
+    lines = ["## Diagnostics", ""]
+    lines.append("| Check | Status | Time | Requests | Downloaded | Findings | Note |")
+    lines.append("| --- | --- | ---: | ---: | ---: | ---: | --- |")
+    for run in diagnostics.checks:
+        if run.status in ("ran", "failed"):
+            size = format_bytes(run.bytes_fetched)
+            numbers = f"{run.duration_ms:.0f} ms | {run.requests} | {size} | {run.issues}"
+        else:
+            numbers = "- | - | - | -"
+        lines.append(f"| {escape_md(run.id)} | {run.status} | {numbers} | {escape_md(run.reason)} |")
+    lines.append("")
+    return lines
+
+
+def _score_section(contributions: list[Contribution], locale: Locale, limit: int = 10) -> list[str]:
+    lines = ["## Score breakdown", ""]
+    lines.append("Points lost per issue type: weight (issue costs / pages scanned) x page factor.")
+    lines.append("")
+    lines.append("| Issue | Issues | Weight x page factor | Points |")
+    lines.append("| --- | ---: | ---: | ---: |")
+    for c in contributions[:limit]:
+        title = escape_md(issue_title(c.issue_type, locale))
+        lines.append(f"| {title} | {c.issues} | {c.formula} | -{c.points:.2f} |")
+    rest = contributions[limit:]
+    if rest:
+        issues, points = sum(c.issues for c in rest), sum(c.points for c in rest)
+        lines.append(f"| {len(rest)} other issue types | {issues} | | -{points:.2f} |")
+    top = contributions[:3]
+    lines.append("")
+    lines.append(
+        f"Fixing the top {len(top)} issue type{'s' if len(top) != 1 else ''} gains "
+        f"~{sum(c.points for c in top):.0f} points."
+    )
+    lines.append("")
+    return lines
+
+
+def _keyword_section(keywords: dict[str, Any], limit: int = 20) -> list[str]:
+    pages = keywords.get("pages") or []
+    lines = ["## Keyword coverage", ""]
+    lines.append(f"Target keywords of {len(pages)} page{'s' if len(pages) != 1 else ''}.")
+    lines.append("")
+    lines.append("| Placement | Pages with a target keyword |")
+    lines.append("| --- | ---: |")
+    for placement, (covered, present) in keywords["coverage"].items():
+        lines.append(f"| {placement.replace('_', ' ').capitalize()} | {covered}/{present} |")
+    lines.append("")
+    gaps = [p for p in pages if False in p["placements"].values()]
+    if gaps:
+        lines.append("| Page | Keywords | Missing from |")
+        lines.append("| --- | --- | --- |")
+        for page in gaps[:limit]:
+            missing = [k.replace("_", " ") for k, v in page["placements"].items() if v is False]
+            keywords_list = escape_md(", ".join(page["keywords"]))
+            lines.append(f"| {escape_md(page['url'])} | {keywords_list} | {', '.join(missing)} |")
+        if len(gaps) > limit:
+            lines.append(f"| … and {len(gaps) - limit} more pages | | |")
+        lines.append("")
+    return lines
+
+
+def render_markdown(summary: dict[str, Any], options: RenderOptions | None = None) -> str:
+    """
+    Render an audit summary (summary.json, optionally with "ai_summary") as Markdown.
+
+    Args:
+        summary: Loaded audit JSON
+        options: Rendering options
+
+    Returns:
+        Markdown document
+    """
+    options = options or RenderOptions()
+    result = migrate(summary)
+    meta = result.meta
+    locale = options.locale or result_locale(meta)
+    localize(result.issues, locale)
+    counts = count_by_severity(i.severity for i in result.issues)
+
+    lines = [f"# SEO Audit — {escape_md(result.site)}", ""]
+    lines.append("| Metric | Value |")
+    lines.append("| --- | --- |")
+    lines.append(f"| Pages scanned | {result.pages_scanned} |")
+    lines.append(f"| Issues found | {len(result.issues)} |")
+    if "health_score" in meta:
+        lines.append(f"| Health score | {meta['health_score']}/100 ({meta.get('health_grade', '?')}) |")
+    for severity in Severity:
+        lines.append(f"| {severity.value.capitalize()} | {counts[severity.value]} |")
+    if counts.get(OTHER):
+        lines.append(f"| Other | {counts[OTHER]} |")
+    performance = meta.get("performance") or {}
+    if performance.get("ttfb_ms") is not None:
+        lines.append(
+            f"| Response time | TTFB {performance['ttfb_ms']:.0f} ms, total {performance['total_ms']:.0f} ms "
+            f"(median of {performance['samples']}) |"
+        )
+    if performance.get("protocol"):
+        lines.append(f"| HTTP protocol | {describe_protocol(performance['protocol'])} |")
+    for psi in stored_results(meta):
+        if psi.field:
+            lines.append(f"| Core Web Vitals ({psi.strategy}) | {field_summary(psi)} |")
+        if psi.lab_scores:
+            scores = ", ".join(f"{name} {score}" for name, score in psi.lab_scores.items())
+            lines.append(f"| Lighthouse ({psi.strategy}) | {scores} |")
+    if meta.get("crawl_scope"):
+        lines.append(f"| Crawl scope | {escape_md(CrawlOptions(**meta['crawl_scope']).describe())} |")
+    if meta.get("timestamp"):
+        lines.append(f"| Timestamp | {escape_md(meta['timestamp'])} |")
+    lines.append("")
+
+    ai = summary.get("ai_summary")
+    if options.include_ai and ai:
+        lines += _ai_section(ai)
+    else:
+        lines += ["## Summary", "", escape_md(generate(result, locale, options.diff)), ""]
+
+    if options.diff is not None:
+        lines += _diff_section(options.diff, locale)
+
+    contributions = explain(result, options.score_model) if options.score_breakdown else []
+    if contributions:
+        lines += _score_section(contributions, locale)
+
+    if meta.get("keywords"):
+        lines += _keyword_section(meta["keywords"])
+
+    groups = _group_by_severity(result.issues)
+    for bucket in [s.value for s in Severity] + [OTHER]:
+        by_type = groups.get(bucket)
+        if not by_type:
+            continue
+        total = sum(len(v) for v in by_type.values())
+        lines += [f"## {bucket.capitalize()} ({total})", ""]
+        for issue_type in sorted(by_type, key=lambda t: (-len(by_type[t]), t)):
+            lines += _issue_section(issue_type, by_type[issue_type], options, locale)
+
+    if options.suppressed:
+        lines += _suppressed_section(options.suppressed, locale)
+
+    diagnostics = Diagnostics.from_meta(meta) if options.include_diagnostics else None
+    if diagnostics is not None:
+        lines += _diagnostics_section(diagnostics)
+
+    return "\n".join(lines).rstrip("\n") + "\n"
+
+
+def write_markdown(summary: dict[str, Any], out_path: Path, options: RenderOptions | None = None) -> Path:
+    """Render and write a Markdown report, returning the output path."""
+    out_path.parent.mkdir(parents=True, exist_ok=True)
+    out_path.write_text(render_markdown(summary, options), encoding="utf-8")
+    return out_path
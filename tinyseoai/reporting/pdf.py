@@ -1,23 +1,102 @@
+"""
+Paginated PDF report: a cover page, the metrics summary, and the issues by severity.
+
+The PDF is printed from a print-only HTML layout rather than the screen
+report: no tabs or collapsed lists, page numbers in the margin, and page
+lists that continue on the next page instead of overflowing. Work Sans is
+inlined as a data URI, and both renderers embed the fonts they use, so the
+file looks the same on machines without it.
+"""
 from __future__ import annotations
 
+import base64
+import json
+from datetime import datetime
 from pathlib import Path
+from typing import Any
+
+from pydantic import BaseModel, Field
+
+from ..audit.scope import CrawlOptions
+from ..config import get_config
+from ..data.migrate import migrate
+from ..data.narrative import generate
+from ..i18n import Locale, result_locale
+from ..store.history import HistoryStore
+from .html_report import env, localized, severity_sections
+
+_FONT = Path(__file__).parent / "fonts" / "WorkSans-Regular.woff2"
+
+
+class PdfOptions(BaseModel):
+    """Layout options for the PDF report."""
+
+    max_pages_per_issue: int | None = Field(default=None, ge=1)  # None: list every page
+    locale: Locale | None = None  # language of titles, details, and advice; None: the result's
+    include_ai: bool = True
+
+
+def _font_data() -> str | None:
+    try:
+        return base64.b64encode(_FONT.read_bytes()).decode("ascii")
+    except OSError:
+        return None
 
-from .html_report import build_html
+
+def render_print_html(summary: dict[str, Any], options: PdfOptions | None = None) -> str:
+    """
+    Render the print layout the PDF is made from.
+
+    Args:
+        summary: Loaded audit JSON (optionally with "ai_summary")
+        options: Page limit, locale, and whether to include the AI summary
+
+    Returns:
+        HTML document with inline CSS and the embedded font
+    """
+    options = options or PdfOptions()
+    cfg = get_config()
+    meta = summary.get("meta", {})
+    site = summary.get("site", "")
+    score = meta.get("health_score")
+    scope = meta.get("crawl_scope")
+    locale = options.locale or result_locale(meta)
+    issues = localized(summary.get("issues", []), locale)
+    ts = meta.get("timestamp") or datetime.utcnow().isoformat() + "Z"
+    ai = summary.get("ai_summary") if options.include_ai else None
+
+    return env().get_template("print.html").render(
+        title="SEO Audit Report",
+        lang=locale.value,
+        site=site,
+        date=ts[:10],
+        pages_scanned=summary.get("pages_scanned", 0),
+        issues=issues,
+        meta=meta,
+        scope=CrawlOptions(**scope).describe() if scope else None,
+        score=score if isinstance(score, int | float) else None,
+        grade=meta.get("health_grade", "?"),
+        severities=severity_sections(issues, options.max_pages_per_issue or len(issues) or 1, locale),
+        ai=ai,
+        narrative=None if ai and ai.get("summary") else generate(migrate(summary), locale),
+        font_data=_font_data(),
+        brand={"name": cfg.brand.name, "accent_hex": cfg.brand.accent_hex},
+    )
 
 
 # We try Playwright first; if not available, fall back to WeasyPrint if installed.
-def write_pdf(summary: dict, out_path: Path) -> Path:
-    html = build_html(summary)
+def write_pdf(summary: dict, out_path: Path, options: PdfOptions | None = None) -> Path:
+    html = render_print_html(summary, options)
     out_path.parent.mkdir(parents=True, exist_ok=True)
 
     try:
-        # Playwright path
+        # Playwright path; margins and page numbers come from the @page rules
         from playwright.sync_api import sync_playwright
         with sync_playwright() as p:
             browser = p.chromium.launch()
             page = browser.new_page()
             page.set_content(html, wait_until="load")
-            page.pdf(path=str(out_path), format="A4", print_background=True, margin={"top":"14mm","bottom":"14mm","left":"12mm","right":"12mm"})
+            page.pdf(path=str(out_path), print_background=True, prefer_css_page_size=True)
             browser.close()
         return out_path
     except Exception as e:
@@ -28,3 +107,30 @@ def write_pdf(summary: dict, out_path: Path) -> Path:
             return out_path
         except Exception as e2:
             raise RuntimeError(f"PDF generation failed. Playwright error: {e}; WeasyPrint error: {e2}") from e
+
+
+def export_pdf(
+    audit_id: int,
+    out_path: Path,
+    options: PdfOptions | None = None,
+    store: HistoryStore | None = None,
+) -> Path:
+    """
+    Write the PDF report of an audit saved in the history.
+
+    Args:
+        audit_id: History ID
+        out_path: Where to write the PDF
+        options: Layout options
+        store: History to read from; None opens the default one
+
+    Raises:
+        StoreError: If the audit does not exist or is corrupted
+    """
+    if store is None:
+        with HistoryStore() as own:
+            result = own.get(audit_id)
+    else:
+        result = store.get(audit_id)
+    return write_pdf(json.loads(result.to_json()), out_path, options)
+
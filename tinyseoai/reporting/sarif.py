@@ -0,0 +1,119 @@
+"""
+SARIF 2.1.0 export for code-scanning dashboards (e.g. GitHub code scanning).
+"""
+from __future__ import annotations
+
+import json
+from pathlib import Path
+from typing import Any
+
+from ..data.fingerprint import FINGERPRINT_VERSION, fingerprint
+from ..data.models import AuditResult, Issue
+from ..data.query import issue_category
+from ..data.recommendations import issue_title, recommendation_for
+from ..data.severity import Severity
+from ..version import package_version
+
+SARIF_VERSION = "2.1.0"
+SARIF_SCHEMA = "https://json.schemastore.org/sarif-2.1.0.json"
+TOOL_NAME = "tinyseoai"
+TOOL_URI = "https://tinyseoai.com"
+
+# SARIF result levels per severity; unknown severities become "warning"
+_LEVELS = {
+    Severity.HIGH: "error",
+    Severity.MEDIUM: "warning",
+    Severity.LOW: "note",
+    Severity.INFO: "none",
+}
+
+
+def _level(issue: Issue) -> str:
+    level = issue.level
+    return _LEVELS[level] if level else "warning"
+
+
+def _rule(issue_type: str, sample: Issue) -> dict[str, Any]:
+    rule: dict[str, Any] = {
+        "id": issue_type,
+        "name": "".join(part.capitalize() for part in issue_type.split("_")) or issue_type,
+        "shortDescription": {"text": issue_title(issue_type)},
+        "defaultConfiguration": {"level": _level(sample)},
+        "properties": {"category": issue_category(sample).value},
+    }
+    recommendation = recommendation_for(issue_type)
+    if recommendation:
+        rule["help"] = {"text": recommendation}
+    return rule
+
+
+def _message(issue: Issue) -> str:
+    parts = [issue.detail or issue_title(issue.type)]
+    recommendation = recommendation_for(issue.type)
+    if recommendation:
+        parts.append(recommendation)
+    return " — ".join(parts)
+
+
+def render_sarif(result: AuditResult) -> dict[str, Any]:
+    """
+    Convert an audit result into a SARIF 2.1.0 log.
+
+    Each issue becomes a result whose rule is the issue type and whose location
+    is the affected page URL. The tinyseoai fingerprint is included under
+    partialFingerprints so dashboards can track issues across runs.
+
+    Args:
+        result: Audit result to export
+
+    Returns:
+        SARIF log as a JSON-ready dict
+    """
+    rule_index: dict[str, int] = {}
+    rules: list[dict[str, Any]] = []
+    results: list[dict[str, Any]] = []
+
+    for issue in result.issues:
+        if issue.type not in rule_index:
+            rule_index[issue.type] = len(rules)
+            rules.append(_rule(issue.type, issue))
+
+        results.append(
+            {
+                "ruleId": issue.type,
+                "ruleIndex": rule_index[issue.type],
+                "level": _level(issue),
+                "message": {"text": _message(issue)},
+                "locations": [
+                    {"physicalLocation": {"artifactLocation": {"uri": issue.url}}}
+                ],
+                "partialFingerprints": {f"tinyseoai/v{FINGERPRINT_VERSION}": fingerprint(issue)},
+                "properties": {"severity": issue.severity},
+            }
+        )
+
+    run: dict[str, Any] = {
+        "tool": {
+            "driver": {
+                "name": TOOL_NAME,
+                "version": package_version(),
+                "informationUri": TOOL_URI,
+                "rules": rules,
+            }
+        },
+        "results": results,
+        "properties": {"site": result.site, "pagesScanned": result.pages_scanned},
+    }
+    if result.meta.get("timestamp"):
+        run["invocations"] = [
+            {"executionSuccessful": True, "endTimeUtc": result.meta["timestamp"]}
+        ]
+
+    return {"$schema": SARIF_SCHEMA, "version": SARIF_VERSION, "runs": [run]}
+
+
+def write_sarif(result: AuditResult, out_path: Path) -> Path:
+    """Write a SARIF log to a file, returning the output path."""
+    out_path.parent.mkdir(parents=True, exist_ok=True)
+    out_path.write_text(json.dumps(render_sarif(result), indent=2), encoding="utf-8")
+    return out_path
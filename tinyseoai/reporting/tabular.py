@@ -0,0 +1,143 @@
+"""
+CSV and TSV export of audit issues.
+"""
+from __future__ import annotations
+
+import csv
+import io
+from collections import defaultdict
+from pathlib import Path
+from typing import TextIO
+
+from pydantic import BaseModel, field_validator
+
+from ..data.effort import efforts
+from ..data.fingerprint import fingerprint
+from ..data.models import AuditResult, Issue
+from ..data.query import issue_category
+from ..data.recommendations import issue_title, recommendation_for
+from ..data.severity import severity_rank
+from ..data.wcag import criterion_of
+
+COLUMNS = (
+    "severity",
+    "category",
+    "wcag",
+    "type",
+    "title",
+    "page_count",
+    "effort",
+    "url",
+    "detail",
+    "fingerprint",
+    "recommendation",
+)
+
+DELIMITERS = {"comma": ",", "tab": "\t", "semicolon": ";"}
+
+BOM = "\ufeff"
+
+
+class CsvOptions(BaseModel):
+    """Options for the CSV/TSV renderers."""
+
+    explode_pages: bool = False  # one row per issue-page pair instead of per issue type
+    delimiter: str = ","  # ",", "\t" or ";" (or their names: comma, tab, semicolon)
+    bom: bool = False  # prefix a UTF-8 BOM so Excel detects the encoding
+
+    @field_validator("delimiter")
+    @classmethod
+    def _known_delimiter(cls, value: str) -> str:
+        value = DELIMITERS.get(value.lower(), value)
+        if value not in DELIMITERS.values():
+            raise ValueError(f"Unsupported delimiter {value!r}; use comma, tab, or semicolon")
+        return value
+
+
+def _sort_key(issue: Issue) -> tuple:
+    return (-severity_rank(issue.severity), issue.type, issue.url, issue.detail or "")
+
+
+def _wcag(issue: Issue) -> str:
+    found = criterion_of(issue)
+    return found.number if found else ""
+
+
+def _exploded_rows(issues: list[Issue]):
+    by_type = efforts(issues)
+    for issue in sorted(issues, key=_sort_key):
+        yield (
+            issue.severity,
+            issue_category(issue).value,
+            _wcag(issue),
+            issue.type,
+            issue_title(issue.type),
+            1,
+            by_type[issue.type].value,
+            issue.url,
+            issue.detail or "",
+            fingerprint(issue),
+            recommendation_for(issue.type) or "",
+        )
+
+
+def _grouped_rows(issues: list[Issue]):
+    by_type = efforts(issues)
+    groups: dict[tuple[str, str], list[Issue]] = defaultdict(list)
+    for issue in sorted(issues, key=_sort_key):
+        groups[(issue.severity, issue.type)].append(issue)
+
+    for (severity, issue_type), members in groups.items():
+        details = list(dict.fromkeys(i.detail for i in members if i.detail))
+        criteria = list(dict.fromkeys(c for c in map(_wcag, members) if c))
+        yield (
+            severity,
+            issue_category(members[0]).value,
+            "\n".join(criteria),
+            issue_type,
+            issue_title(issue_type),
+            len({i.url for i in members}),
+            by_type[issue_type].value,
+            "\n".join(i.url for i in members),
+            "\n".join(details),
+            "\n".join(fingerprint(i) for i in members),
+            recommendation_for(issue_type) or "",
+        )
+
+
+def write_csv(result: AuditResult, stream: TextIO, options: CsvOptions | None = None) -> None:
+    """
+    Write audit issues as CSV/TSV rows to a text stream.
+
+    Rows are written one at a time, so large results are not buffered in memory.
+    In grouped mode, multi-valued cells (WCAG criteria, pages, details, fingerprints) are
+    newline-separated and quoted.
+
+    Args:
+        result: Audit result to export
+        stream: Text stream to write to (open files with newline="")
+        options: Export options
+    """
+    options = options or CsvOptions()
+    if options.bom:
+        stream.write(BOM)
+    writer = csv.writer(stream, delimiter=options.delimiter, quoting=csv.QUOTE_MINIMAL)
+    writer.writerow(COLUMNS)
+    rows = _exploded_rows(result.issues) if options.explode_pages else _grouped_rows(result.issues)
+    for row in rows:
+        writer.writerow(row)
+
+
+def render_csv(result: AuditResult, options: CsvOptions | None = None) -> str:
+    """Render audit issues as a CSV/TSV string."""
+    buffer = io.StringIO(newline="")
+    write_csv(result, buffer, options)
+    return buffer.getvalue()
+
+
+def write_csv_file(result: AuditResult, out_path: Path, options: CsvOptions | None = None) -> Path:
+    """Write a CSV/TSV export to a file, returning the output path."""
+    out_path.parent.mkdir(parents=True, exist_ok=True)
+    with out_path.open("w", encoding="utf-8", newline="") as fh:
+        write_csv(result, fh, options)
+    return out_path
@@ -0,0 +1,236 @@
+"""
+Fixed-width text report for terminals and `cat`, optionally ANSI-coloured.
+
+The layout is the same with and without colour: escapes are added after
+padding, so columns line up either way. Markers are plain ASCII rather than
+emoji, whose terminal width varies.
+"""
+from __future__ import annotations
+
+import re
+import textwrap
+from collections import defaultdict
+from pathlib import Path
+from typing import Any
+
+from pydantic import BaseModel, Field
+from rich.cells import cell_len
+
+from ..audit.checks.protocol import describe as describe_protocol
+from ..audit.scope import CrawlOptions
+from ..data.models import AuditResult, Issue
+from ..data.narrative import generate
+from ..data.recommendations import issue_title, recommendation_for
+from ..data.severity import OTHER, Severity, count_by_severity
+from ..i18n import Locale, localize, result_locale
+
+MIN_WIDTH = 40
+_INDENT = "    "
+_LABEL_WIDTH = 16
+
+_SGR = {"red": "31", "yellow": "33", "blue": "34", "dim": "2"}
+_MARKERS = {"high": "[H]", "medium": "[M]", "low": "[L]", "info": "[I]", OTHER: "[?]"}
+_ESCAPE = re.compile(r"\x1b\[[0-9;]*m")
+
+
+class TextOptions(BaseModel):
+    """Options for the text renderer."""
+
+    max_pages_per_issue: int = Field(default=10, ge=1)
+    locale: Locale | None = None  # language of titles, details, and advice; None: the result's
+    ai: dict[str, Any] | None = None  # the "ai_summary" of summary_with_ai.json; None: a summary from the data
+
+
+def strip_ansi(text: str) -> str:
+    """Remove the escapes render_ansi adds."""
+    return _ESCAPE.sub("", text)
+
+
+class _Painter:
+    def __init__(self, color: bool):
+        self.color = color
+
+    def __call__(self, text: str, *codes: str) -> str:
+        if not self.color or not codes or not text:
+            return text
+        return f"\x1b[{';'.join(codes)}m{text}\x1b[0m"
+
+    def severity(self, text: str, bucket: str, bold: bool = False) -> str:
+        level = Severity.parse(bucket)
+        codes = [_SGR[level.color]] if level else []
+        return self(text, *(["1"] if bold else []), *codes)
+
+
+def _clip(text: str, width: int) -> str:
+    """Cut text to width terminal cells, ending in "…" when cut."""
+    if cell_len(text) <= width:
+        return text
+    out = ""
+    for ch in text:
+        if cell_len(out + ch) > width - 1:
+            break
+        out += ch
+    return out + "…"
+
+
+def _wrap(text: str, width: int, first: str = _INDENT, rest: str | None = None) -> list[str]:
+    text = " ".join(str(text).split())
+    return textwrap.wrap(
+        text,
+        width,
+        initial_indent=first,
+        subsequent_indent=rest if rest is not None else first,
+        break_on_hyphens=False,
+    ) or [first.rstrip()]
+
+
+def _heading(title: str, width: int) -> str:
+    """Title followed by a dashed rule filling the line."""
+    title = _clip(title, width - 2)
+    return f"{title} {'-' * (width - cell_len(title) - 1)}"
+
+
+def _metrics(result: AuditResult, counts: dict[str, int], paint: _Painter) -> list[str]:
+    meta = result.meta
+
+    def row(label: str, value: Any, painted: str | None = None) -> str:
+        return f"  {label:<{_LABEL_WIDTH}}{painted if painted is not None else value}"
+
+    lines = [row("Pages scanned", result.pages_scanned), row("Issues found", len(result.issues))]
+    if "health_score" in meta:
+        grade = meta.get("health_grade", "?")
+        lines.append(row("Health score", f"{meta['health_score']}/100 ({grade})"))
+    for severity in Severity:
+        count = str(counts[severity.value])
+        painted = paint.severity(count, severity.value) if counts[severity.value] else None
+        lines.append(row(severity.value.capitalize(), count, painted))
+    if counts.get(OTHER):
+        lines.append(row("Other", counts[OTHER]))
+    performance = meta.get("performance") or {}
+    if performance.get("ttfb_ms") is not None:
+        timing = f"TTFB {performance['ttfb_ms']:.0f} ms, total {performance['total_ms']:.0f} ms"
+        lines.append(row("Response time", timing))
+    if performance.get("protocol"):
+        lines.append(row("HTTP protocol", describe_protocol(performance["protocol"])))
+    if meta.get("crawl_scope"):
+        lines.append(row("Crawl scope", CrawlOptions(**meta["crawl_scope"]).describe()))
+    if meta.get("timestamp"):
+        lines.append(row("Timestamp", meta["timestamp"]))
+    return lines
+
+
+def _issue_block(
+    issue_type: str,
+    issues: list[Issue],
+    bucket: str,
+    width: int,
+    paint: _Painter,
+    options: TextOptions,
+    locale: Locale,
+) -> list[str]:
+    pages = len({i.url for i in issues})
+    count = f"{pages} page{'s' if pages != 1 else ''}"
+    marker = _MARKERS[bucket]
+    title = _clip(issue_title(issue_type, locale), width - len(marker) - len(count) - 3)
+    gap = " " * (width - len(marker) - cell_len(title) - len(count) - 1)
+    marker = paint.severity(marker, bucket, bold=True)
+    lines = [f"{marker} {paint(title, '1')}{gap}{paint(count, '2')}"]
+
+    shown = issues[: options.max_pages_per_issue]
+    for issue in shown:
+        line = f"{issue.url} — {issue.detail}" if issue.detail else issue.url
+        lines += _wrap(line, width, rest=_INDENT + "  ")
+    if len(issues) > len(shown):
+        lines.append(paint(f"{_INDENT}…and {len(issues) - len(shown)} more", "2"))
+
+    recommendation = recommendation_for(issue_type, locale)
+    if recommendation:
+        wrapped = _wrap(f"Fix: {recommendation}", width, rest=_INDENT + "     ")
+        lines += [paint(line, "2") for line in wrapped]
+    lines.append("")
+    return lines
+
+
+def _analysis(ai: dict[str, Any], width: int, paint: _Painter) -> list[str]:
+    lines = [paint(_heading("ANALYSIS", width), "1"), ""]
+    if ai.get("summary"):
+        lines += _wrap(ai["summary"], width, first="") + [""]
+    top = [
+        f"{i.get('type', '')}: {i.get('why_it_matters', '')}" for i in ai.get("top_issues") or []
+    ]
+    actions = [
+        f"{s.get('action', '')} (impact: {s.get('impact', '?')}, effort: {s.get('effort', '?')})"
+        for s in ai.get("recommended_actions") or []
+    ]
+    quick = [str(q) for q in ai.get("quick_wins") or []]
+    sections = [("Top issues", top), ("Recommended actions", actions), ("Quick wins", quick)]
+    for title, items in sections:
+        if not items:
+            continue
+        lines.append(paint(title, "1"))
+        for item in items:
+            lines += _wrap(item, width, first="  - ", rest="    ")
+        lines.append("")
+    return lines
+
+
+def render_ansi(
+    result: AuditResult, width: int = 100, color: bool = True, options: TextOptions | None = None
+) -> str:
+    """
+    Render an audit result as a fixed-width text report.
+
+    Args:
+        result: Audit result
+        width: Line width in terminal cells (at least 40)
+        color: Add ANSI colour escapes; without them the layout is identical
+        options: Page limit, locale, and the AI analysis to append (without one,
+            a summary written from the data is appended instead)
+
+    Returns:
+        The report, every line at most width cells wide once escapes are removed
+    """
+    options = options or TextOptions()
+    width = max(width, MIN_WIDTH)
+    paint = _Painter(color)
+    locale = options.locale or result_locale(result.meta)
+    ai = options.ai or {"summary": generate(result, locale)}
+    result = result.model_copy(deep=True)
+    localize(result.issues, locale)
+    counts = count_by_severity(i.severity for i in result.issues)
+
+    lines = [paint(_clip(f"SEO Audit — {result.site}", width), "1"), "=" * width, ""]
+    lines += _metrics(result, counts, paint)
+    lines.append("")
+
+    groups: dict[str, dict[str, list[Issue]]] = defaultdict(lambda: defaultdict(list))
+    for issue in result.issues:
+        level = Severity.parse(issue.severity)
+        groups[level.value if level else OTHER][issue.type].append(issue)
+    for bucket in [s.value for s in Severity] + [OTHER]:
+        by_type = groups.get(bucket)
+        if not by_type:
+            continue
+        total = sum(len(v) for v in by_type.values())
+        heading = _heading(f"{bucket.upper()} ({total})", width)
+        lines += [paint.severity(heading, bucket, bold=True), ""]
+        for issue_type in sorted(by_type, key=lambda t: (-len(by_type[t]), t)):
+            issues = by_type[issue_type]
+            lines += _issue_block(issue_type, issues, bucket, width, paint, options, locale)
+
+    lines += _analysis(ai, width, paint)
+
+    return "\n".join(lines).rstrip("\n") + "\n"
+
+
+def write_text(
+    result: AuditResult,
+    out_path: Path,
+    width: int = 100,
+    color: bool = False,
+    options: TextOptions | None = None,
+) -> Path:
+    """Render and write a text report, returning the output path."""
+    out_path.parent.mkdir(parents=True, exist_ok=True)
+    out_path.write_text(render_ansi(result, width, color, options), encoding="utf-8")
+    return out_path
@@ -0,0 +1,988 @@
+"""
+SQLite-backed history of audit results.
+
+Summary numbers live in real columns for fast listing and trends; the full
+result is stored as a zlib-compressed JSON blob. Issue fingerprints are kept
+in a column of their own, so new issues since the previous audit can be
+counted without decoding blobs. Attempts to send a report (e.g. by email)
+are recorded per audit in a deliveries table. Each row also keeps a hash of
+its result, so importing the same file twice stores it once. Comparisons of
+two sites (data.comparison) are records of their own, in a comparisons
+table; they are not audits and do not show up in list, trends, or the
+portfolio. Reachability probes (data.reachability) are plain rows in a
+probes table, small enough to record every few minutes.
+"""
+from __future__ import annotations
+
+import asyncio
+import hashlib
+import json
+import sqlite3
+import threading
+import zlib
+from collections.abc import Callable, Iterable
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Literal
+from urllib.parse import urlparse
+
+from loguru import logger
+from pydantic import BaseModel, ValidationError
+
+from ..data.comparison import SiteComparison
+from ..data.diff import diff
+from ..data.fingerprint import fingerprint
+from ..data.migrate import migrate
+from ..data.models import AuditResult, Issue
+from ..data.reachability import Probe
+from ..data.severity import Severity, count_by_severity
+from ..exceptions import StoreError
+from ..utils.io import read_json
+
+SCHEMA_VERSION = 7
+
+IMPORT_PATTERNS = ("*.json", "*.json.gz", "*.json.zst")  # what import_files finds in folders
+
+# Migration N brings a database from user_version N-1 to N
+_MIGRATIONS = {
+    1: """
+        CREATE TABLE audits (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            site TEXT NOT NULL,
+            host TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            pages_scanned INTEGER NOT NULL,
+            issues INTEGER NOT NULL,
+            blob BLOB NOT NULL
+        );
+        CREATE INDEX idx_audits_host ON audits (host, created_at);
+    """,
+    2: """
+        ALTER TABLE audits ADD COLUMN health_score REAL;
+        ALTER TABLE audits ADD COLUMN high INTEGER NOT NULL DEFAULT 0;
+        ALTER TABLE audits ADD COLUMN medium INTEGER NOT NULL DEFAULT 0;
+        ALTER TABLE audits ADD COLUMN label TEXT;
+    """,
+    3: """
+        ALTER TABLE audits ADD COLUMN fingerprints TEXT;
+    """,
+    4: """
+        CREATE TABLE deliveries (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            audit_id INTEGER NOT NULL,
+            channel TEXT NOT NULL,
+            recipients TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            error TEXT
+        );
+        CREATE INDEX idx_deliveries_audit ON deliveries (audit_id, created_at);
+    """,
+    5: """
+        ALTER TABLE audits ADD COLUMN origin TEXT;
+        ALTER TABLE audits ADD COLUMN content_hash TEXT;
+        CREATE INDEX idx_audits_content_hash ON audits (content_hash);
+    """,
+    6: """
+        CREATE TABLE comparisons (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            site_a TEXT NOT NULL,
+            site_b TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            mode TEXT NOT NULL,
+            label TEXT,
+            blob BLOB NOT NULL
+        );
+        CREATE INDEX idx_comparisons_created ON comparisons (created_at);
+    """,
+    7: """
+        CREATE TABLE probes (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            url TEXT NOT NULL,
+            host TEXT NOT NULL,
+            checked_at TEXT NOT NULL,
+            up INTEGER NOT NULL,
+            status_code INTEGER,
+            latency_ms REAL,
+            final_url TEXT,
+            tls_days_left INTEGER,
+            error TEXT
+        );
+        CREATE INDEX idx_probes_url ON probes (url, checked_at);
+        CREATE INDEX idx_probes_host ON probes (host, checked_at);
+    """,
+}
+
+
+def default_path() -> Path:
+    """History database in the user data directory."""
+    from platformdirs import user_data_dir
+
+    return Path(user_data_dir("tinyseoai")) / "history.sqlite3"
+
+
+class AuditSummary(BaseModel):
+    """One row of the history list."""
+
+    id: int
+    site: str
+    host: str
+    created_at: str
+    pages_scanned: int
+    issues: int
+    high: int
+    medium: int
+    health_score: float | None = None
+    label: str | None = None
+    origin: str | None = None  # "imported" for rows from import_files; None: recorded by an audit
+
+
+class ScorePoint(BaseModel):
+    """One point of a site's score trend."""
+
+    id: int
+    created_at: str
+    health_score: float | None
+    issues: int
+
+
+class PortfolioEntry(BaseModel):
+    """The latest audit of one host, compared with the one before."""
+
+    host: str
+    site: str
+    audit_id: int
+    last_audited: str
+    audits: int
+    health_score: float | None = None
+    score_delta: float | None = None  # only when both audits carry a score
+    high: int
+    new_high: int | None = None  # high issues new since the previous audit; None: first audit
+    label: str | None = None
+
+
+class Delivery(BaseModel):
+    """One attempt to send an audit's report somewhere."""
+
+    id: int
+    audit_id: int
+    channel: str  # e.g. "email"
+    recipients: list[str]
+    created_at: str
+    error: str | None = None  # None: delivered
+
+    @property
+    def ok(self) -> bool:
+        return self.error is None
+
+
+class ComparisonSummary(BaseModel):
+    """One row of the comparison list."""
+
+    id: int
+    site_a: str
+    site_b: str
+    created_at: str
+    mode: str
+    label: str | None = None
+
+
+class ImportOutcome(BaseModel):
+    """What happened to one file passed to HistoryStore.import_files."""
+
+    path: str
+    status: Literal["imported", "duplicate", "failed"]
+    audit_id: int | None = None  # the new row, or the one it duplicates
+    reason: str | None = None  # why it failed
+
+
+class MergeResult(BaseModel):
+    """What HistoryStore.merge_from took from the other database."""
+
+    audits: int = 0
+    duplicates: int = 0  # audits already here (same content hash), not copied
+    deliveries: int = 0
+    comparisons: int = 0
+    probes: int = 0
+
+
+# Called with (files done, files in total) after each file
+ImportProgress = Callable[[int, int], None]
+
+
+class PortfolioFilter(BaseModel):
+    """Filter and page for HistoryStore.portfolio."""
+
+    host: str | None = None
+    limit: int | None = None
+    offset: int = 0
+
+
+class ProbeFilter(BaseModel):
+    """Filter for HistoryStore.probes."""
+
+    url: str | None = None
+    host: str | None = None
+    since: str | None = None  # ISO timestamp, inclusive
+    limit: int | None = None  # the newest samples only
+
+
+class HistoryFilter(BaseModel):
+    """Filter for HistoryStore.list."""
+
+    host: str | None = None
+    since: str | None = None  # ISO timestamp, inclusive
+    until: str | None = None  # ISO timestamp, exclusive
+    limit: int | None = None
+
+
+def _host(site: str) -> str:
+    return (urlparse(site).netloc or site).lower()
+
+
+def _fingerprints(issues: list[Issue]) -> str:
+    """JSON object of fingerprint to severity, the highest when fingerprints collide."""
+    by_fingerprint: dict[str, str] = {}
+    for issue in issues:
+        fp = fingerprint(issue)
+        level = Severity.parse(issue.severity)
+        if fp not in by_fingerprint or level is Severity.HIGH:
+            by_fingerprint[fp] = level.value if level else str(issue.severity)
+    return json.dumps(by_fingerprint, sort_keys=True)
+
+
+def _content_hash(result: AuditResult) -> str:
+    """SHA-256 of the result in the current format, so a legacy file and its upgrade match."""
+    return hashlib.sha256(result.model_dump_json().encode("utf-8")).hexdigest()
+
+
+def _import_paths(paths: Iterable[Path | str]) -> list[Path]:
+    """Files to import: each file as given, and the result files under each folder, sorted."""
+    files = []
+    for path in map(Path, paths):
+        if not path.is_dir():
+            files.append(path)
+            continue
+        found = (p for pattern in IMPORT_PATTERNS for p in path.rglob(pattern))
+        files.extend(sorted(p for p in found if p.is_file()))
+    return files
+
+
+def _probe(row: sqlite3.Row) -> Probe:
+    values = {k: row[k] for k in row.keys() if k not in ("id", "host")}
+    return Probe(**{**values, "up": bool(values["up"])})
+
+
+def _new_high(latest: str, previous: str) -> int:
+    """High issues in the latest fingerprints that the previous audit did not have."""
+    before = json.loads(previous)
+    return sum(
+        1 for fp, severity in json.loads(latest).items()
+        if severity == Severity.HIGH.value and fp not in before
+    )
+
+
+def _open_source(path: Path | str) -> sqlite3.Connection:
+    """Open another history database for reading, refusing one from a newer version."""
+    conn = None
+    try:
+        conn = sqlite3.connect(f"{Path(path).resolve().as_uri()}?mode=ro", uri=True)
+        version = conn.execute("PRAGMA user_version").fetchone()[0]
+        conn.execute("SELECT id FROM audits LIMIT 1")
+    except sqlite3.DatabaseError as e:
+        if conn is not None:
+            conn.close()
+        raise StoreError(f"{path} is not a history database: {e}") from e
+    if version > SCHEMA_VERSION:
+        conn.close()
+        raise StoreError(
+            f"History database version {version} is newer than supported ({SCHEMA_VERSION}); "
+            "upgrade tinyseoai to use it"
+        )
+    return conn
+
+
+class HistoryStore:
+    """
+    Audit history in a single SQLite file.
+
+    Safe to share between threads: one connection in WAL mode, with access
+    serialized by a lock; other processes wait up to 30s for the write lock.
+    Use AsyncHistoryStore from async code.
+    """
+
+    def __init__(self, path: Path | str | None = None):
+        self.path = Path(path) if path is not None else default_path()
+        if str(self.path) != ":memory:":
+            self.path.parent.mkdir(parents=True, exist_ok=True)
+        self._lock = threading.Lock()
+        try:
+            self._conn = sqlite3.connect(str(self.path), check_same_thread=False, timeout=30)
+            self._conn.row_factory = sqlite3.Row
+            self._conn.execute("PRAGMA journal_mode=WAL")
+            self._migrate()
+        except sqlite3.DatabaseError as e:
+            raise StoreError(f"Cannot open history database {self.path}: {e}") from e
+
+    def __enter__(self) -> HistoryStore:
+        return self
+
+    def __exit__(self, *exc) -> None:
+        self.close()
+
+    def close(self) -> None:
+        """Close the database connection."""
+        self._conn.close()
+
+    @property
+    def schema_version(self) -> int:
+        return self._conn.execute("PRAGMA user_version").fetchone()[0]
+
+    def _migrate(self) -> None:
+        with self._lock:
+            current = self.schema_version
+            if current > SCHEMA_VERSION:
+                raise StoreError(
+                    f"History database version {current} is newer than supported ({SCHEMA_VERSION})"
+                )
+            for version in range(current + 1, SCHEMA_VERSION + 1):
+                logger.debug(f"Migrating history database to version {version}")
+                with self._conn:
+                    for statement in filter(str.strip, _MIGRATIONS[version].split(";")):
+                        self._conn.execute(statement)
+                    self._conn.execute(f"PRAGMA user_version = {version}")
+                if version == 2:
+                    self._backfill_v2()
+                if version == 3:
+                    self._backfill_v3()
+                if version == 5:
+                    self._backfill_v5()
+
+    def _backfill_v2(self) -> None:
+        """Fill the v2 summary columns for rows written by v1."""
+        rows = self._conn.execute("SELECT id, blob FROM audits").fetchall()
+        with self._conn:
+            for row in rows:
+                result = self._decode(row["id"], row["blob"])
+                if result is None:
+                    continue
+                counts = count_by_severity(i.severity for i in result.issues)
+                self._conn.execute(
+                    "UPDATE audits SET health_score = ?, high = ?, medium = ? WHERE id = ?",
+                    (result.meta.get("health_score"), counts["high"], counts["medium"], row["id"]),
+                )
+
+    def _backfill_v3(self) -> None:
+        """Fill the fingerprints column; rows that cannot be decoded stay NULL."""
+        rows = self._conn.execute("SELECT id, blob FROM audits").fetchall()
+        with self._conn:
+            for row in rows:
+                result = self._decode(row["id"], row["blob"])
+                if result is None:
+                    continue
+                self._conn.execute(
+                    "UPDATE audits SET fingerprints = ? WHERE id = ?",
+                    (_fingerprints(result.issues), row["id"]),
+                )
+
+    def _backfill_v5(self) -> None:
+        """Fill the content hashes; rows that cannot be decoded stay NULL."""
+        rows = self._conn.execute("SELECT id, blob FROM audits").fetchall()
+        with self._conn:
+            for row in rows:
+                result = self._decode(row["id"], row["blob"])
+                if result is None:
+                    continue
+                self._conn.execute(
+                    "UPDATE audits SET content_hash = ? WHERE id = ?", (_content_hash(result), row["id"])
+                )
+
+    @staticmethod
+    def _decode(audit_id: int, blob: bytes) -> AuditResult | None:
+        try:
+            return migrate(json.loads(zlib.decompress(blob)))
+        except (zlib.error, ValueError) as e:
+            logger.warning(f"Skipping corrupted history entry {audit_id}: {e}")
+            return None
+
+    def insert(
+        self,
+        result: AuditResult,
+        label: str | None = None,
+        origin: str | None = None,
+        created_at: str | None = None,
+    ) -> int:
+        """
+        Store an audit result.
+
+        Args:
+            result: Audit result to store
+            label: Optional free-text label (e.g. "after redesign")
+            origin: Where the row came from (e.g. "imported"); None for audits run here
+            created_at: When the audit ran, if the result has no meta.timestamp; default now
+
+        Returns:
+            ID of the new history entry
+        """
+        counts = count_by_severity(i.severity for i in result.issues)
+        created_at = result.meta.get("timestamp") or created_at or datetime.now(timezone.utc).isoformat()
+        blob = zlib.compress(result.model_dump_json().encode("utf-8"))
+        with self._lock, self._conn:
+            cursor = self._conn.execute(
+                """
+                INSERT INTO audits
+                    (site, host, created_at, pages_scanned, issues, blob, health_score, high, medium, label,
+                     fingerprints, origin, content_hash)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                """,
+                (
+                    result.site,
+                    _host(result.site),
+                    created_at,
+                    result.pages_scanned,
+                    len(result.issues),
+                    blob,
+                    result.meta.get("health_score"),
+                    counts["high"],
+                    counts["medium"],
+                    label,
+                    _fingerprints(result.issues),
+                    origin,
+                    _content_hash(result),
+                ),
+            )
+            return cursor.lastrowid
+
+    def import_files(
+        self,
+        paths: Iterable[Path | str],
+        label: str | None = None,
+        on_progress: ImportProgress | None = None,
+    ) -> list[ImportOutcome]:
+        """
+        Import audit JSON files written by the CLI, of any format version.
+
+        Each file is upgraded like any other saved result and stored with the
+        "imported" origin. The date comes from meta.timestamp, or the file's
+        modification time when the result has none. A file whose result is
+        already in the history (same content hash) is reported as a duplicate
+        and not stored again; a file that cannot be read fails on its own
+        without stopping the batch. Files may be gzip or zstd compressed,
+        told apart by their content rather than their name.
+
+        Args:
+            paths: Result files, or folders to search for *.json, *.json.gz, and *.json.zst
+            label: Label for every imported row
+            on_progress: Called after each file with (done, total)
+
+        Returns:
+            One outcome per file, in order
+        """
+        files = _import_paths(paths)
+        outcomes = []
+        for done, path in enumerate(files, start=1):
+            outcomes.append(self._import_file(path, label))
+            if on_progress is not None:
+                on_progress(done, len(files))
+        imported = sum(1 for outcome in outcomes if outcome.status == "imported")
+        logger.info(f"Imported {imported} of {len(files)} audit file(s)")
+        return outcomes
+
+    def _import_file(self, path: Path, label: str | None) -> ImportOutcome:
+        try:
+            result = migrate(read_json(path))
+            mtime = datetime.fromtimestamp(path.stat().st_mtime, timezone.utc).isoformat()
+        except OSError as e:
+            return ImportOutcome(path=str(path), status="failed", reason=e.strerror or str(e))
+        except ValidationError as e:
+            error = e.errors()[0]
+            field = ".".join(map(str, error["loc"])) or "result"
+            reason = f"Not an audit result: {field}: {error['msg']}"
+            return ImportOutcome(path=str(path), status="failed", reason=reason)
+        except ValueError as e:  # bad JSON, a newer format, or not an object
+            return ImportOutcome(path=str(path), status="failed", reason=str(e))
+
+        with self._lock:
+            row = self._conn.execute(
+                "SELECT id FROM audits WHERE content_hash = ? ORDER BY id LIMIT 1", (_content_hash(result),)
+            ).fetchone()
+        if row is not None:
+            return ImportOutcome(path=str(path), status="duplicate", audit_id=row["id"])
+        audit_id = self.insert(result, label=label, origin="imported", created_at=mtime)
+        return ImportOutcome(path=str(path), status="imported", audit_id=audit_id)
+
+    def backup_to(self, path: Path | str) -> Path:
+        """
+        Copy the database to path with SQLite's backup API.
+
+        Unlike copying the file, this gives a consistent snapshot of a live
+        database, including writes still in the WAL.
+        """
+        path = Path(path)
+        path.parent.mkdir(parents=True, exist_ok=True)
+        target = sqlite3.connect(str(path))
+        try:
+            with self._lock:
+                self._conn.backup(target)
+        finally:
+            target.close()
+        return path
+
+    def replace_from(self, path: Path | str) -> None:
+        """
+        Replace every row with the contents of the database at path.
+
+        An older database is migrated once copied in.
+
+        Raises:
+            StoreError: If path is not a history database or is from a newer version
+        """
+        source = _open_source(path)
+        try:
+            with self._lock:
+                source.backup(self._conn)
+        finally:
+            source.close()
+        self._migrate()
+
+    def merge_from(self, path: Path | str) -> MergeResult:
+        """
+        Add the audits, deliveries, comparisons, and probes of the database at path.
+
+        Audits already here (same content hash) are skipped with their
+        deliveries; a comparison of the same two sites made at the same time
+        is too, as is a probe of the same URL at the same time. Copied rows get new IDs. An older database at path is
+        upgraded in place first.
+
+        Raises:
+            StoreError: If path is not a history database or is from a newer version
+        """
+        _open_source(path).close()
+        merged = MergeResult()
+        with HistoryStore(path) as other:  # brings an older database up to this schema
+            audits = other._conn.execute("SELECT * FROM audits ORDER BY id").fetchall()
+            deliveries = other._conn.execute("SELECT * FROM deliveries ORDER BY id").fetchall()
+            comparisons = other._conn.execute("SELECT * FROM comparisons ORDER BY id").fetchall()
+            probes = other._conn.execute("SELECT * FROM probes ORDER BY id").fetchall()
+        new_ids: dict[int, int] = {}
+        with self._lock, self._conn:
+            for row in audits:
+                if row["content_hash"] is not None and self._conn.execute(
+                    "SELECT 1 FROM audits WHERE content_hash = ?", (row["content_hash"],)
+                ).fetchone():
+                    merged.duplicates += 1
+                    continue
+                columns = [c for c in row.keys() if c != "id"]
+                cursor = self._conn.execute(
+                    f"INSERT INTO audits ({', '.join(columns)}) VALUES ({', '.join('?' * len(columns))})",
+                    [row[c] for c in columns],
+                )
+                new_ids[row["id"]] = cursor.lastrowid
+                merged.audits += 1
+            for row in deliveries:
+                if row["audit_id"] not in new_ids:
+                    continue
+                self._conn.execute(
+                    "INSERT INTO deliveries (audit_id, channel, recipients, created_at, error) "
+                    "VALUES (?, ?, ?, ?, ?)",
+                    (new_ids[row["audit_id"]], row["channel"], row["recipients"], row["created_at"], row["error"]),
+                )
+                merged.deliveries += 1
+            for row in comparisons:
+                if self._conn.execute(
+                    "SELECT 1 FROM comparisons WHERE site_a = ? AND site_b = ? AND created_at = ?",
+                    (row["site_a"], row["site_b"], row["created_at"]),
+                ).fetchone():
+                    continue
+                self._conn.execute(
+                    "INSERT INTO comparisons (site_a, site_b, created_at, mode, label, blob) "
+                    "VALUES (?, ?, ?, ?, ?, ?)",
+                    (row["site_a"], row["site_b"], row["created_at"], row["mode"], row["label"], row["blob"]),
+                )
+                merged.comparisons += 1
+            for row in probes:
+                if self._conn.execute(
+                    "SELECT 1 FROM probes WHERE url = ? AND checked_at = ?", (row["url"], row["checked_at"])
+                ).fetchone():
+                    continue
+                columns = [c for c in row.keys() if c != "id"]
+                self._conn.execute(
+                    f"INSERT INTO probes ({', '.join(columns)}) VALUES ({', '.join('?' * len(columns))})",
+                    [row[c] for c in columns],
+                )
+                merged.probes += 1
+        logger.info(
+            f"Merged {merged.audits} audit(s), {merged.comparisons} comparison(s), and {merged.probes} probe(s) "
+            f"({merged.duplicates} duplicate(s) skipped)"
+        )
+        return merged
+
+    def list(self, filter: HistoryFilter | None = None) -> list[AuditSummary]:
+        """List stored audits, newest first."""
+        filter = filter or HistoryFilter()
+        clauses, params = [], []
+        if filter.host:
+            clauses.append("host = ?")
+            params.append(_host(filter.host) if "://" in filter.host else filter.host.lower())
+        if filter.since:
+            clauses.append("created_at >= ?")
+            params.append(filter.since)
+        if filter.until:
+            clauses.append("created_at < ?")
+            params.append(filter.until)
+        sql = (
+            "SELECT id, site, host, created_at, pages_scanned, issues, high, medium, health_score, label, "
+            "origin FROM audits"
+        )
+        if clauses:
+            sql += " WHERE " + " AND ".join(clauses)
+        sql += " ORDER BY created_at DESC, id DESC"
+        if filter.limit:
+            sql += " LIMIT ?"
+            params.append(filter.limit)
+        with self._lock:
+            rows = self._conn.execute(sql, params).fetchall()
+        return [AuditSummary(**dict(row)) for row in rows]
+
+    def get(self, audit_id: int) -> AuditResult:
+        """
+        Load a stored audit result.
+
+        Raises:
+            StoreError: If the entry does not exist or its data is corrupted
+        """
+        with self._lock:
+            row = self._conn.execute("SELECT blob FROM audits WHERE id = ?", (audit_id,)).fetchone()
+        if row is None:
+            raise StoreError(f"No audit with id {audit_id}")
+        result = self._decode(audit_id, row["blob"])
+        if result is None:
+            raise StoreError(f"Audit {audit_id} is corrupted")
+        return result
+
+    def results(self, filter: HistoryFilter | None = None) -> list[tuple[int, AuditResult]]:
+        """Load full results for a filter, skipping corrupted entries with a warning."""
+        loaded = []
+        for summary in self.list(filter):
+            with self._lock:
+                row = self._conn.execute("SELECT blob FROM audits WHERE id = ?", (summary.id,)).fetchone()
+            result = self._decode(summary.id, row["blob"])
+            if result is not None:
+                loaded.append((summary.id, result))
+        return loaded
+
+    def delete(self, audit_id: int) -> bool:
+        """Delete an entry and its deliveries; returns False if it did not exist."""
+        with self._lock, self._conn:
+            cursor = self._conn.execute("DELETE FROM audits WHERE id = ?", (audit_id,))
+            self._conn.execute("DELETE FROM deliveries WHERE audit_id = ?", (audit_id,))
+            return cursor.rowcount > 0
+
+    def record_delivery(
+        self, audit_id: int, channel: str, recipients: list[str], error: str | None = None
+    ) -> Delivery:
+        """
+        Record an attempt to deliver an audit's report.
+
+        Args:
+            audit_id: History ID of the audit
+            channel: How it was sent (e.g. "email")
+            recipients: Who it was sent to
+            error: Why it failed; None when it was delivered
+
+        Raises:
+            StoreError: If the audit does not exist
+        """
+        created_at = datetime.now(timezone.utc).isoformat()
+        with self._lock, self._conn:
+            if self._conn.execute("SELECT 1 FROM audits WHERE id = ?", (audit_id,)).fetchone() is None:
+                raise StoreError(f"No audit with id {audit_id}")
+            cursor = self._conn.execute(
+                "INSERT INTO deliveries (audit_id, channel, recipients, created_at, error) VALUES (?, ?, ?, ?, ?)",
+                (audit_id, channel, json.dumps(recipients), created_at, error),
+            )
+        return Delivery(
+            id=cursor.lastrowid,
+            audit_id=audit_id,
+            channel=channel,
+            recipients=recipients,
+            created_at=created_at,
+            error=error,
+        )
+
+    def deliveries(self, audit_id: int) -> list[Delivery]:
+        """Delivery attempts of an audit, oldest first."""
+        with self._lock:
+            rows = self._conn.execute(
+                "SELECT id, audit_id, channel, recipients, created_at, error FROM deliveries "
+                "WHERE audit_id = ? ORDER BY created_at, id",
+                (audit_id,),
+            ).fetchall()
+        return [Delivery(**{**dict(row), "recipients": json.loads(row["recipients"])}) for row in rows]
+
+    def insert_comparison(self, comparison: SiteComparison, label: str | None = None) -> int:
+        """
+        Store a comparison of two sites.
+
+        Args:
+            comparison: Result of compare_sites
+            label: Optional free-text label (e.g. "pitch for Acme")
+
+        Returns:
+            ID of the new comparison
+        """
+        blob = zlib.compress(comparison.model_dump_json().encode("utf-8"))
+        with self._lock, self._conn:
+            cursor = self._conn.execute(
+                "INSERT INTO comparisons (site_a, site_b, created_at, mode, label, blob) "
+                "VALUES (?, ?, ?, ?, ?, ?)",
+                (
+                    comparison.a.site,
+                    comparison.b.site,
+                    comparison.created_at,
+                    comparison.mode,
+                    label,
+                    blob,
+                ),
+            )
+            return cursor.lastrowid
+
+    def comparisons(self, limit: int | None = None) -> list[ComparisonSummary]:
+        """List stored comparisons, newest first."""
+        sql = (
+            "SELECT id, site_a, site_b, created_at, mode, label FROM comparisons "
+            "ORDER BY created_at DESC, id DESC"
+        )
+        params: list[int] = []
+        if limit:
+            sql += " LIMIT ?"
+            params.append(limit)
+        with self._lock:
+            rows = self._conn.execute(sql, params).fetchall()
+        return [ComparisonSummary(**dict(row)) for row in rows]
+
+    def get_comparison(self, comparison_id: int) -> SiteComparison:
+        """
+        Load a stored comparison.
+
+        Raises:
+            StoreError: If the comparison does not exist or its data is corrupted
+        """
+        with self._lock:
+            row = self._conn.execute(
+                "SELECT blob FROM comparisons WHERE id = ?", (comparison_id,)
+            ).fetchone()
+        if row is None:
+            raise StoreError(f"No comparison with id {comparison_id}")
+        try:
+            return SiteComparison.model_validate_json(zlib.decompress(row["blob"]))
+        except (zlib.error, ValueError) as e:
+            raise StoreError(f"Comparison {comparison_id} is corrupted: {e}") from e
+
+    def delete_comparison(self, comparison_id: int) -> bool:
+        """Delete a comparison; returns False if it did not exist."""
+        with self._lock, self._conn:
+            cursor = self._conn.execute("DELETE FROM comparisons WHERE id = ?", (comparison_id,))
+            return cursor.rowcount > 0
+
+    def insert_probes(self, probes: Iterable[Probe]) -> int:
+        """Store reachability samples; returns how many were stored."""
+        rows = [
+            (
+                p.url,
+                _host(p.url),
+                p.checked_at,
+                int(p.up),
+                p.status_code,
+                p.latency_ms,
+                p.final_url,
+                p.tls_days_left,
+                p.error,
+            )
+            for p in probes
+        ]
+        with self._lock, self._conn:
+            self._conn.executemany(
+                "INSERT INTO probes (url, host, checked_at, up, status_code, latency_ms, final_url, "
+                "tls_days_left, error) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                rows,
+            )
+        return len(rows)
+
+    def probes(self, filter: ProbeFilter | None = None) -> list[Probe]:
+        """Reachability samples, oldest first, for charting."""
+        filter = filter or ProbeFilter()
+        clauses, params = [], []
+        if filter.url:
+            clauses.append("url = ?")
+            params.append(filter.url)
+        if filter.host:
+            clauses.append("host = ?")
+            params.append(_host(filter.host) if "://" in filter.host else filter.host.lower())
+        if filter.since:
+            clauses.append("checked_at >= ?")
+            params.append(filter.since)
+        sql = "SELECT * FROM probes"
+        if clauses:
+            sql += " WHERE " + " AND ".join(clauses)
+        sql += " ORDER BY checked_at DESC, id DESC"
+        if filter.limit:
+            sql += " LIMIT ?"
+            params.append(filter.limit)
+        with self._lock:
+            rows = self._conn.execute(sql, params).fetchall()
+        return [_probe(row) for row in reversed(rows)]
+
+    def latest_probes(self, urls: Iterable[str] | None = None) -> dict[str, Probe]:
+        """The newest sample of each URL (of urls, when given), to detect transitions against."""
+        with self._lock:
+            rows = self._conn.execute(
+                "SELECT * FROM probes p WHERE id = ("
+                "SELECT id FROM probes WHERE url = p.url ORDER BY checked_at DESC, id DESC LIMIT 1)"
+            ).fetchall()
+        latest = {row["url"]: _probe(row) for row in rows}
+        if urls is not None:
+            wanted = set(urls)
+            latest = {url: probe for url, probe in latest.items() if url in wanted}
+        return latest
+
+    def trend(self, host: str) -> list[ScorePoint]:
+        """Health score over time for a host, oldest first."""
+        host = _host(host) if "://" in host else host.lower()
+        with self._lock:
+            rows = self._conn.execute(
+                "SELECT id, created_at, health_score, issues FROM audits WHERE host = ? "
+                "ORDER BY created_at, id",
+                (host,),
+            ).fetchall()
+        return [ScorePoint(**dict(row)) for row in rows]
+
+    def portfolio(self, filter: PortfolioFilter | None = None) -> list[PortfolioEntry]:
+        """
+        The latest audit of every host, worst first.
+
+        Hosts are ordered by health score (unscored last), then by high
+        issues. Score deltas and new high issues come from the summary and
+        fingerprint columns of the latest two audits; full results are only
+        decoded for rows saved before fingerprints were stored.
+
+        Args:
+            filter: Only one host, and which page of hosts to return
+
+        Returns:
+            One entry per host
+        """
+        filter = filter or PortfolioFilter()
+        where, params = "", []
+        if filter.host:
+            where = "WHERE host = ?"
+            params.append(_host(filter.host) if "://" in filter.host else filter.host.lower())
+        sql = f"""
+            WITH ranked AS (
+                SELECT id, site, host, created_at, health_score, high, label, fingerprints,
+                       ROW_NUMBER() OVER (PARTITION BY host ORDER BY created_at DESC, id DESC) AS rank,
+                       COUNT(*) OVER (PARTITION BY host) AS audits
+                FROM audits {where}
+            )
+            SELECT latest.id, latest.site, latest.host, latest.created_at, latest.health_score,
+                   latest.high, latest.label, latest.fingerprints, latest.audits,
+                   previous.id AS previous_id, previous.health_score AS previous_score,
+                   previous.fingerprints AS previous_fingerprints
+            FROM ranked AS latest
+            LEFT JOIN ranked AS previous ON previous.host = latest.host AND previous.rank = 2
+            WHERE latest.rank = 1
+            ORDER BY latest.health_score IS NULL, latest.health_score, latest.high DESC, latest.host
+            LIMIT ? OFFSET ?
+        """
+        params += [filter.limit if filter.limit else -1, filter.offset]
+        with self._lock:
+            rows = self._conn.execute(sql, params).fetchall()
+
+        entries = []
+        for row in rows:
+            score, previous_score = row["health_score"], row["previous_score"]
+            entries.append(
+                PortfolioEntry(
+                    host=row["host"],
+                    site=row["site"],
+                    audit_id=row["id"],
+                    last_audited=row["created_at"],
+                    audits=row["audits"],
+                    health_score=score,
+                    score_delta=round(score - previous_score, 1)
+                    if score is not None and previous_score is not None
+                    else None,
+                    high=row["high"],
+                    new_high=self._new_high(row),
+                    label=row["label"],
+                )
+            )
+        return entries
+
+    def _new_high(self, row: sqlite3.Row) -> int | None:
+        if row["previous_id"] is None:
+            return None
+        if row["fingerprints"] is not None and row["previous_fingerprints"] is not None:
+            return _new_high(row["fingerprints"], row["previous_fingerprints"])
+        try:
+            changes = diff(self.get(row["previous_id"]), self.get(row["id"]))
+        except StoreError as e:
+            logger.warning(f"Cannot compare the latest audits of {row['host']}: {e}")
+            return None
+        return changes.summary.new["high"]
+
+
+class AsyncHistoryStore:
+    """Async wrapper that runs HistoryStore calls in a worker thread."""
+
+    def __init__(self, store: HistoryStore):
+        self.store = store
+
+    async def insert(self, result: AuditResult, label: str | None = None) -> int:
+        return await asyncio.to_thread(self.store.insert, result, label)
+
+    async def list(self, filter: HistoryFilter | None = None) -> list[AuditSummary]:
+        return await asyncio.to_thread(self.store.list, filter)
+
+    async def import_files(
+        self,
+        paths: Iterable[Path | str],
+        label: str | None = None,
+        on_progress: ImportProgress | None = None,
+    ) -> list[ImportOutcome]:
+        return await asyncio.to_thread(self.store.import_files, list(paths), label, on_progress)
+
+    async def get(self, audit_id: int) -> AuditResult:
+        return await asyncio.to_thread(self.store.get, audit_id)
+
+    async def backup_to(self, path: Path | str) -> Path:
+        return await asyncio.to_thread(self.store.backup_to, path)
+
+    async def merge_from(self, path: Path | str) -> MergeResult:
+        return await asyncio.to_thread(self.store.merge_from, path)
+
+    async def delete(self, audit_id: int) -> bool:
+        return await asyncio.to_thread(self.store.delete, audit_id)
+
+    async def trend(self, host: str) -> list[ScorePoint]:
+        return await asyncio.to_thread(self.store.trend, host)
+
+    async def portfolio(self, filter: PortfolioFilter | None = None) -> list[PortfolioEntry]:
+        return await asyncio.to_thread(self.store.portfolio, filter)
+
+    async def insert_comparison(self, comparison: SiteComparison, label: str | None = None) -> int:
+        return await asyncio.to_thread(self.store.insert_comparison, comparison, label)
+
+    async def comparisons(self, limit: int | None = None) -> list[ComparisonSummary]:
+        return await asyncio.to_thread(self.store.comparisons, limit)
+
+    async def get_comparison(self, comparison_id: int) -> SiteComparison:
+        return await asyncio.to_thread(self.store.get_comparison, comparison_id)
+
+    async def insert_probes(self, probes: Iterable[Probe]) -> int:
+        return await asyncio.to_thread(self.store.insert_probes, list(probes))
+
+    async def probes(self, filter: ProbeFilter | None = None) -> list[Probe]:
+        return await asyncio.to_thread(self.store.probes, filter)
+
+    async def latest_probes(self, urls: Iterable[str] | None = None) -> dict[str, Probe]:
+        return await asyncio.to_thread(self.store.latest_probes, urls)
@@ -0,0 +1,229 @@
+"""
+Content-addressed archive of fetched pages, kept as evidence for findings.
+
+Each page a finding points at is stored once, as objects/<ab>/<sha256>.html
+(the body as fetched) with <sha256>.headers.json next to it (URL, status, and
+response headers); Issue.snapshot holds the hash. A manifest per audit,
+audits/<audit id>.json, lists the objects it uses. Retention keeps the newest
+audits of each site, the size cap then evicts whole audits oldest first, and
+objects no remaining manifest lists are deleted.
+"""
+from __future__ import annotations
+
+import hashlib
+import json
+from collections import defaultdict
+from collections.abc import Iterable
+from datetime import datetime, timezone
+from pathlib import Path
+from typing import Any
+from urllib.parse import urlparse
+
+from loguru import logger
+from pydantic import BaseModel, Field
+
+from ..data.models import AuditResult, Issue
+
+DEFAULT_MAX_BYTES = 200 * 1024 * 1024
+
+
+class SnapshotOptions(BaseModel):
+    """Where snapshots go and how much of them to keep."""
+
+    directory: Path
+    max_bytes: int = Field(DEFAULT_MAX_BYTES, gt=0)  # whole archive, all audits
+    keep_audits: int = Field(5, ge=1)  # newest audits kept per site
+
+
+class Manifest(BaseModel):
+    """The snapshots of one audit."""
+
+    audit_id: str
+    site: str
+    host: str
+    created_at: str
+    objects: dict[str, int] = Field(default_factory=dict)  # hash -> bytes on disk
+
+
+def content_hash(html: str) -> str:
+    """Address of a page body in the archive."""
+    return hashlib.sha256(html.encode("utf-8")).hexdigest()
+
+
+class SnapshotArchive:
+    """A snapshot directory: objects/ with page bodies and headers, audits/ with manifests."""
+
+    def __init__(self, options: SnapshotOptions):
+        self.options = options
+        self.root = Path(options.directory)
+
+    def object_path(self, digest: str) -> Path:
+        """Where the body with this hash is (or would be) stored."""
+        return self.root / "objects" / digest[:2] / f"{digest}.html"
+
+    def headers_path(self, digest: str) -> Path:
+        return self.object_path(digest).with_suffix(".headers.json")
+
+    def path_of(self, digest: str | None) -> Path | None:
+        """The stored body for a hash, or None when it was never stored or has been evicted."""
+        if not digest:
+            return None
+        path = self.object_path(digest)
+        return path if path.is_file() else None
+
+    def _size(self, digest: str) -> int:
+        files = (self.object_path(digest), self.headers_path(digest))
+        return sum(p.stat().st_size for p in files if p.is_file())
+
+    def put(self, url: str, status: int, headers: dict[str, str], html: str) -> tuple[str, int]:
+        """
+        Store a page unless the same body is stored already.
+
+        Returns:
+            The body's hash and the bytes its files take
+        """
+        digest = content_hash(html)
+        path = self.object_path(digest)
+        if not path.is_file():
+            path.parent.mkdir(parents=True, exist_ok=True)
+            path.write_text(html, encoding="utf-8")
+            record = {"url": url, "status": status, "headers": dict(headers)}
+            self.headers_path(digest).write_text(json.dumps(record, indent=2), encoding="utf-8")
+        return digest, self._size(digest)
+
+    def save(self, result: AuditResult, pages: Iterable[Any]) -> Manifest:
+        """
+        Snapshot the pages the result's issues point at, in place.
+
+        Sets Issue.snapshot on issues whose page was fetched with HTML and
+        records the archive in meta["snapshots"]. Pages that would take the
+        audit past the size cap are not stored. Older audits are then pruned.
+
+        Args:
+            result: Audit whose issues to link to snapshots
+            pages: Crawled pages (anything with url, status, headers, and html)
+        """
+        by_url = {page.url: page for page in pages if getattr(page, "html", "")}
+        host = urlparse(result.site).netloc
+        created = datetime.now(timezone.utc)
+        manifest = Manifest(
+            audit_id=self._new_id(created, host),
+            site=result.site,
+            host=host,
+            created_at=created.isoformat(),
+        )
+        stored: dict[str, str] = {}  # page url -> hash
+        skipped: set[str] = set()
+        for issue in result.issues:
+            page = by_url.get(issue.url)
+            if page is None or issue.url in skipped:
+                continue
+            if issue.url not in stored:
+                digest = content_hash(page.html)
+                if digest not in manifest.objects:
+                    size = len(page.html.encode("utf-8"))
+                    if sum(manifest.objects.values()) + size > self.options.max_bytes:
+                        skipped.add(issue.url)
+                        continue
+                    _, manifest.objects[digest] = self.put(
+                        page.url, page.status, page.headers, page.html
+                    )
+                stored[issue.url] = digest
+            issue.snapshot = stored[issue.url]
+
+        self._write_manifest(manifest)
+        result.meta["snapshots"] = {
+            "directory": str(self.root.resolve()),
+            "audit_id": manifest.audit_id,
+            "pages": len(stored),
+            "bytes": sum(manifest.objects.values()),
+            "skipped_pages": len(skipped),
+        }
+        if skipped:
+            logger.warning(f"{len(skipped)} page(s) not snapshotted: size cap reached")
+        self.prune(keep=manifest.audit_id)
+        return manifest
+
+    def _new_id(self, created: datetime, host: str) -> str:
+        base = f"{created:%Y%m%dT%H%M%SZ}-{host.replace(':', '_') or 'site'}"
+        audit_id, n = base, 1
+        while (self.root / "audits" / f"{audit_id}.json").exists():
+            n += 1
+            audit_id = f"{base}-{n}"
+        return audit_id
+
+    def _write_manifest(self, manifest: Manifest) -> None:
+        path = self.root / "audits" / f"{manifest.audit_id}.json"
+        path.parent.mkdir(parents=True, exist_ok=True)
+        path.write_text(manifest.model_dump_json(indent=2), encoding="utf-8")
+
+    def manifests(self) -> list[Manifest]:
+        """Manifests of the audits in the archive, oldest first; unreadable ones are skipped."""
+        found = []
+        for path in sorted((self.root / "audits").glob("*.json")):
+            try:
+                found.append(Manifest.model_validate_json(path.read_text(encoding="utf-8")))
+            except (OSError, ValueError) as e:
+                logger.warning(f"Ignoring snapshot manifest {path}: {e}")
+        return sorted(found, key=lambda m: (m.created_at, m.audit_id))
+
+    def prune(self, keep: str | None = None) -> list[str]:
+        """
+        Apply retention and the size cap, then delete unreferenced objects.
+
+        Args:
+            keep: Audit never evicted (the one just saved)
+
+        Returns:
+            Ids of the audits evicted, oldest first
+        """
+        manifests = self.manifests()
+        evicted: list[Manifest] = []
+        per_host: dict[str, list[Manifest]] = defaultdict(list)
+        for manifest in manifests:
+            per_host[manifest.host].append(manifest)
+        for host_manifests in per_host.values():
+            excess = len(host_manifests) - self.options.keep_audits
+            evicted += [m for m in host_manifests[: max(0, excess)] if m.audit_id != keep]
+
+        remaining = [m for m in manifests if m not in evicted]
+
+        def archive_bytes() -> int:
+            sizes: dict[str, int] = {}
+            for m in remaining:
+                sizes.update(m.objects)
+            return sum(sizes.values())
+
+        while archive_bytes() > self.options.max_bytes:
+            oldest = next((m for m in remaining if m.audit_id != keep), None)
+            if oldest is None:
+                break
+            remaining.remove(oldest)
+            evicted.append(oldest)
+
+        for manifest in evicted:
+            (self.root / "audits" / f"{manifest.audit_id}.json").unlink(missing_ok=True)
+        referenced = {digest for m in remaining for digest in m.objects}
+        for path in (self.root / "objects").glob("*/*.html"):
+            if path.stem not in referenced:
+                path.unlink(missing_ok=True)
+                self.headers_path(path.stem).unlink(missing_ok=True)
+        evicted.sort(key=lambda m: (m.created_at, m.audit_id))
+        return [m.audit_id for m in evicted]
+
+
+def snapshot_archive(meta: dict[str, Any]) -> SnapshotArchive | None:
+    """The archive an audit result was snapshotted to (meta["snapshots"]), if any."""
+    info = meta.get("snapshots") or {}
+    if not info.get("directory"):
+        return None
+    return SnapshotArchive(SnapshotOptions(directory=info["directory"]))
+
+
+def missing_snapshots(result: AuditResult) -> list[Issue]:
+    """Issues whose snapshot is no longer in the archive (evicted, moved, or deleted)."""
+    linked = [i for i in result.issues if i.snapshot]
+    archive = snapshot_archive(result.meta)
+    if archive is None:
+        return linked
+    return [i for i in linked if archive.path_of(i.snapshot) is None]
@@ -0,0 +1,344 @@
+"""
+HTTP clients for audit requests, all sending the same identity.
+
+Every request an audit makes (the crawl, checks that probe assets or
+error pages, page verification) goes through a client from client(), so
+site owners see one recognizable user agent they can allow-list, plus a
+contact address when one is configured.
+
+Credentials for staging sites behind basic auth or a session cookie are
+attached by ScopedCredentials only to requests for the configured host
+(and its subdomains), so links to other sites and assets on a CDN never
+receive them. They are kept out of reprs, dumps, and logs.
+
+Idempotent requests are retried on connection errors, timeouts, and
+502/503/504 with exponential backoff (honoring Retry-After). The client
+records the attempts and outcome per URL, so a check can tell a flaky
+endpoint from a confirmed broken one.
+
+Within metered(), every response of these clients is counted, with the
+bytes downloaded, in a RequestMeter; the check registry meters each check.
+"""
+from __future__ import annotations
+
+import asyncio
+import random
+from base64 import b64encode
+from collections.abc import AsyncIterator, Iterator
+from contextlib import contextmanager
+from contextvars import ContextVar
+from dataclasses import dataclass
+from datetime import datetime, timezone
+from email.utils import parsedate_to_datetime
+from importlib.util import find_spec
+from typing import Any, Literal
+from urllib.parse import urlparse
+
+import httpx
+from loguru import logger
+from pydantic import BaseModel, Field, SecretStr
+
+from ..data.models import Issue
+from ..i18n import msg
+from ..version import package_version
+
+HOMEPAGE = "https://tinyseoai.com"
+IDEMPOTENT_METHODS = frozenset({"GET", "HEAD", "OPTIONS", "PUT", "DELETE", "TRACE"})
+
+
+def default_user_agent() -> str:
+    return f"tinyseoai-audit/{package_version()} (+{HOMEPAGE})"
+
+
+def retry_after_seconds(value: str | None, now: datetime | None = None) -> float | None:
+    """Seconds to wait from a Retry-After header (delta-seconds or HTTP date)."""
+    if not value:
+        return None
+    value = value.strip()
+    try:
+        return max(0.0, float(value))
+    except ValueError:
+        pass
+    try:
+        when = parsedate_to_datetime(value)
+    except (TypeError, ValueError):
+        return None
+    if when.tzinfo is None:
+        when = when.replace(tzinfo=timezone.utc)
+    return max(0.0, (when - (now or datetime.now(timezone.utc))).total_seconds())
+
+
+class RetryPolicy(BaseModel):
+    """When and how long to wait before retrying a request; 4xx responses are never retried."""
+
+    attempts: int = Field(default=3, ge=1)  # tries per request, including the first; 1 turns retries off
+    backoff: float = Field(default=0.5, ge=0)  # seconds before the first retry, doubled after each
+    max_backoff: float = Field(default=10.0, ge=0)
+    jitter: float = Field(default=0.25, ge=0, le=1)  # random extra wait, as a fraction of the backoff
+    max_retry_after: float = Field(default=30.0, ge=0)  # longer Retry-After values are capped
+    statuses: list[int] = Field(default_factory=lambda: [502, 503, 504])
+
+    def delay(self, retry: int, retry_after: float | None = None) -> float:
+        """Seconds to wait before retry number `retry` (1 for the first)."""
+        if retry_after is not None:
+            return min(retry_after, self.max_retry_after)
+        base = min(self.backoff * 2 ** (retry - 1), self.max_backoff)
+        return base + random.uniform(0, base * self.jitter)
+
+
+class Attempts(BaseModel):
+    """How a URL fared: tries made and the final classification."""
+
+    url: str
+    attempts: int
+    # ok: first try; recovered: succeeded after retrying; transient: still failing
+    # with retryable errors when attempts ran out; broken: failed in a way not retried
+    outcome: Literal["ok", "recovered", "transient", "broken"]
+    last: str | None = None  # final status code or error name
+
+    @property
+    def flaky(self) -> bool:
+        return self.outcome in ("recovered", "transient")
+
+
+@dataclass
+class RequestMeter:
+    """HTTP requests made and response bytes downloaded while it was active."""
+
+    requests: int = 0
+    bytes_fetched: int = 0
+
+
+_METER: ContextVar[RequestMeter | None] = ContextVar("tinyseoai_request_meter", default=None)
+
+
+@contextmanager
+def metered() -> Iterator[RequestMeter]:
+    """
+    Count the requests of AuditClients in this context, including tasks
+    started within it.
+    """
+    meter = RequestMeter()
+    token = _METER.set(meter)
+    try:
+        yield meter
+    finally:
+        _METER.reset(token)
+
+
+class _CountingStream(httpx.AsyncByteStream):
+    """A response body that adds the bytes read to a meter."""
+
+    def __init__(self, stream: httpx.AsyncByteStream, meter: RequestMeter):
+        self._stream = stream
+        self._meter = meter
+
+    async def __aiter__(self) -> AsyncIterator[bytes]:
+        async for chunk in self._stream:
+            self._meter.bytes_fetched += len(chunk)
+            yield chunk
+
+    async def aclose(self) -> None:
+        await self._stream.aclose()
+
+
+async def _meter_response(response: httpx.Response) -> None:
+    meter = _METER.get()
+    if meter is None:
+        return
+    meter.requests += 1
+    try:
+        meter.bytes_fetched += len(response.content)  # read already (e.g. by a mock transport)
+    except httpx.ResponseNotRead:
+        response.stream = _CountingStream(response.stream, meter)
+
+
+class HttpOptions(BaseModel):
+    """Request identity, TLS, and retry settings for every request of an audit."""
+
+    user_agent: str | None = None  # None sends default_user_agent()
+    contact: str | None = None  # sent as From, e.g. an address site owners can write to
+    accept_language: str | None = None  # sent as Accept-Language when set
+    verify_tls: bool = True  # False accepts invalid certificates (staging only); flagged in results
+    http2: bool = True
+    retry: RetryPolicy = Field(default_factory=RetryPolicy)
+    auth_host: str | None = None  # host suffix credentials are sent to; None is the audited host
+    # Credentials are never saved with the config or shown; they come from flags or the environment
+    basic_auth: tuple[str, SecretStr] | None = Field(default=None, exclude=True, repr=False)
+    cookie: SecretStr | None = Field(default=None, exclude=True, repr=False)  # a static Cookie header
+    extra_headers: dict[str, SecretStr] = Field(default_factory=dict, exclude=True, repr=False)
+
+    @property
+    def agent(self) -> str:
+        return self.user_agent or default_user_agent()
+
+    def headers(self) -> dict[str, str]:
+        headers = {"User-Agent": self.agent}
+        if self.contact:
+            headers["From"] = self.contact
+        if self.accept_language:
+            headers["Accept-Language"] = self.accept_language
+        return headers
+
+    @property
+    def has_credentials(self) -> bool:
+        return bool(self.basic_auth or self.cookie or self.extra_headers)
+
+    def for_site(self, url: str) -> HttpOptions:
+        """These options with credentials scoped to the host of url unless auth_host is set."""
+        if not self.has_credentials or self.auth_host:
+            return self
+        return self.model_copy(update={"auth_host": urlparse(url).hostname})
+
+    def credentials(self) -> ScopedCredentials | None:
+        if not self.has_credentials or not self.auth_host:
+            return None
+        return ScopedCredentials(self)
+
+
+def host_matches(host: str, suffix: str) -> bool:
+    """True when host is suffix or one of its subdomains (staging.example.com, not badexample.com)."""
+    host, suffix = host.lower().rstrip("."), suffix.lower().strip(".")
+    return host == suffix or host.endswith("." + suffix)
+
+
+class ScopedCredentials:
+    """
+    A request hook adding basic auth, the cookie, and extra headers to
+    requests for auth_host, and removing them from requests elsewhere.
+
+    It runs for every redirect hop; a redirect copies the previous request's
+    headers, so a hop to another host must have them taken off again.
+    """
+
+    def __init__(self, options: HttpOptions):
+        self._host = options.auth_host or ""
+        self._headers = {k: v.get_secret_value() for k, v in options.extra_headers.items()}
+        if options.basic_auth:
+            username, password = options.basic_auth
+            token = b64encode(f"{username}:{password.get_secret_value()}".encode()).decode()
+            self._headers["Authorization"] = f"Basic {token}"
+        self._cookie = options.cookie.get_secret_value() if options.cookie else None
+
+    def __repr__(self) -> str:
+        return f"ScopedCredentials(host={self._host!r})"
+
+    async def __call__(self, request: httpx.Request) -> None:
+        if not host_matches(request.url.host, self._host):
+            for name in self._headers:
+                request.headers.pop(name, None)
+            return
+        request.headers.update(self._headers)
+        if self._cookie:
+            existing = request.headers.get("Cookie")
+            if not existing:
+                request.headers["Cookie"] = self._cookie
+            elif self._cookie not in existing:
+                request.headers["Cookie"] = f"{existing}; {self._cookie}"
+
+
+class AuditClient(httpx.AsyncClient):
+    """
+    An AsyncClient that retries idempotent requests, records the attempts
+    per URL, and reports its requests to the active RequestMeter.
+    """
+
+    def __init__(self, retry: RetryPolicy | None = None, **kwargs: Any):
+        hooks = kwargs.pop("event_hooks", None) or {}
+        hooks = {**hooks, "response": [_meter_response, *hooks.get("response", [])]}
+        super().__init__(event_hooks=hooks, **kwargs)
+        self.retry = retry or RetryPolicy()
+        self.attempts: dict[str, Attempts] = {}
+
+    async def send(self, request: httpx.Request, **kwargs: Any) -> httpx.Response:
+        if request.method not in IDEMPOTENT_METHODS:
+            return await super().send(request, **kwargs)
+        url = str(request.url)
+        attempt = 1
+        while True:
+            try:
+                response = await super().send(request, **kwargs)
+            except (httpx.ConnectError, httpx.TimeoutException) as e:
+                if attempt >= self.retry.attempts:
+                    self._record(url, attempt, "transient", type(e).__name__)
+                    raise
+                wait = self.retry.delay(attempt)
+            except httpx.HTTPError as e:
+                self._record(url, attempt, "broken", type(e).__name__)
+                raise
+            else:
+                status = response.status_code
+                if status not in self.retry.statuses:
+                    outcome = "broken" if status >= 400 else "recovered" if attempt > 1 else "ok"
+                    self._record(url, attempt, outcome, str(status))
+                    return response
+                if attempt >= self.retry.attempts:
+                    self._record(url, attempt, "transient", str(status))
+                    return response
+                retry_after = retry_after_seconds(response.headers.get("retry-after"))
+                wait = self.retry.delay(attempt, retry_after)
+                await response.aclose()
+            logger.debug(f"Retrying {url} in {wait:.2f}s (attempt {attempt} failed)")
+            await asyncio.sleep(wait)
+            attempt += 1
+
+    def _record(self, url: str, attempts: int, outcome: str, last: str) -> None:
+        self.attempts[url] = Attempts(url=url, attempts=attempts, outcome=outcome, last=last)
+
+
+def attempts_for(http: httpx.AsyncClient, url: str) -> Attempts | None:
+    """The attempts recorded for url by an AuditClient; None for other clients or unseen URLs."""
+    return getattr(http, "attempts", {}).get(url)
+
+
+def flaky_urls(http: httpx.AsyncClient) -> list[dict]:
+    """Attempts of the URLs that needed retries, for a result's meta."""
+    return [a.model_dump() for a in getattr(http, "attempts", {}).values() if a.flaky]
+
+
+def client(options: HttpOptions | None = None, **kwargs: Any) -> AuditClient:
+    """
+    An AsyncClient sending the audit's identity headers and retrying
+    transient failures.
+
+    Args:
+        options: Identity, TLS, retry, and credential settings (the defaults
+            when omitted); credentials are only sent once auth_host is set
+        **kwargs: Passed to httpx.AsyncClient (timeout, follow_redirects,
+            transport); headers given here are sent as well
+
+    Returns:
+        A new client; the caller closes it
+    """
+    options = options or HttpOptions()
+    headers = {**options.headers(), **kwargs.pop("headers", {})}
+    if not options.verify_tls:
+        logger.warning("TLS certificate verification is disabled for this audit")
+    # h2 comes with httpx[http2]; without it requests fall back to HTTP/1.1
+    http2 = options.http2 and find_spec("h2") is not None
+    hooks = kwargs.pop("event_hooks", {})
+    credentials = options.credentials()
+    if credentials is not None:
+        hooks = {**hooks, "request": [*hooks.get("request", []), credentials]}
+    return AuditClient(
+        options.retry,
+        headers=headers,
+        verify=options.verify_tls,
+        http2=http2,
+        event_hooks=hooks,
+        **kwargs,
+    )
+
+
+def tls_issues(site_root: str, options: HttpOptions | None) -> list[Issue]:
+    """A finding marking results gathered with certificate verification off, or nothing."""
+    if options is None or options.verify_tls:
+        return []
+    return [
+        Issue(
+            url=site_root,
+            type="tls_verification_disabled",
+            severity="info",
+            **msg("crawl.tls_verification_disabled"),
+        )
+    ]
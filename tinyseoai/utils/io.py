@@ -1,12 +1,146 @@
+"""
+Reading and writing result files, plain or compressed.
+
+Compressed files are recognized by their first bytes, not their name, so a
+gzip file saved as summary.json still loads. gzip is built in; zstd uses
+the standard library's compression.zstd where there is one (Python 3.14+),
+else the zstandard package (pip install tinyseoai[zstd]). Both are
+decompressed and compressed as streams, never as one buffer of the file.
+"""
 from __future__ import annotations
 
+import gzip
 import json
+import os
+import tempfile
 from pathlib import Path
+from typing import IO, Literal
+
+Codec = Literal["gzip", "zstd"]
+
+CODECS: tuple[Codec, ...] = ("gzip", "zstd")
+EXTENSIONS: dict[Codec, str] = {"gzip": ".gz", "zstd": ".zst"}
+
+_MAGIC: dict[Codec, bytes] = {"gzip": b"\x1f\x8b", "zstd": b"\x28\xb5\x2f\xfd"}
 
 
 def ensure_dir(p: Path) -> None:
     p.mkdir(parents=True, exist_ok=True)
 
-def write_json(path: Path, data) -> None:
+
+def _zstd():
+    """The zstd module: the standard library's, else zstandard."""
+    try:
+        from compression import zstd  # Python 3.14+
+    except ImportError:
+        try:
+            import zstandard as zstd
+        except ImportError:
+            raise ValueError(
+                "zstd needs the zstandard package: pip install 'tinyseoai[zstd]'"
+            ) from None
+    return zstd
+
+
+def check_codec(codec: Codec) -> None:
+    """
+    Make sure codec can be written here.
+
+    Raises:
+        ValueError: If the codec is unknown, or zstd has no module to use
+    """
+    if codec not in CODECS:
+        raise ValueError(f"Unknown compression {codec!r}; use {' or '.join(CODECS)}")
+    if codec == "zstd":
+        _zstd()
+
+
+def sniff(path: Path) -> Codec | None:
+    """The codec a file is compressed with, from its first bytes; None: not compressed."""
+    with open(path, "rb") as f:
+        head = f.read(4)
+    return next((codec for codec, magic in _MAGIC.items() if head.startswith(magic)), None)
+
+
+def compressed_path(path: Path, codec: Codec | None) -> Path:
+    """path with the codec's extension added, unless it already ends with it."""
+    if codec is None or path.name.endswith(EXTENSIONS[codec]):
+        return path
+    return path.with_name(path.name + EXTENSIONS[codec])
+
+
+def open_text(path: Path, mode: Literal["r", "w"] = "r", codec: Codec | None = None) -> IO[str]:
+    """
+    Open a file as UTF-8 text, decompressing or compressing as a stream.
+
+    Reading sniffs the codec from the file; writing uses codec, None for plain text.
+
+    Raises:
+        ValueError: If the file is zstd and no zstd module is installed
+    """
+    if mode == "r":
+        codec = sniff(path)
+    if codec == "gzip":
+        return gzip.open(path, f"{mode}t", encoding="utf-8")
+    if codec == "zstd":
+        return _zstd().open(path, f"{mode}t", encoding="utf-8")
+    return open(path, mode, encoding="utf-8")
+
+
+def read_json(path: Path):
+    """
+    Load a JSON file, plain, gzip, or zstd.
+
+    Raises:
+        OSError: If the file cannot be read or its compressed data is corrupt
+        ValueError: If it is not valid JSON, or zstd without a zstd module
+    """
+    try:
+        with open_text(path) as f:
+            return json.load(f)
+    except EOFError as e:  # a truncated compressed file
+        raise ValueError(f"{path} is truncated") from e
+
+
+def write_json(path: Path, data, compress: Codec | None = None) -> Path:
+    """
+    Write data as indented JSON, compressed when compress is set.
+
+    Returns:
+        The file written: path with the codec's extension added when compressed
+    """
+    path = compressed_path(path, compress)
     path.parent.mkdir(parents=True, exist_ok=True)
-    path.write_text(json.dumps(data, indent=2))
+    if compress is None:
+        path.write_text(json.dumps(data, indent=2))
+        return path
+    with open_text(path, "w", compress) as f:
+        for chunk in json.JSONEncoder(indent=2).iterencode(data):
+            f.write(chunk)
+    return path
+
+
+def rewrite_json(path: Path, data) -> Path:
+    """Replace the JSON in an existing file, compressed the way it was, under the same name."""
+    codec = sniff(path) if path.exists() else None
+    with open_text(path, "w", codec) as f:
+        for chunk in json.JSONEncoder(indent=2).iterencode(data):
+            f.write(chunk)
+    return path
+
+
+def write_json_atomic(path: Path, data) -> None:
+    """Write JSON via a temp file and rename, so a failed write leaves `path` untouched."""
+    path.parent.mkdir(parents=True, exist_ok=True)
+    fd, tmp = tempfile.mkstemp(dir=path.parent, prefix=f".{path.name}_", suffix=".tmp")
+    try:
+        with os.fdopen(fd, "w") as f:
+            json.dump(data, f, indent=2)
+        os.replace(tmp, path)
+    except Exception:
+        try:
+            os.unlink(tmp)
+        except OSError:
+            pass
+        raise
+
@@ -10,18 +10,27 @@ class RateLimiter:
     Simple async rate limiter used to throttle crawler requests.
 
     Supports a base requests-per-second value and optionally honors a
-    robots.txt crawl-delay directive when present.
+    robots.txt crawl-delay directive when present. Requests are paced with
+    a token bucket: up to `burst` requests may go out back to back, after
+    which one token refills per interval.
     """
 
-    def __init__(self, requests_per_second: float = 2.0, crawl_delay: float | None = None):
+    def __init__(
+        self, requests_per_second: float = 2.0, crawl_delay: float | None = None, burst: int = 1
+    ):
         if requests_per_second <= 0:
             raise ValueError("requests_per_second must be greater than zero")
+        if burst < 1:
+            raise ValueError("burst must be at least 1")
 
         self.requests_per_second = requests_per_second
+        self.burst = burst
         self._min_interval = 1.0 / requests_per_second
         self._crawl_delay = self._sanitize_delay(crawl_delay)
         self._lock = asyncio.Lock()
-        self._last_request: float | None = None
+        self._tokens = float(burst)
+        self._updated: float | None = None
+        self._not_before = 0.0
 
     @staticmethod
     def _sanitize_delay(delay: float | None) -> float | None:
@@ -40,6 +49,15 @@ class RateLimiter:
             return max(self._min_interval, self._crawl_delay)
         return self._min_interval
 
+    def defer(self, seconds: float) -> None:
+        """Hold all requests for at least `seconds` from now (e.g. after Retry-After)."""
+        self._not_before = max(self._not_before, time.monotonic() + max(0.0, seconds))
+
+    def _refill(self, now: float) -> None:
+        if self._updated is not None:
+            self._tokens = min(self.burst, self._tokens + (now - self._updated) / self.effective_interval)
+        self._updated = now
+
     async def wait(self) -> None:
         """
         Await until the next request is allowed.
@@ -50,15 +68,9 @@ class RateLimiter:
         """
         async with self._lock:
             now = time.monotonic()
-            if self._last_request is None:
-                self._last_request = now
-                return
-
-            elapsed = now - self._last_request
-            interval = self.effective_interval
-            remaining = interval - elapsed
-            if remaining > 0:
-                await asyncio.sleep(remaining)
-                now = time.monotonic()
-
-            self._last_request = now
+            self._refill(now)
+            delay = max(self._not_before - now, (1 - self._tokens) * self.effective_interval)
+            if delay > 0:
+                await asyncio.sleep(delay)
+                self._refill(time.monotonic())
+            self._tokens -= 1
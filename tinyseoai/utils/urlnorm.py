@@ -0,0 +1,218 @@
+"""
+URL normalization for deduplicating crawled pages and matching issues.
+
+normalize lowercases the scheme and host (IDN hosts become punycode),
+drops default ports and the fragment, resolves "." and ".." path segments,
+normalizes percent-escapes, removes tracking/session query parameters,
+and sorts the rest. Two URLs are equivalent when they normalize to the
+same string. Paths stay case-sensitive.
+"""
+from __future__ import annotations
+
+import fnmatch
+import re
+from collections.abc import Iterable, Iterator
+from typing import Literal
+from urllib.parse import parse_qsl, quote, urlencode, urlsplit, urlunsplit
+
+from pydantic import BaseModel
+
+DEFAULT_PORTS = {"http": 80, "https": 443}
+
+# Query parameters that vary per visit and never identify a page (glob patterns)
+STRIP_PARAMS = (
+    "utm_*",
+    "fbclid",
+    "gclid",
+    "msclkid",
+    "dclid",
+    "yclid",
+    "mc_cid",
+    "mc_eid",
+    "_ga",
+    "_gl",
+    "igshid",
+    "ref_src",
+    "sessionid",
+    "phpsessid",
+    "jsessionid",
+)
+
+_ESCAPE = re.compile(r"%([0-9A-Fa-f]{2})")
+_UNRESERVED = frozenset("ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-._~")
+_PATH_SAFE = "/:@!$&'()*+,;=-._~%"  # characters left unescaped in paths (existing escapes included)
+
+
+class NormalizeOptions(BaseModel):
+    """Rules that may differ between callers."""
+
+    trailing_slash: Literal["keep", "strip", "add"] = "keep"  # "/" alone is never stripped
+    strip_params: tuple[str, ...] = STRIP_PARAMS  # case-insensitive globs
+    sort_query: bool = True
+    drop_www: bool = False
+
+
+DEFAULT_OPTIONS = NormalizeOptions()
+
+
+class NormalizedUrl(str):
+    """A normalized URL; use it as a set or dict key."""
+
+    @property
+    def scheme(self) -> str:
+        return urlsplit(self).scheme
+
+    @property
+    def host(self) -> str:
+        return urlsplit(self).netloc
+
+    @property
+    def path(self) -> str:
+        return urlsplit(self).path
+
+    @property
+    def query(self) -> str:
+        return urlsplit(self).query
+
+
+def _host(netloc: str, scheme: str, drop_www: bool) -> str:
+    userinfo, _, hostport = netloc.rpartition("@")
+    if hostport.startswith("[") and "]" in hostport:  # IPv6 literal
+        end = hostport.index("]") + 1
+        host, port = hostport[:end], hostport[end + 1 :]
+    else:
+        host, _, port = hostport.partition(":")
+    host = host.lower().rstrip(".")
+    if not host.isascii():
+        try:
+            host = host.encode("idna").decode("ascii")
+        except UnicodeError:
+            pass  # not a valid IDN; keep it lowercased
+    if drop_www and host.startswith("www."):
+        host = host[4:]
+    if port.isdigit():
+        port = "" if int(port) == DEFAULT_PORTS.get(scheme) else str(int(port))
+    if port:
+        host = f"{host}:{port}"
+    return f"{userinfo}@{host}" if userinfo else host
+
+
+def _unescape_unreserved(text: str) -> str:
+    """Decode escapes of unreserved characters and uppercase the others (RFC 3986, 6.2.2)."""
+
+    def fix(match: re.Match) -> str:
+        char = chr(int(match.group(1), 16))
+        return char if char in _UNRESERVED else f"%{match.group(1).upper()}"
+
+    return _ESCAPE.sub(fix, text)
+
+
+def remove_dot_segments(path: str) -> str:
+    """Resolve "." and ".." segments (RFC 3986, 5.2.4)."""
+    output: list[str] = []
+    for segment in path.split("/")[1:] if path.startswith("/") else path.split("/"):
+        if segment == "..":
+            if output:
+                output.pop()
+        elif segment != ".":
+            output.append(segment)
+    if path.rsplit("/", 1)[-1] in (".", ".."):
+        output.append("")  # "/a/b/.." names the directory "/a/"
+    return "/" + "/".join(output)
+
+
+def _path(path: str, policy: str) -> str:
+    # Spaces and non-ASCII characters are escaped the way browsers send them
+    path = remove_dot_segments(_unescape_unreserved(quote(path or "/", safe=_PATH_SAFE)))
+    if path == "/" or policy == "keep":
+        return path
+    if policy == "strip":
+        return path.rstrip("/") or "/"
+    last = path.rsplit("/", 1)[-1]
+    return path if path.endswith("/") or "." in last else path + "/"
+
+
+def _stripped(name: str, patterns: Iterable[str]) -> bool:
+    name = name.lower()
+    return any(fnmatch.fnmatchcase(name, p.lower()) for p in patterns)
+
+
+def _query(query: str, options: NormalizeOptions) -> str:
+    params = [
+        (k, v)
+        for k, v in parse_qsl(query, keep_blank_values=True)
+        if not _stripped(k, options.strip_params)
+    ]
+    if options.sort_query:
+        params.sort()
+    return urlencode(params)
+
+
+def normalize(url: str, options: NormalizeOptions | None = None) -> NormalizedUrl:
+    """
+    Normalize a URL.
+
+    Args:
+        url: Absolute URL
+        options: Trailing slash policy, parameters to strip, query sorting,
+            and whether "www." is dropped
+
+    Returns:
+        NormalizedUrl; normalizing it again returns the same value
+    """
+    options = options or DEFAULT_OPTIONS
+    parts = urlsplit(url.strip())
+    scheme = parts.scheme.lower()
+    if not parts.netloc:  # mailto:, tel:, and relative references have no hierarchical path
+        return NormalizedUrl(urlunsplit((scheme, "", parts.path.strip(), parts.query.strip(), "")))
+    return NormalizedUrl(
+        urlunsplit(
+            (
+                scheme,
+                _host(parts.netloc, scheme, options.drop_www),
+                _path(parts.path, options.trailing_slash),
+                _query(parts.query, options),
+                "",
+            )
+        )
+    )
+
+
+def equivalent(a: str, b: str, options: NormalizeOptions | None = None) -> bool:
+    """True when two URLs normalize to the same value."""
+    return normalize(a, options) == normalize(b, options)
+
+
+class DedupSet:
+    """
+    Set of URLs compared after normalization.
+
+    Iterating yields the first spelling seen of each URL, in insertion order.
+    """
+
+    def __init__(self, urls: Iterable[str] = (), options: NormalizeOptions | None = None):
+        self.options = options or DEFAULT_OPTIONS
+        self._seen: dict[NormalizedUrl, str] = {}
+        for url in urls:
+            self.add(url)
+
+    def add(self, url: str) -> bool:
+        """Add a URL; returns False if an equivalent one was already present."""
+        key = normalize(url, self.options)
+        if key in self._seen:
+            return False
+        self._seen[key] = url
+        return True
+
+    def original(self, url: str) -> str | None:
+        """The first spelling added of a URL equivalent to this one."""
+        return self._seen.get(normalize(url, self.options))
+
+    def __contains__(self, url: object) -> bool:
+        return isinstance(url, str) and normalize(url, self.options) in self._seen
+
+    def __iter__(self) -> Iterator[str]:
+        return iter(self._seen.values())
+
+    def __len__(self) -> int:
+        return len(self._seen)
@@ -0,0 +1,14 @@
+"""
+Version of the installed package.
+"""
+from __future__ import annotations
+
+from importlib.metadata import PackageNotFoundError, version
+
+
+def package_version() -> str:
+    """The installed tinyseoai version, or 0.0.0 when running from an uninstalled source tree."""
+    try:
+        return version("tinyseoai")
+    except PackageNotFoundError:
+        return "0.0.0"